@@ -59,6 +59,38 @@ impl Index {
 impl BinningIndex for Index {
     type ReferenceSequence = ReferenceSequence;
 
+    /// Returns the number of bits for the minimum interval.
+    ///
+    /// This is always 14.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bam::bai;
+    /// use noodles_csi::BinningIndex;
+    /// let index = bai::Index::default();
+    /// assert_eq!(index.min_shift(), 14);
+    /// ```
+    fn min_shift(&self) -> u8 {
+        MIN_SHIFT
+    }
+
+    /// Returns the depth of the binning index.
+    ///
+    /// This is always 5.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bam::bai;
+    /// use noodles_csi::BinningIndex;
+    /// let index = bai::Index::default();
+    /// assert_eq!(index.depth(), 5);
+    /// ```
+    fn depth(&self) -> u8 {
+        DEPTH
+    }
+
     /// Returns a list of indexed reference sequences.
     ///
     /// This list is parallel to the reference sequences defined in the associated BAM file.
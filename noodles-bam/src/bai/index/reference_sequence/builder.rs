@@ -41,7 +41,8 @@ impl Builder {
             return ReferenceSequence::default();
         }
 
-        let bins: Vec<_> = self.bin_builders.into_values().map(|b| b.build()).collect();
+        let mut bins: Vec<_> = self.bin_builders.into_values().map(|b| b.build()).collect();
+        bins.sort_unstable_by_key(|bin| bin.id());
 
         let intervals = self
             .intervals
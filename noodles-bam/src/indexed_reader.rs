@@ -8,6 +8,7 @@ use std::io::{self, Read, Seek};
 
 use noodles_bgzf as bgzf;
 use noodles_core::Region;
+use noodles_csi::BinningIndex;
 use noodles_sam::{self as sam, alignment::Record, header::ReferenceSequences};
 
 use crate::reader::UnmappedRecords;
@@ -19,12 +20,15 @@ use super::{
 };
 
 /// An indexed BAM reader.
-pub struct IndexedReader<R> {
+///
+/// The index is typically a BAM index (BAI) but can be any binning index, e.g., a
+/// coordinate-sorted index (CSI).
+pub struct IndexedReader<R, I = bai::Index> {
     inner: Reader<R>,
-    index: bai::Index,
+    index: I,
 }
 
-impl<R> IndexedReader<R>
+impl<R, I> IndexedReader<R, I>
 where
     R: Read,
 {
@@ -43,6 +47,11 @@ where
         self.inner.into_inner()
     }
 
+    /// Returns the associated index.
+    pub fn index(&self) -> &I {
+        &self.index
+    }
+
     /// Reads the raw SAM header.
     pub fn read_header(&mut self) -> io::Result<String> {
         self.inner.read_header()
@@ -74,12 +83,12 @@ where
     }
 }
 
-impl<R> IndexedReader<bgzf::Reader<R>>
+impl<R, I> IndexedReader<bgzf::Reader<R>, I>
 where
     R: Read,
 {
     /// Creates an indexed BAM reader.
-    pub fn new(inner: R, index: bai::Index) -> Self {
+    pub fn new(inner: R, index: I) -> Self {
         Self {
             inner: Reader::new(inner),
             index,
@@ -87,9 +96,10 @@ where
     }
 }
 
-impl<R> IndexedReader<bgzf::Reader<R>>
+impl<R, I> IndexedReader<bgzf::Reader<R>, I>
 where
     R: Read + Seek,
+    I: BinningIndex,
 {
     /// Returns an iterator over records that intersect the given region.
     pub fn query<'a>(
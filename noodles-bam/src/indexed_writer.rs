@@ -0,0 +1,222 @@
+//! Indexed BAM writer.
+
+mod builder;
+
+pub use self::builder::Builder;
+
+use std::{
+    fs::File,
+    io::{self, Write},
+    mem,
+    path::PathBuf,
+};
+
+use noodles_bgzf as bgzf;
+use noodles_csi::index::reference_sequence::bin::Chunk;
+use noodles_sam::{self as sam, alignment::Record};
+
+use super::{bai, Writer};
+
+/// An indexed BAM writer.
+///
+/// This writes a coordinate-sorted BAM and simultaneously builds its index (BAI).
+///
+/// # Examples
+///
+/// ```
+/// use std::num::NonZeroUsize;
+///
+/// use noodles_bam as bam;
+/// use noodles_core::Position;
+/// use noodles_sam::{
+///     self as sam,
+///     alignment::Record,
+///     header::record::value::{map::ReferenceSequence, Map},
+/// };
+///
+/// let header = sam::Header::builder()
+///     .add_reference_sequence(
+///         "sq0".parse()?,
+///         Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8)?),
+///     )
+///     .build();
+///
+/// let mut writer = bam::IndexedWriter::new(Vec::new());
+/// writer.write_header(&header)?;
+///
+/// let record = Record::builder()
+///     .set_reference_sequence_id(0)
+///     .set_alignment_start(Position::MIN)
+///     .set_cigar("4M".parse()?)
+///     .set_sequence("ACGT".parse()?)
+///     .build();
+///
+/// writer.write_record(&header, &record)?;
+///
+/// let index = writer.finish()?;
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub struct IndexedWriter<W>
+where
+    W: Write,
+{
+    inner: Writer<bgzf::Writer<W>>,
+    builder: bai::index::Builder,
+    reference_sequence_count: usize,
+    index_dst: Option<PathBuf>,
+}
+
+impl<W> IndexedWriter<W>
+where
+    W: Write,
+{
+    /// Creates an indexed BAM writer.
+    ///
+    /// The output is compressed using BGZF.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bam as bam;
+    /// let writer = bam::IndexedWriter::new(Vec::new());
+    /// ```
+    pub fn new(inner: W) -> Self {
+        Self::with_index_dst(inner, None)
+    }
+
+    pub(crate) fn with_index_dst(inner: W, index_dst: Option<PathBuf>) -> Self {
+        Self {
+            inner: Writer::new(inner),
+            builder: bai::Index::builder(),
+            reference_sequence_count: 0,
+            index_dst,
+        }
+    }
+
+    /// Returns a reference to the underlying BGZF writer.
+    pub fn get_ref(&self) -> &bgzf::Writer<W> {
+        self.inner.get_ref()
+    }
+
+    /// Writes a SAM header and its reference sequences.
+    ///
+    /// The index has a reference sequence for each reference sequence in the header.
+    pub fn write_header(&mut self, header: &sam::Header) -> io::Result<()> {
+        self.inner.write_header(header)?;
+        self.inner
+            .write_reference_sequences(header.reference_sequences())?;
+        self.reference_sequence_count = header.reference_sequences().len();
+        Ok(())
+    }
+
+    /// Writes a record and adds it to the index.
+    ///
+    /// Records must be written in coordinate-sorted order.
+    pub fn write_record(&mut self, header: &sam::Header, record: &Record) -> io::Result<()> {
+        let start_position = self.inner.get_ref().virtual_position();
+        self.inner.write_record(header, record)?;
+        let end_position = self.inner.get_ref().virtual_position();
+
+        let chunk = Chunk::new(start_position, end_position);
+        self.builder.add_record(record, chunk)
+    }
+
+    /// Finishes the output stream and builds the index.
+    ///
+    /// This writes the final BGZF blocks, including the EOF block. If the writer was built from
+    /// a path, the index is also written to its destination (`<dst>.bai`, by default).
+    ///
+    /// No records should be written after the writer is finished.
+    pub fn finish(&mut self) -> io::Result<bai::Index> {
+        self.inner.try_finish()?;
+
+        let index = mem::take(&mut self.builder).build(self.reference_sequence_count);
+
+        if let Some(dst) = self.index_dst.take() {
+            let mut writer = File::create(dst).map(bai::Writer::new)?;
+            writer.write_header()?;
+            writer.write_index(&index)?;
+        }
+
+        Ok(index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{io::Cursor, num::NonZeroUsize};
+
+    use noodles_core::Position;
+    use noodles_csi::BinningIndex;
+    use sam::header::record::value::{map::ReferenceSequence, Map};
+
+    use super::*;
+    use crate::Reader;
+
+    #[test]
+    fn test_finish() -> Result<(), Box<dyn std::error::Error>> {
+        let header = sam::Header::builder()
+            .add_reference_sequence(
+                "sq0".parse()?,
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(55)?),
+            )
+            .add_reference_sequence(
+                "sq1".parse()?,
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(34)?),
+            )
+            .add_reference_sequence(
+                "sq2".parse()?,
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(21)?),
+            )
+            .build();
+
+        let mut writer = IndexedWriter::new(Vec::new());
+        writer.write_header(&header)?;
+
+        for (reference_sequence_id, position) in [(0, 8), (0, 13), (1, 5)] {
+            let record = Record::builder()
+                .set_reference_sequence_id(reference_sequence_id)
+                .set_alignment_start(Position::try_from(position)?)
+                .set_cigar("4M".parse()?)
+                .set_sequence("ACGT".parse()?)
+                .build();
+
+            writer.write_record(&header, &record)?;
+        }
+
+        writer.write_record(&header, &Record::default())?;
+
+        let index = writer.finish()?;
+        let data = writer.get_ref().get_ref().clone();
+
+        assert_eq!(index.reference_sequences().len(), 3);
+        assert_eq!(index.unplaced_unmapped_record_count(), Some(1));
+
+        let mut buf = Vec::new();
+        let mut index_writer = bai::Writer::new(&mut buf);
+        index_writer.write_header()?;
+        index_writer.write_index(&index)?;
+
+        let mut index_reader = bai::Reader::new(&buf[..]);
+        index_reader.read_header()?;
+        assert_eq!(index_reader.read_index()?, index);
+
+        let mut reader = Reader::new(Cursor::new(data));
+        reader.read_header()?;
+        reader.read_reference_sequences()?;
+
+        let region = "sq0:10-20".parse()?;
+        let positions: Vec<_> = reader
+            .query(&header, &index, &region)?
+            .map(|result| result.map(|record| record.alignment_start().map(usize::from)))
+            .collect::<io::Result<_>>()?;
+
+        assert_eq!(positions, [Some(8), Some(13)]);
+
+        let region = "sq2".parse()?;
+        let mut query = reader.query(&header, &index, &region)?;
+        assert!(query.next().is_none());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,70 @@
+use std::{
+    ffi::{OsStr, OsString},
+    fs::File,
+    io,
+    path::{Path, PathBuf},
+};
+
+use super::IndexedWriter;
+
+/// An indexed BAM writer builder.
+#[derive(Debug, Default)]
+pub struct Builder {
+    index_dst: Option<PathBuf>,
+}
+
+impl Builder {
+    /// Sets the destination of the index.
+    ///
+    /// By default, this is `<dst>.bai`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bam::indexed_writer::Builder;
+    /// let builder = Builder::default().set_index_dst("out.bai");
+    /// ```
+    pub fn set_index_dst<P>(mut self, index_dst: P) -> Self
+    where
+        P: Into<PathBuf>,
+    {
+        self.index_dst = Some(index_dst.into());
+        self
+    }
+
+    /// Builds an indexed BAM writer from a path.
+    ///
+    /// The index is written when the writer is finished.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use noodles_bam::indexed_writer::Builder;
+    /// let writer = Builder::default().build_from_path("out.bam")?;
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn build_from_path<P>(self, dst: P) -> io::Result<IndexedWriter<File>>
+    where
+        P: AsRef<Path>,
+    {
+        let dst = dst.as_ref();
+
+        let index_dst = self
+            .index_dst
+            .unwrap_or_else(|| push_ext(dst.into(), "bai"));
+
+        let file = File::create(dst)?;
+
+        Ok(IndexedWriter::with_index_dst(file, Some(index_dst)))
+    }
+}
+
+fn push_ext<S>(path: PathBuf, ext: S) -> PathBuf
+where
+    S: AsRef<OsStr>,
+{
+    let mut s = OsString::from(path);
+    s.push(".");
+    s.push(ext);
+    PathBuf::from(s)
+}
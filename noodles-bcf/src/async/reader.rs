@@ -38,6 +38,7 @@ use crate::{header::string_maps::ContigStringMap, Record};
 pub struct Reader<R> {
     inner: R,
     buf: Vec<u8>,
+    bcf_2_1_compatibility: bool,
 }
 
 impl<R> Reader<R>
@@ -92,7 +93,9 @@ where
     ///
     /// The position of the stream is expected to be at the start.
     ///
-    /// This returns the major and minor format versions as a tuple.
+    /// This returns the major and minor format versions as a tuple. If the format version is 2.1,
+    /// records are subsequently read as BCF 2.1 (see
+    /// [`crate::reader::Builder::set_bcf_2_1_compatibility`]).
     ///
     /// # Examples
     ///
@@ -110,7 +113,14 @@ where
     /// ```
     pub async fn read_file_format(&mut self) -> io::Result<(u8, u8)> {
         read_magic(&mut self.inner).await?;
-        read_format_version(&mut self.inner).await
+
+        let format_version = read_format_version(&mut self.inner).await?;
+
+        if format_version == (2, 1) {
+            self.bcf_2_1_compatibility = true;
+        }
+
+        Ok(format_version)
     }
 
     /// Reads the raw VCF header.
@@ -169,7 +179,13 @@ where
     /// # }
     /// ```
     pub async fn read_record(&mut self, record: &mut Record) -> io::Result<usize> {
-        read_record(&mut self.inner, &mut self.buf, record).await
+        let n = read_record(&mut self.inner, &mut self.buf, record).await?;
+
+        if n > 0 && self.bcf_2_1_compatibility {
+            crate::reader::record::compat::upgrade_record(record)?;
+        }
+
+        Ok(n)
     }
 
     /// Returns an (async) stream over records starting from the current (input) stream position.
@@ -202,14 +218,12 @@ where
     /// ```
     pub fn records(&mut self) -> impl Stream<Item = io::Result<Record>> + '_ {
         Box::pin(stream::try_unfold(
-            (&mut self.inner, Vec::new(), Record::default()),
-            |(mut reader, mut buf, mut record)| async {
-                read_record(&mut reader, &mut buf, &mut record)
-                    .await
-                    .map(|n| match n {
-                        0 => None,
-                        _ => Some((record.clone(), (reader, buf, record))),
-                    })
+            (self, Record::default()),
+            |(reader, mut record)| async {
+                reader.read_record(&mut record).await.map(|n| match n {
+                    0 => None,
+                    _ => Some((record.clone(), (reader, record))),
+                })
             },
         ))
     }
@@ -232,6 +246,10 @@ where
         Self::from(bgzf::AsyncReader::new(inner))
     }
 
+    pub(crate) fn set_bcf_2_1_compatibility(&mut self, bcf_2_1_compatibility: bool) {
+        self.bcf_2_1_compatibility = bcf_2_1_compatibility;
+    }
+
     /// Returns the current virtual position of the underlying BGZF reader.
     ///
     /// # Examples
@@ -337,6 +355,7 @@ impl<R> From<R> for Reader<R> {
         Self {
             inner,
             buf: Vec::new(),
+            bcf_2_1_compatibility: false,
         }
     }
 }
@@ -442,4 +461,32 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_read_record_with_bcf_2_1_file_format() -> io::Result<()> {
+        use crate::{record::Info, Writer};
+
+        let mut record = Record::default();
+        *record.info_mut() = Info::new(vec![0x11, 0x01, 0x21, 0x87, 0x81], 1);
+
+        let mut writer = Writer::from(Vec::new());
+        writer.write_file_format()?;
+        writer.write_header(&Default::default())?;
+        writer.write_record(&record)?;
+
+        let mut data = writer.into_inner();
+        data[4] = 0x01; // minor version = 1
+
+        let mut reader = Reader::from(&data[..]);
+        assert_eq!(reader.read_file_format().await?, (2, 1));
+        reader.read_header().await?;
+        reader.read_record(&mut record).await?;
+
+        assert_eq!(
+            record.info().as_ref(),
+            [0x11, 0x01, 0x22, 0x87, 0xff, 0x01, 0x80]
+        );
+
+        Ok(())
+    }
 }
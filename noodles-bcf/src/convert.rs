@@ -0,0 +1,273 @@
+//! VCF and BCF conversion.
+//!
+//! [`from_vcf`] converts a VCF stream to BCF, and [`to_vcf`] converts a BCF stream to VCF.
+//! [`to_vcf`] streams records, so the entire input is never held in memory. [`from_vcf`] buffers
+//! the raw input records until all keys used by them are known.
+
+use std::io::{self, BufRead, Read, Write};
+
+use noodles_vcf as vcf;
+
+use super::{header::StringMaps, Reader, Writer};
+
+/// Converts a VCF stream to BCF.
+///
+/// This reads a VCF header and records from the given reader and writes the BCF file format, a
+/// header, and records to the given writer.
+///
+/// BCF requires each contig, FILTER, INFO, and FORMAT key used in a record to be in the header
+/// dictionaries (string maps). All records are read before the header is written, and undeclared
+/// keys are added to the header:
+///
+///   * a contig is added without fields;
+///   * a FILTER is added with an empty description;
+///   * an INFO key is added as a `Flag` (`Number=0`) when it has no value, and otherwise using its
+///     reserved definition or `Number=1,Type=String`; and
+///   * a FORMAT key is added using its reserved definition or `Number=1,Type=String`.
+///
+/// The raw records are held in memory until the header is written.
+///
+/// The writer is not finished. Call [`Writer::try_finish`] or drop the writer when done.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io;
+/// use noodles_bcf as bcf;
+/// use noodles_vcf as vcf;
+///
+/// let data = b"##fileformat=VCFv4.3
+/// #CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+/// sq0\t1\t.\tA\t.\t.\tPASS\tDP=8
+/// ";
+///
+/// let mut reader = vcf::Reader::new(&data[..]);
+/// let mut writer = bcf::Writer::new(Vec::new());
+///
+/// bcf::convert::from_vcf(&mut reader, &mut writer)?;
+/// writer.try_finish()?;
+/// # Ok::<_, io::Error>(())
+/// ```
+pub fn from_vcf<R, W>(reader: &mut vcf::Reader<R>, writer: &mut Writer<W>) -> io::Result<()>
+where
+    R: BufRead,
+    W: Write,
+{
+    let mut header: vcf::Header = reader
+        .read_header()?
+        .parse()
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+    let mut lines = Vec::new();
+    let mut buf = String::new();
+
+    while reader.read_record(&mut buf)? != 0 {
+        let record = parse_vcf_record(&buf, &header)?;
+        add_undeclared_keys(&mut header, &record);
+        lines.push(buf.clone());
+        buf.clear();
+    }
+
+    let string_maps = StringMaps::from(&header);
+
+    writer.write_file_format()?;
+    writer.write_header(&header)?;
+
+    for line in &lines {
+        // Records are parsed again using the final header, which declares all of their keys.
+        let record = parse_vcf_record(line, &header)?;
+        writer.write_vcf_record(&header, &string_maps, &record)?;
+    }
+
+    Ok(())
+}
+
+/// Converts a BCF stream to VCF.
+///
+/// This reads the BCF file format, a header, and records from the given reader and writes a VCF
+/// header and records to the given writer. Header record positions (`IDX`) are removed from the
+/// output header, as they are only meaningful in BCF.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io;
+/// use noodles_bcf as bcf;
+/// use noodles_vcf as vcf;
+///
+/// let mut bcf_writer = bcf::Writer::new(Vec::new());
+/// bcf_writer.write_file_format()?;
+/// bcf_writer.write_header(&vcf::Header::default())?;
+/// let data = bcf_writer.into_inner().finish()?;
+///
+/// let mut reader = bcf::Reader::new(&data[..]);
+/// let mut writer = vcf::Writer::new(Vec::new());
+/// bcf::convert::to_vcf(&mut reader, &mut writer)?;
+///
+/// assert_eq!(
+///     writer.get_ref(),
+///     b"##fileformat=VCFv4.3\n#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\n"
+/// );
+/// # Ok::<_, io::Error>(())
+/// ```
+pub fn to_vcf<R, W>(reader: &mut Reader<R>, writer: &mut vcf::Writer<W>) -> io::Result<()>
+where
+    R: Read,
+    W: Write,
+{
+    use super::header;
+
+    reader.read_file_format()?;
+
+    let raw_header = reader.read_header()?;
+
+    let mut header: vcf::Header = raw_header
+        .parse()
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+    let string_maps: StringMaps = raw_header
+        .parse()
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+    header::strip_idx(&mut header);
+    writer.write_header(&header)?;
+
+    for result in reader.records() {
+        let record = result?;
+        let vcf_record = record.try_into_vcf_record(&header, &string_maps)?;
+        writer.write_record(&vcf_record)?;
+    }
+
+    Ok(())
+}
+
+fn parse_vcf_record(s: &str, header: &vcf::Header) -> io::Result<vcf::Record> {
+    vcf::Record::try_from_str(s, header).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+fn add_undeclared_keys(header: &mut vcf::Header, record: &vcf::Record) {
+    use vcf::{
+        header::{
+            record::value::{
+                map::{info::Type, Filter, Format, Info},
+                Map,
+            },
+            Number,
+        },
+        record::{info::field::Value, Chromosome},
+    };
+
+    if let Chromosome::Name(name) = record.chromosome() {
+        if let Ok(name) = name.parse() {
+            header.contigs_mut().entry(name).or_default();
+        }
+    }
+
+    if let Some(filters) = record.filters() {
+        for id in filters.iter().filter(|id| *id != "PASS") {
+            if !header.filters().contains_key(id) {
+                header
+                    .filters_mut()
+                    .insert(id.into(), Map::<Filter>::new(""));
+            }
+        }
+    }
+
+    for (key, value) in record.info().as_ref() {
+        if !header.infos().contains_key(key) {
+            let mut info = Map::<Info>::from(key);
+
+            if let Some(Value::Flag) = value {
+                *info.number_mut() = Number::Count(0);
+                *info.type_mut() = Type::Flag;
+            }
+
+            header.infos_mut().insert(key.clone(), info);
+        }
+    }
+
+    for key in record.genotypes().keys().iter() {
+        if !header.formats().contains_key(key) {
+            header
+                .formats_mut()
+                .insert(key.clone(), Map::<Format>::from(key));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_vcf_and_to_vcf() -> Result<(), Box<dyn std::error::Error>> {
+        const DATA: &[u8] = b"##fileformat=VCFv4.3
+##INFO=<ID=DP,Number=1,Type=Integer,Description=\"Combined depth across samples\">
+##contig=<ID=sq0>
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\tFORMAT\tsample0
+sq0\t1\t.\tA\t.\t.\tPASS\tDP=8\tGT\t0|0
+sq1\t8\tr0\tC\tG\t13.5\tq10\tDP=13;SOMATIC;NOTE=x\tGT:AB\t0/1:ab
+";
+
+        let mut reader = vcf::Reader::new(DATA);
+        let mut writer = Writer::from(Vec::new());
+        from_vcf(&mut reader, &mut writer)?;
+
+        let data = writer.into_inner();
+        let mut reader = Reader::from(&data[..]);
+        let mut writer = vcf::Writer::new(Vec::new());
+        to_vcf(&mut reader, &mut writer)?;
+
+        let expected = b"##fileformat=VCFv4.3
+##INFO=<ID=DP,Number=1,Type=Integer,Description=\"Combined depth across samples\">
+##INFO=<ID=SOMATIC,Number=0,Type=Flag,Description=\"Somatic mutation\">
+##INFO=<ID=NOTE,Number=1,Type=String,Description=\"\">
+##FILTER=<ID=q10,Description=\"\">
+##FORMAT=<ID=GT,Number=1,Type=String,Description=\"Genotype\">
+##FORMAT=<ID=AB,Number=1,Type=String,Description=\"\">
+##contig=<ID=sq0>
+##contig=<ID=sq1>
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\tFORMAT\tsample0
+sq0\t1\t.\tA\t.\t.\tPASS\tDP=8\tGT\t0|0
+sq1\t8\tr0\tC\tG\t13.5\tq10\tDP=13;SOMATIC;NOTE=x\tGT:AB\t0/1:ab
+";
+
+        assert_eq!(
+            std::str::from_utf8(writer.get_ref())?,
+            std::str::from_utf8(expected)?
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_vcf_with_an_undeclared_key_after_many_records(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let mut data =
+            String::from("##fileformat=VCFv4.3\n#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\n");
+
+        for _ in 0..8192 {
+            data.push_str("sq0\t1\t.\tA\t.\t.\t.\t.\n");
+        }
+
+        data.push_str("sq1\t1\t.\tA\t.\t.\tq10\tNOTE=x\n");
+
+        let mut reader = vcf::Reader::new(data.as_bytes());
+        let mut writer = Writer::from(Vec::new());
+        from_vcf(&mut reader, &mut writer)?;
+
+        let data = writer.into_inner();
+        let mut reader = Reader::from(&data[..]);
+        reader.read_file_format()?;
+        let header: vcf::Header = reader.read_header()?.parse()?;
+
+        assert!(header.contigs().contains_key("sq1"));
+        assert!(header.filters().contains_key("q10"));
+        assert!(header.infos().keys().any(|key| key.as_ref() == "NOTE"));
+
+        let records: Vec<_> = reader.records().collect::<io::Result<_>>()?;
+        assert_eq!(records.len(), 8193);
+
+        Ok(())
+    }
+}
@@ -1,5 +1,9 @@
 //! BCF header.
 
+mod idx;
 pub mod string_maps;
 
-pub use self::string_maps::StringMaps;
+pub use self::{
+    idx::{set_idx, strip_idx, validate_idx, IdxError},
+    string_maps::StringMaps,
+};
@@ -0,0 +1,276 @@
+use std::{error, fmt};
+
+use noodles_vcf as vcf;
+
+use super::{string_maps::StringMap, StringMaps};
+
+/// An error returned when VCF header positions (`IDX`) are inconsistent with string maps.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum IdxError {
+    /// A header record ID is not in the string maps.
+    MissingEntry(String),
+    /// A header record position does not match its position in the string maps.
+    PositionMismatch {
+        /// The header record ID.
+        id: String,
+        /// The position in the string maps.
+        expected: usize,
+        /// The position in the header record.
+        actual: usize,
+    },
+}
+
+impl error::Error for IdxError {}
+
+impl fmt::Display for IdxError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingEntry(id) => write!(f, "missing string map entry: {id}"),
+            Self::PositionMismatch {
+                id,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "position mismatch for {id}: expected IDX={expected}, got IDX={actual}"
+            ),
+        }
+    }
+}
+
+/// Sets the positions (`IDX`) of header records to their positions in the string maps.
+///
+/// This applies to INFO, FILTER, FORMAT, and contig records. Existing positions are overwritten.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_bcf::header::{self, StringMaps};
+/// use noodles_vcf::{self as vcf, header::info::key, header::record::value::{map, Map}};
+///
+/// let mut header = vcf::Header::builder()
+///     .add_info(key::TOTAL_DEPTH, Map::<map::Info>::from(&key::TOTAL_DEPTH))
+///     .build();
+///
+/// let string_maps = StringMaps::from(&header);
+/// header::set_idx(&mut header, &string_maps)?;
+///
+/// assert_eq!(header.infos()[&key::TOTAL_DEPTH].idx(), Some(1));
+/// # Ok::<_, header::IdxError>(())
+/// ```
+pub fn set_idx(header: &mut vcf::Header, string_maps: &StringMaps) -> Result<(), IdxError> {
+    fn set(string_map: &StringMap, id: &str, idx: &mut Option<usize>) -> Result<(), IdxError> {
+        let i = string_map
+            .get_index_of(id)
+            .ok_or_else(|| IdxError::MissingEntry(id.into()))?;
+
+        *idx = Some(i);
+
+        Ok(())
+    }
+
+    for (id, info) in header.infos_mut() {
+        set(string_maps.strings(), id.as_ref(), info.idx_mut())?;
+    }
+
+    for (id, filter) in header.filters_mut() {
+        set(string_maps.strings(), id, filter.idx_mut())?;
+    }
+
+    for (id, format) in header.formats_mut() {
+        set(string_maps.strings(), id.as_ref(), format.idx_mut())?;
+    }
+
+    for (id, contig) in header.contigs_mut() {
+        set(string_maps.contigs(), id.as_ref(), contig.idx_mut())?;
+    }
+
+    Ok(())
+}
+
+/// Removes the positions (`IDX`) of header records.
+///
+/// This applies to INFO, FILTER, FORMAT, and contig records. It is typically used when converting
+/// a header read from BCF to a VCF header, where the positions are not meaningful.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_bcf::header;
+/// use noodles_vcf::{self as vcf, header::record::value::{map::Contig, Map}};
+///
+/// let mut header = vcf::Header::builder()
+///     .add_contig("sq0".parse()?, Map::<Contig>::builder().set_idx(0).build()?)
+///     .build();
+///
+/// header::strip_idx(&mut header);
+///
+/// assert!(header.contigs()[0].idx().is_none());
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn strip_idx(header: &mut vcf::Header) {
+    for info in header.infos_mut().values_mut() {
+        *info.idx_mut() = None;
+    }
+
+    for filter in header.filters_mut().values_mut() {
+        *filter.idx_mut() = None;
+    }
+
+    for format in header.formats_mut().values_mut() {
+        *format.idx_mut() = None;
+    }
+
+    for contig in header.contigs_mut().values_mut() {
+        *contig.idx_mut() = None;
+    }
+}
+
+/// Validates the positions (`IDX`) of header records against the string maps.
+///
+/// Each INFO, FILTER, FORMAT, and contig record must be in the string maps, and if the record has
+/// a position, it must be the same as its position in the string maps.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_bcf::header::{self, IdxError, StringMaps};
+/// use noodles_vcf::{self as vcf, header::record::value::{map::Contig, Map}};
+///
+/// let header = vcf::Header::builder()
+///     .add_contig("sq0".parse()?, Map::<Contig>::new())
+///     .build();
+///
+/// let string_maps = StringMaps::from(&header);
+/// assert!(header::validate_idx(&header, &string_maps).is_ok());
+///
+/// let header = vcf::Header::builder()
+///     .add_contig("sq0".parse()?, Map::<Contig>::builder().set_idx(1).build()?)
+///     .build();
+///
+/// assert_eq!(
+///     header::validate_idx(&header, &string_maps),
+///     Err(IdxError::PositionMismatch { id: String::from("sq0"), expected: 0, actual: 1 })
+/// );
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn validate_idx(header: &vcf::Header, string_maps: &StringMaps) -> Result<(), IdxError> {
+    fn validate(string_map: &StringMap, id: &str, idx: Option<usize>) -> Result<(), IdxError> {
+        let expected = string_map
+            .get_index_of(id)
+            .ok_or_else(|| IdxError::MissingEntry(id.into()))?;
+
+        match idx {
+            Some(actual) if actual != expected => Err(IdxError::PositionMismatch {
+                id: id.into(),
+                expected,
+                actual,
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    for (id, info) in header.infos() {
+        validate(string_maps.strings(), id.as_ref(), info.idx())?;
+    }
+
+    for (id, filter) in header.filters() {
+        validate(string_maps.strings(), id, filter.idx())?;
+    }
+
+    for (id, format) in header.formats() {
+        validate(string_maps.strings(), id.as_ref(), format.idx())?;
+    }
+
+    for (id, contig) in header.contigs() {
+        validate(string_maps.contigs(), id.as_ref(), contig.idx())?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use vcf::header::record::value::{
+        map::{Contig, Filter, Info},
+        Map,
+    };
+
+    use super::*;
+
+    fn build_header() -> Result<vcf::Header, Box<dyn std::error::Error>> {
+        use vcf::header::info::key;
+
+        Ok(vcf::Header::builder()
+            .add_info(key::TOTAL_DEPTH, Map::<Info>::from(&key::TOTAL_DEPTH))
+            .add_filter("q10", Map::<Filter>::new("Quality below 10"))
+            .add_contig("sq0".parse()?, Map::<Contig>::new())
+            .add_contig("sq1".parse()?, Map::<Contig>::new())
+            .build())
+    }
+
+    #[test]
+    fn test_set_idx() -> Result<(), Box<dyn std::error::Error>> {
+        let mut header = build_header()?;
+        let string_maps = StringMaps::from(&header);
+
+        set_idx(&mut header, &string_maps)?;
+
+        assert_eq!(header.infos()[0].idx(), Some(1));
+        assert_eq!(header.filters()[0].idx(), Some(2));
+        assert_eq!(header.contigs()[0].idx(), Some(0));
+        assert_eq!(header.contigs()[1].idx(), Some(1));
+
+        assert!(validate_idx(&header, &string_maps).is_ok());
+
+        let actual: StringMaps = header.to_string().parse()?;
+        assert_eq!(actual, string_maps);
+
+        let string_maps = StringMaps::default();
+        assert_eq!(
+            set_idx(&mut header, &string_maps),
+            Err(IdxError::MissingEntry(String::from("DP")))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_strip_idx() -> Result<(), Box<dyn std::error::Error>> {
+        let mut header = build_header()?;
+        let string_maps = StringMaps::from(&header);
+        set_idx(&mut header, &string_maps)?;
+
+        strip_idx(&mut header);
+
+        assert_eq!(header, build_header()?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate_idx() -> Result<(), Box<dyn std::error::Error>> {
+        let mut header = build_header()?;
+        let string_maps = StringMaps::from(&header);
+
+        assert!(validate_idx(&header, &string_maps).is_ok());
+
+        *header.filters_mut()[0].idx_mut() = Some(8);
+
+        assert_eq!(
+            validate_idx(&header, &string_maps),
+            Err(IdxError::PositionMismatch {
+                id: String::from("q10"),
+                expected: 2,
+                actual: 8,
+            })
+        );
+
+        assert_eq!(
+            validate_idx(&header, &StringMaps::default()),
+            Err(IdxError::MissingEntry(String::from("DP")))
+        );
+
+        Ok(())
+    }
+}
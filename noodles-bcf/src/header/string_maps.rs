@@ -173,6 +173,10 @@ fn insert(string_map: &mut StringMap, id: &str, idx: Option<usize>) -> Result<()
             if actual != expected {
                 return Err(ParseError::StringMapPositionMismatch(actual, expected));
             }
+        } else if let Some(entry) = string_map.get_index(i) {
+            let actual = (i, id.into());
+            let expected = (i, entry.into());
+            return Err(ParseError::StringMapPositionMismatch(actual, expected));
         } else {
             string_map.insert_at(i, id.into());
         }
@@ -187,26 +191,63 @@ impl From<&vcf::Header> for StringMaps {
     fn from(header: &vcf::Header) -> Self {
         let mut string_maps = StringMaps::default();
 
-        for id in header.contigs().keys() {
-            string_maps.contigs_mut().insert(id.as_ref().into());
+        // Contig positions (`IDX`) are only honored if they form a valid dictionary. Otherwise,
+        // contigs are in header order.
+        match header.contig_dictionary() {
+            Ok(names) => {
+                for name in names {
+                    string_maps.contigs_mut().insert(name.as_ref().into());
+                }
+            }
+            Err(_) => {
+                for id in header.contigs().keys() {
+                    string_maps.contigs_mut().insert(id.as_ref().into());
+                }
+            }
         }
 
-        for id in header.infos().keys() {
-            string_maps.strings_mut().insert(id.as_ref().into());
-        }
+        // String positions (`IDX`) are only honored if they are consistent. Otherwise, strings are
+        // in header order.
+        match build_string_string_map(header) {
+            Ok(string_string_map) => *string_maps.strings_mut() = string_string_map,
+            Err(_) => {
+                for id in header.infos().keys() {
+                    string_maps.strings_mut().insert(id.as_ref().into());
+                }
 
-        for id in header.filters().keys() {
-            string_maps.strings_mut().insert(id.clone());
-        }
+                for id in header.filters().keys() {
+                    string_maps.strings_mut().insert(id.clone());
+                }
 
-        for id in header.formats().keys() {
-            string_maps.strings_mut().insert(id.as_ref().into());
+                for id in header.formats().keys() {
+                    string_maps.strings_mut().insert(id.as_ref().into());
+                }
+            }
         }
 
         string_maps
     }
 }
 
+fn build_string_string_map(header: &vcf::Header) -> Result<StringStringMap, ParseError> {
+    let mut string_maps = StringMaps::default();
+    let string_string_map = string_maps.strings_mut();
+
+    for (id, info) in header.infos() {
+        insert(string_string_map, id.as_ref(), info.idx())?;
+    }
+
+    for (id, filter) in header.filters() {
+        insert(string_string_map, id, filter.idx())?;
+    }
+
+    for (id, format) in header.formats() {
+        insert(string_string_map, id.as_ref(), format.idx())?;
+    }
+
+    Ok(string_maps.string_string_map)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -380,6 +421,20 @@ mod tests {
                 (1, String::from("DP"))
             ))
         );
+
+        let s = r#"##fileformat=VCFv4.3
+##contig=<ID=sq0,IDX=0>
+##contig=<ID=sq1,IDX=0>
+#CHROM	POS	ID	REF	ALT	QUAL	FILTER	INFO
+"#;
+
+        assert_eq!(
+            s.parse::<StringMaps>(),
+            Err(ParseError::StringMapPositionMismatch(
+                (0, String::from("sq1")),
+                (0, String::from("sq0"))
+            ))
+        );
     }
 
     #[test]
@@ -472,6 +527,69 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_vcf_header_for_string_map_with_contig_positions(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        use vcf::header::record::value::{map::Contig, Map};
+
+        let header = vcf::Header::builder()
+            .add_contig("sq0".parse()?, Map::<Contig>::builder().set_idx(1).build()?)
+            .add_contig("sq1".parse()?, Map::<Contig>::builder().set_idx(0).build()?)
+            .build();
+
+        let string_maps = StringMaps::from(&header);
+        let contig_string_map = string_maps.contigs();
+        assert_eq!(contig_string_map.get_index(0), Some("sq1"));
+        assert_eq!(contig_string_map.get_index(1), Some("sq0"));
+
+        let header = vcf::Header::builder()
+            .add_contig("sq0".parse()?, Map::<Contig>::builder().set_idx(1).build()?)
+            .add_contig("sq1".parse()?, Map::<Contig>::builder().set_idx(1).build()?)
+            .build();
+
+        let string_maps = StringMaps::from(&header);
+        let contig_string_map = string_maps.contigs();
+        assert_eq!(contig_string_map.get_index(0), Some("sq0"));
+        assert_eq!(contig_string_map.get_index(1), Some("sq1"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_vcf_header_for_string_map_with_string_positions(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        use vcf::header::{
+            info::key,
+            record::value::{
+                map::{Filter, Info},
+                Map,
+            },
+        };
+
+        let mut header = vcf::Header::builder()
+            .add_info(key::TOTAL_DEPTH, Map::<Info>::from(&key::TOTAL_DEPTH))
+            .add_filter("q10", Map::<Filter>::new("Quality below 10"))
+            .build();
+
+        *header.infos_mut()[0].idx_mut() = Some(2);
+        *header.filters_mut()[0].idx_mut() = Some(1);
+
+        let string_maps = StringMaps::from(&header);
+        let string_string_map = string_maps.strings();
+        assert_eq!(string_string_map.get_index(0), Some("PASS"));
+        assert_eq!(string_string_map.get_index(1), Some("q10"));
+        assert_eq!(string_string_map.get_index(2), Some("DP"));
+
+        *header.infos_mut()[0].idx_mut() = None;
+
+        let string_maps = StringMaps::from(&header);
+        let string_string_map = string_maps.strings();
+        assert_eq!(string_string_map.get_index(1), Some("DP"));
+        assert_eq!(string_string_map.get_index(2), Some("q10"));
+
+        Ok(())
+    }
+
     #[test]
     fn test_parse_file_format() {
         use vcf::header::FileFormat;
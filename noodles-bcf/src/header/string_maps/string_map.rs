@@ -38,6 +38,10 @@ impl StringMap {
         self.indices.get(value).copied()
     }
 
+    pub(crate) fn len(&self) -> usize {
+        self.entries.len()
+    }
+
     pub(super) fn get_full(&self, value: &str) -> Option<(usize, &str)> {
         self.get_index_of(value)
             .and_then(|i| self.get_index(i).map(|entry| (i, entry)))
@@ -0,0 +1,246 @@
+//! Indexed BCF writer.
+
+mod builder;
+
+pub use self::builder::Builder;
+
+use std::{
+    fs::File,
+    io::{self, Write},
+    mem,
+    path::PathBuf,
+};
+
+use noodles_bgzf as bgzf;
+use noodles_core::Position;
+use noodles_csi::{self as csi, index::reference_sequence::bin::Chunk};
+use noodles_vcf as vcf;
+
+use super::{header::StringMaps, Record, Writer};
+
+/// An indexed BCF writer.
+///
+/// This writes a BCF and simultaneously builds its coordinate-sorted index (CSI).
+///
+/// # Examples
+///
+/// ```
+/// use noodles_bcf::{self as bcf, header::StringMaps};
+/// use noodles_vcf::{self as vcf, record::Position};
+///
+/// let mut writer = bcf::IndexedWriter::new(Vec::new());
+///
+/// let header = vcf::Header::builder()
+///     .add_contig("sq0".parse()?, Default::default())
+///     .build();
+///
+/// writer.write_file_format()?;
+/// writer.write_header(&header)?;
+///
+/// let string_maps = StringMaps::from(&header);
+///
+/// let record = vcf::Record::builder()
+///     .set_chromosome("sq0".parse()?)
+///     .set_position(Position::from(8))
+///     .set_reference_bases("A".parse()?)
+///     .build()?;
+///
+/// writer.write_vcf_record(&header, &string_maps, &record)?;
+///
+/// let index = writer.finish()?;
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub struct IndexedWriter<W>
+where
+    W: Write,
+{
+    inner: Writer<bgzf::Writer<W>>,
+    indexer: csi::index::Indexer,
+    reference_sequence_count: usize,
+    index_dst: Option<PathBuf>,
+}
+
+impl<W> IndexedWriter<W>
+where
+    W: Write,
+{
+    /// Creates an indexed BCF writer.
+    ///
+    /// The output is compressed using BGZF.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf as bcf;
+    /// let writer = bcf::IndexedWriter::new(Vec::new());
+    /// ```
+    pub fn new(inner: W) -> Self {
+        Self::with_index_dst(inner, None)
+    }
+
+    pub(crate) fn with_index_dst(inner: W, index_dst: Option<PathBuf>) -> Self {
+        Self {
+            inner: Writer::new(inner),
+            indexer: csi::Index::indexer(),
+            reference_sequence_count: 0,
+            index_dst,
+        }
+    }
+
+    /// Returns a reference to the underlying BGZF writer.
+    pub fn get_ref(&self) -> &bgzf::Writer<W> {
+        self.inner.get_ref()
+    }
+
+    /// Writes a BCF file format.
+    pub fn write_file_format(&mut self) -> io::Result<()> {
+        self.inner.write_file_format()
+    }
+
+    /// Writes a VCF header.
+    ///
+    /// The index has a reference sequence for each contig in the header.
+    pub fn write_header(&mut self, header: &vcf::Header) -> io::Result<()> {
+        self.inner.write_header(header)?;
+        self.reference_sequence_count = StringMaps::from(header).contigs().len();
+        Ok(())
+    }
+
+    /// Writes a record and adds it to the index.
+    ///
+    /// Records must be written in coordinate-sorted order.
+    pub fn write_record(&mut self, record: &Record) -> io::Result<()> {
+        let start_position = self.inner.get_ref().virtual_position();
+        self.inner.write_record(record)?;
+        let end_position = self.inner.get_ref().virtual_position();
+
+        let (start, end) = resolve_interval(record.position(), record.end()?)?;
+        let chunk = Chunk::new(start_position, end_position);
+
+        self.indexer
+            .add_record(record.chromosome_id(), start, end, chunk)
+    }
+
+    /// Writes a VCF record and adds it to the index.
+    ///
+    /// Records must be written in coordinate-sorted order.
+    pub fn write_vcf_record(
+        &mut self,
+        header: &vcf::Header,
+        string_maps: &StringMaps,
+        record: &vcf::Record,
+    ) -> io::Result<()> {
+        let start_position = self.inner.get_ref().virtual_position();
+        self.inner.write_vcf_record(header, string_maps, record)?;
+        let end_position = self.inner.get_ref().virtual_position();
+
+        let name = record.chromosome().to_string();
+
+        let reference_sequence_id = string_maps.contigs().get_index_of(&name).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("chromosome not in string map: {name}"),
+            )
+        })?;
+
+        let end = record
+            .end()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+        let (start, end) = resolve_interval(record.position(), end)?;
+        let chunk = Chunk::new(start_position, end_position);
+
+        self.indexer
+            .add_record(reference_sequence_id, start, end, chunk)
+    }
+
+    /// Finishes the output stream and builds the index.
+    ///
+    /// This writes the final BGZF blocks, including the EOF block. If the writer was built from
+    /// a path, the index is also written to its destination (`<dst>.csi`, by default).
+    ///
+    /// No records should be written after the writer is finished.
+    pub fn finish(&mut self) -> io::Result<csi::Index> {
+        self.inner.try_finish()?;
+
+        let index = mem::take(&mut self.indexer).build(self.reference_sequence_count);
+
+        if let Some(dst) = self.index_dst.take() {
+            let mut writer = File::create(dst).map(csi::Writer::new)?;
+            writer.write_index(&index)?;
+        }
+
+        Ok(index)
+    }
+}
+
+fn resolve_interval(
+    start: vcf::record::Position,
+    end: vcf::record::Position,
+) -> io::Result<(Position, Position)> {
+    let start = Position::try_from(usize::from(start))
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+    let end = Position::try_from(usize::from(end))
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+    Ok((start, end))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+    use crate::Reader;
+
+    #[test]
+    fn test_finish() -> Result<(), Box<dyn std::error::Error>> {
+        use vcf::header::record::value::{map::Contig, Map};
+
+        let header = vcf::Header::builder()
+            .add_contig("sq0".parse()?, Map::<Contig>::new())
+            .add_contig("sq1".parse()?, Map::<Contig>::new())
+            .add_contig("sq2".parse()?, Map::<Contig>::new())
+            .build();
+
+        let string_maps = StringMaps::from(&header);
+
+        let mut writer = IndexedWriter::new(Vec::new());
+        writer.write_file_format()?;
+        writer.write_header(&header)?;
+
+        for (name, position) in [("sq0", 8), ("sq0", 13), ("sq1", 5)] {
+            let record = vcf::Record::builder()
+                .set_chromosome(name.parse()?)
+                .set_position(vcf::record::Position::from(position))
+                .set_reference_bases("ACGT".parse()?)
+                .build()?;
+
+            writer.write_vcf_record(&header, &string_maps, &record)?;
+        }
+
+        let index = writer.finish()?;
+        let data = writer.get_ref().get_ref().clone();
+
+        assert_eq!(csi::BinningIndex::reference_sequences(&index).len(), 3);
+
+        let mut reader = Reader::new(Cursor::new(data));
+        reader.read_file_format()?;
+        reader.read_header()?;
+
+        let region = "sq0:10-20".parse()?;
+        let positions: Vec<_> = reader
+            .query(string_maps.contigs(), &index, &region)?
+            .map(|result| result.map(|record| usize::from(record.position())))
+            .collect::<io::Result<_>>()?;
+
+        assert_eq!(positions, [8, 13]);
+
+        let region = "sq2".parse()?;
+        let mut query = reader.query(string_maps.contigs(), &index, &region)?;
+        assert!(query.next().is_none());
+
+        Ok(())
+    }
+}
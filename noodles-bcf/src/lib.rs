@@ -5,12 +5,14 @@
 #[cfg(feature = "async")]
 mod r#async;
 
+pub mod convert;
 pub mod header;
+pub mod indexed_writer;
 pub mod reader;
 pub mod record;
-mod writer;
+pub mod writer;
 
-pub use self::{reader::Reader, record::Record, writer::Writer};
+pub use self::{indexed_writer::IndexedWriter, reader::Reader, record::Record, writer::Writer};
 
 #[cfg(feature = "async")]
 pub use self::r#async::Reader as AsyncReader;
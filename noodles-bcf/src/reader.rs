@@ -1,12 +1,13 @@
 //! BCF reader and iterators.
 
+mod builder;
 pub(crate) mod query;
 pub(crate) mod record;
 mod records;
 pub(crate) mod string_map;
 pub(crate) mod value;
 
-pub use self::{query::Query, records::Records};
+pub use self::{builder::Builder, query::Query, records::Records};
 
 use std::{
     ffi::CStr,
@@ -27,6 +28,7 @@ use crate::header::string_maps::ContigStringMap;
 pub struct Reader<R> {
     inner: R,
     buf: Vec<u8>,
+    bcf_2_1_compatibility: bool,
 }
 
 impl<R> Reader<R>
@@ -81,7 +83,8 @@ where
     ///
     /// The position of the stream is expected to be at the start.
     ///
-    /// This returns the major and minor format versions as a tuple.
+    /// This returns the major and minor format versions as a tuple. If the format version is 2.1,
+    /// records are subsequently read as BCF 2.1 (see [`Builder::set_bcf_2_1_compatibility`]).
     ///
     /// # Examples
     ///
@@ -94,7 +97,14 @@ where
     /// ```
     pub fn read_file_format(&mut self) -> io::Result<(u8, u8)> {
         read_magic(&mut self.inner)?;
-        read_format_version(&mut self.inner)
+
+        let format_version = read_format_version(&mut self.inner)?;
+
+        if format_version == (2, 1) {
+            self.bcf_2_1_compatibility = true;
+        }
+
+        Ok(format_version)
     }
 
     /// Reads the raw VCF header.
@@ -143,7 +153,50 @@ where
     /// # Ok::<(), io::Error>(())
     /// ```
     pub fn read_record(&mut self, record: &mut Record) -> io::Result<usize> {
-        record::read_record(&mut self.inner, &mut self.buf, record)
+        let n = record::read_record(&mut self.inner, &mut self.buf, record)?;
+
+        if n > 0 && self.bcf_2_1_compatibility {
+            record::compat::upgrade_record(record)?;
+        }
+
+        Ok(n)
+    }
+
+    /// Reads a single record without its genotypes.
+    ///
+    /// Only the shared (site) fields are read into the record. The per-sample (genotypes) block is
+    /// skipped without being copied or decoded, and the record genotypes are cleared.
+    ///
+    /// The stream is expected to be directly after the header or at the start of another record.
+    ///
+    /// If successful, the record size is returned. If a record size of 0 is returned, the stream
+    /// reached EOF.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::{fs::File, io};
+    /// use noodles_bcf as bcf;
+    ///
+    /// let mut reader = File::open("sample.bcf").map(bcf::Reader::new)?;
+    /// reader.read_file_format()?;
+    /// reader.read_header()?;
+    ///
+    /// let mut record = bcf::Record::default();
+    ///
+    /// while reader.read_site_record(&mut record)? != 0 {
+    ///     println!("{:?}", record.position());
+    /// }
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn read_site_record(&mut self, record: &mut Record) -> io::Result<usize> {
+        let n = record::read_site_record(&mut self.inner, &mut self.buf, record)?;
+
+        if n > 0 && self.bcf_2_1_compatibility {
+            record::compat::upgrade_info(record)?;
+        }
+
+        Ok(n)
     }
 
     /// Returns an iterator over records starting from the current stream position.
@@ -285,6 +338,7 @@ impl<R> From<R> for Reader<R> {
         Self {
             inner,
             buf: Vec::new(),
+            bcf_2_1_compatibility: false,
         }
     }
 }
@@ -386,6 +440,34 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_read_record_with_bcf_2_1_file_format() -> io::Result<()> {
+        use crate::{record::Info, Writer};
+
+        let mut record = Record::default();
+        *record.info_mut() = Info::new(vec![0x11, 0x01, 0x21, 0x87, 0x81], 1);
+
+        let mut writer = Writer::from(Vec::new());
+        writer.write_file_format()?;
+        writer.write_header(&Default::default())?;
+        writer.write_record(&record)?;
+
+        let mut data = writer.into_inner();
+        data[4] = 0x01; // minor version = 1
+
+        let mut reader = Reader::from(&data[..]);
+        assert_eq!(reader.read_file_format()?, (2, 1));
+        reader.read_header()?;
+        reader.read_record(&mut record)?;
+
+        assert_eq!(
+            record.info().as_ref(),
+            [0x11, 0x01, 0x22, 0x87, 0xff, 0x01, 0x80]
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn test_read_header() -> io::Result<()> {
         const NUL: u8 = 0x00;
@@ -0,0 +1,192 @@
+use std::{
+    fs::File,
+    io::{self, BufRead, BufReader, Read},
+    path::Path,
+};
+
+use noodles_bgzf as bgzf;
+
+use super::Reader;
+
+/// A BCF reader builder.
+#[derive(Debug, Default)]
+pub struct Builder {
+    bcf_2_1_compatibility: bool,
+}
+
+impl Builder {
+    /// Sets whether to read records as BCF 2.1.
+    ///
+    /// BCF 2.1, as written by older versions of GATK and bcftools, only reserves the missing and
+    /// end of vector integer sentinels. Integer values that are reserved in BCF 2.2, e.g.,
+    /// `-121i8`, are valid values in BCF 2.1. When enabled, integer vectors that include such
+    /// values are widened to the next integer type when a record is read, and reserved
+    /// floating-point values are read as NaN.
+    ///
+    /// This is also enabled when [`Reader::read_file_format`] reads a BCF 2.1 file format.
+    ///
+    /// By default, this is disabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf as bcf;
+    /// let builder = bcf::reader::Builder::default().set_bcf_2_1_compatibility(true);
+    /// ```
+    pub fn set_bcf_2_1_compatibility(mut self, bcf_2_1_compatibility: bool) -> Self {
+        self.bcf_2_1_compatibility = bcf_2_1_compatibility;
+        self
+    }
+
+    /// Builds a BCF reader from a path.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use noodles_bcf as bcf;
+    /// let reader = bcf::reader::Builder::default().build_from_path("sample.bcf")?;
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn build_from_path<P>(self, src: P) -> io::Result<Reader<bgzf::Reader<File>>>
+    where
+        P: AsRef<Path>,
+    {
+        File::open(src).map(|file| self.build_from_reader(file))
+    }
+
+    /// Builds a BCF reader from a reader.
+    ///
+    /// The given stream is wrapped in a BGZF decoder.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf as bcf;
+    /// let data = [];
+    /// let reader = bcf::reader::Builder::default().build_from_reader(&data[..]);
+    /// ```
+    pub fn build_from_reader<R>(self, reader: R) -> Reader<bgzf::Reader<R>>
+    where
+        R: Read,
+    {
+        self.build_uncompressed_from_reader(bgzf::Reader::new(reader))
+    }
+
+    /// Builds an uncompressed BCF reader from a reader.
+    ///
+    /// The input is expected to be a raw BCF stream (`bcftools view -Ou`), i.e., it is not
+    /// BGZF-encoded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf as bcf;
+    /// let data = [];
+    /// let reader = bcf::reader::Builder::default().build_uncompressed_from_reader(&data[..]);
+    /// ```
+    pub fn build_uncompressed_from_reader<R>(self, reader: R) -> Reader<R>
+    where
+        R: Read,
+    {
+        let mut reader = Reader::from(reader);
+        reader.bcf_2_1_compatibility = self.bcf_2_1_compatibility;
+        reader
+    }
+
+    /// Builds a BCF reader from a reader that is either BGZF-encoded or raw.
+    ///
+    /// The encoding is detected from the start of the stream: a gzip magic number means the
+    /// stream is BGZF-encoded; otherwise, it is read as a raw BCF stream. This is typically used to
+    /// read from standard input, which may be either compressed (`bcftools view -Ob`) or
+    /// uncompressed (`bcftools view -Ou`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bcf as bcf;
+    ///
+    /// let data = b"BCF\x02\x02";
+    /// let mut reader = bcf::reader::Builder::default().build_from_reader_with_detection(&data[..])?;
+    /// assert_eq!(reader.read_file_format()?, (2, 2));
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn build_from_reader_with_detection<'r, R>(
+        self,
+        reader: R,
+    ) -> io::Result<Reader<Box<dyn Read + 'r>>>
+    where
+        R: Read + 'r,
+    {
+        const GZIP_MAGIC_NUMBER: [u8; 2] = [0x1f, 0x8b];
+
+        let mut reader = BufReader::new(reader);
+
+        let inner: Box<dyn Read + 'r> = if is_prefixed_by(&mut reader, &GZIP_MAGIC_NUMBER)? {
+            Box::new(bgzf::Reader::new(reader))
+        } else {
+            Box::new(reader)
+        };
+
+        Ok(self.build_uncompressed_from_reader(inner))
+    }
+
+    /// Builds an async BCF reader from an async reader.
+    ///
+    /// The given stream is wrapped in an async BGZF decoder.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf as bcf;
+    /// let data = [];
+    /// let reader = bcf::reader::Builder::default().build_from_async_reader(&data[..]);
+    /// ```
+    #[cfg(feature = "async")]
+    pub fn build_from_async_reader<R>(self, reader: R) -> crate::AsyncReader<bgzf::AsyncReader<R>>
+    where
+        R: tokio::io::AsyncRead + Unpin,
+    {
+        let mut reader = crate::AsyncReader::new(reader);
+        reader.set_bcf_2_1_compatibility(self.bcf_2_1_compatibility);
+        reader
+    }
+}
+
+fn is_prefixed_by<R>(reader: &mut R, prefix: &[u8]) -> io::Result<bool>
+where
+    R: BufRead,
+{
+    let src = reader.fill_buf()?;
+    Ok(src.starts_with(prefix))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Writer;
+
+    #[test]
+    fn test_build_from_reader_with_detection() -> io::Result<()> {
+        use crate::writer;
+
+        fn t(data: &[u8]) -> io::Result<()> {
+            let mut reader = Builder::default().build_from_reader_with_detection(data)?;
+            assert_eq!(reader.read_file_format()?, (2, 2));
+            reader.read_header()?;
+            Ok(())
+        }
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_file_format()?;
+        writer.write_header(&Default::default())?;
+        t(&writer.into_inner().finish()?)?;
+
+        let mut writer = writer::Builder::default().build_uncompressed_with_writer(Vec::new());
+        writer.write_file_format()?;
+        writer.write_header(&Default::default())?;
+        t(&writer.into_inner())?;
+
+        Ok(())
+    }
+}
@@ -1,3 +1,4 @@
+pub(crate) mod compat;
 mod genotypes;
 pub mod info;
 
@@ -18,16 +19,11 @@ pub fn read_record<R>(reader: &mut R, buf: &mut Vec<u8>, record: &mut Record) ->
 where
     R: Read,
 {
-    let l_shared = match reader.read_u32::<LittleEndian>() {
-        Ok(n) => usize::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?,
-        Err(ref e) if e.kind() == io::ErrorKind::UnexpectedEof => return Ok(0),
-        Err(e) => return Err(e),
+    let (l_shared, l_indiv) = match read_block_sizes(reader)? {
+        Some(sizes) => sizes,
+        None => return Ok(0),
     };
 
-    let l_indiv = reader.read_u32::<LittleEndian>().and_then(|n| {
-        usize::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
-    })?;
-
     buf.resize(l_shared, Default::default());
     reader.read_exact(buf)?;
     let mut buf_reader = &buf[..];
@@ -42,6 +38,55 @@ where
     Ok(l_shared + l_indiv)
 }
 
+pub fn read_site_record<R>(
+    reader: &mut R,
+    buf: &mut Vec<u8>,
+    record: &mut Record,
+) -> io::Result<usize>
+where
+    R: Read,
+{
+    let (l_shared, l_indiv) = match read_block_sizes(reader)? {
+        Some(sizes) => sizes,
+        None => return Ok(0),
+    };
+
+    buf.resize(l_shared, Default::default());
+    reader.read_exact(buf)?;
+    let mut buf_reader = &buf[..];
+    read_site(&mut buf_reader, record)?;
+
+    record.genotypes_mut().as_mut().clear();
+    record.genotypes_mut().set_format_count(0);
+    record.genotypes_mut().set_sample_count(0);
+
+    let len = u64::try_from(l_indiv).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    let n = io::copy(&mut reader.take(len), &mut io::sink())?;
+
+    if n < len {
+        return Err(io::Error::from(io::ErrorKind::UnexpectedEof));
+    }
+
+    Ok(l_shared + l_indiv)
+}
+
+fn read_block_sizes<R>(reader: &mut R) -> io::Result<Option<(usize, usize)>>
+where
+    R: Read,
+{
+    let l_shared = match reader.read_u32::<LittleEndian>() {
+        Ok(n) => usize::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?,
+        Err(ref e) if e.kind() == io::ErrorKind::UnexpectedEof => return Ok(None),
+        Err(e) => return Err(e),
+    };
+
+    let l_indiv = reader.read_u32::<LittleEndian>().and_then(|n| {
+        usize::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    })?;
+
+    Ok(Some((l_shared, l_indiv)))
+}
+
 pub(crate) fn read_site<R>(reader: &mut R, record: &mut Record) -> io::Result<(usize, usize)>
 where
     R: Read,
@@ -437,4 +482,34 @@ pub(crate) mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_read_site_record() -> Result<(), Box<dyn std::error::Error>> {
+        let mut data = DATA.to_vec();
+        data.extend_from_slice(&DATA);
+
+        let mut reader = &data[..];
+        let mut buf = Vec::new();
+        let mut record = Record::default();
+
+        read_record(&mut reader, &mut buf, &mut record)?;
+        assert!(!record.genotypes().is_empty());
+
+        let mut expected = record.clone();
+        *expected.genotypes_mut() = Default::default();
+
+        assert_eq!(read_site_record(&mut reader, &mut buf, &mut record)?, 93);
+        assert_eq!(record, expected);
+        assert!(record.genotypes().is_empty());
+
+        assert_eq!(read_site_record(&mut reader, &mut buf, &mut record)?, 0);
+
+        let mut reader = &DATA[..DATA.len() - 1];
+        assert!(matches!(
+            read_site_record(&mut reader, &mut buf, &mut record),
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof
+        ));
+
+        Ok(())
+    }
 }
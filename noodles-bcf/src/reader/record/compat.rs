@@ -0,0 +1,261 @@
+//! BCF 2.1 compatibility.
+//!
+//! BCF 2.2 reserves the 8 smallest values of each integer type and the signaling NaNs
+//! `0x7f800003..=0x7f800007` (§ 6.3.3 "Type encoding"). BCF 2.1 only reserves the missing
+//! (`0x80`) and end of vector (`0x81`) sentinels, so values such as `-121i8` are valid in BCF 2.1
+//! but are reserved in BCF 2.2.
+//!
+//! These functions rewrite the encoded INFO and genotype fields of a BCF 2.1 record so that they
+//! are valid BCF 2.2: integer vectors that use a BCF 2.2 reserved value are widened to the next
+//! integer type, and floating-point reserved values are replaced with a quiet NaN.
+
+use std::io::{self, Read};
+
+use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
+
+use crate::{
+    reader::value::{read_type, read_value},
+    record::value::{Float, Int16, Int32, Int8, Type},
+    writer::value::write_type,
+    Record,
+};
+
+const QUIET_NAN: u32 = 0x7fc00000;
+
+/// Rewrites the INFO fields and genotypes of a BCF 2.1 record as BCF 2.2.
+pub fn upgrade_record(record: &mut Record) -> io::Result<()> {
+    upgrade_info(record)?;
+    upgrade_genotypes(record)
+}
+
+/// Rewrites the INFO fields of a BCF 2.1 record as BCF 2.2.
+pub fn upgrade_info(record: &mut Record) -> io::Result<()> {
+    let field_count = record.info().len();
+    let buf = record.info_mut().as_mut();
+
+    let mut src = &buf[..];
+    let mut dst = Vec::with_capacity(buf.len());
+
+    for _ in 0..field_count {
+        copy_value(&mut src, &mut dst)?;
+        upgrade_values(&mut src, &mut dst, 1)?;
+    }
+
+    *buf = dst;
+
+    Ok(())
+}
+
+/// Rewrites the genotypes of a BCF 2.1 record as BCF 2.2.
+pub fn upgrade_genotypes(record: &mut Record) -> io::Result<()> {
+    let format_count = record.genotypes().format_count();
+    let sample_count = record.genotypes().len();
+    let buf = record.genotypes_mut().as_mut();
+
+    let mut src = &buf[..];
+    let mut dst = Vec::with_capacity(buf.len());
+
+    for _ in 0..format_count {
+        copy_value(&mut src, &mut dst)?;
+        upgrade_values(&mut src, &mut dst, sample_count)?;
+    }
+
+    *buf = dst;
+
+    Ok(())
+}
+
+// Copies a typed value, e.g., a string map index, as is.
+fn copy_value(src: &mut &[u8], dst: &mut Vec<u8>) -> io::Result<()> {
+    let start = *src;
+    read_value(src)?;
+    let len = start.len() - src.len();
+    dst.extend_from_slice(&start[..len]);
+    Ok(())
+}
+
+// Reads a type descriptor and `n` vectors of values and writes them as BCF 2.2.
+fn upgrade_values(src: &mut &[u8], dst: &mut Vec<u8>, n: usize) -> io::Result<()> {
+    let ty = read_type(src)?;
+
+    match ty {
+        Some(Type::Int8(len)) => {
+            let mut values = vec![0; len * n];
+            src.read_i8_into(&mut values)?;
+
+            if values.iter().any(|&v| is_reserved_int8(v)) {
+                write_type(dst, Some(Type::Int16(len)))?;
+
+                for value in values {
+                    let value = match Int8::from(value) {
+                        Int8::Missing => i16::from(Int16::Missing),
+                        Int8::EndOfVector => i16::from(Int16::EndOfVector),
+                        Int8::Value(n) | Int8::Reserved(n) => i16::from(n),
+                    };
+
+                    dst.write_i16::<LittleEndian>(value)?;
+                }
+            } else {
+                write_type(dst, ty)?;
+                dst.extend(values.into_iter().map(|v| v as u8));
+            }
+        }
+        Some(Type::Int16(len)) => {
+            let mut values = vec![0; len * n];
+            src.read_i16_into::<LittleEndian>(&mut values)?;
+
+            if values.iter().any(|&v| is_reserved_int16(v)) {
+                write_type(dst, Some(Type::Int32(len)))?;
+
+                for value in values {
+                    let value = match Int16::from(value) {
+                        Int16::Missing => i32::from(Int32::Missing),
+                        Int16::EndOfVector => i32::from(Int32::EndOfVector),
+                        Int16::Value(n) | Int16::Reserved(n) => i32::from(n),
+                    };
+
+                    dst.write_i32::<LittleEndian>(value)?;
+                }
+            } else {
+                write_type(dst, ty)?;
+
+                for value in values {
+                    dst.write_i16::<LittleEndian>(value)?;
+                }
+            }
+        }
+        Some(Type::Int32(len)) => {
+            let mut values = vec![0; len * n];
+            src.read_i32_into::<LittleEndian>(&mut values)?;
+
+            if let Some(value) = values.iter().find(|&&v| is_reserved_int32(v)) {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("BCF 2.1 value cannot be represented in BCF 2.2: {value}"),
+                ));
+            }
+
+            write_type(dst, ty)?;
+
+            for value in values {
+                dst.write_i32::<LittleEndian>(value)?;
+            }
+        }
+        Some(Type::Float(len)) => {
+            let mut values = vec![0.0; len * n];
+            src.read_f32_into::<LittleEndian>(&mut values)?;
+
+            write_type(dst, ty)?;
+
+            for value in values {
+                let value = match Float::from(value) {
+                    Float::Reserved(_) => f32::from_bits(QUIET_NAN),
+                    _ => value,
+                };
+
+                dst.write_f32::<LittleEndian>(value)?;
+            }
+        }
+        Some(Type::String(len)) => {
+            let mut buf = vec![0; len * n];
+            src.read_exact(&mut buf)?;
+            write_type(dst, ty)?;
+            dst.extend(buf);
+        }
+        None => write_type(dst, ty)?,
+    }
+
+    Ok(())
+}
+
+fn is_reserved_int8(n: i8) -> bool {
+    matches!(Int8::from(n), Int8::Reserved(_))
+}
+
+fn is_reserved_int16(n: i16) -> bool {
+    matches!(Int16::from(n), Int16::Reserved(_))
+}
+
+fn is_reserved_int32(n: i32) -> bool {
+    matches!(Int32::from(n), Int32::Reserved(_))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_upgrade_info() -> io::Result<()> {
+        let mut record = Record::default();
+
+        let data = vec![
+            0x11, 0x01, // key = 1
+            0x31, 0x05, 0x87, 0x80, // [5, -121, .]
+            0x11, 0x02, // key = 2
+            0x21, 0x08, 0x81, // [8]
+            0x11, 0x03, // key = 3
+            0x15, 0x03, 0x00, 0x80, 0x7f, // 0x7f800003
+        ];
+
+        *record.info_mut() = crate::record::Info::new(data, 3);
+
+        upgrade_info(&mut record)?;
+
+        let expected = [
+            0x11, 0x01, // key = 1
+            0x32, 0x05, 0x00, 0x87, 0xff, 0x00, 0x80, // [5, -121, .]
+            0x11, 0x02, // key = 2
+            0x21, 0x08, 0x81, // [8]
+            0x11, 0x03, // key = 3
+            0x15, 0x00, 0x00, 0xc0, 0x7f, // NaN
+        ];
+
+        assert_eq!(record.info().as_ref(), expected);
+        assert_eq!(record.info().len(), 3);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_upgrade_genotypes() -> io::Result<()> {
+        let mut record = Record::default();
+
+        let genotypes = record.genotypes_mut();
+        genotypes.as_mut().extend([
+            0x11, 0x01, // key = 1
+            0x22, // Int16(2)
+            0x02, 0x80, 0x01, 0x80, // [-32766, .]
+            0x05, 0x00, 0x01, 0x80, // [5, .]
+        ]);
+        genotypes.set_format_count(1);
+        genotypes.set_sample_count(2);
+
+        upgrade_genotypes(&mut record)?;
+
+        let expected = [
+            0x11, 0x01, // key = 1
+            0x23, // Int32(2)
+            0x02, 0x80, 0xff, 0xff, 0x01, 0x00, 0x00, 0x80, // [-32766, .]
+            0x05, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x80, // [5, .]
+        ];
+
+        assert_eq!(record.genotypes().as_ref(), expected);
+
+        let genotypes = record.genotypes_mut();
+        genotypes.as_mut().clear();
+        genotypes.as_mut().extend([
+            0x11, 0x01, // key = 1
+            0x13, // Int32(1)
+            0x02, 0x00, 0x00, 0x80, // -2147483646
+        ]);
+        genotypes.set_format_count(1);
+        genotypes.set_sample_count(1);
+
+        assert!(matches!(
+            upgrade_genotypes(&mut record),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+}
@@ -153,8 +153,8 @@ where
 
         match value {
             Int8::Value(n) => values.push(Some(Value::Integer(i32::from(n)))),
-            Int8::Missing => values.push(None),
-            _ => todo!("unhandled i8 value: {:?}", value),
+            Int8::Missing | Int8::EndOfVector => values.push(None),
+            Int8::Reserved(_) => return Err(invalid_value_error(value)),
         }
     }
 
@@ -178,15 +178,15 @@ where
         let vs: Vec<_> = buf
             .into_iter()
             .map(Int8::from)
-            .filter_map(|value| match value {
-                Int8::Value(n) => Some(Some(i32::from(n))),
-                Int8::Missing => Some(None),
-                Int8::EndOfVector => None,
-                _ => todo!("unhandled i8 array value: {:?}", value),
+            .take_while(|value| !matches!(value, Int8::EndOfVector))
+            .map(|value| match value {
+                Int8::Value(n) => Ok(Some(i32::from(n))),
+                Int8::Missing => Ok(None),
+                _ => Err(invalid_value_error(value)),
             })
-            .collect();
+            .collect::<io::Result<_>>()?;
 
-        if vs.len() == 1 && vs[0].is_none() {
+        if vs.is_empty() || (vs.len() == 1 && vs[0].is_none()) {
             values.push(None);
         } else {
             values.push(Some(Value::IntegerArray(vs)));
@@ -210,8 +210,8 @@ where
 
         match value {
             Int16::Value(n) => values.push(Some(Value::Integer(i32::from(n)))),
-            Int16::Missing => values.push(None),
-            _ => todo!("unhandled i16 value: {:?}", value),
+            Int16::Missing | Int16::EndOfVector => values.push(None),
+            Int16::Reserved(_) => return Err(invalid_value_error(value)),
         }
     }
 
@@ -235,15 +235,15 @@ where
         let vs: Vec<_> = buf
             .into_iter()
             .map(Int16::from)
-            .filter_map(|value| match value {
-                Int16::Value(n) => Some(Some(i32::from(n))),
-                Int16::Missing => Some(None),
-                Int16::EndOfVector => None,
-                _ => todo!("unhandled i16 array value: {:?}", value),
+            .take_while(|value| !matches!(value, Int16::EndOfVector))
+            .map(|value| match value {
+                Int16::Value(n) => Ok(Some(i32::from(n))),
+                Int16::Missing => Ok(None),
+                _ => Err(invalid_value_error(value)),
             })
-            .collect();
+            .collect::<io::Result<_>>()?;
 
-        if vs.len() == 1 && vs[0].is_none() {
+        if vs.is_empty() || (vs.len() == 1 && vs[0].is_none()) {
             values.push(None);
         } else {
             values.push(Some(Value::IntegerArray(vs)));
@@ -267,8 +267,8 @@ where
 
         match value {
             Int32::Value(n) => values.push(Some(Value::Integer(n))),
-            Int32::Missing => values.push(None),
-            _ => todo!("unhandled i32 value: {:?}", value),
+            Int32::Missing | Int32::EndOfVector => values.push(None),
+            Int32::Reserved(_) => return Err(invalid_value_error(value)),
         }
     }
 
@@ -292,15 +292,15 @@ where
         let vs: Vec<_> = buf
             .into_iter()
             .map(Int32::from)
-            .filter_map(|value| match value {
-                Int32::Value(n) => Some(Some(n)),
-                Int32::Missing => Some(None),
-                Int32::EndOfVector => None,
-                _ => todo!("unhandled i32 array value: {:?}", value),
+            .take_while(|value| !matches!(value, Int32::EndOfVector))
+            .map(|value| match value {
+                Int32::Value(n) => Ok(Some(n)),
+                Int32::Missing => Ok(None),
+                _ => Err(invalid_value_error(value)),
             })
-            .collect();
+            .collect::<io::Result<_>>()?;
 
-        if vs.len() == 1 && vs[0].is_none() {
+        if vs.is_empty() || (vs.len() == 1 && vs[0].is_none()) {
             values.push(None);
         } else {
             values.push(Some(Value::IntegerArray(vs)));
@@ -324,8 +324,8 @@ where
 
         match value {
             Float::Value(n) => values.push(Some(Value::Float(n))),
-            Float::Missing => values.push(None),
-            _ => todo!("unhandled f32 value: {:?}", value),
+            Float::Missing | Float::EndOfVector => values.push(None),
+            Float::Reserved(_) => return Err(invalid_value_error(value)),
         }
     }
 
@@ -349,15 +349,15 @@ where
         let vs: Vec<_> = buf
             .into_iter()
             .map(Float::from)
-            .filter_map(|value| match value {
-                Float::Value(n) => Some(Some(n)),
-                Float::Missing => Some(None),
-                Float::EndOfVector => None,
-                _ => todo!("unhandled f32 array value: {:?}", value),
+            .take_while(|value| !matches!(value, Float::EndOfVector))
+            .map(|value| match value {
+                Float::Value(n) => Ok(Some(n)),
+                Float::Missing => Ok(None),
+                _ => Err(invalid_value_error(value)),
             })
-            .collect();
+            .collect::<io::Result<_>>()?;
 
-        if vs.len() == 1 && vs[0].is_none() {
+        if vs.is_empty() || (vs.len() == 1 && vs[0].is_none()) {
             values.push(None);
         } else {
             values.push(Some(Value::FloatArray(vs)));
@@ -386,6 +386,11 @@ where
             None => &buf[..],
         };
 
+        if data.is_empty() {
+            values.push(None);
+            continue;
+        }
+
         let s = str::from_utf8(data).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
         let value = Value::String(s.into());
 
@@ -405,44 +410,77 @@ where
     let mut values = Vec::with_capacity(sample_count);
 
     match read_type(reader)? {
-        Some(Type::Int8(len)) => match len {
-            0 => values.push(None),
-            1 => {
-                for _ in 0..sample_count {
-                    let value = reader
-                        .read_i8()
-                        .map(|v| parse_genotype_genotype_field_values(&[v]))
-                        .map(Value::String)?;
-
-                    values.push(Some(value));
-                }
+        Some(Type::Int8(0) | Type::Int16(0) | Type::Int32(0)) => {
+            values.resize(sample_count, None);
+        }
+        Some(Type::Int8(len)) => {
+            let mut buf = vec![0; len];
+
+            for _ in 0..sample_count {
+                reader.read_i8_into(&mut buf)?;
+                let value = Value::String(parse_genotype_genotype_field_values(&buf));
+                values.push(Some(value));
             }
-            _ => {
-                let mut buf = vec![0; len];
-
-                for _ in 0..sample_count {
-                    reader.read_i8_into(&mut buf)?;
-                    let value = Value::String(parse_genotype_genotype_field_values(&buf));
-                    values.push(Some(value));
-                }
+        }
+        Some(Type::Int16(len)) => {
+            let mut buf = vec![0; len];
+
+            for _ in 0..sample_count {
+                reader.read_i16_into::<LittleEndian>(&mut buf)?;
+
+                let genotype = format_genotype(
+                    buf.iter()
+                        .take_while(|&&n| !matches!(Int16::from(n), Int16::EndOfVector))
+                        .map(|&n| i32::from(n)),
+                );
+
+                values.push(Some(Value::String(genotype)));
             }
-        },
-        ty => todo!("unhandled type: {:?}", ty),
+        }
+        Some(Type::Int32(len)) => {
+            let mut buf = vec![0; len];
+
+            for _ in 0..sample_count {
+                reader.read_i32_into::<LittleEndian>(&mut buf)?;
+
+                let genotype = format_genotype(
+                    buf.iter()
+                        .copied()
+                        .take_while(|&n| !matches!(Int32::from(n), Int32::EndOfVector)),
+                );
+
+                values.push(Some(Value::String(genotype)));
+            }
+        }
+        ty => {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("invalid genotype (GT) type: {ty:?}"),
+            ))
+        }
     }
 
     Ok(values)
 }
 
 fn parse_genotype_genotype_field_values(values: &[i8]) -> String {
+    format_genotype(
+        values
+            .iter()
+            .take_while(|&&n| !matches!(Int8::from(n), Int8::EndOfVector))
+            .map(|&n| i32::from(n)),
+    )
+}
+
+fn format_genotype<I>(values: I) -> String
+where
+    I: Iterator<Item = i32>,
+{
     use std::fmt::Write;
 
     let mut genotype = String::new();
 
-    for (i, &value) in values.iter().enumerate() {
-        if let Int8::EndOfVector = Int8::from(value) {
-            break;
-        }
-
+    for (i, value) in values.enumerate() {
         let j = (value >> 1) - 1;
         let is_phased = value & 0x01 == 1;
 
@@ -464,6 +502,16 @@ fn parse_genotype_genotype_field_values(values: &[i8]) -> String {
     genotype
 }
 
+fn invalid_value_error<T>(value: T) -> io::Error
+where
+    T: std::fmt::Debug,
+{
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("invalid value: {value:?}"),
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -658,6 +706,103 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_read_genotype_field_values_with_missing_and_reserved_values() -> io::Result<()> {
+        let data = [
+            0x11, // Some(Type::Int8(1))
+            0x05, // Some(5)
+            0x81, // None (end of vector)
+        ];
+        let mut reader = &data[..];
+        let actual = read_genotype_field_values(&mut reader, 2)?;
+        assert_eq!(actual, vec![Some(Value::Integer(5)), None]);
+
+        let data = [
+            0x21, // Some(Type::Int8(2))
+            0x81, 0x81, // None
+        ];
+        let mut reader = &data[..];
+        let actual = read_genotype_field_values(&mut reader, 1)?;
+        assert_eq!(actual, vec![None]);
+
+        let data = [
+            0x27, // Some(Type::String(2))
+            b'n', 0x00, // "n"
+            0x00, 0x00, // None
+        ];
+        let mut reader = &data[..];
+        let actual = read_genotype_field_values(&mut reader, 2)?;
+        assert_eq!(actual, vec![Some(Value::String(String::from("n"))), None]);
+
+        let data = [0x11, 0x82];
+        let mut reader = &data[..];
+        assert!(matches!(
+            read_genotype_field_values(&mut reader, 1),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        let data = [0x21, 0x05, 0x82];
+        let mut reader = &data[..];
+        assert!(matches!(
+            read_genotype_field_values(&mut reader, 1),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_genotype_genotype_field_values() -> io::Result<()> {
+        let data = [
+            0x21, // Some(Type::Int8(2))
+            0x02, 0x04, // 0/1
+            0x04, 0x81, // 1
+        ];
+        let mut reader = &data[..];
+        let actual = read_genotype_genotype_field_values(&mut reader, 2)?;
+        let expected = vec![
+            Some(Value::String(String::from("0/1"))),
+            Some(Value::String(String::from("1"))),
+        ];
+        assert_eq!(actual, expected);
+
+        let data = [
+            0x22, // Some(Type::Int16(2))
+            0x02, 0x00, 0xcb, 0x00, // 0|100
+            0x04, 0x00, 0x01, 0x80, // 1
+        ];
+        let mut reader = &data[..];
+        let actual = read_genotype_genotype_field_values(&mut reader, 2)?;
+        let expected = vec![
+            Some(Value::String(String::from("0|100"))),
+            Some(Value::String(String::from("1"))),
+        ];
+        assert_eq!(actual, expected);
+
+        let data = [
+            0x13, // Some(Type::Int32(1))
+            0x0a, 0x00, 0x01, 0x00, // 32772
+        ];
+        let mut reader = &data[..];
+        let actual = read_genotype_genotype_field_values(&mut reader, 1)?;
+        let expected = vec![Some(Value::String(String::from("32772")))];
+        assert_eq!(actual, expected);
+
+        let data = [0x01]; // Some(Type::Int8(0))
+        let mut reader = &data[..];
+        let actual = read_genotype_genotype_field_values(&mut reader, 2)?;
+        assert_eq!(actual, vec![None, None]);
+
+        let data = [0x15, 0x00, 0x00, 0x00, 0x00]; // Some(Type::Float(1))
+        let mut reader = &data[..];
+        assert!(matches!(
+            read_genotype_genotype_field_values(&mut reader, 1),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
     #[test]
     fn test_parse_genotype_genotype_field_values() {
         // Examples from § 6.3.3 Type encoding (2021-05-13)
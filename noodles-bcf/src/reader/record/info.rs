@@ -132,12 +132,13 @@ where
             values
                 .into_iter()
                 .map(Int8::from)
+                .take_while(|value| !matches!(value, Int8::EndOfVector))
                 .map(|value| match value {
-                    Int8::Value(n) => Some(i32::from(n)),
-                    Int8::Missing => None,
-                    _ => todo!("unhandled i8 array value: {:?}", value),
+                    Int8::Value(n) => Ok(Some(i32::from(n))),
+                    Int8::Missing => Ok(None),
+                    _ => Err(invalid_array_value_error(value)),
                 })
-                .collect(),
+                .collect::<io::Result<_>>()?,
         ))),
         Some(Value::Int16(Some(Int16::Value(n)))) => {
             Ok(Some(vcf::record::info::field::Value::Integer(i32::from(n))))
@@ -146,12 +147,13 @@ where
             values
                 .into_iter()
                 .map(Int16::from)
+                .take_while(|value| !matches!(value, Int16::EndOfVector))
                 .map(|value| match value {
-                    Int16::Value(n) => Some(i32::from(n)),
-                    Int16::Missing => None,
-                    _ => todo!("unhandled i16 array value: {:?}", value),
+                    Int16::Value(n) => Ok(Some(i32::from(n))),
+                    Int16::Missing => Ok(None),
+                    _ => Err(invalid_array_value_error(value)),
                 })
-                .collect(),
+                .collect::<io::Result<_>>()?,
         ))),
         Some(Value::Int32(Some(Int32::Value(n)))) => {
             Ok(Some(vcf::record::info::field::Value::Integer(n)))
@@ -160,12 +162,13 @@ where
             values
                 .into_iter()
                 .map(Int32::from)
+                .take_while(|value| !matches!(value, Int32::EndOfVector))
                 .map(|value| match value {
-                    Int32::Value(n) => Some(n),
-                    Int32::Missing => None,
-                    _ => todo!("unhandled i32 array value: {:?}", value),
+                    Int32::Value(n) => Ok(Some(n)),
+                    Int32::Missing => Ok(None),
+                    _ => Err(invalid_array_value_error(value)),
                 })
-                .collect(),
+                .collect::<io::Result<_>>()?,
         ))),
         v => Err(type_mismatch_error(v, Type::Integer)),
     }
@@ -200,12 +203,13 @@ where
             values
                 .into_iter()
                 .map(Float::from)
+                .take_while(|value| !matches!(value, Float::EndOfVector))
                 .map(|value| match value {
-                    Float::Value(n) => Some(n),
-                    Float::Missing => None,
-                    _ => todo!("unhandled float array value: {:?}", value),
+                    Float::Value(n) => Ok(Some(n)),
+                    Float::Missing => Ok(None),
+                    _ => Err(invalid_array_value_error(value)),
                 })
-                .collect(),
+                .collect::<io::Result<_>>()?,
         ))),
         v => Err(type_mismatch_error(v, Type::Float)),
     }
@@ -258,6 +262,16 @@ where
     }
 }
 
+fn invalid_array_value_error<T>(value: T) -> io::Error
+where
+    T: std::fmt::Debug,
+{
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("invalid array value: {value:?}"),
+    )
+}
+
 fn type_mismatch_error(actual: Option<Value>, expected: Type) -> io::Error {
     io::Error::new(
         io::ErrorKind::InvalidData,
@@ -360,6 +374,21 @@ mod tests {
             Some(vec![Some(55), None]),
         )?;
 
+        // Some(Value::IntegerArray([Some(8)])) (end of vector)
+        t(&[0x31, 0x08, 0x81, 0x81], &info, Some(vec![Some(8)]))?;
+        t(&[0x22, 0x15, 0x00, 0x01, 0x80], &info, Some(vec![Some(21)]))?;
+        t(
+            &[0x23, 0x37, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x80],
+            &info,
+            Some(vec![Some(55)]),
+        )?;
+
+        let mut reader = &[0x21, 0x08, 0x82][..];
+        assert!(matches!(
+            read_info_field_value(&mut reader, &info),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
         Ok(())
     }
 
@@ -438,6 +467,12 @@ mod tests {
             &info,
             Some(vec![Some(0.0), None]),
         )?;
+        // Some(Value::FloatArray([0.0])) (end of vector)
+        t(
+            &[0x25, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x80, 0x7f],
+            &info,
+            Some(vec![Some(0.0)]),
+        )?;
 
         Ok(())
     }
@@ -1,13 +1,19 @@
 //! BCF record and fields.
 
+pub mod builder;
 mod convert;
 mod filters;
 mod genotypes;
 mod info;
-pub(crate) mod value;
+pub mod value;
 
 pub(crate) use self::value::Value;
-pub use self::{filters::Filters, genotypes::Genotypes, info::Info};
+pub use self::{
+    builder::Builder,
+    filters::Filters,
+    genotypes::{GenotypeMatrix, Genotypes},
+    info::Info,
+};
 
 use std::io;
 
@@ -32,6 +38,18 @@ pub struct Record {
 }
 
 impl Record {
+    /// Returns a builder to create a record from each of its fields.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf as bcf;
+    /// let builder = bcf::Record::builder();
+    /// ```
+    pub fn builder() -> Builder {
+        Builder::default()
+    }
+
     /// Returns the chromosome ID of the record.
     ///
     /// The chromosome ID represents an index in the contig string map, which associates an ID (by
@@ -202,7 +220,22 @@ impl Record {
         &self.filter
     }
 
-    pub(crate) fn filters_mut(&mut self) -> &mut Filters {
+    /// Returns a mutable reference to the filters.
+    ///
+    /// Filters are string map indices, and changing them does not decode or encode any other
+    /// field.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf as bcf;
+    ///
+    /// let mut record = bcf::Record::default();
+    /// record.filters_mut().as_mut().push(0); // PASS
+    ///
+    /// assert_eq!(record.filters().as_ref(), [0]);
+    /// ```
+    pub fn filters_mut(&mut self) -> &mut Filters {
         &mut self.filter
     }
 
@@ -219,7 +252,25 @@ impl Record {
         &self.info
     }
 
-    pub(crate) fn info_mut(&mut self) -> &mut Info {
+    /// Returns a mutable reference to the info.
+    ///
+    /// Fields are kept in their encoded form. See [`Info::insert`] and [`Info::remove`] to change
+    /// them without decoding the other fields or the genotypes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bcf as bcf;
+    /// use noodles_vcf::record::info::field::Value;
+    ///
+    /// let mut record = bcf::Record::default();
+    /// record.info_mut().insert(1, &Value::Integer(8))?;
+    ///
+    /// assert_eq!(record.info().len(), 1);
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn info_mut(&mut self) -> &mut Info {
         &mut self.info
     }
 
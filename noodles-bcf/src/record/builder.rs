@@ -0,0 +1,635 @@
+//! BCF record builder.
+
+use std::{error, fmt, io};
+
+use noodles_vcf::{
+    self as vcf,
+    header::record::value::{map::Format, Map},
+    record::{
+        genotypes::genotype::field::{
+            value::{genotype::allele::Phasing, Genotype},
+            Value as GenotypeFieldValue,
+        },
+        info::field::Value,
+    },
+};
+
+use super::{
+    value::{Int8, Type},
+    ChromosomeId, Filters, Genotypes, Info, Record,
+};
+
+/// A BCF record builder.
+///
+/// Unlike converting a VCF record, the builder writes fields directly in their binary
+/// representation. Keys (INFO and FORMAT IDs) and filters are given as indices in the header
+/// string map, and the chromosome is given as an index in the header contig string map.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Builder {
+    chromosome_id: ChromosomeId,
+    position: vcf::record::Position,
+    end: Option<vcf::record::Position>,
+    quality_score: Option<vcf::record::QualityScore>,
+    ids: vcf::record::Ids,
+    reference_bases: vcf::record::ReferenceBases,
+    alternate_bases: vcf::record::AlternateBases,
+    filters: Filters,
+    info_fields: Vec<(usize, Value)>,
+    genotype_fields: Vec<(usize, GenotypeField)>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum GenotypeField {
+    Genotype(Vec<Option<Genotype>>),
+    Other(Map<Format>, Vec<Option<GenotypeFieldValue>>),
+}
+
+impl GenotypeField {
+    fn len(&self) -> usize {
+        match self {
+            Self::Genotype(values) => values.len(),
+            Self::Other(_, values) => values.len(),
+        }
+    }
+}
+
+/// An error returned when a BCF record fails to build.
+#[derive(Debug)]
+pub enum BuildError {
+    /// The end position is before the start position.
+    InvalidEnd,
+    /// An INFO field value with the given string map index is invalid.
+    InvalidInfoField(usize, io::Error),
+    /// Genotype field values with the given string map index are invalid.
+    InvalidGenotypeField(usize, io::Error),
+    /// Genotype fields have a different number of samples.
+    SampleCountMismatch {
+        /// The number of samples in the first genotype field.
+        expected: usize,
+        /// The number of samples in the mismatched genotype field.
+        actual: usize,
+    },
+}
+
+impl error::Error for BuildError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::InvalidInfoField(_, e) => Some(e),
+            Self::InvalidGenotypeField(_, e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidEnd => f.write_str("invalid end"),
+            Self::InvalidInfoField(i, _) => write!(f, "invalid INFO field: {i}"),
+            Self::InvalidGenotypeField(i, _) => write!(f, "invalid genotype field: {i}"),
+            Self::SampleCountMismatch { expected, actual } => write!(
+                f,
+                "sample count mismatch: expected {expected}, got {actual}"
+            ),
+        }
+    }
+}
+
+impl Builder {
+    /// Sets the chromosome ID.
+    ///
+    /// This is the index of the chromosome in the contig string map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf as bcf;
+    /// let record = bcf::Record::builder().set_chromosome_id(1).build()?;
+    /// assert_eq!(record.chromosome_id(), 1);
+    /// # Ok::<_, bcf::record::builder::BuildError>(())
+    /// ```
+    pub fn set_chromosome_id(mut self, chromosome_id: ChromosomeId) -> Self {
+        self.chromosome_id = chromosome_id;
+        self
+    }
+
+    /// Sets the start position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf as bcf;
+    /// use noodles_vcf::record::Position;
+    ///
+    /// let record = bcf::Record::builder()
+    ///     .set_position(Position::from(8))
+    ///     .build()?;
+    ///
+    /// assert_eq!(record.position(), Position::from(8));
+    /// # Ok::<_, bcf::record::builder::BuildError>(())
+    /// ```
+    pub fn set_position(mut self, position: vcf::record::Position) -> Self {
+        self.position = position;
+        self
+    }
+
+    /// Sets the end position.
+    ///
+    /// By default, the end position is calculated from the length of the reference bases.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf as bcf;
+    /// use noodles_vcf::record::Position;
+    ///
+    /// let record = bcf::Record::builder()
+    ///     .set_position(Position::from(8))
+    ///     .set_end(Position::from(13))
+    ///     .build()?;
+    ///
+    /// assert_eq!(record.end()?, Position::from(13));
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_end(mut self, end: vcf::record::Position) -> Self {
+        self.end = Some(end);
+        self
+    }
+
+    /// Sets the quality score.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf as bcf;
+    /// use noodles_vcf::record::QualityScore;
+    ///
+    /// let quality_score = QualityScore::try_from(13.0)?;
+    ///
+    /// let record = bcf::Record::builder()
+    ///     .set_quality_score(quality_score)
+    ///     .build()?;
+    ///
+    /// assert_eq!(record.quality_score(), Some(quality_score));
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_quality_score(mut self, quality_score: vcf::record::QualityScore) -> Self {
+        self.quality_score = Some(quality_score);
+        self
+    }
+
+    /// Sets a list of IDs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf as bcf;
+    ///
+    /// let record = bcf::Record::builder()
+    ///     .set_ids("nd0".parse()?)
+    ///     .build()?;
+    ///
+    /// assert_eq!(record.ids(), &"nd0".parse()?);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_ids(mut self, ids: vcf::record::Ids) -> Self {
+        self.ids = ids;
+        self
+    }
+
+    /// Sets the reference bases.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf as bcf;
+    /// use noodles_vcf::record::Position;
+    ///
+    /// let record = bcf::Record::builder()
+    ///     .set_reference_bases("ACGT".parse()?)
+    ///     .build()?;
+    ///
+    /// assert_eq!(record.end()?, Position::from(4));
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_reference_bases(mut self, reference_bases: vcf::record::ReferenceBases) -> Self {
+        self.reference_bases = reference_bases;
+        self
+    }
+
+    /// Sets the alternate bases.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf as bcf;
+    ///
+    /// let builder = bcf::Record::builder()
+    ///     .set_reference_bases("A".parse()?)
+    ///     .set_alternate_bases("C".parse()?);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_alternate_bases(mut self, alternate_bases: vcf::record::AlternateBases) -> Self {
+        self.alternate_bases = alternate_bases;
+        self
+    }
+
+    /// Sets the filters.
+    ///
+    /// Each filter is an index in the string map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf::{self as bcf, record::Filters};
+    ///
+    /// let mut filters = Filters::default();
+    /// filters.as_mut().push(0); // PASS
+    ///
+    /// let record = bcf::Record::builder()
+    ///     .set_filters(filters.clone())
+    ///     .build()?;
+    ///
+    /// assert_eq!(record.filters(), &filters);
+    /// # Ok::<_, bcf::record::builder::BuildError>(())
+    /// ```
+    pub fn set_filters(mut self, filters: Filters) -> Self {
+        self.filters = filters;
+        self
+    }
+
+    /// Adds an INFO field.
+    ///
+    /// The key is the index of the INFO ID in the string map. Integer values are written using the
+    /// smallest type that can represent them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf as bcf;
+    /// use noodles_vcf::record::info::field::Value;
+    ///
+    /// let record = bcf::Record::builder()
+    ///     .add_info_field(1, Value::Integer(5)) // AC=5
+    ///     .build()?;
+    ///
+    /// assert_eq!(record.info().len(), 1);
+    /// assert_eq!(record.info().as_ref(), [0x11, 0x01, 0x11, 0x05]);
+    /// # Ok::<_, bcf::record::builder::BuildError>(())
+    /// ```
+    pub fn add_info_field(mut self, key: usize, value: Value) -> Self {
+        self.info_fields.push((key, value));
+        self
+    }
+
+    /// Adds genotype field values for all samples.
+    ///
+    /// The key is the index of the FORMAT ID in the string map, and the FORMAT header record
+    /// describes the type of the values. There must be one value per sample.
+    ///
+    /// Genotype (`GT`) values are encoded differently and are added using
+    /// [`Self::add_genotype_genotype_field`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf as bcf;
+    /// use noodles_vcf::{
+    ///     header::{format::key, record::value::{map::Format, Map}},
+    ///     record::genotypes::genotype::field::Value,
+    /// };
+    ///
+    /// let format = Map::<Format>::from(&key::CONDITIONAL_GENOTYPE_QUALITY);
+    ///
+    /// let record = bcf::Record::builder()
+    ///     .add_genotype_field(2, &format, vec![Some(Value::Integer(13)), None])
+    ///     .build()?;
+    ///
+    /// let genotypes = record.genotypes();
+    /// assert_eq!(genotypes.len(), 2);
+    /// assert_eq!(genotypes.format_count(), 1);
+    /// # Ok::<_, bcf::record::builder::BuildError>(())
+    /// ```
+    pub fn add_genotype_field(
+        mut self,
+        key: usize,
+        format: &Map<Format>,
+        values: Vec<Option<GenotypeFieldValue>>,
+    ) -> Self {
+        self.genotype_fields
+            .push((key, GenotypeField::Other(format.clone(), values)));
+        self
+    }
+
+    /// Adds genotype (`GT`) field values for all samples.
+    ///
+    /// The key is the index of the `GT` FORMAT ID in the string map. Each genotype is encoded from
+    /// its allele indices and phasing. A missing genotype is encoded as a single missing allele.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf as bcf;
+    /// use noodles_vcf::record::genotypes::genotype::field::value::{
+    ///     genotype::{allele::Phasing, Allele},
+    ///     Genotype,
+    /// };
+    ///
+    /// let genotype = Genotype::try_from(vec![
+    ///     Allele::new(Some(0), Phasing::Unphased),
+    ///     Allele::new(Some(1), Phasing::Unphased),
+    /// ])?;
+    ///
+    /// let record = bcf::Record::builder()
+    ///     .add_genotype_genotype_field(1, vec![Some(genotype), None])
+    ///     .build()?;
+    ///
+    /// assert_eq!(record.genotypes().len(), 2);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn add_genotype_genotype_field(
+        mut self,
+        key: usize,
+        values: Vec<Option<Genotype>>,
+    ) -> Self {
+        self.genotype_fields
+            .push((key, GenotypeField::Genotype(values)));
+        self
+    }
+
+    /// Builds a BCF record.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf as bcf;
+    /// let record = bcf::Record::builder().build()?;
+    /// # Ok::<_, bcf::record::builder::BuildError>(())
+    /// ```
+    pub fn build(self) -> Result<Record, BuildError> {
+        use crate::writer::{
+            string_map::write_string_map_index,
+            vcf_record::{
+                genotypes::write_genotype_field_values, site::info::write_info_field_value,
+            },
+        };
+
+        let start = usize::from(self.position);
+
+        let rlen = match self.end {
+            Some(end) => usize::from(end)
+                .checked_sub(start)
+                .map(|n| n + 1)
+                .ok_or(BuildError::InvalidEnd)?,
+            None => self.reference_bases.len(),
+        };
+
+        let mut info_buf = Vec::new();
+
+        for (key, value) in &self.info_fields {
+            write_string_map_index(&mut info_buf, *key)
+                .and_then(|_| write_info_field_value(&mut info_buf, value))
+                .map_err(|e| BuildError::InvalidInfoField(*key, e))?;
+        }
+
+        let info = Info::new(info_buf, self.info_fields.len());
+
+        let sample_count = self
+            .genotype_fields
+            .first()
+            .map(|(_, field)| field.len())
+            .unwrap_or_default();
+
+        for (_, field) in &self.genotype_fields {
+            let actual = field.len();
+
+            if actual != sample_count {
+                return Err(BuildError::SampleCountMismatch {
+                    expected: sample_count,
+                    actual,
+                });
+            }
+        }
+
+        let mut genotypes = Genotypes::default();
+        let genotypes_buf = genotypes.as_mut();
+
+        for (key, field) in &self.genotype_fields {
+            write_string_map_index(genotypes_buf, *key)
+                .and_then(|_| match field {
+                    GenotypeField::Genotype(values) => write_genotype_values(genotypes_buf, values),
+                    GenotypeField::Other(format, values) => {
+                        let values: Vec<_> = values.iter().map(|v| v.as_ref()).collect();
+                        write_genotype_field_values(genotypes_buf, format, &values)
+                    }
+                })
+                .map_err(|e| BuildError::InvalidGenotypeField(*key, e))?;
+        }
+
+        genotypes.set_format_count(self.genotype_fields.len());
+        genotypes.set_sample_count(sample_count);
+
+        Ok(Record {
+            chrom: self.chromosome_id,
+            pos: self.position,
+            rlen,
+            qual: self.quality_score,
+            id: self.ids,
+            r#ref: self.reference_bases,
+            alt: self.alternate_bases,
+            filter: self.filters,
+            info,
+            genotypes,
+        })
+    }
+}
+
+impl Default for Builder {
+    fn default() -> Self {
+        let record = Record::default();
+
+        Self {
+            chromosome_id: record.chrom,
+            position: record.pos,
+            end: None,
+            quality_score: None,
+            ids: vcf::record::Ids::default(),
+            reference_bases: record.r#ref,
+            alternate_bases: vcf::record::AlternateBases::default(),
+            filters: Filters::default(),
+            info_fields: Vec::new(),
+            genotype_fields: Vec::new(),
+        }
+    }
+}
+
+fn write_genotype_values(buf: &mut Vec<u8>, values: &[Option<Genotype>]) -> io::Result<()> {
+    use crate::writer::value::write_type;
+
+    let mut raw_values = Vec::with_capacity(values.len());
+
+    for value in values {
+        let raw_value = match value {
+            Some(genotype) => encode_genotype(genotype)?,
+            None => vec![0],
+        };
+
+        raw_values.push(raw_value);
+    }
+
+    let max_len = raw_values.iter().map(|v| v.len()).max().unwrap_or_default();
+
+    write_type(buf, Some(Type::Int8(max_len)))?;
+
+    for raw_value in raw_values {
+        let pad = max_len - raw_value.len();
+        buf.extend(raw_value.into_iter().map(|n| n as u8));
+        buf.extend((0..pad).map(|_| i8::from(Int8::EndOfVector) as u8));
+    }
+
+    Ok(())
+}
+
+fn encode_genotype(genotype: &Genotype) -> io::Result<Vec<i8>> {
+    genotype
+        .iter()
+        .enumerate()
+        .map(|(i, allele)| {
+            let j = match allele.position() {
+                Some(position) => position
+                    .checked_add(1)
+                    .and_then(|n| n.checked_mul(2))
+                    .and_then(|n| i8::try_from(n).ok())
+                    .ok_or_else(|| {
+                        io::Error::new(io::ErrorKind::InvalidInput, "invalid allele position")
+                    })?,
+                None => 0,
+            };
+
+            // The phasing of the first allele is implicit.
+            if i > 0 && allele.phasing() == Phasing::Phased {
+                Ok(j | 0x01)
+            } else {
+                Ok(j)
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default() {
+        let builder = Builder::default();
+
+        assert_eq!(builder.chromosome_id, 0);
+        assert_eq!(builder.position, vcf::record::Position::from(1));
+        assert!(builder.end.is_none());
+        assert!(builder.quality_score.is_none());
+        assert!(builder.ids.is_empty());
+        assert_eq!(builder.reference_bases.len(), 1);
+        assert!(builder.alternate_bases.is_empty());
+        assert!(builder.filters.is_empty());
+        assert!(builder.info_fields.is_empty());
+        assert!(builder.genotype_fields.is_empty());
+    }
+
+    #[test]
+    fn test_build() -> Result<(), Box<dyn std::error::Error>> {
+        use vcf::header::format::key;
+
+        use crate::header::StringMaps;
+
+        let raw_header = r#"##fileformat=VCFv4.3
+##INFO=<ID=AC,Number=A,Type=Integer,Description="Allele count">
+##INFO=<ID=AA,Number=1,Type=String,Description="Ancestral allele">
+##FORMAT=<ID=GT,Number=1,Type=String,Description="Genotype">
+##FORMAT=<ID=AD,Number=R,Type=Integer,Description="Read depth for each allele">
+##contig=<ID=sq0>
+##contig=<ID=sq1>
+#CHROM	POS	ID	REF	ALT	QUAL	FILTER	INFO	FORMAT	sample0	sample1
+"#;
+
+        let header: vcf::Header = raw_header.parse()?;
+        let string_maps: StringMaps = raw_header.parse()?;
+
+        let mut filters = Filters::default();
+        filters.as_mut().push(0);
+
+        let ad = header.formats().get(&key::READ_DEPTHS).expect("missing AD");
+
+        let record = Builder::default()
+            .set_chromosome_id(1)
+            .set_position(vcf::record::Position::from(8))
+            .set_ids("nd0".parse()?)
+            .set_reference_bases("A".parse()?)
+            .set_alternate_bases("C,G".parse()?)
+            .set_quality_score(vcf::record::QualityScore::try_from(13.0)?)
+            .set_filters(filters)
+            .add_info_field(1, Value::IntegerArray(vec![Some(1), Some(300)]))
+            .add_info_field(2, Value::String(String::from("A")))
+            .add_genotype_genotype_field(3, vec![Some("0|1".parse()?), None])
+            .add_genotype_field(
+                4,
+                ad,
+                vec![
+                    Some(GenotypeFieldValue::IntegerArray(vec![
+                        Some(5),
+                        Some(8),
+                        Some(0),
+                    ])),
+                    None,
+                ],
+            )
+            .build()?;
+
+        let actual = record.try_into_vcf_record(&header, &string_maps)?;
+        let expected = "sq1\t8\tnd0\tA\tC,G\t13\tPASS\tAC=1,300;AA=A\tGT:AD\t0|1:5,8,0\t.:.";
+        assert_eq!(actual.to_string(), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_with_invalid_fields() -> Result<(), Box<dyn std::error::Error>> {
+        use std::error::Error;
+
+        assert!(matches!(
+            Builder::default()
+                .set_position(vcf::record::Position::from(8))
+                .set_end(vcf::record::Position::from(5))
+                .build(),
+            Err(BuildError::InvalidEnd)
+        ));
+
+        let result = Builder::default()
+            .add_info_field(1, Value::IntegerArray(Vec::new()))
+            .build();
+        assert!(matches!(result, Err(BuildError::InvalidInfoField(1, _))));
+        assert!(result.unwrap_err().source().is_some());
+
+        let result = Builder::default()
+            .add_genotype_genotype_field(1, vec![Some("63".parse()?)])
+            .build();
+        assert!(matches!(
+            result,
+            Err(BuildError::InvalidGenotypeField(1, _))
+        ));
+        assert!(result.unwrap_err().source().is_some());
+
+        assert!(matches!(
+            Builder::default()
+                .add_genotype_genotype_field(1, vec![None])
+                .add_genotype_genotype_field(2, vec![None, None])
+                .build(),
+            Err(BuildError::SampleCountMismatch {
+                expected: 1,
+                actual: 2
+            })
+        ));
+
+        Ok(())
+    }
+}
@@ -1,8 +1,12 @@
+mod matrix;
+
+pub use self::matrix::GenotypeMatrix;
+
 use std::io;
 
 use noodles_vcf as vcf;
 
-use crate::header::string_maps::StringStringMap;
+use crate::{header::string_maps::StringStringMap, record::value::Type};
 
 /// BCF record genotypes.
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
@@ -112,6 +116,264 @@ impl Genotypes {
         self.format_count
     }
 
+    /// Appends the genotype (`GT`) dosages of all samples to a matrix.
+    ///
+    /// The dosage of a sample is the number of its alleles that are not the reference allele. It
+    /// is [`GenotypeMatrix::MISSING_DOSAGE`] if any allele is missing or the record has no
+    /// genotype field.
+    ///
+    /// Values are decoded directly from the encoded genotypes. The matrix must have the same number
+    /// of samples and one value per sample.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf::{header::StringMaps, record::GenotypeMatrix};
+    /// use noodles_bcf as bcf;
+    /// use noodles_vcf::{
+    ///     self as vcf,
+    ///     header::{format::key, record::value::{map::Format, Map}},
+    /// };
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .add_format(key::GENOTYPE, Map::<Format>::from(&key::GENOTYPE))
+    ///     .add_sample_name("sample0")
+    ///     .add_sample_name("sample1")
+    ///     .build();
+    ///
+    /// let string_maps = StringMaps::from(&header);
+    ///
+    /// let record = bcf::Record::builder()
+    ///     .add_genotype_genotype_field(1, vec![Some("0/1".parse()?), Some("1|1".parse()?)])
+    ///     .build()?;
+    ///
+    /// let mut matrix = GenotypeMatrix::new(2, 1);
+    /// record.genotypes().append_genotype_dosages(string_maps.strings(), &mut matrix)?;
+    ///
+    /// assert_eq!(matrix.variant(0), Some(&[1, 2][..]));
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn append_genotype_dosages(
+        &self,
+        string_map: &StringStringMap,
+        matrix: &mut GenotypeMatrix<i8>,
+    ) -> io::Result<()> {
+        use vcf::header::format::key;
+
+        self.validate_matrix_dimensions(matrix.sample_count(), matrix.values_per_sample(), 1)?;
+
+        let field = self.find_field(string_map, key::GENOTYPE.as_ref())?;
+
+        matrix.push_variant(|dst| match field {
+            Some((Some(ty), src)) => {
+                let (n, width) = match ty {
+                    Type::Int8(n) => (n, 1),
+                    Type::Int16(n) => (n, 2),
+                    Type::Int32(n) => (n, 4),
+                    _ => {
+                        return Err(io::Error::new(
+                            io::ErrorKind::InvalidData,
+                            format!("invalid genotype field type: {ty:?}"),
+                        ))
+                    }
+                };
+
+                if n == 0 {
+                    dst.resize(dst.len() + self.len(), GenotypeMatrix::MISSING_DOSAGE);
+                    return Ok(());
+                }
+
+                for sample in src.chunks_exact(n * width) {
+                    let dosage = decode_genotype_dosage(sample, width)?;
+                    dst.push(dosage);
+                }
+
+                Ok(())
+            }
+            _ => {
+                dst.resize(dst.len() + self.len(), GenotypeMatrix::MISSING_DOSAGE);
+                Ok(())
+            }
+        })
+    }
+
+    /// Appends the numeric values of a genotype field of all samples to a matrix.
+    ///
+    /// This is typically used for fields such as dosages (`DS`) and genotype probabilities (`GP`).
+    /// The first [`GenotypeMatrix::values_per_sample`] values of each sample are appended. Missing
+    /// values, including those of samples with fewer values or when the record does not have the
+    /// field, are NaN. Integer values are converted to floating-point values.
+    ///
+    /// Values are decoded directly from the encoded genotypes. The matrix must have the same number
+    /// of samples.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf::{header::StringMaps, record::GenotypeMatrix};
+    /// use noodles_bcf as bcf;
+    /// use noodles_vcf::{
+    ///     self as vcf,
+    ///     header::{format::key, record::value::{map::Format, Map}},
+    ///     record::genotypes::genotype::field::Value,
+    /// };
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .add_format(
+    ///         key::GENOTYPE_POSTERIOR_PROBABILITIES,
+    ///         Map::<Format>::from(&key::GENOTYPE_POSTERIOR_PROBABILITIES),
+    ///     )
+    ///     .add_sample_name("sample0")
+    ///     .build();
+    ///
+    /// let string_maps = StringMaps::from(&header);
+    ///
+    /// let record = bcf::Record::builder()
+    ///     .add_genotype_field(
+    ///         1,
+    ///         &header.formats()[&key::GENOTYPE_POSTERIOR_PROBABILITIES],
+    ///         vec![Some(Value::FloatArray(vec![Some(0.25), Some(0.5), Some(0.25)]))],
+    ///     )
+    ///     .build()?;
+    ///
+    /// let mut matrix = GenotypeMatrix::new(1, 3);
+    /// record.genotypes().append_float_values(
+    ///     string_maps.strings(),
+    ///     &key::GENOTYPE_POSTERIOR_PROBABILITIES,
+    ///     &mut matrix,
+    /// )?;
+    ///
+    /// assert_eq!(matrix.get(0, 0), Some(&[0.25, 0.5, 0.25][..]));
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn append_float_values(
+        &self,
+        string_map: &StringStringMap,
+        key: &vcf::header::format::Key,
+        matrix: &mut GenotypeMatrix<f32>,
+    ) -> io::Result<()> {
+        let values_per_sample = matrix.values_per_sample();
+
+        self.validate_matrix_dimensions(
+            matrix.sample_count(),
+            values_per_sample,
+            values_per_sample,
+        )?;
+
+        let field = self.find_field(string_map, key.as_ref())?;
+
+        matrix.push_variant(|dst| match field {
+            Some((Some(ty), src)) => {
+                let (n, width) = match ty {
+                    Type::Int8(n) => (n, 1),
+                    Type::Int16(n) => (n, 2),
+                    Type::Int32(n) | Type::Float(n) => (n, 4),
+                    Type::String(_) => {
+                        return Err(io::Error::new(
+                            io::ErrorKind::InvalidData,
+                            format!("invalid genotype field type for {key}: {ty:?}"),
+                        ))
+                    }
+                };
+
+                if n == 0 {
+                    dst.resize(dst.len() + self.len() * values_per_sample, f32::NAN);
+                    return Ok(());
+                }
+
+                let is_float = matches!(ty, Type::Float(_));
+
+                for sample in src.chunks_exact(n * width) {
+                    for i in 0..values_per_sample {
+                        let value = if i < n {
+                            let src = &sample[i * width..(i + 1) * width];
+
+                            if is_float {
+                                decode_float(src)?
+                            } else {
+                                decode_int(src)?.flatten().map(|n| n as f32)
+                            }
+                        } else {
+                            None
+                        };
+
+                        dst.push(value.unwrap_or(f32::NAN));
+                    }
+                }
+
+                Ok(())
+            }
+            _ => {
+                dst.resize(dst.len() + self.len() * values_per_sample, f32::NAN);
+                Ok(())
+            }
+        })
+    }
+
+    fn validate_matrix_dimensions(
+        &self,
+        sample_count: usize,
+        values_per_sample: usize,
+        expected_values_per_sample: usize,
+    ) -> io::Result<()> {
+        if sample_count != self.len() {
+            Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "sample count mismatch: expected {}, got {sample_count}",
+                    self.len()
+                ),
+            ))
+        } else if values_per_sample != expected_values_per_sample || values_per_sample == 0 {
+            Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("invalid values per sample: {values_per_sample}"),
+            ))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn find_field(
+        &self,
+        string_map: &StringStringMap,
+        key: &str,
+    ) -> io::Result<Option<(Option<Type>, &[u8])>> {
+        use crate::reader::{string_map::read_string_map_index, value::read_type};
+
+        let i = match string_map.get_index_of(key) {
+            Some(i) => i,
+            None => return Ok(None),
+        };
+
+        let mut reader = &self.buf[..];
+
+        for _ in 0..self.format_count() {
+            let j = read_string_map_index(&mut reader)?;
+            let ty = read_type(&mut reader)?;
+
+            let len = match ty {
+                Some(Type::Int8(n)) | Some(Type::String(n)) => n,
+                Some(Type::Int16(n)) => n * 2,
+                Some(Type::Int32(n)) | Some(Type::Float(n)) => n * 4,
+                None => 0,
+            } * self.len();
+
+            if reader.len() < len {
+                return Err(io::Error::from(io::ErrorKind::UnexpectedEof));
+            }
+
+            let (buf, rest) = reader.split_at(len);
+            reader = rest;
+
+            if i == j {
+                return Ok(Some((ty, buf)));
+            }
+        }
+
+        Ok(None)
+    }
+
     pub(crate) fn set_format_count(&mut self, format_count: usize) {
         self.format_count = format_count;
     }
@@ -121,6 +383,79 @@ impl Genotypes {
     }
 }
 
+// Returns `None` for the end of a vector and `Some(None)` for a missing value.
+fn decode_int(src: &[u8]) -> io::Result<Option<Option<i32>>> {
+    use crate::record::value::{Int16, Int32, Int8};
+
+    let reserved = || io::Error::new(io::ErrorKind::InvalidData, "invalid reserved value");
+
+    match *src {
+        [b0] => match Int8::from(b0 as i8) {
+            Int8::Value(n) => Ok(Some(Some(i32::from(n)))),
+            Int8::Missing => Ok(Some(None)),
+            Int8::EndOfVector => Ok(None),
+            _ => Err(reserved()),
+        },
+        [b0, b1] => match Int16::from(i16::from_le_bytes([b0, b1])) {
+            Int16::Value(n) => Ok(Some(Some(i32::from(n)))),
+            Int16::Missing => Ok(Some(None)),
+            Int16::EndOfVector => Ok(None),
+            _ => Err(reserved()),
+        },
+        [b0, b1, b2, b3] => match Int32::from(i32::from_le_bytes([b0, b1, b2, b3])) {
+            Int32::Value(n) => Ok(Some(Some(n))),
+            Int32::Missing => Ok(Some(None)),
+            Int32::EndOfVector => Ok(None),
+            _ => Err(reserved()),
+        },
+        _ => unreachable!(),
+    }
+}
+
+fn decode_float(src: &[u8]) -> io::Result<Option<f32>> {
+    use crate::record::value::Float;
+
+    let buf = <[u8; 4]>::try_from(src).expect("invalid float width");
+
+    match Float::from(f32::from_le_bytes(buf)) {
+        Float::Value(n) => Ok(Some(n)),
+        Float::Missing | Float::EndOfVector => Ok(None),
+        _ => Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "invalid reserved value",
+        )),
+    }
+}
+
+fn decode_genotype_dosage(src: &[u8], width: usize) -> io::Result<i8> {
+    let mut dosage: i8 = 0;
+    let mut allele_count = 0;
+
+    for raw_value in src.chunks_exact(width) {
+        let value = match decode_int(raw_value)? {
+            Some(Some(value)) => value,
+            Some(None) => return Ok(GenotypeMatrix::MISSING_DOSAGE),
+            None => break,
+        };
+
+        allele_count += 1;
+
+        let allele = (value >> 1) - 1;
+
+        if allele < 0 {
+            return Ok(GenotypeMatrix::MISSING_DOSAGE);
+        } else if allele > 0 {
+            dosage = dosage.saturating_add(1);
+        }
+    }
+
+    if allele_count == 0 {
+        Ok(GenotypeMatrix::MISSING_DOSAGE)
+    } else {
+        Ok(dosage)
+    }
+}
+
 impl AsRef<[u8]> for Genotypes {
     fn as_ref(&self) -> &[u8] {
         &self.buf
@@ -132,3 +467,146 @@ impl AsMut<Vec<u8>> for Genotypes {
         &mut self.buf
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use vcf::{
+        header::{
+            format::key,
+            record::value::{map::Format, Map},
+        },
+        record::genotypes::genotype::field::Value,
+    };
+
+    use super::*;
+    use crate::{header::StringMaps, Record};
+
+    fn build_header() -> vcf::Header {
+        vcf::Header::builder()
+            .add_format(key::GENOTYPE, Map::<Format>::from(&key::GENOTYPE))
+            .add_format(
+                key::GENOTYPE_POSTERIOR_PROBABILITIES,
+                Map::<Format>::from(&key::GENOTYPE_POSTERIOR_PROBABILITIES),
+            )
+            .add_format(key::READ_DEPTH, Map::<Format>::from(&key::READ_DEPTH))
+            .add_sample_name("sample0")
+            .add_sample_name("sample1")
+            .add_sample_name("sample2")
+            .build()
+    }
+
+    #[test]
+    fn test_append_genotype_dosages() -> Result<(), Box<dyn std::error::Error>> {
+        let header = build_header();
+        let string_maps = StringMaps::from(&header);
+
+        let gt = |s: &str| s.parse().map(Some);
+
+        let records = [
+            Record::builder()
+                .add_genotype_genotype_field(1, vec![gt("0/1")?, gt("1|2")?, gt("./.")?])
+                .build()?,
+            Record::builder()
+                .add_genotype_genotype_field(1, vec![gt("1")?, gt("0/0")?, gt("0/.")?])
+                .build()?,
+            Record::builder()
+                .add_genotype_field(
+                    3,
+                    &header.formats()[&key::READ_DEPTH],
+                    vec![Some(Value::Integer(8)), None, None],
+                )
+                .build()?,
+        ];
+
+        let mut matrix = GenotypeMatrix::new(3, 1);
+
+        for record in &records {
+            record
+                .genotypes()
+                .append_genotype_dosages(string_maps.strings(), &mut matrix)?;
+        }
+
+        assert_eq!(matrix.variant_count(), 3);
+        assert_eq!(
+            matrix.as_slice(),
+            [
+                1, 2, -1, // variant 0
+                1, 0, -1, // variant 1
+                -1, -1, -1, // variant 2
+            ]
+        );
+        assert_eq!(matrix.get(1, 0), Some(&[2][..]));
+
+        let mut matrix = GenotypeMatrix::new(2, 1);
+        assert!(matches!(
+            records[0].genotypes().append_genotype_dosages(string_maps.strings(), &mut matrix),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+        assert_eq!(matrix.variant_count(), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_append_float_values() -> Result<(), Box<dyn std::error::Error>> {
+        let header = build_header();
+        let string_maps = StringMaps::from(&header);
+
+        let record = Record::builder()
+            .add_genotype_field(
+                2,
+                &header.formats()[&key::GENOTYPE_POSTERIOR_PROBABILITIES],
+                vec![
+                    Some(Value::FloatArray(vec![Some(0.25), Some(0.5), Some(0.25)])),
+                    Some(Value::FloatArray(vec![Some(0.5), None])),
+                    None,
+                ],
+            )
+            .add_genotype_field(
+                3,
+                &header.formats()[&key::READ_DEPTH],
+                vec![Some(Value::Integer(8)), None, Some(Value::Integer(300))],
+            )
+            .build()?;
+
+        let genotypes = record.genotypes();
+
+        let mut matrix = GenotypeMatrix::new(3, 3);
+        genotypes.append_float_values(
+            string_maps.strings(),
+            &key::GENOTYPE_POSTERIOR_PROBABILITIES,
+            &mut matrix,
+        )?;
+
+        assert_eq!(matrix.get(0, 0), Some(&[0.25, 0.5, 0.25][..]));
+
+        let actual = matrix.get(1, 0).unwrap();
+        assert_eq!(actual[0], 0.5);
+        assert!(actual[1].is_nan() && actual[2].is_nan());
+
+        assert!(matrix.get(2, 0).unwrap().iter().all(|n| n.is_nan()));
+
+        let mut matrix = GenotypeMatrix::new(3, 1);
+        genotypes.append_float_values(string_maps.strings(), &key::READ_DEPTH, &mut matrix)?;
+        genotypes.append_float_values(
+            string_maps.strings(),
+            &key::CONDITIONAL_GENOTYPE_QUALITY,
+            &mut matrix,
+        )?;
+
+        let actual = matrix.as_slice();
+        assert_eq!(actual[0], 8.0);
+        assert!(actual[1].is_nan());
+        assert_eq!(actual[2], 300.0);
+        assert!(actual[3..].iter().all(|n| n.is_nan()));
+
+        let mut matrix = GenotypeMatrix::new(3, 0);
+        assert!(matches!(
+            genotypes.append_float_values(string_maps.strings(), &key::READ_DEPTH, &mut matrix),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+        assert_eq!(matrix.variant_count(), 0);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,187 @@
+/// A dense matrix of per-sample genotype values across records (variants).
+///
+/// Each cell, i.e., a (sample, variant) pair, holds the same number of values. Values are stored
+/// contiguously in variant-major order: all values of the first variant, ordered by sample, then
+/// all values of the second variant, etc. This is the layout of a `(variants, samples, values per
+/// sample)` row-major array or, when there is one value per sample, a `(samples, variants)`
+/// column-major array, so the buffer can be adopted by other array libraries without copying.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct GenotypeMatrix<T> {
+    sample_count: usize,
+    values_per_sample: usize,
+    variant_count: usize,
+    data: Vec<T>,
+}
+
+impl GenotypeMatrix<i8> {
+    /// The dosage of a sample with a missing genotype.
+    pub const MISSING_DOSAGE: i8 = -1;
+}
+
+impl<T> GenotypeMatrix<T> {
+    /// Creates an empty genotype matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf::record::GenotypeMatrix;
+    ///
+    /// let matrix = GenotypeMatrix::<f32>::new(2, 3);
+    ///
+    /// assert_eq!(matrix.sample_count(), 2);
+    /// assert_eq!(matrix.values_per_sample(), 3);
+    /// assert_eq!(matrix.variant_count(), 0);
+    /// ```
+    pub fn new(sample_count: usize, values_per_sample: usize) -> Self {
+        Self {
+            sample_count,
+            values_per_sample,
+            variant_count: 0,
+            data: Vec::new(),
+        }
+    }
+
+    /// Returns the number of samples.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf::record::GenotypeMatrix;
+    /// let matrix = GenotypeMatrix::<i8>::new(2, 1);
+    /// assert_eq!(matrix.sample_count(), 2);
+    /// ```
+    pub fn sample_count(&self) -> usize {
+        self.sample_count
+    }
+
+    /// Returns the number of values per sample.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf::record::GenotypeMatrix;
+    /// let matrix = GenotypeMatrix::<i8>::new(2, 1);
+    /// assert_eq!(matrix.values_per_sample(), 1);
+    /// ```
+    pub fn values_per_sample(&self) -> usize {
+        self.values_per_sample
+    }
+
+    /// Returns the number of variants.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf::record::GenotypeMatrix;
+    /// let matrix = GenotypeMatrix::<i8>::new(2, 1);
+    /// assert_eq!(matrix.variant_count(), 0);
+    /// ```
+    pub fn variant_count(&self) -> usize {
+        self.variant_count
+    }
+
+    /// Returns the values of a sample for a variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf::record::GenotypeMatrix;
+    /// let matrix = GenotypeMatrix::<i8>::new(2, 1);
+    /// assert!(matrix.get(0, 0).is_none());
+    /// ```
+    pub fn get(&self, sample: usize, variant: usize) -> Option<&[T]> {
+        if sample < self.sample_count && variant < self.variant_count {
+            let start = (variant * self.sample_count + sample) * self.values_per_sample;
+            let end = start + self.values_per_sample;
+            Some(&self.data[start..end])
+        } else {
+            None
+        }
+    }
+
+    /// Returns the values of all samples for a variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf::record::GenotypeMatrix;
+    /// let matrix = GenotypeMatrix::<i8>::new(2, 1);
+    /// assert!(matrix.variant(0).is_none());
+    /// ```
+    pub fn variant(&self, variant: usize) -> Option<&[T]> {
+        if variant < self.variant_count {
+            let len = self.sample_count * self.values_per_sample;
+            let start = variant * len;
+            Some(&self.data[start..start + len])
+        } else {
+            None
+        }
+    }
+
+    /// Removes all variants.
+    ///
+    /// This does not affect the capacity of the matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf::record::GenotypeMatrix;
+    /// let mut matrix = GenotypeMatrix::<i8>::new(2, 1);
+    /// matrix.clear();
+    /// assert_eq!(matrix.variant_count(), 0);
+    /// ```
+    pub fn clear(&mut self) {
+        self.data.clear();
+        self.variant_count = 0;
+    }
+
+    /// Returns the values as a slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf::record::GenotypeMatrix;
+    /// let matrix = GenotypeMatrix::<i8>::new(2, 1);
+    /// assert!(matrix.as_slice().is_empty());
+    /// ```
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+
+    /// Returns the values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf::record::GenotypeMatrix;
+    /// let matrix = GenotypeMatrix::<i8>::new(2, 1);
+    /// assert!(matrix.into_vec().is_empty());
+    /// ```
+    pub fn into_vec(self) -> Vec<T> {
+        self.data
+    }
+
+    pub(crate) fn push_variant<F>(&mut self, f: F) -> std::io::Result<()>
+    where
+        F: FnOnce(&mut Vec<T>) -> std::io::Result<()>,
+    {
+        let len = self.data.len();
+
+        match f(&mut self.data) {
+            Ok(()) => {
+                debug_assert_eq!(
+                    self.data.len() - len,
+                    self.sample_count * self.values_per_sample
+                );
+
+                self.variant_count += 1;
+
+                Ok(())
+            }
+            Err(e) => {
+                self.data.truncate(len);
+                Err(e)
+            }
+        }
+    }
+}
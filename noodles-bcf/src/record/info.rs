@@ -1,4 +1,4 @@
-use std::io;
+use std::{io, ops::Range};
 
 use noodles_vcf as vcf;
 
@@ -256,6 +256,115 @@ impl Info {
             .map(|result| result.map(|(_, value)| value))
     }
 
+    /// Inserts or replaces an info field.
+    ///
+    /// The key is the index of the INFO ID in the string map. If the field exists, its encoded
+    /// bytes are replaced in place. Otherwise, the field is appended. The other fields are not
+    /// decoded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bcf::record::Info;
+    /// use noodles_vcf::record::info::field::Value;
+    ///
+    /// let data = vec![
+    ///     0x11, 0x01, 0x11, 0x05, // AC=5
+    ///     0x11, 0x02, 0x11, 0x08, // DP=8
+    /// ];
+    ///
+    /// let mut info = Info::new(data, 2);
+    ///
+    /// info.insert(1, &Value::Integer(3))?; // AC=3
+    /// info.insert(3, &Value::Flag)?; // SOMATIC
+    ///
+    /// assert_eq!(info.len(), 3);
+    /// assert_eq!(info.as_ref(), [
+    ///     0x11, 0x01, 0x11, 0x03, // AC=3
+    ///     0x11, 0x02, 0x11, 0x08, // DP=8
+    ///     0x11, 0x03, 0x00, // SOMATIC
+    /// ]);
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn insert(
+        &mut self,
+        key: usize,
+        value: &vcf::record::info::field::Value,
+    ) -> io::Result<()> {
+        use crate::writer::{
+            string_map::write_string_map_index, vcf_record::site::info::write_info_field_value,
+        };
+
+        let mut field = Vec::new();
+        write_string_map_index(&mut field, key)?;
+        write_info_field_value(&mut field, value)?;
+
+        if let Some(range) = self.find(key)? {
+            self.buf.splice(range, field);
+        } else {
+            self.buf.extend(field);
+            self.field_count += 1;
+        }
+
+        Ok(())
+    }
+
+    /// Removes an info field.
+    ///
+    /// The key is the index of the INFO ID in the string map. This returns whether the field was
+    /// present. The other fields are not decoded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bcf::record::Info;
+    ///
+    /// let data = vec![
+    ///     0x11, 0x01, 0x11, 0x05, // AC=5
+    ///     0x11, 0x02, 0x11, 0x08, // DP=8
+    /// ];
+    ///
+    /// let mut info = Info::new(data, 2);
+    ///
+    /// assert!(info.remove(1)?);
+    /// assert!(!info.remove(1)?);
+    ///
+    /// assert_eq!(info.len(), 1);
+    /// assert_eq!(info.as_ref(), [0x11, 0x02, 0x11, 0x08]); // DP=8
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn remove(&mut self, key: usize) -> io::Result<bool> {
+        if let Some(range) = self.find(key)? {
+            self.buf.drain(range);
+            self.field_count -= 1;
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    }
+
+    fn find(&self, key: usize) -> io::Result<Option<Range<usize>>> {
+        use crate::reader::{string_map::read_string_map_index, value::read_value};
+
+        let mut reader = &self.buf[..];
+
+        for _ in 0..self.len() {
+            let start = self.buf.len() - reader.len();
+
+            let k = read_string_map_index(&mut reader)?;
+            read_value(&mut reader)?;
+
+            if k == key {
+                let end = self.buf.len() - reader.len();
+                return Ok(Some(start..end));
+            }
+        }
+
+        Ok(None)
+    }
+
     pub(crate) fn set_field_count(&mut self, field_count: usize) {
         self.field_count = field_count;
     }
@@ -272,3 +381,73 @@ impl AsMut<Vec<u8>> for Info {
         &mut self.buf
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use vcf::record::info::field::Value;
+
+    use super::*;
+
+    #[test]
+    fn test_insert() -> io::Result<()> {
+        let data = vec![
+            0x11, 0x01, 0x11, 0x05, // AC=5
+            0x11, 0x02, 0x11, 0x08, // DP=8
+        ];
+
+        let mut info = Info::new(data, 2);
+
+        info.insert(1, &Value::String(String::from("nd")))?;
+        assert_eq!(info.len(), 2);
+        assert_eq!(
+            info.as_ref(),
+            [
+                0x11, 0x01, 0x27, b'n', b'd', // AC=nd
+                0x11, 0x02, 0x11, 0x08, // DP=8
+            ]
+        );
+
+        info.insert(2, &Value::Integer(300))?;
+        assert_eq!(info.len(), 2);
+        assert_eq!(
+            info.as_ref(),
+            [
+                0x11, 0x01, 0x27, b'n', b'd', // AC=nd
+                0x11, 0x02, 0x12, 0x2c, 0x01, // DP=300
+            ]
+        );
+
+        let mut info = Info::default();
+        info.insert(1, &Value::Integer(5))?;
+        assert_eq!(info, Info::new(vec![0x11, 0x01, 0x11, 0x05], 1));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_remove() -> io::Result<()> {
+        let data = vec![
+            0x11, 0x01, 0x11, 0x05, // AC=5
+            0x11, 0x02, 0x11, 0x08, // DP=8
+        ];
+
+        let mut info = Info::new(data, 2);
+
+        assert!(info.remove(2)?);
+        assert_eq!(info, Info::new(vec![0x11, 0x01, 0x11, 0x05], 1));
+
+        assert!(!info.remove(2)?);
+
+        assert!(info.remove(1)?);
+        assert!(info.is_empty());
+        assert!(info.as_ref().is_empty());
+
+        let mut info = Info::new(vec![0x11, 0x01], 1);
+        assert!(matches!(
+            info.remove(2),
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof
+        ));
+
+        Ok(())
+    }
+}
@@ -1,3 +1,8 @@
+//! BCF record typed values.
+//!
+//! This is the BCF type system (§ 6.3.3 "Type encoding"): a typed value is a type descriptor byte
+//! (with an optional overflow length) followed by the value data.
+
 mod float;
 mod int16;
 mod int32;
@@ -6,15 +11,179 @@ mod ty;
 
 pub use self::{float::Float, int16::Int16, int32::Int32, int8::Int8, ty::Type};
 
+use std::io::{self, Read, Write};
+
+/// A BCF record typed value.
+///
+/// A scalar of `None` is a value with a length of 0. Array values are raw and may include
+/// sentinels, e.g., [`Int8::EndOfVector`] as `i8`.
 #[derive(Clone, Debug, PartialEq)]
 pub enum Value {
+    /// An 8-bit integer.
     Int8(Option<Int8>),
+    /// An array of 8-bit integers.
     Int8Array(Vec<i8>),
+    /// A 16-bit integer.
     Int16(Option<Int16>),
+    /// An array of 16-bit integers.
     Int16Array(Vec<i16>),
+    /// A 32-bit integer.
     Int32(Option<Int32>),
+    /// An array of 32-bit integers.
     Int32Array(Vec<i32>),
+    /// A 32-bit floating-point.
     Float(Option<Float>),
+    /// An array of 32-bit floating-points.
     FloatArray(Vec<f32>),
+    /// A character string.
     String(Option<String>),
 }
+
+/// Reads a typed value.
+///
+/// This returns `None` for a missing type (`0x00`).
+///
+/// # Examples
+///
+/// ```
+/// # use std::io;
+/// use noodles_bcf::record::value::{self, Int8, Value};
+///
+/// let data = [0x11, 0x05];
+/// let mut reader = &data[..];
+/// assert_eq!(value::read_value(&mut reader)?, Some(Value::Int8(Some(Int8::Value(5)))));
+///
+/// let data = [0x21, 0x05, 0x81];
+/// let mut reader = &data[..];
+/// assert_eq!(value::read_value(&mut reader)?, Some(Value::Int8Array(vec![5, -127])));
+/// # Ok::<_, io::Error>(())
+/// ```
+pub fn read_value<R>(reader: &mut R) -> io::Result<Option<Value>>
+where
+    R: Read,
+{
+    crate::reader::value::read_value(reader)
+}
+
+/// Reads a type descriptor.
+///
+/// This returns `None` for a missing type (`0x00`).
+///
+/// # Examples
+///
+/// ```
+/// # use std::io;
+/// use noodles_bcf::record::value::{self, Type};
+///
+/// let data = [0xf1, 0x11, 0x15];
+/// let mut reader = &data[..];
+/// assert_eq!(value::read_type(&mut reader)?, Some(Type::Int8(21)));
+/// # Ok::<_, io::Error>(())
+/// ```
+pub fn read_type<R>(reader: &mut R) -> io::Result<Option<Type>>
+where
+    R: Read,
+{
+    crate::reader::value::read_type(reader)
+}
+
+/// Writes a typed value.
+///
+/// A value of `None` is written as a missing type (`0x00`).
+///
+/// # Examples
+///
+/// ```
+/// # use std::io;
+/// use noodles_bcf::record::value::{self, Int16, Value};
+///
+/// let mut buf = Vec::new();
+/// value::write_value(&mut buf, Some(Value::Int16(Some(Int16::Missing))))?;
+/// assert_eq!(buf, [0x12, 0x00, 0x80]);
+/// # Ok::<_, io::Error>(())
+/// ```
+pub fn write_value<W>(writer: &mut W, value: Option<Value>) -> io::Result<()>
+where
+    W: Write,
+{
+    crate::writer::value::write_value(writer, value)
+}
+
+/// Writes a type descriptor.
+///
+/// A type of `None` is written as a missing type (`0x00`).
+///
+/// # Examples
+///
+/// ```
+/// # use std::io;
+/// use noodles_bcf::record::value::{self, Type};
+///
+/// let mut buf = Vec::new();
+/// value::write_type(&mut buf, Some(Type::Int8(21)))?;
+/// assert_eq!(buf, [0xf1, 0x11, 0x15]);
+/// # Ok::<_, io::Error>(())
+/// ```
+pub fn write_type<W>(writer: &mut W, ty: Option<Type>) -> io::Result<()>
+where
+    W: Write,
+{
+    crate::writer::value::write_type(writer, ty)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_and_write_value() -> io::Result<()> {
+        fn t(value: Option<Value>) -> io::Result<()> {
+            let mut buf = Vec::new();
+            write_value(&mut buf, value.clone())?;
+
+            let mut reader = &buf[..];
+            let actual = read_value(&mut reader)?;
+
+            assert_eq!(actual, value);
+            assert!(reader.is_empty());
+
+            Ok(())
+        }
+
+        t(None)?;
+
+        t(Some(Value::Int8(None)))?;
+        t(Some(Value::Int8(Some(Int8::Value(Int8::MIN_VALUE)))))?;
+        t(Some(Value::Int8(Some(Int8::Missing))))?;
+        t(Some(Value::Int8(Some(Int8::EndOfVector))))?;
+        t(Some(Value::Int8Array(vec![
+            5,
+            i8::from(Int8::Missing),
+            i8::from(Int8::EndOfVector),
+        ])))?;
+
+        t(Some(Value::Int16(Some(Int16::Value(Int16::MIN_VALUE)))))?;
+        t(Some(Value::Int16(Some(Int16::Missing))))?;
+        t(Some(Value::Int16Array(vec![
+            377,
+            i16::from(Int16::EndOfVector),
+        ])))?;
+
+        t(Some(Value::Int32(Some(Int32::Value(Int32::MAX_VALUE)))))?;
+        t(Some(Value::Int32(Some(Int32::EndOfVector))))?;
+        t(Some(Value::Int32Array(vec![
+            75025,
+            i32::from(Int32::Missing),
+        ])))?;
+
+        t(Some(Value::Float(Some(Float::Value(0.5)))))?;
+        t(Some(Value::Float(Some(Float::Missing))))?;
+        t(Some(Value::Float(Some(Float::EndOfVector))))?;
+
+        t(Some(Value::String(None)))?;
+        t(Some(Value::String(Some(String::from("ndls")))))?;
+        t(Some(Value::String(Some("n".repeat(21)))))?;
+
+        Ok(())
+    }
+}
@@ -1,9 +1,17 @@
+/// A BCF record 32-bit floating-point value.
+///
+/// Sentinels are signaling NaNs with particular bit patterns (§ 6.3.3 "Type encoding"). A quiet NaN
+/// (`0x7fc00000`) is a value.
 #[derive(Clone, Debug, PartialEq)]
 #[non_exhaustive]
 pub enum Float {
+    /// A value.
     Value(f32),
+    /// A missing value (`0x7f800001`).
     Missing,
+    /// The end of a vector (`0x7f800002`).
     EndOfVector,
+    /// A reserved value (`0x7f800003..=0x7f800007`).
     Reserved(f32),
 }
 
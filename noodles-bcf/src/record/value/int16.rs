@@ -1,9 +1,16 @@
+/// A BCF record 16-bit integer value.
+///
+/// The smallest values of the integer range are sentinels (§ 6.3.3 "Type encoding").
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[non_exhaustive]
 pub enum Int16 {
+    /// A value.
     Value(i16),
+    /// A missing value (`0x8000`).
     Missing,
+    /// The end of a vector (`0x8001`).
     EndOfVector,
+    /// A reserved value (`0x8002..=0x8007`).
     Reserved(i16),
 }
 
@@ -1,9 +1,16 @@
+/// A BCF record 32-bit integer value.
+///
+/// The smallest values of the integer range are sentinels (§ 6.3.3 "Type encoding").
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[non_exhaustive]
 pub enum Int32 {
+    /// A value.
     Value(i32),
+    /// A missing value (`0x80000000`).
     Missing,
+    /// The end of a vector (`0x80000001`).
     EndOfVector,
+    /// A reserved value (`0x80000002..=0x80000007`).
     Reserved(i32),
 }
 
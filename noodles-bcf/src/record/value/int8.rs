@@ -1,9 +1,16 @@
+/// A BCF record 8-bit integer value.
+///
+/// The smallest values of the integer range are sentinels (§ 6.3.3 "Type encoding").
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[non_exhaustive]
 pub enum Int8 {
+    /// A value.
     Value(i8),
+    /// A missing value (`0x80`).
     Missing,
+    /// The end of a vector (`0x81`).
     EndOfVector,
+    /// A reserved value (`0x82..=0x87`).
     Reserved(i8),
 }
 
@@ -1,8 +1,16 @@
+/// A BCF record value type.
+///
+/// Each variant holds the number of values.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum Type {
+    /// An 8-bit integer (`1`).
     Int8(usize),
+    /// A 16-bit integer (`2`).
     Int16(usize),
+    /// A 32-bit integer (`3`).
     Int32(usize),
+    /// A 32-bit floating-point (`5`).
     Float(usize),
+    /// A character string (`7`).
     String(usize),
 }
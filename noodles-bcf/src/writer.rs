@@ -1,7 +1,10 @@
+//! BCF writer.
+
+mod builder;
 mod record;
-mod string_map;
-mod value;
-mod vcf_record;
+pub(crate) mod string_map;
+pub(crate) mod value;
+pub(crate) mod vcf_record;
 
 use std::{
     ffi::CString,
@@ -12,6 +15,8 @@ use byteorder::{LittleEndian, WriteBytesExt};
 use noodles_bgzf as bgzf;
 use noodles_vcf as vcf;
 
+pub use self::builder::Builder;
+
 use super::{header::StringMaps, Record};
 
 const MAJOR: u8 = 2;
@@ -20,6 +25,7 @@ const MINOR: u8 = 2;
 /// A BCF writer.
 pub struct Writer<W> {
     inner: W,
+    validate_idx: bool,
 }
 
 impl<W> Writer<W>
@@ -82,6 +88,12 @@ where
 
     /// Writes a VCF header.
     ///
+    /// The positions (`IDX`) of INFO, FILTER, FORMAT, and contig records are set to their
+    /// positions in the string maps built from the header, i.e., [`StringMaps::from`]. This
+    /// guarantees readers build the same string maps from the written header. If the writer was
+    /// built with [`Builder::set_validate_idx`], existing inconsistent positions are an error
+    /// instead of being overwritten.
+    ///
     /// # Examples
     ///
     /// ```
@@ -96,7 +108,20 @@ where
     /// # Ok::<(), io::Error>(())
     /// ```
     pub fn write_header(&mut self, header: &vcf::Header) -> io::Result<()> {
-        write_header(&mut self.inner, header)
+        use crate::header;
+
+        let string_maps = StringMaps::from(header);
+
+        if self.validate_idx {
+            header::validate_idx(header, &string_maps)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+        }
+
+        let mut header = header.clone();
+        header::set_idx(&mut header, &string_maps)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+        write_header(&mut self.inner, &header)
     }
 
     /// Writes a record.
@@ -193,9 +218,33 @@ where
     }
 }
 
+impl Writer<bgzf::MultithreadedWriter> {
+    /// Attempts to finish the output stream.
+    ///
+    /// This flushes any remaining blocks, shuts down the BGZF encoder workers, and writes the BGZF
+    /// EOF block. It is typically only manually called to handle errors that would otherwise be
+    /// ignored when the writer is dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bcf as bcf;
+    /// let mut writer = bcf::writer::Builder::default().build_multithreaded_with_writer(io::sink());
+    /// writer.try_finish()?;
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn try_finish(&mut self) -> io::Result<()> {
+        self.inner.finish()
+    }
+}
+
 impl<W> From<W> for Writer<W> {
     fn from(inner: W) -> Self {
-        Self { inner }
+        Self {
+            inner,
+            validate_idx: false,
+        }
     }
 }
 
@@ -267,4 +316,165 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_write_header_with_idx() -> Result<(), Box<dyn std::error::Error>> {
+        use vcf::header::{
+            info::key,
+            record::value::{
+                map::{Contig, Filter, Info},
+                Map,
+            },
+        };
+
+        let header = vcf::Header::builder()
+            .add_info(key::TOTAL_DEPTH, Map::<Info>::from(&key::TOTAL_DEPTH))
+            .add_filter("q10", Map::<Filter>::new("Quality below 10"))
+            .add_contig("sq0".parse()?, Map::<Contig>::new())
+            .add_contig("sq1".parse()?, Map::<Contig>::new())
+            .build();
+
+        let mut writer = Writer::from(Vec::new());
+        writer.write_header(&header)?;
+
+        let data = writer.into_inner();
+        let text = std::str::from_utf8(&data[4..data.len() - 1])?;
+        let actual: vcf::Header = text.parse()?;
+
+        assert_eq!(actual.infos()[0].idx(), Some(1));
+        assert_eq!(actual.filters()[0].idx(), Some(2));
+        assert_eq!(actual.contigs()[0].idx(), Some(0));
+        assert_eq!(actual.contigs()[1].idx(), Some(1));
+
+        let string_maps: StringMaps = text.parse()?;
+        assert_eq!(string_maps, StringMaps::from(&header));
+
+        let mut writer = Writer {
+            inner: Vec::new(),
+            validate_idx: true,
+        };
+        assert!(writer.write_header(&header).is_ok());
+
+        let mut header = header;
+        *header.filters_mut()[0].idx_mut() = Some(1);
+        assert!(matches!(
+            writer.write_header(&header),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_vcf_record_round_trip() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::{header::StringMaps, Reader};
+
+        let raw_header = r#"##fileformat=VCFv4.3
+##INFO=<ID=AC,Number=A,Type=Integer,Description="Allele count",IDX=1>
+##INFO=<ID=DP,Number=1,Type=Integer,Description="Combined depth",IDX=2>
+##FILTER=<ID=PASS,Description="All filters passed",IDX=0>
+##FORMAT=<ID=GT,Number=1,Type=String,Description="Genotype",IDX=3>
+##FORMAT=<ID=AD,Number=R,Type=Integer,Description="Allelic depths",IDX=4>
+##contig=<ID=sq0,IDX=1>
+##contig=<ID=sq1,IDX=0>
+#CHROM	POS	ID	REF	ALT	QUAL	FILTER	INFO	FORMAT	sample0	sample1
+"#;
+
+        let header: vcf::Header = raw_header.parse()?;
+        let string_maps: StringMaps = raw_header.parse()?;
+
+        let records = [
+            "sq0\t8\tr0\tA\tC,G\t13.5\tPASS\tAC=1,.;DP=.\tGT:AD\t0/1:5,8,0\t1|2:.",
+            "sq1\t13\t.\tT\t.\t.\t.\t.\tGT\t0\t./.",
+        ]
+        .iter()
+        .map(|s| vcf::Record::try_from_str(s, &header))
+        .collect::<Result<Vec<_>, _>>()?;
+
+        let mut writer = Writer::from(Vec::new());
+        writer.write_file_format()?;
+        writer.write_header(&header)?;
+
+        for record in &records {
+            writer.write_vcf_record(&header, &string_maps, record)?;
+        }
+
+        let data = writer.into_inner();
+        let mut reader = Reader::from(&data[..]);
+        reader.read_file_format()?;
+        let actual_raw_header = reader.read_header()?;
+        let actual_string_maps: StringMaps = actual_raw_header.parse()?;
+
+        let actual = reader
+            .records()
+            .map(|result| {
+                result.and_then(|record| record.try_into_vcf_record(&header, &actual_string_maps))
+            })
+            .collect::<io::Result<Vec<_>>>()?;
+
+        assert_eq!(actual, records);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_multithreaded_with_writer() -> Result<(), Box<dyn std::error::Error>> {
+        use std::{
+            num::NonZeroUsize,
+            sync::{Arc, Mutex},
+        };
+
+        use noodles_bgzf::writer::CompressionLevel;
+
+        use crate::Reader;
+
+        #[derive(Clone, Default)]
+        struct SharedBuf(Arc<Mutex<Vec<u8>>>);
+
+        impl Write for SharedBuf {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                self.0.lock().unwrap().write(buf)
+            }
+
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let header = vcf::Header::builder()
+            .add_contig("sq0".parse()?, Default::default())
+            .build();
+
+        let record = Record::builder()
+            .set_chromosome_id(0)
+            .set_position(vcf::record::Position::from(8))
+            .set_reference_bases("A".parse()?)
+            .build()?;
+
+        let buf = SharedBuf::default();
+
+        let mut writer = Builder::default()
+            .set_compression_level(CompressionLevel::none())
+            .set_worker_count(NonZeroUsize::try_from(2)?)
+            .build_multithreaded_with_writer(buf.clone());
+
+        writer.write_file_format()?;
+        writer.write_header(&header)?;
+
+        for _ in 0..2 {
+            writer.write_record(&record)?;
+        }
+
+        writer.try_finish()?;
+
+        let data = buf.0.lock().unwrap().clone();
+        let mut reader = Reader::new(&data[..]);
+        reader.read_file_format()?;
+        reader.read_header()?;
+
+        let actual = reader.records().collect::<io::Result<Vec<_>>>()?;
+        assert_eq!(actual, [record.clone(), record]);
+
+        Ok(())
+    }
 }
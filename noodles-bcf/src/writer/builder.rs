@@ -0,0 +1,171 @@
+use std::{io::Write, num::NonZeroUsize};
+
+use noodles_bgzf::{self as bgzf, writer::CompressionLevel};
+
+use super::Writer;
+
+/// A BCF writer builder.
+#[derive(Debug, Default)]
+pub struct Builder {
+    validate_idx: bool,
+    compression_level: Option<CompressionLevel>,
+    worker_count: Option<NonZeroUsize>,
+}
+
+impl Builder {
+    /// Sets whether to validate header record positions (`IDX`).
+    ///
+    /// Regardless of this setting, [`Writer::write_header`] always writes the positions of INFO,
+    /// FILTER, FORMAT, and contig records so that the string maps of the written header are the
+    /// same as the ones built from the input header.
+    ///
+    /// When enabled, [`Writer::write_header`] instead returns an error if an input header record
+    /// has a position that is inconsistent with the string maps. See
+    /// [`crate::header::validate_idx`].
+    ///
+    /// By default, this is disabled, i.e., inconsistent positions are overwritten.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf as bcf;
+    /// let builder = bcf::writer::Builder::default().set_validate_idx(true);
+    /// ```
+    pub fn set_validate_idx(mut self, validate_idx: bool) -> Self {
+        self.validate_idx = validate_idx;
+        self
+    }
+
+    /// Sets the compression level of the BGZF encoder.
+    ///
+    /// Use [`CompressionLevel::none`] to write uncompressed BGZF blocks. These remain seekable and
+    /// indexable, unlike the output of [`Self::build_uncompressed_with_writer`].
+    ///
+    /// By default, the compression level is set to level 6.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf as bcf;
+    /// use noodles_bgzf::writer::CompressionLevel;
+    ///
+    /// let builder = bcf::writer::Builder::default()
+    ///     .set_compression_level(CompressionLevel::best());
+    /// ```
+    pub fn set_compression_level(mut self, compression_level: CompressionLevel) -> Self {
+        self.compression_level = Some(compression_level);
+        self
+    }
+
+    /// Sets the worker count of the multithreaded BGZF encoder.
+    ///
+    /// This is only used by [`Self::build_multithreaded_with_writer`].
+    ///
+    /// By default, the worker count is set to the number of available logical CPUs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use noodles_bcf as bcf;
+    ///
+    /// let worker_count = NonZeroUsize::try_from(4)?;
+    /// let builder = bcf::writer::Builder::default().set_worker_count(worker_count);
+    /// # Ok::<_, std::num::TryFromIntError>(())
+    /// ```
+    pub fn set_worker_count(mut self, worker_count: NonZeroUsize) -> Self {
+        self.worker_count = Some(worker_count);
+        self
+    }
+
+    /// Builds a BCF writer from a writer.
+    ///
+    /// The given stream is wrapped in a BGZF encoder.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bcf as bcf;
+    /// let writer = bcf::writer::Builder::default().build_with_writer(io::sink());
+    /// ```
+    pub fn build_with_writer<W>(self, writer: W) -> Writer<bgzf::Writer<W>>
+    where
+        W: Write,
+    {
+        let mut builder = bgzf::writer::Builder::default();
+
+        if let Some(compression_level) = self.compression_level {
+            builder = builder.set_compression_level(compression_level);
+        }
+
+        Writer {
+            inner: builder.build_with_writer(writer),
+            validate_idx: self.validate_idx,
+        }
+    }
+
+    /// Builds an uncompressed BCF writer from a writer.
+    ///
+    /// The output is not BGZF-encoded, i.e., this writes a raw BCF stream (`bcftools view -Ou`).
+    /// This is typically used to pass records between processes using a pipe without the overhead
+    /// of compression. The compression level and worker count are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bcf as bcf;
+    ///
+    /// let mut writer = bcf::writer::Builder::default().build_uncompressed_with_writer(Vec::new());
+    /// writer.write_file_format()?;
+    ///
+    /// assert_eq!(writer.get_ref(), b"BCF\x02\x02");
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn build_uncompressed_with_writer<W>(self, writer: W) -> Writer<W>
+    where
+        W: Write,
+    {
+        Writer {
+            inner: writer,
+            validate_idx: self.validate_idx,
+        }
+    }
+
+    /// Builds a BCF writer from a writer using a multithreaded BGZF encoder.
+    ///
+    /// Blocks are compressed in parallel by [`Self::set_worker_count`] workers. The writer must be
+    /// finished ([`Writer::try_finish`]) or dropped to flush the remaining blocks.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bcf as bcf;
+    ///
+    /// let mut writer = bcf::writer::Builder::default().build_multithreaded_with_writer(io::sink());
+    /// writer.try_finish()?;
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn build_multithreaded_with_writer<W>(self, writer: W) -> Writer<bgzf::MultithreadedWriter>
+    where
+        W: Write + Send + 'static,
+    {
+        let mut builder = bgzf::multithreaded_writer::Builder::default();
+
+        if let Some(compression_level) = self.compression_level {
+            builder = builder.set_compression_level(compression_level);
+        }
+
+        if let Some(worker_count) = self.worker_count {
+            builder = builder.set_worker_count(worker_count);
+        }
+
+        Writer {
+            inner: builder.build_with_writer(writer),
+            validate_idx: self.validate_idx,
+        }
+    }
+}
@@ -719,10 +719,10 @@ where
         for n in raw_value {
             let m = u8::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
             writer.write_all(&[m])?;
+        }
 
-            for _ in 0..pad {
-                writer.write_all(&[i8::from(Int8::EndOfVector) as u8])?;
-            }
+        for _ in 0..pad {
+            writer.write_all(&[i8::from(Int8::EndOfVector) as u8])?;
         }
     }
 
@@ -1318,6 +1318,21 @@ mod tests {
 
         assert_eq!(buf, expected);
 
+        let value_0 = Value::String(String::from("0/1"));
+        let value_1 = Value::String(String::from("0/1/2"));
+        let values = [Some(&value_0), Some(&value_1)];
+
+        buf.clear();
+        write_genotype_genotype_field_values(&mut buf, &values)?;
+
+        let expected = [
+            0x31, // Some(Type::Int8(3))
+            0x02, 0x04, 0x81, // "0/1"
+            0x02, 0x04, 0x06, // "0/1/2"
+        ];
+
+        assert_eq!(buf, expected);
+
         Ok(())
     }
 
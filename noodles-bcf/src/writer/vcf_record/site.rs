@@ -1,4 +1,4 @@
-mod info;
+pub(crate) mod info;
 
 use std::io::{self, Write};
 
@@ -52,7 +52,7 @@ where
     write_id(writer, record.ids())?;
     write_ref_alt(writer, record.reference_bases(), record.alternate_bases())?;
     write_filter(writer, string_maps.strings(), record.filters())?;
-    write_info(writer, string_maps.strings(), record.info())?;
+    write_info(writer, header.infos(), string_maps.strings(), record.info())?;
 
     Ok(())
 }
@@ -67,21 +67,23 @@ where
 {
     use vcf::record::Chromosome;
 
-    let chrom = match chromosome {
-        Chromosome::Name(name) => contig_string_map
-            .get_index_of(name)
-            .ok_or_else(|| {
-                io::Error::new(
-                    io::ErrorKind::InvalidInput,
-                    format!("chromosome not in string map: {name}"),
-                )
-            })
-            .and_then(|i| {
-                i32::try_from(i).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))
-            })?,
-        Chromosome::Symbol(_) => todo!("unhandled chromosome: {:?}", chromosome),
+    let name = match chromosome {
+        Chromosome::Name(name) => name.into(),
+        Chromosome::Symbol(_) => chromosome.to_string(),
     };
 
+    let chrom = contig_string_map
+        .get_index_of(&name)
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("chromosome not in string map: {name}"),
+            )
+        })
+        .and_then(|i| {
+            i32::try_from(i).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))
+        })?;
+
     writer.write_i32::<LittleEndian>(chrom)
 }
 
@@ -267,6 +269,7 @@ mod tests {
         let mut contig_string_map = ContigStringMap::default();
         contig_string_map.insert("sq0".into());
         contig_string_map.insert("sq1".into());
+        contig_string_map.insert("<sq2>".into());
 
         t(
             &mut buf,
@@ -282,6 +285,19 @@ mod tests {
             &[0x01, 0x00, 0x00, 0x00],
         )?;
 
+        t(
+            &mut buf,
+            &contig_string_map,
+            &"<sq2>".parse()?,
+            &[0x02, 0x00, 0x00, 0x00],
+        )?;
+
+        buf.clear();
+        assert!(matches!(
+            write_chrom(&mut buf, &contig_string_map, &"sq3".parse()?),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
         Ok(())
     }
 
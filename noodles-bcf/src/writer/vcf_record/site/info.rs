@@ -16,6 +16,7 @@ const DELIMITER: char = ',';
 
 pub fn write_info<W>(
     writer: &mut W,
+    infos: &vcf::header::Infos,
     string_string_map: &StringStringMap,
     info: &vcf::record::Info,
 ) -> io::Result<()>
@@ -23,7 +24,7 @@ where
     W: Write,
 {
     for (key, value) in info.as_ref() {
-        write_info_field(writer, string_string_map, key, value.as_ref())?;
+        write_info_field(writer, infos, string_string_map, key, value.as_ref())?;
     }
 
     Ok(())
@@ -31,6 +32,7 @@ where
 
 fn write_info_field<W>(
     writer: &mut W,
+    infos: &vcf::header::Infos,
     string_string_map: &StringStringMap,
     key: &vcf::header::info::Key,
     value: Option<&vcf::record::info::field::Value>,
@@ -39,7 +41,21 @@ where
     W: Write,
 {
     write_info_field_key(writer, string_string_map, key)?;
-    write_info_field_value(writer, value)?;
+
+    match value {
+        Some(v) => write_info_field_value(writer, v)?,
+        None => {
+            let info = infos.get(key).ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("missing header INFO record for {key}"),
+                )
+            })?;
+
+            write_info_field_missing_value(writer, info.ty())?;
+        }
+    }
+
     Ok(())
 }
 
@@ -62,9 +78,9 @@ where
         .and_then(|i| write_string_map_index(writer, i))
 }
 
-fn write_info_field_value<W>(
+pub(crate) fn write_info_field_value<W>(
     writer: &mut W,
-    value: Option<&vcf::record::info::field::Value>,
+    value: &vcf::record::info::field::Value,
 ) -> io::Result<()>
 where
     W: Write,
@@ -72,24 +88,34 @@ where
     use vcf::record::info::field;
 
     match value {
-        Some(field::Value::Integer(n)) => write_info_field_integer_value(writer, *n),
-        Some(field::Value::Float(n)) => write_info_field_float_value(writer, *n),
-        Some(field::Value::Flag) => write_info_field_flag_value(writer),
-        Some(field::Value::Character(c)) => write_info_field_character_value(writer, *c),
-        Some(field::Value::String(s)) => write_info_field_string_value(writer, s),
-        Some(field::Value::IntegerArray(values)) => {
-            write_info_field_integer_array_value(writer, values)
-        }
-        Some(field::Value::FloatArray(values)) => {
-            write_info_field_float_array_value(writer, values)
-        }
-        Some(field::Value::CharacterArray(values)) => {
+        field::Value::Integer(n) => write_info_field_integer_value(writer, *n),
+        field::Value::Float(n) => write_info_field_float_value(writer, *n),
+        field::Value::Flag => write_info_field_flag_value(writer),
+        field::Value::Character(c) => write_info_field_character_value(writer, *c),
+        field::Value::String(s) => write_info_field_string_value(writer, s),
+        field::Value::IntegerArray(values) => write_info_field_integer_array_value(writer, values),
+        field::Value::FloatArray(values) => write_info_field_float_array_value(writer, values),
+        field::Value::CharacterArray(values) => {
             write_info_field_character_array_value(writer, values)
         }
-        Some(field::Value::StringArray(values)) => {
-            write_info_field_string_array_value(writer, values)
-        }
-        _ => todo!("unhandled INFO field value: {:?}", value),
+        field::Value::StringArray(values) => write_info_field_string_array_value(writer, values),
+    }
+}
+
+fn write_info_field_missing_value<W>(
+    writer: &mut W,
+    ty: vcf::header::record::value::map::info::Type,
+) -> io::Result<()>
+where
+    W: Write,
+{
+    use vcf::header::record::value::map::info::Type;
+
+    match ty {
+        Type::Integer => write_value(writer, Some(Value::Int8(Some(Int8::Missing)))),
+        Type::Float => write_value(writer, Some(Value::Float(Some(Float::Missing)))),
+        Type::Flag => write_info_field_flag_value(writer),
+        Type::Character | Type::String => write_value(writer, Some(Value::String(None))),
     }
 }
 
@@ -207,7 +233,7 @@ where
         let n = match v {
             Int8::Value(n) => n,
             Int8::Missing => i8::from(v),
-            _ => todo!("unhandled i16 array value: {:?}", v),
+            _ => return Err(invalid_array_value_error(v)),
         };
 
         vs.push(n);
@@ -233,7 +259,7 @@ where
         let n = match v {
             Int16::Value(n) => n,
             Int16::Missing => i16::from(v),
-            _ => todo!("unhandled i16 array value: {:?}", v),
+            _ => return Err(invalid_array_value_error(v)),
         };
 
         vs.push(n);
@@ -250,11 +276,11 @@ where
         .iter()
         .map(|value| value.map(Int32::from).unwrap_or(Int32::Missing))
         .map(|value| match value {
-            Int32::Value(n) => n,
-            Int32::Missing => i32::from(value),
-            _ => todo!("unhandled i32 array value: {:?}", value),
+            Int32::Value(n) => Ok(n),
+            Int32::Missing => Ok(i32::from(value)),
+            _ => Err(invalid_array_value_error(value)),
         })
-        .collect();
+        .collect::<io::Result<_>>()?;
 
     write_value(writer, Some(Value::Int32Array(vs)))
 }
@@ -267,11 +293,11 @@ where
         .iter()
         .map(|value| value.map(Float::from).unwrap_or(Float::Missing))
         .map(|value| match value {
-            Float::Value(n) => n,
-            Float::Missing => f32::from(value),
-            _ => todo!("unhandled f32 array value: {:?}", value),
+            Float::Value(n) => Ok(n),
+            Float::Missing => Ok(f32::from(value)),
+            _ => Err(invalid_array_value_error(value)),
         })
-        .collect();
+        .collect::<io::Result<_>>()?;
 
     write_value(writer, Some(Value::FloatArray(vs)))
 }
@@ -321,6 +347,16 @@ where
     write_value(writer, Some(Value::String(Some(s))))
 }
 
+fn invalid_array_value_error<T>(value: T) -> io::Error
+where
+    T: std::fmt::Debug,
+{
+    io::Error::new(
+        io::ErrorKind::InvalidInput,
+        format!("invalid info field array value: {value:?}"),
+    )
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -331,7 +367,7 @@ mod test {
 
         fn t(buf: &mut Vec<u8>, value: &field::Value, expected: &[u8]) -> io::Result<()> {
             buf.clear();
-            write_info_field_value(buf, Some(value))?;
+            write_info_field_value(buf, value)?;
             assert_eq!(buf, expected);
             Ok(())
         }
@@ -341,7 +377,7 @@ mod test {
         let value = field::Value::Integer(-2147483641);
         buf.clear();
         assert!(matches!(
-            write_info_field_value(&mut buf, Some(&value)),
+            write_info_field_value(&mut buf, &value),
             Err(ref e) if e.kind() == io::ErrorKind::InvalidInput
         ));
 
@@ -381,13 +417,35 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_write_info_field_missing_value() -> io::Result<()> {
+        use vcf::header::record::value::map::info::Type;
+
+        fn t(buf: &mut Vec<u8>, ty: Type, expected: &[u8]) -> io::Result<()> {
+            buf.clear();
+            write_info_field_missing_value(buf, ty)?;
+            assert_eq!(buf, expected);
+            Ok(())
+        }
+
+        let mut buf = Vec::new();
+
+        t(&mut buf, Type::Integer, &[0x11, 0x80])?;
+        t(&mut buf, Type::Float, &[0x15, 0x01, 0x00, 0x80, 0x7f])?;
+        t(&mut buf, Type::Flag, &[0x00])?;
+        t(&mut buf, Type::Character, &[0x07])?;
+        t(&mut buf, Type::String, &[0x07])?;
+
+        Ok(())
+    }
+
     #[test]
     fn test_write_info_field_value_with_float_value() -> io::Result<()> {
         use vcf::record::info::field;
 
         let mut buf = Vec::new();
         let value = field::Value::Float(0.0);
-        write_info_field_value(&mut buf, Some(&value))?;
+        write_info_field_value(&mut buf, &value)?;
 
         let expected = [0x15, 0x00, 0x00, 0x00, 0x00];
 
@@ -402,7 +460,7 @@ mod test {
 
         let mut buf = Vec::new();
         let value = field::Value::Flag;
-        write_info_field_value(&mut buf, Some(&value))?;
+        write_info_field_value(&mut buf, &value)?;
 
         let expected = [0x00];
 
@@ -417,7 +475,7 @@ mod test {
 
         let mut buf = Vec::new();
         let value = field::Value::Character('n');
-        write_info_field_value(&mut buf, Some(&value))?;
+        write_info_field_value(&mut buf, &value)?;
 
         let expected = [0x17, 0x6e];
 
@@ -432,7 +490,7 @@ mod test {
 
         let mut buf = Vec::new();
         let value = field::Value::String(String::from("ndls"));
-        write_info_field_value(&mut buf, Some(&value))?;
+        write_info_field_value(&mut buf, &value)?;
 
         let expected = [0x47, 0x6e, 0x64, 0x6c, 0x73];
 
@@ -445,7 +503,7 @@ mod test {
     fn test_write_info_field_value_with_integer_array_value() -> io::Result<()> {
         use vcf::record::info::field;
 
-        fn t(buf: &mut Vec<u8>, value: Option<&field::Value>, expected: &[u8]) -> io::Result<()> {
+        fn t(buf: &mut Vec<u8>, value: &field::Value, expected: &[u8]) -> io::Result<()> {
             buf.clear();
             write_info_field_value(buf, value)?;
             assert_eq!(buf, expected);
@@ -457,96 +515,96 @@ mod test {
         let value = field::Value::IntegerArray(vec![Some(-2147483641), Some(-2147483640)]);
         buf.clear();
         assert!(matches!(
-            write_info_field_value(&mut buf, Some(&value)),
+            write_info_field_value(&mut buf, &value),
             Err(ref e) if e.kind() == io::ErrorKind::InvalidInput
         ));
 
         let value = field::Value::IntegerArray(vec![Some(-2147483640), Some(-2147483639)]);
         t(
             &mut buf,
-            Some(&value),
+            &value,
             &[0x23, 0x08, 0x00, 0x00, 0x80, 0x09, 0x00, 0x00, 0x80],
         )?;
         let value = field::Value::IntegerArray(vec![Some(-2147483640), None]);
         t(
             &mut buf,
-            Some(&value),
+            &value,
             &[0x23, 0x08, 0x00, 0x00, 0x80, 0x00, 0x00, 0x00, 0x80],
         )?;
 
         let value = field::Value::IntegerArray(vec![Some(-32761), Some(-32760)]);
         t(
             &mut buf,
-            Some(&value),
+            &value,
             &[0x23, 0x07, 0x80, 0xff, 0xff, 0x08, 0x80, 0xff, 0xff],
         )?;
         let value = field::Value::IntegerArray(vec![Some(-32761), None]);
         t(
             &mut buf,
-            Some(&value),
+            &value,
             &[0x23, 0x07, 0x80, 0xff, 0xff, 0x00, 0x00, 0x00, 0x80],
         )?;
 
         let value = field::Value::IntegerArray(vec![Some(-32760), Some(-32759)]);
-        t(&mut buf, Some(&value), &[0x22, 0x08, 0x80, 0x09, 0x80])?;
+        t(&mut buf, &value, &[0x22, 0x08, 0x80, 0x09, 0x80])?;
         let value = field::Value::IntegerArray(vec![Some(-32760), None]);
-        t(&mut buf, Some(&value), &[0x22, 0x08, 0x80, 0x00, 0x80])?;
+        t(&mut buf, &value, &[0x22, 0x08, 0x80, 0x00, 0x80])?;
 
         let value = field::Value::IntegerArray(vec![Some(-121), Some(-120)]);
-        t(&mut buf, Some(&value), &[0x22, 0x87, 0xff, 0x88, 0xff])?;
+        t(&mut buf, &value, &[0x22, 0x87, 0xff, 0x88, 0xff])?;
         let value = field::Value::IntegerArray(vec![Some(-121), None]);
-        t(&mut buf, Some(&value), &[0x22, 0x87, 0xff, 0x00, 0x80])?;
+        t(&mut buf, &value, &[0x22, 0x87, 0xff, 0x00, 0x80])?;
 
         let value = field::Value::IntegerArray(vec![Some(-120), Some(-119)]);
-        t(&mut buf, Some(&value), &[0x21, 0x88, 0x89])?;
+        t(&mut buf, &value, &[0x21, 0x88, 0x89])?;
         let value = field::Value::IntegerArray(vec![Some(-120), None]);
-        t(&mut buf, Some(&value), &[0x21, 0x88, 0x80])?;
+        t(&mut buf, &value, &[0x21, 0x88, 0x80])?;
 
         let value = field::Value::IntegerArray(vec![None, Some(0), Some(1)]);
-        t(&mut buf, Some(&value), &[0x31, 0x80, 0x00, 0x01])?;
+        t(&mut buf, &value, &[0x31, 0x80, 0x00, 0x01])?;
         let value = field::Value::IntegerArray(vec![Some(-1), Some(0), Some(1)]);
-        t(&mut buf, Some(&value), &[0x31, 0xff, 0x00, 0x01])?;
+        t(&mut buf, &value, &[0x31, 0xff, 0x00, 0x01])?;
         let value = field::Value::IntegerArray(vec![Some(-1), Some(0), None]);
-        t(&mut buf, Some(&value), &[0x31, 0xff, 0x00, 0x80])?;
+        t(&mut buf, &value, &[0x31, 0xff, 0x00, 0x80])?;
 
         let value = field::Value::IntegerArray(vec![Some(126), Some(127)]);
-        t(&mut buf, Some(&value), &[0x21, 0x7e, 0x7f])?;
+        t(&mut buf, &value, &[0x21, 0x7e, 0x7f])?;
         let value = field::Value::IntegerArray(vec![None, Some(127)]);
-        t(&mut buf, Some(&value), &[0x21, 0x80, 0x7f])?;
+        t(&mut buf, &value, &[0x21, 0x80, 0x7f])?;
 
         let value = field::Value::IntegerArray(vec![Some(127), Some(128)]);
-        t(&mut buf, Some(&value), &[0x22, 0x7f, 0x00, 0x80, 0x00])?;
+        t(&mut buf, &value, &[0x22, 0x7f, 0x00, 0x80, 0x00])?;
         let value = field::Value::IntegerArray(vec![None, Some(128)]);
-        t(&mut buf, Some(&value), &[0x22, 0x00, 0x80, 0x80, 0x00])?;
+        t(&mut buf, &value, &[0x22, 0x00, 0x80, 0x80, 0x00])?;
 
         let value = field::Value::IntegerArray(vec![Some(32766), Some(32767)]);
-        t(&mut buf, Some(&value), &[0x22, 0xfe, 0x7f, 0xff, 0x7f])?;
+        t(&mut buf, &value, &[0x22, 0xfe, 0x7f, 0xff, 0x7f])?;
         let value = field::Value::IntegerArray(vec![None, Some(32767)]);
-        t(&mut buf, Some(&value), &[0x22, 0x00, 0x80, 0xff, 0x7f])?;
+        t(&mut buf, &value, &[0x22, 0x00, 0x80, 0xff, 0x7f])?;
 
         let value = field::Value::IntegerArray(vec![Some(32767), Some(32768)]);
         t(
             &mut buf,
-            Some(&value),
+            &value,
             &[0x23, 0xff, 0x7f, 0x00, 0x00, 0x00, 0x80, 0x00, 0x00],
         )?;
         let value = field::Value::IntegerArray(vec![None, Some(32768)]);
         t(
             &mut buf,
-            Some(&value),
+            &value,
             &[0x23, 0x00, 0x00, 0x00, 0x80, 0x00, 0x80, 0x00, 0x00],
         )?;
 
         let value = field::Value::IntegerArray(vec![Some(2147483646), Some(2147483647)]);
         t(
             &mut buf,
-            Some(&value),
+            &value,
             &[0x23, 0xfe, 0xff, 0xff, 0x7f, 0xff, 0xff, 0xff, 0x7f],
         )?;
         let value = field::Value::IntegerArray(vec![None, Some(2147483647)]);
         t(
             &mut buf,
-            Some(&value),
+            &value,
             &[0x23, 0x00, 0x00, 0x00, 0x80, 0xff, 0xff, 0xff, 0x7f],
         )?;
 
@@ -557,7 +615,7 @@ mod test {
     fn test_write_info_field_value_with_float_array_value() -> io::Result<()> {
         use vcf::record::info::field;
 
-        fn t(buf: &mut Vec<u8>, value: Option<&field::Value>, expected: &[u8]) -> io::Result<()> {
+        fn t(buf: &mut Vec<u8>, value: &field::Value, expected: &[u8]) -> io::Result<()> {
             buf.clear();
             write_info_field_value(buf, value)?;
             assert_eq!(buf, expected);
@@ -569,14 +627,14 @@ mod test {
         let value = field::Value::FloatArray(vec![Some(0.0), Some(1.0)]);
         t(
             &mut buf,
-            Some(&value),
+            &value,
             &[0x25, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0x3f],
         )?;
 
         let value = field::Value::FloatArray(vec![Some(0.0), None]);
         t(
             &mut buf,
-            Some(&value),
+            &value,
             &[0x25, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x80, 0x7f],
         )?;
 
@@ -587,7 +645,7 @@ mod test {
     fn test_write_info_field_value_with_character_array_value() -> io::Result<()> {
         use vcf::record::info::field;
 
-        fn t(buf: &mut Vec<u8>, value: Option<&field::Value>, expected: &[u8]) -> io::Result<()> {
+        fn t(buf: &mut Vec<u8>, value: &field::Value, expected: &[u8]) -> io::Result<()> {
             buf.clear();
             write_info_field_value(buf, value)?;
             assert_eq!(buf, expected);
@@ -599,14 +657,14 @@ mod test {
         let value = field::Value::CharacterArray(vec![Some('n'), Some('d'), Some('l'), Some('s')]);
         t(
             &mut buf,
-            Some(&value),
+            &value,
             &[0x77, 0x6e, 0x2c, 0x64, 0x2c, 0x6c, 0x2c, 0x73],
         )?;
 
         let value = field::Value::CharacterArray(vec![Some('n'), Some('d'), Some('l'), None]);
         t(
             &mut buf,
-            Some(&value),
+            &value,
             &[0x77, 0x6e, 0x2c, 0x64, 0x2c, 0x6c, 0x2c, 0x2e],
         )?;
 
@@ -617,7 +675,7 @@ mod test {
     fn test_write_info_field_value_with_string_array_value() -> io::Result<()> {
         use vcf::record::info::field;
 
-        fn t(buf: &mut Vec<u8>, value: Option<&field::Value>, expected: &[u8]) -> io::Result<()> {
+        fn t(buf: &mut Vec<u8>, value: &field::Value, expected: &[u8]) -> io::Result<()> {
             buf.clear();
             write_info_field_value(buf, value)?;
             assert_eq!(buf, expected);
@@ -628,14 +686,10 @@ mod test {
 
         let value =
             field::Value::StringArray(vec![Some(String::from("nd")), Some(String::from("ls"))]);
-        t(
-            &mut buf,
-            Some(&value),
-            &[0x57, 0x6e, 0x64, 0x2c, 0x6c, 0x73],
-        )?;
+        t(&mut buf, &value, &[0x57, 0x6e, 0x64, 0x2c, 0x6c, 0x73])?;
 
         let value = field::Value::StringArray(vec![Some(String::from("nd")), None]);
-        t(&mut buf, Some(&value), &[0x47, 0x6e, 0x64, 0x2c, 0x2e])?;
+        t(&mut buf, &value, &[0x47, 0x6e, 0x64, 0x2c, 0x2e])?;
 
         Ok(())
     }
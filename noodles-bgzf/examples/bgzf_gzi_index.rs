@@ -0,0 +1,21 @@
+//! Builds a GZ index from a BGZF file.
+//!
+//! This writes the output to stdout rather than `<src>.gzi`.
+//!
+//! The result matches the output of `bgzip --reindex <src>`.
+
+use std::{env, fs::File, io};
+
+use noodles_bgzf::gzi;
+
+fn main() -> io::Result<()> {
+    let src = env::args().nth(1).expect("missing src");
+
+    let index = File::open(src).and_then(gzi::index)?;
+
+    let stdout = io::stdout().lock();
+    let mut writer = gzi::Writer::new(stdout);
+    writer.write_index(&index)?;
+
+    Ok(())
+}
@@ -97,22 +97,31 @@ where
 
         let mut stream = blocks.try_buffered(self.worker_count.get());
 
-        self.block = match stream.try_next().await? {
+        let result = stream.try_next().await;
+        self.stream.replace(stream);
+
+        self.block = match result? {
             Some(mut block) => {
                 let (cpos, upos) = pos.into();
+                let upos = usize::from(upos);
+
+                if upos > block.data().len() {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidInput,
+                        format!("invalid virtual position: {cpos}:{upos}"),
+                    ));
+                }
 
                 self.position = cpos + block.size();
 
                 block.set_position(cpos);
-                block.data_mut().set_position(usize::from(upos));
+                block.data_mut().set_position(upos);
 
                 block
             }
             None => Block::default(),
         };
 
-        self.stream.replace(stream);
-
         Ok(pos)
     }
 }
@@ -39,5 +39,9 @@ impl Future for Deflate {
 
 fn deflate(data: BytesMut, compression_level: CompressionLevel) -> io::Result<GzData> {
     use crate::writer::deflate_data;
-    deflate_data(&data, compression_level)
+
+    let mut cdata = Vec::new();
+    let (crc32, r#isize) = deflate_data(&data, compression_level, &mut cdata)?;
+
+    Ok((cdata, crc32, r#isize))
 }
@@ -45,6 +45,12 @@ impl Block {
         }
     }
 
+    /// Returns whether the block is an EOF marker, i.e., an empty 28-byte block.
+    pub fn is_eof_marker(&self) -> bool {
+        use crate::writer::BGZF_EOF;
+        self.size == BGZF_EOF.len() as u64 && self.data.len() == 0
+    }
+
     pub fn data(&self) -> &Data {
         &self.data
     }
@@ -75,4 +81,16 @@ mod tests {
         block.data_mut().set_position(4);
         assert_eq!(block.virtual_position(), VirtualPosition::from(1376256));
     }
+
+    #[test]
+    fn test_is_eof_marker() {
+        let mut block = Block::default();
+        assert!(!block.is_eof_marker());
+
+        block.set_size(28);
+        assert!(block.is_eof_marker());
+
+        block.data_mut().resize(4);
+        assert!(!block.is_eof_marker());
+    }
 }
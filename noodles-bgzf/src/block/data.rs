@@ -39,6 +39,18 @@ impl Data {
     }
 }
 
+impl From<Vec<u8>> for Data {
+    fn from(buf: Vec<u8>) -> Self {
+        Self { buf, pos: 0 }
+    }
+}
+
+impl From<Data> for Vec<u8> {
+    fn from(data: Data) -> Self {
+        data.buf
+    }
+}
+
 impl AsRef<[u8]> for Data {
     fn as_ref(&self) -> &[u8] {
         &self.buf[self.pos..]
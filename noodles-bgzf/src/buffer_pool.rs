@@ -0,0 +1,123 @@
+//! BGZF buffer pool.
+
+use crossbeam_channel::{Receiver, Sender};
+
+/// A pool of reusable byte buffers.
+///
+/// Multithreaded readers and writers take compressed and uncompressed block buffers from a pool
+/// and return them after use rather than allocating a buffer per block. A pool is cheap to clone,
+/// and clones share the same buffers, so a single pool can be given to multiple readers and
+/// writers, e.g., when recompressing a stream.
+///
+/// Returned buffers are cleared but keep their capacity.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_bgzf as bgzf;
+///
+/// let pool = bgzf::BufferPool::default();
+///
+/// let buf = pool.get();
+/// assert!(buf.is_empty());
+///
+/// pool.put(buf);
+/// assert_eq!(pool.len(), 1);
+/// ```
+#[derive(Clone, Debug)]
+pub struct BufferPool {
+    tx: Sender<Vec<u8>>,
+    rx: Receiver<Vec<u8>>,
+}
+
+impl BufferPool {
+    /// Creates a buffer pool that holds at most the given number of idle buffers.
+    ///
+    /// Buffers returned to a full pool are dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    ///
+    /// let pool = bgzf::BufferPool::new(1);
+    /// pool.put(Vec::new());
+    /// pool.put(Vec::new());
+    ///
+    /// assert_eq!(pool.len(), 1);
+    /// ```
+    pub fn new(capacity: usize) -> Self {
+        let (tx, rx) = crossbeam_channel::bounded(capacity);
+        Self { tx, rx }
+    }
+
+    /// Takes a buffer from the pool.
+    ///
+    /// If the pool is empty, this returns a new buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    ///
+    /// let pool = bgzf::BufferPool::default();
+    /// pool.put(Vec::with_capacity(8));
+    ///
+    /// let buf = pool.get();
+    /// assert!(buf.capacity() >= 8);
+    /// ```
+    pub fn get(&self) -> Vec<u8> {
+        self.rx.try_recv().unwrap_or_default()
+    }
+
+    /// Returns a buffer to the pool.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    ///
+    /// let pool = bgzf::BufferPool::default();
+    /// pool.put(b"noodles".to_vec());
+    ///
+    /// assert!(pool.get().is_empty());
+    /// ```
+    pub fn put(&self, mut buf: Vec<u8>) {
+        buf.clear();
+        self.tx.try_send(buf).ok();
+    }
+
+    /// Returns the number of idle buffers in the pool.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// let pool = bgzf::BufferPool::default();
+    /// assert_eq!(pool.len(), 0);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.rx.len()
+    }
+
+    /// Returns whether the pool has no idle buffers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// let pool = bgzf::BufferPool::default();
+    /// assert!(pool.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.rx.is_empty()
+    }
+}
+
+impl Default for BufferPool {
+    /// Creates a buffer pool with no limit on the number of idle buffers.
+    fn default() -> Self {
+        let (tx, rx) = crossbeam_channel::unbounded();
+        Self { tx, rx }
+    }
+}
@@ -0,0 +1,143 @@
+use std::io::{self, Read};
+
+use super::{reader::block::single, Block};
+
+/// Checks the integrity of a BGZF stream.
+///
+/// This reads the entire stream and inflates every block, verifying each block's header, data
+/// checksum (`CRC32`), and uncompressed data size (`ISIZE`). The stream must also end with an EOF
+/// marker.
+///
+/// This is similar to `bgzip --test`.
+///
+/// # Errors
+///
+/// An error is returned at the first invalid block. The message includes the compressed position
+/// of the block. If the stream does not end with an EOF marker, an error of kind
+/// [`io::ErrorKind::UnexpectedEof`] is returned.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io::{self, Write};
+/// use noodles_bgzf as bgzf;
+///
+/// let mut writer = bgzf::Writer::new(Vec::new());
+/// writer.write_all(b"noodles")?;
+/// let data = writer.finish()?;
+///
+/// bgzf::check(&data[..])?;
+///
+/// let truncated_data = &data[..data.len() - 1];
+/// assert!(bgzf::check(truncated_data).is_err());
+/// # Ok::<_, io::Error>(())
+/// ```
+pub fn check<R>(reader: R) -> io::Result<()>
+where
+    R: Read,
+{
+    let mut reader = single::Reader::new(reader);
+    let mut block = Block::default();
+    let mut position = 0;
+    let mut is_eof_marker = false;
+
+    loop {
+        match reader.next_block(&mut block) {
+            Ok(Some(())) => {
+                position += block.size();
+                is_eof_marker = block.is_eof_marker();
+            }
+            Ok(None) => break,
+            Err(e) => {
+                return Err(io::Error::new(
+                    e.kind(),
+                    format!("invalid block at position {position}: {e}"),
+                ))
+            }
+        }
+    }
+
+    if is_eof_marker {
+        Ok(())
+    } else {
+        Err(io::Error::new(
+            io::ErrorKind::UnexpectedEof,
+            "missing BGZF EOF marker",
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use super::*;
+    use crate::{writer::BGZF_EOF, Writer};
+
+    fn build_data() -> io::Result<Vec<u8>> {
+        let mut writer = Writer::new(Vec::new());
+        writer.write_all(b"noodles")?;
+        writer.flush()?;
+        writer.write_all(b"bgzf")?;
+        writer.finish()
+    }
+
+    #[test]
+    fn test_check() -> io::Result<()> {
+        let data = build_data()?;
+        check(&data[..])?;
+
+        check(BGZF_EOF)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_check_with_missing_eof_marker() -> io::Result<()> {
+        let data = build_data()?;
+        let src = &data[..data.len() - BGZF_EOF.len()];
+
+        assert!(matches!(
+            check(src),
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof
+        ));
+
+        assert!(matches!(
+            check(io::empty()),
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_check_with_invalid_block() -> io::Result<()> {
+        let data = build_data()?;
+        let first_block_size = usize::from(u16::from_le_bytes([data[16], data[17]])) + 1;
+        let second_block_end = data.len() - BGZF_EOF.len();
+
+        // CRC32
+        let mut src = data.clone();
+        src[second_block_end - 8] ^= 0xff;
+
+        let e = check(&src[..]).unwrap_err();
+        assert_eq!(e.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(
+            e.to_string(),
+            format!("invalid block at position {first_block_size}: block data checksum mismatch")
+        );
+
+        // ISIZE
+        let mut src = data;
+        src[first_block_size - 4] += 1;
+
+        let e = check(&src[..]).unwrap_err();
+        assert_eq!(e.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(
+            e.to_string(),
+            "invalid block at position 0: block data size mismatch"
+        );
+
+        Ok(())
+    }
+}
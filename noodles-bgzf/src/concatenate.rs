@@ -0,0 +1,88 @@
+use std::io::{self, Read, Write};
+
+use super::{reader::RawBlocks, writer::BGZF_EOF};
+
+/// Concatenates BGZF streams.
+///
+/// Blocks are copied as-is, i.e., without being inflated or recompressed. EOF markers in the input
+/// streams are removed, and a single EOF marker is written at the end of the output stream.
+///
+/// This is similar to `samtools cat` or concatenating files and removing the intermediate EOF
+/// markers.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io::{self, Read, Write};
+/// use noodles_bgzf as bgzf;
+///
+/// let mut writer = bgzf::Writer::new(Vec::new());
+/// writer.write_all(b"noodles")?;
+/// let a = writer.finish()?;
+///
+/// let mut writer = bgzf::Writer::new(Vec::new());
+/// writer.write_all(b"-bgzf")?;
+/// let b = writer.finish()?;
+///
+/// let mut data = Vec::new();
+/// bgzf::concatenate([&a[..], &b[..]], &mut data)?;
+///
+/// let mut reader = bgzf::Reader::new(&data[..]);
+/// let mut buf = Vec::new();
+/// reader.read_to_end(&mut buf)?;
+/// assert_eq!(buf, b"noodles-bgzf");
+/// # Ok::<_, io::Error>(())
+/// ```
+pub fn concatenate<I, R, W>(readers: I, writer: &mut W) -> io::Result<()>
+where
+    I: IntoIterator<Item = R>,
+    R: Read,
+    W: Write,
+{
+    for reader in readers {
+        for result in RawBlocks::new(reader) {
+            let block = result?;
+
+            if !block.is_eof_marker() {
+                writer.write_all(block.as_ref())?;
+            }
+        }
+    }
+
+    writer.write_all(BGZF_EOF)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Writer;
+
+    #[test]
+    fn test_concatenate() -> io::Result<()> {
+        let mut writer = Writer::new(Vec::new());
+        writer.write_all(b"noodles")?;
+        let a = writer.finish()?;
+
+        let b = BGZF_EOF.to_vec();
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_all(b"bgzf")?;
+        let c = writer.finish()?;
+
+        let mut actual = Vec::new();
+        concatenate([&a[..], &b[..], &c[..]], &mut actual)?;
+
+        let a_len = a.len() - BGZF_EOF.len();
+        let expected = [&a[..a_len], &c[..]].concat();
+
+        assert_eq!(actual, expected);
+
+        let mut actual = Vec::new();
+        concatenate(Vec::<&[u8]>::new(), &mut actual)?;
+        assert_eq!(actual, BGZF_EOF);
+
+        Ok(())
+    }
+}
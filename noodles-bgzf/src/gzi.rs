@@ -7,14 +7,20 @@
 #[cfg(feature = "async")]
 mod r#async;
 
+mod indexer;
 mod reader;
+mod writer;
 
-pub use self::reader::Reader;
+pub use self::{indexer::index, reader::Reader, writer::Writer};
 
 #[cfg(feature = "async")]
 pub use self::r#async::Reader as AsyncReader;
 
-use std::{fs::File, io, path::Path};
+use std::{
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::Path,
+};
 
 /// A gzip index.
 pub type Index = Vec<(u64, u64)>;
@@ -39,3 +45,26 @@ where
     let mut reader = File::open(src).map(Reader::new)?;
     reader.read_index()
 }
+
+/// Writes a gzip index to a file.
+///
+/// This is a convenience function and is equivalent to creating a file at the given path and
+/// writing the index.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::io;
+/// use noodles_bgzf::gzi;
+/// let index = vec![(0, 0), (4668, 21294)];
+/// gzi::write("in.gz.gzi", &index)?;
+/// # Ok::<_, io::Error>(())
+/// ```
+pub fn write<P>(dst: P, index: &[(u64, u64)]) -> io::Result<()>
+where
+    P: AsRef<Path>,
+{
+    let mut writer = File::create(dst).map(BufWriter::new).map(Writer::new)?;
+    writer.write_index(index)?;
+    writer.get_mut().flush()
+}
@@ -0,0 +1,87 @@
+use std::io::{self, Read};
+
+use super::Index;
+use crate::reader::RawBlocks;
+
+/// Builds a gzip index (GZI) from a BGZF stream.
+///
+/// Blocks are not inflated: the uncompressed size of each block is read from its trailer
+/// (`ISIZE`). The index has an entry for the start of each nonempty block, including the implicit
+/// first entry, `(0, 0)`.
+///
+/// This is similar to `bgzip --reindex`.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io::{self, Write};
+/// use noodles_bgzf::{self as bgzf, gzi};
+///
+/// let mut writer = bgzf::Writer::new(Vec::new());
+/// writer.write_all(b"noodles")?;
+/// let position = writer.flush_block()?;
+/// writer.write_all(b"-bgzf")?;
+/// let data = writer.finish()?;
+///
+/// let index = gzi::index(&data[..])?;
+/// assert_eq!(index, [(0, 0), (position.compressed(), 7)]);
+/// # Ok::<_, io::Error>(())
+/// ```
+pub fn index<R>(reader: R) -> io::Result<Index>
+where
+    R: Read,
+{
+    let mut index = vec![(0, 0)];
+    let mut uncompressed_position = 0;
+
+    for result in RawBlocks::new(reader) {
+        let block = result?;
+
+        if block.uncompressed_size() == 0 {
+            continue;
+        }
+
+        if block.position() > 0 {
+            index.push((block.position(), uncompressed_position));
+        }
+
+        uncompressed_position += block.uncompressed_size() as u64;
+    }
+
+    Ok(index)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use super::*;
+    use crate::Writer;
+
+    #[test]
+    fn test_index() -> io::Result<()> {
+        let mut writer = Writer::new(Vec::new());
+
+        writer.write_all(b"noodles")?;
+        let position_1 = writer.flush_block()?;
+        writer.write_all(b"-bgzf")?;
+        let position_2 = writer.flush_block()?;
+        writer.write_all(b"-gzi")?;
+
+        let data = writer.finish()?;
+
+        assert_eq!(
+            index(&data[..])?,
+            [
+                (0, 0),
+                (position_1.compressed(), 7),
+                (position_2.compressed(), 12)
+            ]
+        );
+
+        let data = Writer::new(Vec::new()).finish()?;
+        assert_eq!(index(&data[..])?, [(0, 0)]);
+
+        Ok(())
+    }
+}
@@ -38,17 +38,23 @@
 pub mod r#async;
 
 mod block;
+mod buffer_pool;
+mod check;
+mod concatenate;
 mod gz;
 pub mod gzi;
 pub mod indexed_reader;
-mod multithreaded_writer;
+pub mod multithreaded_writer;
 pub mod reader;
+mod recompress;
+mod split;
 pub mod virtual_position;
 pub mod writer;
 
 pub use self::{
-    indexed_reader::IndexedReader, multithreaded_writer::MultithreadedWriter, reader::Reader,
-    virtual_position::VirtualPosition, writer::Writer,
+    buffer_pool::BufferPool, check::check, concatenate::concatenate, indexed_reader::IndexedReader,
+    multithreaded_writer::MultithreadedWriter, reader::Reader, recompress::recompress,
+    split::split, virtual_position::VirtualPosition, writer::Writer,
 };
 
 #[cfg(feature = "async")]
@@ -1,3 +1,9 @@
+//! Multithreaded BGZF writer.
+
+mod builder;
+
+pub use self::builder::Builder;
+
 use std::{
     io::{self, Write},
     num::NonZeroUsize,
@@ -7,7 +13,7 @@ use std::{
 use bytes::{BufMut, Bytes, BytesMut};
 use crossbeam_channel::{Receiver, Sender};
 
-use super::gz;
+use super::{gz, writer::CompressionLevel, BufferPool};
 
 type BufferedTx = Sender<io::Result<Vec<u8>>>;
 type BufferedRx = Receiver<io::Result<Vec<u8>>>;
@@ -19,6 +25,21 @@ type WriteRx = Receiver<BufferedRx>;
 /// A multithreaded BGZF writer.
 ///
 /// This is much more basic than [`super::Writer`] but uses a thread pool to compress block data.
+/// Blocks are written in order.
+///
+/// Use [`Builder`] to set the compression level, worker count, queue depth, and buffer pool.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io::{self, Write};
+/// use noodles_bgzf as bgzf;
+///
+/// let mut writer = bgzf::multithreaded_writer::Builder::default().build_with_writer(Vec::new());
+/// writer.write_all(b"noodles")?;
+/// writer.finish()?;
+/// # Ok::<_, io::Error>(())
+/// ```
 pub struct MultithreadedWriter {
     writer_handle: Option<JoinHandle<io::Result<()>>>,
     deflater_handles: Vec<JoinHandle<()>>,
@@ -29,15 +50,38 @@ pub struct MultithreadedWriter {
 
 impl MultithreadedWriter {
     /// Creates a multithreaded BGZF writer.
+    ///
+    /// The compression level is set to the default (level 6).
     pub fn with_worker_count<W>(worker_count: NonZeroUsize, inner: W) -> Self
     where
         W: Write + Send + 'static,
     {
-        let (write_tx, write_rx) = crossbeam_channel::bounded(worker_count.get());
-        let (deflate_tx, deflate_rx) = crossbeam_channel::bounded(worker_count.get());
+        Self::new(
+            CompressionLevel::default(),
+            worker_count,
+            worker_count,
+            BufferPool::default(),
+            inner,
+        )
+    }
 
-        let writer_handle = spawn_writer(inner, write_rx);
-        let deflater_handles = spawn_deflaters(worker_count, deflate_rx);
+    pub(crate) fn new<W>(
+        compression_level: CompressionLevel,
+        worker_count: NonZeroUsize,
+        queue_depth: NonZeroUsize,
+        buffer_pool: BufferPool,
+        inner: W,
+    ) -> Self
+    where
+        W: Write + Send + 'static,
+    {
+        let (write_tx, write_rx) = crossbeam_channel::bounded(queue_depth.get());
+        let (deflate_tx, deflate_rx) = crossbeam_channel::bounded(queue_depth.get());
+
+        // Written blocks are returned to the pool for the deflaters to reuse their buffers.
+        let writer_handle = spawn_writer(inner, write_rx, buffer_pool.clone());
+        let deflater_handles =
+            spawn_deflaters(compression_level, worker_count, deflate_rx, buffer_pool);
 
         Self {
             writer_handle: Some(writer_handle),
@@ -78,6 +122,9 @@ impl MultithreadedWriter {
         let message = (src, buffered_tx);
         self.deflate_tx.as_ref().unwrap().send(message).unwrap();
 
+        // This reclaims the previous allocation if its block data was already compressed.
+        self.buf.reserve(super::writer::MAX_BUF_SIZE);
+
         Ok(())
     }
 }
@@ -115,7 +162,11 @@ impl Write for MultithreadedWriter {
     }
 }
 
-fn spawn_writer<W>(mut writer: W, write_rx: WriteRx) -> JoinHandle<io::Result<()>>
+fn spawn_writer<W>(
+    mut writer: W,
+    write_rx: WriteRx,
+    buffer_pool: BufferPool,
+) -> JoinHandle<io::Result<()>>
 where
     W: Write + Send + 'static,
 {
@@ -126,6 +177,7 @@ where
             if let Ok(result) = buffered_rx.recv() {
                 let buf = result?;
                 writer.write_all(&buf[..])?;
+                buffer_pool.put(buf);
             }
         }
 
@@ -135,14 +187,21 @@ where
     })
 }
 
-fn spawn_deflaters(worker_count: NonZeroUsize, deflate_rx: DeflateRx) -> Vec<JoinHandle<()>> {
+fn spawn_deflaters(
+    compression_level: CompressionLevel,
+    worker_count: NonZeroUsize,
+    deflate_rx: DeflateRx,
+    buffer_pool: BufferPool,
+) -> Vec<JoinHandle<()>> {
     (0..worker_count.get())
         .map(|_| {
             let deflate_rx = deflate_rx.clone();
+            let buffer_pool = buffer_pool.clone();
 
             thread::spawn(move || {
                 while let Ok((src, buffered_tx)) = deflate_rx.recv() {
-                    let result = compress(&src);
+                    let mut dst = buffer_pool.get();
+                    let result = compress(&src, compression_level, &mut dst).map(|_| dst);
                     buffered_tx.send(result).ok();
                 }
             })
@@ -150,21 +209,30 @@ fn spawn_deflaters(worker_count: NonZeroUsize, deflate_rx: DeflateRx) -> Vec<Joi
         .collect()
 }
 
-fn compress(src: &[u8]) -> io::Result<Vec<u8>> {
+pub(crate) fn compress(
+    src: &[u8],
+    compression_level: CompressionLevel,
+    dst: &mut Vec<u8>,
+) -> io::Result<()> {
     use super::{writer::deflate_data, BGZF_HEADER_SIZE};
 
-    let mut dst = Vec::new();
+    const BSIZE_POSITION: usize = 16;
 
-    let (cdata, crc32, _) = deflate_data(src, Default::default())?;
+    dst.clear();
 
-    let block_size = BGZF_HEADER_SIZE + cdata.len() + gz::TRAILER_SIZE;
-    put_header(&mut dst, block_size)?;
+    // The block size is overwritten after the data is compressed.
+    put_header(dst, BGZF_HEADER_SIZE + gz::TRAILER_SIZE)?;
 
-    dst.extend(cdata);
+    let (crc32, _) = deflate_data(src, compression_level.into(), dst)?;
 
-    put_trailer(&mut dst, crc32, src.len())?;
+    let block_size = dst.len() + gz::TRAILER_SIZE;
+    let bsize = u16::try_from(block_size - 1)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+    dst[BSIZE_POSITION..BGZF_HEADER_SIZE].copy_from_slice(&bsize.to_le_bytes());
 
-    Ok(dst)
+    put_trailer(dst, crc32, src.len())?;
+
+    Ok(())
 }
 
 fn put_header<B>(dst: &mut B, block_size: usize) -> io::Result<()>
@@ -210,3 +278,102 @@ where
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_with_queue_depth() -> Result<(), Box<dyn std::error::Error>> {
+        use std::{
+            io::Read,
+            sync::{Arc, Mutex},
+        };
+
+        use crate::Reader;
+
+        // A shared buffer to inspect the output after the writer thread finishes.
+        #[derive(Clone, Default)]
+        struct SharedBuf(Arc<Mutex<Vec<u8>>>);
+
+        impl Write for SharedBuf {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                self.0.lock().unwrap().write(buf)
+            }
+
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let data: Vec<_> = (0..1 << 18).map(|i| (i % 251) as u8).collect();
+
+        let buf = SharedBuf::default();
+
+        let mut writer = Builder::default()
+            .set_worker_count(NonZeroUsize::try_from(2)?)
+            .set_queue_depth(NonZeroUsize::try_from(8)?)
+            .build_with_writer(buf.clone());
+
+        writer.write_all(&data)?;
+        writer.finish()?;
+
+        let compressed = buf.0.lock().unwrap().clone();
+        assert!(compressed.ends_with(crate::writer::BGZF_EOF));
+
+        let mut reader = Reader::new(&compressed[..]);
+        let mut actual = Vec::new();
+        reader.read_to_end(&mut actual)?;
+
+        assert_eq!(actual, data);
+
+        let mut writer = crate::Writer::new(Vec::new());
+        writer.write_all(&data)?;
+        let expected = writer.finish()?;
+
+        assert_eq!(compressed, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_with_buffer_pool() -> Result<(), Box<dyn std::error::Error>> {
+        let pool = BufferPool::default();
+
+        let mut writer = Builder::default()
+            .set_worker_count(NonZeroUsize::try_from(2)?)
+            .set_buffer_pool(pool.clone())
+            .build_with_writer(io::sink());
+
+        let data: Vec<_> = (0..1 << 18).map(|i| (i % 251) as u8).collect();
+        writer.write_all(&data)?;
+        writer.finish()?;
+
+        assert!(!pool.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_compress() -> io::Result<()> {
+        use std::io::Read;
+
+        fn decompress(src: &[u8]) -> io::Result<Vec<u8>> {
+            let mut reader = crate::Reader::new(src);
+            let mut buf = Vec::new();
+            reader.read_to_end(&mut buf)?;
+            Ok(buf)
+        }
+
+        // A reused buffer is cleared.
+        let mut dst = vec![0xff; 64];
+
+        compress(b"noodles", CompressionLevel::default(), &mut dst)?;
+        assert_eq!(decompress(&dst)?, b"noodles");
+
+        compress(b"", CompressionLevel::default(), &mut dst)?;
+        assert!(decompress(&dst)?.is_empty());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,134 @@
+use std::{io::Write, num::NonZeroUsize, thread};
+
+use super::MultithreadedWriter;
+use crate::{writer::CompressionLevel, BufferPool};
+
+/// A multithreaded BGZF writer builder.
+#[derive(Debug, Default)]
+pub struct Builder {
+    compression_level: Option<CompressionLevel>,
+    worker_count: Option<NonZeroUsize>,
+    queue_depth: Option<NonZeroUsize>,
+    buffer_pool: Option<BufferPool>,
+}
+
+impl Builder {
+    /// Sets a compression level.
+    ///
+    /// By default, the compression level is set to level 6.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf::{self as bgzf, writer::CompressionLevel};
+    ///
+    /// let builder = bgzf::multithreaded_writer::Builder::default()
+    ///     .set_compression_level(CompressionLevel::best());
+    /// ```
+    pub fn set_compression_level(mut self, compression_level: CompressionLevel) -> Self {
+        self.compression_level = Some(compression_level);
+        self
+    }
+
+    /// Sets a worker count.
+    ///
+    /// This is the number of threads used to compress blocks. By default, the worker count is set
+    /// to the number of available logical CPUs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use noodles_bgzf as bgzf;
+    ///
+    /// let worker_count = NonZeroUsize::try_from(4)?;
+    /// let builder = bgzf::multithreaded_writer::Builder::default()
+    ///     .set_worker_count(worker_count);
+    /// # Ok::<_, std::num::TryFromIntError>(())
+    /// ```
+    pub fn set_worker_count(mut self, worker_count: NonZeroUsize) -> Self {
+        self.worker_count = Some(worker_count);
+        self
+    }
+
+    /// Sets a queue depth.
+    ///
+    /// This is the maximum number of blocks that are queued for compression and, separately, the
+    /// maximum number of blocks that are pending to be written. When the queue is full, writing to
+    /// the writer blocks. A deeper queue smooths out uneven compression times at the cost of
+    /// memory, up to 64 KiB per queued block.
+    ///
+    /// By default, the queue depth is set to the worker count.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use noodles_bgzf as bgzf;
+    ///
+    /// let queue_depth = NonZeroUsize::try_from(16)?;
+    /// let builder = bgzf::multithreaded_writer::Builder::default()
+    ///     .set_queue_depth(queue_depth);
+    /// # Ok::<_, std::num::TryFromIntError>(())
+    /// ```
+    pub fn set_queue_depth(mut self, queue_depth: NonZeroUsize) -> Self {
+        self.queue_depth = Some(queue_depth);
+        self
+    }
+
+    /// Sets a buffer pool.
+    ///
+    /// Compressed block buffers are taken from this pool and returned to it after they are
+    /// written. The pool can be shared with other readers and writers.
+    ///
+    /// By default, each writer uses its own pool.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    ///
+    /// let pool = bgzf::BufferPool::default();
+    /// let builder = bgzf::multithreaded_writer::Builder::default().set_buffer_pool(pool);
+    /// ```
+    pub fn set_buffer_pool(mut self, buffer_pool: BufferPool) -> Self {
+        self.buffer_pool = Some(buffer_pool);
+        self
+    }
+
+    /// Builds a multithreaded BGZF writer from a writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bgzf as bgzf;
+    /// let mut writer = bgzf::multithreaded_writer::Builder::default().build_with_writer(io::sink());
+    /// writer.finish()?;
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn build_with_writer<W>(self, writer: W) -> MultithreadedWriter
+    where
+        W: Write + Send + 'static,
+    {
+        let compression_level = self.compression_level.unwrap_or_default();
+
+        let worker_count = self.worker_count.unwrap_or_else(|| {
+            thread::available_parallelism().unwrap_or_else(|_| NonZeroUsize::new(1).unwrap())
+        });
+
+        let queue_depth = self.queue_depth.unwrap_or(worker_count);
+
+        let buffer_pool = self.buffer_pool.unwrap_or_default();
+
+        MultithreadedWriter::new(
+            compression_level,
+            worker_count,
+            queue_depth,
+            buffer_pool,
+            writer,
+        )
+    }
+}
@@ -2,8 +2,10 @@
 
 pub(crate) mod block;
 mod builder;
+mod raw_block;
+mod raw_blocks;
 
-pub use self::builder::Builder;
+pub use self::{builder::Builder, raw_block::RawBlock, raw_blocks::RawBlocks};
 
 use std::io::{self, BufRead, Read, Seek, SeekFrom};
 
@@ -30,6 +32,7 @@ pub struct Reader<R> {
     inner: block::Inner<R>,
     position: u64,
     block: Block,
+    is_eof_marker_required: bool,
 }
 
 impl<R> Reader<R>
@@ -120,16 +123,22 @@ where
     }
 
     fn read_block(&mut self) -> io::Result<()> {
-        while let Some(mut block) = self.inner.next_block()? {
-            block.set_position(self.position);
-            self.position += block.size();
-            self.block = block;
+        while self.inner.next_block(&mut self.block)?.is_some() {
+            self.block.set_position(self.position);
+            self.position += self.block.size();
 
             if self.block.data().len() > 0 {
-                break;
+                return Ok(());
             }
         }
 
+        if self.is_eof_marker_required && !self.block.is_eof_marker() {
+            return Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "missing BGZF EOF marker",
+            ));
+        }
+
         Ok(())
     }
 }
@@ -161,7 +170,16 @@ where
 
         self.read_block()?;
 
-        self.block.data_mut().set_position(usize::from(upos));
+        let upos = usize::from(upos);
+
+        if upos > self.block.data().len() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("invalid virtual position: {cpos}:{upos}"),
+            ));
+        }
+
+        self.block.data_mut().set_position(upos);
 
         Ok(pos)
     }
@@ -238,10 +256,15 @@ pub(crate) fn inflate_data(src: &[u8], dst: &mut [u8]) -> io::Result<()> {
 
     let mut decoder = Decompressor::new();
 
-    decoder
+    let n = decoder
         .deflate_decompress(src, dst)
-        .map(|_| ())
-        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+    if n == dst.len() {
+        Ok(())
+    } else {
+        Err(block_data_size_mismatch_error())
+    }
 }
 
 #[cfg(not(feature = "libdeflate"))]
@@ -249,7 +272,27 @@ pub(crate) fn inflate_data(src: &[u8], dst: &mut [u8]) -> io::Result<()> {
     use flate2::bufread::DeflateDecoder;
 
     let mut decoder = DeflateDecoder::new(src);
-    decoder.read_exact(dst)
+
+    decoder.read_exact(dst).map_err(|e| {
+        if e.kind() == io::ErrorKind::UnexpectedEof {
+            block_data_size_mismatch_error()
+        } else {
+            e
+        }
+    })?;
+
+    // The inflated data must not be larger than `ISIZE`.
+    let mut buf = [0];
+
+    if decoder.read(&mut buf)? == 0 {
+        Ok(())
+    } else {
+        Err(block_data_size_mismatch_error())
+    }
+}
+
+fn block_data_size_mismatch_error() -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, "block data size mismatch")
 }
 
 #[cfg(test)]
@@ -317,6 +360,141 @@ mod tests {
         assert_eq!(buf, b"dles");
         assert_eq!(reader.virtual_position(), eof);
 
+        assert!(matches!(
+            reader.seek(VirtualPosition::try_from((0, 8))?),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_with_queue_depth() -> Result<(), Box<dyn std::error::Error>> {
+        use std::{io::Write, num::NonZeroUsize};
+
+        use crate::Writer;
+
+        fn read_blocks<R>(reader: &mut Reader<R>) -> io::Result<Vec<(VirtualPosition, Vec<u8>)>>
+        where
+            R: Read,
+        {
+            let mut blocks = Vec::new();
+
+            loop {
+                let buf = reader.fill_buf()?;
+
+                if buf.is_empty() {
+                    break;
+                }
+
+                let data = buf.to_vec();
+                reader.consume(data.len());
+                blocks.push((reader.virtual_position(), data));
+            }
+
+            Ok(blocks)
+        }
+
+        let mut writer = Writer::new(Vec::new());
+
+        for i in 0..8 {
+            let data: Vec<_> = (0..0x10000).map(|j| ((i * j) % 251) as u8).collect();
+            writer.write_all(&data)?;
+        }
+
+        let data = writer.finish()?;
+
+        let mut reader = Reader::new(Cursor::new(&data));
+        let expected = read_blocks(&mut reader)?;
+        assert!(expected.len() > 1);
+
+        let mut reader = Builder::default()
+            .set_queue_depth(NonZeroUsize::try_from(4)?)
+            .build_from_reader(Cursor::new(&data));
+
+        let actual = read_blocks(&mut reader)?;
+        assert_eq!(actual, expected);
+
+        let (pos, _) = expected[2];
+        reader.seek(pos)?;
+        assert_eq!(read_blocks(&mut reader)?, expected[3..]);
+
+        let mut reader = Builder::default()
+            .set_worker_count(NonZeroUsize::try_from(2)?)
+            .set_queue_depth(NonZeroUsize::try_from(3)?)
+            .build_from_reader(Cursor::new(&data));
+
+        let actual = read_blocks(&mut reader)?;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_with_buffer_pool() -> Result<(), Box<dyn std::error::Error>> {
+        use std::{io::Write, num::NonZeroUsize};
+
+        use crate::{BufferPool, Writer};
+
+        let expected: Vec<_> = (0..1 << 18).map(|i| (i % 251) as u8).collect();
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_all(&expected)?;
+        let data = writer.finish()?;
+
+        let pool = BufferPool::default();
+
+        let mut reader = Builder::default()
+            .set_worker_count(NonZeroUsize::try_from(2)?)
+            .set_buffer_pool(pool.clone())
+            .build_from_reader(&data[..]);
+
+        let mut actual = Vec::new();
+        reader.read_to_end(&mut actual)?;
+
+        assert_eq!(actual, expected);
+        assert!(!pool.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_with_eof_marker_required() -> io::Result<()> {
+        use crate::writer::BGZF_EOF;
+
+        #[rustfmt::skip]
+        let data = [
+            // block 0 (b"noodles")
+            0x1f, 0x8b, 0x08, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0x06, 0x00, 0x42, 0x43,
+            0x02, 0x00, 0x22, 0x00, 0xcb, 0xcb, 0xcf, 0x4f, 0xc9, 0x49, 0x2d, 0x06, 0x00, 0xa1,
+            0x58, 0x2a, 0x80, 0x07, 0x00, 0x00, 0x00,
+        ];
+
+        let mut buf = Vec::new();
+
+        let mut reader = Reader::new(&data[..]);
+        reader.read_to_end(&mut buf)?;
+        assert_eq!(buf, b"noodles");
+
+        let mut reader = Builder::default()
+            .set_eof_marker_required(true)
+            .build_from_reader(&data[..]);
+
+        buf.clear();
+        assert!(matches!(
+            reader.read_to_end(&mut buf),
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof
+        ));
+
+        let src: Vec<_> = data.iter().chain(BGZF_EOF).copied().collect();
+        let mut reader = Builder::default()
+            .set_eof_marker_required(true)
+            .build_from_reader(&src[..]);
+
+        buf.clear();
+        reader.read_to_end(&mut buf)?;
+        assert_eq!(buf, b"noodles");
+
         Ok(())
     }
 
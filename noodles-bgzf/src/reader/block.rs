@@ -38,28 +38,18 @@ where
         }
     }
 
-    pub fn next_block(&mut self) -> io::Result<Option<Block>> {
+    /// Reads the next block into the given block.
+    ///
+    /// The given block's buffer is reused. If the stream is at EOF, the block is unchanged.
+    pub fn next_block(&mut self, block: &mut Block) -> io::Result<Option<()>> {
         match self {
-            Self::Single(reader) => reader.next_block(),
-            Self::Multi(reader) => reader.next_block(),
+            Self::Single(reader) => reader.next_block(block),
+            Self::Multi(reader) => reader.next_block(block),
         }
     }
 }
 
-fn read_frame<R>(reader: &mut R) -> io::Result<Option<Vec<u8>>>
-where
-    R: Read,
-{
-    let mut buf = vec![0; BGZF_HEADER_SIZE];
-
-    if read_frame_into(reader, &mut buf)?.is_some() {
-        Ok(Some(buf))
-    } else {
-        Ok(None)
-    }
-}
-
-fn read_frame_into<R>(reader: &mut R, buf: &mut Vec<u8>) -> io::Result<Option<()>>
+pub(crate) fn read_frame_into<R>(reader: &mut R, buf: &mut Vec<u8>) -> io::Result<Option<()>>
 where
     R: Read,
 {
@@ -90,7 +80,7 @@ where
     Ok(Some(()))
 }
 
-fn split_frame(buf: &[u8]) -> (&[u8], &[u8], &[u8]) {
+pub(crate) fn split_frame(buf: &[u8]) -> (&[u8], &[u8], &[u8]) {
     let header = &buf[..BGZF_HEADER_SIZE];
 
     let n = buf.len() - gz::TRAILER_SIZE;
@@ -101,7 +91,7 @@ fn split_frame(buf: &[u8]) -> (&[u8], &[u8], &[u8]) {
     (header, cdata, trailer)
 }
 
-fn parse_header(src: &[u8]) -> io::Result<()> {
+pub(crate) fn parse_header(src: &[u8]) -> io::Result<()> {
     if is_valid_header(src) {
         Ok(())
     } else {
@@ -148,7 +138,7 @@ where
         && subfield_len == BGZF_SLEN
 }
 
-fn parse_trailer<B>(mut src: B) -> io::Result<(u32, usize)>
+pub(crate) fn parse_trailer<B>(mut src: B) -> io::Result<(u32, usize)>
 where
     B: Buf,
 {
@@ -160,28 +150,40 @@ where
     Ok((crc32, r#isize))
 }
 
+#[cfg(feature = "async")]
 pub(crate) fn parse_frame(src: &[u8]) -> io::Result<Block> {
+    let mut block = Block::default();
+    parse_frame_into(src, &mut block)?;
+    Ok(block)
+}
+
+// The block is cleared if the data fails to inflate, i.e., it never holds partially inflated or
+// corrupt data.
+pub(crate) fn parse_frame_into(src: &[u8], block: &mut Block) -> io::Result<()> {
     let (header, cdata, trailer) = split_frame(src);
 
     parse_header(header)?;
     let (crc32, r#isize) = parse_trailer(trailer)?;
 
-    let mut block = Block::default();
-
     let block_size =
         u64::try_from(src.len()).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
-    block.set_size(block_size);
 
     let data = block.data_mut();
     data.set_position(0);
     data.resize(r#isize);
 
-    inflate(cdata, crc32, data.as_mut())?;
+    if let Err(e) = inflate(cdata, crc32, data.as_mut()) {
+        data.resize(0);
+        block.set_size(0);
+        return Err(e);
+    }
 
-    Ok(block)
+    block.set_size(block_size);
+
+    Ok(())
 }
 
-fn inflate(src: &[u8], crc32: u32, dst: &mut [u8]) -> io::Result<()> {
+pub(crate) fn inflate(src: &[u8], crc32: u32, dst: &mut [u8]) -> io::Result<()> {
     use super::inflate_data;
 
     inflate_data(src, dst)?;
@@ -245,15 +247,57 @@ mod tests {
     }
 
     #[test]
-    fn test_read_frame() -> Result<(), Box<dyn std::error::Error>> {
+    fn test_read_frame_into() -> Result<(), Box<dyn std::error::Error>> {
         let mut src = BGZF_EOF;
-        let buf = read_frame(&mut src)?.ok_or("invalid frame")?;
+        let mut buf = Vec::new();
+        read_frame_into(&mut src, &mut buf)?.ok_or("invalid frame")?;
         assert_eq!(buf, BGZF_EOF);
+
+        let mut src = &[][..];
+        assert!(read_frame_into(&mut src, &mut buf)?.is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_frame_into() -> io::Result<()> {
+        let mut block = Block::default();
+        block.data_mut().resize(8);
+        block.data_mut().set_position(5);
+
+        parse_frame_into(BGZF_EOF, &mut block)?;
+
+        assert_eq!(block.size(), BGZF_EOF.len() as u64);
+        assert_eq!(block.data().len(), 0);
+        assert_eq!(block.data().position(), 0);
+
         Ok(())
     }
 
     #[test]
-    fn test_read_frame_with_invalid_block_size() {
+    fn test_parse_frame_into_with_checksum_mismatch() {
+        let src = {
+            let mut eof = BGZF_EOF.to_vec();
+            // CRC32 = 1
+            eof[20] = 0x01;
+            eof
+        };
+
+        let mut block = Block::default();
+        block.set_size(13);
+        block.data_mut().resize(8);
+
+        assert!(matches!(
+            parse_frame_into(&src, &mut block),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        assert_eq!(block.size(), 0);
+        assert_eq!(block.data().len(), 0);
+    }
+
+    #[test]
+    fn test_read_frame_into_with_invalid_block_size() {
         let data = {
             let mut eof = BGZF_EOF.to_vec();
             // BSIZE = 0
@@ -263,6 +307,7 @@ mod tests {
         };
 
         let mut reader = &data[..];
-        assert!(read_frame(&mut reader).is_err());
+        let mut buf = Vec::new();
+        assert!(read_frame_into(&mut reader, &mut buf).is_err());
     }
 }
@@ -1,13 +1,14 @@
 use std::{
     collections::VecDeque,
     io::{self, Read},
+    mem,
     num::NonZeroUsize,
     thread::{self, JoinHandle},
 };
 
 use crossbeam_channel::{Receiver, Sender};
 
-use crate::Block;
+use crate::{Block, BufferPool};
 
 type BufferedTx = Sender<io::Result<Block>>;
 type BufferedRx = Receiver<io::Result<Block>>;
@@ -17,7 +18,10 @@ type InflaterRx = Receiver<(Vec<u8>, BufferedTx)>;
 pub struct Reader<R> {
     inner: Option<R>,
     inflater_tx: Option<InflaterTx>,
+    // Frames and the data of consumed blocks are returned to the pool to reuse their buffers.
+    buffer_pool: BufferPool,
     inflater_handles: Vec<JoinHandle<()>>,
+    queue_depth: usize,
     queue: VecDeque<BufferedRx>,
     is_eof: bool,
 }
@@ -44,17 +48,29 @@ impl<R> Reader<R>
 where
     R: Read,
 {
+    #[cfg(test)]
     pub(crate) fn with_worker_count(worker_count: NonZeroUsize, inner: R) -> Self {
-        let worker_count = worker_count.get();
+        Self::new(worker_count, worker_count, BufferPool::default(), inner)
+    }
+
+    pub(crate) fn new(
+        worker_count: NonZeroUsize,
+        queue_depth: NonZeroUsize,
+        buffer_pool: BufferPool,
+        inner: R,
+    ) -> Self {
+        let (inflater_tx, inflater_rx) = crossbeam_channel::bounded(queue_depth.get());
 
-        let (inflater_tx, inflater_rx) = crossbeam_channel::bounded(worker_count);
-        let inflater_handles = spawn_inflaters(worker_count, inflater_rx);
+        let inflater_handles =
+            spawn_inflaters(worker_count.get(), inflater_rx, buffer_pool.clone());
 
         Self {
             inner: Some(inner),
             inflater_tx: Some(inflater_tx),
+            buffer_pool,
             inflater_handles,
-            queue: VecDeque::with_capacity(worker_count),
+            queue_depth: queue_depth.get(),
+            queue: VecDeque::with_capacity(queue_depth.get()),
             is_eof: false,
         }
     }
@@ -73,12 +89,16 @@ where
         self.inner.take().unwrap()
     }
 
-    pub fn next_block(&mut self) -> io::Result<Option<Block>> {
+    pub fn next_block(&mut self, block: &mut Block) -> io::Result<Option<()>> {
         self.fill_queue()?;
 
         if let Some(buffered_rx) = self.queue.pop_front() {
             if let Ok(result) = buffered_rx.recv() {
-                result.map(Some)
+                let mut next_block = result?;
+                mem::swap(block, &mut next_block);
+                let data = mem::take(next_block.data_mut());
+                self.buffer_pool.put(data.into());
+                Ok(Some(()))
             } else {
                 unreachable!();
             }
@@ -88,13 +108,15 @@ where
     }
 
     fn fill_queue(&mut self) -> io::Result<()> {
-        use super::read_frame;
+        use super::read_frame_into;
 
         let reader = self.inner.as_mut().unwrap();
 
-        while self.queue.len() < self.queue.capacity() && !self.is_eof {
-            match read_frame(reader)? {
-                Some(buf) => {
+        while self.queue.len() < self.queue_depth && !self.is_eof {
+            let mut buf = self.buffer_pool.get();
+
+            match read_frame_into(reader, &mut buf)? {
+                Some(()) => {
                     let (buffered_tx, buffered_rx) = crossbeam_channel::bounded(1);
 
                     self.inflater_tx
@@ -113,17 +135,26 @@ where
     }
 }
 
-fn spawn_inflaters(worker_count: usize, inflater_rx: InflaterRx) -> Vec<JoinHandle<()>> {
-    use super::parse_frame;
+fn spawn_inflaters(
+    worker_count: usize,
+    inflater_rx: InflaterRx,
+    buffer_pool: BufferPool,
+) -> Vec<JoinHandle<()>> {
+    use super::parse_frame_into;
 
     let mut handles = Vec::with_capacity(worker_count);
 
     for _ in 0..worker_count {
         let inflater_rx = inflater_rx.clone();
+        let buffer_pool = buffer_pool.clone();
 
         handles.push(thread::spawn(move || {
             while let Ok((src, buffered_tx)) = inflater_rx.recv() {
-                let result = parse_frame(&src);
+                let mut block = Block::default();
+                *block.data_mut() = buffer_pool.get().into();
+                let result = parse_frame_into(&src, &mut block).map(|_| block);
+
+                buffer_pool.put(src);
 
                 if buffered_tx.send(result).is_err() {
                     continue;
@@ -30,11 +30,11 @@ where
         self.inner
     }
 
-    pub fn next_block(&mut self) -> io::Result<Option<Block>> {
-        use super::{parse_frame, read_frame_into};
+    pub fn next_block(&mut self, block: &mut Block) -> io::Result<Option<()>> {
+        use super::{parse_frame_into, read_frame_into};
 
         if read_frame_into(&mut self.inner, &mut self.buf)?.is_some() {
-            parse_frame(&self.buf).map(Some)
+            parse_frame_into(&self.buf, block).map(Some)
         } else {
             Ok(None)
         }
@@ -6,7 +6,7 @@ use std::{
 };
 
 use super::{block, Reader};
-use crate::Block;
+use crate::{Block, BufferPool};
 
 const DEFAULT_WORKER_COUNT: NonZeroUsize = match NonZeroUsize::new(1) {
     Some(worker_count) => worker_count,
@@ -17,6 +17,9 @@ const DEFAULT_WORKER_COUNT: NonZeroUsize = match NonZeroUsize::new(1) {
 #[derive(Debug)]
 pub struct Builder {
     worker_count: NonZeroUsize,
+    queue_depth: Option<NonZeroUsize>,
+    buffer_pool: Option<BufferPool>,
+    is_eof_marker_required: bool,
 }
 
 impl Builder {
@@ -40,6 +43,81 @@ impl Builder {
         self
     }
 
+    /// Sets the queue depth.
+    ///
+    /// This is the maximum number of upcoming blocks that are read ahead and inflated in the
+    /// background. Inflation then runs concurrently with the consumer of the reader. Blocks are
+    /// still emitted in order, and (virtual) positions are the same as with a sequential reader.
+    ///
+    /// A queue depth greater than 1 enables readahead, even with a worker count of 1.
+    ///
+    /// By default, the queue depth is set to the worker count.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use noodles_bgzf as bgzf;
+    ///
+    /// let queue_depth = NonZeroUsize::try_from(8)?;
+    /// let builder = bgzf::reader::Builder::default().set_queue_depth(queue_depth);
+    /// # Ok::<_, std::num::TryFromIntError>(())
+    /// ```
+    pub fn set_queue_depth(mut self, queue_depth: NonZeroUsize) -> Self {
+        self.queue_depth = Some(queue_depth);
+        self
+    }
+
+    /// Sets the buffer pool.
+    ///
+    /// Readers that read ahead, i.e., with a worker count or queue depth greater than 1, take
+    /// compressed and uncompressed block buffers from this pool and return them after use. The
+    /// pool can be shared with other readers and writers.
+    ///
+    /// By default, each reader uses its own pool.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    ///
+    /// let pool = bgzf::BufferPool::default();
+    /// let builder = bgzf::reader::Builder::default().set_buffer_pool(pool);
+    /// ```
+    pub fn set_buffer_pool(mut self, buffer_pool: BufferPool) -> Self {
+        self.buffer_pool = Some(buffer_pool);
+        self
+    }
+
+    /// Sets whether the stream must end with an EOF marker.
+    ///
+    /// When enabled, reading returns an [`io::ErrorKind::UnexpectedEof`] error if the stream ends
+    /// without a BGZF EOF marker, e.g., when the file is truncated.
+    ///
+    /// By default, a missing EOF marker is ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::{self, Read};
+    /// use noodles_bgzf as bgzf;
+    ///
+    /// let mut reader = bgzf::reader::Builder::default()
+    ///     .set_eof_marker_required(true)
+    ///     .build_from_reader(io::empty());
+    ///
+    /// let mut buf = Vec::new();
+    /// assert!(matches!(
+    ///     reader.read_to_end(&mut buf),
+    ///     Err(e) if e.kind() == io::ErrorKind::UnexpectedEof
+    /// ));
+    /// ```
+    pub fn set_eof_marker_required(mut self, is_eof_marker_required: bool) -> Self {
+        self.is_eof_marker_required = is_eof_marker_required;
+        self
+    }
+
     /// Builds a BGZF reader from a path.
     ///
     /// # Examples
@@ -71,11 +149,15 @@ impl Builder {
     where
         R: Read,
     {
-        let block_reader = if self.worker_count.get() == 1 {
+        let queue_depth = self.queue_depth.unwrap_or(self.worker_count);
+
+        let block_reader = if self.worker_count.get() == 1 && queue_depth.get() == 1 {
             block::Inner::Single(block::single::Reader::new(reader))
         } else {
-            block::Inner::Multi(block::multi::Reader::with_worker_count(
+            block::Inner::Multi(block::multi::Reader::new(
                 self.worker_count,
+                queue_depth,
+                self.buffer_pool.unwrap_or_default(),
                 reader,
             ))
         };
@@ -84,6 +166,7 @@ impl Builder {
             inner: block_reader,
             position: 0,
             block: Block::default(),
+            is_eof_marker_required: self.is_eof_marker_required,
         }
     }
 }
@@ -92,6 +175,9 @@ impl Default for Builder {
     fn default() -> Self {
         Self {
             worker_count: DEFAULT_WORKER_COUNT,
+            queue_depth: None,
+            buffer_pool: None,
+            is_eof_marker_required: false,
         }
     }
 }
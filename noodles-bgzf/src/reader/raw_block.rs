@@ -0,0 +1,164 @@
+use std::io;
+
+use crate::writer::BGZF_EOF;
+
+/// A raw BGZF block.
+///
+/// A raw block holds the complete compressed block (header, compressed data, and trailer) as it
+/// was read from the stream. The compressed data is not inflated unless [`Self::inflate`] is
+/// called.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RawBlock {
+    position: u64,
+    buf: Vec<u8>,
+    crc32: u32,
+    uncompressed_size: usize,
+}
+
+impl RawBlock {
+    pub(super) fn try_new(position: u64, buf: Vec<u8>) -> io::Result<Self> {
+        use super::block::{parse_header, parse_trailer, split_frame};
+
+        let (header, _, trailer) = split_frame(&buf);
+
+        parse_header(header)?;
+        let (crc32, uncompressed_size) = parse_trailer(trailer)?;
+
+        Ok(Self {
+            position,
+            buf,
+            crc32,
+            uncompressed_size,
+        })
+    }
+
+    /// Returns the position of the block in the compressed stream.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bgzf as bgzf;
+    ///
+    /// let data = bgzf::Writer::new(Vec::new()).finish()?;
+    /// let mut blocks = bgzf::reader::RawBlocks::new(&data[..]);
+    ///
+    /// let block = blocks.next().transpose()?.expect("missing block");
+    /// assert_eq!(block.position(), 0);
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+
+    /// Returns the size of the compressed block (`BSIZE` + 1).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bgzf as bgzf;
+    ///
+    /// let data = bgzf::Writer::new(Vec::new()).finish()?;
+    /// let mut blocks = bgzf::reader::RawBlocks::new(&data[..]);
+    ///
+    /// let block = blocks.next().transpose()?.expect("missing block");
+    /// assert_eq!(block.size(), 28);
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn size(&self) -> u64 {
+        self.buf.len() as u64
+    }
+
+    /// Returns the size of the uncompressed data (`ISIZE`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::{self, Write};
+    /// use noodles_bgzf as bgzf;
+    ///
+    /// let mut writer = bgzf::Writer::new(Vec::new());
+    /// writer.write_all(b"noodles")?;
+    /// let data = writer.finish()?;
+    ///
+    /// let mut blocks = bgzf::reader::RawBlocks::new(&data[..]);
+    /// let block = blocks.next().transpose()?.expect("missing block");
+    /// assert_eq!(block.uncompressed_size(), 7);
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn uncompressed_size(&self) -> usize {
+        self.uncompressed_size
+    }
+
+    /// Returns the checksum of the uncompressed data (`CRC32`).
+    pub fn crc32(&self) -> u32 {
+        self.crc32
+    }
+
+    /// Returns the compressed data (`CDATA`).
+    pub fn cdata(&self) -> &[u8] {
+        use super::block::split_frame;
+        let (_, cdata, _) = split_frame(&self.buf);
+        cdata
+    }
+
+    /// Returns whether the block is an EOF marker, i.e., an empty 28-byte block.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bgzf as bgzf;
+    ///
+    /// let data = bgzf::Writer::new(Vec::new()).finish()?;
+    /// let mut blocks = bgzf::reader::RawBlocks::new(&data[..]);
+    ///
+    /// let block = blocks.next().transpose()?.expect("missing block");
+    /// assert!(block.is_eof_marker());
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn is_eof_marker(&self) -> bool {
+        self.uncompressed_size == 0 && self.buf.len() == BGZF_EOF.len()
+    }
+
+    /// Inflates the compressed data.
+    ///
+    /// The checksum (`CRC32`) and size (`ISIZE`) of the uncompressed data are verified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::{self, Write};
+    /// use noodles_bgzf as bgzf;
+    ///
+    /// let mut writer = bgzf::Writer::new(Vec::new());
+    /// writer.write_all(b"noodles")?;
+    /// let data = writer.finish()?;
+    ///
+    /// let mut blocks = bgzf::reader::RawBlocks::new(&data[..]);
+    /// let block = blocks.next().transpose()?.expect("missing block");
+    /// assert_eq!(block.inflate()?, b"noodles");
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn inflate(&self) -> io::Result<Vec<u8>> {
+        use super::block::inflate;
+
+        let mut dst = vec![0; self.uncompressed_size];
+        inflate(self.cdata(), self.crc32, &mut dst)?;
+
+        Ok(dst)
+    }
+}
+
+impl AsRef<[u8]> for RawBlock {
+    fn as_ref(&self) -> &[u8] {
+        &self.buf
+    }
+}
+
+impl From<RawBlock> for Vec<u8> {
+    fn from(block: RawBlock) -> Self {
+        block.buf
+    }
+}
@@ -0,0 +1,188 @@
+use std::io::{self, Read};
+
+use super::RawBlock;
+
+/// An iterator over raw BGZF blocks.
+///
+/// This reads each block as-is, without inflating its compressed data. It is useful for tools
+/// that work at the block level, e.g., indexers, splitters, or repair tools.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io::{self, Write};
+/// use noodles_bgzf as bgzf;
+///
+/// let mut writer = bgzf::Writer::new(Vec::new());
+/// writer.write_all(b"noodles")?;
+/// let data = writer.finish()?;
+///
+/// let blocks: Vec<_> = bgzf::reader::RawBlocks::new(&data[..]).collect::<io::Result<_>>()?;
+/// assert_eq!(blocks.len(), 2);
+/// assert_eq!(blocks[1].position(), blocks[0].size());
+/// # Ok::<_, io::Error>(())
+/// ```
+pub struct RawBlocks<R> {
+    inner: R,
+    position: u64,
+}
+
+impl<R> RawBlocks<R>
+where
+    R: Read,
+{
+    /// Creates an iterator over raw BGZF blocks.
+    ///
+    /// The stream is assumed to start at the compressed position 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bgzf as bgzf;
+    /// let blocks = bgzf::reader::RawBlocks::new(io::empty());
+    /// ```
+    pub fn new(inner: R) -> Self {
+        Self::with_position(inner, 0)
+    }
+
+    /// Creates an iterator over raw BGZF blocks starting at the given compressed position.
+    ///
+    /// The underlying reader must already be at `position`. This is used to report block
+    /// positions after the underlying reader has been seeked.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bgzf as bgzf;
+    /// let blocks = bgzf::reader::RawBlocks::with_position(io::empty(), 8);
+    /// assert_eq!(blocks.position(), 8);
+    /// ```
+    pub fn with_position(inner: R, position: u64) -> Self {
+        Self { inner, position }
+    }
+
+    /// Returns a reference to the underlying reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bgzf as bgzf;
+    /// let blocks = bgzf::reader::RawBlocks::new(io::empty());
+    /// let _inner = blocks.get_ref();
+    /// ```
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Returns the underlying reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bgzf as bgzf;
+    /// let blocks = bgzf::reader::RawBlocks::new(io::empty());
+    /// let _inner = blocks.into_inner();
+    /// ```
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    /// Returns the compressed position of the next block.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bgzf as bgzf;
+    /// let blocks = bgzf::reader::RawBlocks::new(io::empty());
+    /// assert_eq!(blocks.position(), 0);
+    /// ```
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+
+    fn read_raw_block(&mut self) -> io::Result<Option<RawBlock>> {
+        use super::block::read_frame_into;
+
+        let mut buf = Vec::new();
+
+        if read_frame_into(&mut self.inner, &mut buf)?.is_none() {
+            return Ok(None);
+        }
+
+        let block = RawBlock::try_new(self.position, buf)?;
+        self.position += block.size();
+
+        Ok(Some(block))
+    }
+}
+
+impl<R> Iterator for RawBlocks<R>
+where
+    R: Read,
+{
+    type Item = io::Result<RawBlock>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.read_raw_block().transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use super::*;
+    use crate::Writer;
+
+    #[test]
+    fn test_next() -> io::Result<()> {
+        let mut writer = Writer::new(Vec::new());
+        writer.write_all(b"noodles")?;
+        writer.flush()?;
+        writer.write_all(b"bgzf")?;
+        let data = writer.finish()?;
+
+        let blocks: Vec<_> = RawBlocks::new(&data[..]).collect::<io::Result<_>>()?;
+        assert_eq!(blocks.len(), 3);
+
+        let mut position = 0;
+
+        for (block, expected) in blocks.iter().zip([&b"noodles"[..], b"bgzf", b""]) {
+            assert_eq!(block.position(), position);
+            assert_eq!(block.uncompressed_size(), expected.len());
+            assert_eq!(block.inflate()?, expected);
+            position += block.size();
+        }
+
+        assert_eq!(position, data.len() as u64);
+        assert!(!blocks[0].is_eof_marker());
+        assert!(blocks[2].is_eof_marker());
+
+        let raw_data: Vec<_> = blocks
+            .iter()
+            .flat_map(|block| block.as_ref())
+            .copied()
+            .collect();
+        assert_eq!(raw_data, data);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_next_with_invalid_header() {
+        let mut data = crate::writer::BGZF_EOF.to_vec();
+        data[0] = 0x00;
+
+        let mut blocks = RawBlocks::new(&data[..]);
+
+        assert!(matches!(
+            blocks.next(),
+            Some(Err(e)) if e.kind() == io::ErrorKind::InvalidData
+        ));
+    }
+}
@@ -0,0 +1,228 @@
+use std::{
+    collections::VecDeque,
+    io::{self, Read, Write},
+    num::NonZeroUsize,
+    thread,
+};
+
+use crossbeam_channel::{Receiver, Sender};
+
+use super::{
+    reader::{RawBlock, RawBlocks},
+    writer::{CompressionLevel, BGZF_EOF, MAX_BUF_SIZE},
+};
+
+type BufferedTx = Sender<io::Result<Vec<u8>>>;
+type BufferedRx = Receiver<io::Result<Vec<u8>>>;
+type DeflateTx = Sender<(RawBlock, BufferedTx)>;
+type DeflateRx = Receiver<(RawBlock, BufferedTx)>;
+
+/// Recompresses a BGZF stream with the given compression level.
+///
+/// Blocks are inflated and deflated in parallel, using as many workers as there are available
+/// logical CPUs, and written in order. The data is not parsed, so this works with any BGZF
+/// stream, e.g., BAM, BCF, or bgzipped VCF.
+///
+/// Block boundaries are kept, unless a recompressed block would exceed the maximum block size, in
+/// which case, its data is split into multiple blocks. Empty blocks and EOF markers are removed,
+/// and a single EOF marker is written at the end of the output stream.
+///
+/// Because compressed positions change, indexes of the input stream are not valid for the output
+/// stream.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io::{self, Read, Write};
+/// use noodles_bgzf::{self as bgzf, writer::CompressionLevel};
+///
+/// let mut writer = bgzf::writer::Builder::default()
+///     .set_compression_level(CompressionLevel::fast())
+///     .build_with_writer(Vec::new());
+/// writer.write_all(b"noodles")?;
+/// let src = writer.finish()?;
+///
+/// let mut dst = Vec::new();
+/// bgzf::recompress(&src[..], &mut dst, CompressionLevel::best())?;
+///
+/// let mut reader = bgzf::Reader::new(&dst[..]);
+/// let mut buf = Vec::new();
+/// reader.read_to_end(&mut buf)?;
+/// assert_eq!(buf, b"noodles");
+/// # Ok::<_, io::Error>(())
+/// ```
+pub fn recompress<R, W>(
+    reader: R,
+    writer: &mut W,
+    compression_level: CompressionLevel,
+) -> io::Result<()>
+where
+    R: Read,
+    W: Write,
+{
+    let worker_count = thread::available_parallelism()
+        .unwrap_or_else(|_| NonZeroUsize::new(1).unwrap())
+        .get();
+
+    thread::scope(|scope| {
+        let (deflate_tx, deflate_rx): (DeflateTx, DeflateRx) =
+            crossbeam_channel::bounded(worker_count);
+
+        for _ in 0..worker_count {
+            let deflate_rx = deflate_rx.clone();
+
+            scope.spawn(move || {
+                while let Ok((block, buffered_tx)) = deflate_rx.recv() {
+                    let result = recompress_block(&block, compression_level);
+                    buffered_tx.send(result).ok();
+                }
+            });
+        }
+
+        // The workers stop when `deflate_tx` is dropped at the end of this call.
+        copy_blocks(reader, writer, deflate_tx, worker_count)
+    })
+}
+
+fn copy_blocks<R, W>(
+    reader: R,
+    writer: &mut W,
+    deflate_tx: DeflateTx,
+    queue_depth: usize,
+) -> io::Result<()>
+where
+    R: Read,
+    W: Write,
+{
+    let mut queue: VecDeque<BufferedRx> = VecDeque::with_capacity(queue_depth);
+
+    for result in RawBlocks::new(reader) {
+        let block = result?;
+
+        if block.uncompressed_size() == 0 {
+            continue;
+        }
+
+        if queue.len() >= queue_depth {
+            write_next_block(writer, &mut queue)?;
+        }
+
+        let (buffered_tx, buffered_rx) = crossbeam_channel::bounded(1);
+        deflate_tx.send((block, buffered_tx)).unwrap();
+        queue.push_back(buffered_rx);
+    }
+
+    while !queue.is_empty() {
+        write_next_block(writer, &mut queue)?;
+    }
+
+    writer.write_all(BGZF_EOF)?;
+
+    Ok(())
+}
+
+fn write_next_block<W>(writer: &mut W, queue: &mut VecDeque<BufferedRx>) -> io::Result<()>
+where
+    W: Write,
+{
+    if let Some(buffered_rx) = queue.pop_front() {
+        // SAFETY: Workers always send a result.
+        let buf = buffered_rx.recv().unwrap()?;
+        writer.write_all(&buf)?;
+    }
+
+    Ok(())
+}
+
+fn recompress_block(block: &RawBlock, compression_level: CompressionLevel) -> io::Result<Vec<u8>> {
+    use crate::multithreaded_writer::compress;
+
+    let data = block.inflate()?;
+
+    let mut dst = Vec::new();
+    let mut buf = Vec::new();
+
+    for chunk in data.chunks(MAX_BUF_SIZE) {
+        compress(chunk, compression_level, &mut buf)?;
+        dst.extend(&buf);
+    }
+
+    Ok(dst)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Read;
+
+    use super::*;
+    use crate::{writer::Builder, Reader, Writer};
+
+    #[test]
+    fn test_recompress() -> io::Result<()> {
+        let data: Vec<_> = (0..1 << 18).map(|i| (i % 251) as u8).collect();
+
+        let mut writer = Builder::default()
+            .set_compression_level(CompressionLevel::fast())
+            .build_with_writer(Vec::new());
+
+        writer.write_all(&data[..1024])?;
+        writer.flush()?;
+        writer.write_all(&data[1024..])?;
+
+        let src = writer.finish()?;
+
+        let mut actual = Vec::new();
+        recompress(&src[..], &mut actual, CompressionLevel::default())?;
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_all(&data[..1024])?;
+        writer.flush()?;
+        writer.write_all(&data[1024..])?;
+        let expected = writer.finish()?;
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_recompress_with_no_compression() -> io::Result<()> {
+        let data: Vec<_> = (0..1 << 18).map(|i| (i * 7 % 256) as u8).collect();
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_all(&data)?;
+        let src = writer.finish()?;
+
+        let mut dst = Vec::new();
+        recompress(&src[..], &mut dst, CompressionLevel::none())?;
+        assert!(dst.len() > data.len());
+        crate::check(&dst[..])?;
+
+        let mut reader = Reader::new(&dst[..]);
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf)?;
+        assert_eq!(buf, data);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_recompress_with_invalid_block() -> io::Result<()> {
+        let mut writer = Writer::new(Vec::new());
+        writer.write_all(b"noodles")?;
+        let mut src = writer.finish()?;
+
+        // CRC32
+        let i = src.len() - BGZF_EOF.len() - 8;
+        src[i] ^= 0xff;
+
+        let mut dst = Vec::new();
+
+        assert!(matches!(
+            recompress(&src[..], &mut dst, CompressionLevel::default()),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+}
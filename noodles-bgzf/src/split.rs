@@ -0,0 +1,170 @@
+use std::io::{self, Read, Write};
+
+use super::{reader::RawBlocks, writer::BGZF_EOF};
+
+/// Splits a BGZF stream at block boundaries.
+///
+/// The stream is split at the block boundaries nearest the given uncompressed positions, which
+/// must be sorted in nondecreasing order. Shard `i` is written to `writers[i]`, so there must be
+/// exactly one more writer than positions.
+///
+/// Blocks are copied as-is, i.e., without being inflated or recompressed. EOF markers in the input
+/// stream are removed, and each shard is terminated with an EOF marker. Concatenating the shards
+/// (see [`crate::concatenate`]) gives the original data.
+///
+/// Positions that are nearest to the same block boundary produce empty shards, i.e., shards with
+/// only an EOF marker.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io::{self, Read, Write};
+/// use noodles_bgzf as bgzf;
+///
+/// let mut writer = bgzf::Writer::new(Vec::new());
+/// writer.write_all(b"noodles")?;
+/// writer.flush()?;
+/// writer.write_all(b"-bgzf")?;
+/// let data = writer.finish()?;
+///
+/// let mut shards = vec![Vec::new(), Vec::new()];
+/// bgzf::split(&data[..], &[6], &mut shards)?;
+///
+/// let mut buf = Vec::new();
+/// bgzf::Reader::new(&shards[0][..]).read_to_end(&mut buf)?;
+/// assert_eq!(buf, b"noodles");
+/// # Ok::<_, io::Error>(())
+/// ```
+pub fn split<R, W>(reader: R, uncompressed_positions: &[u64], writers: &mut [W]) -> io::Result<()>
+where
+    R: Read,
+    W: Write,
+{
+    if writers.len() != uncompressed_positions.len() + 1 {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "writer count must be one more than the position count",
+        ));
+    }
+
+    if uncompressed_positions.windows(2).any(|w| w[0] > w[1]) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "positions are not sorted",
+        ));
+    }
+
+    let mut i = 0;
+    let mut block_start = 0;
+
+    for result in RawBlocks::new(reader) {
+        let block = result?;
+
+        if block.is_eof_marker() {
+            continue;
+        }
+
+        let block_end = block_start + block.uncompressed_size() as u64;
+
+        // Positions in this block that are nearer the start of the block split before it.
+        while let Some(&position) = uncompressed_positions.get(i) {
+            if position < block_end && position - block_start <= block_end - position {
+                i += 1;
+            } else {
+                break;
+            }
+        }
+
+        writers[i].write_all(block.as_ref())?;
+
+        // The remaining positions in this block split after it.
+        while let Some(&position) = uncompressed_positions.get(i) {
+            if position < block_end {
+                i += 1;
+            } else {
+                break;
+            }
+        }
+
+        block_start = block_end;
+    }
+
+    for writer in writers {
+        writer.write_all(BGZF_EOF)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Reader;
+
+    fn build_data() -> io::Result<Vec<u8>> {
+        use crate::Writer;
+
+        let mut writer = Writer::new(Vec::new());
+
+        // Blocks: [0, 4), [4, 8), [8, 12)
+        for data in [b"abcd", b"efgh", b"ijkl"] {
+            writer.write_all(data)?;
+            writer.flush()?;
+        }
+
+        writer.finish()
+    }
+
+    fn read_shards(shards: &[Vec<u8>]) -> io::Result<Vec<Vec<u8>>> {
+        shards
+            .iter()
+            .map(|shard| {
+                let mut buf = Vec::new();
+                Reader::new(&shard[..]).read_to_end(&mut buf)?;
+                Ok(buf)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_split() -> io::Result<()> {
+        let data = build_data()?;
+
+        let mut shards = vec![Vec::new(); 3];
+        split(&data[..], &[5, 7], &mut shards)?;
+        assert_eq!(read_shards(&shards)?, [&b"abcd"[..], b"efgh", b"ijkl"]);
+
+        let mut shards = vec![Vec::new(); 4];
+        split(&data[..], &[0, 2, 100], &mut shards)?;
+        assert_eq!(read_shards(&shards)?, [&b""[..], b"", b"abcdefghijkl", b""]);
+
+        for shard in &shards {
+            assert!(shard.ends_with(BGZF_EOF));
+        }
+
+        let mut shards = vec![Vec::new(); 2];
+        split(&data[..], &[], &mut shards[..1])?;
+        assert_eq!(shards[0], data);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_split_with_invalid_input() -> io::Result<()> {
+        let data = build_data()?;
+
+        let mut shards = vec![Vec::new(); 2];
+        assert!(matches!(
+            split(&data[..], &[2, 6], &mut shards),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        let mut shards = vec![Vec::new(); 3];
+        assert!(matches!(
+            split(&data[..], &[6, 2], &mut shards),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+}
@@ -30,6 +30,24 @@ const UNCOMPRESSED_POSITION_MASK: u64 = 0xffff;
 /// position, 35047 (`88 e7`).
 ///
 /// This is also called a virtual file offset; or, simply, a virtual offset.
+///
+/// # Ordering
+///
+/// Virtual positions are ordered by compressed position and then by uncompressed position, which
+/// is the same as the order of their raw values. This is the order of data in the uncompressed
+/// stream, so [`Ord::min`] and [`Ord::max`] give the earlier and later positions, respectively.
+///
+/// ```
+/// use noodles_bgzf as bgzf;
+///
+/// let a = bgzf::VirtualPosition::try_from((8, 13))?;
+/// let b = bgzf::VirtualPosition::try_from((21, 3))?;
+///
+/// assert!(a < b);
+/// assert_eq!(a.min(b), a);
+/// assert_eq!(a.max(b), b);
+/// # Ok::<_, bgzf::virtual_position::TryFromU64U16TupleError>(())
+/// ```
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
 pub struct VirtualPosition(u64);
 
@@ -71,6 +89,109 @@ impl VirtualPosition {
     pub fn uncompressed(self) -> u16 {
         (self.0 & UNCOMPRESSED_POSITION_MASK) as u16
     }
+
+    /// Returns a virtual position with the given compressed position.
+    ///
+    /// The uncompressed position is unchanged.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the compressed position is larger than 2^48 - 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    ///
+    /// let virtual_position = bgzf::VirtualPosition::try_from((8, 13))?;
+    /// assert_eq!(
+    ///     virtual_position.with_compressed(21)?,
+    ///     bgzf::VirtualPosition::try_from((21, 13))?
+    /// );
+    /// # Ok::<_, bgzf::virtual_position::TryFromU64U16TupleError>(())
+    /// ```
+    pub fn with_compressed(self, compressed_pos: u64) -> Result<Self, TryFromU64U16TupleError> {
+        Self::try_from((compressed_pos, self.uncompressed()))
+    }
+
+    /// Returns a virtual position with the given uncompressed position.
+    ///
+    /// The compressed position is unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    ///
+    /// let virtual_position = bgzf::VirtualPosition::try_from((8, 13))?;
+    /// assert_eq!(
+    ///     virtual_position.with_uncompressed(0),
+    ///     bgzf::VirtualPosition::try_from((8, 0))?
+    /// );
+    /// # Ok::<_, bgzf::virtual_position::TryFromU64U16TupleError>(())
+    /// ```
+    pub fn with_uncompressed(self, uncompressed_pos: u16) -> Self {
+        Self((self.0 & !UNCOMPRESSED_POSITION_MASK) | u64::from(uncompressed_pos))
+    }
+
+    /// Adds to the compressed position, saturating at [`Self::MAX`].
+    ///
+    /// The uncompressed position is unchanged unless the result saturates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    ///
+    /// let virtual_position = bgzf::VirtualPosition::try_from((8, 13))?;
+    ///
+    /// assert_eq!(
+    ///     virtual_position.saturating_add_compressed(13),
+    ///     bgzf::VirtualPosition::try_from((21, 13))?
+    /// );
+    ///
+    /// assert_eq!(
+    ///     virtual_position.saturating_add_compressed(u64::MAX),
+    ///     bgzf::VirtualPosition::MAX
+    /// );
+    /// # Ok::<_, bgzf::virtual_position::TryFromU64U16TupleError>(())
+    /// ```
+    pub fn saturating_add_compressed(self, n: u64) -> Self {
+        self.compressed()
+            .checked_add(n)
+            .and_then(|compressed_pos| self.with_compressed(compressed_pos).ok())
+            .unwrap_or(Self::MAX)
+    }
+
+    /// Subtracts from the compressed position, saturating at [`Self::MIN`].
+    ///
+    /// The uncompressed position is unchanged unless the result saturates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    ///
+    /// let virtual_position = bgzf::VirtualPosition::try_from((21, 13))?;
+    ///
+    /// assert_eq!(
+    ///     virtual_position.saturating_sub_compressed(13),
+    ///     bgzf::VirtualPosition::try_from((8, 13))?
+    /// );
+    ///
+    /// assert_eq!(
+    ///     virtual_position.saturating_sub_compressed(34),
+    ///     bgzf::VirtualPosition::MIN
+    /// );
+    /// # Ok::<_, bgzf::virtual_position::TryFromU64U16TupleError>(())
+    /// ```
+    pub fn saturating_sub_compressed(self, n: u64) -> Self {
+        match self.compressed().checked_sub(n) {
+            // SAFETY: `compressed_pos` <= `self.compressed()` <= 2^48 - 1.
+            Some(compressed_pos) => self.with_compressed(compressed_pos).unwrap(),
+            None => Self::MIN,
+        }
+    }
 }
 
 impl From<u64> for VirtualPosition {
@@ -139,6 +260,85 @@ impl From<VirtualPosition> for (u64, u16) {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_ord() -> Result<(), TryFromU64U16TupleError> {
+        let a = VirtualPosition::try_from((8, 65535))?;
+        let b = VirtualPosition::try_from((13, 0))?;
+        let c = VirtualPosition::try_from((13, 1))?;
+
+        assert!(a < b);
+        assert!(b < c);
+        assert_eq!(a.max(c), c);
+        assert_eq!(c.min(a), a);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_with_compressed() -> Result<(), TryFromU64U16TupleError> {
+        let pos = VirtualPosition::try_from((8, 13))?;
+
+        assert_eq!(pos.with_compressed(0)?, VirtualPosition::try_from((0, 13))?);
+        assert_eq!(
+            pos.with_compressed(MAX_COMPRESSED_POSITION)?,
+            VirtualPosition::try_from((MAX_COMPRESSED_POSITION, 13))?
+        );
+        assert_eq!(
+            pos.with_compressed(MAX_COMPRESSED_POSITION + 1),
+            Err(TryFromU64U16TupleError::CompressedPositionOverflow)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_with_uncompressed() -> Result<(), TryFromU64U16TupleError> {
+        let pos = VirtualPosition::try_from((8, 13))?;
+        assert_eq!(
+            pos.with_uncompressed(21),
+            VirtualPosition::try_from((8, 21))?
+        );
+        assert_eq!(
+            pos.with_uncompressed(MAX_UNCOMPRESSED_POSITION),
+            VirtualPosition::try_from((8, MAX_UNCOMPRESSED_POSITION))?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_saturating_add_compressed() -> Result<(), TryFromU64U16TupleError> {
+        let pos = VirtualPosition::try_from((8, 13))?;
+
+        assert_eq!(pos.saturating_add_compressed(0), pos);
+        assert_eq!(
+            pos.saturating_add_compressed(MAX_COMPRESSED_POSITION - 8),
+            VirtualPosition::try_from((MAX_COMPRESSED_POSITION, 13))?
+        );
+        assert_eq!(
+            pos.saturating_add_compressed(MAX_COMPRESSED_POSITION - 7),
+            VirtualPosition::MAX
+        );
+        assert_eq!(
+            pos.saturating_add_compressed(u64::MAX),
+            VirtualPosition::MAX
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_saturating_sub_compressed() -> Result<(), TryFromU64U16TupleError> {
+        let pos = VirtualPosition::try_from((8, 13))?;
+
+        assert_eq!(
+            pos.saturating_sub_compressed(8),
+            VirtualPosition::try_from((0, 13))?
+        );
+        assert_eq!(pos.saturating_sub_compressed(9), VirtualPosition::MIN);
+
+        Ok(())
+    }
+
     #[test]
     fn test_from_u64_for_virtual_position() {
         let pos = VirtualPosition::from(88384945211);
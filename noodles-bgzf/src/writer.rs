@@ -74,6 +74,7 @@ where
     inner: Option<W>,
     position: u64,
     buf: Vec<u8>,
+    cdata_buf: Vec<u8>,
     compression_level: CompressionLevelImpl,
 }
 
@@ -151,16 +152,51 @@ where
         VirtualPosition::try_from((self.position, uncompressed_position)).unwrap()
     }
 
-    fn flush_block(&mut self) -> io::Result<()> {
+    /// Ends the current block and returns the virtual position of the start of the next block.
+    ///
+    /// This forces a block boundary, e.g., so that the next record starts at the beginning of a
+    /// block, which is useful for block-aligned indexes. If the current block is empty, no block
+    /// is written.
+    ///
+    /// Unlike [`Write::flush`], this returns the resulting virtual position, which always has an
+    /// uncompressed position of 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::{self, Write};
+    /// use noodles_bgzf as bgzf;
+    ///
+    /// let mut writer = bgzf::Writer::new(Vec::new());
+    /// writer.write_all(b"noodles")?;
+    ///
+    /// let virtual_position = writer.flush_block()?;
+    /// assert_eq!(virtual_position.compressed(), writer.get_ref().len() as u64);
+    /// assert_eq!(virtual_position.uncompressed(), 0);
+    ///
+    /// assert_eq!(writer.flush_block()?, virtual_position);
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn flush_block(&mut self) -> io::Result<VirtualPosition> {
+        if !self.buf.is_empty() {
+            self.write_block()?;
+        }
+
+        Ok(self.virtual_position())
+    }
+
+    fn write_block(&mut self) -> io::Result<()> {
         use self::frame::{write_header, write_trailer};
 
-        let (cdata, crc32, r#isize) = deflate_data(&self.buf, self.compression_level)?;
+        self.cdata_buf.clear();
+        let (crc32, r#isize) =
+            deflate_data(&self.buf, self.compression_level, &mut self.cdata_buf)?;
 
         let inner = self.inner.as_mut().unwrap();
-        let block_size = BGZF_HEADER_SIZE + cdata.len() + gz::TRAILER_SIZE;
+        let block_size = BGZF_HEADER_SIZE + self.cdata_buf.len() + gz::TRAILER_SIZE;
 
         write_header(inner, block_size)?;
-        inner.write_all(&cdata[..])?;
+        inner.write_all(&self.cdata_buf)?;
         write_trailer(inner, crc32, r#isize)?;
 
         self.position += block_size as u64;
@@ -251,50 +287,109 @@ where
         if self.buf.is_empty() {
             Ok(())
         } else {
-            self.flush_block()
+            self.write_block()
         }
     }
 }
 
+// Compresses the data and appends it to `dst`.
+//
+// This returns the checksum (`CRC32`) and size (`ISIZE`) of the uncompressed data. `dst` is not
+// cleared, which allows callers to reuse its allocation or to prepend a header.
 #[cfg(feature = "libdeflate")]
 pub(crate) fn deflate_data(
     src: &[u8],
     compression_level: libdeflater::CompressionLvl,
-) -> io::Result<(Vec<u8>, u32, u32)> {
+    dst: &mut Vec<u8>,
+) -> io::Result<(u32, u32)> {
     use libdeflater::Compressor;
 
+    if compression_level == CompressionLevelImpl::from(CompressionLevel::none()) {
+        return Ok(deflate_stored(src, dst));
+    }
+
     let mut encoder = Compressor::new(compression_level);
 
+    let start = dst.len();
     let max_len = encoder.deflate_compress_bound(src.len());
-    let mut dst = vec![0; max_len];
+    dst.resize(start + max_len, 0);
 
     let len = encoder
-        .deflate_compress(src, &mut dst)
+        .deflate_compress(src, &mut dst[start..])
         .map_err(|_| io::Error::from(io::ErrorKind::InvalidInput))?;
 
-    dst.resize(len, 0);
+    dst.truncate(start + len);
 
     let mut crc = Crc::new();
     crc.update(src);
 
-    Ok((dst, crc.sum(), crc.amount()))
+    Ok((crc.sum(), crc.amount()))
 }
 
+// Compresses the data and appends it to `dst`.
+//
+// This returns the checksum (`CRC32`) and size (`ISIZE`) of the uncompressed data. `dst` is not
+// cleared, which allows callers to reuse its allocation or to prepend a header.
 #[cfg(not(feature = "libdeflate"))]
 pub(crate) fn deflate_data(
     src: &[u8],
     compression_level: flate2::Compression,
-) -> io::Result<(Vec<u8>, u32, u32)> {
+    dst: &mut Vec<u8>,
+) -> io::Result<(u32, u32)> {
     use flate2::write::DeflateEncoder;
 
-    let mut encoder = DeflateEncoder::new(Vec::new(), compression_level);
+    if compression_level == CompressionLevelImpl::from(CompressionLevel::none()) {
+        return Ok(deflate_stored(src, dst));
+    }
+
+    let mut encoder = DeflateEncoder::new(dst, compression_level);
     encoder.write_all(src)?;
-    let dst = encoder.finish()?;
+    encoder.finish()?;
+
+    let mut crc = Crc::new();
+    crc.update(src);
+
+    Ok((crc.sum(), crc.amount()))
+}
+
+// Appends the data to `dst` as stored (uncompressed) DEFLATE blocks.
+//
+// This does not depend on the DEFLATE implementation, which, at compression level 0, may still
+// attempt to compress or use a different block layout.
+fn deflate_stored(src: &[u8], dst: &mut Vec<u8>) -> (u32, u32) {
+    // RFC 1951 § 3.2.4 "Non-compressed blocks (BTYPE=00)"
+    const MAX_STORED_BLOCK_LEN: usize = 65535;
+    const STORED_BLOCK_HEADER_SIZE: usize = 5;
+
+    let block_count = cmp::max(
+        1,
+        (src.len() + MAX_STORED_BLOCK_LEN - 1) / MAX_STORED_BLOCK_LEN,
+    );
+    dst.reserve(src.len() + block_count * STORED_BLOCK_HEADER_SIZE);
+
+    let mut chunks = src.chunks(MAX_STORED_BLOCK_LEN).peekable();
+
+    if chunks.peek().is_none() {
+        dst.extend([0x01, 0x00, 0x00, 0xff, 0xff]);
+    }
+
+    while let Some(chunk) = chunks.next() {
+        // BFINAL is set on the last block. BTYPE = 00.
+        let header = if chunks.peek().is_none() { 0x01 } else { 0x00 };
+        dst.push(header);
+
+        // SAFETY: `chunk.len()` <= `MAX_STORED_BLOCK_LEN`.
+        let len = chunk.len() as u16;
+        dst.extend(len.to_le_bytes());
+        dst.extend((!len).to_le_bytes());
+
+        dst.extend(chunk);
+    }
 
     let mut crc = Crc::new();
     crc.update(src);
 
-    Ok((dst, crc.sum(), crc.amount()))
+    (crc.sum(), crc.amount())
 }
 
 #[cfg(test)]
@@ -324,6 +419,33 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_flush_block() -> Result<(), Box<dyn std::error::Error>> {
+        use std::io::{Cursor, Read};
+
+        use crate::Reader;
+
+        let mut writer = Writer::new(Vec::new());
+
+        assert_eq!(writer.flush_block()?, VirtualPosition::from(0));
+        assert!(writer.get_ref().is_empty());
+
+        writer.write_all(b"noodles")?;
+        let start = writer.flush_block()?;
+        writer.write_all(b"bgzf")?;
+
+        let data = writer.finish()?;
+
+        let mut reader = Reader::new(Cursor::new(data));
+        reader.seek(start)?;
+
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf)?;
+        assert_eq!(buf, b"bgzf");
+
+        Ok(())
+    }
+
     #[test]
     fn test_finish() -> io::Result<()> {
         let mut writer = Writer::new(Vec::new());
@@ -336,4 +458,63 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_write_with_no_compression() -> io::Result<()> {
+        use std::io::Read;
+
+        use crate::Reader;
+
+        let mut writer = Builder::default()
+            .set_compression_level(CompressionLevel::none())
+            .build_with_writer(Vec::new());
+
+        writer.write_all(b"noodles")?;
+
+        let data = writer.finish()?;
+
+        // block header, stored block header, data, block trailer, EOF block
+        assert_eq!(
+            data.len(),
+            BGZF_HEADER_SIZE + 5 + 7 + gz::TRAILER_SIZE + BGZF_EOF.len()
+        );
+        assert_eq!(
+            &data[BGZF_HEADER_SIZE..BGZF_HEADER_SIZE + 12],
+            b"\x01\x07\x00\xf8\xffnoodles"
+        );
+
+        let mut reader = Reader::new(&data[..]);
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf)?;
+
+        assert_eq!(buf, b"noodles");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_deflate_stored() -> io::Result<()> {
+        use std::io::Read;
+
+        use flate2::read::DeflateDecoder;
+
+        let mut cdata = Vec::new();
+        let (crc32, r#isize) = deflate_stored(&[], &mut cdata);
+        assert_eq!(cdata, [0x01, 0x00, 0x00, 0xff, 0xff]);
+        assert_eq!(crc32, 0);
+        assert_eq!(r#isize, 0);
+
+        let src: Vec<_> = (0..0x20000).map(|i| (i % 251) as u8).collect();
+        cdata.clear();
+        let (_, r#isize) = deflate_stored(&src, &mut cdata);
+        assert_eq!(cdata.len(), src.len() + 3 * 5);
+        assert_eq!(r#isize, 0x20000);
+
+        let mut decoder = DeflateDecoder::new(&cdata[..]);
+        let mut buf = Vec::new();
+        decoder.read_to_end(&mut buf)?;
+        assert_eq!(buf, src);
+
+        Ok(())
+    }
 }
@@ -13,6 +13,9 @@ impl Builder {
     ///
     /// By default, the compression level is set to level 6.
     ///
+    /// With [`CompressionLevel::none`], block data is written as stored (uncompressed) DEFLATE
+    /// blocks. This is useful for intermediate files, trading size for speed.
+    ///
     /// # Examples
     ///
     /// ```
@@ -43,6 +46,7 @@ impl Builder {
             inner: Some(writer),
             position: 0,
             buf: Vec::with_capacity(MAX_BUF_SIZE),
+            cdata_buf: Vec::new(),
             compression_level: self.compression_level.into(),
         }
     }
@@ -1,3 +1,6 @@
+//! Async CRAM reader.
+
+mod builder;
 mod crc_reader;
 mod data_container;
 mod header_container;
@@ -5,21 +8,29 @@ mod num;
 mod query;
 mod records;
 
-pub use self::crc_reader::CrcReader;
+pub use self::builder::Builder;
+
+use self::crc_reader::CrcReader;
 
 use bytes::BytesMut;
-use futures::Stream;
+use futures::{stream, Stream};
 use noodles_core::Region;
 use noodles_fasta as fasta;
 use noodles_sam as sam;
 use tokio::io::{self, AsyncRead, AsyncReadExt, AsyncSeek, AsyncSeekExt, SeekFrom};
 
-use crate::{crai, file_definition::Version, DataContainer, FileDefinition, Record};
+use crate::{
+    crai, file_definition::Version, reader::ReadNameTemplate, DataContainer, FileDefinition, Record,
+};
 
 /// An async CRAM reader.
 pub struct Reader<R> {
     inner: R,
     buf: BytesMut,
+    version: Version,
+    try_recover: bool,
+    verify_md5_checksums: bool,
+    read_name_template: ReadNameTemplate,
 }
 
 impl<R> Reader<R>
@@ -36,10 +47,49 @@ where
     /// let reader = cram::AsyncReader::new(&data[..]);
     /// ```
     pub fn new(inner: R) -> Self {
-        Self {
-            inner,
-            buf: BytesMut::new(),
-        }
+        Builder::default().build_with_reader(inner)
+    }
+
+    /// Returns a reference to the underlying reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram as cram;
+    /// let data = [];
+    /// let reader = cram::AsyncReader::new(&data[..]);
+    /// assert!(reader.get_ref().is_empty());
+    /// ```
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Returns a mutable reference to the underlying reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram as cram;
+    /// let data = [];
+    /// let mut reader = cram::AsyncReader::new(&data[..]);
+    /// assert!(reader.get_mut().is_empty());
+    /// ```
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Unwraps and returns the underlying reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram as cram;
+    /// let data = [];
+    /// let reader = cram::AsyncReader::new(&data[..]);
+    /// assert!(reader.into_inner().is_empty());
+    /// ```
+    pub fn into_inner(self) -> R {
+        self.inner
     }
 
     /// Reads the CRAM file definition.
@@ -68,6 +118,8 @@ where
         let format = read_format(&mut self.inner).await?;
         let file_id = read_file_id(&mut self.inner).await?;
 
+        self.version = format;
+
         Ok(FileDefinition::new(format, file_id))
     }
 
@@ -98,13 +150,15 @@ where
     /// ```
     pub async fn read_file_header(&mut self) -> io::Result<String> {
         use self::header_container::read_header_container;
-        read_header_container(&mut self.inner, &mut self.buf).await
+        read_header_container(&mut self.inner, &mut self.buf, self.version).await
     }
 
     /// Reads a data container.
     ///
     /// This returns `None` if the container header is the EOF container header, which signals the
-    /// end of the stream.
+    /// end of the stream. If the stream ends before the EOF container, this returns an error
+    /// wrapping a [`crate::reader::TruncationError`], unless the reader was built to try to
+    /// recover (see [`Builder::set_try_recover`]), in which case it also returns `None`.
     ///
     /// # Examples
     ///
@@ -129,7 +183,94 @@ where
     pub async fn read_data_container(&mut self) -> io::Result<Option<DataContainer>> {
         use self::data_container::read_data_container;
 
-        read_data_container(&mut self.inner, &mut self.buf).await
+        let result = read_data_container(&mut self.inner, &mut self.buf, self.version).await;
+        self.recover(result)
+    }
+
+    /// Reads a data container and its header.
+    ///
+    /// This returns `None` if the container header is the EOF container header, which signals the
+    /// end of the stream. If the stream ends before the EOF container, this returns an error
+    /// wrapping a [`crate::reader::TruncationError`], unless the reader was built to try to
+    /// recover (see [`Builder::set_try_recover`]), in which case it also returns `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::io;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> io::Result<()> {
+    /// use noodles_cram as cram;
+    /// use tokio::fs::File;
+    ///
+    /// let mut reader = File::open("sample.cram").await.map(cram::AsyncReader::new)?;
+    /// reader.read_file_definition().await?;
+    /// reader.read_file_header().await?;
+    ///
+    /// while let Some((header, data_container)) =
+    ///     reader.read_data_container_with_container_header().await?
+    /// {
+    ///     // ...
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn read_data_container_with_container_header(
+        &mut self,
+    ) -> io::Result<Option<(crate::data_container::Header, DataContainer)>> {
+        use self::data_container::read_data_container_with_container_header;
+
+        let result =
+            read_data_container_with_container_header(&mut self.inner, &mut self.buf, self.version)
+                .await;
+
+        self.recover(result)
+    }
+
+    fn recover<T>(&self, result: io::Result<Option<T>>) -> io::Result<Option<T>> {
+        use crate::reader::truncation_error::is_truncation_error;
+
+        match result {
+            Err(e) if self.try_recover && is_truncation_error(&e) => Ok(None),
+            result => result,
+        }
+    }
+
+    /// Returns an (async) stream over data containers starting from the current (input) stream
+    /// position.
+    ///
+    /// The (input) stream position is expected to be at the start of a data container. Records
+    /// are not decoded.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use futures::TryStreamExt;
+    /// use noodles_cram as cram;
+    /// use tokio::fs::File;
+    ///
+    /// let mut reader = File::open("sample.cram").await.map(cram::AsyncReader::new)?;
+    /// reader.read_file_definition().await?;
+    /// reader.read_file_header().await?;
+    ///
+    /// let mut data_containers = reader.data_containers();
+    ///
+    /// while let Some(data_container) = data_containers.try_next().await? {
+    ///     // ...
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn data_containers(&mut self) -> impl Stream<Item = io::Result<DataContainer>> + '_ {
+        Box::pin(stream::try_unfold(self, |reader| async {
+            reader
+                .read_data_container()
+                .await
+                .map(|data_container| data_container.map(|c| (c, reader)))
+        }))
     }
 
     /// Returns an (async) stream over records starting from the current (input) stream position.
@@ -299,7 +440,21 @@ where
 {
     let major = reader.read_u8().await?;
     let minor = reader.read_u8().await?;
-    Ok(Version::new(major, minor))
+
+    let version = Version::new(major, minor);
+
+    if matches!(version.major(), 2 | 3) {
+        Ok(version)
+    } else {
+        Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "unsupported CRAM version: {}.{}",
+                version.major(),
+                version.minor()
+            ),
+        ))
+    }
 }
 
 async fn read_file_id<R>(reader: &mut R) -> io::Result<[u8; 20]>
@@ -341,6 +496,18 @@ mod tests {
         let data = [0x03, 0x00];
         let mut reader = &data[..];
         assert_eq!(read_format(&mut reader).await?, Version::new(3, 0));
+
+        let data = [0x02, 0x01];
+        let mut reader = &data[..];
+        assert_eq!(read_format(&mut reader).await?, Version::new(2, 1));
+
+        let data = [0x01, 0x00];
+        let mut reader = &data[..];
+        assert!(matches!(
+            read_format(&mut reader).await,
+            Err(ref e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
         Ok(())
     }
 
@@ -0,0 +1,94 @@
+use bytes::BytesMut;
+use tokio::io::AsyncRead;
+
+use super::Reader;
+use crate::{file_definition::Version, reader::ReadNameTemplate};
+
+/// An async CRAM reader builder.
+#[derive(Debug)]
+pub struct Builder {
+    try_recover: bool,
+    verify_md5_checksums: bool,
+    read_name_template: ReadNameTemplate,
+}
+
+impl Builder {
+    /// Sets whether to try to recover from a truncated stream.
+    ///
+    /// See [`crate::reader::Builder::set_try_recover`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram as cram;
+    /// let builder = cram::r#async::reader::Builder::default().set_try_recover(true);
+    /// ```
+    pub fn set_try_recover(mut self, try_recover: bool) -> Self {
+        self.try_recover = try_recover;
+        self
+    }
+
+    /// Sets whether to verify slice MD5 checksums.
+    ///
+    /// See [`crate::reader::Builder::set_verify_md5_checksums`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram as cram;
+    /// let builder = cram::r#async::reader::Builder::default().set_verify_md5_checksums(false);
+    /// ```
+    pub fn set_verify_md5_checksums(mut self, verify_md5_checksums: bool) -> Self {
+        self.verify_md5_checksums = verify_md5_checksums;
+        self
+    }
+
+    /// Sets the template for generated read names.
+    ///
+    /// See [`crate::reader::Builder::set_read_name_template`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::{self as cram, reader::ReadNameTemplate};
+    ///
+    /// let template = ReadNameTemplate::new(String::from("sample"), String::from(":"));
+    /// let builder = cram::r#async::reader::Builder::default().set_read_name_template(template);
+    /// ```
+    pub fn set_read_name_template(mut self, read_name_template: ReadNameTemplate) -> Self {
+        self.read_name_template = read_name_template;
+        self
+    }
+
+    /// Builds an async CRAM reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram as cram;
+    /// let reader = cram::r#async::reader::Builder::default().build_with_reader(&[][..]);
+    /// ```
+    pub fn build_with_reader<R>(self, reader: R) -> Reader<R>
+    where
+        R: AsyncRead + Unpin,
+    {
+        Reader {
+            inner: reader,
+            buf: BytesMut::new(),
+            version: Version::default(),
+            try_recover: self.try_recover,
+            verify_md5_checksums: self.verify_md5_checksums,
+            read_name_template: self.read_name_template,
+        }
+    }
+}
+
+impl Default for Builder {
+    fn default() -> Self {
+        Self {
+            try_recover: false,
+            verify_md5_checksums: true,
+            read_name_template: ReadNameTemplate::default(),
+        }
+    }
+}
@@ -6,34 +6,77 @@ use tokio::io::{self, AsyncRead, AsyncReadExt};
 use self::header::read_header;
 use crate::{
     data_container::DataContainer,
-    reader::data_container::{read_compression_header_from_block, read_slice},
+    file_definition::Version,
+    reader::{
+        data_container::{read_compression_header_from_block, read_slice},
+        truncation_error::into_truncated_container_error,
+    },
 };
 
 pub async fn read_data_container<R>(
     reader: &mut R,
     buf: &mut BytesMut,
+    version: Version,
 ) -> io::Result<Option<DataContainer>>
 where
     R: AsyncRead + Unpin,
 {
-    let header = match read_header(reader).await? {
+    let header = match read_header(reader, version).await? {
         Some(header) => header,
         None => return Ok(None),
     };
 
     buf.resize(header.len(), 0);
-    reader.read_exact(buf).await?;
+    reader
+        .read_exact(buf)
+        .await
+        .map_err(into_truncated_container_error)?;
     let mut buf = buf.split().freeze();
 
-    let compression_header = read_compression_header_from_block(&mut buf)?;
+    let compression_header = read_compression_header_from_block(&mut buf, version)?;
 
     let slice_count = header.landmarks().len();
     let mut slices = Vec::with_capacity(slice_count);
 
     for _ in 0..slice_count {
-        let slice = read_slice(&mut buf)?;
+        let slice = read_slice(&mut buf, version)?;
         slices.push(slice);
     }
 
     Ok(Some(DataContainer::new(compression_header, slices)))
 }
+
+pub async fn read_data_container_with_container_header<R>(
+    reader: &mut R,
+    buf: &mut BytesMut,
+    version: Version,
+) -> io::Result<Option<(crate::data_container::Header, DataContainer)>>
+where
+    R: AsyncRead + Unpin,
+{
+    let header = match read_header(reader, version).await? {
+        Some(header) => header,
+        None => return Ok(None),
+    };
+
+    buf.resize(header.len(), 0);
+    reader
+        .read_exact(buf)
+        .await
+        .map_err(into_truncated_container_error)?;
+    let mut buf = buf.split().freeze();
+
+    let compression_header = read_compression_header_from_block(&mut buf, version)?;
+
+    let slice_count = header.landmarks().len();
+    let mut slices = Vec::with_capacity(slice_count);
+
+    for _ in 0..slice_count {
+        let slice = read_slice(&mut buf, version)?;
+        slices.push(slice);
+    }
+
+    let data_container = DataContainer::new(compression_header, slices);
+
+    Ok(Some((header, data_container)))
+}
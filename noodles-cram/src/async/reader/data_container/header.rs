@@ -2,23 +2,63 @@ use tokio::io::{self, AsyncRead, AsyncReadExt};
 
 use crate::{
     data_container::Header,
+    file_definition::Version,
     r#async::reader::{
         num::{read_itf8, read_ltf8},
         CrcReader,
     },
+    reader::{truncation_error::into_truncated_container_error, TruncationError},
 };
 
-pub async fn read_header<R>(reader: &mut R) -> io::Result<Option<Header>>
+pub async fn read_header<R>(reader: &mut R, version: Version) -> io::Result<Option<Header>>
 where
     R: AsyncRead + Unpin,
 {
-    use crate::reader::data_container::header::{build_reference_sequence_context, is_eof};
-
     let mut crc_reader = CrcReader::new(reader);
 
-    let length = crc_reader.read_i32_le().await.and_then(|n| {
-        usize::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
-    })?;
+    let length = match read_length(&mut crc_reader).await? {
+        Some(n) => usize::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?,
+        None => return Err(TruncationError::MissingEofContainer.into()),
+    };
+
+    read_header_fields(crc_reader, length, version)
+        .await
+        .map_err(into_truncated_container_error)
+}
+
+// The stream is at EOF if no bytes are read.
+async fn read_length<R>(reader: &mut R) -> io::Result<Option<i32>>
+where
+    R: AsyncRead + Unpin,
+{
+    let mut buf = [0; 4];
+    let mut n = 0;
+
+    while n < buf.len() {
+        match reader.read(&mut buf[n..]).await {
+            Ok(0) => break,
+            Ok(m) => n += m,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+            Err(e) => return Err(e),
+        }
+    }
+
+    match n {
+        0 => Ok(None),
+        4 => Ok(Some(i32::from_le_bytes(buf))),
+        _ => Err(TruncationError::TruncatedContainer.into()),
+    }
+}
+
+async fn read_header_fields<R>(
+    mut crc_reader: CrcReader<R>,
+    length: usize,
+    version: Version,
+) -> io::Result<Option<Header>>
+where
+    R: AsyncRead + Unpin,
+{
+    use crate::reader::data_container::header::{build_reference_sequence_context, is_eof};
 
     let reference_sequence_id = read_itf8(&mut crc_reader).await?;
     let alignment_start = read_itf8(&mut crc_reader).await?;
@@ -26,9 +66,15 @@ where
 
     let number_of_records = read_itf8(&mut crc_reader).await?;
 
-    let record_counter = read_ltf8(&mut crc_reader).await.and_then(|n| {
-        u64::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
-    })?;
+    // CRAM 2.x record counters are ITF8-encoded.
+    let record_counter = if version.major() >= 3 {
+        read_ltf8(&mut crc_reader).await?
+    } else {
+        read_itf8(&mut crc_reader).await.map(i64::from)?
+    };
+
+    let record_counter =
+        u64::try_from(record_counter).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
 
     let bases = read_ltf8(&mut crc_reader).await.and_then(|n| {
         u64::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
@@ -42,25 +88,37 @@ where
 
     let actual_crc32 = crc_reader.crc().sum();
 
-    let reader = crc_reader.into_inner();
-    let expected_crc32 = reader.read_u32_le().await?;
+    let mut reader = crc_reader.into_inner();
 
-    if actual_crc32 != expected_crc32 {
-        return Err(io::Error::new(
-            io::ErrorKind::InvalidData,
-            format!(
-                "container header checksum mismatch: expected {expected_crc32:08x}, got {actual_crc32:08x}"
-            ),
-        ));
-    }
+    // CRAM 2.x container headers do not have a CRC32.
+    let crc32 = if version.major() >= 3 {
+        let expected_crc32 = reader.read_u32_le().await?;
+
+        if actual_crc32 != expected_crc32 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "container header checksum mismatch: expected {expected_crc32:08x}, got {actual_crc32:08x}"
+                ),
+            ));
+        }
+
+        Some(expected_crc32)
+    } else {
+        None
+    };
 
     if is_eof(
         length,
         reference_sequence_id,
         alignment_start,
         number_of_blocks,
-        actual_crc32,
+        crc32,
     ) {
+        // The EOF container block is discarded but read to check that the stream is complete.
+        let mut buf = vec![0; length];
+        reader.read_exact(&mut buf).await?;
+
         return Ok(None);
     }
 
@@ -126,7 +184,7 @@ mod tests {
         ];
 
         let mut reader = &data[..];
-        let actual = read_header(&mut reader).await?;
+        let actual = read_header(&mut reader, Version::default()).await?;
 
         let expected = Header::builder()
             .set_length(144)
@@ -146,4 +204,42 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_read_header_with_cram_2_1() -> Result<(), Box<dyn std::error::Error>> {
+        let data = [
+            0x90, 0x00, 0x00, 0x00, // length = 144 bytes
+            0x02, // reference sequence ID = 2
+            0x03, // starting position on the reference = 3
+            0x05, // alignment span = 5
+            0x08, // number of records = 8
+            0x80, 0x80, // record counter = 128 (ITF8)
+            0x15, // bases = 21
+            0x22, // number of blocks = 34
+            0x02, // landmark count = 2
+            0x37, // landmarks[0] = 55
+            0x59, // landmarks[1] = 89
+        ];
+
+        let mut reader = &data[..];
+        let actual = read_header(&mut reader, Version::new(2, 1)).await?;
+
+        let expected = Header::builder()
+            .set_length(144)
+            .set_reference_sequence_context(ReferenceSequenceContext::some(
+                2,
+                Position::try_from(3)?,
+                Position::try_from(7)?,
+            ))
+            .set_record_count(8)
+            .set_record_counter(128)
+            .set_base_count(21)
+            .set_block_count(34)
+            .set_landmarks(vec![55, 89])
+            .build();
+
+        assert_eq!(actual, Some(expected));
+
+        Ok(())
+    }
 }
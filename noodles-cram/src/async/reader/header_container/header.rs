@@ -1,11 +1,14 @@
 use tokio::io::{self, AsyncRead, AsyncReadExt};
 
-use crate::r#async::reader::{
-    num::{read_itf8, read_ltf8},
-    CrcReader,
+use crate::{
+    file_definition::Version,
+    r#async::reader::{
+        num::{read_itf8, read_ltf8},
+        CrcReader,
+    },
 };
 
-pub async fn read_header<R>(reader: &mut R) -> io::Result<usize>
+pub async fn read_header<R>(reader: &mut R, version: Version) -> io::Result<usize>
 where
     R: AsyncRead + Unpin,
 {
@@ -28,7 +31,11 @@ where
     read_itf8(&mut crc_reader).await?;
 
     // record counter
-    read_ltf8(&mut crc_reader).await?;
+    if version.major() >= 3 {
+        read_ltf8(&mut crc_reader).await?;
+    } else {
+        read_itf8(&mut crc_reader).await?;
+    }
 
     // base count
     read_ltf8(&mut crc_reader).await?;
@@ -43,15 +50,19 @@ where
     let actual_crc32 = crc_reader.crc().sum();
 
     let reader = crc_reader.into_inner();
-    let expected_crc32 = reader.read_u32_le().await?;
-
-    if actual_crc32 != expected_crc32 {
-        return Err(io::Error::new(
-            io::ErrorKind::InvalidData,
-            format!(
-                "container header checksum mismatch: expected {expected_crc32:08x}, got {actual_crc32:08x}"
-            ),
-        ));
+
+    // CRAM 2.x container headers do not have a CRC32.
+    if version.major() >= 3 {
+        let expected_crc32 = reader.read_u32_le().await?;
+
+        if actual_crc32 != expected_crc32 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "container header checksum mismatch: expected {expected_crc32:08x}, got {actual_crc32:08x}"
+                ),
+            ));
+        }
     }
 
     Ok(length)
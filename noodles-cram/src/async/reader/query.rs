@@ -7,7 +7,7 @@ use noodles_sam as sam;
 use tokio::io::{self, AsyncRead, AsyncSeek};
 
 use super::Reader;
-use crate::{crai, Record};
+use crate::{crai, reader::query::intersects, Record};
 
 struct Context<'a, R> {
     reader: &'a mut Reader<R>,
@@ -20,6 +20,7 @@ struct Context<'a, R> {
     reference_sequence_id: usize,
     interval: Interval,
 
+    last_container_offset: Option<u64>,
     records: vec::IntoIter<Record>,
 }
 
@@ -45,6 +46,7 @@ where
         reference_sequence_id,
         interval,
 
+        last_container_offset: None,
         records: Vec::new().into_iter(),
     };
 
@@ -52,6 +54,11 @@ where
         loop {
             match ctx.records.next() {
                 Some(r) => {
+                    // Records in multi-reference slices can map to other reference sequences.
+                    if r.reference_sequence_id() != Some(ctx.reference_sequence_id) {
+                        continue;
+                    }
+
                     if let (Some(start), Some(end)) = (r.alignment_start(), r.alignment_end()) {
                         let alignment_interval = (start..=end).into();
 
@@ -76,10 +83,18 @@ where
 {
     let index_record = ctx.index.next()?;
 
-    if index_record.reference_sequence_id() != Some(ctx.reference_sequence_id) {
+    if !intersects(index_record, ctx.reference_sequence_id, ctx.interval) {
+        return Some(Ok(()));
+    }
+
+    // An index has a record for each slice, and a container can have many slices. All of the
+    // records of a container are read at once, so it only needs to be read once.
+    if ctx.last_container_offset == Some(index_record.offset()) {
         return Some(Ok(()));
     }
 
+    ctx.last_container_offset = Some(index_record.offset());
+
     if let Err(e) = ctx
         .reader
         .seek(SeekFrom::Start(index_record.offset()))
@@ -94,6 +109,9 @@ where
         Err(e) => return Some(Err(e)),
     };
 
+    let verify_md5_checksums = ctx.reader.verify_md5_checksums;
+    let read_name_template = &ctx.reader.read_name_template;
+
     let records = container
         .slices()
         .iter()
@@ -101,11 +119,13 @@ where
             let compression_header = container.compression_header();
 
             slice.records(compression_header).and_then(|mut records| {
-                slice.resolve_records(
+                slice.resolve_records_with_verification(
                     ctx.reference_sequence_repository,
                     ctx.header,
                     compression_header,
                     &mut records,
+                    verify_md5_checksums,
+                    read_name_template,
                 )?;
 
                 Ok(records)
@@ -126,3 +146,63 @@ where
 
     Some(Ok(()))
 }
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use futures::TryStreamExt;
+    use noodles_core::Position;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_query() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::fixtures::{build_sq0_header, build_sq0_repository, write_records};
+
+        let repository = build_sq0_repository();
+        let header = build_sq0_header()?;
+
+        let records = [("r0", 1), ("r1", 5)]
+            .into_iter()
+            .map(|(name, start)| {
+                Ok(sam::alignment::Record::builder()
+                    .set_read_name(name.parse()?)
+                    .set_flags(sam::record::Flags::empty())
+                    .set_reference_sequence_id(0)
+                    .set_alignment_start(Position::try_from(start)?)
+                    .set_cigar("2M".parse()?)
+                    .set_sequence("CA".parse()?)
+                    .set_quality_scores("NN".parse()?)
+                    .build())
+            })
+            .collect::<Result<Vec<_>, Box<dyn std::error::Error>>>()?;
+
+        let builder =
+            crate::writer::Builder::default().set_reference_sequence_repository(repository.clone());
+        let data = write_records(builder, &header, &records)?;
+
+        let mut reader = Reader::new(Cursor::new(data));
+        reader.read_file_definition().await?;
+        reader.read_file_header().await?;
+        let offset = reader.position().await?;
+
+        // The same container is listed twice, e.g., once for each of its slices.
+        let index = vec![
+            crai::Record::new(Some(0), Position::new(1), 6, offset, 0, 0),
+            crai::Record::new(Some(0), Position::new(1), 6, offset, 1, 0),
+            crai::Record::new(Some(1), Position::new(1), 6, offset, 0, 0),
+        ];
+
+        let region = "sq0:5-8".parse()?;
+        let records: Vec<_> = reader
+            .query(&repository, &header, &index, &region)?
+            .try_collect()
+            .await?;
+
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].read_name(), Some(&"r1".parse()?));
+
+        Ok(())
+    }
+}
@@ -61,6 +61,9 @@ where
         Err(e) => return Some(Err(e)),
     };
 
+    let verify_md5_checksums = ctx.reader.verify_md5_checksums;
+    let read_name_template = &ctx.reader.read_name_template;
+
     let records = container
         .slices()
         .iter()
@@ -68,11 +71,13 @@ where
             let compression_header = container.compression_header();
 
             slice.records(compression_header).and_then(|mut records| {
-                slice.resolve_records(
+                slice.resolve_records_with_verification(
                     ctx.reference_sequence_repository,
                     ctx.header,
                     compression_header,
                     &mut records,
+                    verify_md5_checksums,
+                    read_name_template,
                 )?;
 
                 Ok(records)
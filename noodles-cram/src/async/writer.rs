@@ -184,6 +184,12 @@ where
     ) -> io::Result<()> {
         use crate::data_container::builder::AddRecordError;
 
+        self.options.quality_scores_mode.apply(
+            &self.reference_sequence_repository,
+            header,
+            &mut record,
+        )?;
+
         loop {
             match self.data_container_builder.add_record(record) {
                 Ok(_) => {
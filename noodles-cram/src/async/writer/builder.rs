@@ -3,7 +3,9 @@ use tokio::io::AsyncWrite;
 
 use super::Writer;
 use crate::{
-    data_container::BlockContentEncoderMap, file_definition::Version, writer::Options,
+    data_container::{compression_header::SubstitutionMatrix, BlockContentEncoderMap},
+    file_definition::Version,
+    writer::{Options, QualityScoresMode},
     DataContainer,
 };
 
@@ -50,6 +52,22 @@ impl Builder {
         self
     }
 
+    /// Sets the quality scores mode.
+    ///
+    /// The default is [`QualityScoresMode::Lossless`].
+    pub fn set_quality_scores_mode(mut self, quality_scores_mode: QualityScoresMode) -> Self {
+        self.options.quality_scores_mode = quality_scores_mode;
+        self
+    }
+
+    /// Sets the substitution matrix.
+    ///
+    /// By default, the substitution matrix is built per container.
+    pub fn set_substitution_matrix(mut self, substitution_matrix: SubstitutionMatrix) -> Self {
+        self.options.substitution_matrix = Some(substitution_matrix);
+        self
+    }
+
     /// Builds an async CRAM writer from a writer.
     ///
     /// # Examples
@@ -66,6 +66,14 @@ where
     R: Read,
 {
     let n = reader.read_u8().map(usize::from)?;
+
+    if n == 0 {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "invalid stripe stream count",
+        ));
+    }
+
     let mut clens = Vec::with_capacity(n);
 
     for _ in 0..n {
@@ -78,15 +86,31 @@ where
 
     let mut ulens = Vec::with_capacity(n);
     let mut t = Vec::with_capacity(n);
+    let mut buf = Vec::new();
 
-    for j in 0..n {
+    for (j, clen) in clens.into_iter().enumerate() {
         let mut ulen = len / n;
 
         if len % n > j {
             ulen += 1;
         }
 
-        let chunk = decode(reader, ulen)?;
+        // Streams are read to their compressed lengths, as external codecs (e.g., bzip2) may
+        // read past the end of their stream.
+        buf.resize(clen, 0);
+        reader.read_exact(&mut buf)?;
+
+        let chunk = decode(&mut &buf[..], ulen)?;
+
+        if chunk.len() != ulen {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "invalid stripe stream length: expected {ulen}, got {}",
+                    chunk.len()
+                ),
+            ));
+        }
 
         ulens.push(ulen);
         t.push(chunk);
@@ -143,11 +167,18 @@ where
             run += usize::from(part);
         }
 
-        for j in 1..=run {
-            dst[i + j] = b;
+        let end = i + run + 1;
+
+        if end > dst.len() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "invalid run length",
+            ));
         }
 
-        i += run + 1;
+        dst[i + 1..end].fill(b);
+
+        i = end;
     }
 
     Ok(())
@@ -185,11 +216,18 @@ where
             run += usize::from(part);
         }
 
-        for j in 1..=run {
-            dst[i + j] = b;
+        let end = i + run + 1;
+
+        if end > dst.len() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "invalid run length",
+            ));
         }
 
-        i += run + 1;
+        dst[i + 1..end].fill(b);
+
+        i = end;
     }
 
     Ok(())
@@ -339,4 +377,36 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_decode_stripe_with_no_streams() {
+        let data = [
+            0x08, // flags = STRIPE
+            0x07, // uncompressed len = 7
+            0x00, // stream count = 0
+        ];
+
+        let mut reader = &data[..];
+
+        assert!(matches!(
+            decode(&mut reader, 0),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+    }
+
+    #[test]
+    fn test_decode_rle_with_invalid_run_length() -> io::Result<()> {
+        let mut data = super::super::encode(Flags::RLE, b"nooo")?;
+        // uncompressed len = 2
+        data[1] = 0x02;
+
+        let mut reader = &data[..];
+
+        assert!(matches!(
+            decode(&mut reader, 0),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
 }
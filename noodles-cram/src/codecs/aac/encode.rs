@@ -20,7 +20,7 @@ pub fn encode(mut flags: Flags, src: &[u8]) -> io::Result<Vec<u8>> {
     }
 
     if flags.contains(Flags::STRIPE) {
-        let buf = encode_stripe(&src)?;
+        let buf = encode_stripe(flags, &src)?;
         dst.extend(buf);
         return Ok(dst);
     }
@@ -64,7 +64,7 @@ pub fn encode(mut flags: Flags, src: &[u8]) -> io::Result<Vec<u8>> {
     Ok(dst)
 }
 
-fn encode_stripe(src: &[u8]) -> io::Result<Vec<u8>> {
+fn encode_stripe(flags: Flags, src: &[u8]) -> io::Result<Vec<u8>> {
     const N: usize = 4;
 
     let mut ulens = Vec::with_capacity(N);
@@ -99,8 +99,11 @@ fn encode_stripe(src: &[u8]) -> io::Result<Vec<u8>> {
 
     let mut chunks = vec![Vec::new(); N];
 
+    // Each stream is encoded with the same flags, excluding striping.
+    let stream_flags = flags - Flags::STRIPE - Flags::NO_SIZE;
+
     for (chunk, s) in chunks.iter_mut().zip(t.iter()) {
-        *chunk = encode(Flags::empty(), s)?;
+        *chunk = encode(stream_flags, s)?;
     }
 
     let mut dst = Vec::new();
@@ -144,7 +147,10 @@ fn encode_rle_0(src: &[u8], dst: &mut Vec<u8>) -> io::Result<()> {
         let sym = src[i];
         model_lit.encode(dst, &mut range_coder, sym)?;
 
-        let mut run = src[i + 1..].iter().position(|&s| s != sym).unwrap_or(0);
+        let mut run = src[i + 1..]
+            .iter()
+            .position(|&s| s != sym)
+            .unwrap_or(src.len() - i - 1);
         i += run + 1;
 
         let mut rctx = usize::from(sym);
@@ -184,7 +190,10 @@ fn encode_rle_1(src: &[u8], dst: &mut Vec<u8>) -> io::Result<()> {
         let sym = src[i];
         model_lit[last].encode(dst, &mut range_coder, sym)?;
 
-        let mut run = src[i + 1..].iter().position(|&s| s != sym).unwrap_or(0);
+        let mut run = src[i + 1..]
+            .iter()
+            .position(|&s| s != sym)
+            .unwrap_or(src.len() - i - 1);
         i += run + 1;
 
         let mut rctx = usize::from(sym);
@@ -233,7 +242,9 @@ fn encode_order_1(src: &[u8], dst: &mut Vec<u8>) -> io::Result<()> {
 
     let mut range_coder = RangeCoder::default();
 
-    models[0].encode(dst, &mut range_coder, src[0])?;
+    if let Some(&sym) = src.first() {
+        models[0].encode(dst, &mut range_coder, sym)?;
+    }
 
     for window in src.windows(2) {
         let sym_0 = usize::from(window[0]);
@@ -356,4 +367,40 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_encode_round_trip() -> io::Result<()> {
+        use super::super::decode;
+
+        let srcs: [Vec<u8>; 5] = [
+            Vec::new(),
+            vec![0x6e],
+            b"noodlesssss".to_vec(),
+            (0..=255).collect(),
+            (0..4096u32)
+                .map(|i| b"ACGT"[(i % 7 % 4) as usize])
+                .collect(),
+        ];
+
+        for flags in [
+            Flags::empty(),
+            Flags::ORDER,
+            Flags::STRIPE,
+            Flags::STRIPE | Flags::ORDER | Flags::PACK,
+            Flags::STRIPE | Flags::EXT,
+            Flags::RLE,
+            Flags::ORDER | Flags::RLE,
+            Flags::PACK,
+            Flags::ORDER | Flags::PACK,
+            Flags::ORDER | Flags::RLE | Flags::PACK,
+        ] {
+            for src in &srcs {
+                let buf = encode(flags, src)?;
+                let mut reader = &buf[..];
+                assert_eq!(decode(&mut reader, 0)?, *src, "flags = {flags:?}");
+            }
+        }
+
+        Ok(())
+    }
 }
@@ -34,6 +34,13 @@ impl Model {
     {
         let freq = range_coder.range_get_freq(self.total_freq);
 
+        if freq >= self.total_freq {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "invalid symbol frequency",
+            ));
+        }
+
         let mut acc = 0;
         let mut x = 0;
 
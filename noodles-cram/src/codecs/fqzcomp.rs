@@ -61,6 +61,19 @@ mod tests {
             vec![2, 1, 1, 0, 0, 0, 0, 0, 1, 1],
         ])?;
 
+        t(&[])?;
+        t(&[vec![]])?;
+        t(&[vec![], vec![0, 1, 2], vec![], vec![3]])?;
+        t(&[vec![40; 200], vec![30; 200]])?;
+
         Ok(())
     }
+
+    #[test]
+    fn test_encode_with_mismatched_lengths() {
+        assert!(matches!(
+            encode(&[2, 2], &[0, 1, 2]),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+    }
 }
@@ -33,7 +33,7 @@ where
     let mut x = 0;
     let mut ctx = 0;
 
-    let mut last_len = 0;
+    let mut last_lens = vec![None; params.params.len()];
     let mut rev_len = Vec::new();
 
     while i < buf_len {
@@ -44,17 +44,27 @@ where
                 &mut range_coder,
                 &mut models,
                 &mut record,
-                last_len,
+                &mut last_lens,
                 &mut rev_len,
             )?;
 
-            last_len = record.rec_len;
+            if record.rec_len > buf_len - i {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "record length exceeds buffer length",
+                ));
+            }
 
             if record.is_dup {
-                for j in 0..record.rec_len {
-                    dst[i + j] = dst[i + j - record.rec_len];
+                if record.rec_len > i {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        "duplicate record length exceeds previous records",
+                    ));
                 }
 
+                dst.copy_within(i - record.rec_len..i, i);
+
                 i += record.rec_len;
                 record.pos = 0;
 
@@ -68,7 +78,12 @@ where
         let q = models.qual[usize::from(ctx)].decode(reader, &mut range_coder)?;
 
         dst[i] = if let Some(q_map) = param.q_map.as_deref() {
-            q_map[usize::from(q)]
+            q_map.get(usize::from(q)).copied().ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("invalid quality score symbol: {q}"),
+                )
+            })?
         } else {
             q
         };
@@ -104,34 +119,53 @@ fn fqz_new_record<R>(
     range_coder: &mut RangeCoder,
     models: &mut Models,
     record: &mut Record,
-    mut last_len: usize,
+    last_lens: &mut [Option<usize>],
     rev_len: &mut Vec<(bool, usize)>,
 ) -> io::Result<usize>
 where
     R: Read,
 {
     let mut sel = 0;
-    let mut x = 0;
 
     if parameters.max_sel > 0 {
         sel = models.sel.decode(reader, range_coder)?;
-
-        if parameters.gflags.contains(parameters::Flags::HAVE_S_TAB) {
-            x = usize::from(parameters.s_tab[usize::from(sel)]);
-        }
     }
 
     record.sel = sel;
 
-    let param = &mut parameters.params[x];
+    let x = usize::from(parameters.s_tab[usize::from(sel)]);
+
+    let param = parameters.params.get_mut(x).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("invalid parameter set index: {x}"),
+        )
+    })?;
 
     let is_fixed_len = param.flags.contains(parameter::Flags::DO_LEN);
 
-    if !is_fixed_len || record.rec == 0 {
-        last_len = decode_length(reader, range_coder, models)? as usize;
+    // The length of a fixed-length parameter set is only stored for its first record.
+    let len = match last_lens[x] {
+        Some(len) if is_fixed_len => len,
+        _ => {
+            let len = decode_length(reader, range_coder, models).and_then(|n| {
+                usize::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+            })?;
+
+            last_lens[x] = Some(len);
+
+            len
+        }
+    };
+
+    if len == 0 {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "invalid record length: expected > 0, got 0",
+        ));
     }
 
-    record.rec_len = last_len;
+    record.rec_len = len;
     record.pos = record.rec_len;
 
     if parameters.gflags.contains(parameters::Flags::DO_REV) {
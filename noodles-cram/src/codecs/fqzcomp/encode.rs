@@ -6,6 +6,21 @@ use super::{parameter, parameters, Models};
 use crate::{codecs::aac::RangeCoder, writer::num::write_uint7};
 
 pub fn encode(lens: &[usize], src: &[u8]) -> io::Result<Vec<u8>> {
+    let total_len: usize = lens.iter().sum();
+
+    if total_len != src.len() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "record lengths mismatch: expected {}, got {total_len}",
+                src.len()
+            ),
+        ));
+    }
+
+    // Records without quality scores have no representation in the stream.
+    let lens: Vec<_> = lens.iter().copied().filter(|&len| len > 0).collect();
+
     let mut dst = Vec::new();
 
     let len =
@@ -18,11 +33,11 @@ pub fn encode(lens: &[usize], src: &[u8]) -> io::Result<Vec<u8>> {
         *f = i as u8;
     }
 
-    let parameters = build_parameters(lens, src);
+    let parameters = build_parameters(&lens, src);
     fqz_encode_params(&mut dst, &parameters)?;
 
     let mut range_coder = RangeCoder::default();
-    let mut models = Models::new(parameters.max_sym, 1); // FIXME: max_sel
+    let mut models = Models::new(parameters.max_sym, parameters.max_sel);
 
     let mut p = 0;
     let mut rec_num = 0;
@@ -135,7 +150,7 @@ fn build_parameters(lens: &[usize], src: &[u8]) -> Parameters {
     let q_shift = 5;
     let q_bits = if q_shift > 4 { 9 } else { 8 };
     let p_bits = 7;
-    let p_shift = i32::from(lens[0] > 128);
+    let p_shift = i32::from(lens.first().map(|&len| len > 128).unwrap_or(false));
 
     let q_tab: Vec<_> = (0..=u8::MAX).collect();
 
@@ -35,6 +35,14 @@ where
 
     let (n_param, mut max_sel) = if gflags.contains(Flags::MULTI_PARAM) {
         let n = reader.read_u8()?;
+
+        if n == 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "invalid n_param: expected > 0, got 0",
+            ));
+        }
+
         (usize::from(n), n)
     } else {
         (1, 0)
@@ -44,7 +52,7 @@ where
         max_sel = reader.read_u8()?;
         read_array(reader, 256)?
     } else {
-        Vec::new()
+        build_default_s_tab(n_param)
     };
 
     let mut params = Vec::with_capacity(n_param);
@@ -69,48 +77,49 @@ where
     })
 }
 
+// When the selector table is not stored, a selector maps to the parameter set with the same index,
+// saturating at the last parameter set.
+fn build_default_s_tab(n_param: usize) -> Vec<u8> {
+    let last_i = (n_param - 1) as u8;
+
+    (0..=u8::MAX).map(|i| i.min(last_i)).collect()
+}
+
 pub fn read_array<R>(reader: &mut R, n: usize) -> io::Result<Vec<u8>>
 where
     R: Read,
 {
-    let (mut j, mut z) = (0, 0);
+    let mut z = 0;
     let mut last = 0;
 
-    let mut runs = vec![0; n];
+    let mut runs = Vec::with_capacity(n);
 
     while z < n {
         let run = reader.read_u8()?;
 
-        runs[j] = run;
-        j += 1;
+        runs.push(run);
         z += usize::from(run);
 
         if run == last {
             let copy = reader.read_u8()?;
-
-            for _ in 0..copy {
-                runs[j] = run;
-                j += 1;
-            }
-
+            runs.extend(std::iter::repeat(run).take(usize::from(copy)));
             z += usize::from(run) * usize::from(copy);
         }
 
         last = run;
     }
 
-    let mut a = vec![0; n];
+    let mut a = Vec::with_capacity(n);
+    let mut runs = runs.into_iter();
 
-    let mut i = 0;
-    j = 0;
-    z = 0;
+    for i in 0..=u8::MAX {
+        if a.len() >= n {
+            break;
+        }
 
-    while z < n {
         let mut run_len = 0;
 
-        loop {
-            let part = runs[j];
-            j += 1;
+        for part in runs.by_ref() {
             run_len += usize::from(part);
 
             if part != 255 {
@@ -118,13 +127,64 @@ where
             }
         }
 
-        for _ in 0..run_len {
-            a[z] = i;
-            z += 1;
-        }
+        a.extend(std::iter::repeat(i).take(run_len));
+    }
 
-        i += 1;
+    if a.len() != n {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("invalid array length: expected {n}, got {}", a.len()),
+        ));
     }
 
     Ok(a)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fqz_decode_params_with_multi_param_and_no_s_tab() -> io::Result<()> {
+        const PARAM: [u8; 7] = [0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00];
+
+        let mut data = vec![VERSION, u8::from(Flags::MULTI_PARAM), 0x02];
+        data.extend(PARAM);
+        data.extend(PARAM);
+
+        let mut reader = &data[..];
+        let parameters = fqz_decode_params(&mut reader)?;
+
+        assert_eq!(parameters.max_sel, 2);
+        assert_eq!(parameters.params.len(), 2);
+        assert_eq!(parameters.s_tab.len(), 256);
+        assert_eq!(parameters.s_tab[..3], [0, 1, 1]);
+        assert!(parameters.s_tab[3..].iter().all(|&x| x == 1));
+
+        let data = [VERSION, u8::from(Flags::MULTI_PARAM), 0x00];
+        let mut reader = &data[..];
+
+        assert!(matches!(
+            fqz_decode_params(&mut reader),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_array() -> io::Result<()> {
+        let data = [0x02, 0x02, 0x00];
+        let mut reader = &data[..];
+        assert_eq!(read_array(&mut reader, 4)?, [0, 0, 1, 1]);
+
+        let data = [0x05];
+        let mut reader = &data[..];
+        assert!(matches!(
+            read_array(&mut reader, 4),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+}
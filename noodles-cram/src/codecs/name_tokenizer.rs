@@ -93,4 +93,24 @@ I17_08765:2:124:45613:16161#9\0\
 
         Ok(())
     }
+
+    #[test]
+    fn test_self_with_edge_cases() -> io::Result<()> {
+        fn t(data: &[u8]) -> io::Result<()> {
+            let input = encode(data)?;
+            let mut reader = &input[..];
+            assert_eq!(decode(&mut reader)?, data);
+            Ok(())
+        }
+
+        t(b"")?;
+        t(b"\0")?;
+        t(b"r0\0\0r0\0r0\0")?;
+        t(b"a:007\0a:008\0a:1008\0a:4294967295\0a:4294967296\0")?;
+
+        let name: String = (0..200).map(|i| format!("{i}:")).collect();
+        t(format!("{name}\0{name}x\0").as_bytes())?;
+
+        Ok(())
+    }
 }
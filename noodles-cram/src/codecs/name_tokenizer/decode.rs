@@ -19,7 +19,7 @@ where
     let mut b = decode_token_byte_streams(reader, use_arith, n_names)?;
 
     let mut names = vec![String::new(); n_names];
-    let mut tokens = vec![vec![None; 128]; n_names];
+    let mut tokens = vec![Vec::new(); n_names];
 
     let mut dst = Vec::with_capacity(ulen);
 
@@ -73,18 +73,19 @@ struct TokenReader {
 }
 
 impl TokenReader {
-    fn get(&self, ty: Type) -> &Cursor<Vec<u8>> {
+    fn get(&self, ty: Type) -> io::Result<&Cursor<Vec<u8>>> {
         match ty {
-            Type::Type => &self.type_reader,
-            Type::String => &self.string_reader,
-            Type::Char => &self.char_reader,
-            Type::Digits0 => &self.digits0_reader,
-            Type::Dup => &self.dup_reader,
-            Type::Diff => &self.diff_reader,
-            Type::Digits => &self.digits_reader,
-            Type::Delta => &self.delta_reader,
-            Type::Delta0 => &self.delta0_reader,
-            _ => unimplemented!("unhandled ty: {:?}", ty),
+            Type::Type => Ok(&self.type_reader),
+            Type::String => Ok(&self.string_reader),
+            Type::Char => Ok(&self.char_reader),
+            Type::Digits0 => Ok(&self.digits0_reader),
+            Type::DZLen => Ok(&self.dz_len_reader),
+            Type::Dup => Ok(&self.dup_reader),
+            Type::Diff => Ok(&self.diff_reader),
+            Type::Digits => Ok(&self.digits_reader),
+            Type::Delta => Ok(&self.delta_reader),
+            Type::Delta0 => Ok(&self.delta0_reader),
+            _ => Err(invalid_stream_type(ty)),
         }
     }
 
@@ -104,7 +105,7 @@ impl TokenReader {
         }
     }
 
-    fn set(&mut self, ty: Type, buf: Vec<u8>) {
+    fn set(&mut self, ty: Type, buf: Vec<u8>) -> io::Result<()> {
         match ty {
             Type::Type => *self.type_reader.get_mut() = buf,
             Type::String => *self.string_reader.get_mut() = buf,
@@ -116,8 +117,10 @@ impl TokenReader {
             Type::Digits => *self.digits_reader.get_mut() = buf,
             Type::Delta => *self.delta_reader.get_mut() = buf,
             Type::Delta0 => *self.delta0_reader.get_mut() = buf,
-            _ => unimplemented!("unhandled ty: {:?}", ty),
+            _ => return Err(invalid_stream_type(ty)),
         }
+
+        Ok(())
     }
 
     fn read_type(&mut self) -> io::Result<Type> {
@@ -127,7 +130,12 @@ impl TokenReader {
     }
 
     fn read_distance(&mut self, ty: Type) -> io::Result<usize> {
-        assert!(matches!(ty, Type::Dup | Type::Diff));
+        if !matches!(ty, Type::Dup | Type::Diff) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("invalid name type: expected Dup or Diff, got {ty:?}"),
+            ));
+        }
 
         self.get_mut(ty).read_u32::<LittleEndian>().and_then(|n| {
             usize::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
@@ -163,7 +171,10 @@ impl TokenReader {
                 let delta = self.delta_reader.read_u8().map(u32::from)?;
 
                 match prev_token {
-                    Some(Token::Digits(n)) => Ok(Some(Token::Digits(n + delta))),
+                    Some(Token::Digits(n)) => n
+                        .checked_add(delta)
+                        .map(|m| Some(Token::Digits(m)))
+                        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "invalid delta")),
                     _ => Err(io::Error::new(
                         io::ErrorKind::InvalidData,
                         format!("invalid previous token: {prev_token:?}"),
@@ -174,16 +185,19 @@ impl TokenReader {
                 let delta = self.delta0_reader.read_u8().map(u32::from)?;
 
                 match prev_token {
-                    Some(Token::PaddedDigits(n, width)) => {
-                        Ok(Some(Token::PaddedDigits(n + delta, *width)))
-                    }
+                    Some(Token::PaddedDigits(n, width)) => n
+                        .checked_add(delta)
+                        .map(|m| Some(Token::PaddedDigits(m, *width)))
+                        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "invalid delta")),
                     _ => Err(io::Error::new(
                         io::ErrorKind::InvalidData,
                         format!("invalid previous token: {prev_token:?}"),
                     )),
                 }
             }
-            Type::Match => Ok(prev_token.cloned()),
+            Type::Match => prev_token.cloned().map(Some).ok_or_else(|| {
+                io::Error::new(io::ErrorKind::InvalidData, "missing previous token")
+            }),
             Type::End => Ok(None),
             _ => Ok(Some(Token::Nop)),
         }
@@ -199,8 +213,6 @@ where
     R: Read,
 {
     let mut b = Vec::new();
-    let mut t = -1;
-
     loop {
         let ttype = match reader.read_u8() {
             Ok(n) => n,
@@ -215,14 +227,16 @@ where
             Type::try_from(ttype).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
 
         if tok_new {
-            t += 1;
-
             b.push(TokenReader::default());
 
             if ty != Type::Type {
                 let mut buf = vec![u8::from(Type::Match); n_names];
-                buf[0] = u8::from(ty);
-                b[t as usize].set(Type::Type, buf);
+
+                if let Some(n) = buf.first_mut() {
+                    *n = u8::from(ty);
+                }
+
+                last_token_reader(&mut b)?.set(Type::Type, buf)?;
             }
         }
 
@@ -233,8 +247,16 @@ where
                 Type::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
             })?;
 
-            let buf = b[dup_pos].get(dup_type).get_ref().clone();
-            b[t as usize].set(ty, buf);
+            let buf = b
+                .get(dup_pos)
+                .ok_or_else(|| {
+                    io::Error::new(io::ErrorKind::InvalidData, "invalid duplicate position")
+                })?
+                .get(dup_type)?
+                .get_ref()
+                .clone();
+
+            last_token_reader(&mut b)?.set(ty, buf)?;
         } else {
             let clen = read_uint7(reader).and_then(|n| {
                 usize::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
@@ -251,23 +273,34 @@ where
                 rans_nx16::decode(&mut data_reader, 0)?
             };
 
-            b[t as usize].set(ty, buf);
+            last_token_reader(&mut b)?.set(ty, buf)?;
         }
     }
 
     Ok(b)
 }
 
+fn last_token_reader(b: &mut [TokenReader]) -> io::Result<&mut TokenReader> {
+    b.last_mut()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "missing token stream"))
+}
+
 fn decode_single_name(
     b: &mut [TokenReader],
     names: &mut [String],
     tokens: &mut [Vec<Option<Token>>],
     n: usize,
 ) -> io::Result<String> {
-    let ty = b[0].read_type()?;
-    let dist = b[0].read_distance(ty)?;
+    let type_reader = b
+        .first_mut()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "missing token streams"))?;
+
+    let ty = type_reader.read_type()?;
+    let dist = type_reader.read_distance(ty)?;
 
-    let m = n - dist;
+    let m = n
+        .checked_sub(dist)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "invalid name distance"))?;
 
     if ty == Type::Dup {
         names[n] = names[m].clone();
@@ -278,9 +311,13 @@ fn decode_single_name(
     let mut t = 1;
 
     loop {
-        let prev_token = tokens[m][t].as_ref();
+        let prev_token = tokens[m].get(t).and_then(Option::as_ref);
 
-        if let Some(token) = b[t].read_token(prev_token)? {
+        let token_reader = b
+            .get_mut(t)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "missing token stream"))?;
+
+        if let Some(token) = token_reader.read_token(prev_token)? {
             match &token {
                 Token::Char(c) => names[n].push(*c),
                 Token::String(s) => names[n].push_str(s),
@@ -292,6 +329,10 @@ fn decode_single_name(
                 Token::Nop => {}
             }
 
+            if tokens[n].len() <= t {
+                tokens[n].resize(t + 1, None);
+            }
+
             tokens[n][t] = Some(token);
         } else {
             break;
@@ -303,6 +344,13 @@ fn decode_single_name(
     Ok(names[n].clone())
 }
 
+fn invalid_stream_type(ty: Type) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("invalid token stream type: {ty:?}"),
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -430,4 +478,22 @@ I17_08765:2:124:45613:16161#9\0\
 
         Ok(())
     }
+
+    #[test]
+    fn test_decode_with_a_missing_new_token_stream() {
+        let data = [
+            0x00, 0x00, 0x00, 0x00, // ulen = 0
+            0x01, 0x00, 0x00, 0x00, // n_names = 1
+            0x00, // use_arith = false
+            0x01, // ttype = String
+            0x02, 0x20, 0x00, // clen = 2, data = [] (rANS Nx16, CAT)
+        ];
+
+        let mut reader = &data[..];
+
+        assert!(matches!(
+            decode(&mut reader),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+    }
 }
@@ -11,18 +11,26 @@ use crate::writer::num::write_uint7;
 
 const NUL: u8 = 0x00;
 
+// The maximum number of tokens in a name, excluding the end token.
+const MAX_TOKEN_COUNT: usize = 127;
+
 pub fn encode(mut src: &[u8]) -> io::Result<Vec<u8>> {
     let mut dst = Vec::new();
 
+    let is_empty = src.is_empty();
+
     if let Some(buf) = src.strip_suffix(&[NUL]) {
         src = buf;
     }
 
-    let names: Vec<_> = src
-        .split(|&b| b == NUL)
-        .map(str::from_utf8)
-        .collect::<Result<_, _>>()
-        .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+    let names: Vec<_> = if is_empty {
+        Vec::new()
+    } else {
+        src.split(|&b| b == NUL)
+            .map(str::from_utf8)
+            .collect::<Result<_, _>>()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?
+    };
 
     write_header(&mut dst, src.len(), names.len())?;
 
@@ -192,10 +200,29 @@ fn tokenize(s: &str) -> impl Iterator<Item = &str> {
     })
 }
 
+// Splits a name into at most `MAX_TOKEN_COUNT` tokens. The last token holds the rest of the name
+// if there are more.
+fn split_tokens(name: &str) -> Vec<&str> {
+    let mut raw_tokens = Vec::new();
+    let mut offset = 0;
+
+    for raw_token in tokenize(name) {
+        if raw_tokens.len() == MAX_TOKEN_COUNT - 1 {
+            raw_tokens.push(&name[offset..]);
+            break;
+        }
+
+        offset += raw_token.len();
+        raw_tokens.push(raw_token);
+    }
+
+    raw_tokens
+}
+
 fn build_first_diff(name: &str) -> Diff {
     let mut diff = Diff::new(Mode::Diff(0));
 
-    let raw_tokens = tokenize(name);
+    let raw_tokens = split_tokens(name);
 
     for raw_token in raw_tokens {
         let token = if let Some(n) = parse_digits0(raw_token) {
@@ -227,9 +254,9 @@ fn build_diff(diffs: &[Diff], names_indices: &HashMap<&str, usize>, i: usize, na
     };
 
     let prev_diff = &diffs[i - diff.delta()];
-    let raw_tokens = tokenize(name);
+    let raw_tokens = split_tokens(name);
 
-    for (j, raw_token) in raw_tokens.enumerate() {
+    for (j, raw_token) in raw_tokens.into_iter().enumerate() {
         let mut token = None;
 
         if let (Some(prev_raw_token), Some(prev_token)) =
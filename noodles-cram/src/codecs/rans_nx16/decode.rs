@@ -28,7 +28,7 @@ where
     let n = if flags.contains(Flags::N32) { 32 } else { 4 };
 
     if flags.contains(Flags::STRIPE) {
-        return rans_decode_stripe(reader, len, n);
+        return rans_decode_stripe(reader, len);
     }
 
     let mut p = None;
@@ -47,7 +47,7 @@ where
     let rle_len = len;
 
     if flags.contains(Flags::RLE) {
-        let (m, meta, new_len) = decode_rle_meta(reader, n)?;
+        let (m, meta, new_len) = decode_rle_meta(reader)?;
         l = Some(m);
         rle_meta = Some(meta);
         len = new_len;
@@ -141,11 +141,19 @@ where
     Ok(r)
 }
 
-fn rans_decode_stripe<R>(reader: &mut R, len: usize, n: u32) -> io::Result<Vec<u8>>
+fn rans_decode_stripe<R>(reader: &mut R, len: usize) -> io::Result<Vec<u8>>
 where
     R: Read,
 {
     let x = reader.read_u8().map(usize::from)?;
+
+    if x == 0 {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "invalid stripe stream count",
+        ));
+    }
+
     let mut clens = Vec::with_capacity(x);
 
     for _ in 0..x {
@@ -162,7 +170,7 @@ where
     for j in 0..x {
         let mut ulen = len / x;
 
-        if len % (n as usize) > j {
+        if len % x > j {
             ulen += 1;
         }
 
@@ -176,14 +184,14 @@ where
 
     for j in 0..x {
         for i in 0..ulens[j] {
-            dst[i * (n as usize) + j] = t[j][i];
+            dst[i * x + j] = t[j][i];
         }
     }
 
     Ok(dst)
 }
 
-fn decode_rle_meta<R>(reader: &mut R, n: u32) -> io::Result<([bool; 256], Cursor<Vec<u8>>, usize)>
+fn decode_rle_meta<R>(reader: &mut R) -> io::Result<([bool; 256], Cursor<Vec<u8>>, usize)>
 where
     R: Read,
 {
@@ -209,7 +217,8 @@ where
 
         let mut buf_reader = &buf[..];
         let mut dst = vec![0; rle_meta_len / 2];
-        order_0::decode(&mut buf_reader, &mut dst, n)?;
+        // The RLE metadata is always encoded with 4 states.
+        order_0::decode(&mut buf_reader, &mut dst, 4)?;
 
         dst
     };
@@ -358,4 +367,40 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_decode_stripe_with_2_streams() -> io::Result<()> {
+        let data = [
+            0x08, // flags = STRIPE
+            0x03, // uncompressed len = 3
+            0x02, // stream count = 2
+            0x04, 0x03, // compressed lens = [4, 3]
+            0x20, 0x02, 0x61, 0x63, // CAT, "ac"
+            0x20, 0x01, 0x62, // CAT, "b"
+        ];
+
+        let mut reader = &data[..];
+        assert_eq!(decode(&mut reader, 0)?, b"abc");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_decode_rle_with_invalid_run_length() {
+        let data = [
+            0x60, // flags = CAT | RLE
+            0x02, // uncompressed len = 2
+            0x07, // RLE metadata len = 3 (uncompressed)
+            0x01, // RLE data len = 1
+            0x01, 0x61, 0x05, // RLE symbols = [a], run lengths = [5]
+            0x61, // "a"
+        ];
+
+        let mut reader = &data[..];
+
+        assert!(matches!(
+            decode(&mut reader, 0),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+    }
 }
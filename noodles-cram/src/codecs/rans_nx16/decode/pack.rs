@@ -1,6 +1,26 @@
 use std::{io, num::NonZeroUsize};
 
 pub fn decode(src: &[u8], p: &[u8], n_sym: NonZeroUsize, len: usize) -> io::Result<Vec<u8>> {
+    let symbols_per_byte = match n_sym.get() {
+        1 => 0,
+        2 => 8,
+        3..=4 => 4,
+        5..=16 => 2,
+        _ => {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("expected n_sym to be <= 16, got {n_sym}"),
+            ))
+        }
+    };
+
+    if symbols_per_byte > 0 && src.len() < (len + symbols_per_byte - 1) / symbols_per_byte {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "invalid packed data length",
+        ));
+    }
+
     let mut dst = vec![0; len];
     let mut j = 0;
 
@@ -15,7 +35,7 @@ pub fn decode(src: &[u8], p: &[u8], n_sym: NonZeroUsize, len: usize) -> io::Resu
                 j += 1;
             }
 
-            *b = p[usize::from(v & 0x01)];
+            *b = get_symbol(p, v & 0x01)?;
             v >>= 1;
         }
     } else if n_sym.get() <= 4 {
@@ -27,7 +47,7 @@ pub fn decode(src: &[u8], p: &[u8], n_sym: NonZeroUsize, len: usize) -> io::Resu
                 j += 1;
             }
 
-            *b = p[usize::from(v & 0x03)];
+            *b = get_symbol(p, v & 0x03)?;
             v >>= 2;
         }
     } else if n_sym.get() <= 16 {
@@ -39,7 +59,7 @@ pub fn decode(src: &[u8], p: &[u8], n_sym: NonZeroUsize, len: usize) -> io::Resu
                 j += 1;
             }
 
-            *b = p[usize::from(v & 0x0f)];
+            *b = get_symbol(p, v & 0x0f)?;
             v >>= 4;
         }
     } else {
@@ -51,3 +71,12 @@ pub fn decode(src: &[u8], p: &[u8], n_sym: NonZeroUsize, len: usize) -> io::Resu
 
     Ok(dst)
 }
+
+fn get_symbol(p: &[u8], i: u8) -> io::Result<u8> {
+    p.get(usize::from(i)).copied().ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("invalid packed symbol index: {i}"),
+        )
+    })
+}
@@ -24,11 +24,17 @@ where
                 usize::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
             })?;
 
-            for k in 0..=run {
-                dst[j + k] = sym;
+            let end = j + run + 1;
+
+            if end > dst.len() {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "invalid RLE run length",
+                ));
             }
 
-            j += run + 1;
+            dst[j..end].fill(sym);
+            j = end;
         } else {
             dst[j] = sym;
             j += 1;
@@ -25,7 +25,7 @@ pub fn encode(mut flags: Flags, src: &[u8]) -> io::Result<Vec<u8>> {
     let n = if flags.contains(Flags::N32) { 32 } else { 4 };
 
     if flags.contains(Flags::STRIPE) {
-        let buf = rans_encode_stripe(&src, n)?;
+        let buf = rans_encode_stripe(flags, &src, n)?;
         dst.extend(&buf);
         return Ok(dst);
     }
@@ -208,7 +208,7 @@ where
     Ok(r)
 }
 
-fn rans_encode_stripe(src: &[u8], n: usize) -> io::Result<Vec<u8>> {
+fn rans_encode_stripe(flags: Flags, src: &[u8], n: usize) -> io::Result<Vec<u8>> {
     let mut ulens = Vec::with_capacity(n);
     let mut t = Vec::with_capacity(n);
 
@@ -241,8 +241,11 @@ fn rans_encode_stripe(src: &[u8], n: usize) -> io::Result<Vec<u8>> {
 
     let mut chunks = vec![Vec::new(); n];
 
+    // Each stream is encoded with the same flags, excluding striping.
+    let stream_flags = flags - Flags::STRIPE - Flags::NO_SIZE;
+
     for (chunk, s) in chunks.iter_mut().zip(t.iter()) {
-        *chunk = encode(Flags::empty(), s)?;
+        *chunk = encode(stream_flags, s)?;
     }
 
     let mut dst = Vec::new();
@@ -366,4 +369,52 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_encode_pack_with_unpackable_input() -> io::Result<()> {
+        let actual = encode(Flags::CAT | Flags::PACK, b"")?;
+        assert_eq!(actual, [0x20, 0x00]);
+
+        let src: Vec<_> = (0..=255).collect();
+        let actual = encode(Flags::CAT | Flags::PACK, &src)?;
+        assert_eq!(actual[0], u8::from(Flags::CAT));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_encode_round_trip() -> io::Result<()> {
+        use super::super::decode;
+
+        let srcs: [Vec<u8>; 4] = [
+            Vec::new(),
+            b"noooooooodles".to_vec(),
+            (0..=255).collect(),
+            (0..4096u32)
+                .map(|i| b"ACGT"[(i % 7 % 4) as usize])
+                .collect(),
+        ];
+
+        for flags in [
+            Flags::empty(),
+            Flags::ORDER,
+            Flags::N32,
+            Flags::ORDER | Flags::N32,
+            Flags::STRIPE,
+            Flags::STRIPE | Flags::ORDER | Flags::PACK,
+            Flags::RLE,
+            Flags::ORDER | Flags::RLE,
+            Flags::PACK,
+            Flags::ORDER | Flags::RLE | Flags::PACK,
+            Flags::N32 | Flags::RLE | Flags::PACK,
+        ] {
+            for src in &srcs {
+                let buf = encode(flags, src)?;
+                let mut reader = &buf[..];
+                assert_eq!(decode(&mut reader, 0)?, *src, "flags = {flags:?}");
+            }
+        }
+
+        Ok(())
+    }
 }
@@ -12,6 +12,18 @@ pub fn encode(src: &[u8]) -> io::Result<(Vec<u8>, Vec<u8>)> {
         frequencies[sym] += 1;
     }
 
+    let symbol_count = frequencies.iter().filter(|&&f| f > 0).count();
+
+    // § 3.4 "Bit packing" (2022-04-12): packing is only defined for 1 to 16 symbols.
+    if symbol_count == 0 {
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, "empty input"));
+    } else if symbol_count > 16 {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "unique symbols > 16",
+        ));
+    }
+
     let mut lut = [0; 256];
     let mut n = 0;
 
@@ -50,7 +62,7 @@ pub fn encode(src: &[u8]) -> io::Result<(Vec<u8>, Vec<u8>)> {
         }
 
         dst
-    } else if n <= 16 {
+    } else {
         let len = (src.len() / 2) + 1;
         let mut dst = vec![0; len];
 
@@ -63,11 +75,6 @@ pub fn encode(src: &[u8]) -> io::Result<(Vec<u8>, Vec<u8>)> {
         }
 
         dst
-    } else {
-        return Err(io::Error::new(
-            io::ErrorKind::InvalidInput,
-            "unique symbols > 16",
-        ));
     };
 
     let mut header = Vec::new();
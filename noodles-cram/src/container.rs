@@ -1,3 +1,5 @@
+//! CRAM container and fields.
+
 pub mod block;
 
 pub use self::block::Block;
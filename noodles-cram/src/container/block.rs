@@ -1,11 +1,13 @@
+//! CRAM container block.
+
 mod builder;
 mod compression_method;
 mod content_id;
 mod content_type;
 
+pub(crate) use self::builder::Builder;
 pub use self::{
-    builder::Builder, compression_method::CompressionMethod, content_id::ContentId,
-    content_type::ContentType,
+    compression_method::CompressionMethod, content_id::ContentId, content_type::ContentType,
 };
 
 use std::{io, mem};
@@ -17,6 +19,10 @@ use crate::{
     num::itf8,
 };
 
+/// A CRAM container block.
+///
+/// A block holds (typically compressed) data of a given content type, e.g., a slice header or a
+/// data series.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Block {
     compression_method: CompressionMethod,
@@ -28,30 +34,38 @@ pub struct Block {
 
 #[allow(clippy::len_without_is_empty)]
 impl Block {
-    pub fn builder() -> Builder {
+    pub(crate) fn builder() -> Builder {
         Builder::default()
     }
 
+    /// Returns the compression method.
     pub fn compression_method(&self) -> CompressionMethod {
         self.compression_method
     }
 
+    /// Returns the content type.
     pub fn content_type(&self) -> ContentType {
         self.content_type
     }
 
+    /// Returns the content ID.
+    ///
+    /// For external data, this is the ID referenced by data series and tag encodings.
     pub fn content_id(&self) -> ContentId {
         self.content_id
     }
 
+    /// Returns the uncompressed length of the data.
     pub fn uncompressed_len(&self) -> usize {
         self.uncompressed_len
     }
 
+    /// Returns the (compressed) data.
     pub fn data(&self) -> &[u8] {
         &self.data
     }
 
+    /// Decompresses and returns the data.
     pub fn decompressed_data(&self) -> io::Result<Bytes> {
         use crate::codecs::{bzip2, gzip, lzma};
 
@@ -95,6 +109,7 @@ impl Block {
         }
     }
 
+    /// Returns the size of the block when written, including the block header and CRC32.
     pub fn len(&self) -> usize {
         // method
         mem::size_of::<u8>()
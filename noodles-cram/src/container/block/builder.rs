@@ -77,7 +77,12 @@ impl Builder {
                 CompressionMethod::NameTokenizer,
                 name_tokenizer::encode(&data)?,
             ),
-            Encoder::Fqzcomp => unimplemented!(),
+            Encoder::Fqzcomp => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "fqzcomp can only encode quality scores",
+                ))
+            }
         };
 
         self.compression_method = compression_method;
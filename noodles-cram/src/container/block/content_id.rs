@@ -1,5 +1,8 @@
+//! CRAM container block content ID.
+
 use std::fmt;
 
+/// A CRAM container block content ID.
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
 pub struct ContentId(i32);
 
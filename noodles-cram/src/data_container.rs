@@ -4,15 +4,13 @@ pub mod block_content_encoder_map;
 pub(crate) mod builder;
 pub mod compression_header;
 mod header;
-mod reference_sequence_context;
-pub(crate) mod slice;
+pub mod reference_sequence_context;
+pub mod slice;
 
+pub(crate) use self::builder::Builder;
 pub use self::{
     block_content_encoder_map::BlockContentEncoderMap, compression_header::CompressionHeader,
-    slice::Slice,
-};
-pub(crate) use self::{
-    builder::Builder, header::Header, reference_sequence_context::ReferenceSequenceContext,
+    header::Header, reference_sequence_context::ReferenceSequenceContext, slice::Slice,
 };
 
 /// A CRAM data container.
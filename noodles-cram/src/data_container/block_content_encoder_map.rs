@@ -1,8 +1,9 @@
 //! CRAM data container block content-encoder map.
 
 mod builder;
+mod profile;
 
-pub use self::builder::Builder;
+pub use self::{builder::Builder, profile::Profile};
 
 use std::collections::HashMap;
 
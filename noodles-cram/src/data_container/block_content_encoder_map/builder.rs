@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-use super::BlockContentEncoderMap;
+use super::{BlockContentEncoderMap, Profile};
 use crate::{
     codecs::Encoder,
     container::block,
@@ -32,6 +32,91 @@ impl Builder {
         self
     }
 
+    /// Sets the core data and data series encoders to those of the given profile.
+    ///
+    /// This overrides previously set core data and data series encoders. Data series encoders can
+    /// be overridden afterward using [`Self::set_data_series_encoder`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::{
+    ///     codecs::Encoder,
+    ///     data_container::{
+    ///         block_content_encoder_map::Profile,
+    ///         compression_header::data_series_encoding_map::DataSeries,
+    ///         BlockContentEncoderMap,
+    ///     },
+    /// };
+    ///
+    /// let map = BlockContentEncoderMap::builder()
+    ///     .set_profile(Profile::Archive)
+    ///     .set_data_series_encoder(
+    ///         DataSeries::ReadNames,
+    ///         Some(Encoder::Gzip(Default::default())),
+    ///     )
+    ///     .build();
+    /// ```
+    pub fn set_profile(mut self, profile: Profile) -> Self {
+        use ::bzip2::Compression as Bzip2Compression;
+        use flate2::Compression as GzipCompression;
+
+        use crate::codecs::{aac, rans_nx16};
+
+        use crate::data_container::compression_header::data_series_encoding_map::data_series::STANDARD_DATA_SERIES;
+
+        const LZMA_COMPRESSION_LEVEL: u32 = 6;
+
+        let (core_data_encoder, read_names_encoder, quality_scores_encoder, bases_encoder, encoder) =
+            match profile {
+                Profile::Fast => (
+                    Encoder::Gzip(GzipCompression::fast()),
+                    Encoder::Gzip(GzipCompression::fast()),
+                    Encoder::RansNx16(rans_nx16::Flags::ORDER),
+                    Encoder::RansNx16(rans_nx16::Flags::empty()),
+                    Encoder::RansNx16(rans_nx16::Flags::empty()),
+                ),
+                Profile::Normal => (
+                    Encoder::Gzip(GzipCompression::new(5)),
+                    Encoder::NameTokenizer,
+                    Encoder::RansNx16(rans_nx16::Flags::ORDER),
+                    Encoder::RansNx16(rans_nx16::Flags::ORDER),
+                    Encoder::RansNx16(rans_nx16::Flags::ORDER),
+                ),
+                Profile::Small => (
+                    Encoder::Bzip2(Bzip2Compression::best()),
+                    Encoder::NameTokenizer,
+                    Encoder::Fqzcomp,
+                    Encoder::Bzip2(Bzip2Compression::best()),
+                    Encoder::RansNx16(rans_nx16::Flags::ORDER),
+                ),
+                Profile::Archive => (
+                    Encoder::Lzma(LZMA_COMPRESSION_LEVEL),
+                    Encoder::NameTokenizer,
+                    Encoder::Fqzcomp,
+                    Encoder::Lzma(LZMA_COMPRESSION_LEVEL),
+                    Encoder::AdaptiveArithmeticCoding(aac::Flags::ORDER),
+                ),
+            };
+
+        self.core_data_encoder = Some(core_data_encoder);
+
+        for &data_series in STANDARD_DATA_SERIES {
+            let data_series_encoder = match data_series {
+                DataSeries::ReadNames => read_names_encoder.clone(),
+                DataSeries::QualityScores => quality_scores_encoder.clone(),
+                DataSeries::Bases | DataSeries::Insertion | DataSeries::SoftClip => {
+                    bases_encoder.clone()
+                }
+                _ => encoder.clone(),
+            };
+
+            self = self.set_data_series_encoder(data_series, Some(data_series_encoder));
+        }
+
+        self
+    }
+
     /// Sets a data series encoder.
     ///
     /// # Examples
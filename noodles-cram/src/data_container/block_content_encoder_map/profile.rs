@@ -0,0 +1,25 @@
+/// A block content-encoder map profile.
+///
+/// A profile is a preset of block content encoders that trades encoding speed for compression
+/// ratio. The encoders used by each profile are
+///
+/// | data series                 | fast         | normal         | small          | archive        |
+/// |-----------------------------|--------------|----------------|----------------|----------------|
+/// | core data                   | gzip (1)     | gzip (5)       | bzip2 (9)      | xz (6)         |
+/// | read names (`RN`)           | gzip (1)     | name tokenizer | name tokenizer | name tokenizer |
+/// | quality scores (`QS`)       | rANS Nx16 o1 | rANS Nx16 o1   | fqzcomp        | fqzcomp        |
+/// | bases (`BA`, `IN`, `SC`)    | rANS Nx16 o0 | rANS Nx16 o1   | bzip2 (9)      | xz (6)         |
+/// | (other)                     | rANS Nx16 o0 | rANS Nx16 o1   | rANS Nx16 o1   | arith o1       |
+///
+/// All profiles use CRAM 3.1 codecs, so the writer will write CRAM 3.1.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Profile {
+    /// Prefer encoding speed.
+    Fast,
+    /// Balance encoding speed and compression ratio.
+    Normal,
+    /// Prefer compression ratio.
+    Small,
+    /// Prefer compression ratio, regardless of encoding speed.
+    Archive,
+}
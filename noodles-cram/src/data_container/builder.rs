@@ -1,9 +1,9 @@
-use std::{io, mem, num};
+use std::{io, iter, mem, num};
 
 use noodles_fasta as fasta;
 use noodles_sam as sam;
 
-use super::{slice, CompressionHeader, DataContainer, Slice};
+use super::{slice, CompressionHeader, DataContainer, ReferenceSequenceContext, Slice};
 use crate::{writer::Options, Record};
 
 const MAX_SLICE_COUNT: usize = 1;
@@ -64,10 +64,43 @@ impl Builder {
     }
 
     pub fn build(
-        mut self,
+        self,
         options: &Options,
         reference_sequence_repository: &fasta::Repository,
         header: &sam::Header,
+    ) -> io::Result<DataContainer> {
+        let reference_sequences =
+            self.load_reference_sequences(reference_sequence_repository, header)?;
+
+        self.build_with_reference_sequences(options, &reference_sequences)
+    }
+
+    /// Returns the reference sequence of each slice.
+    ///
+    /// This is separate from building, as the reference sequence repository is not shareable
+    /// across threads.
+    pub fn load_reference_sequences(
+        &self,
+        reference_sequence_repository: &fasta::Repository,
+        header: &sam::Header,
+    ) -> io::Result<Vec<Option<fasta::record::Sequence>>> {
+        self.slice_builders
+            .iter()
+            .chain(iter::once(&self.slice_builder).filter(|builder| !builder.is_empty()))
+            .map(|builder| {
+                get_reference_sequence(
+                    reference_sequence_repository,
+                    header,
+                    builder.reference_sequence_context(),
+                )
+            })
+            .collect()
+    }
+
+    pub fn build_with_reference_sequences(
+        mut self,
+        options: &Options,
+        reference_sequences: &[Option<fasta::record::Sequence>],
     ) -> io::Result<DataContainer> {
         if !self.slice_builder.is_empty() {
             self.slice_builders.push(self.slice_builder);
@@ -83,19 +116,22 @@ impl Builder {
             options.encode_alignment_start_positions_as_deltas = false;
         }
 
-        let compression_header = build_compression_header(&options, &self.slice_builders);
+        let compression_header = build_compression_header(&options, &self.slice_builders)?;
 
         let record_counter = self.record_counter;
         let slices = self
             .slice_builders
             .into_iter()
-            .map(|builder| {
+            .enumerate()
+            .map(|(i, builder)| {
                 builder.build(
                     &options.block_content_encoder_map,
-                    reference_sequence_repository,
-                    header,
+                    reference_sequences
+                        .get(i)
+                        .and_then(|sequence| sequence.as_ref()),
                     &compression_header,
                     record_counter,
+                    options.embed_reference_sequences,
                 )
             })
             .collect::<Result<_, _>>()?;
@@ -107,10 +143,40 @@ impl Builder {
     }
 }
 
+fn get_reference_sequence(
+    reference_sequence_repository: &fasta::Repository,
+    header: &sam::Header,
+    reference_sequence_context: ReferenceSequenceContext,
+) -> io::Result<Option<fasta::record::Sequence>> {
+    let context = match reference_sequence_context {
+        ReferenceSequenceContext::Some(context) => context,
+        _ => return Ok(None),
+    };
+
+    let reference_sequence_name = header
+        .reference_sequences()
+        .get_index(context.reference_sequence_id())
+        .map(|(name, _)| name)
+        .ok_or_else(|| {
+            io::Error::new(io::ErrorKind::InvalidInput, "invalid reference sequence ID")
+        })?;
+
+    reference_sequence_repository
+        .get(reference_sequence_name)
+        .transpose()?
+        .map(Some)
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("missing reference sequence: {reference_sequence_name}"),
+            )
+        })
+}
+
 fn build_compression_header(
     options: &Options,
     slice_builders: &[slice::Builder],
-) -> CompressionHeader {
+) -> io::Result<CompressionHeader> {
     let mut compression_header_builder = CompressionHeader::builder();
     compression_header_builder.apply_options(options);
 
@@ -120,5 +186,7 @@ fn build_compression_header(
         }
     }
 
-    compression_header_builder.build()
+    compression_header_builder
+        .build()
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))
 }
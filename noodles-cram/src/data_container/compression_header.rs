@@ -2,18 +2,20 @@
 
 mod builder;
 pub mod data_series_encoding_map;
-pub(crate) mod encoding;
+pub mod encoding;
 pub mod preservation_map;
-mod tag_encoding_map;
+pub mod tag_encoding_map;
 
-pub(crate) use self::{
-    builder::Builder,
+pub use self::{
+    builder::{BuildError, Builder},
     data_series_encoding_map::DataSeriesEncodingMap,
     encoding::Encoding,
-    preservation_map::{PreservationMap, SubstitutionMatrix, TagIdsDictionary},
+    preservation_map::{PreservationMap, SubstitutionMatrix},
     tag_encoding_map::TagEncodingMap,
 };
 
+pub(crate) use self::preservation_map::TagIdsDictionary;
+
 /// A CRAM data container compression header.
 ///
 /// The compression header has three maps with information about how the data is compressed: a
@@ -26,7 +28,15 @@ pub struct CompressionHeader {
 }
 
 impl CompressionHeader {
-    pub(crate) fn builder() -> Builder {
+    /// Returns a builder to create a compression header.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::data_container::CompressionHeader;
+    /// let builder = CompressionHeader::builder();
+    /// ```
+    pub fn builder() -> Builder {
         Builder::default()
     }
 
@@ -42,15 +52,53 @@ impl CompressionHeader {
         }
     }
 
-    pub(crate) fn preservation_map(&self) -> &PreservationMap {
+    /// Returns the preservation map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::data_container::CompressionHeader;
+    /// let compression_header = CompressionHeader::builder().build()?;
+    /// assert!(compression_header.preservation_map().read_names_included());
+    /// # Ok::<_, noodles_cram::data_container::compression_header::BuildError>(())
+    /// ```
+    pub fn preservation_map(&self) -> &PreservationMap {
         &self.preservation_map
     }
 
-    pub(crate) fn data_series_encoding_map(&self) -> &DataSeriesEncodingMap {
+    /// Returns the substitution matrix.
+    ///
+    /// Read bases that differ from the reference sequence may be stored as substitution codes,
+    /// which are resolved using this matrix.
+    pub fn substitution_matrix(&self) -> &SubstitutionMatrix {
+        self.preservation_map.substitution_matrix()
+    }
+
+    /// Returns the data series encoding map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::data_container::CompressionHeader;
+    /// let compression_header = CompressionHeader::builder().build()?;
+    /// assert!(compression_header.data_series_encoding_map().read_names_encoding().is_some());
+    /// # Ok::<_, noodles_cram::data_container::compression_header::BuildError>(())
+    /// ```
+    pub fn data_series_encoding_map(&self) -> &DataSeriesEncodingMap {
         &self.data_series_encoding_map
     }
 
-    pub(crate) fn tag_encoding_map(&self) -> &TagEncodingMap {
+    /// Returns the tag encoding map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::data_container::CompressionHeader;
+    /// let compression_header = CompressionHeader::builder().build()?;
+    /// assert!(compression_header.tag_encoding_map().is_empty());
+    /// # Ok::<_, noodles_cram::data_container::compression_header::BuildError>(())
+    /// ```
+    pub fn tag_encoding_map(&self) -> &TagEncodingMap {
         &self.tag_encoding_map
     }
 }
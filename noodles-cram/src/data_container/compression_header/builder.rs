@@ -1,29 +1,220 @@
+use std::{error, fmt};
+
 use super::{
-    data_series_encoding_map::DataSeriesEncodingMap, preservation_map, tag_encoding_map,
-    CompressionHeader,
+    data_series_encoding_map::DataSeriesEncodingMap, preservation_map,
+    preservation_map::tag_ids_dictionary::Key, tag_encoding_map, CompressionHeader,
+    PreservationMap, TagEncodingMap,
 };
-use crate::{writer::Options, Record};
+use crate::{container::block, writer::Options, Record};
 
+/// A CRAM data container compression header builder.
+///
+/// Maps that are not set are built from the records given to the builder. A missing data series
+/// encoding map defaults to all data series being stored in external blocks.
 #[derive(Debug, Default)]
 pub struct Builder {
+    preservation_map: Option<PreservationMap>,
+    data_series_encoding_map: Option<DataSeriesEncodingMap>,
+    tag_encoding_map: Option<TagEncodingMap>,
     preservation_map_builder: preservation_map::Builder,
     tag_encoding_map_builder: tag_encoding_map::Builder,
 }
 
 impl Builder {
-    pub fn apply_options(&mut self, options: &Options) {
+    /// Sets the preservation map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::data_container::{
+    ///     compression_header::PreservationMap, CompressionHeader,
+    /// };
+    ///
+    /// let preservation_map = PreservationMap::builder().build();
+    ///
+    /// let compression_header = CompressionHeader::builder()
+    ///     .set_preservation_map(preservation_map.clone())
+    ///     .build()?;
+    ///
+    /// assert_eq!(compression_header.preservation_map(), &preservation_map);
+    /// # Ok::<_, noodles_cram::data_container::compression_header::BuildError>(())
+    /// ```
+    pub fn set_preservation_map(mut self, preservation_map: PreservationMap) -> Self {
+        self.preservation_map = Some(preservation_map);
+        self
+    }
+
+    /// Sets the data series encoding map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::{
+    ///     container::block,
+    ///     data_container::{
+    ///         compression_header::{encoding::codec::Integer, DataSeriesEncodingMap, Encoding},
+    ///         CompressionHeader,
+    ///     },
+    /// };
+    ///
+    /// let external = |id| Encoding::new(Integer::External(block::ContentId::from(id)));
+    ///
+    /// let data_series_encoding_map = DataSeriesEncodingMap::builder()
+    ///     .set_bam_bit_flags_encoding(external(1))
+    ///     .set_cram_bit_flags_encoding(external(2))
+    ///     .set_read_lengths_encoding(external(4))
+    ///     .set_in_seq_positions_encoding(external(5))
+    ///     .set_read_groups_encoding(external(6))
+    ///     .set_tag_ids_encoding(external(13))
+    ///     .build()?;
+    ///
+    /// let compression_header = CompressionHeader::builder()
+    ///     .set_data_series_encoding_map(data_series_encoding_map.clone())
+    ///     .build();
+    ///
+    /// // The read names encoding is required when read names are preserved.
+    /// assert!(compression_header.is_err());
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_data_series_encoding_map(
+        mut self,
+        data_series_encoding_map: DataSeriesEncodingMap,
+    ) -> Self {
+        self.data_series_encoding_map = Some(data_series_encoding_map);
+        self
+    }
+
+    /// Sets the tag encoding map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::data_container::{compression_header::TagEncodingMap, CompressionHeader};
+    ///
+    /// let tag_encoding_map = TagEncodingMap::builder().build();
+    ///
+    /// let compression_header = CompressionHeader::builder()
+    ///     .set_tag_encoding_map(tag_encoding_map.clone())
+    ///     .build()?;
+    ///
+    /// assert_eq!(compression_header.tag_encoding_map(), &tag_encoding_map);
+    /// # Ok::<_, noodles_cram::data_container::compression_header::BuildError>(())
+    /// ```
+    pub fn set_tag_encoding_map(mut self, tag_encoding_map: TagEncodingMap) -> Self {
+        self.tag_encoding_map = Some(tag_encoding_map);
+        self
+    }
+
+    pub(crate) fn apply_options(&mut self, options: &Options) {
         self.preservation_map_builder.apply_options(options);
     }
 
-    pub fn update(&mut self, record: &Record) {
+    pub(crate) fn update(&mut self, record: &Record) {
         self.preservation_map_builder.update(record);
         self.tag_encoding_map_builder.update(record);
     }
 
-    pub fn build(self) -> CompressionHeader {
-        let preservation_map = self.preservation_map_builder.build();
+    /// Builds a compression header.
+    ///
+    /// This fails if the maps are inconsistent, i.e., if read names are preserved but the data
+    /// series encoding map has no read names encoding, or if a key in the tag IDs dictionary has
+    /// no encoding in the tag encoding map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::data_container::CompressionHeader;
+    /// let compression_header = CompressionHeader::builder().build()?;
+    /// # Ok::<_, noodles_cram::data_container::compression_header::BuildError>(())
+    /// ```
+    pub fn build(self) -> Result<CompressionHeader, BuildError> {
+        let preservation_map = self
+            .preservation_map
+            .unwrap_or_else(|| self.preservation_map_builder.build());
+
+        let data_series_encoding_map = self.data_series_encoding_map.unwrap_or_default();
+
+        let tag_encoding_map = self
+            .tag_encoding_map
+            .unwrap_or_else(|| self.tag_encoding_map_builder.build());
+
+        if preservation_map.read_names_included()
+            && data_series_encoding_map.read_names_encoding().is_none()
+        {
+            return Err(BuildError::MissingReadNamesEncoding);
+        }
+
+        for &key in preservation_map.tag_ids_dictionary().iter().flatten() {
+            if !tag_encoding_map.contains_key(&block::ContentId::from(key)) {
+                return Err(BuildError::MissingTagEncoding(key));
+            }
+        }
+
+        Ok(CompressionHeader::new(
+            preservation_map,
+            data_series_encoding_map,
+            tag_encoding_map,
+        ))
+    }
+}
+
+/// An error returned when a compression header fails to build.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum BuildError {
+    /// Read names are preserved, but the read names encoding is missing.
+    MissingReadNamesEncoding,
+    /// A tag IDs dictionary key is missing an encoding.
+    MissingTagEncoding(Key),
+}
+
+impl error::Error for BuildError {}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingReadNamesEncoding => f.write_str("missing read names encoding"),
+            Self::MissingTagEncoding(key) => {
+                write!(f, "missing tag encoding: {}:{}", key.tag(), key.ty())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_sam::record::data::field::{value::Type, Tag};
+
+    use super::*;
+    use crate::data_container::compression_header::preservation_map::TagIdsDictionary;
+
+    #[test]
+    fn test_build() {
+        let preservation_map = PreservationMap::builder()
+            .set_read_names_included(false)
+            .build();
+
         let data_series_encoding_map = DataSeriesEncodingMap::default();
-        let tag_encoding_map = self.tag_encoding_map_builder.build();
-        CompressionHeader::new(preservation_map, data_series_encoding_map, tag_encoding_map)
+
+        assert!(CompressionHeader::builder()
+            .set_preservation_map(preservation_map)
+            .set_data_series_encoding_map(data_series_encoding_map)
+            .build()
+            .is_ok());
+    }
+
+    #[test]
+    fn test_build_with_missing_tag_encoding() {
+        let key = Key::new(Tag::AlignmentHitCount, Type::UInt8);
+
+        let preservation_map = PreservationMap::builder()
+            .set_tag_ids_dictionary(TagIdsDictionary::from(vec![vec![key]]))
+            .build();
+
+        assert_eq!(
+            CompressionHeader::builder()
+                .set_preservation_map(preservation_map)
+                .build(),
+            Err(BuildError::MissingTagEncoding(key))
+        );
     }
 }
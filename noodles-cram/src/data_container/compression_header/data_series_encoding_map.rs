@@ -1,10 +1,9 @@
 //! CRAM data container compress header data series-encoding map.
 
-mod builder;
+pub mod builder;
 pub(crate) mod data_series;
 
-pub(crate) use self::builder::Builder;
-pub use self::data_series::DataSeries;
+pub use self::{builder::Builder, data_series::DataSeries};
 
 use super::{
     encoding::codec::{Byte, ByteArray, Integer},
@@ -13,8 +12,11 @@ use super::{
 use crate::container::block;
 
 /// A container compression header data series encoding map.
+///
+/// The BAM bit flags, CRAM bit flags, read lengths, in-seq positions, read groups, and tag IDs
+/// encodings are required. All other data series encodings are optional.
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub(crate) struct DataSeriesEncodingMap {
+pub struct DataSeriesEncodingMap {
     bam_bit_flags_encoding: Encoding<Integer>,
     cram_bit_flags_encoding: Encoding<Integer>,
     reference_id_encoding: Option<Encoding<Integer>>,
@@ -46,11 +48,19 @@ pub(crate) struct DataSeriesEncodingMap {
 }
 
 impl DataSeriesEncodingMap {
-    pub(crate) fn builder() -> Builder {
+    /// Returns a builder to create a data series encoding map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::data_container::compression_header::DataSeriesEncodingMap;
+    /// let builder = DataSeriesEncodingMap::builder();
+    /// ```
+    pub fn builder() -> Builder {
         Builder::default()
     }
 
-    pub fn len(&self) -> usize {
+    pub(crate) fn len(&self) -> usize {
         // BAM bit flags, CRAM bit flags, read lengths, in-seq positions, read groups, tag IDs
         let mut n = 6;
 
@@ -148,114 +158,142 @@ impl DataSeriesEncodingMap {
         n
     }
 
+    /// Returns the BAM bit flags encoding.
     pub fn bam_bit_flags_encoding(&self) -> &Encoding<Integer> {
         &self.bam_bit_flags_encoding
     }
 
+    /// Returns the CRAM bit flags encoding.
     pub fn cram_bit_flags_encoding(&self) -> &Encoding<Integer> {
         &self.cram_bit_flags_encoding
     }
 
+    /// Returns the reference ID encoding.
     pub fn reference_id_encoding(&self) -> Option<&Encoding<Integer>> {
         self.reference_id_encoding.as_ref()
     }
 
+    /// Returns the read lengths encoding.
     pub fn read_lengths_encoding(&self) -> &Encoding<Integer> {
         &self.read_lengths_encoding
     }
 
+    /// Returns the in seq positions encoding.
     pub fn in_seq_positions_encoding(&self) -> &Encoding<Integer> {
         &self.in_seq_positions_encoding
     }
 
+    /// Returns the read groups encoding.
     pub fn read_groups_encoding(&self) -> &Encoding<Integer> {
         &self.read_groups_encoding
     }
 
+    /// Returns the read names encoding.
     pub fn read_names_encoding(&self) -> Option<&Encoding<ByteArray>> {
         self.read_names_encoding.as_ref()
     }
 
+    /// Returns the next mate bit flags encoding.
     pub fn next_mate_bit_flags_encoding(&self) -> Option<&Encoding<Integer>> {
         self.next_mate_bit_flags_encoding.as_ref()
     }
 
+    /// Returns the next fragment reference sequence ID encoding.
     pub fn next_fragment_reference_sequence_id_encoding(&self) -> Option<&Encoding<Integer>> {
         self.next_fragment_reference_sequence_id_encoding.as_ref()
     }
 
+    /// Returns the next mate alignment start encoding.
     pub fn next_mate_alignment_start_encoding(&self) -> Option<&Encoding<Integer>> {
         self.next_mate_alignment_start_encoding.as_ref()
     }
 
+    /// Returns the template size encoding.
     pub fn template_size_encoding(&self) -> Option<&Encoding<Integer>> {
         self.template_size_encoding.as_ref()
     }
 
+    /// Returns the distance to next fragment encoding.
     pub fn distance_to_next_fragment_encoding(&self) -> Option<&Encoding<Integer>> {
         self.distance_to_next_fragment_encoding.as_ref()
     }
 
+    /// Returns the tag IDs encoding.
     pub fn tag_ids_encoding(&self) -> &Encoding<Integer> {
         &self.tag_ids_encoding
     }
 
+    /// Returns the number of read features encoding.
     pub fn number_of_read_features_encoding(&self) -> Option<&Encoding<Integer>> {
         self.number_of_read_features_encoding.as_ref()
     }
 
+    /// Returns the read features codes encoding.
     pub fn read_features_codes_encoding(&self) -> Option<&Encoding<Byte>> {
         self.read_features_codes_encoding.as_ref()
     }
 
+    /// Returns the in read positions encoding.
     pub fn in_read_positions_encoding(&self) -> Option<&Encoding<Integer>> {
         self.in_read_positions_encoding.as_ref()
     }
 
+    /// Returns the deletion lengths encoding.
     pub fn deletion_lengths_encoding(&self) -> Option<&Encoding<Integer>> {
         self.deletion_lengths_encoding.as_ref()
     }
 
+    /// Returns the stretches of bases encoding.
     pub fn stretches_of_bases_encoding(&self) -> Option<&Encoding<ByteArray>> {
         self.stretches_of_bases_encoding.as_ref()
     }
 
+    /// Returns the stretches of quality scores encoding.
     pub fn stretches_of_quality_scores_encoding(&self) -> Option<&Encoding<ByteArray>> {
         self.stretches_of_quality_scores_encoding.as_ref()
     }
 
+    /// Returns the base substitution codes encoding.
     pub fn base_substitution_codes_encoding(&self) -> Option<&Encoding<Byte>> {
         self.base_substitution_codes_encoding.as_ref()
     }
 
+    /// Returns the insertion encoding.
     pub fn insertion_encoding(&self) -> Option<&Encoding<ByteArray>> {
         self.insertion_encoding.as_ref()
     }
 
+    /// Returns the reference skip length encoding.
     pub fn reference_skip_length_encoding(&self) -> Option<&Encoding<Integer>> {
         self.reference_skip_length_encoding.as_ref()
     }
 
+    /// Returns the padding encoding.
     pub fn padding_encoding(&self) -> Option<&Encoding<Integer>> {
         self.padding_encoding.as_ref()
     }
 
+    /// Returns the hard clip encoding.
     pub fn hard_clip_encoding(&self) -> Option<&Encoding<Integer>> {
         self.hard_clip_encoding.as_ref()
     }
 
+    /// Returns the soft clip encoding.
     pub fn soft_clip_encoding(&self) -> Option<&Encoding<ByteArray>> {
         self.soft_clip_encoding.as_ref()
     }
 
+    /// Returns the mapping qualities encoding.
     pub fn mapping_qualities_encoding(&self) -> Option<&Encoding<Integer>> {
         self.mapping_qualities_encoding.as_ref()
     }
 
+    /// Returns the bases encoding.
     pub fn bases_encoding(&self) -> Option<&Encoding<Byte>> {
         self.bases_encoding.as_ref()
     }
 
+    /// Returns the quality scores encoding.
     pub fn quality_scores_encoding(&self) -> Option<&Encoding<Byte>> {
         self.quality_scores_encoding.as_ref()
     }
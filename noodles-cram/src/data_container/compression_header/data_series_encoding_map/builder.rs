@@ -1,3 +1,5 @@
+//! CRAM data container compression header data series encoding map builder.
+
 use std::{error, fmt};
 
 use crate::data_container::compression_header::{
@@ -7,6 +9,7 @@ use crate::data_container::compression_header::{
 
 use super::DataSeriesEncodingMap;
 
+/// A CRAM data container compression header data series encoding map builder.
 #[derive(Default)]
 pub struct Builder {
     bam_bit_flags_encoding: Option<Encoding<Integer>>,
@@ -40,46 +43,55 @@ pub struct Builder {
 }
 
 impl Builder {
+    /// Sets the BAM bit flags encoding.
     pub fn set_bam_bit_flags_encoding(mut self, encoding: Encoding<Integer>) -> Self {
         self.bam_bit_flags_encoding = Some(encoding);
         self
     }
 
+    /// Sets the CRAM bit flags encoding.
     pub fn set_cram_bit_flags_encoding(mut self, encoding: Encoding<Integer>) -> Self {
         self.cram_bit_flags_encoding = Some(encoding);
         self
     }
 
+    /// Sets the reference ID encoding.
     pub fn set_reference_id_encoding(mut self, encoding: Encoding<Integer>) -> Self {
         self.reference_id_encoding = Some(encoding);
         self
     }
 
+    /// Sets the read lengths encoding.
     pub fn set_read_lengths_encoding(mut self, encoding: Encoding<Integer>) -> Self {
         self.read_lengths_encoding = Some(encoding);
         self
     }
 
+    /// Sets the in seq positions encoding.
     pub fn set_in_seq_positions_encoding(mut self, encoding: Encoding<Integer>) -> Self {
         self.in_seq_positions_encoding = Some(encoding);
         self
     }
 
+    /// Sets the read groups encoding.
     pub fn set_read_groups_encoding(mut self, encoding: Encoding<Integer>) -> Self {
         self.read_groups_encoding = Some(encoding);
         self
     }
 
+    /// Sets the read names encoding.
     pub fn set_read_names_encoding(mut self, encoding: Encoding<ByteArray>) -> Self {
         self.read_names_encoding = Some(encoding);
         self
     }
 
+    /// Sets the next mate bit flags encoding.
     pub fn set_next_mate_bit_flags_encoding(mut self, encoding: Encoding<Integer>) -> Self {
         self.next_mate_bit_flags_encoding = Some(encoding);
         self
     }
 
+    /// Sets the next fragment reference sequence ID encoding.
     pub fn set_next_fragment_reference_sequence_id_encoding(
         mut self,
         encoding: Encoding<Integer>,
@@ -88,51 +100,61 @@ impl Builder {
         self
     }
 
+    /// Sets the next mate alignment start encoding.
     pub fn set_next_mate_alignment_start_encoding(mut self, encoding: Encoding<Integer>) -> Self {
         self.next_mate_alignment_start_encoding = Some(encoding);
         self
     }
 
+    /// Sets the template size encoding.
     pub fn set_template_size_encoding(mut self, encoding: Encoding<Integer>) -> Self {
         self.template_size_encoding = Some(encoding);
         self
     }
 
+    /// Sets the distance to next fragment encoding.
     pub fn set_distance_to_next_fragment_encoding(mut self, encoding: Encoding<Integer>) -> Self {
         self.distance_to_next_fragment_encoding = Some(encoding);
         self
     }
 
+    /// Sets the tag IDs encoding.
     pub fn set_tag_ids_encoding(mut self, encoding: Encoding<Integer>) -> Self {
         self.tag_ids_encoding = Some(encoding);
         self
     }
 
+    /// Sets the number of read features encoding.
     pub fn set_number_of_read_features_encoding(mut self, encoding: Encoding<Integer>) -> Self {
         self.number_of_read_features_encoding = Some(encoding);
         self
     }
 
+    /// Sets the read features codes encoding.
     pub fn set_read_features_codes_encoding(mut self, encoding: Encoding<Byte>) -> Self {
         self.read_features_codes_encoding = Some(encoding);
         self
     }
 
+    /// Sets the in read positions encoding.
     pub fn set_in_read_positions_encoding(mut self, encoding: Encoding<Integer>) -> Self {
         self.in_read_positions_encoding = Some(encoding);
         self
     }
 
+    /// Sets the deletion lengths encoding.
     pub fn set_deletion_lengths_encoding(mut self, encoding: Encoding<Integer>) -> Self {
         self.deletion_lengths_encoding = Some(encoding);
         self
     }
 
+    /// Sets the stretches of bases encoding.
     pub fn set_stretches_of_bases_encoding(mut self, encoding: Encoding<ByteArray>) -> Self {
         self.stretches_of_bases_encoding = Some(encoding);
         self
     }
 
+    /// Sets the stretches of quality scores encoding.
     pub fn set_stretches_of_quality_scores_encoding(
         mut self,
         encoding: Encoding<ByteArray>,
@@ -141,52 +163,96 @@ impl Builder {
         self
     }
 
+    /// Sets the base substitution codes encoding.
     pub fn set_base_substitution_codes_encoding(mut self, encoding: Encoding<Byte>) -> Self {
         self.base_substitution_codes_encoding = Some(encoding);
         self
     }
 
+    /// Sets the insertion encoding.
     pub fn set_insertion_encoding(mut self, encoding: Encoding<ByteArray>) -> Self {
         self.insertion_encoding = Some(encoding);
         self
     }
 
+    /// Sets the reference skip length encoding.
     pub fn set_reference_skip_length_encoding(mut self, encoding: Encoding<Integer>) -> Self {
         self.reference_skip_length_encoding = Some(encoding);
         self
     }
 
+    /// Sets the padding encoding.
     pub fn set_padding_encoding(mut self, encoding: Encoding<Integer>) -> Self {
         self.padding_encoding = Some(encoding);
         self
     }
 
+    /// Sets the hard clip encoding.
     pub fn set_hard_clip_encoding(mut self, encoding: Encoding<Integer>) -> Self {
         self.hard_clip_encoding = Some(encoding);
         self
     }
 
+    /// Sets the soft clip encoding.
     pub fn set_soft_clip_encoding(mut self, encoding: Encoding<ByteArray>) -> Self {
         self.soft_clip_encoding = Some(encoding);
         self
     }
 
+    /// Sets the mapping qualities encoding.
     pub fn set_mapping_qualities_encoding(mut self, encoding: Encoding<Integer>) -> Self {
         self.mapping_qualities_encoding = Some(encoding);
         self
     }
 
+    /// Sets the bases encoding.
     pub fn set_bases_encoding(mut self, encoding: Encoding<Byte>) -> Self {
         self.bases_encoding = Some(encoding);
         self
     }
 
+    /// Sets the quality scores encoding.
     pub fn set_quality_scores_encoding(mut self, encoding: Encoding<Byte>) -> Self {
         self.quality_scores_encoding = Some(encoding);
         self
     }
 
-    pub(crate) fn build(self) -> Result<DataSeriesEncodingMap, BuildError> {
+    /// Builds a data series encoding map.
+    ///
+    /// This fails if any of the required data series encodings are missing, i.e., BAM bit flags,
+    /// CRAM bit flags, read lengths, in-seq positions, read groups, and tag IDs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::{
+    ///     container::block,
+    ///     data_container::compression_header::{
+    ///         data_series_encoding_map::builder::BuildError, encoding::codec::Integer,
+    ///         DataSeriesEncodingMap, Encoding,
+    ///     },
+    /// };
+    ///
+    /// let external = |id| Encoding::new(Integer::External(block::ContentId::from(id)));
+    ///
+    /// let map = DataSeriesEncodingMap::builder()
+    ///     .set_bam_bit_flags_encoding(external(1))
+    ///     .set_cram_bit_flags_encoding(external(2))
+    ///     .set_read_lengths_encoding(external(4))
+    ///     .set_in_seq_positions_encoding(external(5))
+    ///     .set_read_groups_encoding(external(6))
+    ///     .set_tag_ids_encoding(external(13))
+    ///     .build()?;
+    ///
+    /// assert!(map.read_names_encoding().is_none());
+    ///
+    /// assert_eq!(
+    ///     DataSeriesEncodingMap::builder().build(),
+    ///     Err(BuildError::MissingBamBitFlagsEncoding)
+    /// );
+    /// # Ok::<_, BuildError>(())
+    /// ```
+    pub fn build(self) -> Result<DataSeriesEncodingMap, BuildError> {
         Ok(DataSeriesEncodingMap {
             bam_bit_flags_encoding: self
                 .bam_bit_flags_encoding
@@ -233,14 +299,21 @@ impl Builder {
     }
 }
 
+/// An error returned when a data series encoding map fails to build.
 #[allow(clippy::enum_variant_names)]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum BuildError {
+    /// The BAM bit flags encoding is missing.
     MissingBamBitFlagsEncoding,
+    /// The CRAM bit flags encoding is missing.
     MissingCramBitFlagsEncoding,
+    /// The read lengths encoding is missing.
     MissingReadLengthsEncoding,
+    /// The in-seq positions encoding is missing.
     MissingInSeqPositionsEncoding,
+    /// The read groups encoding is missing.
     MissingReadGroupsEncoding,
+    /// The tag IDs encoding is missing.
     MissingTagIdsEncoding,
 }
 
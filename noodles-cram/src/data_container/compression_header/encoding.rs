@@ -1,16 +1,46 @@
+//! CRAM data container compression header encoding.
+
 pub mod codec;
 mod kind;
 
-pub use self::kind::Kind;
+pub(crate) use self::kind::Kind;
 
+/// A CRAM data container compression header encoding.
+///
+/// An encoding wraps a codec that describes how values of a data series are encoded.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Encoding<C>(C);
 
 impl<C> Encoding<C> {
+    /// Creates an encoding.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::{
+    ///     container::block,
+    ///     data_container::compression_header::{encoding::codec::Integer, Encoding},
+    /// };
+    ///
+    /// let encoding = Encoding::new(Integer::External(block::ContentId::from(1)));
+    /// ```
     pub fn new(codec: C) -> Self {
         Self(codec)
     }
 
+    /// Returns the codec.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::{
+    ///     container::block,
+    ///     data_container::compression_header::{encoding::codec::Integer, Encoding},
+    /// };
+    ///
+    /// let encoding = Encoding::new(Integer::External(block::ContentId::from(1)));
+    /// assert_eq!(encoding.get(), &Integer::External(block::ContentId::from(1)));
+    /// ```
     pub fn get(&self) -> &C {
         &self.0
     }
@@ -1,9 +1,10 @@
 use crate::container::block;
 
+/// A CRAM encoding codec that decodes bytes.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum Byte {
-    // block_content_id
+    /// External codec (`block_content_id`).
     External(block::ContentId),
-    // alphabet, bit_lens
+    /// Huffman codec (`alphabet`, `bit_lens`).
     Huffman(Vec<i32>, Vec<u32>),
 }
@@ -6,10 +6,11 @@ use crate::{
     },
 };
 
+/// A CRAM encoding codec that decodes byte arrays.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum ByteArray {
-    // len_encoding, value_encoding
+    /// Byte array length codec (`len_encoding`, `value_encoding`).
     ByteArrayLen(Encoding<Integer>, Encoding<Byte>),
-    // stop_byte, block_content_id
+    /// Byte array stop codec (`stop_byte`, `block_content_id`).
     ByteArrayStop(u8, block::ContentId),
 }
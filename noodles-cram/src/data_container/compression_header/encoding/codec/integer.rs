@@ -1,19 +1,20 @@
 use crate::container::block;
 
+/// A CRAM encoding codec that decodes integers.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum Integer {
-    // block_content_id
+    /// External codec (`block_content_id`).
     External(block::ContentId),
-    // offset, m
+    /// Golomb codec (`offset`, `m`).
     Golomb(i32, i32),
-    // alphabet, bit_lens
+    /// Huffman codec (`alphabet`, `bit_lens`).
     Huffman(Vec<i32>, Vec<u32>),
-    // offset, len
+    /// Beta codec (`offset`, `len`).
     Beta(i32, u32),
-    // offset, k
+    /// Subexponential codec (`offset`, `k`).
     Subexp(i32, i32),
-    // offset, log2_m
+    /// Golomb-Rice codec (`offset`, `log2_m`).
     GolombRice(i32, i32),
-    // offset
+    /// Elias gamma codec (`offset`).
     Gamma(i32),
 }
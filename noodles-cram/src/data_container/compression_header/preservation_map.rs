@@ -2,16 +2,21 @@
 
 mod builder;
 pub(crate) mod key;
-pub(crate) mod substitution_matrix;
+pub mod substitution_matrix;
 pub mod tag_ids_dictionary;
 
-pub(crate) use {
-    builder::Builder, key::Key, substitution_matrix::SubstitutionMatrix,
-    tag_ids_dictionary::TagIdsDictionary,
+pub use self::{
+    builder::Builder, substitution_matrix::SubstitutionMatrix, tag_ids_dictionary::TagIdsDictionary,
 };
 
+pub(crate) use self::key::Key;
+
+/// A CRAM data container compression header preservation map.
+///
+/// The preservation map holds flags and dictionaries that apply to all records in the data
+/// container.
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub(crate) struct PreservationMap {
+pub struct PreservationMap {
     read_names_included: bool,
     ap_data_series_delta: bool,
     is_reference_required: bool,
@@ -20,7 +25,19 @@ pub(crate) struct PreservationMap {
 }
 
 impl PreservationMap {
-    pub fn new(
+    /// Returns a builder to create a preservation map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::data_container::compression_header::PreservationMap;
+    /// let builder = PreservationMap::builder();
+    /// ```
+    pub fn builder() -> Builder {
+        Builder::default()
+    }
+
+    pub(crate) fn new(
         read_names_included: bool,
         ap_data_series_delta: bool,
         is_reference_required: bool,
@@ -36,22 +53,70 @@ impl PreservationMap {
         }
     }
 
+    /// Returns whether read names are preserved (`RN`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::data_container::compression_header::PreservationMap;
+    /// let preservation_map = PreservationMap::builder().build();
+    /// assert!(preservation_map.read_names_included());
+    /// ```
     pub fn read_names_included(&self) -> bool {
         self.read_names_included
     }
 
+    /// Returns whether alignment start positions are stored as deltas (`AP`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::data_container::compression_header::PreservationMap;
+    /// let preservation_map = PreservationMap::builder().build();
+    /// assert!(preservation_map.ap_data_series_delta());
+    /// ```
     pub fn ap_data_series_delta(&self) -> bool {
         self.ap_data_series_delta
     }
 
+    /// Returns whether an external reference sequence is required to decode records (`RR`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::data_container::compression_header::PreservationMap;
+    /// let preservation_map = PreservationMap::builder().build();
+    /// assert!(preservation_map.is_reference_required());
+    /// ```
     pub fn is_reference_required(&self) -> bool {
         self.is_reference_required
     }
 
+    /// Returns the substitution matrix (`SM`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::data_container::compression_header::{
+    ///     preservation_map::SubstitutionMatrix, PreservationMap,
+    /// };
+    ///
+    /// let preservation_map = PreservationMap::builder().build();
+    /// assert_eq!(preservation_map.substitution_matrix(), &SubstitutionMatrix::default());
+    /// ```
     pub fn substitution_matrix(&self) -> &SubstitutionMatrix {
         &self.substitution_matrix
     }
 
+    /// Returns the tag IDs dictionary (`TD`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::data_container::compression_header::PreservationMap;
+    /// let preservation_map = PreservationMap::builder().build();
+    /// assert!(preservation_map.tag_ids_dictionary().is_empty());
+    /// ```
     pub fn tag_ids_dictionary(&self) -> &TagIdsDictionary {
         &self.tag_ids_dictionary
     }
@@ -1,29 +1,154 @@
-use super::{substitution_matrix, tag_ids_dictionary, PreservationMap};
+use super::{
+    substitution_matrix, tag_ids_dictionary, PreservationMap, SubstitutionMatrix, TagIdsDictionary,
+};
 use crate::{writer::Options, Record};
 
+/// A CRAM data container compression header preservation map builder.
+///
+/// If the substitution matrix or tag IDs dictionary is not set, it is built from the records
+/// given to the builder.
 #[derive(Debug)]
 pub struct Builder {
     read_names_included: bool,
     ap_data_series_delta: bool,
     reference_required: bool,
+    substitution_matrix: Option<SubstitutionMatrix>,
+    tag_ids_dictionary: Option<TagIdsDictionary>,
     substitution_matrix_builder: substitution_matrix::Builder,
     tag_ids_dictionary_builder: tag_ids_dictionary::Builder,
 }
 
 impl Builder {
-    pub fn apply_options(&mut self, options: &Options) {
+    /// Sets whether read names are preserved (`RN`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::data_container::compression_header::PreservationMap;
+    ///
+    /// let preservation_map = PreservationMap::builder()
+    ///     .set_read_names_included(false)
+    ///     .build();
+    ///
+    /// assert!(!preservation_map.read_names_included());
+    /// ```
+    pub fn set_read_names_included(mut self, read_names_included: bool) -> Self {
+        self.read_names_included = read_names_included;
+        self
+    }
+
+    /// Sets whether alignment start positions are stored as deltas (`AP`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::data_container::compression_header::PreservationMap;
+    ///
+    /// let preservation_map = PreservationMap::builder()
+    ///     .set_ap_data_series_delta(false)
+    ///     .build();
+    ///
+    /// assert!(!preservation_map.ap_data_series_delta());
+    /// ```
+    pub fn set_ap_data_series_delta(mut self, ap_data_series_delta: bool) -> Self {
+        self.ap_data_series_delta = ap_data_series_delta;
+        self
+    }
+
+    /// Sets whether an external reference sequence is required to decode records (`RR`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::data_container::compression_header::PreservationMap;
+    ///
+    /// let preservation_map = PreservationMap::builder()
+    ///     .set_reference_required(false)
+    ///     .build();
+    ///
+    /// assert!(!preservation_map.is_reference_required());
+    /// ```
+    pub fn set_reference_required(mut self, reference_required: bool) -> Self {
+        self.reference_required = reference_required;
+        self
+    }
+
+    /// Sets the substitution matrix (`SM`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::data_container::compression_header::{
+    ///     preservation_map::SubstitutionMatrix, PreservationMap,
+    /// };
+    ///
+    /// let substitution_matrix = SubstitutionMatrix::default();
+    ///
+    /// let preservation_map = PreservationMap::builder()
+    ///     .set_substitution_matrix(substitution_matrix.clone())
+    ///     .build();
+    ///
+    /// assert_eq!(preservation_map.substitution_matrix(), &substitution_matrix);
+    /// ```
+    pub fn set_substitution_matrix(mut self, substitution_matrix: SubstitutionMatrix) -> Self {
+        self.substitution_matrix = Some(substitution_matrix);
+        self
+    }
+
+    /// Sets the tag IDs dictionary (`TD`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::data_container::compression_header::{
+    ///     preservation_map::{tag_ids_dictionary::Key, TagIdsDictionary},
+    ///     PreservationMap,
+    /// };
+    /// use noodles_sam::record::data::field::{value::Type, Tag};
+    ///
+    /// let tag_ids_dictionary = TagIdsDictionary::from(vec![
+    ///     vec![Key::new(Tag::AlignmentHitCount, Type::UInt8)],
+    /// ]);
+    ///
+    /// let preservation_map = PreservationMap::builder()
+    ///     .set_tag_ids_dictionary(tag_ids_dictionary.clone())
+    ///     .build();
+    ///
+    /// assert_eq!(preservation_map.tag_ids_dictionary(), &tag_ids_dictionary);
+    /// ```
+    pub fn set_tag_ids_dictionary(mut self, tag_ids_dictionary: TagIdsDictionary) -> Self {
+        self.tag_ids_dictionary = Some(tag_ids_dictionary);
+        self
+    }
+
+    pub(crate) fn apply_options(&mut self, options: &Options) {
         self.read_names_included = options.preserve_read_names;
         self.ap_data_series_delta = options.encode_alignment_start_positions_as_deltas;
+        self.reference_required = !options.embed_reference_sequences;
+        self.substitution_matrix = options.substitution_matrix.clone();
     }
 
-    pub fn update(&mut self, record: &Record) {
+    pub(crate) fn update(&mut self, record: &Record) {
         self.substitution_matrix_builder.update(record);
         self.tag_ids_dictionary_builder.update(record);
     }
 
-    pub(crate) fn build(self) -> PreservationMap {
-        let substitution_matrix = self.substitution_matrix_builder.build();
-        let tag_ids_dictionary = self.tag_ids_dictionary_builder.build();
+    /// Builds a preservation map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::data_container::compression_header::PreservationMap;
+    /// let preservation_map = PreservationMap::builder().build();
+    /// ```
+    pub fn build(self) -> PreservationMap {
+        let substitution_matrix = self
+            .substitution_matrix
+            .unwrap_or_else(|| self.substitution_matrix_builder.build());
+
+        let tag_ids_dictionary = self
+            .tag_ids_dictionary
+            .unwrap_or_else(|| self.tag_ids_dictionary_builder.build());
 
         PreservationMap::new(
             self.read_names_included,
@@ -43,6 +168,8 @@ impl Default for Builder {
             read_names_included: true,
             ap_data_series_delta: true,
             reference_required: true,
+            substitution_matrix: None,
+            tag_ids_dictionary: None,
             substitution_matrix_builder: substitution_matrix::Builder::default(),
             tag_ids_dictionary_builder: tag_ids_dictionary::Builder::default(),
         }
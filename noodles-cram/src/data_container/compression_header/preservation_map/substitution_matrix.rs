@@ -1,7 +1,9 @@
+//! CRAM data container compression header preservation map substitution matrix.
+
 mod builder;
 mod histogram;
 
-pub use self::builder::Builder;
+pub(crate) use self::builder::Builder;
 
 use std::{cmp, error, fmt};
 
@@ -10,28 +12,47 @@ use crate::record::feature::substitution::Base;
 
 type Substitutions = [[Base; 4]; 5];
 
+/// A CRAM data container compression header preservation map substitution matrix (`SM`).
+///
+/// A substitution matrix maps a reference base and a 2-bit substitution code to a read base. Each
+/// reference base has its own ordering of the four other bases.
+///
+/// The default substitution matrix orders the read bases by the fixed order ACGTN.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct SubstitutionMatrix {
     substitutions: Substitutions,
 }
 
 impl SubstitutionMatrix {
-    pub fn builder() -> Builder {
-        Builder::default()
-    }
-
+    /// Returns the read base for the given reference base and substitution code.
+    ///
+    /// Only the lower 2 bits of the substitution code are used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::{
+    ///     data_container::compression_header::preservation_map::SubstitutionMatrix,
+    ///     record::feature::substitution::Base,
+    /// };
+    ///
+    /// let substitution_matrix = SubstitutionMatrix::default();
+    /// assert_eq!(substitution_matrix.get(Base::A, 0), Base::C);
+    /// assert_eq!(substitution_matrix.get(Base::A, 3), Base::N);
+    ///
+    /// // A: [T, G, C, N], C: [A, G, T, N], ...
+    /// let substitution_matrix = SubstitutionMatrix::try_from([0x93, 0x1b, 0x1b, 0x1b, 0x1b])?;
+    /// assert_eq!(substitution_matrix.get(Base::A, 0), Base::T);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
     pub fn get(&self, reference_base: Base, substitution_code: u8) -> Base {
-        self.substitutions[reference_base as usize][substitution_code as usize]
+        self.substitutions[reference_base as usize][usize::from(substitution_code & 0x03)]
     }
 
-    pub fn find_code(&self, reference_base: Base, read_base: Base) -> u8 {
-        for code in [0b00, 0b01, 0b10, 0b11] {
-            if self.get(reference_base, code) == read_base {
-                return code;
-            }
-        }
-
-        unreachable!();
+    pub(crate) fn find_code(&self, reference_base: Base, read_base: Base) -> Option<u8> {
+        [0b00, 0b01, 0b10, 0b11]
+            .into_iter()
+            .find(|&code| self.get(reference_base, code) == read_base)
     }
 }
 
@@ -77,6 +98,7 @@ impl From<Histogram> for SubstitutionMatrix {
     }
 }
 
+/// An error returned when a raw substitution matrix fails to convert.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct TryFromByteArrayError([u8; 5]);
 
@@ -92,6 +114,11 @@ impl TryFrom<[u8; 5]> for SubstitutionMatrix {
     type Error = TryFromByteArrayError;
 
     fn try_from(b: [u8; 5]) -> Result<Self, Self::Error> {
+        // Each row must assign the four codes to the four read bases.
+        if !b.iter().all(|&codes| is_permutation(codes)) {
+            return Err(TryFromByteArrayError(b));
+        }
+
         let mut matrix = Self::default();
 
         set_substitutions(
@@ -133,6 +160,17 @@ impl TryFrom<[u8; 5]> for SubstitutionMatrix {
     }
 }
 
+fn is_permutation(codes: u8) -> bool {
+    let mut seen = 0u8;
+
+    for i in 0..4 {
+        let code = (codes >> (2 * i)) & 0x03;
+        seen |= 1 << code;
+    }
+
+    seen == 0x0f
+}
+
 fn set_substitutions(
     reference_base: Base,
     codes: u8,
@@ -190,10 +228,11 @@ mod tests {
             ],
         };
 
-        assert_eq!(matrix.find_code(Base::A, Base::T), 0b00);
-        assert_eq!(matrix.find_code(Base::C, Base::G), 0b01);
-        assert_eq!(matrix.find_code(Base::G, Base::C), 0b10);
-        assert_eq!(matrix.find_code(Base::T, Base::C), 0b11);
+        assert_eq!(matrix.find_code(Base::A, Base::T), Some(0b00));
+        assert_eq!(matrix.find_code(Base::C, Base::G), Some(0b01));
+        assert_eq!(matrix.find_code(Base::G, Base::C), Some(0b10));
+        assert_eq!(matrix.find_code(Base::T, Base::C), Some(0b11));
+        assert_eq!(matrix.find_code(Base::A, Base::A), None);
     }
 
     #[test]
@@ -212,6 +251,12 @@ mod tests {
 
         assert_eq!(actual, expected);
 
+        let codes = [0x93, 0x1b, 0x00, 0xb1, 0xc6];
+        assert_eq!(
+            SubstitutionMatrix::try_from(codes),
+            Err(TryFromByteArrayError(codes))
+        );
+
         Ok(())
     }
 
@@ -8,8 +8,12 @@ pub use self::key::Key;
 
 use std::ops::Deref;
 
+/// A CRAM data container compression header preservation map tag IDs dictionary.
+///
+/// Each line of the dictionary is the list of tag keys for a group of records. Records reference
+/// a line by its index.
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub(crate) struct TagIdsDictionary(Vec<Vec<Key>>);
+pub struct TagIdsDictionary(Vec<Vec<Key>>);
 
 impl Deref for TagIdsDictionary {
     type Target = [Vec<Key>];
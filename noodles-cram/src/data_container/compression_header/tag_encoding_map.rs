@@ -1,3 +1,5 @@
+//! CRAM data container compression header tag encoding map.
+
 mod builder;
 
 pub use self::builder::Builder;
@@ -7,9 +9,26 @@ use std::{collections::HashMap, ops::Deref};
 use super::{encoding::codec::ByteArray, Encoding};
 use crate::container::block;
 
+/// A CRAM data container compression header tag encoding map.
+///
+/// The map is keyed by the block content ID of a tag IDs dictionary key.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct TagEncodingMap(HashMap<block::ContentId, Encoding<ByteArray>>);
 
+impl TagEncodingMap {
+    /// Returns a builder to create a tag encoding map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::data_container::compression_header::TagEncodingMap;
+    /// let builder = TagEncodingMap::builder();
+    /// ```
+    pub fn builder() -> Builder {
+        Builder::default()
+    }
+}
+
 impl Deref for TagEncodingMap {
     type Target = HashMap<block::ContentId, Encoding<ByteArray>>;
 
@@ -1,5 +1,7 @@
 use std::collections::{HashMap, HashSet};
 
+use noodles_sam::record::data::field::value::Type;
+
 use super::TagEncodingMap;
 
 use crate::{
@@ -12,39 +14,100 @@ use crate::{
     Record,
 };
 
+/// A CRAM data container compression header tag encoding map builder.
+///
+/// Tags seen in records given to the builder are assigned a default encoding. These are
+/// overridden by encodings that are set explicitly.
 #[derive(Debug, Default)]
 pub struct Builder {
     keys: HashSet<Key>,
+    encodings: HashMap<block::ContentId, Encoding<ByteArray>>,
 }
 
 impl Builder {
-    pub fn update(&mut self, record: &Record) {
+    /// Sets the encoding of a tag.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::{
+    ///     container::block,
+    ///     data_container::compression_header::{
+    ///         encoding::codec::{Byte, ByteArray, Integer},
+    ///         preservation_map::tag_ids_dictionary::Key,
+    ///         Encoding, TagEncodingMap,
+    ///     },
+    /// };
+    /// use noodles_sam::record::data::field::{value::Type, Tag};
+    ///
+    /// let key = Key::new(Tag::Comment, Type::String);
+    /// let id = block::ContentId::from(key);
+    ///
+    /// let encoding = Encoding::new(ByteArray::ByteArrayStop(0x00, id));
+    ///
+    /// let tag_encoding_map = TagEncodingMap::builder()
+    ///     .set_encoding(key, encoding.clone())
+    ///     .build();
+    ///
+    /// assert_eq!(tag_encoding_map.get(&id), Some(&encoding));
+    /// ```
+    pub fn set_encoding(mut self, key: Key, encoding: Encoding<ByteArray>) -> Self {
+        self.encodings.insert(block::ContentId::from(key), encoding);
+        self
+    }
+
+    pub(crate) fn update(&mut self, record: &Record) {
         for (tag, value) in record.tags().iter() {
             let key = Key::new(tag, value.ty());
             self.keys.insert(key);
         }
     }
 
+    /// Builds a tag encoding map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::data_container::compression_header::TagEncodingMap;
+    /// let tag_encoding_map = TagEncodingMap::builder().build();
+    /// assert!(tag_encoding_map.is_empty());
+    /// ```
     pub fn build(self) -> TagEncodingMap {
         let mut map = HashMap::new();
 
         for key in self.keys {
             let id = block::ContentId::from(key);
 
-            let len_encoding = Encoding::new(Integer::External(id));
+            // Values with a fixed size have a constant length, which is not written.
+            let len_encoding = match value_size(key.ty()) {
+                Some(len) => Encoding::new(Integer::Huffman(vec![len], vec![0])),
+                None => Encoding::new(Integer::External(id)),
+            };
+
             let value_encoding = Encoding::new(Byte::External(id));
             let encoding = Encoding::new(ByteArray::ByteArrayLen(len_encoding, value_encoding));
 
             map.insert(id, encoding);
         }
 
+        map.extend(self.encodings);
+
         TagEncodingMap::from(map)
     }
 }
 
+fn value_size(ty: Type) -> Option<i32> {
+    match ty {
+        Type::Character | Type::Int8 | Type::UInt8 => Some(1),
+        Type::Int16 | Type::UInt16 => Some(2),
+        Type::Int32 | Type::UInt32 | Type::Float => Some(4),
+        Type::String | Type::Hex | Type::Array => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use noodles_sam::record::data::field::{value::Type, Tag, Value};
+    use noodles_sam::record::data::field::{Tag, Value};
 
     use super::*;
 
@@ -75,7 +138,7 @@ mod tests {
             (
                 nh,
                 Encoding::new(ByteArray::ByteArrayLen(
-                    Encoding::new(Integer::External(nh)),
+                    Encoding::new(Integer::Huffman(vec![1], vec![0])),
                     Encoding::new(Byte::External(nh)),
                 )),
             ),
@@ -92,4 +155,19 @@ mod tests {
 
         assert_eq!(*actual, expected);
     }
+
+    #[test]
+    fn test_value_size() {
+        assert_eq!(value_size(Type::Character), Some(1));
+        assert_eq!(value_size(Type::Int8), Some(1));
+        assert_eq!(value_size(Type::UInt8), Some(1));
+        assert_eq!(value_size(Type::Int16), Some(2));
+        assert_eq!(value_size(Type::UInt16), Some(2));
+        assert_eq!(value_size(Type::Int32), Some(4));
+        assert_eq!(value_size(Type::UInt32), Some(4));
+        assert_eq!(value_size(Type::Float), Some(4));
+        assert_eq!(value_size(Type::String), None);
+        assert_eq!(value_size(Type::Hex), None);
+        assert_eq!(value_size(Type::Array), None);
+    }
 }
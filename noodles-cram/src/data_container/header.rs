@@ -4,6 +4,10 @@ pub use self::builder::Builder;
 
 use super::ReferenceSequenceContext;
 
+/// A CRAM data container header.
+///
+/// The header describes the container, e.g., the region its records map to and where its slices
+/// start. It can be read without reading any of the container blocks.
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
 pub struct Header {
     length: usize,
@@ -17,34 +21,48 @@ pub struct Header {
 
 #[allow(clippy::len_without_is_empty)]
 impl Header {
-    pub fn builder() -> Builder {
+    pub(crate) fn builder() -> Builder {
         Builder::default()
     }
 
+    /// Returns the length of the container data, i.e., the compression header and slices, in
+    /// bytes.
     pub fn len(&self) -> usize {
         self.length
     }
 
+    /// Returns the reference sequence context.
+    ///
+    /// This is the reference sequence and alignment span of the records in the container.
     pub fn reference_sequence_context(&self) -> ReferenceSequenceContext {
         self.reference_sequence_context
     }
 
+    /// Returns the number of records in the container.
     pub fn record_count(&self) -> i32 {
         self.record_count
     }
 
+    /// Returns the record counter.
+    ///
+    /// This is the 0-based index of the first record in the container.
     pub fn record_counter(&self) -> u64 {
         self.record_counter
     }
 
+    /// Returns the number of read bases in the container.
     pub fn base_count(&self) -> u64 {
         self.base_count
     }
 
+    /// Returns the number of blocks in the container.
     pub fn block_count(&self) -> usize {
         self.block_count
     }
 
+    /// Returns the landmarks.
+    ///
+    /// These are the positions of the slices, relative to the end of the container header.
     pub fn landmarks(&self) -> &[usize] {
         &self.landmarks
     }
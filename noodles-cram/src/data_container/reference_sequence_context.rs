@@ -1,7 +1,10 @@
+//! CRAM data container reference sequence context.
+
 use std::cmp;
 
 use noodles_core::Position;
 
+/// A reference sequence context of records that map to a single reference sequence.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub struct Context {
     reference_sequence_id: usize,
@@ -22,32 +25,43 @@ impl Context {
         }
     }
 
+    /// Returns the reference sequence ID.
     pub fn reference_sequence_id(&self) -> usize {
         self.reference_sequence_id
     }
 
+    /// Returns the alignment start.
     pub fn alignment_start(&self) -> Position {
         self.alignment_start
     }
 
+    /// Returns the alignment span.
     pub fn alignment_span(&self) -> usize {
         usize::from(self.alignment_end) - usize::from(self.alignment_start) + 1
     }
 
+    /// Returns the alignment end.
     pub fn alignment_end(&self) -> Position {
         self.alignment_end
     }
 }
 
+/// A CRAM data container reference sequence context.
+///
+/// This describes which reference sequence the records of a container or slice map to.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum ReferenceSequenceContext {
+    /// All records map to the same reference sequence.
     Some(Context),
+    /// No records are mapped.
     None,
+    /// Records map to more than one reference sequence or are a mix of mapped and unmapped
+    /// records.
     Many,
 }
 
 impl ReferenceSequenceContext {
-    pub fn some(
+    pub(crate) fn some(
         reference_sequence_id: usize,
         alignment_start: Position,
         alignment_end: Position,
@@ -59,11 +73,20 @@ impl ReferenceSequenceContext {
         ))
     }
 
+    /// Returns whether records map to more than one reference sequence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::data_container::ReferenceSequenceContext;
+    /// assert!(ReferenceSequenceContext::Many.is_many());
+    /// assert!(!ReferenceSequenceContext::None.is_many());
+    /// ```
     pub fn is_many(&self) -> bool {
         matches!(self, Self::Many)
     }
 
-    pub fn update(
+    pub(crate) fn update(
         &mut self,
         reference_sequence_id: Option<usize>,
         alignment_start: Option<Position>,
@@ -1,17 +1,27 @@
+//! CRAM data container slice.
+
 pub(crate) mod builder;
-pub(crate) mod header;
+mod header;
 
-pub use self::{builder::Builder, header::Header};
+pub(crate) use self::builder::Builder;
+pub use self::header::Header;
 
-use std::io;
+use std::{collections::HashSet, io};
 
 use noodles_core::Position;
 use noodles_fasta as fasta;
 use noodles_sam as sam;
 
-use super::{CompressionHeader, ReferenceSequenceContext};
+use super::{
+    compression_header::{
+        encoding::codec::{Byte, ByteArray, Integer},
+        Encoding,
+    },
+    CompressionHeader, ReferenceSequenceContext,
+};
 use crate::{
-    container::Block,
+    container::{block, Block},
+    reader::ReadNameTemplate,
     record::resolve::{resolve_bases, resolve_quality_scores},
     BitReader, Record,
 };
@@ -40,15 +50,18 @@ impl Slice {
         }
     }
 
-    pub(crate) fn header(&self) -> &Header {
+    /// Returns the slice header.
+    pub fn header(&self) -> &Header {
         &self.header
     }
 
-    pub(crate) fn core_data_block(&self) -> &Block {
+    /// Returns the core data block.
+    pub fn core_data_block(&self) -> &Block {
         &self.core_data_block
     }
 
-    pub(crate) fn external_blocks(&self) -> &[Block] {
+    /// Returns the external blocks.
+    pub fn external_blocks(&self) -> &[Block] {
         &self.external_blocks
     }
 
@@ -110,9 +123,85 @@ impl Slice {
         Ok(records)
     }
 
+    /// Reads the records of an unmapped slice, decoding only the data series needed for
+    /// sequence records, i.e., read names, bases, and quality scores.
+    ///
+    /// External blocks of data series that are not needed are neither decompressed nor read.
+    /// Read names are resolved, and, if enabled, the content MD5 checksums are verified.
+    ///
+    /// This returns `None` if the slice has mapped records or if a skipped data series cannot be
+    /// skipped, i.e., it is read from the core data block or shares an external block with a
+    /// decoded data series. The records must then be read and resolved in full.
+    pub(crate) fn unmapped_sequence_records(
+        &self,
+        compression_header: &CompressionHeader,
+        verify_md5_checksums: bool,
+        read_name_template: &ReadNameTemplate,
+    ) -> io::Result<Option<Vec<Record>>> {
+        use crate::reader::record::ExternalDataReaders;
+
+        if !matches!(
+            self.header.reference_sequence_context(),
+            ReferenceSequenceContext::None
+        ) {
+            return Ok(None);
+        }
+
+        let external_block_content_ids =
+            match get_unmapped_sequence_record_block_content_ids(compression_header) {
+                Some(ids) => ids,
+                None => return Ok(None),
+            };
+
+        let core_data_reader = self
+            .core_data_block
+            .decompressed_data()
+            .map(BitReader::new)?;
+
+        let mut external_data_readers = ExternalDataReaders::new();
+
+        for block in self.external_blocks() {
+            if external_block_content_ids.contains(&block.content_id()) {
+                let reader = block.decompressed_data()?;
+                external_data_readers.insert(block.content_id(), reader);
+            }
+        }
+
+        let mut record_reader = crate::reader::record::Reader::new(
+            compression_header,
+            core_data_reader,
+            external_data_readers,
+            self.header.reference_sequence_context(),
+        );
+
+        let record_count = self.header().record_count();
+        let mut records = Vec::with_capacity(record_count);
+
+        let start_id = self.header().record_counter();
+        let end_id = start_id + (record_count as u64);
+
+        for id in start_id..end_id {
+            let mut record = record_reader.read_unmapped_sequence_record()?;
+            record.id = id;
+            records.push(record);
+        }
+
+        resolve_read_names(&mut records, read_name_template)?;
+
+        if verify_md5_checksums {
+            self.verify_content_md5_checksums(&records)?;
+        }
+
+        Ok(Some(records))
+    }
+
     /// Resolves records.
     ///
-    /// This resolves mates, read names, bases, and quality scores.
+    /// This resolves mates, read names, bases, and quality scores. Missing read names are
+    /// generated using the default [`ReadNameTemplate`].
+    ///
+    /// This also verifies the slice reference sequence MD5 checksum and, if present in the slice
+    /// header, the MD5 checksums of the resolved bases (`BD`) and quality scores (`SD`).
     pub fn resolve_records(
         &self,
         reference_sequence_repository: &fasta::Repository,
@@ -120,17 +209,41 @@ impl Slice {
         compression_header: &CompressionHeader,
         records: &mut [Record],
     ) -> io::Result<()> {
-        resolve_mates(records)?;
+        self.resolve_records_with_verification(
+            reference_sequence_repository,
+            header,
+            compression_header,
+            records,
+            true,
+            &ReadNameTemplate::default(),
+        )
+    }
+
+    pub(crate) fn resolve_records_with_verification(
+        &self,
+        reference_sequence_repository: &fasta::Repository,
+        header: &sam::Header,
+        compression_header: &CompressionHeader,
+        records: &mut [Record],
+        verify_md5_checksums: bool,
+        read_name_template: &ReadNameTemplate,
+    ) -> io::Result<()> {
+        resolve_mates(records, read_name_template)?;
 
         self.resolve_bases(
             reference_sequence_repository,
             header,
             compression_header,
             records,
+            verify_md5_checksums,
         )?;
 
         self.resolve_quality_scores(records);
 
+        if verify_md5_checksums {
+            self.verify_content_md5_checksums(records)?;
+        }
+
         Ok(())
     }
 
@@ -140,6 +253,7 @@ impl Slice {
         header: &sam::Header,
         compression_header: &CompressionHeader,
         records: &mut [Record],
+        verify_md5_checksums: bool,
     ) -> io::Result<()> {
         let embedded_reference_sequence = if let Some(block_content_id) =
             self.header().embedded_reference_bases_block_content_id()
@@ -148,7 +262,12 @@ impl Slice {
                 .external_blocks()
                 .iter()
                 .find(|block| block.content_id() == block_content_id)
-                .expect("invalid block content ID");
+                .ok_or_else(|| {
+                    io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        "missing embedded reference sequence block",
+                    )
+                })?;
 
             let data = block.decompressed_data()?;
             let sequence = fasta::record::Sequence::from(data);
@@ -158,43 +277,13 @@ impl Slice {
             None
         };
 
-        // § 11 "Reference sequences" (2021-11-15): "All CRAM reader implementations are
-        // expected to check for reference MD5 checksums and report any missing or
-        // mismatching entries."
-        if compression_header
-            .preservation_map()
-            .is_reference_required()
-        {
-            if let ReferenceSequenceContext::Some(context) =
-                self.header().reference_sequence_context()
-            {
-                let reference_sequence_name = header
-                    .reference_sequences()
-                    .get_index(context.reference_sequence_id())
-                    .map(|(name, _)| name)
-                    .expect("invalid slice reference sequence ID");
-
-                let sequence = reference_sequence_repository
-                    .get(reference_sequence_name)
-                    .transpose()?
-                    .expect("invalid slice reference sequence name");
-
-                let start = context.alignment_start();
-                let end = context.alignment_end();
-
-                let actual_md5 =
-                    builder::calculate_normalized_sequence_digest(&sequence[start..=end]);
-                let expected_md5 = self.header().reference_md5();
-
-                if actual_md5 != expected_md5 {
-                    return Err(io::Error::new(
-                        io::ErrorKind::InvalidData,
-                        format!(
-                            "reference sequence checksum mismatch: expected {expected_md5:?}, got {actual_md5:?}"
-                        ),
-                    ));
-                }
-            }
+        if verify_md5_checksums {
+            self.verify_reference_md5_checksum(
+                reference_sequence_repository,
+                header,
+                compression_header,
+                embedded_reference_sequence.as_ref(),
+            )?;
         }
 
         for record in records {
@@ -203,37 +292,49 @@ impl Slice {
                 continue;
             }
 
-            let mut alignment_start = record.alignment_start.expect("invalid alignment start");
-
-            let reference_sequence = if compression_header
-                .preservation_map()
-                .is_reference_required()
-            {
-                let reference_sequence_name = record
-                    .reference_sequence(header.reference_sequences())
-                    .transpose()?
-                    .map(|(name, _)| name)
-                    .expect("invalid reference sequence ID");
-
-                let sequence = reference_sequence_repository
-                    .get(reference_sequence_name)
-                    .transpose()?
-                    .expect("invalid reference sequence name");
+            let mut alignment_start = record.alignment_start.ok_or_else(|| {
+                io::Error::new(io::ErrorKind::InvalidData, "missing alignment start")
+            })?;
 
-                Some(sequence)
-            } else if let Some(ref sequence) = embedded_reference_sequence {
+            let reference_sequence = if let Some(ref sequence) = embedded_reference_sequence {
                 let offset = match self.header().reference_sequence_context() {
                     ReferenceSequenceContext::Some(context) => {
                         usize::from(context.alignment_start())
                     }
-                    _ => panic!("invalid slice alignment start"),
+                    _ => {
+                        return Err(io::Error::new(
+                            io::ErrorKind::InvalidData,
+                            "invalid slice alignment start",
+                        ))
+                    }
                 };
 
-                let start = usize::from(alignment_start) - offset + 1;
+                let start = usize::from(alignment_start)
+                    .checked_sub(offset)
+                    .map(|n| n + 1)
+                    .ok_or_else(|| {
+                        io::Error::new(io::ErrorKind::InvalidData, "invalid alignment start")
+                    })?;
                 alignment_start = Position::try_from(start)
                     .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
 
                 Some(sequence.clone())
+            } else if compression_header
+                .preservation_map()
+                .is_reference_required()
+            {
+                let reference_sequence_name = record
+                    .reference_sequence(header.reference_sequences())
+                    .transpose()?
+                    .map(|(name, _)| name)
+                    .ok_or_else(|| {
+                        io::Error::new(io::ErrorKind::InvalidData, "missing reference sequence ID")
+                    })?;
+
+                let sequence =
+                    get_reference_sequence(reference_sequence_repository, reference_sequence_name)?;
+
+                Some(sequence)
             } else {
                 None
             };
@@ -266,9 +367,325 @@ impl Slice {
             }
         }
     }
+
+    fn verify_reference_md5_checksum(
+        &self,
+        reference_sequence_repository: &fasta::Repository,
+        header: &sam::Header,
+        compression_header: &CompressionHeader,
+        embedded_reference_sequence: Option<&fasta::record::Sequence>,
+    ) -> io::Result<()> {
+        // § 11 "Reference sequences" (2021-11-15): "All CRAM reader implementations are
+        // expected to check for reference MD5 checksums and report any missing or
+        // mismatching entries."
+        if let Some(sequence) = embedded_reference_sequence {
+            let actual_md5 = builder::calculate_normalized_sequence_digest(sequence.as_ref());
+            let expected_md5 = self.header().reference_md5();
+
+            if expected_md5 != [0; 16] && actual_md5 != expected_md5 {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!(
+                        "embedded reference sequence checksum mismatch: expected {expected_md5:?}, got {actual_md5:?}"
+                    ),
+                ));
+            }
+        } else if compression_header
+            .preservation_map()
+            .is_reference_required()
+        {
+            if let ReferenceSequenceContext::Some(context) =
+                self.header().reference_sequence_context()
+            {
+                let reference_sequence_name = header
+                    .reference_sequences()
+                    .get_index(context.reference_sequence_id())
+                    .map(|(name, _)| name)
+                    .ok_or_else(|| {
+                        io::Error::new(
+                            io::ErrorKind::InvalidData,
+                            "invalid slice reference sequence ID",
+                        )
+                    })?;
+
+                let sequence =
+                    get_reference_sequence(reference_sequence_repository, reference_sequence_name)?;
+
+                let start = context.alignment_start();
+                let end = context.alignment_end();
+
+                let actual_md5 =
+                    builder::calculate_normalized_sequence_digest(&sequence[start..=end]);
+                let expected_md5 = self.header().reference_md5();
+
+                if actual_md5 != expected_md5 {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!(
+                            "reference sequence checksum mismatch: expected {expected_md5:?}, got {actual_md5:?}"
+                        ),
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn verify_content_md5_checksums(&self, records: &[Record]) -> io::Result<()> {
+        let checksums = get_content_md5_checksums(self.header.optional_tags())?;
+
+        if let Some(expected_md5) = checksums.bases {
+            let actual_md5 = calculate_bases_digest(records);
+
+            if actual_md5 != expected_md5 {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!(
+                        "slice bases checksum mismatch: expected {expected_md5:?}, got {actual_md5:?}"
+                    ),
+                ));
+            }
+        }
+
+        if let Some(expected_md5) = checksums.quality_scores {
+            let actual_md5 = calculate_quality_scores_digest(records);
+
+            if actual_md5 != expected_md5 {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!(
+                        "slice quality scores checksum mismatch: expected {expected_md5:?}, got {actual_md5:?}"
+                    ),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// § 8.5 "Slice header block" (2022-04-12): Reserved optional tags for the MD5 checksums of the
+// bases (`BD`) and quality scores (`SD`) of the records in the slice. Other content digests, e.g.,
+// SHA-512 (`B5`, `S5`) and CRC32 (`B1`, `S1`), are not checked.
+#[derive(Debug, Default)]
+struct ContentMd5Checksums {
+    bases: Option<[u8; 16]>,
+    quality_scores: Option<[u8; 16]>,
 }
 
-fn resolve_mates(records: &mut [Record]) -> io::Result<()> {
+fn get_content_md5_checksums(mut src: &[u8]) -> io::Result<ContentMd5Checksums> {
+    use noodles_bam::reader::record::data::field::get_value;
+    use sam::record::data::field::{value::Type, Value};
+
+    let mut checksums = ContentMd5Checksums::default();
+
+    while !src.is_empty() {
+        if src.len() < 3 {
+            return Err(io::Error::from(io::ErrorKind::UnexpectedEof));
+        }
+
+        let tag = [src[0], src[1]];
+        let ty =
+            Type::try_from(src[2]).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        src = &src[3..];
+
+        let value = get_value(&mut src, ty)?;
+
+        let dst = match &tag {
+            b"BD" => &mut checksums.bases,
+            b"SD" => &mut checksums.quality_scores,
+            _ => continue,
+        };
+
+        let md5 = match value {
+            Value::UInt8Array(buf) => <[u8; 16]>::try_from(buf)
+                .map_err(|_| io::Error::new(io::ErrorKind::InvalidData, "invalid MD5 checksum"))?,
+            _ => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "invalid MD5 checksum type",
+                ))
+            }
+        };
+
+        *dst = Some(md5);
+    }
+
+    Ok(checksums)
+}
+
+// The bases of all records are concatenated, in order and without separators.
+fn calculate_bases_digest(records: &[Record]) -> [u8; 16] {
+    use md5::{Digest, Md5};
+
+    let mut hasher = Md5::new();
+
+    for record in records {
+        let bases: Vec<_> = record
+            .bases()
+            .as_ref()
+            .iter()
+            .map(|&b| u8::from(b))
+            .collect();
+        hasher.update(&bases);
+    }
+
+    hasher.finalize().into()
+}
+
+// The (raw) quality scores of all records are concatenated, in order and without separators.
+fn calculate_quality_scores_digest(records: &[Record]) -> [u8; 16] {
+    use md5::{Digest, Md5};
+
+    let mut hasher = Md5::new();
+
+    for record in records {
+        let scores: Vec<_> = record
+            .quality_scores()
+            .as_ref()
+            .iter()
+            .map(|&score| u8::from(score))
+            .collect();
+
+        hasher.update(&scores);
+    }
+
+    hasher.finalize().into()
+}
+
+fn get_reference_sequence(
+    reference_sequence_repository: &fasta::Repository,
+    reference_sequence_name: &str,
+) -> io::Result<fasta::record::Sequence> {
+    reference_sequence_repository
+        .get(reference_sequence_name)
+        .transpose()?
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("missing reference sequence: {reference_sequence_name}"),
+            )
+        })
+}
+
+// Returns the external blocks read when decoding unmapped sequence records or `None` if the other
+// data series cannot be skipped.
+fn get_unmapped_sequence_record_block_content_ids(
+    compression_header: &CompressionHeader,
+) -> Option<HashSet<block::ContentId>> {
+    let data_series_encoding_map = compression_header.data_series_encoding_map();
+
+    let mut decoded = EncodingBlocks::default();
+    decoded.add_integer(Some(data_series_encoding_map.bam_bit_flags_encoding()));
+    decoded.add_integer(Some(data_series_encoding_map.cram_bit_flags_encoding()));
+    decoded.add_integer(Some(data_series_encoding_map.read_lengths_encoding()));
+    decoded.add_byte_array(data_series_encoding_map.read_names_encoding());
+    decoded.add_integer(data_series_encoding_map.distance_to_next_fragment_encoding());
+    decoded.add_byte(data_series_encoding_map.bases_encoding());
+    decoded.add_byte(data_series_encoding_map.quality_scores_encoding());
+
+    let mut skipped = EncodingBlocks::default();
+    skipped.add_integer(Some(data_series_encoding_map.in_seq_positions_encoding()));
+    skipped.add_integer(Some(data_series_encoding_map.read_groups_encoding()));
+    skipped.add_integer(data_series_encoding_map.next_mate_bit_flags_encoding());
+    skipped.add_integer(data_series_encoding_map.next_fragment_reference_sequence_id_encoding());
+    skipped.add_integer(data_series_encoding_map.next_mate_alignment_start_encoding());
+    skipped.add_integer(data_series_encoding_map.template_size_encoding());
+    skipped.add_integer(Some(data_series_encoding_map.tag_ids_encoding()));
+
+    for encoding in compression_header.tag_encoding_map().values() {
+        skipped.add_byte_array(Some(encoding));
+    }
+
+    if skipped.uses_core_data_block
+        || !skipped
+            .external_block_content_ids
+            .is_disjoint(&decoded.external_block_content_ids)
+    {
+        None
+    } else {
+        Some(decoded.external_block_content_ids)
+    }
+}
+
+// The blocks read by a set of encodings.
+#[derive(Default)]
+struct EncodingBlocks {
+    uses_core_data_block: bool,
+    external_block_content_ids: HashSet<block::ContentId>,
+}
+
+impl EncodingBlocks {
+    fn add_integer(&mut self, encoding: Option<&Encoding<Integer>>) {
+        match encoding.map(|e| e.get()) {
+            None => {}
+            Some(Integer::External(block_content_id)) => {
+                self.external_block_content_ids.insert(*block_content_id);
+            }
+            // A Huffman code with a single symbol has a length of 0 and is not read.
+            Some(Integer::Huffman(alphabet, _)) if alphabet.len() == 1 => {}
+            Some(_) => self.uses_core_data_block = true,
+        }
+    }
+
+    fn add_byte(&mut self, encoding: Option<&Encoding<Byte>>) {
+        match encoding.map(|e| e.get()) {
+            None => {}
+            Some(Byte::External(block_content_id)) => {
+                self.external_block_content_ids.insert(*block_content_id);
+            }
+            Some(Byte::Huffman(alphabet, _)) if alphabet.len() == 1 => {}
+            Some(_) => self.uses_core_data_block = true,
+        }
+    }
+
+    fn add_byte_array(&mut self, encoding: Option<&Encoding<ByteArray>>) {
+        match encoding.map(|e| e.get()) {
+            None => {}
+            Some(ByteArray::ByteArrayLen(len_encoding, value_encoding)) => {
+                self.add_integer(Some(len_encoding));
+                self.add_byte(Some(value_encoding));
+            }
+            Some(ByteArray::ByteArrayStop(_, block_content_id)) => {
+                self.external_block_content_ids.insert(*block_content_id);
+            }
+        }
+    }
+}
+
+fn resolve_read_names(
+    records: &mut [Record],
+    read_name_template: &ReadNameTemplate,
+) -> io::Result<()> {
+    for i in 0..records.len() {
+        if records[i].read_name().is_none() {
+            records[i].read_name = read_name_template.generate(records[i].id()).map(Some)?;
+        }
+
+        // Mates downstream in the slice are given the read name of this record.
+        if let Some(distance_to_next_fragment) = records[i].distance_to_next_fragment() {
+            let mate_index = i + distance_to_next_fragment + 1;
+
+            let read_name = records[i].read_name().cloned();
+
+            let mate = records.get_mut(mate_index).ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "invalid distance to next fragment",
+                )
+            })?;
+
+            if mate.read_name().is_none() {
+                mate.read_name = read_name;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn resolve_mates(records: &mut [Record], read_name_template: &ReadNameTemplate) -> io::Result<()> {
     let mut mate_indices = vec![None; records.len()];
 
     for (i, record) in records.iter().enumerate() {
@@ -280,18 +697,12 @@ fn resolve_mates(records: &mut [Record]) -> io::Result<()> {
 
     let mut i = 0;
 
-    while i < records.len() - 1 {
+    while i < records.len() {
         let record = &mut records[i];
 
+        // Mates downstream in the slice are given the read name of this record.
         if record.read_name().is_none() {
-            let read_name = record
-                .id()
-                .to_string()
-                .parse()
-                .map(Some)
-                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
-
-            record.read_name = read_name;
+            record.read_name = read_name_template.generate(record.id()).map(Some)?;
         }
 
         if mate_indices[i].is_none() {
@@ -416,9 +827,10 @@ mod tests {
                 .build(),
         ];
 
-        resolve_mates(&mut records)?;
+        resolve_mates(&mut records, &ReadNameTemplate::default())?;
 
-        let read_name_1 = ReadName::try_from(b"1".to_vec())?;
+        // Record IDs are 0-based, and generated read names are 1-based.
+        let read_name_1 = ReadName::try_from(b"2".to_vec())?;
 
         assert_eq!(records[0].read_name(), Some(&read_name_1));
         assert_eq!(
@@ -442,7 +854,7 @@ mod tests {
         );
         assert_eq!(records[1].template_size(), -12);
 
-        let read_name_3 = ReadName::try_from(b"3".to_vec())?;
+        let read_name_3 = ReadName::try_from(b"4".to_vec())?;
         assert_eq!(records[2].read_name(), Some(&read_name_3));
 
         assert_eq!(records[3].read_name(), Some(&read_name_1));
@@ -459,6 +871,124 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_resolve_mates_generates_read_names() -> Result<(), Box<dyn std::error::Error>> {
+        use sam::record::ReadName;
+
+        let mut records = Vec::new();
+        resolve_mates(&mut records, &ReadNameTemplate::default())?;
+
+        let mut records = vec![
+            Record::builder().set_id(5).build(),
+            Record::builder().set_id(8).build(),
+        ];
+
+        resolve_mates(&mut records, &ReadNameTemplate::default())?;
+
+        assert_eq!(
+            records[0].read_name(),
+            Some(&ReadName::try_from(b"6".to_vec())?)
+        );
+        assert_eq!(
+            records[1].read_name(),
+            Some(&ReadName::try_from(b"9".to_vec())?)
+        );
+
+        let mut records = vec![
+            Record::builder().set_id(5).build(),
+            Record::builder().set_id(8).build(),
+        ];
+
+        let read_name_template = ReadNameTemplate::new(String::from("sample"), String::from(":"));
+        resolve_mates(&mut records, &read_name_template)?;
+
+        assert_eq!(
+            records[0].read_name(),
+            Some(&ReadName::try_from(b"sample:6".to_vec())?)
+        );
+        assert_eq!(
+            records[1].read_name(),
+            Some(&ReadName::try_from(b"sample:9".to_vec())?)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_unmapped_sequence_record_block_content_ids(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        use crate::data_container::compression_header::{
+            data_series_encoding_map::DataSeries, DataSeriesEncodingMap, PreservationMap,
+        };
+
+        fn build_data_series_encoding_map(
+            in_seq_positions_encoding: Integer,
+            tag_ids_encoding: Integer,
+        ) -> Result<DataSeriesEncodingMap, Box<dyn std::error::Error>> {
+            let external =
+                |data_series| Encoding::new(Integer::External(block::ContentId::from(data_series)));
+
+            DataSeriesEncodingMap::builder()
+                .set_bam_bit_flags_encoding(external(DataSeries::BamBitFlags))
+                .set_cram_bit_flags_encoding(external(DataSeries::CramBitFlags))
+                .set_read_lengths_encoding(external(DataSeries::ReadLengths))
+                .set_in_seq_positions_encoding(Encoding::new(in_seq_positions_encoding))
+                .set_read_groups_encoding(external(DataSeries::ReadGroups))
+                .set_tag_ids_encoding(Encoding::new(tag_ids_encoding))
+                .set_bases_encoding(Encoding::new(Byte::External(block::ContentId::from(
+                    DataSeries::Bases,
+                ))))
+                .build()
+                .map_err(|e| e.into())
+        }
+
+        let compression_header = CompressionHeader::builder().build()?;
+        let actual = get_unmapped_sequence_record_block_content_ids(&compression_header);
+        let expected = [
+            DataSeries::BamBitFlags,
+            DataSeries::CramBitFlags,
+            DataSeries::ReadLengths,
+            DataSeries::ReadNames,
+            DataSeries::DistanceToNextFragment,
+            DataSeries::Bases,
+            DataSeries::QualityScores,
+        ]
+        .into_iter()
+        .map(block::ContentId::from)
+        .collect();
+        assert_eq!(actual, Some(expected));
+
+        // A skipped data series is read from the core data block.
+        let compression_header = CompressionHeader::builder()
+            .set_preservation_map(
+                PreservationMap::builder()
+                    .set_read_names_included(false)
+                    .build(),
+            )
+            .set_data_series_encoding_map(build_data_series_encoding_map(
+                Integer::Beta(0, 8),
+                Integer::External(block::ContentId::from(13)),
+            )?)
+            .build()?;
+        assert!(get_unmapped_sequence_record_block_content_ids(&compression_header).is_none());
+
+        // A skipped data series shares an external block with a decoded data series.
+        let compression_header = CompressionHeader::builder()
+            .set_preservation_map(
+                PreservationMap::builder()
+                    .set_read_names_included(false)
+                    .build(),
+            )
+            .set_data_series_encoding_map(build_data_series_encoding_map(
+                Integer::External(block::ContentId::from(DataSeries::InSeqPositions)),
+                Integer::External(block::ContentId::from(DataSeries::Bases)),
+            )?)
+            .build()?;
+        assert!(get_unmapped_sequence_record_block_content_ids(&compression_header).is_none());
+
+        Ok(())
+    }
+
     #[test]
     fn test_calculate_template_size() -> Result<(), noodles_core::position::TryFromIntError> {
         use sam::record::Flags;
@@ -527,4 +1057,71 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_verify_content_md5_checksums() -> Result<(), Box<dyn std::error::Error>> {
+        use md5::{Digest, Md5};
+
+        use crate::container::block::ContentType;
+
+        fn build_slice(optional_tags: Vec<u8>) -> Slice {
+            let header = Header::builder().set_optional_tags(optional_tags).build();
+            let core_data_block = Block::builder()
+                .set_content_type(ContentType::CoreData)
+                .build();
+
+            Slice::new(header, core_data_block, Vec::new())
+        }
+
+        fn build_md5_field(tag: &[u8; 2], md5: &[u8]) -> Vec<u8> {
+            let mut buf = tag.to_vec();
+            buf.extend([b'B', b'C']);
+            buf.extend((md5.len() as i32).to_le_bytes());
+            buf.extend(md5);
+            buf
+        }
+
+        let records = [
+            Record::builder()
+                .set_bases("ACGT".parse()?)
+                .set_quality_scores("NDLS".parse()?)
+                .build(),
+            Record::builder()
+                .set_bases("TTA".parse()?)
+                .set_quality_scores("!!!".parse()?)
+                .build(),
+        ];
+
+        let bases_md5 = Md5::digest(b"ACGTTTA");
+        let quality_scores_md5 = Md5::digest([45, 35, 43, 50, 0, 0, 0]);
+
+        let slice = build_slice(Vec::new());
+        assert!(slice.verify_content_md5_checksums(&records).is_ok());
+
+        let mut optional_tags = build_md5_field(b"BD", &bases_md5);
+        optional_tags.extend(build_md5_field(b"SD", &quality_scores_md5));
+        optional_tags.extend([b'X', b'Y', b'C', 0x08]); // XY:C:8
+        let slice = build_slice(optional_tags);
+        assert!(slice.verify_content_md5_checksums(&records).is_ok());
+
+        let slice = build_slice(build_md5_field(b"BD", &quality_scores_md5));
+        assert!(matches!(
+            slice.verify_content_md5_checksums(&records),
+            Err(ref e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        let slice = build_slice(build_md5_field(b"SD", &bases_md5));
+        assert!(matches!(
+            slice.verify_content_md5_checksums(&records),
+            Err(ref e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        let slice = build_slice(build_md5_field(b"BD", &bases_md5[..8]));
+        assert!(matches!(
+            slice.verify_content_md5_checksums(&records),
+            Err(ref e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
 }
@@ -3,7 +3,6 @@ use std::{collections::HashMap, io};
 use bytes::Bytes;
 use md5::{Digest, Md5};
 use noodles_fasta as fasta;
-use noodles_sam as sam;
 
 use crate::{
     codecs::Encoder,
@@ -19,6 +18,7 @@ use crate::{
 use super::{Header, Slice};
 
 const CORE_DATA_BLOCK_CONTENT_ID: i32 = 0;
+const EMBEDDED_REFERENCE_BASES_BLOCK_CONTENT_ID: i32 = i32::MAX;
 const MAX_RECORD_COUNT: usize = 10240;
 
 #[derive(Debug, Default)]
@@ -78,59 +78,73 @@ impl Builder {
     pub fn build(
         mut self,
         block_content_encoder_map: &BlockContentEncoderMap,
-        reference_sequence_repostitory: &fasta::repository::Repository,
-        header: &sam::Header,
+        reference_sequence: Option<&fasta::record::Sequence>,
         compression_header: &CompressionHeader,
         record_counter: u64,
+        embed_reference_sequences: bool,
     ) -> io::Result<Slice> {
-        let (core_data_block, external_blocks) = write_records(
+        let (core_data_block, mut external_blocks) = write_records(
             block_content_encoder_map,
             compression_header,
             self.reference_sequence_context,
             &mut self.records,
         )?;
 
-        let mut block_content_ids = Vec::with_capacity(external_blocks.len() + 1);
-        block_content_ids.push(core_data_block.content_id());
-
-        for block in &external_blocks {
-            block_content_ids.push(block.content_id());
-        }
+        let mut embedded_reference_bases_block_content_id = None;
 
         let reference_md5 = match self.reference_sequence_context {
             ReferenceSequenceContext::Some(context) => {
-                let reference_sequence_name = header
-                    .reference_sequences()
-                    .get_index(context.reference_sequence_id())
-                    .map(|(name, _)| name)
-                    .expect("invalid reference sequence ID");
-
-                let reference_sequence = reference_sequence_repostitory
-                    .get(reference_sequence_name)
-                    .expect("missing reference sequence")
-                    .expect("invalid reference sequence");
+                let reference_sequence = reference_sequence.ok_or_else(|| {
+                    io::Error::new(io::ErrorKind::InvalidInput, "missing reference sequence")
+                })?;
 
                 let (start, end) = (context.alignment_start(), context.alignment_end());
                 let sequence = &reference_sequence[start..=end];
 
+                if embed_reference_sequences {
+                    let block = build_embedded_reference_bases_block(sequence)?;
+                    embedded_reference_bases_block_content_id = Some(block.content_id());
+                    external_blocks.push(block);
+                }
+
                 calculate_normalized_sequence_digest(sequence)
             }
             _ => [0; 16],
         };
 
-        let header = Header::builder()
+        let mut block_content_ids = Vec::with_capacity(external_blocks.len() + 1);
+        block_content_ids.push(core_data_block.content_id());
+
+        for block in &external_blocks {
+            block_content_ids.push(block.content_id());
+        }
+
+        let mut header = Header::builder()
             .set_reference_sequence_context(self.reference_sequence_context)
             .set_record_count(self.records.len())
             .set_record_counter(record_counter)
             .set_block_count(block_content_ids.len())
             .set_block_content_ids(block_content_ids)
-            .set_reference_md5(reference_md5)
-            .build();
+            .set_reference_md5(reference_md5);
+
+        if let Some(id) = embedded_reference_bases_block_content_id {
+            header = header.set_embedded_reference_bases_block_content_id(id);
+        }
 
-        Ok(Slice::new(header, core_data_block, external_blocks))
+        Ok(Slice::new(header.build(), core_data_block, external_blocks))
     }
 }
 
+fn build_embedded_reference_bases_block(sequence: &[u8]) -> io::Result<Block> {
+    Block::builder()
+        .set_content_type(block::ContentType::ExternalData)
+        .set_content_id(block::ContentId::from(
+            EMBEDDED_REFERENCE_BASES_BLOCK_CONTENT_ID,
+        ))
+        .compress_and_set_data(sequence.to_vec(), Encoder::Gzip(Default::default()))
+        .map(|builder| builder.build())
+}
+
 fn write_records(
     block_content_encoder_map: &BlockContentEncoderMap,
     compression_header: &CompressionHeader,
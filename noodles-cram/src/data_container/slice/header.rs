@@ -4,6 +4,7 @@ pub use builder::Builder;
 
 use crate::{container::block, data_container::ReferenceSequenceContext};
 
+/// A CRAM data container slice header.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Header {
     reference_sequence_context: ReferenceSequenceContext,
@@ -17,38 +18,52 @@ pub struct Header {
 }
 
 impl Header {
-    pub fn builder() -> Builder {
+    pub(crate) fn builder() -> Builder {
         Builder::default()
     }
 
+    /// Returns the reference sequence context.
+    ///
+    /// This is the reference sequence and alignment span of the records in the slice.
     pub fn reference_sequence_context(&self) -> ReferenceSequenceContext {
         self.reference_sequence_context
     }
 
+    /// Returns the number of records in the slice.
     pub fn record_count(&self) -> usize {
         self.record_count
     }
 
+    /// Returns the record counter.
+    ///
+    /// This is the 0-based index of the first record in the slice.
     pub fn record_counter(&self) -> u64 {
         self.record_counter
     }
 
+    /// Returns the number of blocks in the slice.
     pub fn block_count(&self) -> usize {
         self.block_count
     }
 
+    /// Returns the content IDs of the blocks in the slice.
     pub fn block_content_ids(&self) -> &[block::ContentId] {
         &self.block_content_ids
     }
 
+    /// Returns the content ID of the block with the embedded reference sequence, if any.
     pub fn embedded_reference_bases_block_content_id(&self) -> Option<block::ContentId> {
         self.embedded_reference_bases_block_content_id
     }
 
+    /// Returns the MD5 checksum of the reference sequence span.
+    ///
+    /// This is all zeros if the slice has no single reference sequence.
     pub fn reference_md5(&self) -> &[u8] {
         &self.reference_md5
     }
 
+    /// Returns the raw optional tags.
     pub fn optional_tags(&self) -> &[u8] {
         &self.optional_tags
     }
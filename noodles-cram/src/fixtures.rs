@@ -0,0 +1,84 @@
+//! Shared test fixtures.
+
+use std::{error::Error, io, num::NonZeroUsize};
+
+use noodles_fasta as fasta;
+use noodles_sam::{
+    self as sam,
+    header::record::value::{map::ReferenceSequence, Map},
+};
+
+use crate::{writer, Reader, Record};
+
+/// The number of records written by [`build_multi_container_records`].
+pub(crate) const MULTI_CONTAINER_RECORD_COUNT: usize = 25000;
+
+/// Builds a repository with a single reference sequence (`sq0`).
+pub(crate) fn build_repository(sequence: &[u8]) -> fasta::Repository {
+    use fasta::record::{Definition, Sequence};
+
+    fasta::Repository::new(vec![fasta::Record::new(
+        Definition::new("sq0", None),
+        Sequence::from(sequence.to_vec()),
+    )])
+}
+
+/// Builds a repository where `sq0` is `TTCACCCA`.
+pub(crate) fn build_sq0_repository() -> fasta::Repository {
+    build_repository(b"TTCACCCA")
+}
+
+/// Builds a header with a single reference sequence (`sq0`) of length 8.
+pub(crate) fn build_sq0_header() -> Result<sam::Header, Box<dyn Error>> {
+    Ok(sam::Header::builder()
+        .add_reference_sequence(
+            "sq0".parse()?,
+            Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8)?),
+        )
+        .build())
+}
+
+/// Builds unmapped records that are written to 3 data containers.
+pub(crate) fn build_multi_container_records() -> Result<Vec<sam::alignment::Record>, Box<dyn Error>>
+{
+    (0..MULTI_CONTAINER_RECORD_COUNT)
+        .map(|i| {
+            Ok(sam::alignment::Record::builder()
+                .set_read_name(format!("r{i}").parse()?)
+                .set_sequence("ACGT".parse()?)
+                .build())
+        })
+        .collect()
+}
+
+/// Writes a CRAM file with the given alignment records.
+pub(crate) fn write_records(
+    builder: writer::Builder,
+    header: &sam::Header,
+    records: &[sam::alignment::Record],
+) -> io::Result<Vec<u8>> {
+    let mut writer = builder.build_with_writer(Vec::new());
+
+    writer.write_file_definition()?;
+    writer.write_file_header(header)?;
+
+    for record in records {
+        writer.write_record(header, Record::try_from_alignment_record(header, record)?)?;
+    }
+
+    writer.try_finish(header)?;
+
+    Ok(writer.get_ref().clone())
+}
+
+/// Reads all records of a CRAM file.
+pub(crate) fn read_records(
+    src: &[u8],
+    repository: &fasta::Repository,
+    header: &sam::Header,
+) -> io::Result<Vec<Record>> {
+    let mut reader = Reader::new(src);
+    reader.read_file_definition()?;
+    reader.read_file_header()?;
+    reader.records(repository, header).collect()
+}
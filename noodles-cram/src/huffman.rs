@@ -1,8 +1,11 @@
-use std::{collections::HashMap, io};
+use std::{
+    collections::HashMap,
+    io::{self, Write},
+};
 
 use bytes::Buf;
 
-use crate::BitReader;
+use crate::{BitReader, BitWriter};
 
 type CodeBook = HashMap<i32, (i32, u32)>;
 
@@ -60,6 +63,31 @@ impl CanonicalHuffmanDecoder {
     }
 }
 
+pub struct CanonicalHuffmanEncoder {
+    code_book: CodeBook,
+}
+
+impl CanonicalHuffmanEncoder {
+    pub fn new(alphabet: &[i32], bit_lens: &[u32]) -> Self {
+        let code_book = build_canonical_code_book(alphabet, bit_lens);
+        Self { code_book }
+    }
+
+    pub fn encode<W>(&self, writer: &mut BitWriter<W>, symbol: i32) -> io::Result<()>
+    where
+        W: Write,
+    {
+        let (code, len) = self.code_book.get(&symbol).copied().ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("symbol not in alphabet: {symbol}"),
+            )
+        })?;
+
+        writer.write_u32(code as u32, len as usize)
+    }
+}
+
 fn build_canonical_code_book(alphabet: &[i32], bit_lens: &[u32]) -> CodeBook {
     let sorted_alphabet = {
         let mut pairs: Vec<_> = alphabet.iter().zip(bit_lens.iter()).collect();
@@ -107,6 +135,29 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_encode() -> io::Result<()> {
+        let symbols = [0x4e, 0x44, 0x4c];
+        let bit_lens = [1, 2, 2];
+        let encoder = CanonicalHuffmanEncoder::new(&symbols, &bit_lens);
+
+        let mut writer = BitWriter::new(Vec::new());
+
+        for symbol in [0x4e, 0x44, 0x4c, 0x4e] {
+            encoder.encode(&mut writer, symbol)?;
+        }
+
+        assert_eq!(writer.finish()?, [0b01011000]);
+
+        let mut writer = BitWriter::new(Vec::new());
+        assert!(matches!(
+            encoder.encode(&mut writer, 0x41),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
     #[test]
     fn test_build_canonical_code_book() {
         let symbols = [65, 66, 67, 68, 69, 70];
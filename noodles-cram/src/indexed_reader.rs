@@ -0,0 +1,130 @@
+//! Indexed CRAM reader.
+
+mod builder;
+
+pub use self::builder::Builder;
+
+use std::io::{self, Read, Seek};
+
+use noodles_core::Region;
+use noodles_fasta as fasta;
+use noodles_sam as sam;
+
+use super::{
+    crai,
+    reader::{Query, Records},
+    DataContainer, FileDefinition, Reader,
+};
+
+/// An indexed CRAM reader.
+///
+/// # Examples
+///
+/// ```no_run
+/// use noodles_cram as cram;
+///
+/// let mut reader = cram::indexed_reader::Builder::default().build_from_path("sample.cram")?;
+/// reader.read_file_definition()?;
+///
+/// let header = reader.read_file_header()?.parse()?;
+/// let region = "sq0:8-13".parse()?;
+///
+/// for result in reader.query(&header, &region)? {
+///     let record = result?;
+///     // ...
+/// }
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub struct IndexedReader<R> {
+    inner: Reader<R>,
+    reference_sequence_repository: fasta::Repository,
+    index: crai::Index,
+}
+
+impl<R> IndexedReader<R>
+where
+    R: Read,
+{
+    /// Creates an indexed CRAM reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::{self as cram, crai};
+    /// let reader = cram::IndexedReader::new(&[][..], crai::Index::default());
+    /// ```
+    pub fn new(inner: R, index: crai::Index) -> Self {
+        Self {
+            inner: Reader::new(inner),
+            reference_sequence_repository: fasta::Repository::default(),
+            index,
+        }
+    }
+
+    /// Returns a reference to the underlying reader.
+    pub fn get_ref(&self) -> &R {
+        self.inner.get_ref()
+    }
+
+    /// Returns a mutable reference to the underlying reader.
+    pub fn get_mut(&mut self) -> &mut R {
+        self.inner.get_mut()
+    }
+
+    /// Returns the underlying reader.
+    pub fn into_inner(self) -> R {
+        self.inner.into_inner()
+    }
+
+    /// Returns the reference sequence repository.
+    pub fn reference_sequence_repository(&self) -> &fasta::Repository {
+        &self.reference_sequence_repository
+    }
+
+    /// Returns the index.
+    pub fn index(&self) -> &crai::Index {
+        &self.index
+    }
+
+    /// Reads the CRAM file definition.
+    pub fn read_file_definition(&mut self) -> io::Result<FileDefinition> {
+        self.inner.read_file_definition()
+    }
+
+    /// Reads the raw SAM header.
+    pub fn read_file_header(&mut self) -> io::Result<String> {
+        self.inner.read_file_header()
+    }
+
+    /// Reads a data container.
+    pub fn read_data_container(&mut self) -> io::Result<Option<DataContainer>> {
+        self.inner.read_data_container()
+    }
+
+    /// Returns an iterator over records starting from the current stream position.
+    pub fn records<'a>(&'a mut self, header: &'a sam::Header) -> Records<'a, R> {
+        self.inner
+            .records(&self.reference_sequence_repository, header)
+    }
+}
+
+impl<R> IndexedReader<R>
+where
+    R: Read + Seek,
+{
+    /// Returns an iterator over records that intersect the given region.
+    ///
+    /// Only the containers with slices that intersect the region are read.
+    pub fn query<'a>(
+        &'a mut self,
+        header: &'a sam::Header,
+        region: &Region,
+    ) -> io::Result<Query<'a, R>> {
+        self.inner.query(
+            &self.reference_sequence_repository,
+            header,
+            &self.index,
+            region,
+        )
+    }
+}
@@ -125,11 +125,12 @@ fn push_index_records_for_multi_reference_slice(
             .entry(reference_sequence_id)
             .or_default();
 
-        let alignment_start = record.alignment_start();
-        range.start = cmp::min(range.start, alignment_start);
-
-        let alignment_end = record.alignment_end();
-        range.end = cmp::max(range.end, alignment_end);
+        if let (Some(alignment_start), Some(alignment_end)) =
+            (record.alignment_start(), record.alignment_end())
+        {
+            range.start = cmp::min(range.start, Some(alignment_start));
+            range.end = cmp::max(range.end, Some(alignment_end));
+        }
     }
 
     let mut sorted_reference_sequence_ids: Vec<_> =
@@ -144,7 +145,8 @@ fn push_index_records_for_multi_reference_slice(
                 let span = usize::from(end) - usize::from(start) + 1;
                 (Some(start), span)
             } else {
-                todo!("unhandled interval: {:?}", range);
+                // None of the records that reference this sequence have alignment positions.
+                (None, 0)
             }
         } else {
             (None, 0)
@@ -8,20 +8,24 @@ pub mod r#async;
 mod bit_reader;
 mod bit_writer;
 pub mod codecs;
-pub(crate) mod container;
+pub mod container;
 pub mod crai;
 pub mod data_container;
 pub mod file_definition;
+#[cfg(test)]
+mod fixtures;
 mod huffman;
+pub mod indexed_reader;
 mod indexer;
 mod num;
 pub mod reader;
 pub mod record;
+pub mod repository;
 pub mod writer;
 
 pub use self::{
-    data_container::DataContainer, file_definition::FileDefinition, indexer::index, reader::Reader,
-    record::Record, writer::Writer,
+    data_container::DataContainer, file_definition::FileDefinition, indexed_reader::IndexedReader,
+    indexer::index, reader::Reader, record::Record, writer::Writer,
 };
 
 #[cfg(feature = "async")]
@@ -1,16 +1,29 @@
 //! CRAM reader and record iterator.
 
+mod builder;
 pub(crate) mod container;
 pub(crate) mod data_container;
+mod data_container_offsets;
+mod fastq_records;
 pub(crate) mod header_container;
 pub(crate) mod num;
-mod query;
+mod parallel_records;
+pub(crate) mod query;
+mod read_name_template;
 pub(crate) mod record;
 mod records;
+pub(crate) mod truncation_error;
 
-pub use self::{query::Query, records::Records};
+pub use self::{
+    builder::Builder, data_container_offsets::DataContainerOffsets, fastq_records::FastqRecords,
+    parallel_records::ParallelRecords, query::Query, read_name_template::ReadNameTemplate,
+    records::Records, truncation_error::TruncationError,
+};
 
-use std::io::{self, Read, Seek, SeekFrom};
+use std::{
+    io::{self, Read, Seek, SeekFrom},
+    num::NonZeroUsize,
+};
 
 use bytes::BytesMut;
 use noodles_core::Region;
@@ -49,6 +62,10 @@ use crate::data_container::DataContainer;
 pub struct Reader<R> {
     inner: R,
     buf: BytesMut,
+    version: Version,
+    try_recover: bool,
+    verify_md5_checksums: bool,
+    read_name_template: ReadNameTemplate,
 }
 
 impl<R> Reader<R>
@@ -66,10 +83,7 @@ where
     /// # Ok::<(), io::Error>(())
     /// ```
     pub fn new(reader: R) -> Self {
-        Self {
-            inner: reader,
-            buf: BytesMut::new(),
-        }
+        Builder::default().build_with_reader(reader)
     }
 
     /// Returns a reference to the underlying reader.
@@ -120,6 +134,9 @@ where
     ///
     /// The position of the stream is expected to be at the start.
     ///
+    /// The format version determines how the rest of the stream is decoded. CRAM 2.1 and 3.x are
+    /// supported.
+    ///
     /// # Examples
     ///
     /// ```no_run
@@ -135,6 +152,8 @@ where
         let format = read_format(&mut self.inner)?;
         let file_id = read_file_id(&mut self.inner)?;
 
+        self.version = format;
+
         Ok(FileDefinition::new(format, file_id))
     }
 
@@ -160,20 +179,59 @@ where
     /// ```
     pub fn read_file_header(&mut self) -> io::Result<String> {
         use self::header_container::read_header_container;
-        read_header_container(&mut self.inner, &mut self.buf)
+        read_header_container(&mut self.inner, &mut self.buf, self.version)
     }
 
-    pub(crate) fn read_data_container_with_container_header(
+    /// Reads a data container and its header.
+    ///
+    /// This returns `None` if the container header is the EOF container header, which signals the
+    /// end of the stream. If the stream ends before the EOF container, this returns an error
+    /// wrapping a [`TruncationError`], unless the reader was built to try to recover (see
+    /// [`Builder::set_try_recover`]), in which case it also returns `None`.
+    ///
+    /// The container header, slice headers, and blocks can be inspected without decoding any
+    /// records, e.g., to summarize the layout of a CRAM file.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::{fs::File, io};
+    /// use noodles_cram as cram;
+    ///
+    /// let mut reader = File::open("sample.cram").map(cram::Reader::new)?;
+    /// reader.read_file_definition()?;
+    /// reader.read_file_header()?;
+    ///
+    /// while let Some((header, data_container)) =
+    ///     reader.read_data_container_with_container_header()?
+    /// {
+    ///     println!("{:?} {}", header.reference_sequence_context(), header.record_count());
+    ///
+    ///     for slice in data_container.slices() {
+    ///         for block in slice.external_blocks() {
+    ///             println!("{} {:?}", block.content_id(), block.compression_method());
+    ///         }
+    ///     }
+    /// }
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn read_data_container_with_container_header(
         &mut self,
     ) -> io::Result<Option<(crate::data_container::Header, DataContainer)>> {
         use self::data_container::read_data_container_with_container_header;
-        read_data_container_with_container_header(&mut self.inner, &mut self.buf)
+
+        let result =
+            read_data_container_with_container_header(&mut self.inner, &mut self.buf, self.version);
+
+        self.recover(result)
     }
 
     /// Reads a data container.
     ///
     /// This returns `None` if the container header is the EOF container header, which signals the
-    /// end of the stream.
+    /// end of the stream. If the stream ends before the EOF container, this returns an error
+    /// wrapping a [`TruncationError`], unless the reader was built to try to recover (see
+    /// [`Builder::set_try_recover`]), in which case it also returns `None`.
     ///
     /// # Examples
     ///
@@ -193,7 +251,17 @@ where
     pub fn read_data_container(&mut self) -> io::Result<Option<DataContainer>> {
         use self::data_container::read_data_container;
 
-        read_data_container(&mut self.inner, &mut self.buf)
+        let result = read_data_container(&mut self.inner, &mut self.buf, self.version);
+        self.recover(result)
+    }
+
+    fn recover<T>(&self, result: io::Result<Option<T>>) -> io::Result<Option<T>> {
+        use self::truncation_error::is_truncation_error;
+
+        match result {
+            Err(e) if self.try_recover && is_truncation_error(&e) => Ok(None),
+            result => result,
+        }
     }
 
     /// Returns a iterator over records starting from the current stream position.
@@ -227,6 +295,84 @@ where
     ) -> Records<'a, R> {
         Records::new(self, reference_sequence_repository, header)
     }
+
+    /// Returns an iterator over records as FASTQ records starting from the current stream
+    /// position.
+    ///
+    /// Unmapped slices are decoded using a fast path that only reads read names, bases, and
+    /// quality scores, e.g., to convert unaligned CRAM files to FASTQ. See [`FastqRecords`].
+    ///
+    /// The stream is expected to be at the start of a data container.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::{fs::File, io};
+    /// use noodles_cram as cram;
+    /// use noodles_fasta as fasta;
+    /// use noodles_fastq as fastq;
+    ///
+    /// let repository = fasta::Repository::default();
+    ///
+    /// let mut reader = File::open("sample.cram").map(cram::Reader::new)?;
+    /// reader.read_file_definition()?;
+    ///
+    /// let header = reader.read_file_header()?.parse()?;
+    ///
+    /// let mut writer = fastq::Writer::new(io::stdout().lock());
+    ///
+    /// for result in reader.fastq_records(&repository, &header) {
+    ///     let record = result?;
+    ///     writer.write_record(&record)?;
+    /// }
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn fastq_records<'a>(
+        &'a mut self,
+        reference_sequence_repository: &'a fasta::Repository,
+        header: &'a sam::Header,
+    ) -> FastqRecords<'a, R> {
+        FastqRecords::new(self, reference_sequence_repository, header)
+    }
+
+    /// Returns an iterator over records that are decoded in parallel.
+    ///
+    /// Data containers are read on the calling thread and decoded on `worker_count` worker
+    /// threads. Records are returned in input order.
+    ///
+    /// The stream is expected to be at the start of a data container.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::fs::File;
+    /// use std::num::NonZeroUsize;
+    /// use noodles_cram as cram;
+    /// use noodles_fasta as fasta;
+    ///
+    /// let repository = fasta::Repository::default();
+    ///
+    /// let mut reader = File::open("sample.cram").map(cram::Reader::new)?;
+    /// reader.read_file_definition()?;
+    ///
+    /// let header = reader.read_file_header()?.parse()?;
+    ///
+    /// let worker_count = NonZeroUsize::try_from(4)?;
+    ///
+    /// for result in reader.parallel_records(&repository, &header, worker_count) {
+    ///     let record = result?;
+    ///     println!("{:?}", record);
+    /// }
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn parallel_records<'a>(
+        &'a mut self,
+        reference_sequence_repository: &'a fasta::Repository,
+        header: &'a sam::Header,
+        worker_count: NonZeroUsize,
+    ) -> ParallelRecords<'a, R> {
+        ParallelRecords::new(self, reference_sequence_repository, header, worker_count)
+    }
 }
 
 impl<R> Reader<R>
@@ -235,7 +381,8 @@ where
 {
     /// Seeks the underlying reader to the given position.
     ///
-    /// Positions typically come from the associated CRAM index file.
+    /// Positions typically come from the associated CRAM index file or are data container offsets
+    /// (see [`Self::data_container_offsets`]).
     ///
     /// # Examples
     ///
@@ -254,6 +401,9 @@ where
 
     /// Returns the current position of the underlying reader.
     ///
+    /// When the reader is at the start of a data container, e.g., directly after reading the file
+    /// header or a data container, this is the absolute offset of the data container.
+    ///
     /// # Examples
     ///
     /// ```
@@ -269,6 +419,81 @@ where
         self.inner.stream_position()
     }
 
+    /// Returns an iterator over the absolute offsets of data containers starting from the current
+    /// stream position.
+    ///
+    /// The stream is expected to be at the start of a data container. Only container headers are
+    /// read; container bodies are skipped. The EOF container is not included.
+    ///
+    /// This can be used to split a CRAM file into shards of data containers without an index,
+    /// e.g., to read them on separate workers, each with its own reader (see
+    /// [`Self::records_from`]).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::{fs::File, io::{self, SeekFrom}};
+    /// use noodles_cram as cram;
+    ///
+    /// let mut reader = File::open("sample.cram").map(cram::Reader::new)?;
+    /// reader.read_file_definition()?;
+    /// reader.read_file_header()?;
+    ///
+    /// let offsets: Vec<_> = reader.data_container_offsets().collect::<io::Result<_>>()?;
+    ///
+    /// // Reads the data containers in the first shard, i.e., the first 8 data containers.
+    /// let (start, end) = (offsets[0], offsets.get(8).copied().unwrap_or(u64::MAX));
+    /// reader.seek(SeekFrom::Start(start))?;
+    ///
+    /// while reader.position()? < end {
+    ///     let data_container = match reader.read_data_container()? {
+    ///         Some(data_container) => data_container,
+    ///         None => break,
+    ///     };
+    ///
+    ///     // ...
+    /// }
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn data_container_offsets(&mut self) -> DataContainerOffsets<'_, R> {
+        DataContainerOffsets::new(self)
+    }
+
+    /// Returns an iterator over records starting from the data container at the given offset.
+    ///
+    /// The offset is the absolute offset of a data container, e.g., a position recorded using
+    /// [`Self::position`] or from [`Self::data_container_offsets`]. This allows iteration to be
+    /// resumed from a data container.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::{fs::File, io};
+    /// use noodles_cram as cram;
+    /// use noodles_fasta as fasta;
+    ///
+    /// let repository = fasta::Repository::default();
+    ///
+    /// let mut reader = File::open("sample.cram").map(cram::Reader::new)?;
+    /// reader.read_file_definition()?;
+    /// let header = reader.read_file_header()?.parse()?;
+    ///
+    /// for result in reader.records_from(&repository, &header, 17711)? {
+    ///     let record = result?;
+    ///     // ...
+    /// }
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn records_from<'a>(
+        &'a mut self,
+        reference_sequence_repository: &'a fasta::Repository,
+        header: &'a sam::Header,
+        offset: u64,
+    ) -> io::Result<Records<'a, R>> {
+        self.seek(SeekFrom::Start(offset))?;
+        Ok(self.records(reference_sequence_repository, header))
+    }
+
     /// Returns an iterator over records that intersects the given region.
     ///
     /// # Examples
@@ -369,7 +594,21 @@ where
 {
     let mut buf = [0; 2];
     reader.read_exact(&mut buf)?;
-    Ok(Version::new(buf[0], buf[1]))
+
+    let version = Version::new(buf[0], buf[1]);
+
+    if matches!(version.major(), 2 | 3) {
+        Ok(version)
+    } else {
+        Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "unsupported CRAM version: {}.{}",
+                version.major(),
+                version.minor()
+            ),
+        ))
+    }
 }
 
 fn read_file_id<R>(reader: &mut R) -> io::Result<[u8; 20]>
@@ -405,6 +644,26 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_read_format() -> io::Result<()> {
+        let data = [0x02, 0x01];
+        let mut reader = &data[..];
+        assert_eq!(read_format(&mut reader)?, Version::new(2, 1));
+
+        let data = [0x03, 0x01];
+        let mut reader = &data[..];
+        assert_eq!(read_format(&mut reader)?, Version::new(3, 1));
+
+        let data = [0x01, 0x00];
+        let mut reader = &data[..];
+        assert!(matches!(
+            read_format(&mut reader),
+            Err(ref e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
     #[test]
     fn test_read_magic_number() {
         let data = b"CRAM";
@@ -428,4 +687,363 @@ mod tests {
             Err(ref e) if e.kind() == io::ErrorKind::InvalidData,
         ));
     }
+
+    #[test]
+    fn test_read_data_container_with_container_header() -> Result<(), Box<dyn std::error::Error>> {
+        use noodles_core::Position;
+
+        use crate::{
+            container::block::ContentType,
+            data_container::ReferenceSequenceContext,
+            fixtures::{build_sq0_header, build_sq0_repository, write_records},
+        };
+
+        let header = build_sq0_header()?;
+
+        let records = [("r0", 2), ("r1", 5)]
+            .into_iter()
+            .map(|(name, start)| {
+                Ok(sam::alignment::Record::builder()
+                    .set_read_name(name.parse()?)
+                    .set_flags(sam::record::Flags::empty())
+                    .set_reference_sequence_id(0)
+                    .set_alignment_start(Position::try_from(start)?)
+                    .set_cigar("3M".parse()?)
+                    .set_sequence("TCA".parse()?)
+                    .set_quality_scores("NDL".parse()?)
+                    .build())
+            })
+            .collect::<Result<Vec<_>, Box<dyn std::error::Error>>>()?;
+
+        let builder = crate::writer::Builder::default()
+            .set_reference_sequence_repository(build_sq0_repository());
+        let data = write_records(builder, &header, &records)?;
+
+        let mut reader = Reader::new(&data[..]);
+        reader.read_file_definition()?;
+        reader.read_file_header()?;
+
+        let (container_header, data_container) = reader
+            .read_data_container_with_container_header()?
+            .ok_or("missing data container")?;
+
+        let expected_reference_sequence_context =
+            ReferenceSequenceContext::some(0, Position::try_from(2)?, Position::try_from(7)?);
+
+        assert_eq!(
+            container_header.reference_sequence_context(),
+            expected_reference_sequence_context
+        );
+        assert_eq!(container_header.record_count(), 2);
+        assert_eq!(container_header.base_count(), 6);
+        assert_eq!(
+            container_header.landmarks().len(),
+            data_container.slices().len()
+        );
+
+        let slice = &data_container.slices()[0];
+        let slice_header = slice.header();
+        assert_eq!(
+            slice_header.reference_sequence_context(),
+            expected_reference_sequence_context
+        );
+        assert_eq!(slice_header.record_count(), 2);
+
+        assert_eq!(
+            slice.core_data_block().content_type(),
+            ContentType::CoreData
+        );
+
+        let block_content_ids: Vec<_> = std::iter::once(slice.core_data_block())
+            .chain(slice.external_blocks())
+            .map(|block| block.content_id())
+            .collect();
+        assert_eq!(slice_header.block_content_ids(), block_content_ids);
+
+        assert!(reader
+            .read_data_container_with_container_header()?
+            .is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_data_container_with_a_truncated_stream() -> Result<(), Box<dyn std::error::Error>>
+    {
+        use crate::{fixtures::write_records, writer::container::EOF};
+
+        fn count_data_containers(src: &[u8], try_recover: bool) -> io::Result<usize> {
+            let mut reader = Builder::default()
+                .set_try_recover(try_recover)
+                .build_with_reader(src);
+
+            reader.read_file_definition()?;
+            reader.read_file_header()?;
+
+            let mut n = 0;
+
+            while reader.read_data_container()?.is_some() {
+                n += 1;
+            }
+
+            Ok(n)
+        }
+
+        fn truncation_error(result: io::Result<usize>) -> Option<TruncationError> {
+            result
+                .err()
+                .and_then(|e| e.into_inner())
+                .and_then(|e| e.downcast().ok())
+                .map(|e| *e)
+        }
+
+        let header = sam::Header::default();
+
+        let record = sam::alignment::Record::builder()
+            .set_read_name("r0".parse()?)
+            .set_sequence("ACGT".parse()?)
+            .build();
+
+        let data = write_records(crate::writer::Builder::default(), &header, &[record])?;
+        let data = &data[..];
+        let eof_container_start = data.len() - EOF.len();
+
+        assert_eq!(count_data_containers(data, false)?, 1);
+
+        let src = &data[..eof_container_start];
+        assert_eq!(
+            truncation_error(count_data_containers(src, false)),
+            Some(TruncationError::MissingEofContainer)
+        );
+        assert_eq!(count_data_containers(src, true)?, 1);
+
+        for end in [eof_container_start + 2, data.len() - 1] {
+            let src = &data[..end];
+            assert_eq!(
+                truncation_error(count_data_containers(src, false)),
+                Some(TruncationError::TruncatedContainer)
+            );
+            assert_eq!(count_data_containers(src, true)?, 1);
+        }
+
+        let src = &data[..eof_container_start - 1];
+        assert_eq!(
+            truncation_error(count_data_containers(src, false)),
+            Some(TruncationError::TruncatedContainer)
+        );
+        assert_eq!(count_data_containers(src, true)?, 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_records_with_a_reference_sequence_checksum_mismatch(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        use noodles_core::Position;
+
+        use crate::fixtures::{
+            build_repository, build_sq0_header, build_sq0_repository, write_records,
+        };
+
+        let header = build_sq0_header()?;
+
+        let record = sam::alignment::Record::builder()
+            .set_flags(sam::record::Flags::empty())
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::try_from(2)?)
+            .set_cigar("3M".parse()?)
+            .set_sequence("TCA".parse()?)
+            .build();
+
+        let builder = crate::writer::Builder::default()
+            .set_reference_sequence_repository(build_sq0_repository());
+        let data = write_records(builder, &header, &[record])?;
+
+        let repository = build_repository(b"TTGACCCA");
+
+        let mut reader = Reader::new(&data[..]);
+        reader.read_file_definition()?;
+        reader.read_file_header()?;
+        let result: io::Result<Vec<_>> = reader.records(&repository, &header).collect();
+        assert!(matches!(result, Err(ref e) if e.kind() == io::ErrorKind::InvalidData));
+
+        let mut reader = Builder::default()
+            .set_verify_md5_checksums(false)
+            .build_with_reader(&data[..]);
+        reader.read_file_definition()?;
+        reader.read_file_header()?;
+        let records: Vec<_> = reader
+            .records(&repository, &header)
+            .collect::<io::Result<_>>()?;
+        assert_eq!(records.len(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_data_container_offsets() -> Result<(), Box<dyn std::error::Error>> {
+        use std::io::Cursor;
+
+        use crate::fixtures::{
+            build_multi_container_records, write_records, MULTI_CONTAINER_RECORD_COUNT,
+        };
+
+        let header = sam::Header::default();
+        let records = build_multi_container_records()?;
+        let data = write_records(crate::writer::Builder::default(), &header, &records)?;
+
+        let repository = fasta::Repository::default();
+
+        let mut reader = Reader::new(Cursor::new(data));
+        reader.read_file_definition()?;
+        reader.read_file_header()?;
+
+        let start = reader.position()?;
+        let offsets: Vec<_> = reader.data_container_offsets().collect::<io::Result<_>>()?;
+
+        assert_eq!(offsets.len(), 3);
+        assert_eq!(offsets[0], start);
+
+        let mut record_counts = Vec::new();
+
+        for &offset in &offsets {
+            reader.seek(SeekFrom::Start(offset))?;
+
+            let (container_header, _) = reader
+                .read_data_container_with_container_header()?
+                .ok_or("missing data container")?;
+
+            record_counts.push(usize::try_from(container_header.record_count())?);
+        }
+
+        let expected: Vec<_> = reader
+            .records_from(&repository, &header, start)?
+            .collect::<io::Result<_>>()?;
+        assert_eq!(expected.len(), MULTI_CONTAINER_RECORD_COUNT);
+
+        let actual: Vec<_> = reader
+            .records_from(&repository, &header, offsets[1])?
+            .collect::<io::Result<_>>()?;
+        assert_eq!(actual, &expected[record_counts[0]..]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_records_with_a_read_name_template() -> Result<(), Box<dyn std::error::Error>> {
+        use noodles_core::Position;
+
+        use crate::fixtures::{build_repository, build_sq0_header, write_records};
+
+        let repository = build_repository(b"ACGTACGT");
+        let header = build_sq0_header()?;
+
+        let build_record = |read_name: Option<&str>, flags| {
+            let mut builder = sam::alignment::Record::builder()
+                .set_flags(flags)
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::MIN)
+                .set_cigar("4M".parse()?)
+                .set_sequence("ACGT".parse()?);
+
+            if let Some(name) = read_name {
+                builder = builder.set_read_name(name.parse()?);
+            }
+
+            Ok::<_, Box<dyn std::error::Error>>(builder.build())
+        };
+
+        // A mate pair in the same slice and a record without a read name. Read names of the mates
+        // are not stored.
+        let records = [
+            build_record(
+                Some("r0"),
+                sam::record::Flags::SEGMENTED | sam::record::Flags::FIRST_SEGMENT,
+            )?,
+            build_record(
+                Some("r0"),
+                sam::record::Flags::SEGMENTED | sam::record::Flags::LAST_SEGMENT,
+            )?,
+            build_record(None, sam::record::Flags::empty())?,
+        ];
+
+        let builder = crate::writer::Builder::default()
+            .set_reference_sequence_repository(repository.clone())
+            .preserve_read_names(false);
+        let data = write_records(builder, &header, &records)?;
+
+        let mut reader = Builder::default()
+            .set_read_name_template(ReadNameTemplate::new(
+                String::from("sample"),
+                String::from(":"),
+            ))
+            .build_with_reader(&data[..]);
+        reader.read_file_definition()?;
+        reader.read_file_header()?;
+
+        let read_names: Vec<_> = reader
+            .records(&repository, &header)
+            .map(|result| result.map(|record| record.read_name().cloned()))
+            .collect::<io::Result<_>>()?;
+
+        assert_eq!(
+            read_names,
+            [
+                Some("sample:1".parse()?),
+                Some("sample:1".parse()?),
+                Some("sample:3".parse()?),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_fastq_records() -> Result<(), Box<dyn std::error::Error>> {
+        use noodles_fastq as fastq;
+        use sam::record::Flags;
+
+        use crate::fixtures::write_records;
+
+        let header = sam::Header::default();
+
+        let records = [
+            sam::alignment::Record::builder()
+                .set_read_name("r0".parse()?)
+                .set_sequence("ACGT".parse()?)
+                .set_quality_scores("NDLS".parse()?)
+                .set_data("NH:i:1".parse()?)
+                .build(),
+            sam::alignment::Record::builder()
+                .set_read_name("r1".parse()?)
+                .set_flags(Flags::UNMAPPED | Flags::REVERSE_COMPLEMENTED)
+                .set_sequence("AACG".parse()?)
+                .build(),
+            sam::alignment::Record::builder()
+                .set_read_name("r2".parse()?)
+                .set_flags(Flags::UNMAPPED | Flags::SECONDARY)
+                .set_sequence("TTTT".parse()?)
+                .build(),
+        ];
+
+        let data = write_records(crate::writer::Builder::default(), &header, &records)?;
+        let repository = fasta::Repository::default();
+
+        let mut reader = Reader::new(&data[..]);
+        reader.read_file_definition()?;
+        reader.read_file_header()?;
+
+        let actual: Vec<_> = reader
+            .fastq_records(&repository, &header)
+            .collect::<io::Result<_>>()?;
+
+        let expected = [
+            fastq::Record::new("r0", "ACGT", "NDLS"),
+            fastq::Record::new("r1", "CGTT", "\"\"\"\""),
+        ];
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
 }
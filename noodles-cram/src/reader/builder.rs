@@ -0,0 +1,108 @@
+use std::io::Read;
+
+use bytes::BytesMut;
+
+use super::{ReadNameTemplate, Reader};
+use crate::file_definition::Version;
+
+/// A CRAM reader builder.
+#[derive(Debug)]
+pub struct Builder {
+    try_recover: bool,
+    verify_md5_checksums: bool,
+    read_name_template: ReadNameTemplate,
+}
+
+impl Builder {
+    /// Sets whether to try to recover from a truncated stream.
+    ///
+    /// If `true`, a stream that ends before the EOF container is treated as the end of the
+    /// stream, i.e., all complete data containers are read, and the incomplete one is discarded.
+    /// Otherwise, reading a data container returns an error wrapping a
+    /// [`super::TruncationError`].
+    ///
+    /// The default is `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram as cram;
+    /// let builder = cram::reader::Builder::default().set_try_recover(true);
+    /// ```
+    pub fn set_try_recover(mut self, try_recover: bool) -> Self {
+        self.try_recover = try_recover;
+        self
+    }
+
+    /// Sets whether to verify slice MD5 checksums.
+    ///
+    /// This includes the reference sequence MD5 checksum of each slice and, if present in the
+    /// slice header, the MD5 checksums of the resolved bases (`BD`) and quality scores (`SD`) of
+    /// the records in the slice. Disabling verification skips hashing the reference sequence and
+    /// records when reading records, e.g., for speed when the input is trusted.
+    ///
+    /// The default is `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram as cram;
+    /// let builder = cram::reader::Builder::default().set_verify_md5_checksums(false);
+    /// ```
+    pub fn set_verify_md5_checksums(mut self, verify_md5_checksums: bool) -> Self {
+        self.verify_md5_checksums = verify_md5_checksums;
+        self
+    }
+
+    /// Sets the template for generated read names.
+    ///
+    /// Read names are generated for records in data containers that do not preserve read names.
+    /// See [`ReadNameTemplate`].
+    ///
+    /// The default template generates read names from the 1-based record number only.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::{self as cram, reader::ReadNameTemplate};
+    ///
+    /// let template = ReadNameTemplate::new(String::from("sample"), String::from(":"));
+    /// let builder = cram::reader::Builder::default().set_read_name_template(template);
+    /// ```
+    pub fn set_read_name_template(mut self, read_name_template: ReadNameTemplate) -> Self {
+        self.read_name_template = read_name_template;
+        self
+    }
+
+    /// Builds a CRAM reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram as cram;
+    /// let reader = cram::reader::Builder::default().build_with_reader(&[][..]);
+    /// ```
+    pub fn build_with_reader<R>(self, reader: R) -> Reader<R>
+    where
+        R: Read,
+    {
+        Reader {
+            inner: reader,
+            buf: BytesMut::new(),
+            version: Version::default(),
+            try_recover: self.try_recover,
+            verify_md5_checksums: self.verify_md5_checksums,
+            read_name_template: self.read_name_template,
+        }
+    }
+}
+
+impl Default for Builder {
+    fn default() -> Self {
+        Self {
+            try_recover: false,
+            verify_md5_checksums: true,
+            read_name_template: ReadNameTemplate::default(),
+        }
+    }
+}
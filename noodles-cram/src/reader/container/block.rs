@@ -7,10 +7,11 @@ use crate::{
         block::{CompressionMethod, ContentId, ContentType},
         Block,
     },
+    file_definition::Version,
     reader::num::get_itf8,
 };
 
-pub fn read_block(src: &mut Bytes) -> io::Result<Block> {
+pub fn read_block(src: &mut Bytes, version: Version) -> io::Result<Block> {
     let original_src = src.clone();
 
     let method = get_compression_method(src)?;
@@ -31,22 +32,25 @@ pub fn read_block(src: &mut Bytes) -> io::Result<Block> {
 
     let data = src.split_to(size_in_bytes);
 
-    if src.remaining() < mem::size_of::<u32>() {
-        return Err(io::Error::from(io::ErrorKind::UnexpectedEof));
-    }
+    // CRAM 2.x blocks do not have a CRC32.
+    if version.major() >= 3 {
+        if src.remaining() < mem::size_of::<u32>() {
+            return Err(io::Error::from(io::ErrorKind::UnexpectedEof));
+        }
 
-    let end = original_src.len() - src.len();
-    let actual_crc32 = crc32(&original_src[..end]);
+        let end = original_src.len() - src.len();
+        let actual_crc32 = crc32(&original_src[..end]);
 
-    let expected_crc32 = src.get_u32_le();
+        let expected_crc32 = src.get_u32_le();
 
-    if actual_crc32 != expected_crc32 {
-        return Err(io::Error::new(
-            io::ErrorKind::InvalidData,
-            format!(
-                "container block checksum mismatch: expected {expected_crc32:08x}, got {actual_crc32:08x}"
-            ),
-        ));
+        if actual_crc32 != expected_crc32 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "container block checksum mismatch: expected {expected_crc32:08x}, got {actual_crc32:08x}"
+                ),
+            ));
+        }
     }
 
     let mut builder = Block::builder()
@@ -135,7 +139,32 @@ mod tests {
             0x6e, 0x64, 0x6c, 0x73, // data = b"ndls",
             0xd7, 0x12, 0x46, 0x3e, // CRC32 = 3e4612d7
         ]);
-        let actual = read_block(&mut data)?;
+        let actual = read_block(&mut data, Version::default())?;
+
+        let expected = Block::builder()
+            .set_compression_method(CompressionMethod::None)
+            .set_content_type(ContentType::ExternalData)
+            .set_content_id(ContentId::from(1))
+            .set_uncompressed_len(4)
+            .set_data(Bytes::from_static(b"ndls"))
+            .build();
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_block_with_cram_2_1() -> io::Result<()> {
+        let mut data = Bytes::from_static(&[
+            0x00, // compression method = none (0)
+            0x04, // content type = external data (4)
+            0x01, // block content ID = 1
+            0x04, // size in bytes = 4 bytes
+            0x04, // raw size in bytes = 4 bytes
+            0x6e, 0x64, 0x6c, 0x73, // data = b"ndls",
+        ]);
+        let actual = read_block(&mut data, Version::new(2, 1))?;
 
         let expected = Block::builder()
             .set_compression_method(CompressionMethod::None)
@@ -146,6 +175,7 @@ mod tests {
             .build();
 
         assert_eq!(actual, expected);
+        assert!(data.is_empty());
 
         Ok(())
     }
@@ -161,7 +191,7 @@ mod tests {
             // data = b"",
             0xbd, 0xac, 0x02, 0xbd, // CRC32 = bd02acbd
         ]);
-        let actual = read_block(&mut data)?;
+        let actual = read_block(&mut data, Version::default())?;
 
         let expected = Block::builder()
             .set_content_type(ContentType::ExternalData)
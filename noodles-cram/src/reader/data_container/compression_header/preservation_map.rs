@@ -128,10 +128,24 @@ fn get_tag_ids_dictionary(src: &mut Bytes) -> io::Result<TagIdsDictionary> {
 
     let mut dictionary = Vec::new();
 
-    while let Some(i) = buf.iter().position(|&b| b == NUL) {
+    while buf.has_remaining() {
+        let i = buf.iter().position(|&b| b == NUL).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                "unterminated tag IDs dictionary line",
+            )
+        })?;
+
         let keys_buf = buf.split_to(i);
         buf.advance(1); // Discard the NUL terminator.
 
+        if keys_buf.len() % 3 != 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "invalid tag IDs dictionary line length",
+            ));
+        }
+
         let mut line = Vec::new();
 
         for chunk in keys_buf.chunks_exact(3) {
@@ -224,6 +238,53 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_get_tag_ids_dictionary() -> io::Result<()> {
+        let mut src = Bytes::from_static(&[
+            0x0a, // data.len = 10
+            0x00, // line 0 = []
+            0x4e, 0x48, 0x43, 0x43, 0x4f, 0x5a, 0x00, // line 1 = [NH:C, CO:Z]
+            0x00, // line 2 = []
+            0x00, // line 3 = []
+        ]);
+
+        let actual = get_tag_ids_dictionary(&mut src)?;
+
+        let expected = TagIdsDictionary::from(vec![
+            Vec::new(),
+            vec![
+                tag_ids_dictionary::Key::new(Tag::AlignmentHitCount, Type::UInt8),
+                tag_ids_dictionary::Key::new(Tag::Comment, Type::String),
+            ],
+            Vec::new(),
+            Vec::new(),
+        ]);
+
+        assert_eq!(actual, expected);
+
+        let mut src = Bytes::from_static(&[
+            0x03, // data.len = 3
+            0x4e, 0x48, 0x00, // line 0 = [NH]
+        ]);
+
+        assert!(matches!(
+            get_tag_ids_dictionary(&mut src),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData,
+        ));
+
+        let mut src = Bytes::from_static(&[
+            0x03, // data.len = 3
+            0x4e, 0x48, 0x43, // line 0 = [NH:C] (missing NUL terminator)
+        ]);
+
+        assert!(matches!(
+            get_tag_ids_dictionary(&mut src),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData,
+        ));
+
+        Ok(())
+    }
+
     #[test]
     fn test_get_bool() -> io::Result<()> {
         let data = [0x00];
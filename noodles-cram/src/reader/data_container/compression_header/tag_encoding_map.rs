@@ -32,3 +32,54 @@ pub fn get_tag_encoding_map(src: &mut Bytes) -> io::Result<TagEncodingMap> {
 
     Ok(TagEncodingMap::from(map))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data_container::compression_header::{
+        encoding::codec::{Byte, ByteArray, Integer},
+        Encoding,
+    };
+
+    #[test]
+    fn test_get_tag_encoding_map_with_fixed_size_values() -> io::Result<()> {
+        // As written by htslib for CRAM 3.0: the lengths of fixed-size values are Huffman-encoded
+        // constants, and the values are stored in external blocks keyed by their tag IDs.
+        let mut src = Bytes::from_static(&[
+            0x25, // data_len = 37
+            0x02, // map_len = 2
+            0xe0, 0x4e, 0x48, 0x43, // key = NH:C
+            0x04, 0x0c, // byte array length encoding
+            0x03, 0x04, 0x01, 0x01, 0x01, 0x00, // len_encoding = Huffman([1], [0])
+            0x01, 0x04, 0xe0, 0x4e, 0x48, 0x43, // value_encoding = External(NH:C)
+            0xe0, 0x58, 0x46, 0x66, // key = XF:f
+            0x04, 0x0c, // byte array length encoding
+            0x03, 0x04, 0x01, 0x04, 0x01, 0x00, // len_encoding = Huffman([4], [0])
+            0x01, 0x04, 0xe0, 0x58, 0x46, 0x66, // value_encoding = External(XF:f)
+        ]);
+
+        let actual = get_tag_encoding_map(&mut src)?;
+
+        let t = |id: i32, len: i32| {
+            let id = block::ContentId::from(id);
+
+            (
+                id,
+                Encoding::new(ByteArray::ByteArrayLen(
+                    Encoding::new(Integer::Huffman(vec![len], vec![0])),
+                    Encoding::new(Byte::External(id)),
+                )),
+            )
+        };
+
+        let expected = TagEncodingMap::from(
+            [t(0x4e4843, 1), t(0x584666, 4)]
+                .into_iter()
+                .collect::<HashMap<_, _>>(),
+        );
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+}
@@ -9,7 +9,12 @@ use noodles_core::Position;
 
 use crate::{
     data_container::{Header, ReferenceSequenceContext},
-    reader::num::{read_itf8, read_ltf8},
+    file_definition::Version,
+    reader::{
+        num::{read_itf8, read_ltf8},
+        truncation_error::into_truncated_container_error,
+        TruncationError,
+    },
 };
 
 // § 9 "End of file container" (2022-04-12)
@@ -19,25 +24,70 @@ const EOF_ALIGNMENT_START: i32 = 4_542_278;
 const EOF_BLOCK_COUNT: usize = 1;
 const EOF_CRC32: u32 = 0x4f_d9_bd_05;
 
-pub fn read_header<R>(reader: &mut R) -> io::Result<Option<Header>>
+// The CRAM 2.1 EOF container is the same but without the block CRC32.
+const EOF_LENGTH_2_1: usize = 11;
+
+pub fn read_header<R>(reader: &mut R, version: Version) -> io::Result<Option<Header>>
 where
     R: Read,
 {
     let mut crc_reader = CrcReader::new(reader);
 
-    let length = crc_reader.read_i32::<LittleEndian>().and_then(|n| {
-        usize::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
-    })?;
+    let length = match read_length(&mut crc_reader)? {
+        Some(n) => usize::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?,
+        None => return Err(TruncationError::MissingEofContainer.into()),
+    };
+
+    read_header_fields(crc_reader, length, version).map_err(into_truncated_container_error)
+}
+
+// The stream is at EOF if no bytes are read.
+fn read_length<R>(reader: &mut R) -> io::Result<Option<i32>>
+where
+    R: Read,
+{
+    let mut buf = [0; 4];
+    let mut n = 0;
+
+    while n < buf.len() {
+        match reader.read(&mut buf[n..]) {
+            Ok(0) => break,
+            Ok(m) => n += m,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+            Err(e) => return Err(e),
+        }
+    }
 
+    match n {
+        0 => Ok(None),
+        4 => Ok(Some(i32::from_le_bytes(buf))),
+        _ => Err(TruncationError::TruncatedContainer.into()),
+    }
+}
+
+fn read_header_fields<R>(
+    mut crc_reader: CrcReader<R>,
+    length: usize,
+    version: Version,
+) -> io::Result<Option<Header>>
+where
+    R: Read,
+{
     let reference_sequence_id = read_itf8(&mut crc_reader)?;
     let alignment_start = read_itf8(&mut crc_reader)?;
     let alignment_span = read_itf8(&mut crc_reader)?;
 
     let number_of_records = read_itf8(&mut crc_reader)?;
 
-    let record_counter = read_ltf8(&mut crc_reader).and_then(|n| {
-        u64::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
-    })?;
+    // CRAM 2.x record counters are ITF8-encoded.
+    let record_counter = if version.major() >= 3 {
+        read_ltf8(&mut crc_reader)?
+    } else {
+        read_itf8(&mut crc_reader).map(i64::from)?
+    };
+
+    let record_counter =
+        u64::try_from(record_counter).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
 
     let bases = read_ltf8(&mut crc_reader).and_then(|n| {
         u64::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
@@ -51,25 +101,37 @@ where
 
     let actual_crc32 = crc_reader.crc().sum();
 
-    let reader = crc_reader.into_inner();
-    let expected_crc32 = reader.read_u32::<LittleEndian>()?;
+    let mut reader = crc_reader.into_inner();
 
-    if actual_crc32 != expected_crc32 {
-        return Err(io::Error::new(
-            io::ErrorKind::InvalidData,
-            format!(
-                "container header checksum mismatch: expected {expected_crc32:08x}, got {actual_crc32:08x}"
-            ),
-        ));
-    }
+    // CRAM 2.x container headers do not have a CRC32.
+    let crc32 = if version.major() >= 3 {
+        let expected_crc32 = reader.read_u32::<LittleEndian>()?;
+
+        if actual_crc32 != expected_crc32 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "container header checksum mismatch: expected {expected_crc32:08x}, got {actual_crc32:08x}"
+                ),
+            ));
+        }
+
+        Some(expected_crc32)
+    } else {
+        None
+    };
 
     if is_eof(
         length,
         reference_sequence_id,
         alignment_start,
         number_of_blocks,
-        expected_crc32,
+        crc32,
     ) {
+        // The EOF container block is discarded but read to check that the stream is complete.
+        let mut buf = vec![0; length];
+        reader.read_exact(&mut buf)?;
+
         return Ok(None);
     }
 
@@ -110,18 +172,25 @@ where
     Ok(buf)
 }
 
+/// Returns whether the container header is an EOF container header.
+///
+/// The CRC32 is `None` for CRAM 2.x container headers.
 pub(crate) fn is_eof(
     length: usize,
     reference_sequence_id: i32,
     alignment_start: i32,
     block_count: usize,
-    crc32: u32,
+    crc32: Option<u32>,
 ) -> bool {
-    length == EOF_LENGTH
+    let is_eof_length_and_crc32 = match crc32 {
+        Some(crc32) => length == EOF_LENGTH && crc32 == EOF_CRC32,
+        None => length == EOF_LENGTH_2_1,
+    };
+
+    is_eof_length_and_crc32
         && reference_sequence_id == EOF_REFERENCE_SEQUENCE_ID
         && alignment_start == EOF_ALIGNMENT_START
         && block_count == EOF_BLOCK_COUNT
-        && crc32 == EOF_CRC32
 }
 
 pub(crate) fn build_reference_sequence_context(
@@ -181,7 +250,7 @@ mod tests {
             0x21, 0xf7, 0x9c, 0xed, // CRC32
         ];
         let mut reader = &data[..];
-        let actual = read_header(&mut reader)?;
+        let actual = read_header(&mut reader, Version::default())?;
 
         let expected = Header::builder()
             .set_length(144)
@@ -215,11 +284,75 @@ mod tests {
             0x01, // number of blocks = 1
             0x00, // landmark count = 0
             0x05, 0xbd, 0xd9, 0x4f, // CRC32
+            0x00, 0x01, 0x00, 0x06, 0x06, 0x01, 0x00, 0x01, 0x00, 0x01, 0x00, 0xee, 0x63, 0x01,
+            0x4b, // block
+        ];
+        let mut reader = &data[..];
+        let actual = read_header(&mut reader, Version::default())?;
+
+        assert!(actual.is_none());
+        assert!(reader.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_header_with_cram_2_1() -> Result<(), Box<dyn std::error::Error>> {
+        let data = [
+            0x90, 0x00, 0x00, 0x00, // length = 144 bytes
+            0x02, // reference sequence ID = 2
+            0x03, // starting position on the reference = 3
+            0x05, // alignment span = 5
+            0x08, // number of records = 8
+            0x80, 0x80, // record counter = 128 (ITF8)
+            0x15, // bases = 21
+            0x22, // number of blocks = 34
+            0x02, // landmark count = 2
+            0x37, // landmarks[0] = 55
+            0x59, // landmarks[1] = 89
+        ];
+        let mut reader = &data[..];
+        let actual = read_header(&mut reader, Version::new(2, 1))?;
+
+        let expected = Header::builder()
+            .set_length(144)
+            .set_reference_sequence_context(ReferenceSequenceContext::some(
+                2,
+                Position::try_from(3)?,
+                Position::try_from(7)?,
+            ))
+            .set_record_count(8)
+            .set_record_counter(128)
+            .set_base_count(21)
+            .set_block_count(34)
+            .set_landmarks(vec![55, 89])
+            .build();
+
+        assert_eq!(actual, Some(expected));
+        assert!(reader.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_header_with_cram_2_1_eof() -> io::Result<()> {
+        let data = [
+            0x0b, 0x00, 0x00, 0x00, // length = 11 bytes
+            0xff, 0xff, 0xff, 0xff, 0x0f, // reference sequence ID = None (-1)
+            0xe0, 0x45, 0x4f, 0x46, // starting position on the reference = 4542278
+            0x00, // alignment span = 0
+            0x00, // number of records = 0
+            0x00, // record counter = 0
+            0x00, // bases = 0
+            0x01, // number of blocks = 1
+            0x00, // landmark count = 0
+            0x00, 0x01, 0x00, 0x06, 0x06, 0x01, 0x00, 0x01, 0x00, 0x01, 0x00, // block
         ];
         let mut reader = &data[..];
-        let actual = read_header(&mut reader)?;
+        let actual = read_header(&mut reader, Version::new(2, 1))?;
 
         assert!(actual.is_none());
+        assert!(reader.is_empty());
 
         Ok(())
     }
@@ -242,7 +375,7 @@ mod tests {
         let mut reader = &data[..];
 
         assert!(matches!(
-            read_header(&mut reader),
+            read_header(&mut reader, Version::default()),
             Err(e) if e.kind() == io::ErrorKind::InvalidData,
         ));
     }
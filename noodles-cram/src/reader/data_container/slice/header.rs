@@ -6,10 +6,11 @@ use noodles_core::Position;
 use crate::{
     container::block,
     data_container::{slice, ReferenceSequenceContext},
+    file_definition::Version,
     reader::num::{get_itf8, get_ltf8},
 };
 
-pub fn get_header<B>(src: &mut B) -> io::Result<slice::Header>
+pub fn get_header<B>(src: &mut B, version: Version) -> io::Result<slice::Header>
 where
     B: Buf,
 {
@@ -19,9 +20,15 @@ where
         usize::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
     })?;
 
-    let record_counter = get_ltf8(src).and_then(|n| {
-        u64::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
-    })?;
+    // CRAM 2.x record counters are ITF8-encoded.
+    let record_counter = if version.major() >= 3 {
+        get_ltf8(src)?
+    } else {
+        get_itf8(src).map(i64::from)?
+    };
+
+    let record_counter =
+        u64::try_from(record_counter).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
 
     let block_count = get_itf8(src).and_then(|n| {
         usize::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
@@ -174,7 +181,7 @@ mod tests {
             0x7e, 0xf7, // reference MD5 (b"ACGTA")
         ];
         let mut reader = &data[..];
-        let actual = get_header(&mut reader)?;
+        let actual = get_header(&mut reader, Version::default())?;
 
         let expected = slice::Header::builder()
             .set_reference_sequence_context(ReferenceSequenceContext::some(
@@ -196,4 +203,30 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_get_header_with_cram_2_1() -> Result<(), Box<dyn std::error::Error>> {
+        let data = [
+            0x02, // reference sequence ID = 2
+            0x03, // alignment start = 3
+            0x05, // alignment span = 5
+            0x08, // number of records = 8
+            0x80, 0x80, // record counter = 128 (ITF8)
+            0x01, // number of blocks = 1
+            0x01, // block content ID count = 1
+            0x15, // block content IDs[0] = 21
+            0xff, 0xff, 0xff, 0xff, 0x0f, // embedded reference bases block content ID = -1
+            0x57, 0xb2, 0x96, 0xa3, 0x16, 0x0a, 0x2c, 0xac, 0x9c, 0x83, 0x33, 0x12, 0x6f, 0xf2,
+            0x7e, 0xf7, // reference MD5 (b"ACGTA")
+        ];
+        let mut reader = &data[..];
+        let actual = get_header(&mut reader, Version::new(2, 1))?;
+
+        assert_eq!(actual.record_count(), 8);
+        assert_eq!(actual.record_counter(), 128);
+        assert_eq!(actual.block_count(), 1);
+        assert!(reader.is_empty());
+
+        Ok(())
+    }
 }
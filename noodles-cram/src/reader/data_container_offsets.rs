@@ -0,0 +1,70 @@
+use std::io::{self, Read, Seek, SeekFrom};
+
+use super::Reader;
+
+/// An iterator over the offsets of data containers of a CRAM reader.
+///
+/// Only container headers are read. Container bodies are skipped.
+///
+/// This is created by calling [`Reader::data_container_offsets`].
+pub struct DataContainerOffsets<'a, R> {
+    reader: &'a mut Reader<R>,
+    is_eof: bool,
+}
+
+impl<'a, R> DataContainerOffsets<'a, R>
+where
+    R: Read + Seek,
+{
+    pub(crate) fn new(reader: &'a mut Reader<R>) -> Self {
+        Self {
+            reader,
+            is_eof: false,
+        }
+    }
+
+    fn read_data_container_offset(&mut self) -> io::Result<Option<u64>> {
+        use super::data_container::header::read_header;
+
+        let offset = self.reader.position()?;
+
+        let result = read_header(&mut self.reader.inner, self.reader.version);
+
+        let header = match self.reader.recover(result)? {
+            Some(header) => header,
+            None => return Ok(None),
+        };
+
+        let len = i64::try_from(header.len())
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        self.reader.inner.seek(SeekFrom::Current(len))?;
+
+        Ok(Some(offset))
+    }
+}
+
+impl<'a, R> Iterator for DataContainerOffsets<'a, R>
+where
+    R: Read + Seek,
+{
+    type Item = io::Result<u64>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.is_eof {
+            return None;
+        }
+
+        match self.read_data_container_offset() {
+            Ok(Some(offset)) => Some(Ok(offset)),
+            Ok(None) => {
+                self.is_eof = true;
+                None
+            }
+            Err(e) => {
+                self.is_eof = true;
+                Some(Err(e))
+            }
+        }
+    }
+}
@@ -0,0 +1,239 @@
+use std::{
+    io::{self, Read},
+    vec,
+};
+
+use noodles_fasta as fasta;
+use noodles_fastq as fastq;
+use noodles_sam as sam;
+
+use super::Reader;
+use crate::Record;
+
+// samtools-fastq(1): "-v INT: default quality score if not given in file [1]"
+const DEFAULT_QUALITY_SCORE: u8 = 1;
+
+/// An iterator over records of a CRAM reader as FASTQ records.
+///
+/// Slices that only have unmapped records are decoded using a fast path that only reads the
+/// read names, bases, and quality scores. Positions, mate data, and tags are skipped. Other
+/// slices, e.g., slices with mapped records, are fully decoded and resolved.
+///
+/// Secondary and supplementary records are skipped. Reverse complemented records are returned in
+/// their original orientation, and records without quality scores are given a default quality
+/// score of 1, consistent with `samtools fastq`.
+///
+/// This is created by calling [`Reader::fastq_records`].
+pub struct FastqRecords<'a, R>
+where
+    R: Read,
+{
+    reader: &'a mut Reader<R>,
+    reference_sequence_repository: &'a fasta::Repository,
+    header: &'a sam::Header,
+    records: vec::IntoIter<fastq::Record>,
+}
+
+impl<'a, R> FastqRecords<'a, R>
+where
+    R: Read,
+{
+    pub(crate) fn new(
+        reader: &'a mut Reader<R>,
+        reference_sequence_repository: &'a fasta::Repository,
+        header: &'a sam::Header,
+    ) -> Self {
+        Self {
+            reader,
+            reference_sequence_repository,
+            header,
+            records: Vec::new().into_iter(),
+        }
+    }
+
+    fn read_container_records(&mut self) -> io::Result<bool> {
+        let container = match self.reader.read_data_container()? {
+            Some(c) => c,
+            None => return Ok(true),
+        };
+
+        let verify_md5_checksums = self.reader.verify_md5_checksums;
+        let read_name_template = &self.reader.read_name_template;
+
+        self.records = container
+            .slices()
+            .iter()
+            .map(|slice| {
+                let compression_header = container.compression_header();
+
+                let records = match slice.unmapped_sequence_records(
+                    compression_header,
+                    verify_md5_checksums,
+                    read_name_template,
+                )? {
+                    Some(records) => records,
+                    None => {
+                        let mut records = slice.records(compression_header)?;
+
+                        slice.resolve_records_with_verification(
+                            self.reference_sequence_repository,
+                            self.header,
+                            compression_header,
+                            &mut records,
+                            verify_md5_checksums,
+                            read_name_template,
+                        )?;
+
+                        records
+                    }
+                };
+
+                Ok(records)
+            })
+            .collect::<io::Result<Vec<_>>>()?
+            .into_iter()
+            .flatten()
+            .filter(|record| {
+                let flags = record.flags();
+                !flags.is_secondary() && !flags.is_supplementary()
+            })
+            .map(build_fastq_record)
+            .collect::<Vec<_>>()
+            .into_iter();
+
+        Ok(false)
+    }
+}
+
+impl<'a, R> Iterator for FastqRecords<'a, R>
+where
+    R: Read,
+{
+    type Item = io::Result<fastq::Record>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.records.next() {
+                Some(r) => return Some(Ok(r)),
+                None => match self.read_container_records() {
+                    Ok(true) => return None,
+                    Ok(false) => {}
+                    Err(e) => return Some(Err(e)),
+                },
+            }
+        }
+    }
+}
+
+fn build_fastq_record(record: Record) -> fastq::Record {
+    use sam::record::read_name::MISSING;
+
+    let name = record
+        .read_name()
+        .cloned()
+        .map(Vec::from)
+        .unwrap_or_else(|| MISSING.to_vec());
+
+    let mut sequence: Vec<_> = record
+        .bases()
+        .as_ref()
+        .iter()
+        .map(|&base| u8::from(base))
+        .collect();
+
+    let mut quality_scores: Vec<_> = if record.quality_scores().is_empty() {
+        vec![DEFAULT_QUALITY_SCORE; sequence.len()]
+    } else {
+        record
+            .quality_scores()
+            .as_ref()
+            .iter()
+            .map(|&score| u8::from(score))
+            .collect()
+    };
+
+    if record.flags().is_reverse_complemented() {
+        sequence.reverse();
+
+        for base in &mut sequence {
+            *base = complement(*base);
+        }
+
+        quality_scores.reverse();
+    }
+
+    // Quality scores are written using Phred+33 encoding.
+    for score in &mut quality_scores {
+        *score += b'!';
+    }
+
+    fastq::Record::new(name, sequence, quality_scores)
+}
+
+fn complement(base: u8) -> u8 {
+    match base {
+        b'A' => b'T',
+        b'C' => b'G',
+        b'G' => b'C',
+        b'T' => b'A',
+        b'R' => b'Y',
+        b'Y' => b'R',
+        b'K' => b'M',
+        b'M' => b'K',
+        b'B' => b'V',
+        b'V' => b'B',
+        b'D' => b'H',
+        b'H' => b'D',
+        b => b,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_fastq_record() -> Result<(), Box<dyn std::error::Error>> {
+        use sam::record::{quality_scores::Score, Flags};
+
+        let record = Record::builder()
+            .set_bam_flags(Flags::UNMAPPED)
+            .set_read_name("r0".parse()?)
+            .set_read_length(4)
+            .set_bases("ACGN".parse()?)
+            .set_quality_scores(sam::record::QualityScores::from(vec![
+                Score::try_from(0)?,
+                Score::try_from(10)?,
+                Score::try_from(20)?,
+                Score::try_from(30)?,
+            ]))
+            .build();
+
+        assert_eq!(
+            build_fastq_record(record),
+            fastq::Record::new("r0", "ACGN", "!+5?")
+        );
+
+        let record = Record::builder()
+            .set_bam_flags(Flags::UNMAPPED | Flags::REVERSE_COMPLEMENTED)
+            .set_read_name("r1".parse()?)
+            .set_read_length(4)
+            .set_bases("AACG".parse()?)
+            .build();
+
+        assert_eq!(
+            build_fastq_record(record),
+            fastq::Record::new("r1", "CGTT", "\"\"\"\"")
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_complement() {
+        assert_eq!(complement(b'A'), b'T');
+        assert_eq!(complement(b'G'), b'C');
+        assert_eq!(complement(b'N'), b'N');
+        assert_eq!(complement(b'='), b'=');
+    }
+}
@@ -8,28 +8,35 @@ use std::{
 use bytes::{Buf, Bytes, BytesMut};
 
 use self::header::read_header;
-use crate::container::{
-    block::{CompressionMethod, ContentType},
-    Block,
+use crate::{
+    container::{
+        block::{CompressionMethod, ContentType},
+        Block,
+    },
+    file_definition::Version,
 };
 
-pub fn read_header_container<R>(reader: &mut R, buf: &mut BytesMut) -> io::Result<String>
+pub fn read_header_container<R>(
+    reader: &mut R,
+    buf: &mut BytesMut,
+    version: Version,
+) -> io::Result<String>
 where
     R: Read,
 {
-    let len = read_header(reader)?;
+    let len = read_header(reader, version)?;
 
     buf.resize(len, 0);
     reader.read_exact(buf)?;
     let mut buf = buf.split().freeze();
 
-    read_raw_sam_header_from_block(&mut buf)
+    read_raw_sam_header_from_block(&mut buf, version)
 }
 
-pub fn read_raw_sam_header_from_block(src: &mut Bytes) -> io::Result<String> {
+pub fn read_raw_sam_header_from_block(src: &mut Bytes, version: Version) -> io::Result<String> {
     use super::container::read_block;
 
-    let block = read_block(src)?;
+    let block = read_block(src, version)?;
     read_raw_sam_header(&block)
 }
 
@@ -0,0 +1,249 @@
+use std::{
+    collections::VecDeque,
+    io::{self, Read},
+    num::NonZeroUsize,
+    thread::{self, JoinHandle},
+    vec,
+};
+
+use crossbeam_channel::{Receiver, Sender};
+use noodles_fasta as fasta;
+use noodles_sam as sam;
+
+use super::Reader;
+use crate::{DataContainer, Record};
+
+type Decoded = io::Result<(DataContainer, Vec<Vec<Record>>)>;
+type DecodedTx = Sender<Decoded>;
+type DecodedRx = Receiver<Decoded>;
+type DecoderTx = Sender<(DataContainer, DecodedTx)>;
+type DecoderRx = Receiver<(DataContainer, DecodedTx)>;
+
+/// An iterator over records of a CRAM reader that are decoded in parallel.
+///
+/// Data containers are read on the calling thread, and their slices are decoded on a pool of
+/// worker threads. Records are resolved (e.g., bases are restored from the reference sequence) on
+/// the calling thread, as the reference sequence repository is not shareable across threads.
+/// Records are returned in input order.
+///
+/// This is created by calling [`Reader::parallel_records`].
+pub struct ParallelRecords<'a, R> {
+    reader: &'a mut Reader<R>,
+    reference_sequence_repository: &'a fasta::Repository,
+    header: &'a sam::Header,
+    decoder_tx: Option<DecoderTx>,
+    decoder_handles: Vec<JoinHandle<()>>,
+    queue: VecDeque<DecodedRx>,
+    queue_capacity: usize,
+    records: vec::IntoIter<Record>,
+    is_eof: bool,
+}
+
+impl<'a, R> ParallelRecords<'a, R>
+where
+    R: Read,
+{
+    pub(crate) fn new(
+        reader: &'a mut Reader<R>,
+        reference_sequence_repository: &'a fasta::Repository,
+        header: &'a sam::Header,
+        worker_count: NonZeroUsize,
+    ) -> Self {
+        let worker_count = worker_count.get();
+
+        let (decoder_tx, decoder_rx) = crossbeam_channel::bounded(worker_count);
+        let decoder_handles = spawn_decoders(worker_count, decoder_rx);
+
+        // Keep each worker busy while the next data container is read.
+        let queue_capacity = 2 * worker_count;
+
+        Self {
+            reader,
+            reference_sequence_repository,
+            header,
+            decoder_tx: Some(decoder_tx),
+            decoder_handles,
+            queue: VecDeque::with_capacity(queue_capacity),
+            queue_capacity,
+            records: Vec::new().into_iter(),
+            is_eof: false,
+        }
+    }
+
+    fn fill_queue(&mut self) -> io::Result<()> {
+        while self.queue.len() < self.queue_capacity && !self.is_eof {
+            let (decoded_tx, decoded_rx) = crossbeam_channel::bounded(1);
+
+            match self.reader.read_data_container() {
+                Ok(Some(data_container)) => {
+                    let decoder_tx = self.decoder_tx.as_ref().ok_or_else(disconnected)?;
+                    decoder_tx
+                        .send((data_container, decoded_tx))
+                        .map_err(|_| disconnected())?;
+                }
+                Ok(None) => {
+                    self.is_eof = true;
+                    break;
+                }
+                Err(e) => {
+                    // The error is queued to keep the records before it in order.
+                    self.is_eof = true;
+                    decoded_tx.send(Err(e)).map_err(|_| disconnected())?;
+                }
+            }
+
+            self.queue.push_back(decoded_rx);
+        }
+
+        Ok(())
+    }
+
+    fn stop(&mut self) {
+        self.is_eof = true;
+        self.queue.clear();
+        self.records = Vec::new().into_iter();
+    }
+
+    fn resolve_records(
+        &self,
+        data_container: &DataContainer,
+        slice_records: Vec<Vec<Record>>,
+    ) -> io::Result<Vec<Record>> {
+        let compression_header = data_container.compression_header();
+        let verify_md5_checksums = self.reader.verify_md5_checksums;
+        let read_name_template = &self.reader.read_name_template;
+        let mut records = Vec::new();
+
+        for (slice, mut slice_records) in data_container.slices().iter().zip(slice_records) {
+            slice.resolve_records_with_verification(
+                self.reference_sequence_repository,
+                self.header,
+                compression_header,
+                &mut slice_records,
+                verify_md5_checksums,
+                read_name_template,
+            )?;
+
+            records.extend(slice_records);
+        }
+
+        Ok(records)
+    }
+}
+
+impl<'a, R> Iterator for ParallelRecords<'a, R>
+where
+    R: Read,
+{
+    type Item = io::Result<Record>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(record) = self.records.next() {
+                return Some(Ok(record));
+            }
+
+            if let Err(e) = self.fill_queue() {
+                self.stop();
+                return Some(Err(e));
+            }
+
+            let decoded_rx = self.queue.pop_front()?;
+
+            let result = match decoded_rx.recv() {
+                Ok(result) => result.and_then(|(data_container, slice_records)| {
+                    self.resolve_records(&data_container, slice_records)
+                }),
+                Err(_) => {
+                    self.stop();
+                    return Some(Err(disconnected()));
+                }
+            };
+
+            match result {
+                Ok(records) => self.records = records.into_iter(),
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+}
+
+impl<'a, R> Drop for ParallelRecords<'a, R> {
+    fn drop(&mut self) {
+        self.decoder_tx.take();
+        self.queue.clear();
+
+        for handle in self.decoder_handles.drain(..) {
+            let _ = handle.join();
+        }
+    }
+}
+
+// A decoder worker stops early only if it panics.
+fn disconnected() -> io::Error {
+    io::Error::new(io::ErrorKind::Other, "data container decoder disconnected")
+}
+
+fn spawn_decoders(worker_count: usize, decoder_rx: DecoderRx) -> Vec<JoinHandle<()>> {
+    let mut handles = Vec::with_capacity(worker_count);
+
+    for _ in 0..worker_count {
+        let decoder_rx = decoder_rx.clone();
+
+        handles.push(thread::spawn(move || {
+            while let Ok((data_container, decoded_tx)) = decoder_rx.recv() {
+                let result = decode_data_container(&data_container)
+                    .map(|slice_records| (data_container, slice_records));
+
+                if decoded_tx.send(result).is_err() {
+                    continue;
+                }
+            }
+        }));
+    }
+
+    handles
+}
+
+fn decode_data_container(data_container: &DataContainer) -> io::Result<Vec<Vec<Record>>> {
+    let compression_header = data_container.compression_header();
+
+    data_container
+        .slices()
+        .iter()
+        .map(|slice| slice.records(compression_header))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::fixtures::{
+            build_multi_container_records, read_records, write_records,
+            MULTI_CONTAINER_RECORD_COUNT,
+        };
+
+        let header = sam::Header::default();
+        let records = build_multi_container_records()?;
+        let data = write_records(crate::writer::Builder::default(), &header, &records)?;
+
+        let repository = fasta::Repository::default();
+        let expected = read_records(&data, &repository, &header)?;
+
+        let mut reader = Reader::new(&data[..]);
+        reader.read_file_definition()?;
+        reader.read_file_header()?;
+        let worker_count = NonZeroUsize::try_from(3)?;
+        let actual: Vec<_> = reader
+            .parallel_records(&repository, &header, worker_count)
+            .collect::<io::Result<_>>()?;
+
+        assert_eq!(actual.len(), MULTI_CONTAINER_RECORD_COUNT);
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+}
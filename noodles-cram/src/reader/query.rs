@@ -3,7 +3,7 @@ use std::{
     slice, vec,
 };
 
-use noodles_core::region::Interval;
+use noodles_core::{region::Interval, Position};
 use noodles_fasta as fasta;
 use noodles_sam as sam;
 
@@ -27,6 +27,7 @@ where
     reference_sequence_id: usize,
     interval: Interval,
 
+    last_container_offset: Option<u64>,
     records: vec::IntoIter<Record>,
 }
 
@@ -53,6 +54,7 @@ where
             reference_sequence_id,
             interval,
 
+            last_container_offset: None,
             records: Vec::new().into_iter(),
         }
     }
@@ -60,10 +62,18 @@ where
     fn read_next_container(&mut self) -> Option<io::Result<()>> {
         let index_record = self.index.next()?;
 
-        if index_record.reference_sequence_id() != Some(self.reference_sequence_id) {
+        if !intersects(index_record, self.reference_sequence_id, self.interval) {
             return Some(Ok(()));
         }
 
+        // An index has a record for each slice, and a container can have many slices. All of the
+        // records of a container are read at once, so it only needs to be read once.
+        if self.last_container_offset == Some(index_record.offset()) {
+            return Some(Ok(()));
+        }
+
+        self.last_container_offset = Some(index_record.offset());
+
         if let Err(e) = self.reader.seek(SeekFrom::Start(index_record.offset())) {
             return Some(Err(e));
         }
@@ -74,6 +84,9 @@ where
             Err(e) => return Some(Err(e)),
         };
 
+        let verify_md5_checksums = self.reader.verify_md5_checksums;
+        let read_name_template = &self.reader.read_name_template;
+
         let records = container
             .slices()
             .iter()
@@ -81,11 +94,13 @@ where
                 let compression_header = container.compression_header();
 
                 slice.records(compression_header).and_then(|mut records| {
-                    slice.resolve_records(
+                    slice.resolve_records_with_verification(
                         self.reference_sequence_repository,
                         self.header,
                         compression_header,
                         &mut records,
+                        verify_md5_checksums,
+                        read_name_template,
                     )?;
 
                     Ok(records)
@@ -118,6 +133,11 @@ where
         loop {
             match self.records.next() {
                 Some(r) => {
+                    // Records in multi-reference slices can map to other reference sequences.
+                    if r.reference_sequence_id() != Some(self.reference_sequence_id) {
+                        continue;
+                    }
+
                     if let (Some(start), Some(end)) = (r.alignment_start(), r.alignment_end()) {
                         let alignment_interval = (start..=end).into();
 
@@ -135,3 +155,124 @@ where
         }
     }
 }
+
+pub(crate) fn intersects(
+    record: &crai::Record,
+    reference_sequence_id: usize,
+    interval: Interval,
+) -> bool {
+    if record.reference_sequence_id() != Some(reference_sequence_id) {
+        return false;
+    }
+
+    let start = match record.alignment_start() {
+        Some(start) => start,
+        None => return false,
+    };
+
+    let end = usize::from(start)
+        .checked_add(record.alignment_span())
+        .and_then(|n| n.checked_sub(1))
+        .and_then(Position::new)
+        .unwrap_or(start);
+
+    interval.intersects((start..=end).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_intersects() -> Result<(), Box<dyn std::error::Error>> {
+        let record = crai::Record::new(Some(0), Position::new(8), 5, 0, 0, 0);
+
+        let interval = (Position::try_from(10)?..=Position::try_from(20)?).into();
+        assert!(intersects(&record, 0, interval));
+        assert!(!intersects(&record, 1, interval));
+
+        let interval = (Position::try_from(13)?..=Position::try_from(20)?).into();
+        assert!(!intersects(&record, 0, interval));
+
+        let interval = (Position::try_from(1)?..=Position::try_from(8)?).into();
+        assert!(intersects(&record, 0, interval));
+
+        let record = crai::Record::new(None, None, 0, 0, 0, 0);
+        assert!(!intersects(&record, 0, Interval::from(..)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_next_with_multi_reference_slice() -> Result<(), Box<dyn std::error::Error>> {
+        use std::num::NonZeroUsize;
+
+        use fasta::record::{Definition, Sequence};
+        use sam::header::record::value::{map::ReferenceSequence, Map};
+
+        use crate::fixtures::write_records;
+
+        let repository = fasta::Repository::new(vec![
+            fasta::Record::new(
+                Definition::new("sq0", None),
+                Sequence::from(b"TTCACCCA".to_vec()),
+            ),
+            fasta::Record::new(
+                Definition::new("sq1", None),
+                Sequence::from(b"GGACGTTT".to_vec()),
+            ),
+        ]);
+
+        let header = sam::Header::builder()
+            .add_reference_sequence(
+                "sq0".parse()?,
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8)?),
+            )
+            .add_reference_sequence(
+                "sq1".parse()?,
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8)?),
+            )
+            .build();
+
+        // Both records are written to the same (multi-reference) slice.
+        let records = [("r0", 0), ("r1", 1)]
+            .into_iter()
+            .map(|(name, reference_sequence_id)| {
+                Ok(sam::alignment::Record::builder()
+                    .set_read_name(name.parse()?)
+                    .set_flags(sam::record::Flags::empty())
+                    .set_reference_sequence_id(reference_sequence_id)
+                    .set_alignment_start(Position::try_from(3)?)
+                    .set_cigar("2M".parse()?)
+                    .set_sequence("CA".parse()?)
+                    .set_quality_scores("NN".parse()?)
+                    .build())
+            })
+            .collect::<Result<Vec<_>, Box<dyn std::error::Error>>>()?;
+
+        let builder =
+            crate::writer::Builder::default().set_reference_sequence_repository(repository.clone());
+        let data = write_records(builder, &header, &records)?;
+
+        let mut reader = Reader::new(io::Cursor::new(data));
+        reader.read_file_definition()?;
+        reader.read_file_header()?;
+        let offset = reader.position()?;
+
+        let index = vec![
+            crai::Record::new(Some(0), Position::new(3), 2, offset, 0, 0),
+            crai::Record::new(Some(1), Position::new(3), 2, offset, 0, 0),
+        ];
+
+        let region = "sq1:1-8".parse()?;
+        let records: Vec<_> = reader
+            .query(&repository, &header, &index, &region)?
+            .collect::<io::Result<_>>()?;
+
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].read_name(), Some(&"r1".parse()?));
+        assert_eq!(records[0].reference_sequence_id(), Some(1));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,105 @@
+use std::io;
+
+use noodles_sam as sam;
+
+/// A template for generated read names.
+///
+/// When read names are not preserved in a data container (`RN` = `false`), read names are
+/// generated from the record counter, i.e., the 1-based position of the record in the file. This
+/// is consistent with samtools (htslib), which names such a record `{prefix}:{n}`.
+///
+/// A generated read name is `{prefix}{delimiter}{n}`. If the prefix is empty, the read name is
+/// only the record number `{n}`.
+///
+/// Mates in the same slice share the read name of the first segment. Read names of records with
+/// mates in other slices (detached records) are always stored and are never generated.
+///
+/// The default template has an empty prefix and the delimiter `:`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReadNameTemplate {
+    prefix: String,
+    delimiter: String,
+}
+
+impl ReadNameTemplate {
+    /// Creates a read name template.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::reader::ReadNameTemplate;
+    /// let template = ReadNameTemplate::new(String::from("sample"), String::from(":"));
+    /// ```
+    pub fn new(prefix: String, delimiter: String) -> Self {
+        Self { prefix, delimiter }
+    }
+
+    /// Returns the prefix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::reader::ReadNameTemplate;
+    /// let template = ReadNameTemplate::new(String::from("sample"), String::from(":"));
+    /// assert_eq!(template.prefix(), "sample");
+    /// ```
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    /// Returns the delimiter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::reader::ReadNameTemplate;
+    /// let template = ReadNameTemplate::new(String::from("sample"), String::from(":"));
+    /// assert_eq!(template.delimiter(), ":");
+    /// ```
+    pub fn delimiter(&self) -> &str {
+        &self.delimiter
+    }
+
+    /// Generates a read name for the record with the given 0-based record counter.
+    pub(crate) fn generate(&self, record_counter: u64) -> io::Result<sam::record::ReadName> {
+        let n = record_counter + 1;
+
+        let s = if self.prefix.is_empty() {
+            n.to_string()
+        } else {
+            format!("{}{}{n}", self.prefix, self.delimiter)
+        };
+
+        s.parse()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))
+    }
+}
+
+impl Default for ReadNameTemplate {
+    fn default() -> Self {
+        Self::new(String::new(), String::from(":"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate() -> Result<(), Box<dyn std::error::Error>> {
+        let template = ReadNameTemplate::default();
+        assert_eq!(template.generate(0)?, "1".parse()?);
+        assert_eq!(template.generate(7)?, "8".parse()?);
+
+        let template = ReadNameTemplate::new(String::from("sample"), String::from("."));
+        assert_eq!(template.generate(0)?, "sample.1".parse()?);
+
+        let template = ReadNameTemplate::new(String::from("sample 1"), String::from(":"));
+        assert!(matches!(
+            template.generate(0),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+}
@@ -122,6 +122,49 @@ where
         Ok(record)
     }
 
+    /// Reads an unmapped record, decoding only its flags, read length, read name, distance to the
+    /// next fragment, bases, and quality scores.
+    ///
+    /// Positions, mate data (except read names of detached records), and tags are not decoded. The
+    /// caller must ensure that these data series are not read from the core data block and do not
+    /// share an external block with a decoded data series.
+    pub fn read_unmapped_sequence_record(&mut self) -> io::Result<Record> {
+        let bam_bit_flags = self.read_bam_bit_flags()?;
+        let cram_bit_flags = self.read_cram_bit_flags()?;
+
+        if !bam_bit_flags.is_unmapped() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "unexpected mapped record",
+            ));
+        }
+
+        let mut record = Record {
+            bam_bit_flags,
+            cram_bit_flags,
+            ..Default::default()
+        };
+
+        let read_length = self.read_read_length()?;
+        record.read_length = read_length;
+
+        self.read_read_names(&mut record)?;
+
+        if cram_bit_flags.is_detached() {
+            let preservation_map = self.compression_header.preservation_map();
+
+            if !preservation_map.read_names_included() {
+                record.read_name = self.read_read_name()?;
+            }
+        } else if cram_bit_flags.has_mate_downstream() {
+            record.distance_to_next_fragment = self.read_distance_to_next_fragment().map(Some)?;
+        }
+
+        self.read_unmapped_read(&mut record, cram_bit_flags, read_length)?;
+
+        Ok(record)
+    }
+
     fn read_bam_bit_flags(&mut self) -> io::Result<sam::record::Flags> {
         let encoding = self
             .compression_header
@@ -1194,4 +1237,77 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_read_tag_data_with_fixed_size_values() -> Result<(), Box<dyn std::error::Error>> {
+        use sam::record::data::field::{value::Type, Tag, Value};
+
+        use crate::data_container::compression_header::{
+            preservation_map::TagIdsDictionary, PreservationMap, TagEncodingMap,
+        };
+
+        let nh = tag_ids_dictionary::Key::new(Tag::AlignmentHitCount, Type::UInt8);
+        let xf = tag_ids_dictionary::Key::new(Tag::try_from(*b"XF")?, Type::Float);
+
+        let preservation_map = PreservationMap::builder()
+            .set_tag_ids_dictionary(TagIdsDictionary::from(vec![vec![nh, xf]]))
+            .build();
+
+        // As written by htslib for CRAM 3.0: the lengths of fixed-size values are constants that
+        // are not stored.
+        let fixed_size_encoding = |key: tag_ids_dictionary::Key, len| {
+            let id = block::ContentId::from(key);
+
+            (
+                id,
+                Encoding::new(ByteArray::ByteArrayLen(
+                    Encoding::new(Integer::Huffman(vec![len], vec![0])),
+                    Encoding::new(Byte::External(id)),
+                )),
+            )
+        };
+
+        let tag_encoding_map = TagEncodingMap::from(
+            [fixed_size_encoding(nh, 1), fixed_size_encoding(xf, 4)]
+                .into_iter()
+                .collect::<std::collections::HashMap<_, _>>(),
+        );
+
+        let compression_header = CompressionHeader::builder()
+            .set_preservation_map(preservation_map)
+            .set_tag_encoding_map(tag_encoding_map)
+            .build()?;
+
+        let core_data = [];
+        let tag_ids_data = [0x00];
+        let nh_data = [0x01];
+        let xf_data = [0x00, 0x00, 0x00, 0x3f]; // 0.5
+
+        let mut external_data_readers = ExternalDataReaders::new();
+        external_data_readers.insert(
+            block::ContentId::from(DataSeries::TagIds),
+            &tag_ids_data[..],
+        );
+        external_data_readers.insert(block::ContentId::from(nh), &nh_data[..]);
+        external_data_readers.insert(block::ContentId::from(xf), &xf_data[..]);
+
+        let mut reader = Reader::new(
+            &compression_header,
+            BitReader::new(&core_data[..]),
+            external_data_readers,
+            ReferenceSequenceContext::None,
+        );
+
+        let actual = reader.read_tag_data()?;
+        let expected: sam::record::Data = [
+            (Tag::AlignmentHitCount, Value::UInt8(1)),
+            (Tag::try_from(*b"XF")?, Value::Float(0.5)),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
 }
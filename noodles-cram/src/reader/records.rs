@@ -45,6 +45,9 @@ where
             None => return Ok(true),
         };
 
+        let verify_md5_checksums = self.reader.verify_md5_checksums;
+        let read_name_template = &self.reader.read_name_template;
+
         self.records = container
             .slices()
             .iter()
@@ -52,11 +55,13 @@ where
                 let compression_header = container.compression_header();
 
                 slice.records(compression_header).and_then(|mut records| {
-                    slice.resolve_records(
+                    slice.resolve_records_with_verification(
                         self.reference_sequence_repository,
                         self.header,
                         compression_header,
                         &mut records,
+                        verify_md5_checksums,
+                        read_name_template,
                     )?;
 
                     Ok(records)
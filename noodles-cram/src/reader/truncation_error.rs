@@ -0,0 +1,86 @@
+use std::{error, fmt, io};
+
+/// An error returned when a CRAM stream ends before its EOF container.
+///
+/// This is wrapped in an [`io::Error`] with the kind [`io::ErrorKind::UnexpectedEof`] and can be
+/// recovered using [`io::Error::get_ref`] and [`error::Error::downcast_ref`].
+///
+/// # Examples
+///
+/// ```
+/// use noodles_cram::{self as cram, reader::TruncationError};
+///
+/// let mut writer = cram::Writer::new(Vec::new());
+/// writer.write_file_definition()?;
+/// writer.write_file_header(&Default::default())?;
+///
+/// // The EOF container is not written.
+/// let data = writer.get_ref();
+///
+/// let mut reader = cram::Reader::new(&data[..]);
+/// reader.read_file_definition()?;
+/// reader.read_file_header()?;
+///
+/// let e = reader.read_data_container().err().unwrap();
+///
+/// assert_eq!(
+///     e.get_ref().and_then(|e| e.downcast_ref()),
+///     Some(&TruncationError::MissingEofContainer)
+/// );
+/// # Ok::<_, std::io::Error>(())
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TruncationError {
+    /// The stream ended at a container boundary but without an EOF container.
+    MissingEofContainer,
+    /// The stream ended in the middle of a container.
+    TruncatedContainer,
+}
+
+impl error::Error for TruncationError {}
+
+impl fmt::Display for TruncationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingEofContainer => f.write_str("missing EOF container"),
+            Self::TruncatedContainer => f.write_str("truncated container"),
+        }
+    }
+}
+
+impl From<TruncationError> for io::Error {
+    fn from(e: TruncationError) -> Self {
+        Self::new(io::ErrorKind::UnexpectedEof, e)
+    }
+}
+
+/// Converts an unexpected EOF error while reading a container to a truncated container error.
+pub(crate) fn into_truncated_container_error(e: io::Error) -> io::Error {
+    if e.kind() == io::ErrorKind::UnexpectedEof {
+        TruncationError::TruncatedContainer.into()
+    } else {
+        e
+    }
+}
+
+/// Returns whether the error is a [`TruncationError`].
+pub(crate) fn is_truncation_error(e: &io::Error) -> bool {
+    e.get_ref()
+        .map(|e| e.is::<TruncationError>())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_into_truncated_container_error() {
+        let e = into_truncated_container_error(io::Error::from(io::ErrorKind::UnexpectedEof));
+        assert!(is_truncation_error(&e));
+        assert_eq!(e.kind(), io::ErrorKind::UnexpectedEof);
+
+        let e = into_truncated_container_error(io::Error::from(io::ErrorKind::InvalidData));
+        assert!(!is_truncation_error(&e));
+    }
+}
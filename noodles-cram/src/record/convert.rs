@@ -9,6 +9,25 @@ use super::{Features, Flags, Record};
 
 impl Record {
     /// Converts an alignment record to a CRAM record.
+    ///
+    /// Alignment records are read by both the BAM and SAM readers. CIGAR operations are converted
+    /// to read features. If a mapped record is missing its sequence, the sequence is decoded as
+    /// unknown; and if it is missing either its sequence or quality scores, the quality scores are
+    /// stored as an array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram as cram;
+    /// use noodles_sam as sam;
+    ///
+    /// let header = sam::Header::default();
+    /// let alignment_record = sam::alignment::Record::default();
+    ///
+    /// let record = cram::Record::try_from_alignment_record(&header, &alignment_record)?;
+    /// assert_eq!(record.try_into_alignment_record(&header)?, alignment_record);
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
     pub fn try_from_alignment_record(
         header: &sam::Header,
         record: &sam::alignment::Record,
@@ -20,13 +39,30 @@ impl Record {
 
         let mut flags = Flags::default();
 
+        if !bam_flags.is_unmapped() {
+            if record.sequence().is_empty() {
+                flags.insert(Flags::DECODE_SEQUENCE_AS_UNKNOWN);
+            }
+
+            // Features cannot hold missing quality scores, so they are stored as an array instead.
+            if record.sequence().is_empty() || record.quality_scores().is_empty() {
+                flags.insert(Flags::QUALITY_SCORES_STORED_AS_ARRAY);
+            }
+        }
+
         if let Some((reference_sequence_name, _)) = record.reference_sequence(header).transpose()? {
             let reference_sequence_id =
                 get_reference_sequence_id(header.reference_sequences(), reference_sequence_name)?;
             builder = builder.set_reference_sequence_id(reference_sequence_id);
         }
 
-        builder = builder.set_read_length(record.sequence().len());
+        let read_length = if flags.decode_sequence_as_unknown() {
+            record.cigar().read_length()
+        } else {
+            record.sequence().len()
+        };
+
+        builder = builder.set_read_length(read_length);
 
         if let Some(alignment_start) = record.alignment_start() {
             builder = builder.set_alignment_start(alignment_start);
@@ -94,6 +130,28 @@ impl Record {
     }
 
     /// Converts this CRAM record to an alignment record.
+    ///
+    /// The CIGAR operations are reconstructed from the read features, and the read group is
+    /// restored as an `RG` data field. The resulting record can be written by both the BAM and SAM
+    /// writers.
+    ///
+    /// This is a method rather than a `TryFrom` implementation because the conversion needs the
+    /// header to resolve the read group, and `TryFrom<(&sam::Header, cram::Record)>` cannot be
+    /// implemented for [`sam::alignment::Record`] outside of noodles-sam.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram as cram;
+    /// use noodles_sam as sam;
+    ///
+    /// let header = sam::Header::default();
+    /// let record = cram::Record::default();
+    ///
+    /// let alignment_record = record.try_into_alignment_record(&header)?;
+    /// assert_eq!(alignment_record, sam::alignment::Record::default());
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
     pub fn try_into_alignment_record(
         self,
         header: &sam::Header,
@@ -209,3 +267,44 @@ fn maybe_insert_read_group(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_from_alignment_record_with_missing_sequence_and_quality_scores(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        use std::num::NonZeroUsize;
+
+        use noodles_core::Position;
+        use sam::header::record::value::{map::ReferenceSequence, Map};
+
+        let header = sam::Header::builder()
+            .add_reference_sequence(
+                "sq0".parse()?,
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8)?),
+            )
+            .build();
+
+        let alignment_record = sam::alignment::Record::builder()
+            .set_flags(sam::record::Flags::empty())
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::try_from(2)?)
+            .set_cigar("1S2M1I1M".parse()?)
+            .build();
+
+        let record = Record::try_from_alignment_record(&header, &alignment_record)?;
+
+        assert!(record.cram_flags().decode_sequence_as_unknown());
+        assert!(record.cram_flags().are_quality_scores_stored_as_array());
+        assert_eq!(record.read_length(), 5);
+        assert!(record.bases().is_empty());
+        assert!(record.quality_scores().is_empty());
+
+        let actual = record.try_into_alignment_record(&header)?;
+        assert_eq!(actual, alignment_record);
+
+        Ok(())
+    }
+}
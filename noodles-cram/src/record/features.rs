@@ -18,6 +18,10 @@ pub struct Features(Vec<Feature>);
 
 impl Features {
     /// Converts SAM record CIGAR operations to CRAM record features.
+    ///
+    /// If the given flags decode the sequence as unknown, the sequence is not read, and
+    /// insertions and soft clips are given `N` bases. If the flags store quality scores as an
+    /// array, quality scores are not added as features.
     pub fn from_cigar(
         flags: Flags,
         cigar: &sam::record::Cigar,
@@ -122,7 +126,17 @@ fn cigar_to_features(
     sequence: &sam::record::Sequence,
     quality_scores: &sam::record::QualityScores,
 ) -> Features {
-    use sam::record::cigar::op::Kind;
+    use sam::record::{cigar::op::Kind, sequence::Base};
+
+    // When the sequence is unknown, read bases are not stored. Features that require bases (i.e.,
+    // insertions and soft clips) are given placeholder bases to keep their lengths.
+    let get_bases = |start: Position, end: Position| {
+        if flags.decode_sequence_as_unknown() {
+            vec![Base::N; usize::from(end) - usize::from(start)]
+        } else {
+            sequence[start..end].to_vec()
+        }
+    };
 
     let mut features = Features::default();
     let mut read_position = Position::MIN;
@@ -130,9 +144,17 @@ fn cigar_to_features(
     for op in cigar.iter() {
         match op.kind() {
             Kind::Match | Kind::SequenceMatch | Kind::SequenceMismatch => {
-                if op.len() == 1 {
+                if flags.decode_sequence_as_unknown() {
+                    // Matches are implied by the absence of features.
+                } else if op.len() == 1 {
                     let base = sequence[read_position];
-                    let score = quality_scores[read_position];
+
+                    // Quality scores stored as an array are not read from the feature.
+                    let score = quality_scores
+                        .get(read_position)
+                        .copied()
+                        .unwrap_or_default();
+
                     features.push(Feature::ReadBase(read_position, base, score));
                 } else {
                     let end = read_position
@@ -150,7 +172,12 @@ fn cigar_to_features(
             }
             Kind::Insertion => {
                 if op.len() == 1 {
-                    let base = sequence[read_position];
+                    let base = if flags.decode_sequence_as_unknown() {
+                        Base::N
+                    } else {
+                        sequence[read_position]
+                    };
+
                     features.push(Feature::InsertBase(read_position, base));
 
                     if !flags.are_quality_scores_stored_as_array() {
@@ -162,8 +189,8 @@ fn cigar_to_features(
                         .checked_add(op.len())
                         .expect("attempt to add with overflow");
 
-                    let bases = &sequence[read_position..end];
-                    features.push(Feature::Insertion(read_position, bases.to_vec()));
+                    let bases = get_bases(read_position, end);
+                    features.push(Feature::Insertion(read_position, bases));
 
                     if !flags.are_quality_scores_stored_as_array() {
                         let scores = &quality_scores[read_position..end];
@@ -178,11 +205,12 @@ fn cigar_to_features(
                     .checked_add(op.len())
                     .expect("attempt to add with overflow");
 
-                let bases = &sequence[read_position..end];
-                features.push(Feature::SoftClip(read_position, bases.to_vec()));
+                let bases = get_bases(read_position, end);
+                let base_count = bases.len();
+                features.push(Feature::SoftClip(read_position, bases));
 
                 if !flags.are_quality_scores_stored_as_array() {
-                    if bases.len() == 1 {
+                    if base_count == 1 {
                         let score = quality_scores[read_position];
                         features.push(Feature::QualityScore(read_position, score));
                     } else {
@@ -343,6 +371,37 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_cigar_to_features_with_missing_sequence_and_quality_scores(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        use sam::record::{quality_scores::Score, sequence::Base};
+
+        let cigar = "1S1M1I2M".parse()?;
+        let sequence = sam::record::Sequence::default();
+        let quality_scores = sam::record::QualityScores::default();
+
+        let flags = Flags::QUALITY_SCORES_STORED_AS_ARRAY | Flags::DECODE_SEQUENCE_AS_UNKNOWN;
+        let actual = cigar_to_features(flags, &cigar, &sequence, &quality_scores);
+        let expected = Features::from(vec![
+            Feature::SoftClip(Position::try_from(1)?, vec![Base::N]),
+            Feature::InsertBase(Position::try_from(3)?, Base::N),
+        ]);
+        assert_eq!(actual, expected);
+
+        let cigar = "1M1S".parse()?;
+        let sequence = "AC".parse()?;
+
+        let flags = Flags::QUALITY_SCORES_STORED_AS_ARRAY;
+        let actual = cigar_to_features(flags, &cigar, &sequence, &quality_scores);
+        let expected = Features::from(vec![
+            Feature::ReadBase(Position::try_from(1)?, Base::A, Score::default()),
+            Feature::SoftClip(Position::try_from(2)?, vec![Base::C]),
+        ]);
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
     #[test]
     fn test_cigar_to_features_with_quality_scores_stored_as_array(
     ) -> Result<(), Box<dyn std::error::Error>> {
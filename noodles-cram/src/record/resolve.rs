@@ -44,7 +44,8 @@ pub(crate) fn resolve_bases(
             Feature::ReadBase(_, base, _) => buf[read_position] = *base,
             Feature::Substitution(_, substitution::Value::Code(code)) => {
                 if let Some(reference_sequence) = reference_sequence {
-                    let base = reference_sequence[reference_position];
+                    // Reference sequences may be soft-masked (lowercase).
+                    let base = reference_sequence[reference_position].to_ascii_uppercase();
                     let reference_base = SubstitutionBase::try_from(base).unwrap_or_default();
                     let read_base = substitution_matrix.get(reference_base, *code);
                     buf[read_position] = Base::from(read_base);
@@ -238,6 +239,34 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_resolve_bases_with_substitutions() -> Result<(), Box<dyn std::error::Error>> {
+        // soft-masked
+        let reference_sequence = fasta::record::Sequence::from(b"acgtACGT".to_vec());
+        // A: [T, G, C, N], C: [A, G, T, N], ...
+        let substitution_matrix = SubstitutionMatrix::try_from([0x93, 0x1b, 0x1b, 0x1b, 0x1b])?;
+        let alignment_start = Position::try_from(1)?;
+
+        let features = Features::from(vec![
+            Feature::Substitution(Position::try_from(1)?, substitution::Value::Code(0)),
+            Feature::Substitution(Position::try_from(5)?, substitution::Value::Code(0)),
+            Feature::Substitution(Position::try_from(6)?, substitution::Value::Code(2)),
+        ]);
+
+        let actual = resolve_bases(
+            Some(&reference_sequence),
+            &substitution_matrix,
+            &features,
+            alignment_start,
+            8,
+        )?;
+
+        let expected = "TCGTTTGT".parse()?;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
     #[test]
     fn test_resolve_bases_without_a_reference_sequence() -> Result<(), Box<dyn std::error::Error>> {
         let substitution_matrix = SubstitutionMatrix::default();
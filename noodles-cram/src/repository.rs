@@ -0,0 +1,3 @@
+//! CRAM reference sequence repository adapters.
+
+pub mod adapters;
@@ -0,0 +1,5 @@
+//! CRAM reference sequence repository adapters.
+
+pub mod ref_path;
+
+pub use self::ref_path::RefPath;
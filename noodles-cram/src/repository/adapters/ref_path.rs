@@ -0,0 +1,398 @@
+//! Reference sequence lookup by MD5 checksum.
+
+mod builder;
+
+pub use self::builder::Builder;
+
+use std::{
+    collections::HashMap,
+    fmt::Write as _,
+    fs::{self, File},
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+use noodles_fasta::{
+    self as fasta,
+    record::{Definition, Sequence},
+    repository::Adapter,
+};
+use noodles_sam::{self as sam, header::record::value::map::reference_sequence::Md5Checksum};
+
+use crate::data_container::slice::builder::calculate_normalized_sequence_digest;
+
+/// The default `REF_PATH` when it is not set in the environment.
+pub const DEFAULT_REF_PATH: &str = "https://www.ebi.ac.uk/ena/cram/md5/%s";
+
+/// A reference sequence repository adapter that resolves sequences by MD5 checksum.
+///
+/// This mirrors the `REF_PATH` and `REF_CACHE` lookup used by htslib. A sequence is resolved by
+/// its name in the SAM header, which maps to the MD5 checksum of the reference sequence (`@SQ
+/// M5`). Sources are searched in the following order:
+///
+///   1. the local adapter, e.g., a FASTA file, if the sequence it returns has the expected
+///      checksum;
+///   2. the `REF_CACHE` location; and
+///   3. each `REF_PATH` location, which is either a local path or a URL.
+///
+/// A location is a template where `%s` is replaced with the (rest of the) hex-encoded MD5
+/// checksum and `%Ns` is replaced with the next `N` characters of the checksum, e.g.,
+/// `/data/cache/%2s/%2s/%s`. If a location has no `%s`, `/%s` is appended.
+///
+/// Files at these locations hold only the normalized (uppercase, no whitespace) sequence.
+/// Sequences that are downloaded are added to the cache, if one is set.
+///
+/// Downloading sequences from URLs requires the `remote` feature. Otherwise, URL locations are
+/// skipped.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_cram::repository::adapters::RefPath;
+/// use noodles_fasta as fasta;
+/// use noodles_sam as sam;
+///
+/// let header = sam::Header::default();
+/// let adapter = RefPath::builder().set_ref_path("/data/ref/%s").build(&header);
+/// let repository = fasta::Repository::new(adapter);
+/// ```
+pub struct RefPath {
+    checksums: HashMap<String, [u8; 16]>,
+    local: Option<Box<dyn Adapter>>,
+    ref_path: Vec<String>,
+    ref_cache: Option<String>,
+}
+
+impl RefPath {
+    /// Returns a builder to create a `REF_PATH` adapter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::repository::adapters::RefPath;
+    /// let builder = RefPath::builder();
+    /// ```
+    pub fn builder() -> Builder {
+        Builder::default()
+    }
+
+    /// Creates a `REF_PATH` adapter using the `REF_PATH` and `REF_CACHE` environment variables.
+    ///
+    /// If `REF_PATH` is not set, [`DEFAULT_REF_PATH`] is used. If neither `REF_PATH` nor
+    /// `REF_CACHE` is set, the cache defaults to `$XDG_CACHE_HOME/hts-ref/%2s/%2s/%s` or
+    /// `$HOME/.cache/hts-ref/%2s/%2s/%s`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::repository::adapters::RefPath;
+    /// use noodles_sam as sam;
+    ///
+    /// let header = sam::Header::default();
+    /// let adapter = RefPath::from_env(&header);
+    /// ```
+    pub fn from_env(header: &sam::Header) -> Self {
+        Builder::default().set_env().build(header)
+    }
+
+    fn get_by_checksum(&self, checksum: &[u8; 16]) -> io::Result<Option<Vec<u8>>> {
+        let md5 = Md5Checksum::from(*checksum).to_string();
+
+        if let Some(template) = &self.ref_cache {
+            let src = expand(template, &md5);
+
+            if let Some(sequence) = read_local(&src, checksum)? {
+                return Ok(Some(sequence));
+            }
+        }
+
+        for template in &self.ref_path {
+            let src = expand(template, &md5);
+
+            if is_url(&src) {
+                if let Some(sequence) = read_remote(&src, checksum)? {
+                    if let Some(template) = &self.ref_cache {
+                        write_cache(expand(template, &md5), &sequence)?;
+                    }
+
+                    return Ok(Some(sequence));
+                }
+            } else if let Some(sequence) = read_local(&src, checksum)? {
+                return Ok(Some(sequence));
+            }
+        }
+
+        Ok(None)
+    }
+}
+
+impl Adapter for RefPath {
+    fn get(&mut self, name: &str) -> Option<io::Result<fasta::Record>> {
+        let checksum = match self.checksums.get(name) {
+            Some(checksum) => *checksum,
+            None => return self.local.as_mut().and_then(|local| local.get(name)),
+        };
+
+        if let Some(local) = self.local.as_mut() {
+            match local.get(name) {
+                Some(Ok(record))
+                    if calculate_normalized_sequence_digest(record.sequence().as_ref())
+                        == checksum =>
+                {
+                    return Some(Ok(record));
+                }
+                Some(Err(e)) => return Some(Err(e)),
+                _ => {}
+            }
+        }
+
+        match self.get_by_checksum(&checksum) {
+            Ok(Some(sequence)) => Some(Ok(fasta::Record::new(
+                Definition::new(name, None),
+                Sequence::from(sequence),
+            ))),
+            Ok(None) => None,
+            Err(e) => Some(Err(e)),
+        }
+    }
+}
+
+fn split_ref_path(s: &str) -> Vec<String> {
+    const URL_SCHEMES: [&str; 3] = ["ftp", "http", "https"];
+
+    let mut locations: Vec<String> = Vec::new();
+    let mut is_url_scheme = false;
+
+    for component in s.split(':') {
+        if is_url_scheme && component.starts_with("//") {
+            if let Some(location) = locations.last_mut() {
+                location.push(':');
+                location.push_str(component);
+            }
+        } else {
+            locations.push(component.into());
+        }
+
+        is_url_scheme = URL_SCHEMES.contains(&component);
+    }
+
+    locations.retain(|location| !location.is_empty());
+
+    locations
+}
+
+// Expands a location template, replacing `%s` with the remaining MD5 checksum and `%Ns` with the
+// next `N` characters of the checksum.
+fn expand(template: &str, md5: &str) -> String {
+    let mut dst = String::with_capacity(template.len() + md5.len());
+    let mut rest = md5;
+    let mut has_checksum = false;
+
+    let mut chars = template.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            dst.push(c);
+            continue;
+        }
+
+        let mut n = None;
+
+        while let Some(d) = chars.peek().and_then(|c| c.to_digit(10)) {
+            n = Some(n.unwrap_or(0) * 10 + d as usize);
+            chars.next();
+        }
+
+        match chars.next() {
+            Some('s') => {
+                let len = n.map(|n| n.min(rest.len())).unwrap_or(rest.len());
+                let (head, tail) = rest.split_at(len);
+                dst.push_str(head);
+                rest = tail;
+                has_checksum = true;
+            }
+            Some('%') if n.is_none() => dst.push('%'),
+            Some(c) => {
+                dst.push('%');
+
+                if let Some(n) = n {
+                    write!(dst, "{n}").unwrap();
+                }
+
+                dst.push(c);
+            }
+            None => dst.push('%'),
+        }
+    }
+
+    if !has_checksum {
+        if !dst.ends_with('/') {
+            dst.push('/');
+        }
+
+        dst.push_str(md5);
+    }
+
+    dst
+}
+
+fn is_url(s: &str) -> bool {
+    s.starts_with("ftp://") || s.starts_with("http://") || s.starts_with("https://")
+}
+
+fn normalize(mut sequence: Vec<u8>) -> Vec<u8> {
+    sequence.retain(|b| b.is_ascii_graphic());
+    sequence.make_ascii_uppercase();
+    sequence
+}
+
+fn verify(sequence: Vec<u8>, checksum: &[u8; 16]) -> io::Result<Vec<u8>> {
+    let sequence = normalize(sequence);
+
+    if calculate_normalized_sequence_digest(&sequence) == *checksum {
+        Ok(sequence)
+    } else {
+        Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "reference sequence checksum mismatch: expected {}",
+                Md5Checksum::from(*checksum)
+            ),
+        ))
+    }
+}
+
+fn read_local<P>(src: P, checksum: &[u8; 16]) -> io::Result<Option<Vec<u8>>>
+where
+    P: AsRef<Path>,
+{
+    let mut file = match File::open(src) {
+        Ok(file) => file,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(e),
+    };
+
+    let mut buf = Vec::new();
+    file.read_to_end(&mut buf)?;
+
+    verify(buf, checksum).map(Some)
+}
+
+#[cfg(feature = "remote")]
+fn read_remote(url: &str, checksum: &[u8; 16]) -> io::Result<Option<Vec<u8>>> {
+    let response = match ureq::get(url).call() {
+        Ok(response) => response,
+        Err(ureq::Error::Status(404, _)) => return Ok(None),
+        Err(e) => return Err(io::Error::new(io::ErrorKind::Other, e)),
+    };
+
+    let mut buf = Vec::new();
+    response.into_reader().read_to_end(&mut buf)?;
+
+    verify(buf, checksum).map(Some)
+}
+
+#[cfg(not(feature = "remote"))]
+fn read_remote(_: &str, _: &[u8; 16]) -> io::Result<Option<Vec<u8>>> {
+    Ok(None)
+}
+
+fn write_cache(dst: String, sequence: &[u8]) -> io::Result<()> {
+    let dst = PathBuf::from(dst);
+
+    if let Some(parent) = dst.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    // Write to a temporary file first so that concurrent readers never see a partial sequence.
+    let mut tmp = dst.clone().into_os_string();
+    tmp.push(format!(".tmp.{}", std::process::id()));
+
+    fs::write(&tmp, sequence)?;
+    fs::rename(&tmp, &dst)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_ref_path() {
+        assert_eq!(
+            split_ref_path("/data/ref/%s:https://www.ebi.ac.uk/ena/cram/md5/%s::/tmp"),
+            [
+                "/data/ref/%s",
+                "https://www.ebi.ac.uk/ena/cram/md5/%s",
+                "/tmp"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_expand() {
+        const MD5: &str = "be19336b7e15968f7ac7dc82493d9cd8";
+
+        assert_eq!(
+            expand("/data/ref/%2s/%2s/%s", MD5),
+            "/data/ref/be/19/336b7e15968f7ac7dc82493d9cd8"
+        );
+        assert_eq!(
+            expand("/data/ref/%s.fa", MD5),
+            "/data/ref/be19336b7e15968f7ac7dc82493d9cd8.fa"
+        );
+        assert_eq!(
+            expand("/data/ref", MD5),
+            "/data/ref/be19336b7e15968f7ac7dc82493d9cd8"
+        );
+        assert_eq!(
+            expand("/data/100%%/%s", MD5),
+            "/data/100%/be19336b7e15968f7ac7dc82493d9cd8"
+        );
+    }
+
+    #[test]
+    fn test_get() -> Result<(), Box<dyn std::error::Error>> {
+        use sam::header::record::value::{map::ReferenceSequence, Map};
+
+        let dir =
+            std::env::temp_dir().join(format!("noodles-cram-ref-path-{}", std::process::id()));
+
+        let checksum = calculate_normalized_sequence_digest(b"ACGT");
+        let md5 = Md5Checksum::from(checksum).to_string();
+        let sq0_src = dir.join(&md5[..2]).join(&md5[2..]);
+        write_cache(sq0_src.to_string_lossy().into_owned(), b"acgt\n")?;
+
+        let header = sam::Header::builder()
+            .add_reference_sequence(
+                "sq0".parse()?,
+                Map::<ReferenceSequence>::builder()
+                    .set_length(4.try_into()?)
+                    .set_md5_checksum(Md5Checksum::from(checksum))
+                    .build()?,
+            )
+            .add_reference_sequence(
+                "sq1".parse()?,
+                Map::<ReferenceSequence>::builder()
+                    .set_length(4.try_into()?)
+                    .set_md5_checksum(Md5Checksum::from([0; 16]))
+                    .build()?,
+            )
+            .build();
+
+        let ref_path = format!("{}/%2s/%s", dir.display());
+        let mut adapter = RefPath::builder().set_ref_path(&ref_path).build(&header);
+
+        let record = adapter.get("sq0").transpose()?;
+        assert_eq!(
+            record.map(|r| r.sequence().as_ref().to_vec()),
+            Some(b"ACGT".to_vec())
+        );
+
+        assert!(adapter.get("sq1").is_none());
+        assert!(adapter.get("sq2").is_none());
+
+        fs::remove_dir_all(dir)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,135 @@
+use std::{env, path::PathBuf};
+
+use noodles_fasta::repository::Adapter;
+use noodles_sam as sam;
+
+use super::{split_ref_path, RefPath, DEFAULT_REF_PATH};
+
+/// A `REF_PATH` adapter builder.
+#[derive(Default)]
+pub struct Builder {
+    local: Option<Box<dyn Adapter>>,
+    ref_path: Vec<String>,
+    ref_cache: Option<String>,
+}
+
+impl Builder {
+    /// Sets a local adapter.
+    ///
+    /// The local adapter, e.g., one that reads a FASTA file, is searched before any `REF_PATH`
+    /// location.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::repository::adapters::RefPath;
+    /// use noodles_fasta::repository::adapters::Empty;
+    ///
+    /// let builder = RefPath::builder().set_local(Empty::new());
+    /// ```
+    pub fn set_local<A>(mut self, adapter: A) -> Self
+    where
+        A: Adapter + 'static,
+    {
+        self.local = Some(Box::new(adapter));
+        self
+    }
+
+    /// Sets the `REF_PATH` locations.
+    ///
+    /// This is a colon-separated list of location templates. URLs, e.g.,
+    /// `https://www.ebi.ac.uk/ena/cram/md5/%s`, are kept whole.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::repository::adapters::RefPath;
+    /// let builder = RefPath::builder().set_ref_path("/data/ref/%2s/%s:https://www.ebi.ac.uk/ena/cram/md5/%s");
+    /// ```
+    pub fn set_ref_path(mut self, ref_path: &str) -> Self {
+        self.ref_path = split_ref_path(ref_path);
+        self
+    }
+
+    /// Sets the `REF_CACHE` location.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::repository::adapters::RefPath;
+    /// let builder = RefPath::builder().set_ref_cache("/data/cache/%2s/%2s/%s");
+    /// ```
+    pub fn set_ref_cache<S>(mut self, ref_cache: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.ref_cache = Some(ref_cache.into());
+        self
+    }
+
+    /// Sets the `REF_PATH` and `REF_CACHE` locations from the environment.
+    ///
+    /// See [`RefPath::from_env`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::repository::adapters::RefPath;
+    /// let builder = RefPath::builder().set_env();
+    /// ```
+    pub fn set_env(mut self) -> Self {
+        let ref_path = env::var("REF_PATH").ok();
+        let ref_cache = env::var("REF_CACHE").ok();
+
+        self.ref_cache = match (&ref_path, ref_cache) {
+            (_, Some(ref_cache)) => Some(ref_cache),
+            (None, None) => default_ref_cache(),
+            (Some(_), None) => None,
+        };
+
+        self.ref_path = split_ref_path(ref_path.as_deref().unwrap_or(DEFAULT_REF_PATH));
+
+        self
+    }
+
+    /// Builds a `REF_PATH` adapter.
+    ///
+    /// The reference sequence MD5 checksums (`@SQ M5`) are read from the given header.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::repository::adapters::RefPath;
+    /// use noodles_sam as sam;
+    ///
+    /// let header = sam::Header::default();
+    /// let adapter = RefPath::builder().build(&header);
+    /// ```
+    pub fn build(self, header: &sam::Header) -> RefPath {
+        let checksums = header
+            .reference_sequences()
+            .iter()
+            .filter_map(|(name, reference_sequence)| {
+                reference_sequence
+                    .md5_checksum()
+                    .map(|checksum| (name.to_string(), <[u8; 16]>::from(checksum)))
+            })
+            .collect();
+
+        RefPath {
+            checksums,
+            local: self.local,
+            ref_path: self.ref_path,
+            ref_cache: self.ref_cache,
+        }
+    }
+}
+
+fn default_ref_cache() -> Option<String> {
+    const SUFFIX: &str = "hts-ref/%2s/%2s/%s";
+
+    env::var_os("XDG_CACHE_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".cache")))
+        .map(|dir| dir.join(SUFFIX).to_string_lossy().into_owned())
+}
@@ -3,13 +3,15 @@
 pub(crate) mod builder;
 pub(crate) mod container;
 pub(crate) mod data_container;
+mod encoder_pool;
 pub(crate) mod header_container;
 pub(crate) mod num;
 mod options;
+mod quality_scores_mode;
 pub(crate) mod record;
 
-pub use self::builder::Builder;
 pub(crate) use self::options::Options;
+pub use self::{builder::Builder, quality_scores_mode::QualityScoresMode};
 
 use std::{
     io::{self, Write},
@@ -54,6 +56,7 @@ where
     options: Options,
     data_container_builder: crate::data_container::Builder,
     record_counter: u64,
+    encoder_pool: Option<encoder_pool::EncoderPool>,
 }
 
 impl<W> Writer<W>
@@ -104,8 +107,17 @@ where
     /// # Ok::<(), io::Error>(())
     /// ```
     pub fn try_finish(&mut self, header: &sam::Header) -> io::Result<()> {
-        use self::container::write_eof_container;
+        use self::{container::write_eof_container, data_container::write_data_container};
+
         self.flush(header)?;
+
+        if let Some(encoder_pool) = self.encoder_pool.as_mut() {
+            while let Some(result) = encoder_pool.recv() {
+                let (data_container, base_count) = result?;
+                write_data_container(&mut self.inner, &data_container, base_count)?;
+            }
+        }
+
         write_eof_container(&mut self.inner)
     }
 
@@ -199,6 +211,12 @@ where
     pub fn write_record(&mut self, header: &sam::Header, mut record: Record) -> io::Result<()> {
         use super::data_container::builder::AddRecordError;
 
+        self.options.quality_scores_mode.apply(
+            &self.reference_sequence_repository,
+            header,
+            &mut record,
+        )?;
+
         loop {
             match self.data_container_builder.add_record(record) {
                 Ok(_) => {
@@ -231,6 +249,23 @@ where
             DataContainer::builder(self.record_counter),
         );
 
+        if let Some(encoder_pool) = self.encoder_pool.as_mut() {
+            let reference_sequences = data_container_builder
+                .load_reference_sequences(&self.reference_sequence_repository, header)?;
+
+            encoder_pool.send(data_container_builder, reference_sequences)?;
+
+            // Containers are written in order as the pool fills.
+            while encoder_pool.is_full() {
+                if let Some(result) = encoder_pool.recv() {
+                    let (data_container, base_count) = result?;
+                    write_data_container(&mut self.inner, &data_container, base_count)?;
+                }
+            }
+
+            return Ok(());
+        }
+
         let base_count = data_container_builder.base_count();
 
         let data_container = data_container_builder.build(
@@ -298,7 +333,12 @@ pub(crate) fn add_missing_reference_sequence_checksums(
             let sequence = reference_sequence_repository
                 .get(name)
                 .transpose()?
-                .expect("missing reference sequence");
+                .ok_or_else(|| {
+                    io::Error::new(
+                        io::ErrorKind::InvalidInput,
+                        format!("missing reference sequence: {name}"),
+                    )
+                })?;
 
             let checksum = calculate_normalized_sequence_digest(&sequence[..]);
 
@@ -360,4 +400,496 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_write_record_with_embedded_reference_sequences(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        use noodles_core::Position;
+
+        use crate::{
+            fixtures::{build_sq0_header, build_sq0_repository, write_records},
+            Reader,
+        };
+
+        let header = build_sq0_header()?;
+
+        let record = sam::alignment::Record::builder()
+            .set_read_name("r0".parse()?)
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::try_from(3)?)
+            .set_cigar("4M".parse()?)
+            .set_sequence("CGCC".parse()?)
+            .build();
+
+        let builder = Builder::default()
+            .set_reference_sequence_repository(build_sq0_repository())
+            .embed_reference_sequences(true);
+
+        let data = write_records(builder, &header, std::slice::from_ref(&record))?;
+
+        let mut reader = Reader::new(&data[..]);
+        reader.read_file_definition()?;
+        reader.read_file_header()?;
+
+        let data_container = reader
+            .read_data_container()?
+            .ok_or("missing data container")?;
+
+        assert!(!data_container
+            .compression_header()
+            .preservation_map()
+            .is_reference_required());
+
+        let slice = &data_container.slices()[0];
+        assert!(slice
+            .header()
+            .embedded_reference_bases_block_content_id()
+            .is_some());
+
+        // The reference sequence is not in the repository.
+        let repository = fasta::Repository::default();
+        let mut records = slice.records(data_container.compression_header())?;
+        slice.resolve_records(
+            &repository,
+            &header,
+            data_container.compression_header(),
+            &mut records,
+        )?;
+
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].bases(), record.sequence());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_records_with_profiles() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::{
+            data_container::{block_content_encoder_map::Profile, BlockContentEncoderMap},
+            fixtures::write_records,
+            Reader,
+        };
+
+        let header = sam::Header::default();
+
+        let records = [
+            sam::alignment::Record::builder()
+                .set_read_name("r0".parse()?)
+                .set_sequence("ACGT".parse()?)
+                .set_quality_scores("NDLS".parse()?)
+                .build(),
+            sam::alignment::Record::builder()
+                .set_read_name("r1".parse()?)
+                .set_sequence("TTCACCCA".parse()?)
+                .set_quality_scores("NNNNDDDD".parse()?)
+                .build(),
+        ];
+
+        for profile in [
+            Profile::Fast,
+            Profile::Normal,
+            Profile::Small,
+            Profile::Archive,
+        ] {
+            let block_content_encoder_map = BlockContentEncoderMap::builder()
+                .set_profile(profile)
+                .build();
+
+            let builder =
+                Builder::default().set_block_content_encoder_map(block_content_encoder_map);
+            let data = write_records(builder, &header, &records)?;
+
+            let mut reader = Reader::new(&data[..]);
+            let file_definition = reader.read_file_definition()?;
+            assert_eq!(file_definition.version(), Version::new(3, 1));
+            reader.read_file_header()?;
+
+            let repository = fasta::Repository::default();
+            let actual: Vec<_> = reader
+                .records(&repository, &header)
+                .collect::<Result<_, _>>()?;
+
+            assert_eq!(actual.len(), records.len(), "profile = {profile:?}");
+
+            for (actual_record, expected_record) in actual.iter().zip(&records) {
+                assert_eq!(actual_record.read_name(), expected_record.read_name());
+                assert_eq!(actual_record.bases(), expected_record.sequence());
+                assert_eq!(
+                    actual_record.quality_scores(),
+                    expected_record.quality_scores(),
+                    "profile = {profile:?}"
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_records_with_quality_scores_modes() -> Result<(), Box<dyn std::error::Error>> {
+        use noodles_core::Position;
+        use sam::record::{quality_scores::Score, QualityScores};
+
+        use crate::fixtures::{
+            build_sq0_header, build_sq0_repository, read_records, write_records,
+        };
+
+        let repository = build_sq0_repository();
+        let header = build_sq0_header()?;
+
+        // The base at read position 3 mismatches the reference sequence.
+        let record = sam::alignment::Record::builder()
+            .set_read_name("r0".parse()?)
+            .set_flags(sam::record::Flags::empty())
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::try_from(3)?)
+            .set_cigar("1S4M".parse()?)
+            .set_sequence("ACGCC".parse()?)
+            .set_quality_scores("+5?IS".parse()?)
+            .build();
+
+        for (quality_scores_mode, expected) in [
+            (QualityScoresMode::Lossless, "+5?IS"),
+            (QualityScoresMode::Illumina8Binning, "07BII"),
+            (QualityScoresMode::Fixed(Score::try_from(30)?), "?????"),
+            (QualityScoresMode::PreserveMismatches, "!!?!!"),
+        ] {
+            let builder = Builder::default()
+                .set_reference_sequence_repository(repository.clone())
+                .set_quality_scores_mode(quality_scores_mode);
+
+            let data = write_records(builder, &header, std::slice::from_ref(&record))?;
+            let actual = read_records(&data, &repository, &header)?;
+
+            let expected: QualityScores = expected.parse()?;
+
+            assert_eq!(actual.len(), 1);
+            assert_eq!(actual[0].bases(), record.sequence());
+            assert_eq!(
+                actual[0].quality_scores(),
+                &expected,
+                "quality_scores_mode = {quality_scores_mode:?}"
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_records_with_missing_sequence_or_quality_scores(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        use noodles_core::Position;
+
+        use crate::fixtures::{
+            build_sq0_header, build_sq0_repository, read_records, write_records,
+        };
+
+        let repository = build_sq0_repository();
+        let header = build_sq0_header()?;
+
+        let build_record = |cigar: &str, sequence: &str, quality_scores: &str| {
+            let mut builder = sam::alignment::Record::builder()
+                .set_read_name("r0".parse()?)
+                .set_flags(sam::record::Flags::empty())
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::try_from(2)?)
+                .set_cigar(cigar.parse()?);
+
+            if sequence != "*" {
+                builder = builder.set_sequence(sequence.parse()?);
+            }
+
+            if quality_scores != "*" {
+                builder = builder.set_quality_scores(quality_scores.parse()?);
+            }
+
+            Ok::<_, Box<dyn std::error::Error>>(builder.build())
+        };
+
+        let records = [
+            build_record("1S2M1I1M", "*", "*")?,
+            build_record("1M1D1M", "*", "*")?,
+            build_record("1S4M", "ACACC", "*")?,
+            build_record("1M", "A", "*")?,
+            build_record("2M", "TC", "ND")?,
+        ];
+
+        let builder = Builder::default().set_reference_sequence_repository(repository.clone());
+        let data = write_records(builder, &header, &records)?;
+
+        let actual: Vec<_> = read_records(&data, &repository, &header)?
+            .into_iter()
+            .map(|record| record.try_into_alignment_record(&header))
+            .collect::<Result<_, _>>()?;
+
+        assert_eq!(actual, records);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_records_with_tags() -> Result<(), Box<dyn std::error::Error>> {
+        use sam::record::data::field::{Tag, Value};
+
+        use crate::fixtures::{read_records, write_records};
+
+        let header = sam::Header::default();
+
+        let values = [
+            Value::Character('n'.try_into()?),
+            Value::Int8(-8),
+            Value::UInt8(8),
+            Value::Int16(-1337),
+            Value::UInt16(1337),
+            Value::Int32(-65536),
+            Value::UInt32(65536),
+            Value::Float(0.5),
+            Value::String(String::new()),
+            Value::String(String::from("noodles")),
+            Value::Hex("CAFE".parse()?),
+            Value::Int8Array(Vec::new()),
+            Value::Int8Array(vec![-8, 8]),
+            Value::UInt8Array(vec![8, 13]),
+            Value::Int16Array(vec![-1337, 1337]),
+            Value::UInt16Array(vec![1337, 65535]),
+            Value::Int32Array(vec![-65536, 65536]),
+            Value::UInt32Array(vec![65536, 4294967295]),
+            Value::FloatArray(vec![0.5, 1.5]),
+        ];
+
+        let mut records = Vec::with_capacity(values.len() + 1);
+
+        for (i, value) in values.into_iter().enumerate() {
+            let mut data = sam::record::Data::default();
+            data.insert(Tag::try_from(*b"X0")?, value);
+
+            // Vary the tag lines.
+            if i % 2 == 0 {
+                data.insert(Tag::AlignmentHitCount, Value::UInt8(1));
+            }
+
+            let record = sam::alignment::Record::builder()
+                .set_read_name(format!("r{i}").parse()?)
+                .set_sequence("ACGT".parse()?)
+                .set_data(data)
+                .build();
+
+            records.push(record);
+        }
+
+        records.push(
+            sam::alignment::Record::builder()
+                .set_read_name("r19".parse()?)
+                .set_sequence("ACGT".parse()?)
+                .build(),
+        );
+
+        let data = write_records(Builder::default(), &header, &records)?;
+
+        let repository = fasta::Repository::default();
+        let actual: Vec<_> = read_records(&data, &repository, &header)?
+            .into_iter()
+            .map(|record| record.try_into_alignment_record(&header))
+            .collect::<Result<_, _>>()?;
+
+        assert_eq!(actual, records);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_records_with_substitution_matrix() -> Result<(), Box<dyn std::error::Error>> {
+        use noodles_core::Position;
+
+        use crate::{
+            data_container::compression_header::SubstitutionMatrix,
+            fixtures::{build_sq0_header, build_sq0_repository},
+            record::{
+                feature::substitution::{self, Base},
+                Feature,
+            },
+            Reader,
+        };
+
+        let repository = build_sq0_repository();
+        let header = build_sq0_header()?;
+
+        // A: [T, G, C, N], C: [N, G, A, T], ...
+        let substitution_matrix = SubstitutionMatrix::try_from([0x93, 0x9c, 0x1b, 0x1b, 0x1b])?;
+
+        let record = Record::builder()
+            .set_bam_flags(sam::record::Flags::empty())
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::try_from(3)?)
+            .set_read_length(4)
+            .add_feature(Feature::Substitution(
+                Position::try_from(1)?,
+                substitution::Value::Bases(Base::C, Base::T),
+            ))
+            .add_feature(Feature::Substitution(
+                Position::try_from(2)?,
+                substitution::Value::Bases(Base::A, Base::G),
+            ))
+            .build();
+
+        let mut writer = Builder::default()
+            .set_reference_sequence_repository(repository.clone())
+            .set_substitution_matrix(substitution_matrix.clone())
+            .build_with_writer(Vec::new());
+
+        writer.write_file_definition()?;
+        writer.write_file_header(&header)?;
+        writer.write_record(&header, record)?;
+        writer.try_finish(&header)?;
+
+        let mut reader = Reader::new(&writer.get_ref()[..]);
+        reader.read_file_definition()?;
+        reader.read_file_header()?;
+
+        let data_container = reader
+            .read_data_container()?
+            .ok_or("missing data container")?;
+
+        let compression_header = data_container.compression_header();
+        assert_eq!(
+            compression_header.substitution_matrix(),
+            &substitution_matrix
+        );
+
+        let slice = &data_container.slices()[0];
+        let mut records = slice.records(compression_header)?;
+        slice.resolve_records(&repository, &header, compression_header, &mut records)?;
+
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].bases(), &"TGCC".parse()?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_records_with_multi_reference_slice() -> Result<(), Box<dyn std::error::Error>> {
+        use std::num::NonZeroUsize;
+
+        use fasta::record::{Definition, Sequence};
+        use noodles_core::Position;
+        use sam::header::record::value::{map::ReferenceSequence, Map};
+
+        use crate::{data_container::ReferenceSequenceContext, fixtures::write_records, Reader};
+
+        let repository = fasta::Repository::new(vec![
+            fasta::Record::new(
+                Definition::new("sq0", None),
+                Sequence::from(b"TTCACCCA".to_vec()),
+            ),
+            fasta::Record::new(
+                Definition::new("sq1", None),
+                Sequence::from(b"GGACGTTT".to_vec()),
+            ),
+        ]);
+
+        let header = sam::Header::builder()
+            .add_reference_sequence(
+                "sq0".parse()?,
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8)?),
+            )
+            .add_reference_sequence(
+                "sq1".parse()?,
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8)?),
+            )
+            .build();
+
+        let build_mapped_record = |name: &str, reference_sequence_id, alignment_start| {
+            Ok::<_, Box<dyn std::error::Error>>(
+                sam::alignment::Record::builder()
+                    .set_read_name(name.parse()?)
+                    .set_flags(sam::record::Flags::empty())
+                    .set_reference_sequence_id(reference_sequence_id)
+                    .set_alignment_start(Position::try_from(alignment_start)?)
+                    .set_cigar("4M".parse()?)
+                    .set_sequence("ACGT".parse()?)
+                    .set_quality_scores("NDLS".parse()?)
+                    .build(),
+            )
+        };
+
+        let records = [
+            build_mapped_record("r0", 1, 3)?,
+            sam::alignment::Record::builder()
+                .set_read_name("r1".parse()?)
+                .set_sequence("ACGT".parse()?)
+                .set_quality_scores("NDLS".parse()?)
+                .build(),
+            build_mapped_record("r2", 0, 2)?,
+        ];
+
+        let builder = Builder::default().set_reference_sequence_repository(repository.clone());
+        let data = write_records(builder, &header, &records)?;
+
+        let mut reader = Reader::new(&data[..]);
+        reader.read_file_definition()?;
+        reader.read_file_header()?;
+
+        let data_container = reader
+            .read_data_container()?
+            .ok_or("missing data container")?;
+
+        assert_eq!(data_container.slices().len(), 1);
+
+        let slice = &data_container.slices()[0];
+        assert_eq!(
+            slice.header().reference_sequence_context(),
+            ReferenceSequenceContext::Many
+        );
+
+        let mut actual = slice.records(data_container.compression_header())?;
+        slice.resolve_records(
+            &repository,
+            &header,
+            data_container.compression_header(),
+            &mut actual,
+        )?;
+
+        assert_eq!(actual.len(), records.len());
+
+        for (actual_record, expected_record) in actual.iter().zip(&records) {
+            assert_eq!(
+                actual_record.reference_sequence_id(),
+                expected_record.reference_sequence_id()
+            );
+            assert_eq!(
+                actual_record.alignment_start(),
+                expected_record.alignment_start()
+            );
+            assert_eq!(actual_record.bases(), expected_record.sequence());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_records_with_workers() -> Result<(), Box<dyn std::error::Error>> {
+        use std::num::NonZeroUsize;
+
+        use crate::fixtures::{
+            build_multi_container_records, read_records, write_records,
+            MULTI_CONTAINER_RECORD_COUNT,
+        };
+
+        let header = sam::Header::default();
+        let records = build_multi_container_records()?;
+        let repository = fasta::Repository::default();
+
+        let data = write_records(Builder::default(), &header, &records)?;
+        let expected = read_records(&data, &repository, &header)?;
+
+        let worker_count = NonZeroUsize::try_from(2)?;
+        let builder = Builder::default().set_worker_count(worker_count);
+        let data = write_records(builder, &header, &records)?;
+        let actual = read_records(&data, &repository, &header)?;
+
+        assert_eq!(actual.len(), MULTI_CONTAINER_RECORD_COUNT);
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
 }
@@ -1,10 +1,12 @@
-use std::io::Write;
+use std::{io::Write, num::NonZeroUsize};
 
 use noodles_fasta as fasta;
 
-use super::{Options, Writer};
+use super::{encoder_pool::EncoderPool, Options, QualityScoresMode, Writer};
 use crate::{
-    codecs::Encoder, data_container::BlockContentEncoderMap, file_definition::Version,
+    codecs::Encoder,
+    data_container::{compression_header::SubstitutionMatrix, BlockContentEncoderMap},
+    file_definition::Version,
     DataContainer,
 };
 
@@ -13,6 +15,7 @@ use crate::{
 pub struct Builder {
     reference_sequence_repository: fasta::Repository,
     options: Options,
+    worker_count: Option<NonZeroUsize>,
 }
 
 impl Builder {
@@ -71,6 +74,29 @@ impl Builder {
         self
     }
 
+    /// Sets whether to embed reference sequences.
+    ///
+    /// If `true`, the region of the reference sequence that a slice covers is written to the
+    /// slice, and the reference sequence is not required to decode the records. This is useful
+    /// for references that are not available from a public repository, e.g., de novo assemblies.
+    /// The reference sequence repository is still used to embed the reference sequences.
+    ///
+    /// Reference sequences are not embedded in slices with records that map to more than one
+    /// reference sequence.
+    ///
+    /// The default is `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram as cram;
+    /// let builder = cram::writer::Builder::default().embed_reference_sequences(true);
+    /// ```
+    pub fn embed_reference_sequences(mut self, value: bool) -> Self {
+        self.options.embed_reference_sequences = value;
+        self
+    }
+
     /// Sets the block content-encoder map.
     ///
     /// # Examples
@@ -87,6 +113,71 @@ impl Builder {
         self
     }
 
+    /// Sets the quality scores mode.
+    ///
+    /// Lossy modes reduce the size of the quality scores data series at the cost of precision.
+    ///
+    /// The default is [`QualityScoresMode::Lossless`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::{self as cram, writer::QualityScoresMode};
+    ///
+    /// let builder = cram::writer::Builder::default()
+    ///     .set_quality_scores_mode(QualityScoresMode::Illumina8Binning);
+    /// ```
+    pub fn set_quality_scores_mode(mut self, quality_scores_mode: QualityScoresMode) -> Self {
+        self.options.quality_scores_mode = quality_scores_mode;
+        self
+    }
+
+    /// Sets the substitution matrix.
+    ///
+    /// This is used in every data container, rather than building a substitution matrix from the
+    /// frequencies of the base substitutions in each container.
+    ///
+    /// By default, the substitution matrix is built per container.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::{
+    ///     self as cram, data_container::compression_header::SubstitutionMatrix,
+    /// };
+    ///
+    /// let substitution_matrix = SubstitutionMatrix::try_from([0x93, 0x1b, 0x1b, 0x1b, 0x1b])?;
+    /// let builder = cram::writer::Builder::default().set_substitution_matrix(substitution_matrix);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_substitution_matrix(mut self, substitution_matrix: SubstitutionMatrix) -> Self {
+        self.options.substitution_matrix = Some(substitution_matrix);
+        self
+    }
+
+    /// Sets the number of worker threads used to build data containers.
+    ///
+    /// Building a data container, i.e., encoding and compressing its records, is the most
+    /// CPU-intensive part of writing CRAM. With more than one worker, data containers are built in
+    /// parallel and written in order on the calling thread.
+    ///
+    /// The default is 1, i.e., data containers are built on the calling thread.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use noodles_cram as cram;
+    ///
+    /// let worker_count = NonZeroUsize::try_from(4)?;
+    /// let builder = cram::writer::Builder::default().set_worker_count(worker_count);
+    /// # Ok::<_, std::num::TryFromIntError>(())
+    /// ```
+    pub fn set_worker_count(mut self, worker_count: NonZeroUsize) -> Self {
+        self.worker_count = Some(worker_count);
+        self
+    }
+
     /// Builds a CRAM writer.
     ///
     /// # Examples
@@ -103,12 +194,18 @@ impl Builder {
             self.options.version = Version::new(3, 1);
         }
 
+        let encoder_pool = self
+            .worker_count
+            .filter(|n| n.get() > 1)
+            .map(|worker_count| EncoderPool::new(worker_count, self.options.clone()));
+
         Writer {
             inner: writer,
             reference_sequence_repository: self.reference_sequence_repository,
             options: self.options,
             data_container_builder: DataContainer::builder(0),
             record_counter: 0,
+            encoder_pool,
         }
     }
 }
@@ -117,7 +214,10 @@ pub fn uses_cram_3_1_codecs(block_content_encoder_map: &BlockContentEncoderMap)
     fn is_cram_3_1_codec(encoder: &Encoder) -> bool {
         matches!(
             encoder,
-            Encoder::RansNx16(_) | Encoder::AdaptiveArithmeticCoding(_) | Encoder::NameTokenizer
+            Encoder::RansNx16(_)
+                | Encoder::AdaptiveArithmeticCoding(_)
+                | Encoder::NameTokenizer
+                | Encoder::Fqzcomp
         )
     }
 
@@ -141,7 +241,10 @@ mod tests {
 
     #[test]
     fn test_uses_cram_3_1_codecs() {
-        use crate::codecs::rans_nx16::Flags;
+        use crate::{
+            codecs::rans_nx16::Flags,
+            data_container::compression_header::data_series_encoding_map::DataSeries,
+        };
 
         let block_content_encoder_map = BlockContentEncoderMap::default();
         assert!(!uses_cram_3_1_codecs(&block_content_encoder_map));
@@ -150,5 +253,10 @@ mod tests {
             .set_core_data_encoder(Some(Encoder::RansNx16(Flags::empty())))
             .build();
         assert!(uses_cram_3_1_codecs(&block_content_encoder_map));
+
+        let block_content_encoder_map = BlockContentEncoderMap::builder()
+            .set_data_series_encoder(DataSeries::QualityScores, Some(Encoder::Fqzcomp))
+            .build();
+        assert!(uses_cram_3_1_codecs(&block_content_encoder_map));
     }
 }
@@ -0,0 +1,130 @@
+use std::{
+    collections::VecDeque,
+    io,
+    num::NonZeroUsize,
+    sync::Arc,
+    thread::{self, JoinHandle},
+};
+
+use crossbeam_channel::{Receiver, Sender};
+use noodles_fasta as fasta;
+
+use super::Options;
+use crate::{data_container, DataContainer};
+
+type Encoded = io::Result<(DataContainer, u64)>;
+type EncodedTx = Sender<Encoded>;
+type EncodedRx = Receiver<Encoded>;
+type Job = (
+    data_container::Builder,
+    Vec<Option<fasta::record::Sequence>>,
+    EncodedTx,
+);
+type EncoderTx = Sender<Job>;
+type EncoderRx = Receiver<Job>;
+
+/// A pool of workers that build (and compress) data containers.
+///
+/// Data containers are received in the order they are sent.
+#[derive(Debug)]
+pub(super) struct EncoderPool {
+    encoder_tx: Option<EncoderTx>,
+    encoder_handles: Vec<JoinHandle<()>>,
+    queue: VecDeque<EncodedRx>,
+    queue_capacity: usize,
+}
+
+impl EncoderPool {
+    pub fn new(worker_count: NonZeroUsize, options: Options) -> Self {
+        let worker_count = worker_count.get();
+
+        let (encoder_tx, encoder_rx) = crossbeam_channel::bounded(worker_count);
+        let encoder_handles = spawn_encoders(worker_count, Arc::new(options), encoder_rx);
+
+        Self {
+            encoder_tx: Some(encoder_tx),
+            encoder_handles,
+            queue: VecDeque::with_capacity(worker_count),
+            queue_capacity: worker_count,
+        }
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.queue.len() >= self.queue_capacity
+    }
+
+    pub fn send(
+        &mut self,
+        builder: data_container::Builder,
+        reference_sequences: Vec<Option<fasta::record::Sequence>>,
+    ) -> io::Result<()> {
+        let (encoded_tx, encoded_rx) = crossbeam_channel::bounded(1);
+
+        let encoder_tx = self.encoder_tx.as_ref().ok_or_else(disconnected)?;
+        encoder_tx
+            .send((builder, reference_sequences, encoded_tx))
+            .map_err(|_| disconnected())?;
+
+        self.queue.push_back(encoded_rx);
+
+        Ok(())
+    }
+
+    /// Receives the next data container and its base count.
+    pub fn recv(&mut self) -> Option<Encoded> {
+        let encoded_rx = self.queue.pop_front()?;
+
+        match encoded_rx.recv() {
+            Ok(result) => Some(result),
+            Err(_) => {
+                self.queue.clear();
+                Some(Err(disconnected()))
+            }
+        }
+    }
+}
+
+impl Drop for EncoderPool {
+    fn drop(&mut self) {
+        self.encoder_tx.take();
+        self.queue.clear();
+
+        for handle in self.encoder_handles.drain(..) {
+            let _ = handle.join();
+        }
+    }
+}
+
+// An encoder worker stops early only if it panics.
+fn disconnected() -> io::Error {
+    io::Error::new(io::ErrorKind::Other, "data container encoder disconnected")
+}
+
+fn spawn_encoders(
+    worker_count: usize,
+    options: Arc<Options>,
+    encoder_rx: EncoderRx,
+) -> Vec<JoinHandle<()>> {
+    let mut handles = Vec::with_capacity(worker_count);
+
+    for _ in 0..worker_count {
+        let options = options.clone();
+        let encoder_rx = encoder_rx.clone();
+
+        handles.push(thread::spawn(move || {
+            while let Ok((builder, reference_sequences, encoded_tx)) = encoder_rx.recv() {
+                let base_count = builder.base_count();
+
+                let result = builder
+                    .build_with_reference_sequences(&options, &reference_sequences)
+                    .map(|data_container| (data_container, base_count));
+
+                if encoded_tx.send(result).is_err() {
+                    continue;
+                }
+            }
+        }));
+    }
+
+    handles
+}
@@ -1,11 +1,18 @@
-use crate::{data_container::BlockContentEncoderMap, file_definition::Version};
+use super::QualityScoresMode;
+use crate::{
+    data_container::{compression_header::SubstitutionMatrix, BlockContentEncoderMap},
+    file_definition::Version,
+};
 
 #[derive(Clone, Debug)]
 pub struct Options {
     pub preserve_read_names: bool,
     pub encode_alignment_start_positions_as_deltas: bool,
+    pub embed_reference_sequences: bool,
     pub version: Version,
     pub block_content_encoder_map: BlockContentEncoderMap,
+    pub quality_scores_mode: QualityScoresMode,
+    pub substitution_matrix: Option<SubstitutionMatrix>,
 }
 
 impl Default for Options {
@@ -13,8 +20,11 @@ impl Default for Options {
         Self {
             preserve_read_names: true,
             encode_alignment_start_positions_as_deltas: true,
+            embed_reference_sequences: false,
             version: Version::default(),
             block_content_encoder_map: BlockContentEncoderMap::default(),
+            quality_scores_mode: QualityScoresMode::default(),
+            substitution_matrix: None,
         }
     }
 }
@@ -0,0 +1,322 @@
+use std::io;
+
+use noodles_core::Position;
+use noodles_fasta as fasta;
+use noodles_sam::{self as sam, record::quality_scores::Score};
+
+use crate::{
+    record::{resolve::resolve_quality_scores, Feature, Flags},
+    Record,
+};
+
+/// A CRAM writer quality scores mode.
+///
+/// This describes how quality scores are written. All modes other than [`Self::Lossless`] discard
+/// information, i.e., the quality scores read back are not necessarily the ones that were written.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum QualityScoresMode {
+    /// Quality scores are written as is.
+    #[default]
+    Lossless,
+    /// Quality scores are reduced to the 8 levels used by Illumina.
+    ///
+    /// | score  | binned |
+    /// |--------|--------|
+    /// | 0–1    | 0–1    |
+    /// | 2–9    | 6      |
+    /// | 10–19  | 15     |
+    /// | 20–24  | 22     |
+    /// | 25–29  | 27     |
+    /// | 30–34  | 33     |
+    /// | 35–39  | 37     |
+    /// | ≥ 40   | 40     |
+    Illumina8Binning,
+    /// All quality scores are replaced with the given score.
+    Fixed(Score),
+    /// Quality scores are only kept at read positions with bases that differ from the reference
+    /// sequence.
+    ///
+    /// Quality scores are stored in read features rather than as an array, and the CRAM record
+    /// flag `QUALITY_SCORES_STORED_AS_ARRAY` is unset. The remaining quality scores are read back
+    /// as 0. Unmapped records have no reference sequence to differ from, so all their quality
+    /// scores are discarded.
+    ///
+    /// This requires the reference sequences of mapped records.
+    PreserveMismatches,
+}
+
+impl QualityScoresMode {
+    pub(crate) fn apply(
+        self,
+        reference_sequence_repository: &fasta::Repository,
+        header: &sam::Header,
+        record: &mut Record,
+    ) -> io::Result<()> {
+        if record.quality_scores().is_empty() {
+            return Ok(());
+        }
+
+        match self {
+            Self::Lossless => {}
+            Self::Illumina8Binning => map_scores(record, bin_score),
+            Self::Fixed(score) => map_scores(record, |_| score),
+            Self::PreserveMismatches => {
+                if record.flags().is_unmapped() {
+                    record
+                        .cram_bit_flags
+                        .remove(Flags::QUALITY_SCORES_STORED_AS_ARRAY);
+                    record.quality_scores = sam::record::QualityScores::default();
+                } else {
+                    let reference_sequence =
+                        get_reference_sequence(reference_sequence_repository, header, record)?;
+                    preserve_mismatches(record, &reference_sequence)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn map_scores<F>(record: &mut Record, f: F)
+where
+    F: Fn(Score) -> Score,
+{
+    for score in record.quality_scores.as_mut() {
+        *score = f(*score);
+    }
+
+    for feature in record.features.iter_mut() {
+        match feature {
+            Feature::Scores(_, scores) => {
+                for score in scores {
+                    *score = f(*score);
+                }
+            }
+            Feature::ReadBase(_, _, score) | Feature::QualityScore(_, score) => *score = f(*score),
+            _ => {}
+        }
+    }
+}
+
+fn bin_score(score: Score) -> Score {
+    let n = match u8::from(score) {
+        n @ 0..=1 => n,
+        2..=9 => 6,
+        10..=19 => 15,
+        20..=24 => 22,
+        25..=29 => 27,
+        30..=34 => 33,
+        35..=39 => 37,
+        _ => 40,
+    };
+
+    // All binned scores are valid scores.
+    Score::try_from(n).unwrap()
+}
+
+fn get_reference_sequence(
+    reference_sequence_repository: &fasta::Repository,
+    header: &sam::Header,
+    record: &Record,
+) -> io::Result<fasta::record::Sequence> {
+    let reference_sequence_name = record
+        .reference_sequence_id()
+        .and_then(|id| header.reference_sequences().get_index(id))
+        .map(|(name, _)| name)
+        .ok_or_else(|| {
+            io::Error::new(io::ErrorKind::InvalidInput, "invalid reference sequence ID")
+        })?;
+
+    reference_sequence_repository
+        .get(reference_sequence_name)
+        .transpose()?
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("missing reference sequence: {reference_sequence_name}"),
+            )
+        })
+}
+
+fn preserve_mismatches(
+    record: &mut Record,
+    reference_sequence: &fasta::record::Sequence,
+) -> io::Result<()> {
+    let alignment_start = record
+        .alignment_start()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "missing alignment start"))?;
+
+    let quality_scores = record.quality_scores.clone();
+
+    let get_score = |read_position: Position| {
+        quality_scores
+            .as_ref()
+            .get(usize::from(read_position) - 1)
+            .copied()
+            .ok_or_else(|| {
+                io::Error::new(io::ErrorKind::InvalidInput, "invalid quality scores length")
+            })
+    };
+
+    let is_mismatch = |reference_position: Position, base: sam::record::sequence::Base| {
+        reference_sequence
+            .get(reference_position)
+            .map(|&reference_base| !u8::from(base).eq_ignore_ascii_case(&reference_base))
+            .unwrap_or(true)
+    };
+
+    let mut mismatches = Vec::new();
+
+    for ((reference_position, read_position), feature) in
+        record.features.with_positions(alignment_start)
+    {
+        match feature {
+            Feature::Bases(_, bases) => {
+                for (i, &base) in bases.iter().enumerate() {
+                    let reference_position = reference_position
+                        .checked_add(i)
+                        .expect("attempt to add with overflow");
+
+                    if is_mismatch(reference_position, base) {
+                        let read_position = read_position
+                            .checked_add(i)
+                            .expect("attempt to add with overflow");
+
+                        mismatches.push(read_position);
+                    }
+                }
+            }
+            Feature::ReadBase(_, base, _) if is_mismatch(reference_position, *base) => {
+                mismatches.push(read_position);
+            }
+            Feature::Substitution(..) => mismatches.push(read_position),
+            _ => {}
+        }
+    }
+
+    let mut features = Vec::with_capacity(record.features.len() + mismatches.len());
+
+    for feature in record.features.drain(..) {
+        match feature {
+            Feature::Scores(..) | Feature::QualityScore(..) => {}
+            Feature::ReadBase(position, base, _) => {
+                let score = if mismatches.contains(&position) {
+                    get_score(position)?
+                } else {
+                    Score::MIN
+                };
+
+                features.push(Feature::ReadBase(position, base, score));
+            }
+            _ => features.push(feature),
+        }
+    }
+
+    for position in mismatches {
+        let has_read_base = features
+            .iter()
+            .any(|feature| matches!(feature, Feature::ReadBase(p, ..) if *p == position));
+
+        if !has_read_base {
+            features.push(Feature::QualityScore(position, get_score(position)?));
+        }
+    }
+
+    // This is stable, so features at the same position keep their relative order.
+    features.sort_by_key(|feature| feature.position());
+
+    record.features = features.into();
+    record.quality_scores = resolve_quality_scores(&record.features, record.read_length());
+    record
+        .cram_bit_flags
+        .remove(Flags::QUALITY_SCORES_STORED_AS_ARRAY);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bin_score() -> Result<(), Box<dyn std::error::Error>> {
+        let t = |n: u8, expected: u8| -> Result<(), Box<dyn std::error::Error>> {
+            assert_eq!(bin_score(Score::try_from(n)?), Score::try_from(expected)?);
+            Ok(())
+        };
+
+        t(0, 0)?;
+        t(1, 1)?;
+        t(2, 6)?;
+        t(9, 6)?;
+        t(10, 15)?;
+        t(19, 15)?;
+        t(20, 22)?;
+        t(24, 22)?;
+        t(25, 27)?;
+        t(29, 27)?;
+        t(30, 33)?;
+        t(34, 33)?;
+        t(35, 37)?;
+        t(39, 37)?;
+        t(40, 40)?;
+        t(93, 40)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_preserve_mismatches() -> Result<(), Box<dyn std::error::Error>> {
+        use sam::record::sequence::Base;
+
+        use crate::record::Features;
+
+        let reference_sequence = fasta::record::Sequence::from(b"ACGTACGT".to_vec());
+
+        // 1S4M, with a mismatch at read position 4 (reference position 4)
+        let mut record = Record::builder()
+            .set_alignment_start(Position::try_from(2)?)
+            .set_read_length(5)
+            .set_features(
+                vec![
+                    Feature::SoftClip(Position::try_from(1)?, vec![Base::N]),
+                    Feature::Scores(Position::try_from(1)?, vec![Score::try_from(10)?]),
+                    Feature::Bases(
+                        Position::try_from(2)?,
+                        vec![Base::C, Base::G, Base::A, Base::A],
+                    ),
+                    Feature::Scores(
+                        Position::try_from(2)?,
+                        vec![
+                            Score::try_from(20)?,
+                            Score::try_from(30)?,
+                            Score::try_from(40)?,
+                            Score::try_from(50)?,
+                        ],
+                    ),
+                ]
+                .into(),
+            )
+            .set_quality_scores("+5?IS".parse()?)
+            .build();
+
+        preserve_mismatches(&mut record, &reference_sequence)?;
+
+        assert!(!record.cram_flags().are_quality_scores_stored_as_array());
+        assert_eq!(
+            record.features(),
+            &Features::from(vec![
+                Feature::SoftClip(Position::try_from(1)?, vec![Base::N]),
+                Feature::Bases(
+                    Position::try_from(2)?,
+                    vec![Base::C, Base::G, Base::A, Base::A],
+                ),
+                Feature::QualityScore(Position::try_from(4)?, Score::try_from(40)?),
+            ])
+        );
+        assert_eq!(record.quality_scores(), &"!!!I!".parse()?);
+
+        Ok(())
+    }
+}
@@ -25,6 +25,7 @@ use crate::{
         },
         CompressionHeader, ReferenceSequenceContext,
     },
+    huffman::CanonicalHuffmanEncoder,
     record::{
         feature::{self, substitution},
         Feature, Flags, NextMateFlags,
@@ -56,9 +57,13 @@ impl fmt::Display for WriteRecordError {
     }
 }
 
+// Canonical Huffman encoders by alphabet and bit lengths.
+type HuffmanEncoders<'c> = HashMap<(&'c [i32], &'c [u32]), CanonicalHuffmanEncoder>;
+
 pub struct Writer<'a, W, X> {
     compression_header: &'a CompressionHeader,
     core_data_writer: &'a mut BitWriter<W>,
+    huffman_encoders: HuffmanEncoders<'a>,
     external_data_writers: &'a mut HashMap<block::ContentId, X>,
     reference_sequence_context: ReferenceSequenceContext,
     prev_alignment_start: Option<Position>,
@@ -83,6 +88,7 @@ where
         Self {
             compression_header,
             core_data_writer,
+            huffman_encoders: HuffmanEncoders::new(),
             external_data_writers,
             reference_sequence_context,
             prev_alignment_start: initial_alignment_start,
@@ -126,6 +132,7 @@ where
         encode_itf8(
             encoding,
             self.core_data_writer,
+            &mut self.huffman_encoders,
             self.external_data_writers,
             bam_bit_flags,
         )
@@ -142,6 +149,7 @@ where
         encode_itf8(
             encoding,
             self.core_data_writer,
+            &mut self.huffman_encoders,
             self.external_data_writers,
             cram_bit_flags,
         )
@@ -182,6 +190,7 @@ where
         encode_itf8(
             encoding,
             self.core_data_writer,
+            &mut self.huffman_encoders,
             self.external_data_writers,
             reference_id,
         )
@@ -199,6 +208,7 @@ where
         encode_itf8(
             encoding,
             self.core_data_writer,
+            &mut self.huffman_encoders,
             self.external_data_writers,
             len,
         )
@@ -245,6 +255,7 @@ where
         encode_itf8(
             encoding,
             self.core_data_writer,
+            &mut self.huffman_encoders,
             self.external_data_writers,
             alignment_start_or_delta,
         )
@@ -268,6 +279,7 @@ where
         encode_itf8(
             encoding,
             self.core_data_writer,
+            &mut self.huffman_encoders,
             self.external_data_writers,
             read_group,
         )
@@ -292,6 +304,7 @@ where
         encode_byte_array(
             encoding,
             self.core_data_writer,
+            &mut self.huffman_encoders,
             self.external_data_writers,
             read_name,
         )
@@ -336,6 +349,7 @@ where
                 encode_itf8(
                     encoding,
                     self.core_data_writer,
+                    &mut self.huffman_encoders,
                     self.external_data_writers,
                     next_mate_bit_flags,
                 )
@@ -371,6 +385,7 @@ where
         encode_itf8(
             encoding,
             self.core_data_writer,
+            &mut self.huffman_encoders,
             self.external_data_writers,
             raw_next_fragment_reference_sequence_id,
         )
@@ -401,6 +416,7 @@ where
         encode_itf8(
             encoding,
             self.core_data_writer,
+            &mut self.huffman_encoders,
             self.external_data_writers,
             position,
         )
@@ -420,6 +436,7 @@ where
                 encode_itf8(
                     encoding,
                     self.core_data_writer,
+                    &mut self.huffman_encoders,
                     self.external_data_writers,
                     template_size,
                 )
@@ -447,6 +464,7 @@ where
         encode_itf8(
             encoding,
             self.core_data_writer,
+            &mut self.huffman_encoders,
             self.external_data_writers,
             n,
         )
@@ -497,6 +515,7 @@ where
             encode_byte_array(
                 encoding,
                 self.core_data_writer,
+                &mut self.huffman_encoders,
                 self.external_data_writers,
                 &buf,
             )?;
@@ -517,6 +536,7 @@ where
         encode_itf8(
             encoding,
             self.core_data_writer,
+            &mut self.huffman_encoders,
             self.external_data_writers,
             n,
         )
@@ -536,9 +556,7 @@ where
         self.write_mapping_quality(record.mapping_quality())?;
 
         if record.cram_flags().are_quality_scores_stored_as_array() {
-            for &score in record.quality_scores().as_ref() {
-                self.write_quality_score(score)?;
-            }
+            self.write_quality_scores_stored_as_array(record)?;
         }
 
         Ok(())
@@ -562,6 +580,7 @@ where
         encode_itf8(
             encoding,
             self.core_data_writer,
+            &mut self.huffman_encoders,
             self.external_data_writers,
             number_of_read_features,
         )
@@ -630,6 +649,7 @@ where
                 encode_byte(
                     encoding,
                     self.core_data_writer,
+                    &mut self.huffman_encoders,
                     self.external_data_writers,
                     feature_code,
                 )
@@ -654,6 +674,7 @@ where
         encode_itf8(
             encoding,
             self.core_data_writer,
+            &mut self.huffman_encoders,
             self.external_data_writers,
             position,
         )
@@ -676,6 +697,7 @@ where
         encode_byte_array(
             encoding,
             self.core_data_writer,
+            &mut self.huffman_encoders,
             self.external_data_writers,
             &raw_bases,
         )
@@ -700,6 +722,7 @@ where
         encode_byte_array(
             encoding,
             self.core_data_writer,
+            &mut self.huffman_encoders,
             self.external_data_writers,
             &scores,
         )
@@ -722,12 +745,33 @@ where
         encode_byte(
             encoding,
             self.core_data_writer,
+            &mut self.huffman_encoders,
             self.external_data_writers,
             raw_base,
         )
     }
 
+    fn write_quality_scores_stored_as_array(&mut self, record: &Record) -> io::Result<()> {
+        const MISSING_QUALITY_SCORE: u8 = 0xff;
+
+        if record.quality_scores().is_empty() {
+            for _ in 0..record.read_length() {
+                self.write_raw_quality_score(MISSING_QUALITY_SCORE)?;
+            }
+        } else {
+            for &score in record.quality_scores().as_ref() {
+                self.write_quality_score(score)?;
+            }
+        }
+
+        Ok(())
+    }
+
     fn write_quality_score(&mut self, quality_score: Score) -> io::Result<()> {
+        self.write_raw_quality_score(u8::from(quality_score))
+    }
+
+    fn write_raw_quality_score(&mut self, score: u8) -> io::Result<()> {
         let encoding = self
             .compression_header
             .data_series_encoding_map()
@@ -739,11 +783,10 @@ where
                 )
             })?;
 
-        let score = u8::from(quality_score);
-
         encode_byte(
             encoding,
             self.core_data_writer,
+            &mut self.huffman_encoders,
             self.external_data_writers,
             score,
         )
@@ -767,9 +810,14 @@ where
             .substitution_matrix();
 
         let code = match value {
-            substitution::Value::Bases(reference_base, read_base) => {
-                substitution_matrix.find_code(reference_base, read_base)
-            }
+            substitution::Value::Bases(reference_base, read_base) => substitution_matrix
+                .find_code(reference_base, read_base)
+                .ok_or_else(|| {
+                    io::Error::new(
+                        io::ErrorKind::InvalidInput,
+                        format!("invalid base substitution: {reference_base:?} -> {read_base:?}"),
+                    )
+                })?,
             substitution::Value::Code(_) => {
                 return Err(io::Error::new(
                     io::ErrorKind::InvalidInput,
@@ -781,6 +829,7 @@ where
         encode_byte(
             encoding,
             self.core_data_writer,
+            &mut self.huffman_encoders,
             self.external_data_writers,
             code,
         )
@@ -803,6 +852,7 @@ where
         encode_byte_array(
             encoding,
             self.core_data_writer,
+            &mut self.huffman_encoders,
             self.external_data_writers,
             &raw_bases,
         )
@@ -825,6 +875,7 @@ where
         encode_itf8(
             encoding,
             self.core_data_writer,
+            &mut self.huffman_encoders,
             self.external_data_writers,
             n,
         )
@@ -847,6 +898,7 @@ where
         encode_itf8(
             encoding,
             self.core_data_writer,
+            &mut self.huffman_encoders,
             self.external_data_writers,
             n,
         )
@@ -869,6 +921,7 @@ where
         encode_byte_array(
             encoding,
             self.core_data_writer,
+            &mut self.huffman_encoders,
             self.external_data_writers,
             &raw_bases,
         )
@@ -891,6 +944,7 @@ where
         encode_itf8(
             encoding,
             self.core_data_writer,
+            &mut self.huffman_encoders,
             self.external_data_writers,
             n,
         )
@@ -913,6 +967,7 @@ where
         encode_itf8(
             encoding,
             self.core_data_writer,
+            &mut self.huffman_encoders,
             self.external_data_writers,
             n,
         )
@@ -942,6 +997,7 @@ where
         encode_itf8(
             encoding,
             self.core_data_writer,
+            &mut self.huffman_encoders,
             self.external_data_writers,
             mapping_quality,
         )
@@ -953,18 +1009,17 @@ where
         }
 
         if record.cram_flags().are_quality_scores_stored_as_array() {
-            for &score in record.quality_scores().as_ref() {
-                self.write_quality_score(score)?;
-            }
+            self.write_quality_scores_stored_as_array(record)?;
         }
 
         Ok(())
     }
 }
 
-fn encode_byte<W, X>(
-    encoding: &Encoding<Byte>,
-    _core_data_writer: &mut BitWriter<W>,
+fn encode_byte<'c, W, X>(
+    encoding: &'c Encoding<Byte>,
+    core_data_writer: &mut BitWriter<W>,
+    huffman_encoders: &mut HuffmanEncoders<'c>,
     external_data_writers: &mut HashMap<block::ContentId, X>,
     value: u8,
 ) -> io::Result<()>
@@ -985,13 +1040,20 @@ where
 
             writer.write_u8(value)
         }
-        _ => todo!("encode_byte: {:?}", encoding),
+        Byte::Huffman(alphabet, bit_lens) => encode_huffman(
+            core_data_writer,
+            huffman_encoders,
+            alphabet,
+            bit_lens,
+            i32::from(value),
+        ),
     }
 }
 
-fn encode_itf8<W, X>(
-    encoding: &Encoding<Integer>,
-    _core_data_writer: &mut BitWriter<W>,
+fn encode_itf8<'c, W, X>(
+    encoding: &'c Encoding<Integer>,
+    core_data_writer: &mut BitWriter<W>,
+    huffman_encoders: &mut HuffmanEncoders<'c>,
     external_data_writers: &mut HashMap<block::ContentId, X>,
     value: i32,
 ) -> io::Result<()>
@@ -1012,13 +1074,67 @@ where
 
             write_itf8(writer, value)
         }
-        _ => todo!("encode_itf8: {:?}", encoding),
+        Integer::Huffman(alphabet, bit_lens) => encode_huffman(
+            core_data_writer,
+            huffman_encoders,
+            alphabet,
+            bit_lens,
+            value,
+        ),
+        Integer::Beta(offset, len) => {
+            let n = value
+                .checked_add(*offset)
+                .and_then(|n| u32::try_from(n).ok())
+                .filter(|&n| *len >= 32 || n >> len == 0)
+                .ok_or_else(|| {
+                    io::Error::new(
+                        io::ErrorKind::InvalidInput,
+                        format!("value cannot be beta encoded with offset {offset} and length {len}: {value}"),
+                    )
+                })?;
+
+            core_data_writer.write_u32(n, *len as usize)
+        }
+        _ => Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("unsupported integer encoding: {encoding:?}"),
+        )),
     }
 }
 
-fn encode_byte_array<W, X>(
-    encoding: &Encoding<ByteArray>,
+fn encode_huffman<'c, W>(
     core_data_writer: &mut BitWriter<W>,
+    huffman_encoders: &mut HuffmanEncoders<'c>,
+    alphabet: &'c [i32],
+    bit_lens: &'c [u32],
+    value: i32,
+) -> io::Result<()>
+where
+    W: Write,
+{
+    // A single symbol alphabet is a constant and is not written.
+    if let [symbol] = alphabet {
+        if *symbol == value {
+            Ok(())
+        } else {
+            Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("symbol not in alphabet: {value}"),
+            ))
+        }
+    } else {
+        let encoder = huffman_encoders
+            .entry((alphabet, bit_lens))
+            .or_insert_with(|| CanonicalHuffmanEncoder::new(alphabet, bit_lens));
+
+        encoder.encode(core_data_writer, value)
+    }
+}
+
+fn encode_byte_array<'c, W, X>(
+    encoding: &'c Encoding<ByteArray>,
+    core_data_writer: &mut BitWriter<W>,
+    huffman_encoders: &mut HuffmanEncoders<'c>,
     external_data_writers: &mut HashMap<block::ContentId, X>,
     data: &[u8],
 ) -> io::Result<()>
@@ -1030,12 +1146,19 @@ where
         ByteArray::ByteArrayLen(len_encoding, value_encoding) => {
             let len = i32::try_from(data.len())
                 .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
-            encode_itf8(len_encoding, core_data_writer, external_data_writers, len)?;
+            encode_itf8(
+                len_encoding,
+                core_data_writer,
+                huffman_encoders,
+                external_data_writers,
+                len,
+            )?;
 
             for &value in data {
                 encode_byte(
                     value_encoding,
                     core_data_writer,
+                    huffman_encoders,
                     external_data_writers,
                     value,
                 )?;
@@ -1060,3 +1183,64 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_itf8() -> io::Result<()> {
+        fn t(encoding: &Encoding<Integer>, value: i32, expected: &[u8]) -> io::Result<()> {
+            let mut core_data_writer = BitWriter::new(Vec::new());
+            let mut external_data_writers: HashMap<block::ContentId, Vec<u8>> = HashMap::new();
+            encode_itf8(
+                encoding,
+                &mut core_data_writer,
+                &mut HuffmanEncoders::new(),
+                &mut external_data_writers,
+                value,
+            )?;
+            assert_eq!(core_data_writer.finish()?, expected);
+            Ok(())
+        }
+
+        t(
+            &Encoding::new(Integer::Huffman(vec![0x4e], vec![0])),
+            0x4e,
+            &[],
+        )?;
+        t(
+            &Encoding::new(Integer::Huffman(vec![0x4e, 0x44, 0x4c], vec![1, 2, 2])),
+            0x4c,
+            &[0b11000000],
+        )?;
+        t(&Encoding::new(Integer::Beta(1, 3)), 3, &[0b10000000])?;
+
+        let mut core_data_writer = BitWriter::new(Vec::new());
+        let mut external_data_writers: HashMap<block::ContentId, Vec<u8>> = HashMap::new();
+
+        assert!(matches!(
+            encode_itf8(
+                &Encoding::new(Integer::Beta(1, 3)),
+                &mut core_data_writer,
+                &mut HuffmanEncoders::new(),
+                &mut external_data_writers,
+                8,
+            ),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        assert!(matches!(
+            encode_itf8(
+                &Encoding::new(Integer::Huffman(vec![0x4e], vec![0])),
+                &mut core_data_writer,
+                &mut HuffmanEncoders::new(),
+                &mut external_data_writers,
+                0x44,
+            ),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+}
@@ -1,32 +1,133 @@
 //! Binning index utilities.
 
+mod check;
 mod reference_sequence_ext;
 
-pub use reference_sequence_ext::ReferenceSequenceExt;
+pub use self::{
+    check::{check, check_with},
+    reference_sequence_ext::ReferenceSequenceExt,
+};
 
 use std::io;
 
 use noodles_bgzf as bgzf;
-use noodles_core::region::Interval;
+use noodles_core::{region::Interval, Position};
 
-use super::index::reference_sequence::bin::Chunk;
+use super::index::reference_sequence::{bin::Chunk, Metadata};
 
 /// A binning index.
+///
+/// This is implemented by the BAM index (BAI), coordinate-sorted index (CSI), and tabix index
+/// types, which allows readers to query records using any of them.
 pub trait BinningIndex {
     /// The returned output indexed reference sequence.
     type ReferenceSequence: ReferenceSequenceExt;
 
+    /// Returns the number of bits for the minimum interval.
+    fn min_shift(&self) -> u8;
+
+    /// Returns the depth of the binning index.
+    fn depth(&self) -> u8;
+
     /// Returns a list of indexed reference sequences.
     fn reference_sequences(&self) -> &[Self::ReferenceSequence];
 
+    /// Returns the number of indexed reference sequences.
+    fn reference_sequence_count(&self) -> usize {
+        self.reference_sequences().len()
+    }
+
+    /// Returns the metadata of the reference sequence with the given ID, if any.
+    fn metadata(&self, reference_sequence_id: usize) -> Option<&Metadata> {
+        self.reference_sequences()
+            .get(reference_sequence_id)
+            .and_then(|reference_sequence| reference_sequence.metadata())
+    }
+
+    /// Returns the lower bound of the file position of records that can overlap the given start
+    /// position.
+    ///
+    /// This uses the linear index or, for indices without one, the bin offsets. Chunks that end
+    /// before this position can be skipped.
+    fn min_offset(
+        &self,
+        reference_sequence_id: usize,
+        start: Position,
+    ) -> io::Result<bgzf::VirtualPosition> {
+        let reference_sequence = self
+            .reference_sequences()
+            .get(reference_sequence_id)
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("invalid reference sequence ID: {reference_sequence_id}"),
+                )
+            })?;
+
+        Ok(reference_sequence.min_offset(self.min_shift(), self.depth(), start))
+    }
+
     /// Returns the number of unplaced, unmapped records in the associated file.
     fn unplaced_unmapped_record_count(&self) -> Option<u64>;
 
+    /// Returns the reference sequence names stored in the index header, if any.
+    ///
+    /// BAI indices do not have a header, so this is `None` by default.
+    fn reference_sequence_names(&self) -> Option<Vec<&str>> {
+        None
+    }
+
     /// Returns the chunks that overlap with the given region.
+    ///
+    /// The chunks are optimized for reading: they are sorted by start position, chunks that end
+    /// at or before the lower bound given by [`Self::min_offset`] are removed, and overlapping
+    /// and adjacent chunks are merged.
     fn query<I>(&self, reference_sequence_id: usize, interval: I) -> io::Result<Vec<Chunk>>
     where
         I: Into<Interval>;
 
+    /// Returns the chunks that overlap with any of the given regions.
+    ///
+    /// A region is a reference sequence ID and an interval. The chunks of all regions are
+    /// deduplicated, sorted by start position, and merged, i.e., the result is a plan to read the
+    /// file in a single forward pass, and no part of the file is read more than once.
+    ///
+    /// Because chunks of different regions can be merged, records read from the returned chunks
+    /// must be filtered by the regions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_csi::{self as csi, index::ReferenceSequence, BinningIndex};
+    ///
+    /// let index = csi::Index::builder()
+    ///     .set_reference_sequences(vec![ReferenceSequence::new(Vec::new(), None); 2])
+    ///     .build();
+    ///
+    /// let chunks = index.query_regions([
+    ///     (0, Position::try_from(8)?..=Position::try_from(13)?),
+    ///     (1, Position::try_from(21)?..=Position::try_from(34)?),
+    /// ])?;
+    ///
+    /// assert!(chunks.is_empty());
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    fn query_regions<I, J>(&self, regions: I) -> io::Result<Vec<Chunk>>
+    where
+        I: IntoIterator<Item = (usize, J)>,
+        J: Into<Interval>,
+    {
+        let mut chunks = Vec::new();
+
+        for (reference_sequence_id, interval) in regions {
+            let region_chunks = self.query(reference_sequence_id, interval)?;
+            chunks.extend(region_chunks);
+        }
+
+        Ok(merge_chunks(&chunks))
+    }
+
     /// Returns the start position of the first record in the last linear bin.
     ///
     /// This is the closest position to the unplaced, unmapped records, if any, that is available
@@ -170,6 +271,91 @@ mod tests {
         ]
     }
 
+    #[test]
+    fn test_default_methods() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::Index;
+
+        let mut indexer = Index::indexer();
+        indexer.add_record(
+            1,
+            Position::try_from(8)?,
+            Position::try_from(13)?,
+            Chunk::new(
+                bgzf::VirtualPosition::from(144),
+                bgzf::VirtualPosition::from(233),
+            ),
+        )?;
+        let index = indexer.build(3);
+
+        assert_eq!(index.reference_sequence_count(), 3);
+
+        assert!(index.metadata(0).is_none());
+        assert_eq!(
+            index.metadata(1),
+            Some(&Metadata::new(
+                bgzf::VirtualPosition::from(144),
+                bgzf::VirtualPosition::from(233),
+                1,
+                0
+            ))
+        );
+        assert!(index.metadata(3).is_none());
+
+        assert_eq!(
+            index.min_offset(1, Position::try_from(8)?)?,
+            bgzf::VirtualPosition::from(144)
+        );
+        assert!(index.min_offset(3, Position::MIN).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_query_regions() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::Index;
+
+        let mut indexer = Index::indexer();
+
+        for (reference_sequence_id, start, end, chunk_start, chunk_end) in [
+            (0, 8, 13, 0, 144),
+            (0, 21, 34, 144, 233),
+            (1, 8, 13, 233, 377),
+            (1, 8, 55, 377, 610),
+            (1, 1000000, 1000008, 610, 987),
+        ] {
+            indexer.add_record(
+                reference_sequence_id,
+                Position::try_from(start)?,
+                Position::try_from(end)?,
+                Chunk::new(
+                    bgzf::VirtualPosition::from(chunk_start),
+                    bgzf::VirtualPosition::from(chunk_end),
+                ),
+            )?;
+        }
+
+        let index = indexer.build(2);
+
+        let actual = index.query_regions([
+            (0, Position::try_from(8)?..=Position::try_from(21)?),
+            (1, Position::try_from(13)?..=Position::try_from(21)?),
+            (0, Position::try_from(13)?..=Position::try_from(13)?),
+        ])?;
+
+        let expected = [Chunk::new(
+            bgzf::VirtualPosition::from(0),
+            bgzf::VirtualPosition::from(610),
+        )];
+
+        assert_eq!(actual, expected);
+
+        assert!(index
+            .query_regions([(2, Position::MIN..=Position::MIN)])
+            .is_err());
+
+        Ok(())
+    }
+
     #[test]
     fn test_merge_chunks() {
         let chunks = build_chunks();
@@ -0,0 +1,393 @@
+use std::io::{self, Read, Seek, SeekFrom};
+
+use noodles_bgzf as bgzf;
+
+use super::{BinningIndex, ReferenceSequenceExt};
+
+/// Checks that a binning index is consistent with its associated data file.
+///
+/// This validates that
+///
+///   * the number of indexed reference sequences is the same as the number of given reference
+///     sequence names, typically the reference sequences in the data file header;
+///   * if the index has a header, its reference sequence names are the same as the given names;
+///   * all chunk and metadata virtual positions point within the data file;
+///   * chunk start positions are not after their end positions;
+///   * the linear index, if any, is non-decreasing; and
+///   * the reader can seek to the start of each chunk.
+///
+/// An index that was built for a different (e.g., older) version of the data file typically fails
+/// one of these checks.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::{fs::File, io};
+/// use noodles_bgzf as bgzf;
+/// use noodles_csi::{self as csi, binning_index};
+///
+/// let index = csi::read("sample.bcf.csi")?;
+/// let mut reader = File::open("sample.bcf").map(bgzf::Reader::new)?;
+/// binning_index::check(&index, &mut reader, &["sq0", "sq1"])?;
+/// # Ok::<_, io::Error>(())
+/// ```
+pub fn check<I, R, S>(
+    index: &I,
+    reader: &mut bgzf::Reader<R>,
+    reference_sequence_names: &[S],
+) -> io::Result<()>
+where
+    I: BinningIndex,
+    R: Read + Seek,
+    S: AsRef<str>,
+{
+    check_with(index, reader, reference_sequence_names, |_, _| Ok(()))
+}
+
+/// Checks that a binning index is consistent with its associated data file, spot-decoding each
+/// chunk.
+///
+/// This runs the same checks as [`check`]. Additionally, after seeking to the start of each
+/// chunk, `f` is called with the reference sequence ID and the reader, e.g., to decode the first
+/// record in the chunk and verify that it is placed on the expected reference sequence.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::{fs::File, io::{self, BufRead}};
+/// use noodles_bgzf as bgzf;
+/// use noodles_csi::{self as csi, binning_index};
+///
+/// let index = csi::read("sample.bed.gz.csi")?;
+/// let mut reader = File::open("sample.bed.gz").map(bgzf::Reader::new)?;
+///
+/// binning_index::check_with(&index, &mut reader, &["sq0", "sq1"], |_, reader| {
+///     let mut line = String::new();
+///     reader.read_line(&mut line)?;
+///     Ok(())
+/// })?;
+/// # Ok::<_, io::Error>(())
+/// ```
+pub fn check_with<I, R, S, F>(
+    index: &I,
+    reader: &mut bgzf::Reader<R>,
+    reference_sequence_names: &[S],
+    mut f: F,
+) -> io::Result<()>
+where
+    I: BinningIndex,
+    R: Read + Seek,
+    S: AsRef<str>,
+    F: FnMut(usize, &mut bgzf::Reader<R>) -> io::Result<()>,
+{
+    if index.reference_sequence_count() != reference_sequence_names.len() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "reference sequence count mismatch: expected {}, got {}",
+                reference_sequence_names.len(),
+                index.reference_sequence_count()
+            ),
+        ));
+    }
+
+    if let Some(index_reference_sequence_names) = index.reference_sequence_names() {
+        check_reference_sequence_names(reference_sequence_names, &index_reference_sequence_names)?;
+    }
+
+    let file_len = reader.get_mut().seek(SeekFrom::End(0))?;
+
+    for (reference_sequence_id, reference_sequence) in
+        index.reference_sequences().iter().enumerate()
+    {
+        if let Some(metadata) = reference_sequence.metadata() {
+            check_virtual_position(reference_sequence_id, metadata.start_position(), file_len)?;
+            check_virtual_position(reference_sequence_id, metadata.end_position(), file_len)?;
+        }
+
+        if let Some(linear_index) = reference_sequence.linear_index() {
+            check_linear_index(reference_sequence_id, linear_index)?;
+        }
+
+        for chunk in reference_sequence.chunks() {
+            if chunk.start() > chunk.end() {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!(
+                        "reference sequence {reference_sequence_id}: invalid chunk: start ({:?}) > end ({:?})",
+                        chunk.start(),
+                        chunk.end()
+                    ),
+                ));
+            }
+
+            check_virtual_position(reference_sequence_id, chunk.start(), file_len)?;
+            check_virtual_position(reference_sequence_id, chunk.end(), file_len)?;
+
+            reader.seek(chunk.start()).map_err(|e| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!(
+                        "reference sequence {reference_sequence_id}: invalid chunk start ({:?}): {e}",
+                        chunk.start()
+                    ),
+                )
+            })?;
+
+            f(reference_sequence_id, reader)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn check_reference_sequence_names<S>(expected: &[S], actual: &[&str]) -> io::Result<()>
+where
+    S: AsRef<str>,
+{
+    for (reference_sequence_id, (expected_name, actual_name)) in
+        expected.iter().zip(actual).enumerate()
+    {
+        let expected_name = expected_name.as_ref();
+
+        if expected_name != *actual_name {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "reference sequence {reference_sequence_id}: name mismatch: expected {expected_name}, got {actual_name}"
+                ),
+            ));
+        }
+    }
+
+    if expected.len() == actual.len() {
+        Ok(())
+    } else {
+        Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "reference sequence name count mismatch: expected {}, got {}",
+                expected.len(),
+                actual.len()
+            ),
+        ))
+    }
+}
+
+fn check_virtual_position(
+    reference_sequence_id: usize,
+    position: bgzf::VirtualPosition,
+    file_len: u64,
+) -> io::Result<()> {
+    if position.compressed() > file_len {
+        Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "reference sequence {reference_sequence_id}: virtual position ({position:?}) is past the end of the file ({file_len})"
+            ),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+fn check_linear_index(
+    reference_sequence_id: usize,
+    linear_index: &[bgzf::VirtualPosition],
+) -> io::Result<()> {
+    let mut prev = bgzf::VirtualPosition::default();
+
+    // Empty windows are stored as 0 and are not required to be ordered.
+    for &position in linear_index
+        .iter()
+        .filter(|&&position| position != bgzf::VirtualPosition::default())
+    {
+        if position < prev {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("reference sequence {reference_sequence_id}: linear index is not sorted"),
+            ));
+        }
+
+        prev = position;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Cursor, Write};
+
+    use super::*;
+    use crate::index::{
+        reference_sequence::{bin::Chunk, Bin},
+        Index, ReferenceSequence,
+    };
+
+    fn build_data() -> io::Result<Vec<u8>> {
+        let mut writer = bgzf::Writer::new(Vec::new());
+        writer.write_all(b"noodles")?;
+        writer.finish()
+    }
+
+    fn build_index(chunk: Chunk) -> Index {
+        let bins = vec![Bin::new(4681, chunk.start(), vec![chunk])];
+        let reference_sequences = vec![ReferenceSequence::new(bins, None)];
+        Index::builder()
+            .set_reference_sequences(reference_sequences)
+            .build()
+    }
+
+    #[test]
+    fn test_check() -> Result<(), Box<dyn std::error::Error>> {
+        let data = build_data()?;
+
+        let index = build_index(Chunk::new(
+            bgzf::VirtualPosition::try_from((0, 0))?,
+            bgzf::VirtualPosition::try_from((0, 7))?,
+        ));
+
+        let mut reader = bgzf::Reader::new(Cursor::new(&data));
+        check(&index, &mut reader, &["sq0"])?;
+
+        let mut reader = bgzf::Reader::new(Cursor::new(&data));
+        assert!(matches!(
+            check(&index, &mut reader, &["sq0", "sq1"]),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        let index = build_index(Chunk::new(
+            bgzf::VirtualPosition::try_from((0, 7))?,
+            bgzf::VirtualPosition::try_from((0, 0))?,
+        ));
+        let mut reader = bgzf::Reader::new(Cursor::new(&data));
+        assert!(matches!(
+            check(&index, &mut reader, &["sq0"]),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        let index = build_index(Chunk::new(
+            bgzf::VirtualPosition::try_from((0, 0))?,
+            bgzf::VirtualPosition::try_from((65536, 0))?,
+        ));
+        let mut reader = bgzf::Reader::new(Cursor::new(&data));
+        assert!(matches!(
+            check(&index, &mut reader, &["sq0"]),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        let index = build_index(Chunk::new(
+            bgzf::VirtualPosition::try_from((0, 13))?,
+            bgzf::VirtualPosition::try_from((0, 21))?,
+        ));
+        let mut reader = bgzf::Reader::new(Cursor::new(&data));
+        assert!(matches!(
+            check(&index, &mut reader, &["sq0"]),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_check_with_index_reference_sequence_names() -> Result<(), Box<dyn std::error::Error>> {
+        const AUX: [u8; 32] = [
+            0x00, 0x00, 0x00, 0x00, // format = 0 (generic)
+            0x01, 0x00, 0x00, 0x00, // col_seq = 1
+            0x02, 0x00, 0x00, 0x00, // col_beg = 2
+            0x03, 0x00, 0x00, 0x00, // col_end = 3
+            b'#', 0x00, 0x00, 0x00, // meta = '#'
+            0x00, 0x00, 0x00, 0x00, // skip = 0
+            0x04, 0x00, 0x00, 0x00, // l_nm = 4
+            b's', b'q', b'0', 0x00, // names = ["sq0"]
+        ];
+
+        let data = build_data()?;
+
+        let chunk = Chunk::new(
+            bgzf::VirtualPosition::try_from((0, 0))?,
+            bgzf::VirtualPosition::try_from((0, 7))?,
+        );
+        let bins = vec![Bin::new(4681, chunk.start(), vec![chunk])];
+        let index = Index::builder()
+            .set_aux(AUX.to_vec())
+            .set_reference_sequences(vec![ReferenceSequence::new(bins, None)])
+            .build();
+
+        let mut reader = bgzf::Reader::new(Cursor::new(&data));
+        check(&index, &mut reader, &["sq0"])?;
+
+        let mut reader = bgzf::Reader::new(Cursor::new(&data));
+        assert!(matches!(
+            check(&index, &mut reader, &["sq1"]),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_check_with() -> Result<(), Box<dyn std::error::Error>> {
+        let data = build_data()?;
+
+        let index = build_index(Chunk::new(
+            bgzf::VirtualPosition::try_from((0, 3))?,
+            bgzf::VirtualPosition::try_from((0, 7))?,
+        ));
+
+        let mut reader = bgzf::Reader::new(Cursor::new(&data));
+        let mut buf = Vec::new();
+
+        check_with(
+            &index,
+            &mut reader,
+            &["sq0"],
+            |reference_sequence_id, reader| {
+                assert_eq!(reference_sequence_id, 0);
+                reader.read_to_end(&mut buf)?;
+                Ok(())
+            },
+        )?;
+
+        assert_eq!(buf, b"dles");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_check_reference_sequence_names() {
+        assert!(check_reference_sequence_names(&["sq0", "sq1"], &["sq0", "sq1"]).is_ok());
+
+        assert!(matches!(
+            check_reference_sequence_names(&["sq0", "sq1"], &["sq0", "sq2"]),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        assert!(matches!(
+            check_reference_sequence_names(&["sq0", "sq1"], &["sq0"]),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+    }
+
+    #[test]
+    fn test_check_linear_index() -> Result<(), bgzf::virtual_position::TryFromU64U16TupleError> {
+        let linear_index = [
+            bgzf::VirtualPosition::try_from((0, 8))?,
+            bgzf::VirtualPosition::default(),
+            bgzf::VirtualPosition::try_from((0, 13))?,
+        ];
+        assert!(check_linear_index(0, &linear_index).is_ok());
+
+        let linear_index = [
+            bgzf::VirtualPosition::try_from((0, 13))?,
+            bgzf::VirtualPosition::try_from((0, 8))?,
+        ];
+        assert!(matches!(
+            check_linear_index(0, &linear_index),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+}
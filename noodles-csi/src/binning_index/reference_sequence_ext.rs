@@ -1,6 +1,7 @@
 use noodles_bgzf as bgzf;
+use noodles_core::Position;
 
-use crate::index::reference_sequence::Metadata;
+use crate::index::reference_sequence::{bin::Chunk, Metadata};
 
 /// An extension that adds methods to binning index reference sequence types.
 pub trait ReferenceSequenceExt {
@@ -9,4 +10,29 @@ pub trait ReferenceSequenceExt {
 
     /// Returns the start position of the first record in the last linear bin.
     fn first_record_in_last_linear_bin_start_position(&self) -> Option<bgzf::VirtualPosition>;
+
+    /// Returns the chunks of all bins in the reference sequence.
+    fn chunks(&self) -> Vec<Chunk>;
+
+    /// Returns the linear index, if the index has one.
+    ///
+    /// This is the list of the start positions of the first records in each 16 KiB window.
+    fn linear_index(&self) -> Option<&[bgzf::VirtualPosition]> {
+        None
+    }
+
+    /// Returns the lower bound of the file position of records that can overlap the given start
+    /// position.
+    ///
+    /// By default, this is the offset of the linear index window (`2^min_shift` positions) that
+    /// includes the start position. Reference sequences without a linear index override this,
+    /// e.g., to use bin offsets.
+    fn min_offset(&self, min_shift: u8, _depth: u8, start: Position) -> bgzf::VirtualPosition {
+        let i = (usize::from(start) - 1) >> min_shift;
+
+        self.linear_index()
+            .and_then(|linear_index| linear_index.get(i))
+            .copied()
+            .unwrap_or_default()
+    }
 }
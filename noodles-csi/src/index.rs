@@ -1,15 +1,16 @@
 //! Coordinate-sorted index and fields.
 
 mod builder;
+mod indexer;
 pub mod reference_sequence;
 
-pub use self::{builder::Builder, reference_sequence::ReferenceSequence};
+pub use self::{builder::Builder, indexer::Indexer, reference_sequence::ReferenceSequence};
 
-use std::io;
+use std::{io, mem, str};
 
 use noodles_core::{region::Interval, Position};
 
-use super::{index::reference_sequence::bin::Chunk, BinningIndex};
+use super::{binning_index::optimize_chunks, index::reference_sequence::bin::Chunk, BinningIndex};
 
 /// A coordinate-sorted index (CSI).
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -34,6 +35,18 @@ impl Index {
         Builder::default()
     }
 
+    /// Returns an indexer to create an index from records.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_csi as csi;
+    /// let indexer = csi::Index::indexer();
+    /// ```
+    pub fn indexer() -> Indexer {
+        Indexer::default()
+    }
+
     /// Returns the number of bits for the minimum interval.
     ///
     /// # Examples
@@ -77,6 +90,32 @@ impl Index {
 impl BinningIndex for Index {
     type ReferenceSequence = ReferenceSequence;
 
+    /// Returns the number of bits for the minimum interval.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_csi::{self as csi, BinningIndex};
+    /// let index = csi::Index::builder().set_min_shift(12).build();
+    /// assert_eq!(BinningIndex::min_shift(&index), 12);
+    /// ```
+    fn min_shift(&self) -> u8 {
+        self.min_shift
+    }
+
+    /// Returns the depth of the binning index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_csi::{self as csi, BinningIndex};
+    /// let index = csi::Index::builder().set_depth(6).build();
+    /// assert_eq!(BinningIndex::depth(&index), 6);
+    /// ```
+    fn depth(&self) -> u8 {
+        self.depth
+    }
+
     /// Returns a list of indexed reference sequences.
     ///
     /// # Examples
@@ -103,6 +142,21 @@ impl BinningIndex for Index {
         self.n_no_coor
     }
 
+    /// Returns the reference sequence names stored in the auxiliary data, if any.
+    ///
+    /// These are only available when the auxiliary data is a tabix header.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_csi::{self as csi, BinningIndex};
+    /// let index = csi::Index::default();
+    /// assert!(index.reference_sequence_names().is_none());
+    /// ```
+    fn reference_sequence_names(&self) -> Option<Vec<&str>> {
+        parse_aux_reference_sequence_names(self.aux())
+    }
+
     fn query<I>(&self, reference_sequence_id: usize, interval: I) -> io::Result<Vec<Chunk>>
     where
         I: Into<Interval>,
@@ -117,6 +171,8 @@ impl BinningIndex for Index {
                 )
             })?;
 
+        let interval = interval.into();
+
         let query_bins = reference_sequence
             .query(self.min_shift(), self.depth(), interval)
             .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
@@ -127,10 +183,38 @@ impl BinningIndex for Index {
             .copied()
             .collect();
 
-        Ok(chunks)
+        let (start, _) = resolve_interval(self.min_shift(), self.depth(), interval)?;
+        let min_offset = reference_sequence.min_offset(self.min_shift(), self.depth(), start);
+        let merged_chunks = optimize_chunks(&chunks, min_offset);
+
+        Ok(merged_chunks)
     }
 }
 
+// The auxiliary data of a tabix header is 6 `int32_t` fields (format, col_seq, col_beg, col_end,
+// meta, skip), the length of the names block (`l_nm`), and the NUL-terminated names.
+fn parse_aux_reference_sequence_names(aux: &[u8]) -> Option<Vec<&str>> {
+    const NAMES_LENGTH_OFFSET: usize = 24;
+    const NUL: u8 = 0x00;
+
+    let names_offset = NAMES_LENGTH_OFFSET + mem::size_of::<i32>();
+    let raw_l_nm = aux.get(NAMES_LENGTH_OFFSET..names_offset)?;
+    let l_nm = raw_l_nm.try_into().map(i32::from_le_bytes).ok()?;
+    let l_nm = usize::try_from(l_nm).ok()?;
+
+    let raw_names = aux.get(names_offset..names_offset + l_nm)?;
+
+    if raw_names.is_empty() {
+        return Some(Vec::new());
+    }
+
+    raw_names
+        .strip_suffix(&[NUL])?
+        .split(|&b| b == NUL)
+        .map(|raw_name| str::from_utf8(raw_name).ok())
+        .collect()
+}
+
 impl Default for Index {
     fn default() -> Self {
         Self::builder().build()
@@ -165,3 +249,73 @@ where
         Ok((start, end))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::index::reference_sequence::Bin;
+    use noodles_bgzf as bgzf;
+
+    fn build_chunk(start: u64, end: u64) -> Chunk {
+        Chunk::new(
+            bgzf::VirtualPosition::from(start),
+            bgzf::VirtualPosition::from(end),
+        )
+    }
+
+    #[test]
+    fn test_query() -> Result<(), Box<dyn std::error::Error>> {
+        let bins = vec![
+            Bin::new(
+                0,
+                bgzf::VirtualPosition::default(),
+                vec![build_chunk(2, 5), build_chunk(10, 20)],
+            ),
+            Bin::new(
+                4681,
+                bgzf::VirtualPosition::from(12),
+                vec![
+                    build_chunk(15, 30),
+                    build_chunk(30, 34),
+                    build_chunk(40, 50),
+                ],
+            ),
+        ];
+
+        let index = Index::builder()
+            .set_reference_sequences(vec![ReferenceSequence::new(bins, None)])
+            .build();
+
+        let chunks = index.query(0, Position::try_from(1)?..=Position::try_from(8)?)?;
+        assert_eq!(chunks, [build_chunk(10, 34), build_chunk(40, 50)]);
+
+        assert!(matches!(
+            index.query(1, Position::try_from(1)?..=Position::try_from(8)?),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_aux_reference_sequence_names() {
+        assert!(parse_aux_reference_sequence_names(&[]).is_none());
+
+        let mut aux = vec![0; 24];
+        aux.extend([0x00, 0x00, 0x00, 0x00]); // l_nm = 0
+        assert_eq!(parse_aux_reference_sequence_names(&aux), Some(Vec::new()));
+
+        let mut aux = vec![0; 24];
+        aux.extend([0x08, 0x00, 0x00, 0x00]); // l_nm = 8
+        aux.extend(b"sq0\x00sq1\x00");
+        assert_eq!(
+            parse_aux_reference_sequence_names(&aux),
+            Some(vec!["sq0", "sq1"])
+        );
+
+        let mut aux = vec![0; 24];
+        aux.extend([0x03, 0x00, 0x00, 0x00]); // l_nm = 3
+        aux.extend(b"sq0");
+        assert!(parse_aux_reference_sequence_names(&aux).is_none());
+    }
+}
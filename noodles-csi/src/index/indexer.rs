@@ -0,0 +1,338 @@
+use std::{cmp::Ordering, io};
+
+use noodles_core::Position;
+
+use super::{
+    reference_sequence::{self, bin::Chunk},
+    Index, ReferenceSequence,
+};
+
+const DEFAULT_MIN_SHIFT: u8 = 14;
+const DEFAULT_DEPTH: u8 = 5;
+
+/// A coordinate-sorted index (CSI) indexer.
+///
+/// Records must be added in coordinate order.
+#[derive(Debug)]
+pub struct Indexer {
+    min_shift: u8,
+    depth: u8,
+    aux: Vec<u8>,
+    current_start: Option<Position>,
+    reference_sequence_builders: Vec<reference_sequence::Builder>,
+    unplaced_unmapped_record_count: u64,
+}
+
+impl Indexer {
+    /// Creates a coordinate-sorted index (CSI) indexer with the given binning parameters.
+    ///
+    /// The default min shift and depth are 14 and 5, respectively.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if `min_shift` is 0 or if the binning coordinate space
+    /// (`2^(min_shift + 3 * depth)`) does not fit in a 64-bit signed integer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_csi as csi;
+    ///
+    /// let indexer = csi::index::Indexer::new(12, 6)?;
+    /// let index = indexer.build(0);
+    /// assert_eq!(index.min_shift(), 12);
+    /// assert_eq!(index.depth(), 6);
+    ///
+    /// assert!(csi::index::Indexer::new(0, 6).is_err());
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn new(min_shift: u8, depth: u8) -> io::Result<Self> {
+        ReferenceSequence::max_position(min_shift, depth)?;
+        Ok(Self::with_binning(min_shift, depth))
+    }
+
+    fn with_binning(min_shift: u8, depth: u8) -> Self {
+        Self {
+            min_shift,
+            depth,
+            aux: Vec::new(),
+            current_start: None,
+            reference_sequence_builders: Vec::new(),
+            unplaced_unmapped_record_count: 0,
+        }
+    }
+
+    /// Sets the auxiliary data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_csi as csi;
+    ///
+    /// let mut indexer = csi::Index::indexer();
+    /// indexer.set_aux(b"ndls".to_vec());
+    ///
+    /// let index = indexer.build(0);
+    /// assert_eq!(index.aux(), b"ndls");
+    /// ```
+    pub fn set_aux(&mut self, aux: Vec<u8>) {
+        self.aux = aux;
+    }
+
+    /// Adds a record.
+    ///
+    /// Records must be added in coordinate-sorted order, i.e., grouped by reference sequence and
+    /// sorted by start position. The chunk is the range of virtual positions of the record in the
+    /// associated BGZF stream.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the record interval is invalid or if the record is out of order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// use noodles_core::Position;
+    /// use noodles_csi::{self as csi, index::reference_sequence::bin::Chunk};
+    ///
+    /// let mut indexer = csi::Index::indexer();
+    ///
+    /// indexer.add_record(
+    ///     0,
+    ///     Position::try_from(8)?,
+    ///     Position::try_from(13)?,
+    ///     Chunk::new(bgzf::VirtualPosition::from(144), bgzf::VirtualPosition::from(233)),
+    /// )?;
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn add_record(
+        &mut self,
+        reference_sequence_id: usize,
+        start: Position,
+        end: Position,
+        chunk: Chunk,
+    ) -> io::Result<()> {
+        let max_position = ReferenceSequence::max_position(self.min_shift, self.depth)?;
+
+        if start > end || end > max_position {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("invalid record interval: [{start}, {end}]"),
+            ));
+        }
+
+        let reference_sequence_count = self.reference_sequence_builders.len();
+
+        match (reference_sequence_id + 1).cmp(&reference_sequence_count) {
+            Ordering::Less => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("unsorted reference sequence ID: {reference_sequence_id}"),
+                ))
+            }
+            Ordering::Equal => {
+                if let Some(current_start) = self.current_start {
+                    if start < current_start {
+                        return Err(io::Error::new(
+                            io::ErrorKind::InvalidInput,
+                            format!(
+                                "unsorted record: {reference_sequence_id}:{start} is before {reference_sequence_id}:{current_start}"
+                            ),
+                        ));
+                    }
+                }
+            }
+            Ordering::Greater => self
+                .reference_sequence_builders
+                .resize_with(reference_sequence_id + 1, Default::default),
+        }
+
+        self.current_start = Some(start);
+
+        let reference_sequence_builder = self
+            .reference_sequence_builders
+            .last_mut()
+            .expect("reference_sequence_builders cannot be empty");
+
+        reference_sequence_builder.add_record(self.min_shift, self.depth, start, end, chunk);
+
+        Ok(())
+    }
+
+    /// Adds a record that is not placed on a reference sequence and is unmapped.
+    ///
+    /// These are counted and stored in the index as the number of unplaced, unmapped records.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_csi::{self as csi, BinningIndex};
+    ///
+    /// let mut indexer = csi::Index::indexer();
+    /// indexer.add_unplaced_unmapped_record();
+    ///
+    /// let index = indexer.build(0);
+    /// assert_eq!(index.unplaced_unmapped_record_count(), Some(1));
+    /// ```
+    pub fn add_unplaced_unmapped_record(&mut self) {
+        self.unplaced_unmapped_record_count += 1;
+    }
+
+    /// Builds a coordinate-sorted index.
+    ///
+    /// The index has at least the given number of reference sequences.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_csi::{self as csi, BinningIndex};
+    /// let indexer = csi::Index::indexer();
+    /// let index = indexer.build(2);
+    /// assert_eq!(index.reference_sequences().len(), 2);
+    /// ```
+    pub fn build(mut self, reference_sequence_count: usize) -> Index {
+        if self.reference_sequence_builders.len() < reference_sequence_count {
+            self.reference_sequence_builders
+                .resize_with(reference_sequence_count, Default::default);
+        }
+
+        let (min_shift, depth) = (self.min_shift, self.depth);
+
+        let reference_sequences = self
+            .reference_sequence_builders
+            .into_iter()
+            .map(|b| b.build(depth))
+            .collect();
+
+        Index::builder()
+            .set_min_shift(min_shift)
+            .set_depth(depth)
+            .set_aux(self.aux)
+            .set_reference_sequences(reference_sequences)
+            .set_unplaced_unmapped_record_count(self.unplaced_unmapped_record_count)
+            .build()
+    }
+}
+
+impl Default for Indexer {
+    fn default() -> Self {
+        Self::with_binning(DEFAULT_MIN_SHIFT, DEFAULT_DEPTH)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_bgzf as bgzf;
+
+    use super::*;
+    use crate::BinningIndex;
+
+    #[test]
+    fn test_add_record() -> Result<(), Box<dyn std::error::Error>> {
+        let mut indexer = Indexer::default();
+
+        let chunk = Chunk::new(
+            bgzf::VirtualPosition::from(0),
+            bgzf::VirtualPosition::from(9),
+        );
+
+        indexer.add_record(1, Position::try_from(8)?, Position::try_from(13)?, chunk)?;
+
+        assert!(matches!(
+            indexer.add_record(0, Position::try_from(8)?, Position::try_from(13)?, chunk),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        assert!(matches!(
+            indexer.add_record(1, Position::try_from(13)?, Position::try_from(8)?, chunk),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        assert!(matches!(
+            indexer.add_record(1, Position::try_from(8)?, Position::try_from(1 << 29)?, chunk),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        assert!(matches!(
+            indexer.add_record(1, Position::try_from(5)?, Position::try_from(13)?, chunk),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        indexer.add_record(2, Position::try_from(5)?, Position::try_from(8)?, chunk)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_new() -> Result<(), Box<dyn std::error::Error>> {
+        let mut indexer = Indexer::new(12, 6)?;
+
+        // max position = 2^(12 + 3 * 6) - 1 = 2^30 - 1
+        indexer.add_record(
+            0,
+            Position::try_from(8)?,
+            Position::try_from(1 << 29)?,
+            Chunk::new(
+                bgzf::VirtualPosition::from(0),
+                bgzf::VirtualPosition::from(9),
+            ),
+        )?;
+
+        let index = indexer.build(1);
+        assert_eq!(index.min_shift(), 12);
+        assert_eq!(index.depth(), 6);
+
+        assert!(matches!(
+            Indexer::new(0, 6),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        // 2^(16 + 3 * 16) does not fit in a 64-bit signed integer.
+        assert!(matches!(
+            Indexer::new(16, 16),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_build() -> Result<(), Box<dyn std::error::Error>> {
+        let mut indexer = Indexer::default();
+
+        indexer.add_record(
+            1,
+            Position::try_from(8)?,
+            Position::try_from(13)?,
+            Chunk::new(
+                bgzf::VirtualPosition::from(0),
+                bgzf::VirtualPosition::from(9),
+            ),
+        )?;
+
+        let index = indexer.build(3);
+
+        assert_eq!(index.min_shift(), DEFAULT_MIN_SHIFT);
+        assert_eq!(index.depth(), DEFAULT_DEPTH);
+        assert!(index.aux().is_empty());
+        assert_eq!(index.unplaced_unmapped_record_count(), Some(0));
+
+        let reference_sequences = index.reference_sequences();
+        assert_eq!(reference_sequences.len(), 3);
+        assert!(reference_sequences[0].bins().is_empty());
+        assert_eq!(reference_sequences[1].bins().len(), 1);
+        assert!(reference_sequences[2].bins().is_empty());
+
+        let chunks = index.query(1, Position::try_from(1)?..=Position::try_from(21)?)?;
+        assert_eq!(
+            chunks,
+            [Chunk::new(
+                bgzf::VirtualPosition::from(0),
+                bgzf::VirtualPosition::from(9)
+            )]
+        );
+
+        Ok(())
+    }
+}
@@ -1,8 +1,10 @@
 //! Coordinate-sorted index (CSI) reference sequence and fields.
 
 pub mod bin;
+mod builder;
 mod metadata;
 
+pub(crate) use self::builder::Builder;
 pub use self::{bin::Bin, metadata::Metadata};
 
 use std::{io, num::NonZeroUsize};
@@ -11,6 +13,7 @@ use bit_vec::BitVec;
 use noodles_bgzf as bgzf;
 use noodles_core::{region::Interval, Position};
 
+use self::bin::Chunk;
 use super::resolve_interval;
 use crate::binning_index::ReferenceSequenceExt;
 
@@ -22,10 +25,27 @@ pub struct ReferenceSequence {
 }
 
 impl ReferenceSequence {
+    // The binning coordinate space is `[0, 2^(min_shift + 3 * depth))`. Positions are 64-bit
+    // signed integers, so the space is limited to 2^63.
     pub(super) fn max_position(min_shift: u8, depth: u8) -> io::Result<Position> {
-        assert!(min_shift > 0);
-        let n = (1 << (usize::from(min_shift) + 3 * usize::from(depth))) - 1;
-        Position::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))
+        const MAX_BITS: u32 = 63;
+
+        let bits = u32::from(min_shift) + 3 * u32::from(depth);
+
+        if min_shift == 0 || bits > MAX_BITS {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("invalid binning parameters: min_shift = {min_shift}, depth = {depth}"),
+            ));
+        }
+
+        let n = (1u64 << bits) - 1;
+
+        usize::try_from(n)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))
+            .and_then(|n| {
+                Position::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))
+            })
     }
 
     /// Creates a CSI reference sequence.
@@ -190,6 +210,18 @@ impl ReferenceSequenceExt for ReferenceSequence {
     fn first_record_in_last_linear_bin_start_position(&self) -> Option<bgzf::VirtualPosition> {
         self.bins().iter().map(|bin| bin.loffset()).max()
     }
+
+    fn chunks(&self) -> Vec<Chunk> {
+        self.bins()
+            .iter()
+            .flat_map(|bin| bin.chunks())
+            .copied()
+            .collect()
+    }
+
+    fn min_offset(&self, min_shift: u8, depth: u8, start: Position) -> bgzf::VirtualPosition {
+        ReferenceSequence::min_offset(self, min_shift, depth, start)
+    }
 }
 
 const M: usize = match NonZeroUsize::new(8) {
@@ -266,6 +298,17 @@ mod tests {
         let actual = ReferenceSequence::max_position(MIN_SHIFT, DEPTH)?;
         let expected = Position::try_from(536870911)?;
         assert_eq!(actual, expected);
+
+        assert!(matches!(
+            ReferenceSequence::max_position(0, DEPTH),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        assert!(matches!(
+            ReferenceSequence::max_position(MIN_SHIFT, 17),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
         Ok(())
     }
 
@@ -0,0 +1,235 @@
+use std::{cmp, collections::BTreeMap};
+
+use noodles_bgzf as bgzf;
+use noodles_core::Position;
+
+use super::{bin::Chunk, reg2bin, Bin, Metadata, ReferenceSequence};
+
+#[derive(Debug, Default)]
+pub(crate) struct Builder {
+    bins: BTreeMap<usize, Vec<Chunk>>,
+    intervals: Vec<Option<bgzf::VirtualPosition>>,
+    start_position: bgzf::VirtualPosition,
+    end_position: bgzf::VirtualPosition,
+    mapped_record_count: u64,
+}
+
+impl Builder {
+    pub fn add_record(
+        &mut self,
+        min_shift: u8,
+        depth: u8,
+        start: Position,
+        end: Position,
+        chunk: Chunk,
+    ) -> &mut Self {
+        self.update_bins(min_shift, depth, start, end, chunk);
+        self.update_intervals(min_shift, start, end, chunk);
+        self.update_metadata(chunk);
+        self
+    }
+
+    pub fn build(self, depth: u8) -> ReferenceSequence {
+        if self.bins.is_empty() {
+            return ReferenceSequence::new(Vec::new(), None);
+        }
+
+        let bins = self
+            .bins
+            .iter()
+            .map(|(&id, chunks)| {
+                let loffset = self.loffset(depth, id);
+                Bin::new(id, loffset, chunks.clone())
+            })
+            .collect();
+
+        let metadata = Metadata::new(
+            self.start_position,
+            self.end_position,
+            self.mapped_record_count,
+            0,
+        );
+
+        ReferenceSequence::new(bins, Some(metadata))
+    }
+
+    fn update_bins(
+        &mut self,
+        min_shift: u8,
+        depth: u8,
+        start: Position,
+        end: Position,
+        chunk: Chunk,
+    ) {
+        let id = reg2bin(start, end, min_shift, depth);
+        let chunks = self.bins.entry(id).or_default();
+
+        if let Some(last_chunk) = chunks.last_mut() {
+            if chunk.start() <= last_chunk.end() {
+                *last_chunk = Chunk::new(last_chunk.start(), chunk.end());
+                return;
+            }
+        }
+
+        chunks.push(chunk);
+    }
+
+    fn update_intervals(&mut self, min_shift: u8, start: Position, end: Position, chunk: Chunk) {
+        let start_index = (usize::from(start) - 1) >> min_shift;
+        let end_index = (usize::from(end) - 1) >> min_shift;
+
+        if end_index >= self.intervals.len() {
+            self.intervals.resize(end_index + 1, None);
+        }
+
+        for interval in &mut self.intervals[start_index..=end_index] {
+            interval.get_or_insert(chunk.start());
+        }
+    }
+
+    fn update_metadata(&mut self, chunk: Chunk) {
+        if self.mapped_record_count == 0 {
+            self.start_position = chunk.start();
+            self.end_position = chunk.end();
+        } else {
+            self.start_position = cmp::min(self.start_position, chunk.start());
+            self.end_position = cmp::max(self.end_position, chunk.end());
+        }
+
+        self.mapped_record_count += 1;
+    }
+
+    // The start of the first record that overlaps the start of the bin or, if there is none, any
+    // position after it.
+    fn loffset(&self, depth: u8, id: usize) -> bgzf::VirtualPosition {
+        let i = bin_start(depth, id);
+
+        self.intervals
+            .get(i..)
+            .and_then(|intervals| intervals.iter().flatten().next().copied())
+            .unwrap_or_default()
+    }
+}
+
+// Returns the index of the first minimum interval (`1 << min_shift`) covered by the bin.
+fn bin_start(depth: u8, id: usize) -> usize {
+    let mut level = 0;
+    let mut first_id = 0;
+
+    loop {
+        let next_first_id = first_id + (1 << (3 * level));
+
+        if id < next_first_id || level == usize::from(depth) {
+            break;
+        }
+
+        first_id = next_first_id;
+        level += 1;
+    }
+
+    (id - first_id) << (3 * (usize::from(depth) - level))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build() -> Result<(), noodles_core::position::TryFromIntError> {
+        const MIN_SHIFT: u8 = 14;
+        const DEPTH: u8 = 5;
+
+        let mut builder = Builder::default();
+
+        builder.add_record(
+            MIN_SHIFT,
+            DEPTH,
+            Position::try_from(8)?,
+            Position::try_from(13)?,
+            Chunk::new(
+                bgzf::VirtualPosition::from(0),
+                bgzf::VirtualPosition::from(9),
+            ),
+        );
+
+        builder.add_record(
+            MIN_SHIFT,
+            DEPTH,
+            Position::try_from(121393)?,
+            Position::try_from(196418)?,
+            Chunk::new(
+                bgzf::VirtualPosition::from(9),
+                bgzf::VirtualPosition::from(3473408),
+            ),
+        );
+
+        builder.add_record(
+            MIN_SHIFT,
+            DEPTH,
+            Position::try_from(121394)?,
+            Position::try_from(121395)?,
+            Chunk::new(
+                bgzf::VirtualPosition::from(3473408),
+                bgzf::VirtualPosition::from(3473421),
+            ),
+        );
+
+        let actual = builder.build(DEPTH);
+
+        let expected = ReferenceSequence::new(
+            vec![
+                Bin::new(
+                    73,
+                    bgzf::VirtualPosition::from(0),
+                    vec![Chunk::new(
+                        bgzf::VirtualPosition::from(9),
+                        bgzf::VirtualPosition::from(3473408),
+                    )],
+                ),
+                Bin::new(
+                    4681,
+                    bgzf::VirtualPosition::from(0),
+                    vec![Chunk::new(
+                        bgzf::VirtualPosition::from(0),
+                        bgzf::VirtualPosition::from(9),
+                    )],
+                ),
+                Bin::new(
+                    4688,
+                    bgzf::VirtualPosition::from(9),
+                    vec![Chunk::new(
+                        bgzf::VirtualPosition::from(3473408),
+                        bgzf::VirtualPosition::from(3473421),
+                    )],
+                ),
+            ],
+            Some(Metadata::new(
+                bgzf::VirtualPosition::from(0),
+                bgzf::VirtualPosition::from(3473421),
+                3,
+                0,
+            )),
+        );
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_with_no_records() {
+        let reference_sequence = Builder::default().build(5);
+        assert_eq!(reference_sequence, ReferenceSequence::new(Vec::new(), None));
+    }
+
+    #[test]
+    fn test_bin_start() {
+        assert_eq!(bin_start(5, 0), 0);
+        assert_eq!(bin_start(5, 1), 0);
+        assert_eq!(bin_start(5, 2), 1 << 12);
+        assert_eq!(bin_start(5, 73), 0);
+        assert_eq!(bin_start(5, 74), 1 << 6);
+        assert_eq!(bin_start(5, 4681), 0);
+        assert_eq!(bin_start(5, 4688), 7);
+    }
+}
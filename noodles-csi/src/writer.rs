@@ -162,7 +162,7 @@ where
         let chunk_beg = u64::from(chunk.start());
         writer.write_u64::<LittleEndian>(chunk_beg)?;
 
-        let chunk_end = u64::from(chunk.start());
+        let chunk_end = u64::from(chunk.end());
         writer.write_u64::<LittleEndian>(chunk_end)?;
     }
 
@@ -203,6 +203,27 @@ where
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_write_chunks() -> io::Result<()> {
+        let mut buf = Vec::new();
+        let chunks = [Chunk::new(
+            bgzf::VirtualPosition::from(8),
+            bgzf::VirtualPosition::from(13),
+        )];
+
+        write_chunks(&mut buf, &chunks)?;
+
+        let expected = [
+            0x01, 0x00, 0x00, 0x00, // n_chunk = 1
+            0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // chunk_beg = 8
+            0x0d, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // chunk_end = 13
+        ];
+
+        assert_eq!(buf, expected);
+
+        Ok(())
+    }
+
     #[test]
     fn test_write_metadata() -> io::Result<()> {
         let mut buf = Vec::new();
@@ -1,5 +1,8 @@
 //! Builds a FASTA index.
 //!
+//! The input can be bgzipped if its extension is `gz` or `bgz`. Use the `bgzf_gzi_index` example
+//! in noodles-bgzf to build its GZ index (`<src>.gzi`).
+//!
 //! This writes the output to stdout rather than `<src>.fai`.
 //!
 //! The result matches the output of `samtools faidx <src>`.
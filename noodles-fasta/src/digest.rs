@@ -0,0 +1,206 @@
+//! FASTA sequence digests.
+//!
+//! This calculates the MD5 checksum of a sequence, as used in SAM reference sequence records
+//! (`@SQ M5`) and CRAM, and the refget sequence identifier (`SQ.<sha512t24u>`).
+
+use std::io::{self, Write};
+
+use base64::Engine;
+use md5::{Digest as _, Md5};
+use sha2::Sha512;
+
+const SHA512T24U_LENGTH: usize = 24;
+
+/// A streaming sequence digester.
+///
+/// Sequence data can be written in chunks of any size, e.g., using a
+/// [`crate::reader::SequenceReader`] with [`io::copy`], so entire sequences never need to be in
+/// memory.
+///
+/// The sequence is normalized before it is hashed: all characters outside of the inclusive range
+/// 33 (`!`) to 126 (`~`), e.g., line endings, are removed, and lowercase characters are
+/// converted to uppercase.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io;
+/// use noodles_fasta::{self as fasta, digest::Digester};
+///
+/// let data = b">sq0\nACGT\nacgt\n";
+/// let mut reader = fasta::Reader::new(&data[..]);
+/// reader.read_definition(&mut String::new())?;
+///
+/// let mut digester = Digester::new();
+/// io::copy(&mut reader.sequence_reader(), &mut digester)?;
+/// let digest = digester.finalize();
+///
+/// assert_eq!(
+///     digest.ga4gh_identifier(),
+///     "SQ.mZaH9yJZKglZq7R1h5zLOyAGTQrXu72F"
+/// );
+/// # Ok::<_, io::Error>(())
+/// ```
+#[derive(Clone, Default)]
+pub struct Digester {
+    md5: Md5,
+    sha512: Sha512,
+    buf: Vec<u8>,
+}
+
+impl Digester {
+    /// Creates a sequence digester.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::digest::Digester;
+    /// let digester = Digester::new();
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds sequence data to the digests.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::digest::Digester;
+    ///
+    /// let mut digester = Digester::new();
+    /// digester.update(b"ACGT");
+    /// digester.update(b"acgt\n");
+    /// ```
+    pub fn update(&mut self, data: &[u8]) {
+        self.buf.clear();
+        self.buf.extend(
+            data.iter()
+                .filter(|b| b.is_ascii_graphic())
+                .map(|b| b.to_ascii_uppercase()),
+        );
+
+        self.md5.update(&self.buf);
+        self.sha512.update(&self.buf);
+    }
+
+    /// Returns the digests of the sequence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::digest::Digester;
+    ///
+    /// let mut digester = Digester::new();
+    /// digester.update(b"ACGT");
+    /// let _digest = digester.finalize();
+    /// ```
+    pub fn finalize(self) -> Digest {
+        let md5 = self.md5.finalize().into();
+
+        let sha512 = self.sha512.finalize();
+        let mut sha512t24 = [0; SHA512T24U_LENGTH];
+        sha512t24.copy_from_slice(&sha512[..SHA512T24U_LENGTH]);
+
+        Digest { md5, sha512t24 }
+    }
+}
+
+impl Write for Digester {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.update(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// The digests of a sequence.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Digest {
+    md5: [u8; 16],
+    sha512t24: [u8; SHA512T24U_LENGTH],
+}
+
+impl Digest {
+    /// Returns the MD5 checksum of the normalized sequence.
+    ///
+    /// This is the value of the SAM reference sequence MD5 checksum field (`@SQ M5`) when encoded
+    /// as lowercase hex.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::digest::Digester;
+    ///
+    /// let mut digester = Digester::new();
+    /// digester.update(b"ACGT");
+    /// let digest = digester.finalize();
+    ///
+    /// assert_eq!(digest.md5(), [
+    ///     0xf1, 0xf8, 0xf4, 0xbf, 0x41, 0x3b, 0x16, 0xad,
+    ///     0x13, 0x57, 0x22, 0xaa, 0x45, 0x91, 0x04, 0x3e,
+    /// ]);
+    /// ```
+    pub fn md5(&self) -> [u8; 16] {
+        self.md5
+    }
+
+    /// Returns the refget `sha512t24u` digest of the normalized sequence.
+    ///
+    /// This is the base64url encoding of the first 24 bytes of the SHA-512 digest.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::digest::Digester;
+    ///
+    /// let mut digester = Digester::new();
+    /// digester.update(b"ACGT");
+    /// let digest = digester.finalize();
+    ///
+    /// assert_eq!(digest.sha512t24u(), "aKF498dAxcJAqme6QYQ7EZ07-fiw8Kw2");
+    /// ```
+    pub fn sha512t24u(&self) -> String {
+        base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(self.sha512t24)
+    }
+
+    /// Returns the GA4GH refget sequence identifier, i.e., `SQ.<sha512t24u>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::digest::Digester;
+    ///
+    /// let mut digester = Digester::new();
+    /// digester.update(b"ACGT");
+    /// let digest = digester.finalize();
+    ///
+    /// assert_eq!(digest.ga4gh_identifier(), "SQ.aKF498dAxcJAqme6QYQ7EZ07-fiw8Kw2");
+    /// ```
+    pub fn ga4gh_identifier(&self) -> String {
+        format!("SQ.{}", self.sha512t24u())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_update() {
+        let mut digester = Digester::new();
+        digester.update(b"ACGT");
+        let expected = digester.finalize();
+
+        let mut digester = Digester::new();
+        digester.update(b"ac");
+        digester.update(b"\r\n");
+        digester.update(b"g t\n");
+        let actual = digester.finalize();
+
+        assert_eq!(actual, expected);
+    }
+}
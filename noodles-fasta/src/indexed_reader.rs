@@ -43,6 +43,11 @@ where
         self.inner.into_inner()
     }
 
+    /// Returns the associated index.
+    pub fn index(&self) -> &fai::Index {
+        &self.index
+    }
+
     /// Reads a raw definition line.
     pub fn read_definition(&mut self, buf: &mut String) -> io::Result<usize> {
         self.inner.read_definition(buf)
@@ -59,7 +64,57 @@ where
     R: BufRead + Seek,
 {
     /// Returns a record of the given region.
+    ///
+    /// This seeks directly to the start of the region using the index and only reads the bases in
+    /// the region. For a bgzipped FASTA, the underlying reader is expected to be a BGZF reader
+    /// that can seek to uncompressed positions, e.g., one built with a gzip index (GZI).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use noodles_fasta as fasta;
+    ///
+    /// let mut reader = fasta::indexed_reader::Builder::default()
+    ///     .build_from_path("reference.fa.gz")?;
+    ///
+    /// let region = "sq0:8-13".parse()?;
+    /// let record = reader.query(&region)?;
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
     pub fn query(&mut self, region: &Region) -> io::Result<Record> {
         self.inner.query(&self.index, region)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Cursor, Write};
+
+    use noodles_bgzf as bgzf;
+
+    use super::*;
+
+    #[test]
+    fn test_query_with_bgzf() -> Result<(), Box<dyn std::error::Error>> {
+        let mut writer = bgzf::Writer::new(Vec::new());
+        writer.write_all(b">sq0\nACGT\n")?;
+        writer.flush_block()?;
+        writer.write_all(b">sq1\nNNAC\nGTNN\n")?;
+        let data = writer.finish()?;
+
+        let gzi = bgzf::gzi::index(&data[..])?;
+
+        let index = crate::io::index(bgzf::Reader::new(&data[..]))?;
+        assert_eq!(index[1], fai::Record::new(String::from("sq1"), 8, 15, 4, 5));
+
+        let inner = bgzf::indexed_reader::Builder::default()
+            .set_index(gzi)
+            .build_from_reader(Cursor::new(data))?;
+        let mut reader = IndexedReader::new(inner, index);
+
+        let record = reader.query(&"sq1:3-6".parse()?)?;
+        assert_eq!(record.sequence().as_ref(), b"ACGT");
+
+        Ok(())
+    }
+}
@@ -117,4 +117,40 @@ mod tests {
     fn test_build_index_src() {
         assert_eq!(build_index_src("ref.fa"), PathBuf::from("ref.fa.fai"));
     }
+
+    #[test]
+    fn test_build_from_path_with_bgzf() -> Result<(), Box<dyn std::error::Error>> {
+        use std::{fs, io::Write};
+
+        use noodles_bgzf::gzi;
+
+        let dir = std::env::temp_dir().join(format!(
+            "noodles-fasta-indexed-reader-builder-{}",
+            std::process::id()
+        ));
+        fs::create_dir_all(&dir)?;
+
+        let src = dir.join("ref.fa.gz");
+
+        let mut writer = File::create(&src).map(bgzf::Writer::new)?;
+        writer.write_all(b">sq0\nACGT\n")?;
+        writer.flush_block()?;
+        writer.write_all(b">sq1\nNNAC\nGTNN\n")?;
+        writer.finish()?;
+
+        let index = crate::index(&src)?;
+        fai::write(build_index_src(&src), &index)?;
+
+        let gzi_index = File::open(&src).and_then(gzi::index)?;
+        gzi::write(push_ext(src.clone(), "gzi"), &gzi_index)?;
+
+        let mut reader = Builder::default().build_from_path(&src)?;
+        let record = reader.query(&"sq1:3-6".parse()?)?;
+
+        fs::remove_dir_all(&dir)?;
+
+        assert_eq!(record.sequence().as_ref(), b"ACGT");
+
+        Ok(())
+    }
 }
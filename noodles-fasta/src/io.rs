@@ -1,8 +1,72 @@
 //! FASTA I/O.
 
-use std::io::{BufRead, Seek};
+use std::io::{self, BufRead, Seek};
+
+use super::{fai, indexer::Indexer};
 
 /// A reader that is both buffered and seekable.
 pub trait BufReadSeek: BufRead + Seek {}
 
 impl<T> BufReadSeek for T where T: BufRead + Seek {}
+
+/// Indexes a FASTA stream.
+///
+/// The reader can be a plain or decompressing (e.g., BGZF) stream. Offsets in the resulting index
+/// are positions in the (uncompressed) stream.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io;
+/// use noodles_fasta::{self as fasta, fai};
+///
+/// let data = b">sq0\nACGT\n>sq1\nNNNN\nNNNN\nNN\n";
+/// let index = fasta::io::index(&data[..])?;
+///
+/// assert_eq!(index, [
+///     fai::Record::new(String::from("sq0"), 4, 5, 4, 5),
+///     fai::Record::new(String::from("sq1"), 10, 15, 4, 5),
+/// ]);
+/// # Ok::<_, io::Error>(())
+/// ```
+pub fn index<R>(reader: R) -> io::Result<fai::Index>
+where
+    R: BufRead,
+{
+    let mut indexer = Indexer::new(reader);
+    let mut index = Vec::new();
+
+    while let Some(record) = indexer.index_record()? {
+        index.push(record);
+    }
+
+    Ok(index)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use noodles_bgzf as bgzf;
+
+    use super::*;
+
+    #[test]
+    fn test_index_with_bgzf() -> io::Result<()> {
+        let mut writer = bgzf::Writer::new(Vec::new());
+        writer.write_all(b">sq0\nACGT\n>sq1\nNNNN\nNNNN\nNN\n")?;
+        let data = writer.finish()?;
+
+        let index = index(bgzf::Reader::new(&data[..]))?;
+
+        assert_eq!(
+            index,
+            [
+                fai::Record::new(String::from("sq0"), 4, 5, 4, 5),
+                fai::Record::new(String::from("sq1"), 10, 15, 4, 5),
+            ]
+        );
+
+        Ok(())
+    }
+}
@@ -50,6 +50,7 @@
 #[cfg(feature = "async")]
 pub(crate) mod r#async;
 
+pub mod digest;
 pub mod fai;
 pub mod indexed_reader;
 mod indexer;
@@ -69,10 +70,13 @@ pub use self::r#async::Reader as AsyncReader;
 
 use std::{fs::File, io::BufReader, path::Path};
 
-use self::indexer::Indexer;
+use noodles_bgzf as bgzf;
 
 /// Indexes a FASTA file.
 ///
+/// If the file extension is `gz` or `bgz`, the file is read as a bgzipped FASTA. Offsets in the
+/// resulting index are positions in the uncompressed stream, the same as `samtools faidx`.
+///
 /// # Examples
 ///
 /// ```no_run
@@ -85,12 +89,11 @@ pub fn index<P>(src: P) -> std::io::Result<fai::Index>
 where
     P: AsRef<Path>,
 {
-    let mut indexer = File::open(src).map(BufReader::new).map(Indexer::new)?;
-    let mut index = Vec::new();
+    let src = src.as_ref();
+    let file = File::open(src)?;
 
-    while let Some(i) = indexer.index_record()? {
-        index.push(i);
+    match src.extension().and_then(|ext| ext.to_str()) {
+        Some("gz" | "bgz") => io::index(bgzf::Reader::new(file)),
+        _ => io::index(BufReader::new(file)),
     }
-
-    Ok(index)
 }
@@ -2,8 +2,9 @@
 
 mod builder;
 mod records;
+mod sequence_reader;
 
-pub use self::{builder::Builder, records::Records};
+pub use self::{builder::Builder, records::Records, sequence_reader::SequenceReader};
 
 use std::{
     io::{self, BufRead, Seek, SeekFrom},
@@ -139,6 +140,44 @@ where
         read_sequence(&mut self.inner, buf)
     }
 
+    /// Returns a reader of the current sequence.
+    ///
+    /// Unlike [`Self::read_sequence`], this does not read the entire sequence into memory. The
+    /// returned reader yields the sequence without line endings in chunks of at most the size of
+    /// the underlying buffer and reaches EOF at the next definition or the end of the stream.
+    ///
+    /// The position of the stream is expected to be at the start of a sequence, which is directly
+    /// after a definition.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::{self, Read};
+    /// use noodles_fasta as fasta;
+    ///
+    /// let data = b">sq0\nACGT\n>sq1\nNNNN\nNNNN\nNN\n";
+    /// let mut reader = fasta::Reader::new(&data[..]);
+    ///
+    /// let mut definition = String::new();
+    /// let mut buf = Vec::new();
+    ///
+    /// reader.read_definition(&mut definition)?;
+    /// reader.sequence_reader().read_to_end(&mut buf)?;
+    /// assert_eq!(buf, b"ACGT");
+    ///
+    /// definition.clear();
+    /// buf.clear();
+    ///
+    /// reader.read_definition(&mut definition)?;
+    /// reader.sequence_reader().read_to_end(&mut buf)?;
+    /// assert_eq!(definition, ">sq1");
+    /// assert_eq!(buf, b"NNNNNNNNNN");
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn sequence_reader(&mut self) -> SequenceReader<'_, R> {
+        SequenceReader::new(&mut self.inner)
+    }
+
     /// Returns an iterator over records starting from the current stream position.
     ///
     /// The position of the stream is expected to be at the start or at the start of another
@@ -221,16 +260,27 @@ where
                 )
             })?;
 
-        let pos = index_record.offset();
+        let len = usize::try_from(index_record.len())
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+        let range = interval_to_slice_range(region.interval(), len);
+
+        if range.start > range.end || range.end > len {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("invalid region: {region} (sequence length = {len})"),
+            ));
+        }
+
+        let pos = sequence_offset(index_record, range.start)?;
         self.get_mut().seek(SeekFrom::Start(pos))?;
 
         let definition = Definition::new(region.to_string(), None);
 
-        let mut raw_sequence = Vec::new();
-        self.read_sequence(&mut raw_sequence)?;
+        let mut raw_sequence = Vec::with_capacity(range.len());
+        read_sequence_limit(self.get_mut(), range.len(), &mut raw_sequence)?;
 
-        let range = interval_to_slice_range(region.interval(), raw_sequence.len());
-        let sequence = Sequence::from(raw_sequence[range].to_vec());
+        let sequence = Sequence::from(raw_sequence);
 
         Ok(Record::new(definition, sequence))
     }
@@ -306,6 +356,70 @@ where
     Ok(bytes_read)
 }
 
+// Calculates the stream position of the base at the given 0-based index of a sequence.
+fn sequence_offset(index_record: &fai::Record, i: usize) -> io::Result<u64> {
+    let line_bases = index_record.line_bases();
+
+    if line_bases == 0 {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "invalid index record: line bases = 0",
+        ));
+    }
+
+    let i = i as u64;
+    let (line, column) = (i / line_bases, i % line_bases);
+
+    Ok(index_record.offset() + line * index_record.line_width() + column)
+}
+
+// Reads `len` bases of a sequence, skipping line endings.
+//
+// An error is returned if the sequence ends before `len` bases are read.
+fn read_sequence_limit<R>(reader: &mut R, len: usize, buf: &mut Vec<u8>) -> io::Result<()>
+where
+    R: BufRead,
+{
+    const LINE_FEED: u8 = b'\n';
+    const CARRIAGE_RETURN: u8 = b'\r';
+
+    let mut is_eol = false;
+
+    while buf.len() < len {
+        let reader_buf = reader.fill_buf()?;
+
+        if reader_buf.is_empty() {
+            return Err(io::Error::from(io::ErrorKind::UnexpectedEof));
+        }
+
+        let mut consumed = 0;
+
+        for &b in reader_buf {
+            if buf.len() >= len {
+                break;
+            }
+
+            match b {
+                LINE_FEED => is_eol = true,
+                CARRIAGE_RETURN => {}
+                DEFINITION_PREFIX if is_eol => {
+                    return Err(io::Error::from(io::ErrorKind::UnexpectedEof))
+                }
+                _ => {
+                    buf.push(b);
+                    is_eol = false;
+                }
+            }
+
+            consumed += 1;
+        }
+
+        reader.consume(consumed);
+    }
+
+    Ok(())
+}
+
 // Shifts a 1-based interval to a 0-based range for slicing.
 fn interval_to_slice_range<I>(interval: I, len: usize) -> Range<usize>
 where
@@ -407,6 +521,59 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_query() -> Result<(), Box<dyn std::error::Error>> {
+        use std::io::Cursor;
+
+        let data = b">sq0\nACG\nTNA\nCG\n>sq1\r\nACG\r\nTA\r\n";
+        let index = vec![
+            fai::Record::new(String::from("sq0"), 8, 5, 3, 4),
+            fai::Record::new(String::from("sq1"), 5, 22, 3, 5),
+        ];
+
+        let mut reader = Reader::new(Cursor::new(data));
+
+        let record = reader.query(&index, &"sq0:3-7".parse()?)?;
+        assert_eq!(record.sequence().as_ref(), b"GTNAC");
+
+        let record = reader.query(&index, &"sq0:7".parse()?)?;
+        assert_eq!(record.sequence().as_ref(), b"CG");
+
+        let record = reader.query(&index, &"sq1:3-4".parse()?)?;
+        assert_eq!(record.sequence().as_ref(), b"GT");
+
+        assert!(matches!(
+            reader.query(&index, &"sq0:8-9".parse()?),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        assert!(matches!(
+            reader.query(&index, &"sq2".parse()?),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_sequence_limit() -> io::Result<()> {
+        let data = b"ACGT\nNN\n>sq1\nACGT\n";
+
+        let mut reader = &data[..];
+        let mut buf = Vec::new();
+        read_sequence_limit(&mut reader, 5, &mut buf)?;
+        assert_eq!(buf, b"ACGTN");
+
+        let mut reader = &data[..];
+        let mut buf = Vec::new();
+        assert!(matches!(
+            read_sequence_limit(&mut reader, 8, &mut buf),
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof
+        ));
+
+        Ok(())
+    }
+
     #[test]
     fn test_interval_to_slice_range() -> Result<(), noodles_core::position::TryFromIntError> {
         use noodles_core::Position;
@@ -0,0 +1,121 @@
+use std::io::{self, BufRead, Read};
+
+use super::DEFINITION_PREFIX;
+
+const LINE_FEED: u8 = b'\n';
+const CARRIAGE_RETURN: u8 = b'\r';
+
+/// A reader of the sequence of a FASTA record.
+///
+/// This reads the sequence in bounded chunks, i.e., at most the size of the underlying buffer,
+/// without line endings. It reaches EOF at the next definition or the end of the stream.
+///
+/// This is created by calling [`super::Reader::sequence_reader`].
+pub struct SequenceReader<'r, R> {
+    inner: &'r mut R,
+}
+
+impl<'r, R> SequenceReader<'r, R>
+where
+    R: BufRead,
+{
+    pub(super) fn new(inner: &'r mut R) -> Self {
+        Self { inner }
+    }
+}
+
+impl<'r, R> Read for SequenceReader<'r, R>
+where
+    R: BufRead,
+{
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let src = self.fill_buf()?;
+        let amt = src.len().min(buf.len());
+        buf[..amt].copy_from_slice(&src[..amt]);
+        self.consume(amt);
+        Ok(amt)
+    }
+}
+
+impl<'r, R> BufRead for SequenceReader<'r, R>
+where
+    R: BufRead,
+{
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        // Line endings are skipped before returning the buffer, so a definition prefix at the
+        // start of the buffer is always at the start of a line.
+        loop {
+            let src = self.inner.fill_buf()?;
+
+            match src.first() {
+                Some(&LINE_FEED | &CARRIAGE_RETURN) => self.inner.consume(1),
+                _ => break,
+            }
+        }
+
+        let src = self.inner.fill_buf()?;
+
+        if src.first() == Some(&DEFINITION_PREFIX) {
+            return Ok(&[]);
+        }
+
+        let len = src
+            .iter()
+            .position(|&b| b == LINE_FEED || b == CARRIAGE_RETURN)
+            .unwrap_or(src.len());
+
+        Ok(&src[..len])
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.inner.consume(amt);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_to_end() -> io::Result<()> {
+        let data = b"ACGT\r\nNN\n\n>sq1\nACGT\n";
+        let mut reader = &data[..];
+
+        let mut buf = Vec::new();
+        SequenceReader::new(&mut reader).read_to_end(&mut buf)?;
+
+        assert_eq!(buf, b"ACGTNN");
+        assert_eq!(reader, b">sq1\nACGT\n");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_fill_buf_with_small_buffer() -> io::Result<()> {
+        use std::io::BufReader;
+
+        let data = b"ACGT\nNNNN\nAC\n";
+        let mut reader = BufReader::with_capacity(3, &data[..]);
+        let mut sequence_reader = SequenceReader::new(&mut reader);
+
+        let mut chunks = Vec::new();
+
+        loop {
+            let buf = sequence_reader.fill_buf()?;
+
+            if buf.is_empty() {
+                break;
+            }
+
+            assert!(buf.len() <= 3);
+            chunks.push(buf.to_vec());
+
+            let len = buf.len();
+            sequence_reader.consume(len);
+        }
+
+        assert_eq!(chunks.concat(), b"ACGTNNNNAC");
+
+        Ok(())
+    }
+}
@@ -6,8 +6,9 @@ pub mod adapters;
 pub use self::adapter::Adapter;
 
 use std::{
-    collections::HashMap,
+    collections::{HashMap, VecDeque},
     fmt, io,
+    num::NonZeroUsize,
     sync::{Arc, RwLock},
 };
 
@@ -16,20 +17,90 @@ use super::record::Sequence;
 struct AdapterCache {
     adapter: Box<dyn Adapter>,
     cache: HashMap<String, Sequence>,
+    capacity: Option<NonZeroUsize>,
+    // Names of cached sequences, from least to most recently used. This is only tracked when the
+    // cache capacity is bounded.
+    recently_used: VecDeque<String>,
+}
+
+impl AdapterCache {
+    fn touch(&mut self, name: &str) {
+        if let Some(i) = self.recently_used.iter().position(|n| n == name) {
+            if let Some(name) = self.recently_used.remove(i) {
+                self.recently_used.push_back(name);
+            }
+        }
+    }
+
+    fn insert(&mut self, name: &str, sequence: Sequence) {
+        if let Some(capacity) = self.capacity {
+            while self.cache.len() >= capacity.get() {
+                match self.recently_used.pop_front() {
+                    Some(name) => {
+                        self.cache.remove(&name);
+                    }
+                    None => break,
+                }
+            }
+
+            self.recently_used.push_back(name.into());
+        }
+
+        self.cache.insert(name.into(), sequence);
+    }
 }
 
 /// A caching sequence repository.
+///
+/// Sequences are loaded from the adapter on first use and cached. By default, the cache is
+/// unbounded. Use [`Self::with_capacity`] to only keep the most recently used sequences.
 pub struct Repository(Arc<RwLock<AdapterCache>>);
 
 impl Repository {
     /// Creates a sequence repository.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::{self as fasta, repository::adapters::Empty};
+    /// let repository = fasta::Repository::new(Empty::new());
+    /// ```
     pub fn new<A>(adapter: A) -> Self
+    where
+        A: Adapter + 'static,
+    {
+        Self::build(adapter, None)
+    }
+
+    /// Creates a sequence repository that caches at most `capacity` sequences.
+    ///
+    /// When the cache is full, the least recently used sequence is evicted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use noodles_fasta::{self as fasta, repository::adapters::Empty};
+    ///
+    /// let capacity = NonZeroUsize::new(2).unwrap();
+    /// let repository = fasta::Repository::with_capacity(Empty::new(), capacity);
+    /// ```
+    pub fn with_capacity<A>(adapter: A, capacity: NonZeroUsize) -> Self
+    where
+        A: Adapter + 'static,
+    {
+        Self::build(adapter, Some(capacity))
+    }
+
+    fn build<A>(adapter: A, capacity: Option<NonZeroUsize>) -> Self
     where
         A: Adapter + 'static,
     {
         Self(Arc::new(RwLock::new(AdapterCache {
             adapter: Box::new(adapter),
             cache: HashMap::new(),
+            capacity,
+            recently_used: VecDeque::new(),
         })))
     }
 
@@ -38,21 +109,26 @@ impl Repository {
         {
             let lock = self.0.read().unwrap();
 
-            if let Some(sequence) = lock.cache.get(name) {
-                return Some(Ok(sequence.clone()));
+            if lock.capacity.is_none() {
+                if let Some(sequence) = lock.cache.get(name) {
+                    return Some(Ok(sequence.clone()));
+                }
             }
         }
 
         let mut lock = self.0.write().unwrap();
 
+        if let Some(sequence) = lock.cache.get(name).cloned() {
+            lock.touch(name);
+            return Some(Ok(sequence));
+        }
+
         let record = match lock.adapter.get(name)? {
             Ok(record) => record,
             Err(e) => return Some(Err(e)),
         };
 
-        lock.cache
-            .entry(name.into())
-            .or_insert_with(|| record.sequence().clone());
+        lock.insert(name, record.sequence().clone());
 
         Some(Ok(record.sequence().clone()))
     }
@@ -69,7 +145,9 @@ impl Repository {
 
     /// Clears the sequence cache.
     pub fn clear(&self) {
-        self.0.write().unwrap().cache.clear();
+        let mut lock = self.0.write().unwrap();
+        lock.cache.clear();
+        lock.recently_used.clear();
     }
 }
 
@@ -117,4 +195,41 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_get_with_capacity() -> io::Result<()> {
+        let records: Vec<_> = ["sq0", "sq1", "sq2"]
+            .into_iter()
+            .map(|name| {
+                Record::new(
+                    Definition::new(name, None),
+                    Sequence::from(b"ACGT".to_vec()),
+                )
+            })
+            .collect();
+
+        let capacity = NonZeroUsize::new(2).unwrap();
+        let repository = Repository::with_capacity(records, capacity);
+
+        let cached_names = |repository: &Repository| {
+            let lock = repository.0.read().unwrap();
+            let mut names: Vec<_> = lock.cache.keys().cloned().collect();
+            names.sort();
+            names
+        };
+
+        repository.get("sq0").transpose()?;
+        repository.get("sq1").transpose()?;
+        assert_eq!(cached_names(&repository), ["sq0", "sq1"]);
+
+        // sq0 is now the most recently used sequence, so sq1 is evicted.
+        repository.get("sq0").transpose()?;
+        repository.get("sq2").transpose()?;
+        assert_eq!(cached_names(&repository), ["sq0", "sq2"]);
+
+        repository.clear();
+        assert!(repository.is_empty());
+
+        Ok(())
+    }
 }
@@ -34,7 +34,42 @@ where
     R: BufRead + Seek,
 {
     fn get(&mut self, name: &str) -> Option<io::Result<Record>> {
+        if !self
+            .reader
+            .index()
+            .iter()
+            .any(|record| record.name() == name)
+        {
+            return None;
+        }
+
         let region = Region::new(name, ..);
         Some(self.reader.query(&region))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+    use crate::fai;
+
+    #[test]
+    fn test_get() -> io::Result<()> {
+        let data = b">sq0\nACGT\n";
+        let index = vec![fai::Record::new(String::from("sq0"), 4, 5, 4, 5)];
+        let reader = crate::IndexedReader::new(Cursor::new(&data[..]), index);
+        let mut adapter = IndexedReader::new(reader);
+
+        let record = adapter.get("sq0").transpose()?;
+        assert_eq!(
+            record.map(|record| record.sequence().as_ref().to_vec()),
+            Some(b"ACGT".to_vec())
+        );
+
+        assert!(adapter.get("sq1").is_none());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,113 @@
+use std::{collections::HashMap, io};
+
+use crate::{
+    digest::Digester,
+    record::{Definition, Sequence},
+    repository::Adapter,
+    Record,
+};
+
+/// An MD5-keyed adapter.
+///
+/// Sequences are stored by the MD5 checksum of their normalized sequence, and names are resolved
+/// to checksums, e.g., from the `M5` field of SAM header reference sequence records. A sequence
+/// shared by multiple names is stored once.
+#[derive(Debug, Default)]
+pub struct Md5 {
+    checksums: HashMap<String, [u8; 16]>,
+    sequences: HashMap<[u8; 16], Sequence>,
+}
+
+impl Md5 {
+    /// Creates an MD5-keyed adapter from a map of names to MD5 checksums.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use noodles_fasta::repository::adapters::Md5;
+    /// let adapter = Md5::new(HashMap::new());
+    /// ```
+    pub fn new(checksums: HashMap<String, [u8; 16]>) -> Self {
+        Self {
+            checksums,
+            sequences: HashMap::new(),
+        }
+    }
+
+    /// Adds a sequence and returns its MD5 checksum.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use noodles_fasta::{record::Sequence, repository::adapters::Md5};
+    ///
+    /// let mut adapter = Md5::new(HashMap::new());
+    /// let checksum = adapter.insert(Sequence::from(b"ACGT".to_vec()));
+    ///
+    /// assert_eq!(checksum, [
+    ///     0xf1, 0xf8, 0xf4, 0xbf, 0x41, 0x3b, 0x16, 0xad,
+    ///     0x13, 0x57, 0x22, 0xaa, 0x45, 0x91, 0x04, 0x3e,
+    /// ]);
+    /// ```
+    pub fn insert(&mut self, sequence: Sequence) -> [u8; 16] {
+        let mut digester = Digester::new();
+        digester.update(sequence.as_ref());
+        let checksum = digester.finalize().md5();
+
+        self.sequences.insert(checksum, sequence);
+
+        checksum
+    }
+}
+
+impl Adapter for Md5 {
+    fn get(&mut self, name: &str) -> Option<io::Result<Record>> {
+        let checksum = self.checksums.get(name)?;
+        let sequence = self.sequences.get(checksum)?;
+
+        let definition = Definition::new(name, None);
+        Some(Ok(Record::new(definition, sequence.clone())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get() -> io::Result<()> {
+        let sequence = Sequence::from(b"acgt".to_vec());
+
+        let mut digester = Digester::new();
+        digester.update(sequence.as_ref());
+        let checksum = digester.finalize().md5();
+
+        let checksums = [
+            (String::from("sq0"), checksum),
+            (String::from("chr0"), checksum),
+            (String::from("sq1"), [0; 16]),
+        ]
+        .into_iter()
+        .collect();
+
+        let mut adapter = Md5::new(checksums);
+        assert_eq!(adapter.insert(sequence.clone()), checksum);
+
+        assert_eq!(
+            adapter.get("sq0").transpose()?,
+            Some(Record::new(Definition::new("sq0", None), sequence.clone()))
+        );
+        assert_eq!(
+            adapter.get("chr0").transpose()?,
+            Some(Record::new(Definition::new("chr0", None), sequence))
+        );
+        assert_eq!(adapter.sequences.len(), 1);
+
+        assert!(adapter.get("sq1").is_none());
+        assert!(adapter.get("sq2").is_none());
+
+        Ok(())
+    }
+}
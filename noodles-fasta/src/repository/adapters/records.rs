@@ -1,6 +1,10 @@
-use std::io;
+use std::{collections::HashMap, io};
 
-use crate::{repository::Adapter, Record};
+use crate::{
+    record::{Definition, Sequence},
+    repository::Adapter,
+    Record,
+};
 
 impl Adapter for Vec<Record> {
     fn get(&mut self, name: &str) -> Option<io::Result<Record>> {
@@ -10,3 +14,34 @@ impl Adapter for Vec<Record> {
             .map(Ok)
     }
 }
+
+impl Adapter for HashMap<String, Sequence> {
+    fn get(&mut self, name: &str) -> Option<io::Result<Record>> {
+        HashMap::get(self, name).map(|sequence| {
+            let definition = Definition::new(name, None);
+            Ok(Record::new(definition, sequence.clone()))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_with_hash_map() -> io::Result<()> {
+        let sequence = Sequence::from(b"ACGT".to_vec());
+
+        let mut adapter: HashMap<String, Sequence> = [(String::from("sq0"), sequence.clone())]
+            .into_iter()
+            .collect();
+
+        assert_eq!(
+            Adapter::get(&mut adapter, "sq0").transpose()?,
+            Some(Record::new(Definition::new("sq0", None), sequence))
+        );
+        assert!(Adapter::get(&mut adapter, "sq1").is_none());
+
+        Ok(())
+    }
+}
@@ -1,5 +1,6 @@
 //! Alignment record and fields.
 
 pub mod record;
+pub mod umi;
 
 pub use self::record::Record;
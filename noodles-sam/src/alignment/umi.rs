@@ -0,0 +1,417 @@
+//! UMI-aware alignment record grouping.
+
+mod builder;
+mod group;
+
+pub use self::{builder::Builder, group::Group};
+
+use std::{
+    collections::{BTreeMap, VecDeque},
+    io,
+    iter::Peekable,
+};
+
+use indexmap::IndexMap;
+use noodles_core::Position;
+
+use super::Record;
+use crate::record::data::field::{Tag, Value};
+
+type Key = (Option<usize>, Option<Position>);
+
+/// An iterator over groups of alignment records.
+///
+/// Records are grouped by 5′ position, strand, and UMI. The 5′ position is the reference sequence
+/// ID and the alignment start of a forward strand record or the alignment end of a reverse strand
+/// record. The input records must be coordinate-sorted.
+///
+/// This is created by calling [`Builder::build`].
+pub struct Groups<I>
+where
+    I: Iterator<Item = io::Result<Record>>,
+{
+    records: Peekable<I>,
+    tag: Tag,
+    max_distance: usize,
+    group_id_tag: Option<Tag>,
+    next_id: usize,
+    batches: BTreeMap<Key, Vec<Record>>,
+    groups: VecDeque<Group>,
+}
+
+impl<I> Groups<I>
+where
+    I: Iterator<Item = io::Result<Record>>,
+{
+    pub(crate) fn new(
+        records: I,
+        tag: Tag,
+        max_distance: usize,
+        group_id_tag: Option<Tag>,
+    ) -> Self {
+        Self {
+            records: records.peekable(),
+            tag,
+            max_distance,
+            group_id_tag,
+            next_id: 0,
+            batches: BTreeMap::new(),
+            groups: VecDeque::new(),
+        }
+    }
+
+    fn read_batch(&mut self) -> io::Result<Option<Vec<Record>>> {
+        loop {
+            // The 5′ position of a record is never before its alignment start, so a batch is
+            // complete once the input moves past its position.
+            if let Some(batch_key) = self.batches.keys().next().copied() {
+                let is_complete = match self.records.peek() {
+                    Some(Ok(record)) => is_before(batch_key, record),
+                    Some(Err(_)) => false,
+                    None => true,
+                };
+
+                if is_complete {
+                    return Ok(self.batches.remove(&batch_key));
+                }
+            }
+
+            match self.records.next() {
+                Some(result) => {
+                    let record = result?;
+                    self.batches.entry(key(&record)).or_default().push(record);
+                }
+                None => return Ok(None),
+            }
+        }
+    }
+
+    fn group_batch(&mut self, batch: Vec<Record>) -> io::Result<()> {
+        let mut buckets: IndexMap<(bool, String), Vec<Record>> = IndexMap::new();
+
+        for record in batch {
+            let umi = get_umi(&record, self.tag)?.into();
+            let is_reverse_complemented = record.flags().is_reverse_complemented();
+
+            buckets
+                .entry((is_reverse_complemented, umi))
+                .or_default()
+                .push(record);
+        }
+
+        let keys: Vec<_> = buckets.keys().cloned().collect();
+        let mut buckets: Vec<_> = buckets.into_values().map(Some).collect();
+
+        for cluster in cluster(&keys, self.max_distance) {
+            // The representative UMI is the one with the most records. Ties are broken by the
+            // first seen.
+            let mut representative = cluster[0];
+
+            for &i in &cluster[1..] {
+                if bucket_len(&buckets[i]) > bucket_len(&buckets[representative]) {
+                    representative = i;
+                }
+            }
+
+            let (_, umi) = &keys[representative];
+            let id = self.next_id;
+            self.next_id += 1;
+
+            let mut records = Vec::new();
+
+            for i in cluster {
+                if let Some(bucket) = buckets[i].take() {
+                    records.extend(bucket);
+                }
+            }
+
+            if let Some(tag) = self.group_id_tag {
+                for record in &mut records {
+                    record.data_mut().insert(tag, Value::String(id.to_string()));
+                }
+            }
+
+            self.groups.push_back(Group::new(id, umi.clone(), records));
+        }
+
+        Ok(())
+    }
+}
+
+impl<I> Iterator for Groups<I>
+where
+    I: Iterator<Item = io::Result<Record>>,
+{
+    type Item = io::Result<Group>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(group) = self.groups.pop_front() {
+                return Some(Ok(group));
+            }
+
+            match self.read_batch() {
+                Ok(Some(batch)) => {
+                    if let Err(e) = self.group_batch(batch) {
+                        return Some(Err(e));
+                    }
+                }
+                Ok(None) => return None,
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+}
+
+fn key(record: &Record) -> Key {
+    let position = if record.flags().is_reverse_complemented() && record.alignment_span() > 0 {
+        record.alignment_end()
+    } else {
+        record.alignment_start()
+    };
+
+    (record.reference_sequence_id(), position)
+}
+
+fn is_before(batch_key: Key, record: &Record) -> bool {
+    let (reference_sequence_id, position) = batch_key;
+
+    reference_sequence_id != record.reference_sequence_id() || position < record.alignment_start()
+}
+
+fn get_umi(record: &Record, tag: Tag) -> io::Result<&str> {
+    match record.data().get(tag) {
+        Some(value) => value
+            .as_str()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "invalid UMI")),
+        None => Err(io::Error::new(io::ErrorKind::InvalidData, "missing UMI")),
+    }
+}
+
+fn bucket_len(bucket: &Option<Vec<Record>>) -> usize {
+    bucket.as_ref().map(Vec::len).unwrap_or_default()
+}
+
+// Clusters UMIs on the same strand that are within the given Hamming distance of each other
+// (single linkage). Clusters are ordered by their first member.
+fn cluster(keys: &[(bool, String)], max_distance: usize) -> Vec<Vec<usize>> {
+    let mut parents: Vec<usize> = (0..keys.len()).collect();
+
+    if max_distance > 0 {
+        for i in 0..keys.len() {
+            for j in (i + 1)..keys.len() {
+                let ((a_is_reverse_complemented, a), (b_is_reverse_complemented, b)) =
+                    (&keys[i], &keys[j]);
+
+                if a_is_reverse_complemented == b_is_reverse_complemented
+                    && hamming_distance(a, b).map(|d| d <= max_distance) == Some(true)
+                {
+                    let (root_i, root_j) = (find(&mut parents, i), find(&mut parents, j));
+                    let (lo, hi) = (root_i.min(root_j), root_i.max(root_j));
+                    parents[hi] = lo;
+                }
+            }
+        }
+    }
+
+    let mut clusters: IndexMap<usize, Vec<usize>> = IndexMap::new();
+
+    for i in 0..keys.len() {
+        let root = find(&mut parents, i);
+        clusters.entry(root).or_default().push(i);
+    }
+
+    clusters.into_values().collect()
+}
+
+fn find(parents: &mut [usize], mut i: usize) -> usize {
+    while parents[i] != i {
+        parents[i] = parents[parents[i]];
+        i = parents[i];
+    }
+
+    i
+}
+
+fn hamming_distance(a: &str, b: &str) -> Option<usize> {
+    if a.len() == b.len() {
+        Some(a.bytes().zip(b.bytes()).filter(|(x, y)| x != y).count())
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::record::Flags;
+
+    fn build_record(start: usize, is_reverse_complemented: bool, umi: &str) -> Record {
+        let flags = if is_reverse_complemented {
+            Flags::REVERSE_COMPLEMENTED
+        } else {
+            Flags::empty()
+        };
+
+        Record::builder()
+            .set_flags(flags)
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::new(start).unwrap())
+            .set_data(
+                [(Tag::UmiSequence, Value::String(umi.into()))]
+                    .into_iter()
+                    .collect(),
+            )
+            .build()
+    }
+
+    fn build_record_with_cigar(
+        start: usize,
+        cigar: &str,
+        is_reverse_complemented: bool,
+        umi: &str,
+    ) -> Record {
+        let mut record = build_record(start, is_reverse_complemented, umi);
+        *record.cigar_mut() = cigar.parse().unwrap();
+        record
+    }
+
+    #[test]
+    fn test_next() -> io::Result<()> {
+        let records = [
+            build_record(8, false, "ACGT"),
+            build_record(8, false, "ACGT"),
+            build_record(8, true, "ACGT"),
+            build_record(8, false, "TTTT"),
+            build_record(13, false, "ACGT"),
+        ];
+
+        let groups: Vec<_> = Builder::default()
+            .build(records.into_iter().map(Ok))
+            .collect::<io::Result<_>>()?;
+
+        let actual: Vec<_> = groups
+            .iter()
+            .map(|group| (group.id(), group.umi(), group.records().len()))
+            .collect();
+
+        let expected = [
+            (0, "ACGT", 2),
+            (1, "ACGT", 1),
+            (2, "TTTT", 1),
+            (3, "ACGT", 1),
+        ];
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_next_with_reverse_strand_records() -> io::Result<()> {
+        let records = [
+            build_record_with_cigar(5, "8M", true, "ACGT"),
+            build_record_with_cigar(8, "4M", false, "ACGT"),
+            build_record_with_cigar(8, "5M", true, "ACGT"),
+            build_record_with_cigar(9, "4M", true, "ACGT"),
+            build_record_with_cigar(10, "4M", true, "ACGT"),
+        ];
+
+        let groups: Vec<_> = Builder::default()
+            .build(records.into_iter().map(Ok))
+            .collect::<io::Result<_>>()?;
+
+        let actual: Vec<_> = groups
+            .iter()
+            .map(|group| {
+                let starts: Vec<_> = group
+                    .records()
+                    .iter()
+                    .filter_map(|record| record.alignment_start())
+                    .map(usize::from)
+                    .collect();
+
+                (group.id(), starts)
+            })
+            .collect();
+
+        // The 5′ positions are 12, 8, 12, 12, and 13, respectively.
+        let expected = [(0, vec![8]), (1, vec![5, 8, 9]), (2, vec![10])];
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_next_with_max_distance() -> io::Result<()> {
+        let records = [
+            build_record(8, false, "ACGA"),
+            build_record(8, false, "ACGT"),
+            build_record(8, false, "ACGT"),
+            build_record(8, true, "ACGA"),
+            build_record(8, false, "TTTT"),
+        ];
+
+        let groups: Vec<_> = Builder::default()
+            .set_max_distance(1)
+            .build(records.into_iter().map(Ok))
+            .collect::<io::Result<_>>()?;
+
+        let actual: Vec<_> = groups
+            .iter()
+            .map(|group| (group.id(), group.umi(), group.records().len()))
+            .collect();
+
+        let expected = [(0, "ACGT", 3), (1, "ACGA", 1), (2, "TTTT", 1)];
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_next_with_group_id_tag() -> io::Result<()> {
+        let records = [
+            build_record(8, false, "ACGT"),
+            build_record(13, false, "ACGT"),
+        ];
+
+        let groups: Vec<_> = Builder::default()
+            .set_group_id_tag(Tag::UmiId)
+            .build(records.into_iter().map(Ok))
+            .collect::<io::Result<_>>()?;
+
+        let ids: Vec<_> = groups
+            .iter()
+            .flat_map(|group| group.records())
+            .map(|record| {
+                record
+                    .data()
+                    .get(Tag::UmiId)
+                    .and_then(|value| value.as_str())
+            })
+            .collect();
+
+        assert_eq!(ids, [Some("0"), Some("1")]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_next_with_missing_umi() {
+        let records = [Record::default()];
+        let mut groups = Builder::default().build(records.into_iter().map(Ok));
+
+        assert!(matches!(
+            groups.next(),
+            Some(Err(e)) if e.kind() == io::ErrorKind::InvalidData
+        ));
+    }
+
+    #[test]
+    fn test_hamming_distance() {
+        assert_eq!(hamming_distance("ACGT", "ACGT"), Some(0));
+        assert_eq!(hamming_distance("ACGT", "ACGA"), Some(1));
+        assert_eq!(hamming_distance("ACGT", "TGCA"), Some(4));
+        assert_eq!(hamming_distance("ACGT", "ACG"), None);
+    }
+}
@@ -0,0 +1,96 @@
+use std::io;
+
+use super::Groups;
+use crate::{alignment::Record, record::data::field::Tag};
+
+/// A UMI groups builder.
+#[derive(Debug)]
+pub struct Builder {
+    tag: Tag,
+    max_distance: usize,
+    group_id_tag: Option<Tag>,
+}
+
+impl Builder {
+    /// Sets the data field tag that holds the UMI.
+    ///
+    /// By default, this is `RX` ([`Tag::UmiSequence`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::{alignment::umi, record::data::field::Tag};
+    /// let builder = umi::Builder::default().set_tag(Tag::UmiSequence);
+    /// ```
+    pub fn set_tag(mut self, tag: Tag) -> Self {
+        self.tag = tag;
+        self
+    }
+
+    /// Sets the maximum Hamming distance between UMIs in the same group.
+    ///
+    /// UMIs on the same strand at the same position that are within this distance of each other
+    /// are clustered into a single group. The group UMI is the one with the most records.
+    ///
+    /// By default, this is 0, i.e., UMIs must match exactly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::umi;
+    /// let builder = umi::Builder::default().set_max_distance(1);
+    /// ```
+    pub fn set_max_distance(mut self, max_distance: usize) -> Self {
+        self.max_distance = max_distance;
+        self
+    }
+
+    /// Sets the data field tag to write group IDs to.
+    ///
+    /// When set, each record in a group is given the group ID as a string value, e.g., `MI:Z:0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::{alignment::umi, record::data::field::Tag};
+    /// let builder = umi::Builder::default().set_group_id_tag(Tag::UmiId);
+    /// ```
+    pub fn set_group_id_tag(mut self, group_id_tag: Tag) -> Self {
+        self.group_id_tag = Some(group_id_tag);
+        self
+    }
+
+    /// Builds an iterator over UMI groups from coordinate-sorted records.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io;
+    /// use noodles_sam::alignment::{umi, Record};
+    ///
+    /// let records: Vec<io::Result<Record>> = Vec::new();
+    /// let mut groups = umi::Builder::default().build(records);
+    /// assert!(groups.next().is_none());
+    /// ```
+    pub fn build<I>(self, records: I) -> Groups<I::IntoIter>
+    where
+        I: IntoIterator<Item = io::Result<Record>>,
+    {
+        Groups::new(
+            records.into_iter(),
+            self.tag,
+            self.max_distance,
+            self.group_id_tag,
+        )
+    }
+}
+
+impl Default for Builder {
+    fn default() -> Self {
+        Self {
+            tag: Tag::UmiSequence,
+            max_distance: 0,
+            group_id_tag: None,
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use crate::alignment::Record;
+
+/// A group of alignment records that share a position, strand, and UMI.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Group {
+    id: usize,
+    umi: String,
+    records: Vec<Record>,
+}
+
+impl Group {
+    pub(super) fn new(id: usize, umi: String, records: Vec<Record>) -> Self {
+        Self { id, umi, records }
+    }
+
+    /// Returns the group ID.
+    ///
+    /// Group IDs are assigned sequentially, starting at 0.
+    pub fn id(&self) -> usize {
+        self.id
+    }
+
+    /// Returns the UMI that represents the group.
+    pub fn umi(&self) -> &str {
+        &self.umi
+    }
+
+    /// Returns the records in the group.
+    pub fn records(&self) -> &[Record] {
+        &self.records
+    }
+
+    /// Returns the records in the group, consuming the group.
+    pub fn into_records(self) -> Vec<Record> {
+        self.records
+    }
+}
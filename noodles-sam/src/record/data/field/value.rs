@@ -1,8 +1,10 @@
 //! SAM record data field value and types.
 
+pub mod base_modifications;
 pub mod character;
 pub mod hex;
 pub mod subtype;
+pub mod supplementary_alignments;
 pub mod ty;
 
 pub use self::{character::Character, hex::Hex, subtype::Subtype, ty::Type};
@@ -0,0 +1,478 @@
+//! SAM record data field value base modifications.
+//!
+//! Base modifications are stored in the `MM` (base modifications) and `ML` (base modification
+//! probabilities) data fields.
+
+mod calls;
+pub mod group;
+
+pub use self::{
+    calls::{Call, Calls, ReferenceCalls},
+    group::Group,
+};
+
+use std::{error, fmt, io, num, ops::Deref};
+
+use self::group::{strand, unmodified_base, Modification, Status, UnmodifiedBase};
+use crate::record::{sequence::Base, Sequence};
+
+const GROUP_TERMINATOR: char = ';';
+const POSITION_DELIMITER: char = ',';
+
+/// SAM record data field value base modifications.
+///
+/// This is the parsed form of the `MM` data field value.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct BaseModifications {
+    groups: Vec<Group>,
+    is_reverse_complemented: bool,
+}
+
+impl BaseModifications {
+    /// Parses a raw `MM` data field value.
+    ///
+    /// The positions of modified bases are resolved against the record sequence. When the record
+    /// is reverse complemented, the skip counts are relative to the reverse complement of the
+    /// sequence, as described in the SAM tags specification.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::record::{
+    ///     data::field::value::base_modifications::{
+    ///         group::{Modification, Strand, UnmodifiedBase},
+    ///         BaseModifications, Group,
+    ///     },
+    ///     Sequence,
+    /// };
+    ///
+    /// let sequence: Sequence = "CACCCGATGACCGGCT".parse()?;
+    /// let base_modifications = BaseModifications::parse("C+m,1,3,0;", false, &sequence)?;
+    ///
+    /// assert_eq!(
+    ///     base_modifications.as_ref(),
+    ///     [Group::new(
+    ///         UnmodifiedBase::C,
+    ///         Strand::Forward,
+    ///         vec![Modification::FIVE_METHYLCYTOSINE],
+    ///         None,
+    ///         vec![2, 11, 14],
+    ///     )],
+    /// );
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn parse(
+        s: &str,
+        is_reverse_complemented: bool,
+        sequence: &Sequence,
+    ) -> Result<Self, ParseError> {
+        let mut groups = Vec::new();
+        let mut src = s;
+
+        while !src.is_empty() {
+            let i = src.find(GROUP_TERMINATOR).ok_or(ParseError::Invalid)?;
+            let group = parse_group(&src[..i], is_reverse_complemented, sequence)?;
+            groups.push(group);
+            src = &src[i + 1..];
+        }
+
+        Ok(Self {
+            groups,
+            is_reverse_complemented,
+        })
+    }
+
+    /// Returns an iterator over the modification calls.
+    ///
+    /// `probabilities` is the `ML` data field value. If it is empty, the calls have no
+    /// probabilities; otherwise, it must have one value per modification per position.
+    ///
+    /// Probabilities are in the order of the `MM` data field value. For reverse complemented
+    /// records, they are matched to the positions they were listed with, not to the (ascending)
+    /// resolved positions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::record::{
+    ///     data::field::value::base_modifications::{group::Modification, BaseModifications},
+    ///     Sequence,
+    /// };
+    ///
+    /// let sequence: Sequence = "CACCCGATGACCGGCT".parse()?;
+    /// let base_modifications = BaseModifications::parse("C+mh,1,3;", false, &sequence)?;
+    /// let probabilities = [250, 2, 128, 64];
+    ///
+    /// let calls: Vec<_> = base_modifications
+    ///     .calls(&probabilities)?
+    ///     .map(|call| (call.position(), call.modification(), call.probability()))
+    ///     .collect();
+    ///
+    /// assert_eq!(calls, [
+    ///     (2, Modification::FIVE_METHYLCYTOSINE, Some(250)),
+    ///     (2, Modification::FIVE_HYDROXYMETHYLCYTOSINE, Some(2)),
+    ///     (11, Modification::FIVE_METHYLCYTOSINE, Some(128)),
+    ///     (11, Modification::FIVE_HYDROXYMETHYLCYTOSINE, Some(64)),
+    /// ]);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn calls<'a>(&'a self, probabilities: &'a [u8]) -> io::Result<Calls<'a>> {
+        let probabilities = if probabilities.is_empty() {
+            None
+        } else {
+            let call_count: usize = self
+                .iter()
+                .map(|group| group.positions().len() * group.modifications().len())
+                .sum();
+
+            if probabilities.len() != call_count {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "base modification probabilities length mismatch",
+                ));
+            }
+
+            Some(probabilities)
+        };
+
+        Ok(Calls::new(
+            &self.groups,
+            probabilities,
+            self.is_reverse_complemented,
+        ))
+    }
+}
+
+impl AsRef<[Group]> for BaseModifications {
+    fn as_ref(&self) -> &[Group] {
+        &self.groups
+    }
+}
+
+impl Deref for BaseModifications {
+    type Target = [Group];
+
+    fn deref(&self) -> &Self::Target {
+        &self.groups
+    }
+}
+
+/// An error returned when raw base modifications fail to parse.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The input is invalid.
+    Invalid,
+    /// The unmodified base is invalid.
+    InvalidUnmodifiedBase(unmodified_base::TryFromU8Error),
+    /// The strand is invalid.
+    InvalidStrand(strand::TryFromU8Error),
+    /// A modification is missing.
+    MissingModification,
+    /// A modification is invalid.
+    InvalidModification,
+    /// A skip count is invalid.
+    InvalidSkipCount(num::ParseIntError),
+    /// A position is out of range of the sequence.
+    InvalidPosition,
+}
+
+impl error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::InvalidUnmodifiedBase(e) => Some(e),
+            Self::InvalidStrand(e) => Some(e),
+            Self::InvalidSkipCount(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Invalid => f.write_str("invalid input"),
+            Self::InvalidUnmodifiedBase(_) => f.write_str("invalid unmodified base"),
+            Self::InvalidStrand(_) => f.write_str("invalid strand"),
+            Self::MissingModification => f.write_str("missing modification"),
+            Self::InvalidModification => f.write_str("invalid modification"),
+            Self::InvalidSkipCount(_) => f.write_str("invalid skip count"),
+            Self::InvalidPosition => f.write_str("invalid position"),
+        }
+    }
+}
+
+fn parse_group(
+    s: &str,
+    is_reverse_complemented: bool,
+    sequence: &Sequence,
+) -> Result<Group, ParseError> {
+    let mut src = s.as_bytes();
+
+    let (unmodified_base, strand) = match src {
+        [b, s, rest @ ..] => {
+            src = rest;
+
+            (
+                UnmodifiedBase::try_from(*b).map_err(ParseError::InvalidUnmodifiedBase)?,
+                strand::Strand::try_from(*s).map_err(ParseError::InvalidStrand)?,
+            )
+        }
+        _ => return Err(ParseError::Invalid),
+    };
+
+    let modifications = parse_modifications(&mut src)?;
+    let status = parse_status(&mut src);
+
+    let mut skip_counts = Vec::new();
+
+    if !src.is_empty() {
+        let raw_skip_counts = std::str::from_utf8(src).map_err(|_| ParseError::Invalid)?;
+        let raw_skip_counts = raw_skip_counts
+            .strip_prefix(POSITION_DELIMITER)
+            .ok_or(ParseError::Invalid)?;
+
+        for raw_skip_count in raw_skip_counts.split(POSITION_DELIMITER) {
+            let n = raw_skip_count
+                .parse()
+                .map_err(ParseError::InvalidSkipCount)?;
+
+            skip_counts.push(n);
+        }
+    }
+
+    let positions = resolve_positions(
+        unmodified_base,
+        is_reverse_complemented,
+        sequence,
+        &skip_counts,
+    )?;
+
+    Ok(Group::new(
+        unmodified_base,
+        strand,
+        modifications,
+        status,
+        positions,
+    ))
+}
+
+fn parse_modifications(src: &mut &[u8]) -> Result<Vec<Modification>, ParseError> {
+    let len = src
+        .iter()
+        .position(|&b| matches!(b, b'.' | b'?' | b','))
+        .unwrap_or(src.len());
+
+    let (buf, rest) = src.split_at(len);
+    *src = rest;
+
+    if buf.is_empty() {
+        Err(ParseError::MissingModification)
+    } else if buf.iter().all(u8::is_ascii_digit) {
+        let id = std::str::from_utf8(buf)
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .ok_or(ParseError::InvalidModification)?;
+
+        Ok(vec![Modification::ChebiId(id)])
+    } else if buf.iter().all(u8::is_ascii_alphabetic) {
+        Ok(buf.iter().copied().map(Modification::Code).collect())
+    } else {
+        Err(ParseError::InvalidModification)
+    }
+}
+
+fn parse_status(src: &mut &[u8]) -> Option<Status> {
+    let status = match src.first() {
+        Some(b'.') => Status::Implicit,
+        Some(b'?') => Status::Explicit,
+        _ => return None,
+    };
+
+    *src = &src[1..];
+
+    Some(status)
+}
+
+fn resolve_positions(
+    unmodified_base: UnmodifiedBase,
+    is_reverse_complemented: bool,
+    sequence: &Sequence,
+    skip_counts: &[usize],
+) -> Result<Vec<usize>, ParseError> {
+    let bases = sequence.as_ref();
+
+    let mut candidates: Box<dyn Iterator<Item = usize>> = if is_reverse_complemented {
+        let target = unmodified_base.complement();
+
+        Box::new(
+            (0..bases.len())
+                .rev()
+                .filter(move |&i| matches_base(target, bases[i])),
+        )
+    } else {
+        Box::new((0..bases.len()).filter(move |&i| matches_base(unmodified_base, bases[i])))
+    };
+
+    let mut positions = Vec::with_capacity(skip_counts.len());
+
+    for &n in skip_counts {
+        let position = candidates.nth(n).ok_or(ParseError::InvalidPosition)?;
+        positions.push(position);
+    }
+
+    if is_reverse_complemented {
+        positions.reverse();
+    }
+
+    Ok(positions)
+}
+
+fn matches_base(unmodified_base: UnmodifiedBase, base: Base) -> bool {
+    match unmodified_base {
+        UnmodifiedBase::A => base == Base::A,
+        UnmodifiedBase::C => base == Base::C,
+        UnmodifiedBase::G => base == Base::G,
+        UnmodifiedBase::T => base == Base::T,
+        UnmodifiedBase::U => base == Base::U,
+        UnmodifiedBase::N => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{group::Strand, *};
+
+    #[test]
+    fn test_parse() -> Result<(), Box<dyn std::error::Error>> {
+        let sequence: Sequence = "AGCTCTCCAGAGTCGNACGCCATYCGCGCGCCACCA".parse()?;
+
+        let actual = BaseModifications::parse("C+m,2,2,1,4,1;C+h?,6,7;", false, &sequence)?;
+        let expected = [
+            Group::new(
+                UnmodifiedBase::C,
+                Strand::Forward,
+                vec![Modification::FIVE_METHYLCYTOSINE],
+                None,
+                vec![6, 17, 20, 31, 34],
+            ),
+            Group::new(
+                UnmodifiedBase::C,
+                Strand::Forward,
+                vec![Modification::FIVE_HYDROXYMETHYLCYTOSINE],
+                Some(Status::Explicit),
+                vec![19, 34],
+            ),
+        ];
+        assert_eq!(actual.as_ref(), expected);
+
+        assert_eq!(
+            BaseModifications::parse("", false, &sequence),
+            Ok(BaseModifications::default())
+        );
+
+        let actual = BaseModifications::parse("N+76792.;", false, &sequence)?;
+        let expected = [Group::new(
+            UnmodifiedBase::N,
+            Strand::Forward,
+            vec![Modification::ChebiId(76792)],
+            Some(Status::Implicit),
+            Vec::new(),
+        )];
+        assert_eq!(actual.as_ref(), expected);
+
+        assert_eq!(
+            BaseModifications::parse("C+m,2", false, &sequence),
+            Err(ParseError::Invalid)
+        );
+        assert_eq!(
+            BaseModifications::parse("C+,2;", false, &sequence),
+            Err(ParseError::MissingModification)
+        );
+        assert!(matches!(
+            BaseModifications::parse("Z+m,2;", false, &sequence),
+            Err(ParseError::InvalidUnmodifiedBase(_))
+        ));
+        assert!(matches!(
+            BaseModifications::parse("C*m,2;", false, &sequence),
+            Err(ParseError::InvalidStrand(_))
+        ));
+        assert!(matches!(
+            BaseModifications::parse("C+m,x;", false, &sequence),
+            Err(ParseError::InvalidSkipCount(_))
+        ));
+        assert_eq!(
+            BaseModifications::parse("C+m,64;", false, &sequence),
+            Err(ParseError::InvalidPosition)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_with_reverse_complemented_record() -> Result<(), Box<dyn std::error::Error>> {
+        // The reverse complement is `GCGGTCATCGGG`.
+        let sequence: Sequence = "CCCGATGACCGC".parse()?;
+
+        let actual = BaseModifications::parse("C+m,0,1;", true, &sequence)?;
+        let expected = [Group::new(
+            UnmodifiedBase::C,
+            Strand::Forward,
+            vec![Modification::FIVE_METHYLCYTOSINE],
+            None,
+            vec![3, 10],
+        )];
+        assert_eq!(actual.as_ref(), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_calls() -> Result<(), Box<dyn std::error::Error>> {
+        let sequence: Sequence = "CACCCGATGACCGGCT".parse()?;
+        let base_modifications = BaseModifications::parse("C+m,1;C+h,0;", false, &sequence)?;
+
+        let actual: Vec<_> = base_modifications
+            .calls(&[])?
+            .map(|call| (call.position(), call.probability()))
+            .collect();
+        assert_eq!(actual, [(2, None), (0, None)]);
+
+        let actual: Vec<_> = base_modifications
+            .calls(&[200, 10])?
+            .map(|call| (call.position(), call.probability()))
+            .collect();
+        assert_eq!(actual, [(2, Some(200)), (0, Some(10))]);
+
+        assert!(matches!(
+            base_modifications.calls(&[200]),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_calls_with_reverse_complemented_record() -> Result<(), Box<dyn std::error::Error>> {
+        // The reverse complement is `GCGGTCATCGGG`.
+        let sequence: Sequence = "CCCGATGACCGC".parse()?;
+        let base_modifications = BaseModifications::parse("C+mh,0,1;C+h,1;", true, &sequence)?;
+
+        let actual: Vec<_> = base_modifications
+            .calls(&[1, 2, 3, 4, 5])?
+            .map(|call| (call.position(), call.modification(), call.probability()))
+            .collect();
+
+        assert_eq!(
+            actual,
+            [
+                (3, Modification::FIVE_METHYLCYTOSINE, Some(3)),
+                (3, Modification::FIVE_HYDROXYMETHYLCYTOSINE, Some(4)),
+                (10, Modification::FIVE_METHYLCYTOSINE, Some(1)),
+                (10, Modification::FIVE_HYDROXYMETHYLCYTOSINE, Some(2)),
+                (6, Modification::FIVE_HYDROXYMETHYLCYTOSINE, Some(5)),
+            ]
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,246 @@
+use noodles_core::Position;
+
+use super::{
+    group::{Modification, Strand, UnmodifiedBase},
+    Group,
+};
+use crate::record::Cigar;
+
+/// A base modification call.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Call {
+    position: usize,
+    unmodified_base: UnmodifiedBase,
+    strand: Strand,
+    modification: Modification,
+    probability: Option<u8>,
+}
+
+impl Call {
+    /// Returns the position of the modified base.
+    ///
+    /// This is a 0-based index into the record sequence (`SEQ`).
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Returns the unmodified base.
+    pub fn unmodified_base(&self) -> UnmodifiedBase {
+        self.unmodified_base
+    }
+
+    /// Returns the strand.
+    pub fn strand(&self) -> Strand {
+        self.strand
+    }
+
+    /// Returns the modification.
+    pub fn modification(&self) -> Modification {
+        self.modification
+    }
+
+    /// Returns the encoded probability of the modification, if set.
+    ///
+    /// This is the raw `ML` value. The probability range 0.0 to 1.0 is scaled to 0 to 255, where
+    /// each value covers 1/256 of the range.
+    pub fn probability(&self) -> Option<u8> {
+        self.probability
+    }
+}
+
+/// An iterator over base modification calls.
+///
+/// This is created by calling [`super::BaseModifications::calls`].
+pub struct Calls<'a> {
+    groups: &'a [Group],
+    probabilities: Option<&'a [u8]>,
+    is_reverse_complemented: bool,
+    group_index: usize,
+    position_index: usize,
+    modification_index: usize,
+    // The index of the first probability of the current group.
+    probability_offset: usize,
+}
+
+impl<'a> Calls<'a> {
+    pub(super) fn new(
+        groups: &'a [Group],
+        probabilities: Option<&'a [u8]>,
+        is_reverse_complemented: bool,
+    ) -> Self {
+        Self {
+            groups,
+            probabilities,
+            is_reverse_complemented,
+            group_index: 0,
+            position_index: 0,
+            modification_index: 0,
+            probability_offset: 0,
+        }
+    }
+
+    /// Maps the calls to reference positions using the alignment start and CIGAR.
+    ///
+    /// Calls at sequence positions that do not align to the reference, e.g., insertions and soft
+    /// clips, are mapped to `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_sam::record::{
+    ///     data::field::value::base_modifications::BaseModifications, Cigar, Sequence,
+    /// };
+    ///
+    /// let sequence: Sequence = "ACCGA".parse()?;
+    /// let base_modifications = BaseModifications::parse("C+m,0,0;", false, &sequence)?;
+    /// let cigar: Cigar = "2M1I2M".parse()?;
+    ///
+    /// let positions: Vec<_> = base_modifications
+    ///     .calls(&[])?
+    ///     .with_reference_positions(Position::try_from(8)?, &cigar)
+    ///     .map(|(position, _)| position)
+    ///     .collect();
+    ///
+    /// assert_eq!(positions, [Some(Position::try_from(9)?), None]);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn with_reference_positions(
+        self,
+        alignment_start: Position,
+        cigar: &Cigar,
+    ) -> ReferenceCalls<'a> {
+        ReferenceCalls {
+            calls: self,
+            reference_positions: build_reference_positions(alignment_start, cigar),
+        }
+    }
+}
+
+impl<'a> Iterator for Calls<'a> {
+    type Item = Call;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let group = self.groups.get(self.group_index)?;
+            let positions = group.positions();
+
+            let position = match positions.get(self.position_index) {
+                Some(&position) => position,
+                None => {
+                    self.probability_offset += positions.len() * group.modifications().len();
+                    self.group_index += 1;
+                    self.position_index = 0;
+                    self.modification_index = 0;
+                    continue;
+                }
+            };
+
+            let modification = match group.modifications().get(self.modification_index) {
+                Some(&modification) => modification,
+                None => {
+                    self.position_index += 1;
+                    self.modification_index = 0;
+                    continue;
+                }
+            };
+
+            // Positions of reverse complemented records are resolved in descending order and
+            // then reversed, but probabilities remain in the original order.
+            let i = if self.is_reverse_complemented {
+                positions.len() - 1 - self.position_index
+            } else {
+                self.position_index
+            };
+
+            let probability_index =
+                self.probability_offset + i * group.modifications().len() + self.modification_index;
+
+            let probability = self
+                .probabilities
+                .and_then(|probabilities| probabilities.get(probability_index).copied());
+
+            self.modification_index += 1;
+
+            return Some(Call {
+                position,
+                unmodified_base: group.unmodified_base(),
+                strand: group.strand(),
+                modification,
+                probability,
+            });
+        }
+    }
+}
+
+/// An iterator over base modification calls with their reference positions.
+///
+/// This is created by calling [`Calls::with_reference_positions`].
+pub struct ReferenceCalls<'a> {
+    calls: Calls<'a>,
+    reference_positions: Vec<Option<Position>>,
+}
+
+impl<'a> Iterator for ReferenceCalls<'a> {
+    type Item = (Option<Position>, Call);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let call = self.calls.next()?;
+        let reference_position = self
+            .reference_positions
+            .get(call.position())
+            .copied()
+            .flatten();
+        Some((reference_position, call))
+    }
+}
+
+fn build_reference_positions(alignment_start: Position, cigar: &Cigar) -> Vec<Option<Position>> {
+    let mut reference_positions = Vec::with_capacity(cigar.read_length());
+    let mut reference_position = usize::from(alignment_start);
+
+    for op in cigar.iter() {
+        let kind = op.kind();
+
+        match (kind.consumes_read(), kind.consumes_reference()) {
+            (true, true) => {
+                for i in 0..op.len() {
+                    reference_positions.push(Position::new(reference_position + i));
+                }
+
+                reference_position += op.len();
+            }
+            (true, false) => {
+                reference_positions.extend((0..op.len()).map(|_| None));
+            }
+            (false, true) => reference_position += op.len(),
+            (false, false) => {}
+        }
+    }
+
+    reference_positions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_reference_positions() -> Result<(), Box<dyn std::error::Error>> {
+        let alignment_start = Position::try_from(8)?;
+        let cigar = "1S2M1I1D1M".parse()?;
+
+        assert_eq!(
+            build_reference_positions(alignment_start, &cigar),
+            [
+                None,
+                Position::new(8),
+                Position::new(9),
+                None,
+                Position::new(11),
+            ]
+        );
+
+        Ok(())
+    }
+}
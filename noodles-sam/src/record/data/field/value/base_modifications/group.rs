@@ -0,0 +1,86 @@
+//! SAM record data field value base modification group.
+
+pub mod modification;
+pub mod status;
+pub mod strand;
+pub mod unmodified_base;
+
+pub use self::{
+    modification::Modification, status::Status, strand::Strand, unmodified_base::UnmodifiedBase,
+};
+
+/// A base modification group.
+///
+/// A group describes one or more modifications of an unmodified base on a strand and the
+/// positions in the sequence at which they occur.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Group {
+    unmodified_base: UnmodifiedBase,
+    strand: Strand,
+    modifications: Vec<Modification>,
+    status: Option<Status>,
+    positions: Vec<usize>,
+}
+
+impl Group {
+    /// Creates a base modification group.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::record::data::field::value::base_modifications::{
+    ///     group::{Modification, Strand, UnmodifiedBase},
+    ///     Group,
+    /// };
+    ///
+    /// let group = Group::new(
+    ///     UnmodifiedBase::C,
+    ///     Strand::Forward,
+    ///     vec![Modification::FIVE_METHYLCYTOSINE],
+    ///     None,
+    ///     vec![2, 8],
+    /// );
+    /// ```
+    pub fn new(
+        unmodified_base: UnmodifiedBase,
+        strand: Strand,
+        modifications: Vec<Modification>,
+        status: Option<Status>,
+        positions: Vec<usize>,
+    ) -> Self {
+        Self {
+            unmodified_base,
+            strand,
+            modifications,
+            status,
+            positions,
+        }
+    }
+
+    /// Returns the unmodified base.
+    pub fn unmodified_base(&self) -> UnmodifiedBase {
+        self.unmodified_base
+    }
+
+    /// Returns the strand.
+    pub fn strand(&self) -> Strand {
+        self.strand
+    }
+
+    /// Returns the list of modifications.
+    pub fn modifications(&self) -> &[Modification] {
+        &self.modifications
+    }
+
+    /// Returns the status.
+    pub fn status(&self) -> Option<Status> {
+        self.status
+    }
+
+    /// Returns the positions of the modified bases.
+    ///
+    /// Positions are 0-based indices into the record sequence (`SEQ`).
+    pub fn positions(&self) -> &[usize] {
+        &self.positions
+    }
+}
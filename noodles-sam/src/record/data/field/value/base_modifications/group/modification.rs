@@ -0,0 +1,38 @@
+//! SAM record data field value base modification group modification.
+
+use std::fmt;
+
+/// A base modification.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Modification {
+    /// A modification identified by a single-character code, e.g., `m` (5mC).
+    Code(u8),
+    /// A modification identified by a ChEBI ID.
+    ChebiId(u32),
+}
+
+impl Modification {
+    /// 5-methylcytosine (`m`).
+    pub const FIVE_METHYLCYTOSINE: Self = Self::Code(b'm');
+
+    /// 5-hydroxymethylcytosine (`h`).
+    pub const FIVE_HYDROXYMETHYLCYTOSINE: Self = Self::Code(b'h');
+
+    /// 5-formylcytosine (`f`).
+    pub const FIVE_FORMYLCYTOSINE: Self = Self::Code(b'f');
+
+    /// 5-carboxylcytosine (`c`).
+    pub const FIVE_CARBOXYLCYTOSINE: Self = Self::Code(b'c');
+
+    /// 6-methyladenine (`a`).
+    pub const SIX_METHYLADENINE: Self = Self::Code(b'a');
+}
+
+impl fmt::Display for Modification {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Code(code) => write!(f, "{}", char::from(*code)),
+            Self::ChebiId(id) => write!(f, "{id}"),
+        }
+    }
+}
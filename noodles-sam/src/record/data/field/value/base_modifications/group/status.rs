@@ -0,0 +1,12 @@
+//! SAM record data field value base modification group status.
+
+/// A base modification group status.
+///
+/// This describes how to interpret bases that are skipped by the position list.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Status {
+    /// Skipped bases are assumed to be unmodified (`.`).
+    Implicit,
+    /// Skipped bases have an unknown modification status (`?`).
+    Explicit,
+}
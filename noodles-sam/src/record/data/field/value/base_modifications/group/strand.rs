@@ -0,0 +1,68 @@
+//! SAM record data field value base modification group strand.
+
+use std::{error, fmt};
+
+/// A base modification group strand.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Strand {
+    /// The modification is on the same strand as the sequence (`+`).
+    Forward,
+    /// The modification is on the opposite strand of the sequence (`-`).
+    Reverse,
+}
+
+/// An error returned when a base modification group strand fails to parse.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TryFromU8Error {
+    /// The input is invalid.
+    Invalid(u8),
+}
+
+impl error::Error for TryFromU8Error {}
+
+impl fmt::Display for TryFromU8Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Invalid(b) => write!(f, "invalid input: expected {{+, -}}, got {b}"),
+        }
+    }
+}
+
+impl TryFrom<u8> for Strand {
+    type Error = TryFromU8Error;
+
+    fn try_from(b: u8) -> Result<Self, Self::Error> {
+        match b {
+            b'+' => Ok(Self::Forward),
+            b'-' => Ok(Self::Reverse),
+            _ => Err(TryFromU8Error::Invalid(b)),
+        }
+    }
+}
+
+impl From<Strand> for u8 {
+    fn from(strand: Strand) -> Self {
+        match strand {
+            Strand::Forward => b'+',
+            Strand::Reverse => b'-',
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_from_u8_for_strand() {
+        assert_eq!(Strand::try_from(b'+'), Ok(Strand::Forward));
+        assert_eq!(Strand::try_from(b'-'), Ok(Strand::Reverse));
+        assert_eq!(Strand::try_from(b'.'), Err(TryFromU8Error::Invalid(b'.')));
+    }
+
+    #[test]
+    fn test_from_strand_for_u8() {
+        assert_eq!(u8::from(Strand::Forward), b'+');
+        assert_eq!(u8::from(Strand::Reverse), b'-');
+    }
+}
@@ -0,0 +1,116 @@
+//! SAM record data field value base modification group unmodified base.
+
+use std::{error, fmt};
+
+/// A base modification group unmodified base.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum UnmodifiedBase {
+    /// Adenine (`A`).
+    A,
+    /// Cytosine (`C`).
+    C,
+    /// Guanine (`G`).
+    G,
+    /// Thymine (`T`).
+    T,
+    /// Uracil (`U`).
+    U,
+    /// Any base (`N`).
+    N,
+}
+
+impl UnmodifiedBase {
+    /// Returns the complement of the base.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::record::data::field::value::base_modifications::group::UnmodifiedBase;
+    /// assert_eq!(UnmodifiedBase::C.complement(), UnmodifiedBase::G);
+    /// assert_eq!(UnmodifiedBase::N.complement(), UnmodifiedBase::N);
+    /// ```
+    pub fn complement(self) -> Self {
+        match self {
+            Self::A => Self::T,
+            Self::C => Self::G,
+            Self::G => Self::C,
+            Self::T | Self::U => Self::A,
+            Self::N => Self::N,
+        }
+    }
+}
+
+/// An error returned when a base modification group unmodified base fails to parse.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TryFromU8Error {
+    /// The input is invalid.
+    Invalid(u8),
+}
+
+impl error::Error for TryFromU8Error {}
+
+impl fmt::Display for TryFromU8Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Invalid(b) => write!(f, "invalid input: expected {{A, C, G, T, U, N}}, got {b}"),
+        }
+    }
+}
+
+impl TryFrom<u8> for UnmodifiedBase {
+    type Error = TryFromU8Error;
+
+    fn try_from(b: u8) -> Result<Self, Self::Error> {
+        match b {
+            b'A' => Ok(Self::A),
+            b'C' => Ok(Self::C),
+            b'G' => Ok(Self::G),
+            b'T' => Ok(Self::T),
+            b'U' => Ok(Self::U),
+            b'N' => Ok(Self::N),
+            _ => Err(TryFromU8Error::Invalid(b)),
+        }
+    }
+}
+
+impl From<UnmodifiedBase> for u8 {
+    fn from(base: UnmodifiedBase) -> Self {
+        match base {
+            UnmodifiedBase::A => b'A',
+            UnmodifiedBase::C => b'C',
+            UnmodifiedBase::G => b'G',
+            UnmodifiedBase::T => b'T',
+            UnmodifiedBase::U => b'U',
+            UnmodifiedBase::N => b'N',
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_from_u8_for_unmodified_base() {
+        assert_eq!(UnmodifiedBase::try_from(b'A'), Ok(UnmodifiedBase::A));
+        assert_eq!(UnmodifiedBase::try_from(b'C'), Ok(UnmodifiedBase::C));
+        assert_eq!(UnmodifiedBase::try_from(b'G'), Ok(UnmodifiedBase::G));
+        assert_eq!(UnmodifiedBase::try_from(b'T'), Ok(UnmodifiedBase::T));
+        assert_eq!(UnmodifiedBase::try_from(b'U'), Ok(UnmodifiedBase::U));
+        assert_eq!(UnmodifiedBase::try_from(b'N'), Ok(UnmodifiedBase::N));
+        assert_eq!(
+            UnmodifiedBase::try_from(b'n'),
+            Err(TryFromU8Error::Invalid(b'n'))
+        );
+    }
+
+    #[test]
+    fn test_from_unmodified_base_for_u8() {
+        assert_eq!(u8::from(UnmodifiedBase::A), b'A');
+        assert_eq!(u8::from(UnmodifiedBase::C), b'C');
+        assert_eq!(u8::from(UnmodifiedBase::G), b'G');
+        assert_eq!(u8::from(UnmodifiedBase::T), b'T');
+        assert_eq!(u8::from(UnmodifiedBase::U), b'U');
+        assert_eq!(u8::from(UnmodifiedBase::N), b'N');
+    }
+}
@@ -0,0 +1,169 @@
+//! SAM record data field value supplementary alignments.
+
+pub mod supplementary_alignment;
+
+pub use self::supplementary_alignment::SupplementaryAlignment;
+
+use std::{error, fmt, ops::Deref, str::FromStr};
+
+const TERMINATOR: char = ';';
+
+/// SAM record data field value supplementary alignments.
+///
+/// This is the parsed form of the `SA` (other canonical alignments in a chimeric alignment) data
+/// field value.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SupplementaryAlignments(Vec<SupplementaryAlignment>);
+
+impl AsRef<[SupplementaryAlignment]> for SupplementaryAlignments {
+    fn as_ref(&self) -> &[SupplementaryAlignment] {
+        &self.0
+    }
+}
+
+impl Deref for SupplementaryAlignments {
+    type Target = [SupplementaryAlignment];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<Vec<SupplementaryAlignment>> for SupplementaryAlignments {
+    fn from(supplementary_alignments: Vec<SupplementaryAlignment>) -> Self {
+        Self(supplementary_alignments)
+    }
+}
+
+impl From<SupplementaryAlignments> for Vec<SupplementaryAlignment> {
+    fn from(supplementary_alignments: SupplementaryAlignments) -> Self {
+        supplementary_alignments.0
+    }
+}
+
+impl fmt::Display for SupplementaryAlignments {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for supplementary_alignment in self.iter() {
+            write!(f, "{supplementary_alignment}{TERMINATOR}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// An error returned when raw supplementary alignments fail to parse.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The input is empty.
+    Empty,
+    /// The input is invalid.
+    Invalid,
+    /// A supplementary alignment is invalid.
+    InvalidSupplementaryAlignment(supplementary_alignment::ParseError),
+}
+
+impl error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::InvalidSupplementaryAlignment(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("empty input"),
+            Self::Invalid => f.write_str("invalid input"),
+            Self::InvalidSupplementaryAlignment(_) => {
+                f.write_str("invalid supplementary alignment")
+            }
+        }
+    }
+}
+
+impl FromStr for SupplementaryAlignments {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(ParseError::Empty);
+        }
+
+        let s = s.strip_suffix(TERMINATOR).ok_or(ParseError::Invalid)?;
+
+        s.split(TERMINATOR)
+            .map(|t| t.parse().map_err(ParseError::InvalidSupplementaryAlignment))
+            .collect::<Result<_, _>>()
+            .map(Self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_core::Position;
+
+    use super::{supplementary_alignment::Strand, *};
+    use crate::record::MappingQuality;
+
+    #[test]
+    fn test_fmt() -> Result<(), Box<dyn error::Error>> {
+        let supplementary_alignments = SupplementaryAlignments::from(vec![
+            SupplementaryAlignment::new(
+                "sq0".parse()?,
+                Position::try_from(8)?,
+                Strand::Forward,
+                "13M".parse()?,
+                MappingQuality::new(60),
+                0,
+            ),
+            SupplementaryAlignment::new(
+                "sq1".parse()?,
+                Position::try_from(13)?,
+                Strand::Reverse,
+                "5S8M".parse()?,
+                MappingQuality::new(21),
+                1,
+            ),
+        ]);
+
+        assert_eq!(
+            supplementary_alignments.to_string(),
+            "sq0,8,+,13M,60,0;sq1,13,-,5S8M,21,1;"
+        );
+
+        assert!(SupplementaryAlignments::default().to_string().is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_str() -> Result<(), Box<dyn error::Error>> {
+        let supplementary_alignments: SupplementaryAlignments =
+            "sq0,8,+,13M,60,0;sq1,13,-,5S8M,21,1;".parse()?;
+
+        assert_eq!(supplementary_alignments.len(), 2);
+        assert_eq!(
+            supplementary_alignments[1]
+                .reference_sequence_name()
+                .as_str(),
+            "sq1"
+        );
+
+        assert_eq!(
+            "".parse::<SupplementaryAlignments>(),
+            Err(ParseError::Empty)
+        );
+        assert_eq!(
+            "sq0,8,+,13M,60,0".parse::<SupplementaryAlignments>(),
+            Err(ParseError::Invalid)
+        );
+        assert!(matches!(
+            "sq0,8,+,13M,60;".parse::<SupplementaryAlignments>(),
+            Err(ParseError::InvalidSupplementaryAlignment(_))
+        ));
+
+        Ok(())
+    }
+}
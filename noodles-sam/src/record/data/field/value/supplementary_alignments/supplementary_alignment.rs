@@ -0,0 +1,307 @@
+//! SAM record data field value supplementary alignment.
+
+use std::{error, fmt, num, str::FromStr};
+
+use noodles_core::{position, Position};
+
+use crate::record::{
+    cigar, mapping_quality, reference_sequence_name, Cigar, MappingQuality, ReferenceSequenceName,
+};
+
+const DELIMITER: char = ',';
+
+/// A supplementary alignment strand.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Strand {
+    /// Forward strand (`+`).
+    Forward,
+    /// Reverse strand (`-`).
+    Reverse,
+}
+
+/// A SAM record data field value supplementary alignment.
+///
+/// This is a single entry in the `SA` (other canonical alignments in a chimeric alignment) data
+/// field value.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SupplementaryAlignment {
+    reference_sequence_name: ReferenceSequenceName,
+    position: Position,
+    strand: Strand,
+    cigar: Cigar,
+    mapping_quality: Option<MappingQuality>,
+    edit_distance: u32,
+}
+
+impl SupplementaryAlignment {
+    /// Creates a supplementary alignment.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_sam::record::{
+    ///     data::field::value::supplementary_alignments::{
+    ///         supplementary_alignment::Strand, SupplementaryAlignment,
+    ///     },
+    ///     MappingQuality,
+    /// };
+    ///
+    /// let supplementary_alignment = SupplementaryAlignment::new(
+    ///     "sq0".parse()?,
+    ///     Position::try_from(8)?,
+    ///     Strand::Forward,
+    ///     "13M".parse()?,
+    ///     MappingQuality::new(60),
+    ///     0,
+    /// );
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn new(
+        reference_sequence_name: ReferenceSequenceName,
+        position: Position,
+        strand: Strand,
+        cigar: Cigar,
+        mapping_quality: Option<MappingQuality>,
+        edit_distance: u32,
+    ) -> Self {
+        Self {
+            reference_sequence_name,
+            position,
+            strand,
+            cigar,
+            mapping_quality,
+            edit_distance,
+        }
+    }
+
+    /// Returns the reference sequence name.
+    pub fn reference_sequence_name(&self) -> &ReferenceSequenceName {
+        &self.reference_sequence_name
+    }
+
+    /// Returns the start position.
+    pub fn position(&self) -> Position {
+        self.position
+    }
+
+    /// Returns the strand.
+    pub fn strand(&self) -> Strand {
+        self.strand
+    }
+
+    /// Returns the CIGAR.
+    pub fn cigar(&self) -> &Cigar {
+        &self.cigar
+    }
+
+    /// Returns the mapping quality.
+    pub fn mapping_quality(&self) -> Option<MappingQuality> {
+        self.mapping_quality
+    }
+
+    /// Returns the edit distance (`NM`).
+    pub fn edit_distance(&self) -> u32 {
+        self.edit_distance
+    }
+}
+
+impl fmt::Display for SupplementaryAlignment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let strand = match self.strand {
+            Strand::Forward => '+',
+            Strand::Reverse => '-',
+        };
+
+        let mapping_quality = self
+            .mapping_quality
+            .map(|mapq| mapq.get())
+            .unwrap_or(mapping_quality::MISSING);
+
+        write!(
+            f,
+            "{}{DELIMITER}{}{DELIMITER}{strand}{DELIMITER}{}{DELIMITER}{mapping_quality}{DELIMITER}{}",
+            self.reference_sequence_name, self.position, self.cigar, self.edit_distance
+        )
+    }
+}
+
+/// An error returned when a raw supplementary alignment fails to parse.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The input is invalid.
+    Invalid,
+    /// The reference sequence name is invalid.
+    InvalidReferenceSequenceName(reference_sequence_name::ParseError),
+    /// The position is invalid.
+    InvalidPosition(position::ParseError),
+    /// The strand is invalid.
+    InvalidStrand,
+    /// The CIGAR is invalid.
+    InvalidCigar(cigar::ParseError),
+    /// The mapping quality is invalid.
+    InvalidMappingQuality(num::ParseIntError),
+    /// The edit distance is invalid.
+    InvalidEditDistance(num::ParseIntError),
+}
+
+impl error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::InvalidReferenceSequenceName(e) => Some(e),
+            Self::InvalidPosition(e) => Some(e),
+            Self::InvalidCigar(e) => Some(e),
+            Self::InvalidMappingQuality(e) => Some(e),
+            Self::InvalidEditDistance(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Invalid => f.write_str("invalid input"),
+            Self::InvalidReferenceSequenceName(_) => f.write_str("invalid reference sequence name"),
+            Self::InvalidPosition(_) => f.write_str("invalid position"),
+            Self::InvalidStrand => f.write_str("invalid strand"),
+            Self::InvalidCigar(_) => f.write_str("invalid CIGAR"),
+            Self::InvalidMappingQuality(_) => f.write_str("invalid mapping quality"),
+            Self::InvalidEditDistance(_) => f.write_str("invalid edit distance"),
+        }
+    }
+}
+
+impl FromStr for SupplementaryAlignment {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut fields = s.split(DELIMITER);
+
+        let reference_sequence_name = fields
+            .next()
+            .ok_or(ParseError::Invalid)
+            .and_then(|t| t.parse().map_err(ParseError::InvalidReferenceSequenceName))?;
+
+        let position = fields
+            .next()
+            .ok_or(ParseError::Invalid)
+            .and_then(|t| t.parse().map_err(ParseError::InvalidPosition))?;
+
+        let strand = match fields.next() {
+            Some("+") => Strand::Forward,
+            Some("-") => Strand::Reverse,
+            Some(_) => return Err(ParseError::InvalidStrand),
+            None => return Err(ParseError::Invalid),
+        };
+
+        let cigar = fields
+            .next()
+            .ok_or(ParseError::Invalid)
+            .and_then(|t| t.parse().map_err(ParseError::InvalidCigar))?;
+
+        let mapping_quality = fields
+            .next()
+            .ok_or(ParseError::Invalid)
+            .and_then(|t| t.parse().map_err(ParseError::InvalidMappingQuality))
+            .map(MappingQuality::new)?;
+
+        let edit_distance = fields
+            .next()
+            .ok_or(ParseError::Invalid)
+            .and_then(|t| t.parse().map_err(ParseError::InvalidEditDistance))?;
+
+        if fields.next().is_some() {
+            return Err(ParseError::Invalid);
+        }
+
+        Ok(Self::new(
+            reference_sequence_name,
+            position,
+            strand,
+            cigar,
+            mapping_quality,
+            edit_distance,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_supplementary_alignment() -> Result<SupplementaryAlignment, Box<dyn error::Error>> {
+        Ok(SupplementaryAlignment::new(
+            "sq0".parse()?,
+            Position::try_from(8)?,
+            Strand::Reverse,
+            "5S8M".parse()?,
+            MappingQuality::new(60),
+            1,
+        ))
+    }
+
+    #[test]
+    fn test_fmt() -> Result<(), Box<dyn error::Error>> {
+        let supplementary_alignment = build_supplementary_alignment()?;
+        assert_eq!(supplementary_alignment.to_string(), "sq0,8,-,5S8M,60,1");
+
+        let supplementary_alignment = SupplementaryAlignment::new(
+            "sq0".parse()?,
+            Position::try_from(8)?,
+            Strand::Forward,
+            "13M".parse()?,
+            None,
+            0,
+        );
+        assert_eq!(supplementary_alignment.to_string(), "sq0,8,+,13M,255,0");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_str() -> Result<(), Box<dyn error::Error>> {
+        assert_eq!(
+            "sq0,8,-,5S8M,60,1".parse::<SupplementaryAlignment>(),
+            Ok(build_supplementary_alignment()?)
+        );
+
+        let supplementary_alignment: SupplementaryAlignment = "sq0,8,+,13M,255,0".parse()?;
+        assert!(supplementary_alignment.mapping_quality().is_none());
+
+        assert_eq!(
+            "sq0,8,-,5S8M,60".parse::<SupplementaryAlignment>(),
+            Err(ParseError::Invalid)
+        );
+        assert_eq!(
+            "sq0,8,-,5S8M,60,1,0".parse::<SupplementaryAlignment>(),
+            Err(ParseError::Invalid)
+        );
+        assert!(matches!(
+            ",8,-,5S8M,60,1".parse::<SupplementaryAlignment>(),
+            Err(ParseError::InvalidReferenceSequenceName(_))
+        ));
+        assert!(matches!(
+            "sq0,0,-,5S8M,60,1".parse::<SupplementaryAlignment>(),
+            Err(ParseError::InvalidPosition(_))
+        ));
+        assert_eq!(
+            "sq0,8,.,5S8M,60,1".parse::<SupplementaryAlignment>(),
+            Err(ParseError::InvalidStrand)
+        );
+        assert!(matches!(
+            "sq0,8,-,*,60,1".parse::<SupplementaryAlignment>(),
+            Err(ParseError::InvalidCigar(_))
+        ));
+        assert!(matches!(
+            "sq0,8,-,5S8M,256,1".parse::<SupplementaryAlignment>(),
+            Err(ParseError::InvalidMappingQuality(_))
+        ));
+        assert!(matches!(
+            "sq0,8,-,5S8M,60,-1".parse::<SupplementaryAlignment>(),
+            Err(ParseError::InvalidEditDistance(_))
+        ));
+
+        Ok(())
+    }
+}
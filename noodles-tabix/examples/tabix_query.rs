@@ -0,0 +1,33 @@
+//! Queries a bgzipped, tab-delimited file with a tabix index.
+//!
+//! The index is read from `<src>.tbi`.
+//!
+//! The result matches the output of `tabix <src> <region>`.
+
+use std::{
+    env,
+    fs::File,
+    io::{self, Write},
+};
+
+use noodles_tabix as tabix;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let mut args = env::args().skip(1);
+
+    let src = args.next().expect("missing src");
+    let region = args.next().expect("missing region").parse()?;
+
+    let index = tabix::read(format!("{src}.tbi"))?;
+    let mut reader = File::open(src).map(|f| tabix::IndexedReader::new(f, index))?;
+
+    let stdout = io::stdout().lock();
+    let mut writer = io::BufWriter::new(stdout);
+
+    for result in reader.query(&region)? {
+        let line = result?;
+        writeln!(writer, "{line}")?;
+    }
+
+    Ok(())
+}
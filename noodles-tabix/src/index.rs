@@ -68,6 +68,38 @@ impl Index {
 impl BinningIndex for Index {
     type ReferenceSequence = ReferenceSequence;
 
+    /// Returns the number of bits for the minimum interval.
+    ///
+    /// This is always 14.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_tabix as tabix;
+    /// use noodles_csi::BinningIndex;
+    /// let index = tabix::Index::default();
+    /// assert_eq!(index.min_shift(), 14);
+    /// ```
+    fn min_shift(&self) -> u8 {
+        MIN_SHIFT
+    }
+
+    /// Returns the depth of the binning index.
+    ///
+    /// This is always 5.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_tabix as tabix;
+    /// use noodles_csi::BinningIndex;
+    /// let index = tabix::Index::default();
+    /// assert_eq!(index.depth(), 5);
+    /// ```
+    fn depth(&self) -> u8 {
+        DEPTH
+    }
+
     /// Returns a list of indexed reference sequences.
     ///
     /// # Examples
@@ -96,6 +128,21 @@ impl BinningIndex for Index {
         self.unplaced_unmapped_record_count
     }
 
+    /// Returns the reference sequence names stored in the index header.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_csi::BinningIndex;
+    /// use noodles_tabix as tabix;
+    /// let index = tabix::Index::default();
+    /// assert_eq!(index.reference_sequence_names(), Some(Vec::new()));
+    /// ```
+    fn reference_sequence_names(&self) -> Option<Vec<&str>> {
+        let names = self.header().reference_sequence_names();
+        Some(names.iter().map(|name| name.as_str()).collect())
+    }
+
     fn query<I>(&self, reference_sequence_id: usize, interval: I) -> io::Result<Vec<Chunk>>
     where
         I: Into<Interval>,
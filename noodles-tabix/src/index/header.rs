@@ -2,10 +2,14 @@
 
 mod builder;
 pub mod format;
+mod record;
 
 pub use self::{builder::Builder, format::Format};
 
+use std::io;
+
 use indexmap::IndexSet;
+use noodles_core::Position;
 
 /// An ordered set of reference sequence names.
 pub type ReferenceSequenceNames = IndexSet<String>;
@@ -162,6 +166,56 @@ impl Header {
     pub fn reference_sequence_names(&self) -> &ReferenceSequenceNames {
         &self.reference_sequence_names
     }
+
+    /// Returns whether the given line is a header line.
+    ///
+    /// A header line is either one of the first lines to skip or starts with the line comment
+    /// prefix. `line_number` is 0-based.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_tabix as tabix;
+    ///
+    /// let header = tabix::index::header::Builder::bed()
+    ///     .set_line_skip_count(1)
+    ///     .build();
+    ///
+    /// assert!(header.is_header_line(0, "chrom\tstart\tend"));
+    /// assert!(header.is_header_line(1, "#comment"));
+    /// assert!(!header.is_header_line(1, "sq0\t8\t13"));
+    /// ```
+    pub fn is_header_line(&self, line_number: usize, line: &str) -> bool {
+        let is_skipped_line =
+            u32::try_from(line_number).map_or(false, |n| n < self.line_skip_count);
+
+        is_skipped_line || line.as_bytes().first() == Some(&self.line_comment_prefix)
+    }
+
+    /// Parses the reference sequence name and interval of a data line.
+    ///
+    /// The columns are read using the configured indices. The returned interval is 1-based and
+    /// inclusive, regardless of the coordinate system of the format. For SAM, the end position is
+    /// calculated from the CIGAR; and for VCF, it is the `END` info field, if present, or the end
+    /// of the reference bases.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_tabix as tabix;
+    ///
+    /// let header = tabix::index::header::Builder::bed().build();
+    /// let (name, start, end) = header.parse_record("sq0\t7\t13")?;
+    ///
+    /// assert_eq!(name, "sq0");
+    /// assert_eq!(start, Position::try_from(8)?);
+    /// assert_eq!(end, Position::try_from(13)?);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn parse_record<'l>(&self, line: &'l str) -> io::Result<(&'l str, Position, Position)> {
+        record::parse_record(self, line)
+    }
 }
 
 impl Default for Header {
@@ -169,3 +223,39 @@ impl Default for Header {
         Builder::default().build()
     }
 }
+
+impl TryFrom<&[u8]> for Header {
+    type Error = io::Error;
+
+    /// Decodes a tabix index header from raw data.
+    ///
+    /// This is the layout of the header in a tabix index and of the auxiliary data of a
+    /// coordinate-sorted index (CSI) of a tab-delimited file.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_tabix as tabix;
+    ///
+    /// let header = tabix::index::header::Builder::vcf().build();
+    /// let aux = Vec::try_from(&header)?;
+    ///
+    /// assert_eq!(tabix::index::Header::try_from(&aux[..])?, header);
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    fn try_from(mut buf: &[u8]) -> Result<Self, Self::Error> {
+        crate::reader::read_header(&mut buf)
+    }
+}
+
+impl TryFrom<&Header> for Vec<u8> {
+    type Error = io::Error;
+
+    /// Encodes a tabix index header, e.g., as the auxiliary data of a coordinate-sorted index
+    /// (CSI).
+    fn try_from(header: &Header) -> Result<Self, Self::Error> {
+        let mut buf = Vec::new();
+        crate::writer::write_header(&mut buf, header)?;
+        Ok(buf)
+    }
+}
@@ -0,0 +1,208 @@
+use std::{cmp, io};
+
+use noodles_core::Position;
+
+use super::{format::CoordinateSystem, Format, Header};
+
+const FIELD_DELIMITER: char = '\t';
+
+pub(super) fn parse_record<'l>(
+    header: &Header,
+    line: &'l str,
+) -> io::Result<(&'l str, Position, Position)> {
+    let fields: Vec<_> = line.split(FIELD_DELIMITER).collect();
+
+    let reference_sequence_name = get_field(&fields, header.reference_sequence_name_index())?;
+    let raw_start = get_field(&fields, header.start_position_index())?;
+
+    let (start, end) = match header.format() {
+        Format::Generic(coordinate_system) => {
+            let start = parse_start(raw_start, coordinate_system)?;
+
+            let end = match header.end_position_index() {
+                Some(i) => get_field(&fields, i).and_then(parse_position)?,
+                None => start,
+            };
+
+            // A BED record with an empty interval, e.g., an insertion, has an end before its
+            // 1-based start.
+            (start, cmp::max(start, end))
+        }
+        Format::Sam => parse_sam_interval(&fields, raw_start)?,
+        Format::Vcf => parse_vcf_interval(&fields, raw_start)?,
+    };
+
+    Ok((reference_sequence_name, start, end))
+}
+
+// `i` is 1-based.
+fn get_field<'l>(fields: &[&'l str], i: usize) -> io::Result<&'l str> {
+    i.checked_sub(1)
+        .and_then(|j| fields.get(j))
+        .copied()
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("missing field at column {i}"),
+            )
+        })
+}
+
+fn parse_position(s: &str) -> io::Result<Position> {
+    s.parse()
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+fn parse_start(s: &str, coordinate_system: CoordinateSystem) -> io::Result<Position> {
+    match coordinate_system {
+        CoordinateSystem::Gff => parse_position(s),
+        CoordinateSystem::Bed => s
+            .parse::<usize>()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+            .and_then(|n| {
+                n.checked_add(1)
+                    .and_then(Position::new)
+                    .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "invalid start"))
+            }),
+    }
+}
+
+fn parse_sam_interval(fields: &[&str], raw_start: &str) -> io::Result<(Position, Position)> {
+    const CIGAR_INDEX: usize = 6;
+    const MISSING: &str = "*";
+
+    let start = parse_position(raw_start)?;
+
+    let cigar = get_field(fields, CIGAR_INDEX)?;
+
+    if cigar == MISSING {
+        return Ok((start, start));
+    }
+
+    let mut reference_len: usize = 0;
+    let mut n: usize = 0;
+
+    for b in cigar.bytes() {
+        match b {
+            b'0'..=b'9' => {
+                n = n
+                    .checked_mul(10)
+                    .and_then(|m| m.checked_add(usize::from(b - b'0')))
+                    .ok_or_else(|| invalid_cigar(cigar))?;
+            }
+            b'M' | b'D' | b'N' | b'=' | b'X' => {
+                reference_len = reference_len
+                    .checked_add(n)
+                    .ok_or_else(|| invalid_cigar(cigar))?;
+                n = 0;
+            }
+            b'I' | b'S' | b'H' | b'P' => n = 0,
+            _ => return Err(invalid_cigar(cigar)),
+        }
+    }
+
+    let end = resolve_end(start, reference_len)?;
+
+    Ok((start, end))
+}
+
+fn invalid_cigar(cigar: &str) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("invalid CIGAR: {cigar}"),
+    )
+}
+
+fn parse_vcf_interval(fields: &[&str], raw_start: &str) -> io::Result<(Position, Position)> {
+    const REFERENCE_BASES_INDEX: usize = 4;
+    const INFO_INDEX: usize = 8;
+    const END_KEY: &str = "END";
+
+    let start = parse_position(raw_start)?;
+
+    let raw_end = fields.get(INFO_INDEX - 1).and_then(|info| {
+        info.split(';').find_map(|field| {
+            field
+                .split_once('=')
+                .filter(|(key, _)| *key == END_KEY)
+                .map(|(_, value)| value)
+        })
+    });
+
+    let end = if let Some(s) = raw_end {
+        parse_position(s)?
+    } else {
+        let reference_bases = get_field(fields, REFERENCE_BASES_INDEX)?;
+        resolve_end(start, reference_bases.len())?
+    };
+
+    Ok((start, cmp::max(start, end)))
+}
+
+fn resolve_end(start: Position, len: usize) -> io::Result<Position> {
+    if len == 0 {
+        return Ok(start);
+    }
+
+    usize::from(start)
+        .checked_add(len - 1)
+        .and_then(Position::new)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "invalid end"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::index::header::Builder;
+
+    #[test]
+    fn test_parse_record() -> Result<(), Box<dyn std::error::Error>> {
+        let header = Builder::bed().build();
+        assert_eq!(
+            parse_record(&header, "sq0\t7\t13")?,
+            ("sq0", Position::try_from(8)?, Position::try_from(13)?)
+        );
+        assert_eq!(
+            parse_record(&header, "sq0\t7\t7")?,
+            ("sq0", Position::try_from(8)?, Position::try_from(8)?)
+        );
+
+        let header = Builder::gff().build();
+        assert_eq!(
+            parse_record(&header, "sq0\tNDLS\tgene\t8\t13\t.\t+\t.\tID=0")?,
+            ("sq0", Position::try_from(8)?, Position::try_from(13)?)
+        );
+
+        let header = Builder::vcf().build();
+        assert_eq!(
+            parse_record(&header, "sq0\t8\t.\tACGT\tA\t.\tPASS\t.")?,
+            ("sq0", Position::try_from(8)?, Position::try_from(11)?)
+        );
+        assert_eq!(
+            parse_record(&header, "sq0\t8\t.\tA\t<DEL>\t.\tPASS\tSVTYPE=DEL;END=21")?,
+            ("sq0", Position::try_from(8)?, Position::try_from(21)?)
+        );
+
+        let header = Builder::sam().build();
+        assert_eq!(
+            parse_record(
+                &header,
+                "r0\t0\tsq0\t8\t255\t2S4M1I2D3N\t*\t0\t0\tACGTACG\t*"
+            )?,
+            ("sq0", Position::try_from(8)?, Position::try_from(16)?)
+        );
+
+        assert!(matches!(
+            parse_record(&header, "r0\t0\tsq0\t8\t255\t99999999999999999999999M\t*\t0\t0\tA\t*"),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        let header = Builder::bed().build();
+        assert!(matches!(
+            parse_record(&header, "sq0\t7"),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+}
@@ -1,3 +1,5 @@
+use std::io;
+
 use noodles_core::Position;
 use noodles_csi::index::reference_sequence::bin::Chunk;
 
@@ -8,6 +10,7 @@ use super::{header::ReferenceSequenceNames, reference_sequence, Header, Index, R
 pub struct Indexer {
     header: Header,
     current_reference_sequence_name: String,
+    current_start: Option<Position>,
     reference_sequence_names: ReferenceSequenceNames,
     reference_sequence_builders: Vec<reference_sequence::Builder>,
 }
@@ -27,6 +30,16 @@ impl Indexer {
 
     /// Adds a record.
     ///
+    /// Records must be added in coordinate-sorted order, i.e., grouped by reference sequence and
+    /// sorted by start position. The chunk is the range of virtual positions of the record in the
+    /// associated BGZF stream.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the record is out of order, i.e., if its reference sequence was
+    /// already completed or if its start position is before the start position of the previous
+    /// record.
+    ///
     /// # Examples
     ///
     /// ```
@@ -43,8 +56,8 @@ impl Indexer {
     /// indexer.add_record("sq0", start, end, Chunk::new(
     ///     bgzf::VirtualPosition::from(144),
     ///     bgzf::VirtualPosition::from(233),
-    /// ));
-    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ))?;
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
     /// ```
     pub fn add_record(
         &mut self,
@@ -52,23 +65,51 @@ impl Indexer {
         start: Position,
         end: Position,
         chunk: Chunk,
-    ) {
-        if reference_sequence_name != self.current_reference_sequence_name {
+    ) -> io::Result<()> {
+        if reference_sequence_name != self.current_reference_sequence_name
+            || self.reference_sequence_builders.is_empty()
+        {
+            if self
+                .reference_sequence_names
+                .contains(reference_sequence_name)
+            {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("unsorted reference sequence: {reference_sequence_name}"),
+                ));
+            }
+
             self.reference_sequence_builders
                 .push(ReferenceSequence::builder());
 
             self.current_reference_sequence_name = reference_sequence_name.into();
+            self.current_start = None;
 
             self.reference_sequence_names
                 .insert(reference_sequence_name.into());
         }
 
+        if let Some(current_start) = self.current_start {
+            if start < current_start {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!(
+                        "unsorted record: {reference_sequence_name}:{start} is before {reference_sequence_name}:{current_start}"
+                    ),
+                ));
+            }
+        }
+
+        self.current_start = Some(start);
+
         let reference_sequence_builder = self
             .reference_sequence_builders
             .last_mut()
             .expect("reference_sequence_builders cannot be empty");
 
         reference_sequence_builder.add_record(start, end, chunk);
+
+        Ok(())
     }
 
     /// Builds a tabix index.
@@ -94,3 +135,99 @@ impl Indexer {
             .build()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use noodles_bgzf as bgzf;
+    use noodles_csi::BinningIndex;
+
+    use super::*;
+
+    fn build_chunk(start: u64, end: u64) -> Chunk {
+        Chunk::new(
+            bgzf::VirtualPosition::from(start),
+            bgzf::VirtualPosition::from(end),
+        )
+    }
+
+    #[test]
+    fn test_add_record() -> Result<(), Box<dyn std::error::Error>> {
+        let mut indexer = Indexer::default();
+
+        indexer.add_record(
+            "sq0",
+            Position::try_from(8)?,
+            Position::try_from(13)?,
+            build_chunk(0, 9),
+        )?;
+
+        indexer.add_record(
+            "sq0",
+            Position::try_from(21)?,
+            Position::try_from(34)?,
+            build_chunk(9, 18),
+        )?;
+
+        indexer.add_record(
+            "sq1",
+            Position::try_from(5)?,
+            Position::try_from(8)?,
+            build_chunk(18, 27),
+        )?;
+
+        let index = indexer.build();
+
+        let names: Vec<_> = index
+            .header()
+            .reference_sequence_names()
+            .iter()
+            .map(|name| name.as_str())
+            .collect();
+        assert_eq!(names, ["sq0", "sq1"]);
+
+        assert_eq!(index.reference_sequences().len(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_record_with_unsorted_records() -> Result<(), Box<dyn std::error::Error>> {
+        let mut indexer = Indexer::default();
+
+        indexer.add_record(
+            "sq0",
+            Position::try_from(13)?,
+            Position::try_from(21)?,
+            build_chunk(0, 9),
+        )?;
+
+        assert!(matches!(
+            indexer.add_record(
+                "sq0",
+                Position::try_from(8)?,
+                Position::try_from(21)?,
+                build_chunk(9, 18),
+            ),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        indexer.add_record(
+            "sq1",
+            Position::try_from(5)?,
+            Position::try_from(8)?,
+            build_chunk(18, 27),
+        )?;
+
+        assert!(matches!(
+            indexer.add_record(
+                "sq0",
+                Position::try_from(34)?,
+                Position::try_from(55)?,
+                build_chunk(27, 36),
+            ),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+}
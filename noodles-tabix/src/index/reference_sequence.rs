@@ -12,7 +12,10 @@ use std::io;
 use bit_vec::BitVec;
 use noodles_bgzf as bgzf;
 use noodles_core::{region::Interval, Position};
-use noodles_csi::{binning_index::ReferenceSequenceExt, index::reference_sequence::Metadata};
+use noodles_csi::{
+    binning_index::ReferenceSequenceExt,
+    index::reference_sequence::{bin::Chunk, Metadata},
+};
 
 use super::{resolve_interval, MIN_SHIFT};
 
@@ -184,6 +187,18 @@ impl ReferenceSequenceExt for ReferenceSequence {
     fn first_record_in_last_linear_bin_start_position(&self) -> Option<bgzf::VirtualPosition> {
         self.intervals().last().copied()
     }
+
+    fn chunks(&self) -> Vec<Chunk> {
+        self.bins()
+            .iter()
+            .flat_map(|bin| bin.chunks())
+            .copied()
+            .collect()
+    }
+
+    fn linear_index(&self) -> Option<&[bgzf::VirtualPosition]> {
+        Some(self.intervals())
+    }
 }
 
 fn region_to_bins(start: Position, end: Position) -> BitVec {
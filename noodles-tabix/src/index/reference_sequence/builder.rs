@@ -73,9 +73,15 @@ impl Builder {
     }
 
     fn update_metadata(&mut self, chunk: Chunk) {
+        if self.mapped_record_count == 0 {
+            self.start_position = chunk.start();
+            self.end_position = chunk.end();
+        } else {
+            self.start_position = cmp::min(self.start_position, chunk.start());
+            self.end_position = cmp::max(self.end_position, chunk.end());
+        }
+
         self.mapped_record_count += 1;
-        self.start_position = cmp::min(self.start_position, chunk.start());
-        self.end_position = cmp::max(self.end_position, chunk.end());
     }
 }
 
@@ -104,6 +110,8 @@ fn region_to_bin(start: Position, end: Position) -> io::Result<u16> {
 
 #[cfg(test)]
 mod tests {
+    use noodles_csi::binning_index::ReferenceSequenceExt;
+
     use super::*;
 
     #[test]
@@ -178,6 +186,44 @@ mod tests {
 
         assert_eq!(actual.intervals(), expected.intervals());
 
+        assert_eq!(
+            actual.metadata(),
+            Some(&Metadata::new(
+                bgzf::VirtualPosition::from(0),
+                bgzf::VirtualPosition::from(3473408),
+                2,
+                0
+            ))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_with_metadata() -> Result<(), noodles_core::position::TryFromIntError> {
+        let mut builder = Builder::default();
+
+        builder.add_record(
+            Position::try_from(8)?,
+            Position::try_from(13)?,
+            Chunk::new(
+                bgzf::VirtualPosition::from(233),
+                bgzf::VirtualPosition::from(377),
+            ),
+        );
+
+        let reference_sequence = builder.build();
+
+        assert_eq!(
+            reference_sequence.metadata(),
+            Some(&Metadata::new(
+                bgzf::VirtualPosition::from(233),
+                bgzf::VirtualPosition::from(377),
+                1,
+                0
+            ))
+        );
+
         Ok(())
     }
 
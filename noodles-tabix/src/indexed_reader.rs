@@ -0,0 +1,264 @@
+//! Indexed tab-delimited file reader.
+
+mod query;
+
+pub use self::query::Query;
+
+use std::io::{self, Read, Seek};
+
+use noodles_bgzf as bgzf;
+use noodles_core::Region;
+use noodles_csi::BinningIndex;
+
+use super::Index;
+
+/// An indexed reader of a bgzipped, tab-delimited file.
+///
+/// This combines a reader of the data file with its tabix index to allow querying lines by
+/// region. Lines are read and filtered using the tabix index header, i.e., the reference sequence
+/// name and position columns, the coordinate system, and the line comment prefix, which matches
+/// the behavior of `tabix <src> <region>`.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::fs::File;
+/// use noodles_tabix as tabix;
+///
+/// let index = tabix::read("sample.bed.gz.tbi")?;
+/// let mut reader = File::open("sample.bed.gz").map(|f| tabix::IndexedReader::new(f, index))?;
+///
+/// let region = "sq0:8-13".parse()?;
+///
+/// for result in reader.query(&region)? {
+///     let line = result?;
+///     println!("{line}");
+/// }
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub struct IndexedReader<R> {
+    inner: bgzf::Reader<R>,
+    index: Index,
+}
+
+impl<R> IndexedReader<R>
+where
+    R: Read,
+{
+    /// Creates an indexed reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_tabix as tabix;
+    ///
+    /// let data = [];
+    /// let index = tabix::Index::default();
+    /// let reader = tabix::IndexedReader::new(&data[..], index);
+    /// ```
+    pub fn new(inner: R, index: Index) -> Self {
+        Self {
+            inner: bgzf::Reader::new(inner),
+            index,
+        }
+    }
+
+    /// Returns a reference to the underlying reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_tabix as tabix;
+    ///
+    /// let data = [];
+    /// let reader = tabix::IndexedReader::new(&data[..], tabix::Index::default());
+    /// let _inner = reader.get_ref();
+    /// ```
+    pub fn get_ref(&self) -> &bgzf::Reader<R> {
+        &self.inner
+    }
+
+    /// Returns a mutable reference to the underlying reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_tabix as tabix;
+    ///
+    /// let data = [];
+    /// let mut reader = tabix::IndexedReader::new(&data[..], tabix::Index::default());
+    /// let _inner = reader.get_mut();
+    /// ```
+    pub fn get_mut(&mut self) -> &mut bgzf::Reader<R> {
+        &mut self.inner
+    }
+
+    /// Returns the underlying reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_tabix as tabix;
+    ///
+    /// let data = [];
+    /// let reader = tabix::IndexedReader::new(&data[..], tabix::Index::default());
+    /// let _inner = reader.into_inner();
+    /// ```
+    pub fn into_inner(self) -> bgzf::Reader<R> {
+        self.inner
+    }
+
+    /// Returns the associated index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_tabix as tabix;
+    ///
+    /// let data = [];
+    /// let reader = tabix::IndexedReader::new(&data[..], tabix::Index::default());
+    /// let _index = reader.index();
+    /// ```
+    pub fn index(&self) -> &Index {
+        &self.index
+    }
+}
+
+impl<R> IndexedReader<R>
+where
+    R: Read + Seek,
+{
+    /// Returns an iterator over lines that intersect the given region.
+    ///
+    /// Each item is a data line without the trailing newline. Header lines, i.e., lines that
+    /// start with the line comment prefix, are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use std::fs::File;
+    /// use noodles_tabix as tabix;
+    ///
+    /// let index = tabix::read("sample.bed.gz.tbi")?;
+    /// let mut reader = File::open("sample.bed.gz").map(|f| tabix::IndexedReader::new(f, index))?;
+    ///
+    /// let region = "sq0:8-13".parse()?;
+    /// let query = reader.query(&region)?;
+    ///
+    /// for result in query {
+    ///     let line = result?;
+    ///     println!("{line}");
+    /// }
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn query(&mut self, region: &Region) -> io::Result<Query<'_, R>> {
+        let header = self.index.header();
+
+        let reference_sequence_id = header
+            .reference_sequence_names()
+            .get_index_of(region.name())
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!(
+                        "region reference sequence does not exist in reference sequences: {region:?}"
+                    ),
+                )
+            })?;
+
+        let chunks = self.index.query(reference_sequence_id, region.interval())?;
+
+        Ok(Query::new(
+            &mut self.inner,
+            header,
+            chunks,
+            region.name(),
+            region.interval(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Cursor, Write};
+
+    use super::*;
+    use crate::index::header::Builder;
+
+    fn build_reader(
+        data: &[u8],
+        header: crate::index::Header,
+    ) -> io::Result<IndexedReader<Cursor<Vec<u8>>>> {
+        let mut writer = bgzf::Writer::new(Vec::new());
+        writer.write_all(data)?;
+        let buf = writer.finish()?;
+
+        let index = {
+            let mut reader = bgzf::Reader::new(&buf[..]);
+            crate::indexer::index(&mut reader, header)?
+        };
+
+        Ok(IndexedReader::new(Cursor::new(buf), index))
+    }
+
+    fn query(
+        reader: &mut IndexedReader<Cursor<Vec<u8>>>,
+        region: &str,
+    ) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+        let region = region.parse()?;
+        let lines = reader.query(&region)?.collect::<io::Result<_>>()?;
+        Ok(lines)
+    }
+
+    #[test]
+    fn test_query_with_bed() -> Result<(), Box<dyn std::error::Error>> {
+        const DATA: &[u8] = b"\
+#chrom\tstart\tend
+sq0\t7\t13
+#comment
+sq0\t13\t21
+sq0\t21\t34
+sq1\t0\t5
+";
+
+        let mut reader = build_reader(DATA, Builder::bed().build())?;
+
+        // BED start positions are 0-based, i.e., `sq0:8-8` intersects [8, 13] but not [14, 21].
+        assert_eq!(query(&mut reader, "sq0:8-8")?, ["sq0\t7\t13"]);
+        assert_eq!(
+            query(&mut reader, "sq0:13-14")?,
+            ["sq0\t7\t13", "sq0\t13\t21"]
+        );
+        assert_eq!(query(&mut reader, "sq0:35-55")?, Vec::<String>::new());
+        assert_eq!(query(&mut reader, "sq1")?, ["sq1\t0\t5"]);
+
+        assert!(matches!(
+            reader.query(&"sq2".parse()?),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_query_with_gff() -> Result<(), Box<dyn std::error::Error>> {
+        const DATA: &[u8] = b"\
+##gff-version 3
+sq0\tnoodles\tgene\t8\t13\t.\t+\t.\tID=g0
+sq0\tnoodles\tgene\t14\t21\t.\t+\t.\tID=g1
+";
+
+        let mut reader = build_reader(DATA, Builder::gff().build())?;
+
+        assert_eq!(
+            query(&mut reader, "sq0:13-13")?,
+            ["sq0\tnoodles\tgene\t8\t13\t.\t+\t.\tID=g0"]
+        );
+        assert_eq!(
+            query(&mut reader, "sq0:14")?,
+            ["sq0\tnoodles\tgene\t14\t21\t.\t+\t.\tID=g1"]
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,131 @@
+use std::{
+    io::{self, BufRead, Read, Seek},
+    vec,
+};
+
+use noodles_bgzf as bgzf;
+use noodles_core::region::Interval;
+use noodles_csi::index::reference_sequence::bin::Chunk;
+
+use crate::index::Header;
+
+enum State {
+    Seek,
+    Read(bgzf::VirtualPosition),
+    Done,
+}
+
+/// An iterator over lines of a tab-delimited file that intersect a given region.
+///
+/// This is created by calling [`super::IndexedReader::query`].
+pub struct Query<'r, R> {
+    reader: &'r mut bgzf::Reader<R>,
+    header: &'r Header,
+
+    chunks: vec::IntoIter<Chunk>,
+
+    reference_sequence_name: String,
+    interval: Interval,
+
+    state: State,
+    line_buf: String,
+}
+
+impl<'r, R> Query<'r, R>
+where
+    R: Read + Seek,
+{
+    pub(super) fn new(
+        reader: &'r mut bgzf::Reader<R>,
+        header: &'r Header,
+        chunks: Vec<Chunk>,
+        reference_sequence_name: &str,
+        interval: Interval,
+    ) -> Self {
+        Self {
+            reader,
+            header,
+
+            chunks: chunks.into_iter(),
+
+            reference_sequence_name: reference_sequence_name.into(),
+            interval,
+
+            state: State::Seek,
+            line_buf: String::new(),
+        }
+    }
+
+    fn read_line(&mut self) -> io::Result<usize> {
+        self.line_buf.clear();
+
+        let n = self.reader.read_line(&mut self.line_buf)?;
+
+        if self.line_buf.ends_with('\n') {
+            self.line_buf.pop();
+
+            if self.line_buf.ends_with('\r') {
+                self.line_buf.pop();
+            }
+        }
+
+        Ok(n)
+    }
+
+    fn intersects(&self) -> io::Result<bool> {
+        let line = &self.line_buf;
+
+        // Skipped lines are never indexed, so only comment lines can be read from a chunk.
+        if line.as_bytes().first() == Some(&self.header.line_comment_prefix()) {
+            return Ok(false);
+        }
+
+        let (reference_sequence_name, start, end) = self.header.parse_record(line)?;
+        let interval = Interval::from(start..=end);
+
+        Ok(reference_sequence_name == self.reference_sequence_name
+            && interval.intersects(self.interval))
+    }
+}
+
+impl<'r, R> Iterator for Query<'r, R>
+where
+    R: Read + Seek,
+{
+    type Item = io::Result<String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.state {
+                State::Seek => {
+                    self.state = match self.chunks.next() {
+                        Some(chunk) => {
+                            if let Err(e) = self.reader.seek(chunk.start()) {
+                                return Some(Err(e));
+                            }
+
+                            State::Read(chunk.end())
+                        }
+                        None => State::Done,
+                    }
+                }
+                State::Read(chunk_end) => match self.read_line() {
+                    Ok(0) => self.state = State::Seek,
+                    Ok(_) => {
+                        if self.reader.virtual_position() >= chunk_end {
+                            self.state = State::Seek;
+                        }
+
+                        match self.intersects() {
+                            Ok(true) => return Some(Ok(self.line_buf.clone())),
+                            Ok(false) => {}
+                            Err(e) => return Some(Err(e)),
+                        }
+                    }
+                    Err(e) => return Some(Err(e)),
+                },
+                State::Done => return None,
+            }
+        }
+    }
+}
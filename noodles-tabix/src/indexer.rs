@@ -0,0 +1,170 @@
+use std::{
+    fs::File,
+    io::{self, BufRead, Read},
+    path::Path,
+};
+
+use noodles_bgzf as bgzf;
+use noodles_csi::index::reference_sequence::bin::Chunk;
+
+use super::{index::Header, Index};
+
+/// Indexes a bgzipped, coordinate-sorted, tab-delimited file.
+///
+/// The header describes how to read the file, i.e., its format, the columns of the reference
+/// sequence name and start and end positions, the line comment prefix, and the number of lines
+/// to skip at the start of the file. Presets for common formats are available from
+/// [`crate::index::header::Builder`].
+///
+/// Records must be grouped by reference sequence and sorted by start position. Header lines,
+/// i.e., skipped lines and lines that start with the line comment prefix, are not indexed.
+///
+/// This is the same as running `tabix` with a preset (`-p`) or custom column configuration.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::io;
+/// use noodles_tabix as tabix;
+/// let header = tabix::index::header::Builder::bed().build();
+/// let index = tabix::index_file("sample.bed.gz", header)?;
+/// # Ok::<(), io::Error>(())
+/// ```
+pub fn index_file<P>(src: P, header: Header) -> io::Result<Index>
+where
+    P: AsRef<Path>,
+{
+    let mut reader = File::open(src).map(bgzf::Reader::new)?;
+    index(&mut reader, header)
+}
+
+pub(crate) fn index<R>(reader: &mut bgzf::Reader<R>, header: Header) -> io::Result<Index>
+where
+    R: Read,
+{
+    let mut indexer = Index::indexer();
+
+    let mut line = String::new();
+    let mut line_number = 0;
+    let mut start_position = reader.virtual_position();
+
+    loop {
+        line.clear();
+
+        if reader.read_line(&mut line)? == 0 {
+            break;
+        }
+
+        let end_position = reader.virtual_position();
+
+        let is_header_line = header.is_header_line(line_number, &line);
+        line_number += 1;
+
+        if !is_header_line {
+            let (reference_sequence_name, start, end) = header.parse_record(trim_newline(&line))?;
+
+            let chunk = Chunk::new(start_position, end_position);
+            indexer.add_record(reference_sequence_name, start, end, chunk)?;
+        }
+
+        start_position = end_position;
+    }
+
+    indexer.set_header(header);
+
+    Ok(indexer.build())
+}
+
+fn trim_newline(s: &str) -> &str {
+    let s = s.strip_suffix('\n').unwrap_or(s);
+    s.strip_suffix('\r').unwrap_or(s)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use noodles_core::Position;
+    use noodles_csi::BinningIndex;
+
+    use super::*;
+    use crate::index::header::Builder;
+
+    fn compress(data: &[u8]) -> io::Result<Vec<u8>> {
+        let mut writer = bgzf::Writer::new(Vec::new());
+        writer.write_all(data)?;
+        writer.finish()
+    }
+
+    fn reference_sequence_names(index: &Index) -> Vec<&str> {
+        index
+            .header()
+            .reference_sequence_names()
+            .iter()
+            .map(|name| name.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_index_with_bed() -> Result<(), Box<dyn std::error::Error>> {
+        let data = compress(b"#comment\nsq0\t7\t13\nsq0\t21\t21\nsq1\t0\t5\n")?;
+        let mut reader = bgzf::Reader::new(&data[..]);
+
+        let index = index(&mut reader, Builder::bed().build())?;
+
+        assert_eq!(reference_sequence_names(&index), ["sq0", "sq1"]);
+        assert_eq!(index.reference_sequences().len(), 2);
+
+        let metadata = index.metadata(0).expect("missing metadata");
+        assert_eq!(metadata.mapped_record_count(), 2);
+
+        let chunks = index.query(0, Position::try_from(8)?..=Position::try_from(8)?)?;
+        assert!(!chunks.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_index_with_line_skip_count() -> Result<(), Box<dyn std::error::Error>> {
+        let data = compress(b"chrom\tstart\tend\nsq0\t8\t13\n")?;
+        let mut reader = bgzf::Reader::new(&data[..]);
+
+        let header = Builder::gff()
+            .set_start_position_index(2)
+            .set_end_position_index(Some(3))
+            .set_line_skip_count(1)
+            .build();
+
+        let idx = index(&mut reader, header)?;
+
+        assert_eq!(idx.header().line_skip_count(), 1);
+        assert_eq!(reference_sequence_names(&idx), ["sq0"]);
+
+        let data = compress(b"chrom\tstart\tend\nsq0\t8\t13\n")?;
+        let mut reader = bgzf::Reader::new(&data[..]);
+        let header = Builder::gff()
+            .set_start_position_index(2)
+            .set_end_position_index(Some(3))
+            .build();
+
+        assert!(matches!(
+            index(&mut reader, header),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_index_with_unsorted_records() -> io::Result<()> {
+        let data = compress(b"sq0\t13\t21\nsq0\t8\t13\n")?;
+        let mut reader = bgzf::Reader::new(&data[..]);
+
+        assert!(matches!(
+            index(&mut reader, Builder::bed().build()),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+}
@@ -29,10 +29,15 @@
 pub mod r#async;
 
 pub mod index;
+pub mod indexed_reader;
+mod indexer;
 mod reader;
 mod writer;
 
-pub use self::{index::Index, reader::Reader, writer::Writer};
+pub use self::{
+    index::Index, indexed_reader::IndexedReader, indexer::index_file, reader::Reader,
+    writer::Writer,
+};
 
 #[cfg(feature = "async")]
 pub use self::r#async::{Reader as AsyncReader, Writer as AsyncWriter};
@@ -114,7 +114,7 @@ where
     writer.write_all(MAGIC_NUMBER)
 }
 
-fn write_header<W>(writer: &mut W, header: &index::Header) -> io::Result<()>
+pub(crate) fn write_header<W>(writer: &mut W, header: &index::Header) -> io::Result<()>
 where
     W: Write,
 {
@@ -0,0 +1,511 @@
+//! VCF record annotation.
+//!
+//! This transfers INFO fields and IDs from an annotation source, e.g., dbSNP or gnomAD, onto
+//! matching records. The records and the annotation source are walked in lockstep, so both must
+//! be sorted by position (see [`crate::record::compare`]).
+
+mod builder;
+
+pub use self::builder::Builder;
+
+use std::io;
+
+use crate::{
+    header::{info::Key, Number},
+    record::{
+        self, alternate_bases::Allele, info, multiallelic::ArrayValue, reference_bases::Base,
+    },
+    Header, Record,
+};
+
+/// A VCF record annotator.
+///
+/// A source record matches a record when they have the same chromosome and position and either
+/// share at least one alternate allele or both have no alternate alleles and the same reference
+/// bases. Alternate alleles are compared after trimming the bases they share with their reference
+/// bases, so padded representations of the same variant, e.g., `CAT>C` and `CATG>CG`, are equal.
+///
+/// Allele-specific INFO values are reindexed to the alleles of the annotated record: values with
+/// a number of `A` or `R` are gathered per allele from the matching source records, where alleles
+/// that are not in any source record get missing values. Values with a number of `G` are only
+/// copied from a source record with the same alternate alleles. Other values are copied from the
+/// first matching source record that has them. Copied values replace existing values.
+///
+/// This is created by calling [`Builder::build`].
+///
+/// # Examples
+///
+/// ```
+/// use noodles_vcf::{
+///     self as vcf,
+///     annotate,
+///     header::{
+///         info::key,
+///         record::value::{map::Info, Map},
+///     },
+/// };
+///
+/// let header = vcf::Header::default();
+///
+/// let source_header = vcf::Header::builder()
+///     .add_info(key::ALLELE_FREQUENCIES, Map::<Info>::from(&key::ALLELE_FREQUENCIES))
+///     .build();
+///
+/// let source = [
+///     vcf::Record::try_from_str("sq0\t8\trs1\tA\tC,G\t.\tPASS\tAF=0.25,0.5", &source_header),
+/// ];
+/// let source = source.into_iter().map(|result| {
+///     result.map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))
+/// });
+///
+/// let mut annotator = annotate::Builder::default()
+///     .add_info_key(key::ALLELE_FREQUENCIES)
+///     .set_copy_ids(true)
+///     .build(&header, &source_header, source);
+///
+/// let mut header = header;
+/// annotator.update_header(&mut header);
+///
+/// let mut record = vcf::Record::try_from_str("sq0\t8\t.\tA\tG\t.\tPASS\t.", &header)?;
+/// annotator.annotate(&mut record)?;
+///
+/// assert_eq!(record.to_string(), "sq0\t8\trs1\tA\tG\t.\tPASS\tAF=0.5");
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub struct Annotator<I> {
+    header: Header,
+    source_header: Header,
+    source: I,
+    head: Option<Record>,
+    // The source records at the position of the last annotated record.
+    buffer: Vec<Record>,
+    info_keys: Vec<Key>,
+    copy_ids: bool,
+    is_initialized: bool,
+}
+
+impl<I> Annotator<I>
+where
+    I: Iterator<Item = io::Result<Record>>,
+{
+    /// Adds the definitions of the copied INFO fields to a header.
+    ///
+    /// Definitions are taken from the annotation source header. Existing definitions are kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{
+    ///     self as vcf,
+    ///     annotate,
+    ///     header::{
+    ///         info::key,
+    ///         record::value::{map::Info, Map},
+    ///     },
+    /// };
+    ///
+    /// let mut header = vcf::Header::default();
+    ///
+    /// let source_header = vcf::Header::builder()
+    ///     .add_info(key::ALLELE_FREQUENCIES, Map::<Info>::from(&key::ALLELE_FREQUENCIES))
+    ///     .build();
+    ///
+    /// let annotator = annotate::Builder::default()
+    ///     .add_info_key(key::ALLELE_FREQUENCIES)
+    ///     .build(&header, &source_header, std::iter::empty());
+    ///
+    /// annotator.update_header(&mut header);
+    /// assert!(header.infos().contains_key(&key::ALLELE_FREQUENCIES));
+    /// ```
+    pub fn update_header(&self, header: &mut Header) {
+        for key in &self.info_keys {
+            if let Some(info) = self.source_header.infos().get(key) {
+                header
+                    .infos_mut()
+                    .entry(key.clone())
+                    .or_insert_with(|| info.clone());
+            }
+        }
+    }
+
+    /// Annotates a record from the matching annotation source records.
+    ///
+    /// Records must be annotated in sorted order. Source records before the given record are
+    /// skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, annotate};
+    ///
+    /// let header = vcf::Header::default();
+    ///
+    /// let source = [vcf::Record::try_from_str("sq0\t8\trs1\tA\tC\t.\tPASS\t.", &header)];
+    /// let source = source.into_iter().map(|result| {
+    ///     result.map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))
+    /// });
+    ///
+    /// let mut annotator = annotate::Builder::default()
+    ///     .set_copy_ids(true)
+    ///     .build(&header, &header, source);
+    ///
+    /// let mut record = vcf::Record::try_from_str("sq0\t8\t.\tA\tC\t.\tPASS\t.", &header)?;
+    /// annotator.annotate(&mut record)?;
+    /// assert_eq!(record.ids().to_string(), "rs1");
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// An error of kind [`io::ErrorKind::InvalidData`] is returned if the annotation source is not
+    /// sorted.
+    pub fn annotate(&mut self, record: &mut Record) -> io::Result<()> {
+        self.fill_buffer(record)?;
+
+        let candidates: Vec<_> = self
+            .buffer
+            .iter()
+            .filter(|source_record| is_match(source_record, record))
+            .collect();
+
+        if candidates.is_empty() {
+            return Ok(());
+        }
+
+        if self.copy_ids {
+            for source_record in &candidates {
+                for id in source_record.ids().iter() {
+                    record.ids_mut().insert(id.clone());
+                }
+            }
+        }
+
+        // The (source record, source alternate allele index) of each alternate allele.
+        let allele_sources: Vec<_> = record
+            .alternate_bases()
+            .iter()
+            .map(|allele| {
+                candidates.iter().find_map(|source_record| {
+                    find_allele(source_record, record, allele).map(|j| (*source_record, j))
+                })
+            })
+            .collect();
+
+        for key in &self.info_keys {
+            let number = self
+                .source_header
+                .infos()
+                .get(key)
+                .map(|info| info.number())
+                .unwrap_or(Number::Unknown);
+
+            let value = match number {
+                Number::A => gather(allele_sources.iter().copied(), key),
+                Number::R => {
+                    let reference_source = candidates
+                        .iter()
+                        .find(|source_record| get(source_record, key).is_some())
+                        .map(|source_record| (*source_record, 0));
+
+                    let sources = allele_sources
+                        .iter()
+                        .map(|source| source.map(|(source_record, j)| (source_record, j + 1)));
+
+                    gather([reference_source].into_iter().chain(sources), key)
+                }
+                Number::G => candidates
+                    .iter()
+                    .filter(|source_record| has_same_alleles(source_record, record))
+                    .find_map(|source_record| get(source_record, key))
+                    .cloned(),
+                Number::Count(_) | Number::Unknown => candidates
+                    .iter()
+                    .find_map(|source_record| get(source_record, key))
+                    .cloned(),
+            };
+
+            if let Some(value) = value {
+                record.info_mut().insert(key.clone(), Some(value));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn fill_buffer(&mut self, record: &Record) -> io::Result<()> {
+        if !self.is_initialized {
+            self.head = self.source.next().transpose()?;
+            self.is_initialized = true;
+        }
+
+        if let Some(source_record) = self.buffer.first() {
+            if record::compare(&self.header, source_record, record).is_lt() {
+                self.buffer.clear();
+            }
+        }
+
+        while let Some(head) = &self.head {
+            let ordering = record::compare(&self.header, head, record);
+
+            if ordering.is_gt() {
+                break;
+            }
+
+            let source_record = self.take()?;
+
+            if ordering.is_eq() {
+                self.buffer.push(source_record);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn take(&mut self) -> io::Result<Record> {
+        // The caller guarantees the source has a head.
+        let record = self.head.take().expect("missing source head");
+        self.head = self.source.next().transpose()?;
+
+        if let Some(next_record) = &self.head {
+            if record::compare(&self.header, next_record, &record).is_lt() {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "unsorted annotation source records",
+                ));
+            }
+        }
+
+        Ok(record)
+    }
+}
+
+fn is_match(source_record: &Record, record: &Record) -> bool {
+    let source_alleles = source_record.alternate_bases();
+    let alleles = record.alternate_bases();
+
+    if source_alleles.is_empty() && alleles.is_empty() {
+        return source_record.reference_bases() == record.reference_bases();
+    }
+
+    alleles
+        .iter()
+        .any(|allele| find_allele(source_record, record, allele).is_some())
+}
+
+fn has_same_alleles(source_record: &Record, record: &Record) -> bool {
+    let source_alleles = source_record.alternate_bases();
+    let alleles = record.alternate_bases();
+
+    source_alleles.len() == alleles.len()
+        && source_alleles.iter().zip(alleles.iter()).all(|(a, b)| {
+            is_equivalent_allele(
+                source_record.reference_bases(),
+                a,
+                record.reference_bases(),
+                b,
+            )
+        })
+}
+
+// Returns the index of the source alternate allele that is equivalent to the given alternate
+// allele of the record.
+fn find_allele(source_record: &Record, record: &Record, allele: &Allele) -> Option<usize> {
+    source_record.alternate_bases().iter().position(|a| {
+        is_equivalent_allele(
+            source_record.reference_bases(),
+            a,
+            record.reference_bases(),
+            allele,
+        )
+    })
+}
+
+fn is_equivalent_allele(
+    a_reference_bases: &[Base],
+    a: &Allele,
+    b_reference_bases: &[Base],
+    b: &Allele,
+) -> bool {
+    match (a, b) {
+        (Allele::Bases(a_bases), Allele::Bases(b_bases)) => {
+            normalize(a_reference_bases, a_bases) == normalize(b_reference_bases, b_bases)
+        }
+        _ => a_reference_bases == b_reference_bases && a == b,
+    }
+}
+
+// Trims the shared suffix and then the shared prefix of a reference and alternate allele.
+//
+// This returns the number of bases trimmed from the start, i.e., the offset from the record
+// position, and the remaining reference and alternate bases.
+fn normalize<'a>(
+    mut reference_bases: &'a [Base],
+    mut bases: &'a [Base],
+) -> (usize, &'a [Base], &'a [Base]) {
+    while let (Some((r, rs)), Some((a, as_))) = (reference_bases.split_last(), bases.split_last()) {
+        if r != a {
+            break;
+        }
+
+        reference_bases = rs;
+        bases = as_;
+    }
+
+    let mut offset = 0;
+
+    while let (Some((r, rs)), Some((a, as_))) = (reference_bases.split_first(), bases.split_first())
+    {
+        if r != a {
+            break;
+        }
+
+        reference_bases = rs;
+        bases = as_;
+        offset += 1;
+    }
+
+    (offset, reference_bases, bases)
+}
+
+fn get<'r>(record: &'r Record, key: &Key) -> Option<&'r info::field::Value> {
+    record.info().get(key).flatten()
+}
+
+fn gather<'r, S>(sources: S, key: &Key) -> Option<info::field::Value>
+where
+    S: IntoIterator<Item = Option<(&'r Record, usize)>>,
+{
+    let sources: Vec<_> = sources
+        .into_iter()
+        .map(|source| source.and_then(|(record, i)| get(record, key).map(|value| (value, i))))
+        .collect();
+
+    info::field::Value::gather(&sources)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        fixtures::parse_records,
+        header::{
+            info::key,
+            record::value::{map::Info, Map},
+        },
+    };
+
+    fn build_source_header() -> Header {
+        Header::builder()
+            .add_info(key::ALLELE_COUNT, Map::<Info>::from(&key::ALLELE_COUNT))
+            .add_info(
+                key::TOTAL_READ_DEPTHS,
+                Map::<Info>::from(&key::TOTAL_READ_DEPTHS),
+            )
+            .add_info(key::TOTAL_DEPTH, Map::<Info>::from(&key::TOTAL_DEPTH))
+            .build()
+    }
+
+    #[test]
+    fn test_annotate() -> Result<(), Box<dyn std::error::Error>> {
+        let source_header = build_source_header();
+
+        let source = parse_records(
+            &source_header,
+            &[
+                "sq0\t1\trs1\tA\tC\t.\tPASS\tDP=1",
+                "sq0\t8\trs2\tA\tC\t.\tPASS\tAC=3;AD=10,3;DP=13",
+                "sq0\t8\trs3\tA\tG\t.\tPASS\tAC=5;AD=8,5;DP=13",
+                "sq0\t8\trs4\tAT\tA\t.\tPASS\tAC=1",
+                "sq0\t13\trs5\tG\t.\t.\tPASS\tDP=21",
+            ],
+        );
+
+        let header = Header::default();
+
+        let mut annotator = Builder::default()
+            .add_info_key(key::ALLELE_COUNT)
+            .add_info_key(key::TOTAL_READ_DEPTHS)
+            .add_info_key(key::TOTAL_DEPTH)
+            .set_copy_ids(true)
+            .build(&header, &source_header, source.into_iter());
+
+        let mut header = header;
+        annotator.update_header(&mut header);
+        assert_eq!(header.infos().len(), 3);
+
+        let mut record = Record::try_from_str("sq0\t5\t.\tA\tC\t.\tPASS\t.", &header)?;
+        annotator.annotate(&mut record)?;
+        assert_eq!(record.to_string(), "sq0\t5\t.\tA\tC\t.\tPASS\t.");
+
+        let mut record = Record::try_from_str("sq0\t8\trs0\tA\tG,T,C\t.\tPASS\tDP=2", &header)?;
+        annotator.annotate(&mut record)?;
+        assert_eq!(
+            record.to_string(),
+            "sq0\t8\trs0;rs2;rs3\tA\tG,T,C\t.\tPASS\tDP=13;AC=5,.,3;AD=10,5,.,3"
+        );
+
+        let mut record = Record::try_from_str("sq0\t8\t.\tAT\tA\t.\tPASS\t.", &header)?;
+        annotator.annotate(&mut record)?;
+        assert_eq!(record.to_string(), "sq0\t8\trs4\tAT\tA\t.\tPASS\tAC=1");
+
+        let mut record = Record::try_from_str("sq0\t13\t.\tG\t.\t.\tPASS\t.", &header)?;
+        annotator.annotate(&mut record)?;
+        assert_eq!(record.to_string(), "sq0\t13\trs5\tG\t.\t.\tPASS\tDP=21");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_annotate_with_padded_alleles() -> Result<(), Box<dyn std::error::Error>> {
+        let source_header = build_source_header();
+
+        let source = parse_records(
+            &source_header,
+            &["sq0\t8\trs1\tCAT\tC,CATAT\t.\tPASS\tAC=2,4"],
+        );
+
+        let header = Header::default();
+
+        let mut annotator = Builder::default()
+            .add_info_key(key::ALLELE_COUNT)
+            .set_copy_ids(true)
+            .build(&header, &source_header, source.into_iter());
+
+        let mut header = header;
+        annotator.update_header(&mut header);
+
+        let mut record = Record::try_from_str("sq0\t8\t.\tcatg\tcg,catatg\t.\tPASS\t.", &header)?;
+        annotator.annotate(&mut record)?;
+        assert_eq!(
+            record.to_string(),
+            "sq0\t8\trs1\tCATG\tCG,CATATG\t.\tPASS\tAC=2,4"
+        );
+
+        let mut record = Record::try_from_str("sq0\t8\t.\tCA\tC\t.\tPASS\t.", &header)?;
+        annotator.annotate(&mut record)?;
+        assert_eq!(record.to_string(), "sq0\t8\t.\tCA\tC\t.\tPASS\t.");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_annotate_with_unsorted_source() -> Result<(), Box<dyn std::error::Error>> {
+        let header = Header::default();
+
+        let source = parse_records(
+            &header,
+            &["sq0\t8\t.\tA\tC\t.\tPASS\t.", "sq0\t5\t.\tA\tC\t.\tPASS\t."],
+        );
+
+        let mut annotator = Builder::default().build(&header, &header, source.into_iter());
+
+        let mut record = Record::try_from_str("sq0\t13\t.\tA\tC\t.\tPASS\t.", &header)?;
+
+        assert!(matches!(
+            annotator.annotate(&mut record),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+}
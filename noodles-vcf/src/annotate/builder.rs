@@ -0,0 +1,77 @@
+use super::Annotator;
+use crate::{header::info::Key, Header, Record};
+
+/// A VCF record annotator builder.
+#[derive(Debug, Default)]
+pub struct Builder {
+    info_keys: Vec<Key>,
+    copy_ids: bool,
+}
+
+impl Builder {
+    /// Adds an INFO field key to copy from the annotation source.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{annotate, header::info::key};
+    /// let builder = annotate::Builder::default().add_info_key(key::ALLELE_FREQUENCIES);
+    /// ```
+    pub fn add_info_key(mut self, key: Key) -> Self {
+        if !self.info_keys.contains(&key) {
+            self.info_keys.push(key);
+        }
+
+        self
+    }
+
+    /// Sets whether to copy IDs from the annotation source.
+    ///
+    /// Copied IDs are appended to the IDs of the annotated record.
+    ///
+    /// By default, this is disabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::annotate;
+    /// let builder = annotate::Builder::default().set_copy_ids(true);
+    /// ```
+    pub fn set_copy_ids(mut self, copy_ids: bool) -> Self {
+        self.copy_ids = copy_ids;
+        self
+    }
+
+    /// Builds a VCF record annotator.
+    ///
+    /// `header` is the header of the records to annotate, and its contigs define the sort order
+    /// of both the records and the annotation source. `source_header` is the header of the
+    /// annotation source, and its INFO definitions are used to reindex allele-specific values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, annotate};
+    ///
+    /// let header = vcf::Header::default();
+    /// let source_header = vcf::Header::default();
+    /// let source = std::iter::empty();
+    ///
+    /// let annotator = annotate::Builder::default().build(&header, &source_header, source);
+    /// ```
+    pub fn build<I>(self, header: &Header, source_header: &Header, source: I) -> Annotator<I>
+    where
+        I: Iterator<Item = std::io::Result<Record>>,
+    {
+        Annotator {
+            header: header.clone(),
+            source_header: source_header.clone(),
+            source,
+            head: None,
+            buffer: Vec::new(),
+            info_keys: self.info_keys,
+            copy_ids: self.copy_ids,
+            is_initialized: false,
+        }
+    }
+}
@@ -1,6 +1,10 @@
 use tokio::io::{self, AsyncWrite, AsyncWriteExt};
 
-use crate::{Header, Record};
+use crate::{
+    header::FileFormat,
+    writer::{record::write_record, FloatFormat},
+    Header, Record,
+};
 
 const LINE_FEED: u8 = b'\n';
 
@@ -10,6 +14,7 @@ where
     W: AsyncWrite,
 {
     inner: W,
+    file_format: FileFormat,
 }
 
 impl<W> Writer<W>
@@ -25,7 +30,71 @@ where
     /// let writer = vcf::AsyncWriter::new(Vec::new());
     /// ```
     pub fn new(inner: W) -> Self {
-        Self { inner }
+        Self {
+            inner,
+            file_format: FileFormat::default(),
+        }
+    }
+
+    /// Returns a reference to the underlying writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf as vcf;
+    /// let writer = vcf::AsyncWriter::new(Vec::new());
+    /// assert!(writer.get_ref().is_empty());
+    /// ```
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Returns a mutable reference to the underlying writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf as vcf;
+    /// let mut writer = vcf::AsyncWriter::new(Vec::new());
+    /// assert!(writer.get_mut().is_empty());
+    /// ```
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Returns the underlying writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf as vcf;
+    /// let writer = vcf::AsyncWriter::new(Vec::new());
+    /// assert!(writer.into_inner().is_empty());
+    /// ```
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+
+    /// Shuts down the output stream.
+    ///
+    /// This must be called when writing to a BGZF stream (e.g., a [`noodles_bgzf::AsyncWriter`])
+    /// to flush any remaining data and write the final EOF block.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> io::Result<()> {
+    /// use noodles_vcf as vcf;
+    /// let mut writer = vcf::AsyncWriter::new(Vec::new());
+    /// writer.shutdown().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn shutdown(&mut self) -> io::Result<()> {
+        self.inner.shutdown().await
     }
 
     /// Writes a VCF header.
@@ -47,12 +116,17 @@ where
     /// # }
     /// ```
     pub async fn write_header(&mut self, header: &Header) -> io::Result<()> {
+        self.file_format = header.file_format();
+
         let raw_header = header.to_string();
         self.inner.write_all(raw_header.as_bytes()).await
     }
 
     /// Writes a VCF record.
     ///
+    /// String and character values are percent-encoded if supported by the file format of the
+    /// header written by [`Self::write_header`].
+    ///
     /// # Examples
     ///
     /// ```
@@ -72,9 +146,53 @@ where
     /// # }
     /// ```
     pub async fn write_record(&mut self, record: &Record) -> io::Result<()> {
-        let raw_record = record.to_string();
+        let mut raw_record = String::new();
+        write_record(
+            &mut raw_record,
+            record,
+            &FloatFormat::default(),
+            self.file_format,
+        )
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
         self.inner.write_all(raw_record.as_bytes()).await?;
         self.inner.write_u8(LINE_FEED).await?;
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_write_record_with_reserved_characters() -> Result<(), Box<dyn std::error::Error>>
+    {
+        let header = Header::default();
+        let record = Record::try_from_str("sq0\t1\t.\tA\t.\t.\tPASS\tNOTE=n%3Bd%2Cl", &header)?;
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_record(&record).await?;
+
+        let mut sync_writer = crate::Writer::new(Vec::new());
+        sync_writer.write_record(&record)?;
+
+        let expected = b"sq0\t1\t.\tA\t.\t.\tPASS\tNOTE=n%3Bd%2Cl\n";
+        assert_eq!(writer.get_ref(), expected);
+        assert_eq!(writer.get_ref(), sync_writer.get_ref());
+
+        let header = Header::builder()
+            .set_file_format(FileFormat::new(4, 2))
+            .build();
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_header(&header).await?;
+        writer.get_mut().clear();
+        writer.write_record(&record).await?;
+
+        // Percent-encoding was introduced in VCF 4.3.
+        assert_eq!(writer.get_ref(), b"sq0\t1\t.\tA\t.\t.\tPASS\tNOTE=n;d,l\n");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,16 @@
+//! Shared test fixtures.
+
+use std::io;
+
+use crate::{Header, Record};
+
+/// Parses VCF record lines, mapping parse errors to I/O errors.
+pub(crate) fn parse_records(header: &Header, lines: &[&str]) -> Vec<io::Result<Record>> {
+    lines
+        .iter()
+        .map(|line| {
+            Record::try_from_str(line, header)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+        })
+        .collect()
+}
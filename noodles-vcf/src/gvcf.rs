@@ -0,0 +1,146 @@
+//! gVCF reference blocks.
+//!
+//! A gVCF reference block is a record that represents a run of positions that match the reference
+//! sequence. Its only alternate allele is the non-reference allele (`<NON_REF>` or `<*>`), and the
+//! block end is given by the `END` INFO field, i.e., [`Record::end`].
+
+mod query;
+
+pub use self::query::{Mode, Query};
+
+use noodles_core::Region;
+
+use crate::{
+    record::alternate_bases::{allele::Symbol, Allele},
+    Record,
+};
+
+/// Returns whether an alternate allele is a gVCF non-reference allele (`<NON_REF>` or `<*>`).
+///
+/// # Examples
+///
+/// ```
+/// use noodles_vcf::gvcf;
+///
+/// assert!(gvcf::is_non_ref_allele(&"<NON_REF>".parse()?));
+/// assert!(gvcf::is_non_ref_allele(&"<*>".parse()?));
+/// assert!(!gvcf::is_non_ref_allele(&"<DEL>".parse()?));
+/// assert!(!gvcf::is_non_ref_allele(&"A".parse()?));
+/// # Ok::<_, noodles_vcf::record::alternate_bases::allele::ParseError>(())
+/// ```
+pub fn is_non_ref_allele(allele: &Allele) -> bool {
+    // `<NON_REF>` is parsed as an unspecified symbol.
+    matches!(allele, Allele::Symbol(Symbol::Unspecified))
+}
+
+/// Returns whether a record is a gVCF reference block.
+///
+/// A reference block has a single reference base and only non-reference alternate alleles
+/// (`<NON_REF>` or `<*>`).
+///
+/// # Examples
+///
+/// ```
+/// use noodles_vcf::{self as vcf, gvcf};
+///
+/// let header = vcf::Header::default();
+///
+/// let record = vcf::Record::try_from_str("sq0\t1\t.\tA\t<NON_REF>\t.\tPASS\tEND=8", &header)?;
+/// assert!(gvcf::is_reference_block(&record));
+///
+/// let record = vcf::Record::try_from_str("sq0\t1\t.\tA\tC,<NON_REF>\t.\tPASS\t.", &header)?;
+/// assert!(!gvcf::is_reference_block(&record));
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn is_reference_block(record: &Record) -> bool {
+    let alternate_bases = record.alternate_bases();
+
+    record.reference_bases().len() == 1
+        && !alternate_bases.is_empty()
+        && alternate_bases.iter().all(is_non_ref_allele)
+}
+
+/// Returns an iterator over records that intersect a region.
+///
+/// Reference blocks are clipped to the region and, using [`Mode::Expand`], split into single
+/// position records. The reference bases of clipped and split blocks are taken from the given
+/// reference sequence, i.e., the complete sequence of the region reference sequence. Records that
+/// are not reference blocks are passed through unchanged.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_vcf::{self as vcf, gvcf};
+///
+/// let header = vcf::Header::default();
+/// let reference_sequence = b"ACGTACGT";
+///
+/// let records = [
+///     vcf::Record::try_from_str("sq0\t1\t.\tA\t<NON_REF>\t.\tPASS\tEND=4", &header),
+///     vcf::Record::try_from_str("sq0\t5\t.\tA\tC,<NON_REF>\t.\tPASS\t.", &header),
+///     vcf::Record::try_from_str("sq0\t6\t.\tC\t<NON_REF>\t.\tPASS\tEND=8", &header),
+/// ];
+///
+/// let region = "sq0:3-6".parse()?;
+///
+/// let records: Vec<_> = gvcf::query(
+///     records.into_iter().map(|result| result.map_err(|e| {
+///         std::io::Error::new(std::io::ErrorKind::InvalidData, e)
+///     })),
+///     &region,
+///     reference_sequence,
+///     gvcf::Mode::Intersect,
+/// )
+/// .map(|result| result.map(|record| record.to_string()))
+/// .collect::<std::io::Result<_>>()?;
+///
+/// assert_eq!(records, [
+///     "sq0\t3\t.\tG\t<*>\t.\tPASS\tEND=4",
+///     "sq0\t5\t.\tA\tC,<*>\t.\tPASS\t.",
+///     "sq0\t6\t.\tC\t<*>\t.\tPASS\tEND=6",
+/// ]);
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn query<'a, I>(
+    records: I,
+    region: &'a Region,
+    reference_sequence: &'a [u8],
+    mode: Mode,
+) -> Query<'a, I::IntoIter>
+where
+    I: IntoIterator<Item = std::io::Result<Record>>,
+{
+    Query::new(records.into_iter(), region, reference_sequence, mode)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_non_ref_allele() -> Result<(), Box<dyn std::error::Error>> {
+        assert!(is_non_ref_allele(&"<NON_REF>".parse()?));
+        assert!(is_non_ref_allele(&"<*>".parse()?));
+        assert!(!is_non_ref_allele(&"<NON_REF:1>".parse()?));
+        assert!(!is_non_ref_allele(&"<DEL>".parse()?));
+        assert!(!is_non_ref_allele(&"*".parse()?));
+        assert!(!is_non_ref_allele(&"A".parse()?));
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_reference_block() -> Result<(), Box<dyn std::error::Error>> {
+        let header = crate::Header::default();
+
+        let record = Record::try_from_str("sq0\t1\t.\tA\t<*>\t.\tPASS\tEND=8", &header)?;
+        assert!(is_reference_block(&record));
+
+        let record = Record::try_from_str("sq0\t1\t.\tA\t.\t.\tPASS\t.", &header)?;
+        assert!(!is_reference_block(&record));
+
+        let record = Record::try_from_str("sq0\t1\t.\tAC\t<NON_REF>\t.\tPASS\t.", &header)?;
+        assert!(!is_reference_block(&record));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,252 @@
+use std::io;
+
+use noodles_core::Region;
+
+use super::is_reference_block;
+use crate::{
+    header::info::key,
+    record::{info::field::Value, reference_bases::Base, Chromosome, Position, ReferenceBases},
+    Record,
+};
+
+/// The mode of handling reference blocks that intersect a region.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Mode {
+    /// Clip reference blocks to the region.
+    #[default]
+    Intersect,
+    /// Clip reference blocks to the region and split them into single position records.
+    Expand,
+}
+
+/// An iterator over records that intersect a region.
+///
+/// This is created by calling [`super::query`].
+pub struct Query<'a, I> {
+    records: I,
+    reference_sequence_name: &'a str,
+    start: usize,
+    end: usize,
+    reference_sequence: &'a [u8],
+    mode: Mode,
+    expanding_block: Option<(Record, usize, usize)>,
+}
+
+impl<'a, I> Query<'a, I>
+where
+    I: Iterator<Item = io::Result<Record>>,
+{
+    pub(super) fn new(
+        records: I,
+        region: &'a Region,
+        reference_sequence: &'a [u8],
+        mode: Mode,
+    ) -> Self {
+        let interval = region.interval();
+
+        Self {
+            records,
+            reference_sequence_name: region.name(),
+            start: interval.start().map(usize::from).unwrap_or(1),
+            end: interval.end().map(usize::from).unwrap_or(usize::MAX),
+            reference_sequence,
+            mode,
+            expanding_block: None,
+        }
+    }
+
+    fn next_expanded_record(&mut self) -> Option<io::Result<Record>> {
+        let (block, position, end) = self.expanding_block.as_mut()?;
+
+        let result = clip_block(block, *position, *position, self.reference_sequence);
+
+        if *position >= *end {
+            self.expanding_block = None;
+        } else {
+            *position += 1;
+        }
+
+        Some(result)
+    }
+
+    fn process_record(&mut self, record: Record) -> io::Result<Option<Record>> {
+        if !matches!(record.chromosome(), Chromosome::Name(name) if name == self.reference_sequence_name)
+        {
+            return Ok(None);
+        }
+
+        let start = usize::from(record.position());
+        let end = record
+            .end()
+            .map(usize::from)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        if end < self.start || start > self.end {
+            return Ok(None);
+        } else if !is_reference_block(&record) {
+            return Ok(Some(record));
+        }
+
+        let clipped_start = start.max(self.start);
+        let clipped_end = end.min(self.end);
+
+        match self.mode {
+            Mode::Intersect => {
+                clip_block(&record, clipped_start, clipped_end, self.reference_sequence).map(Some)
+            }
+            Mode::Expand => {
+                self.expanding_block = Some((record, clipped_start, clipped_end));
+                Ok(None)
+            }
+        }
+    }
+}
+
+impl<'a, I> Iterator for Query<'a, I>
+where
+    I: Iterator<Item = io::Result<Record>>,
+{
+    type Item = io::Result<Record>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(result) = self.next_expanded_record() {
+                return Some(result);
+            }
+
+            let record = match self.records.next()? {
+                Ok(record) => record,
+                Err(e) => return Some(Err(e)),
+            };
+
+            match self.process_record(record) {
+                Ok(Some(record)) => return Some(Ok(record)),
+                Ok(None) => {}
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+}
+
+fn clip_block(
+    block: &Record,
+    start: usize,
+    end: usize,
+    reference_sequence: &[u8],
+) -> io::Result<Record> {
+    let mut record = block.clone();
+
+    if start != usize::from(block.position()) {
+        *record.position_mut() = Position::from(start);
+        *record.reference_bases_mut() = get_reference_bases(reference_sequence, start)?;
+    }
+
+    let end = i32::try_from(end).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+    record
+        .info_mut()
+        .insert(key::END_POSITION, Some(Value::Integer(end)));
+
+    Ok(record)
+}
+
+fn get_reference_bases(reference_sequence: &[u8], position: usize) -> io::Result<ReferenceBases> {
+    let b = position
+        .checked_sub(1)
+        .and_then(|i| reference_sequence.get(i))
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "position out of reference sequence bounds",
+            )
+        })?;
+
+    Base::try_from(char::from(b.to_ascii_uppercase()))
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+        .and_then(|base| {
+            ReferenceBases::try_from(vec![base])
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{fixtures::parse_records, Header};
+
+    #[test]
+    fn test_next_with_intersect_mode() -> Result<(), Box<dyn std::error::Error>> {
+        let header = Header::default();
+        let records = parse_records(
+            &header,
+            &[
+                "sq0\t1\t.\tA\t<NON_REF>\t.\tPASS\tEND=2",
+                "sq0\t3\t.\tG\t<NON_REF>\t.\tPASS\tEND=6",
+                "sq0\t7\t.\tG\tT,<NON_REF>\t.\tPASS\t.",
+                "sq0\t8\t.\tT\t<NON_REF>\t.\tPASS\tEND=10",
+                "sq1\t1\t.\tA\t<NON_REF>\t.\tPASS\tEND=10",
+            ],
+        );
+        let region = "sq0:4-8".parse()?;
+
+        let actual: Vec<_> = super::super::query(records, &region, b"ACGTACGTAC", Mode::Intersect)
+            .map(|result| result.map(|record| record.to_string()))
+            .collect::<io::Result<_>>()?;
+
+        assert_eq!(
+            actual,
+            [
+                "sq0\t4\t.\tT\t<*>\t.\tPASS\tEND=6",
+                "sq0\t7\t.\tG\tT,<*>\t.\tPASS\t.",
+                "sq0\t8\t.\tT\t<*>\t.\tPASS\tEND=8",
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_next_with_expand_mode() -> Result<(), Box<dyn std::error::Error>> {
+        let header = Header::default();
+        let records = parse_records(
+            &header,
+            &[
+                "sq0\t1\t.\tA\t<*>\t.\tPASS\tEND=4",
+                "sq0\t5\t.\tA\tC,<*>\t.\tPASS\t.",
+            ],
+        );
+        let region = "sq0:2-5".parse()?;
+
+        let actual: Vec<_> = super::super::query(records, &region, b"ACGTACGTAC", Mode::Expand)
+            .map(|result| result.map(|record| record.to_string()))
+            .collect::<io::Result<_>>()?;
+
+        assert_eq!(
+            actual,
+            [
+                "sq0\t2\t.\tC\t<*>\t.\tPASS\tEND=2",
+                "sq0\t3\t.\tG\t<*>\t.\tPASS\tEND=3",
+                "sq0\t4\t.\tT\t<*>\t.\tPASS\tEND=4",
+                "sq0\t5\t.\tA\tC,<*>\t.\tPASS\t.",
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_next_with_block_out_of_reference_sequence_bounds(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let header = Header::default();
+        let records = parse_records(&header, &["sq0\t1\t.\tA\t<NON_REF>\t.\tPASS\tEND=20"]);
+        let region = "sq0:15-20".parse()?;
+
+        let mut query = super::super::query(records, &region, b"ACGTACGTAC", Mode::Intersect);
+
+        assert!(matches!(
+            query.next(),
+            Some(Err(e)) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+}
@@ -1,17 +1,27 @@
 //! VCF header and fields.
 
 mod builder;
+mod contig_dictionary;
 pub mod file_format;
 mod fmt;
 pub mod format;
 pub mod info;
+pub mod merge;
 mod number;
 pub mod parser;
 pub mod record;
+pub mod verify;
 
 pub use self::{
-    builder::Builder, file_format::FileFormat, number::Number, parser::ParseError, parser::Parser,
+    builder::Builder,
+    contig_dictionary::ContigDictionaryError,
+    file_format::FileFormat,
+    merge::{merge, merge_with_namespaces},
+    number::Number,
+    parser::ParseError,
+    parser::Parser,
     record::Record,
+    verify::{verify_contig_checksums, verify_contigs},
 };
 
 use std::{hash::Hash, str::FromStr};
@@ -19,7 +29,7 @@ use std::{hash::Hash, str::FromStr};
 use indexmap::{IndexMap, IndexSet};
 
 use self::record::value::{
-    map::{contig, AlternativeAllele, Contig, Filter, Format, Info, Meta},
+    map::{contig, AlternativeAllele, Contig, Filter, Format, Info, Meta, Pedigree, Sample},
     Map,
 };
 
@@ -56,6 +66,8 @@ pub struct Header {
     assembly: Option<String>,
     contigs: Contigs,
     meta: IndexMap<String, Map<Meta>>,
+    samples: IndexMap<String, Map<Sample>>,
+    pedigrees: IndexMap<String, Map<Pedigree>>,
     pedigree_db: Option<String>,
     sample_names: SampleNames,
     other_records: OtherRecords,
@@ -113,6 +125,25 @@ impl Header {
         &mut self.file_format
     }
 
+    /// Returns the known VCF version of the file format (`fileformat`).
+    ///
+    /// This returns `None` if the file format is not a known VCF version.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, header::{file_format::Version, FileFormat}};
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .set_file_format(FileFormat::new(4, 4))
+    ///     .build();
+    ///
+    /// assert_eq!(header.version(), Some(Version::V4_4));
+    /// ```
+    pub fn version(&self) -> Option<file_format::Version> {
+        self.file_format.version()
+    }
+
     /// Returns a map of information records (`INFO`).
     ///
     /// # Examples
@@ -386,6 +417,35 @@ impl Header {
         &mut self.contigs
     }
 
+    /// Returns the dictionary of contigs.
+    ///
+    /// This is the list of contig names ordered by their positions. A contig with an `IDX` field
+    /// is at that position, and contigs without one are appended after them in header order.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if more than one contig has the same position or if a position has no
+    /// contig.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, header::record::value::{map::Contig, Map}};
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .add_contig("sq0".parse()?, Map::<Contig>::builder().set_idx(1).build()?)
+    ///     .add_contig("sq1".parse()?, Map::<Contig>::builder().set_idx(0).build()?)
+    ///     .build();
+    ///
+    /// let contig_dictionary = header.contig_dictionary()?;
+    /// let names: Vec<&str> = contig_dictionary.iter().map(|name| name.as_ref()).collect();
+    /// assert_eq!(names, ["sq1", "sq0"]);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn contig_dictionary(&self) -> Result<Vec<&contig::Name>, ContigDictionaryError> {
+        contig_dictionary::build(&self.contigs)
+    }
+
     /// Returns a map of meta records (`META`).
     ///
     /// # Examples
@@ -431,6 +491,93 @@ impl Header {
         &mut self.meta
     }
 
+    /// Returns a map of sample records (`SAMPLE`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, header::record::value::{map::Sample, Map}};
+    ///
+    /// let sample = Map::<Sample>::builder().set_description("Tumor").build()?;
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .add_sample("sample0", sample.clone())
+    ///     .build();
+    ///
+    /// let records = header.samples();
+    /// assert_eq!(records.len(), 1);
+    /// assert_eq!(records.get("sample0"), Some(&sample));
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn samples(&self) -> &IndexMap<String, Map<Sample>> {
+        &self.samples
+    }
+
+    /// Returns a mutable reference to a map of sample records (`SAMPLE`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, header::record::value::{map::Sample, Map}};
+    ///
+    /// let mut header = vcf::Header::default();
+    ///
+    /// let sample = Map::<Sample>::new();
+    /// header.samples_mut().insert(String::from("sample0"), sample.clone());
+    ///
+    /// let records = header.samples();
+    /// assert_eq!(records.len(), 1);
+    /// assert_eq!(&records[0], &sample);
+    /// ```
+    pub fn samples_mut(&mut self) -> &mut IndexMap<String, Map<Sample>> {
+        &mut self.samples
+    }
+
+    /// Returns a map of pedigree records (`PEDIGREE`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, header::record::value::{map::Pedigree, Map}};
+    ///
+    /// let pedigree = Map::<Pedigree>::builder()
+    ///     .set_father("sample1")
+    ///     .set_mother("sample2")
+    ///     .build()?;
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .add_pedigree("sample0", pedigree)
+    ///     .build();
+    ///
+    /// let pedigree = &header.pedigrees()["sample0"];
+    /// assert_eq!(pedigree.father(), Some("sample1"));
+    /// assert_eq!(pedigree.mother(), Some("sample2"));
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn pedigrees(&self) -> &IndexMap<String, Map<Pedigree>> {
+        &self.pedigrees
+    }
+
+    /// Returns a mutable reference to a map of pedigree records (`PEDIGREE`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, header::record::value::{map::Pedigree, Map}};
+    ///
+    /// let mut header = vcf::Header::default();
+    ///
+    /// let pedigree = Map::<Pedigree>::new();
+    /// header.pedigrees_mut().insert(String::from("sample0"), pedigree.clone());
+    ///
+    /// let records = header.pedigrees();
+    /// assert_eq!(records.len(), 1);
+    /// assert_eq!(&records[0], &pedigree);
+    /// ```
+    pub fn pedigrees_mut(&mut self) -> &mut IndexMap<String, Map<Pedigree>> {
+        &mut self.pedigrees
+    }
+
     /// Returns a URI to the relationships between genomes (`pedigreeDB`).
     ///
     /// # Examples
@@ -510,7 +657,7 @@ impl Header {
     /// Returns a map of records with nonstandard keys.
     ///
     /// This includes all records other than `fileformat`, `INFO`, `FILTER`, `FORMAT`, `ALT`,
-    /// `assembly`, `contig`, `META`, `SAMPLE`, and `pedigreeDB`.
+    /// `assembly`, `contig`, `META`, `SAMPLE`, `PEDIGREE`, and `pedigreeDB`.
     ///
     /// # Examples
     ///
@@ -536,7 +683,7 @@ impl Header {
     /// Returns a mutable reference to a map of records with nonstandard keys.
     ///
     /// This includes all records other than `fileformat`, `INFO`, `FILTER`, `FORMAT`, `ALT`,
-    /// `assembly`, `contig`, `META`, `SAMPLE`, and `pedigreeDB`.
+    /// `assembly`, `contig`, `META`, `SAMPLE`, `PEDIGREE`, and `pedigreeDB`.
     ///
     /// To simply add an unstructured record, consider using [`Self::insert`] instead.
     ///
@@ -563,7 +710,7 @@ impl Header {
     /// Returns a header record with the given key.
     ///
     /// This includes all records other than `fileformat`, `INFO`, `FILTER`, `FORMAT`, `ALT`,
-    /// `assembly`, `contig`, `META`, `SAMPLE`, and `pedigreeDB`.
+    /// `assembly`, `contig`, `META`, `SAMPLE`, `PEDIGREE`, and `pedigreeDB`.
     ///
     /// # Examples
     ///
@@ -701,6 +848,28 @@ impl std::fmt::Display for Header {
             )?;
         }
 
+        for (id, sample) in self.samples() {
+            writeln!(
+                f,
+                "{}{}=<ID={}{}>",
+                record::PREFIX,
+                record::key::SAMPLE,
+                id,
+                sample
+            )?;
+        }
+
+        for (id, pedigree) in self.pedigrees() {
+            writeln!(
+                f,
+                "{}{}=<ID={}{}>",
+                record::PREFIX,
+                record::key::PEDIGREE,
+                id,
+                pedigree
+            )?;
+        }
+
         if let Some(pedigree_db) = self.pedigree_db() {
             writeln!(
                 f,
@@ -2,7 +2,7 @@ use super::{
     record::{
         self,
         value::{
-            map::{AlternativeAllele, Contig, Filter, Format, Info, Meta},
+            map::{AlternativeAllele, Contig, Filter, Format, Info, Meta, Pedigree, Sample},
             Map,
         },
     },
@@ -23,6 +23,8 @@ pub struct Builder {
     assembly: Option<String>,
     contigs: Contigs,
     meta: IndexMap<String, Map<Meta>>,
+    samples: IndexMap<String, Map<Sample>>,
+    pedigrees: IndexMap<String, Map<Pedigree>>,
     pedigree_db: Option<String>,
     sample_names: SampleNames,
     other_records: OtherRecords,
@@ -73,6 +75,40 @@ impl Builder {
         self
     }
 
+    /// Adds a reserved information record (`INFO`) with its spec-defined definition.
+    ///
+    /// The number, type, and description are taken from the definition of the reserved key for
+    /// the builder's file format. If the file format does not define the key, the latest
+    /// definition is used. Keys that are not reserved are added with a number of 1, a string
+    /// type, and an empty description.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{
+    ///     self as vcf,
+    ///     header::{info::key, Number},
+    /// };
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .add_reserved_info(key::ALLELE_FREQUENCIES)
+    ///     .build();
+    ///
+    /// let info = &header.infos()[&key::ALLELE_FREQUENCIES];
+    /// assert_eq!(info.number(), Number::A);
+    /// assert_eq!(info.description(), "Allele frequency for each ALT allele in the same order as listed");
+    /// ```
+    pub fn add_reserved_info(self, id: super::info::Key) -> Self {
+        use super::info::key;
+
+        let info = match key::definition(self.file_format, &id) {
+            Some((number, ty, description)) => Map::<Info>::new(number, ty, description),
+            None => Map::<Info>::from(&id),
+        };
+
+        self.add_info(id, info)
+    }
+
     /// Adds a filter record (`FILTER`).
     ///
     /// # Examples
@@ -124,6 +160,41 @@ impl Builder {
         self
     }
 
+    /// Adds a reserved genotype format record (`FORMAT`) with its spec-defined definition.
+    ///
+    /// The number, type, and description are taken from the definition of the reserved key for
+    /// the builder's file format. If the file format does not define the key, the latest
+    /// definition is used. Keys that are not reserved are added with a number of 1, a string
+    /// type, and an empty description.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{
+    ///     self as vcf,
+    ///     header::{format::key, Number},
+    /// };
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .add_reserved_format(key::GENOTYPE)
+    ///     .add_reserved_format(key::READ_DEPTHS)
+    ///     .build();
+    ///
+    /// let format = &header.formats()[&key::READ_DEPTHS];
+    /// assert_eq!(format.number(), Number::R);
+    /// assert_eq!(format.description(), "Read depth for each allele");
+    /// ```
+    pub fn add_reserved_format(self, id: super::format::Key) -> Self {
+        use super::format::key;
+
+        let format = match key::definition(self.file_format, &id) {
+            Some((number, ty, description)) => Map::<Format>::new(number, ty, description),
+            None => Map::<Format>::from(&id),
+        };
+
+        self.add_format(id, format)
+    }
+
     /// Adds an alternative allele record (`ALT`).
     ///
     /// # Examples
@@ -234,6 +305,61 @@ impl Builder {
         self
     }
 
+    /// Adds a sample record (`SAMPLE`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, header::record::value::{map::Sample, Map}};
+    ///
+    /// let sample = Map::<Sample>::builder().set_description("Tumor").build()?;
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .add_sample("sample0", sample.clone())
+    ///     .build();
+    ///
+    /// let records = header.samples();
+    /// assert_eq!(records.len(), 1);
+    /// assert_eq!(&records[0], &sample);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn add_sample<I>(mut self, id: I, sample: Map<Sample>) -> Self
+    where
+        I: Into<String>,
+    {
+        self.samples.insert(id.into(), sample);
+        self
+    }
+
+    /// Adds a pedigree record (`PEDIGREE`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, header::record::value::{map::Pedigree, Map}};
+    ///
+    /// let pedigree = Map::<Pedigree>::builder()
+    ///     .set_father("sample1")
+    ///     .set_mother("sample2")
+    ///     .build()?;
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .add_pedigree("sample0", pedigree.clone())
+    ///     .build();
+    ///
+    /// let records = header.pedigrees();
+    /// assert_eq!(records.len(), 1);
+    /// assert_eq!(&records[0], &pedigree);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn add_pedigree<I>(mut self, id: I, pedigree: Map<Pedigree>) -> Self
+    where
+        I: Into<String>,
+    {
+        self.pedigrees.insert(id.into(), pedigree);
+        self
+    }
+
     /// Sets a pedigree database record (`pedigreeDB`).
     ///
     /// # Examples
@@ -347,6 +473,8 @@ impl Builder {
             assembly: self.assembly,
             contigs: self.contigs,
             meta: self.meta,
+            samples: self.samples,
+            pedigrees: self.pedigrees,
             pedigree_db: self.pedigree_db,
             sample_names: self.sample_names,
             other_records: self.other_records,
@@ -370,10 +498,32 @@ mod tests {
         assert!(header.assembly().is_none());
         assert!(header.contigs().is_empty());
         assert!(header.meta().is_empty());
+        assert!(header.samples().is_empty());
+        assert!(header.pedigrees().is_empty());
         assert!(header.pedigree_db().is_none());
         assert!(header.sample_names().is_empty());
     }
 
+    #[test]
+    fn test_add_reserved_info() {
+        use crate::header::{info::key, Number};
+
+        let header = Builder::default()
+            .add_reserved_info(key::SV_LENGTHS)
+            .add_reserved_info(key::SV_CLAIM)
+            .build();
+
+        assert_eq!(header.infos()[&key::SV_LENGTHS].number(), Number::Unknown);
+        assert_eq!(header.infos()[&key::SV_CLAIM].number(), Number::A);
+
+        let header = Builder::default()
+            .set_file_format(FileFormat::new(4, 4))
+            .add_reserved_info(key::SV_LENGTHS)
+            .build();
+
+        assert_eq!(header.infos()[&key::SV_LENGTHS].number(), Number::A);
+    }
+
     #[test]
     fn test_build() -> Result<(), crate::header::record::value::map::contig::name::ParseError> {
         use crate::{
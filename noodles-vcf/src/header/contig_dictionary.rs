@@ -0,0 +1,118 @@
+use std::{error, fmt};
+
+use super::{record::value::map::contig::Name, Contigs};
+
+/// An error returned when a VCF header contig dictionary fails to build.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ContigDictionaryError {
+    /// More than one contig has the same position (`IDX`).
+    DuplicateIdx(usize),
+    /// No contig has the given position (`IDX`).
+    MissingIdx(usize),
+}
+
+impl error::Error for ContigDictionaryError {}
+
+impl fmt::Display for ContigDictionaryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::DuplicateIdx(idx) => write!(f, "duplicate IDX: {idx}"),
+            Self::MissingIdx(idx) => write!(f, "missing IDX: {idx}"),
+        }
+    }
+}
+
+// Builds the dictionary of contigs (§ 6.2.1 "Dictionary of strings").
+//
+// A contig with an `IDX` field is placed at that position. Contigs without an `IDX` field are
+// then appended to the end of the dictionary in header order.
+pub(super) fn build(contigs: &Contigs) -> Result<Vec<&Name>, ContigDictionaryError> {
+    let mut entries: Vec<Option<&Name>> = Vec::with_capacity(contigs.len());
+
+    for (name, contig) in contigs {
+        if let Some(i) = contig.idx() {
+            if i >= entries.len() {
+                entries.resize(i + 1, None);
+            }
+
+            if entries[i].replace(name).is_some() {
+                return Err(ContigDictionaryError::DuplicateIdx(i));
+            }
+        }
+    }
+
+    for (name, contig) in contigs {
+        if contig.idx().is_none() {
+            entries.push(Some(name));
+        }
+    }
+
+    entries
+        .into_iter()
+        .enumerate()
+        .map(|(i, entry)| entry.ok_or(ContigDictionaryError::MissingIdx(i)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::header::record::value::{map::Contig, Map};
+
+    fn contig_with_idx(idx: usize) -> Result<Map<Contig>, Box<dyn std::error::Error>> {
+        Map::<Contig>::builder()
+            .set_idx(idx)
+            .build()
+            .map_err(|e| e.into())
+    }
+
+    #[test]
+    fn test_build() -> Result<(), Box<dyn std::error::Error>> {
+        let sq0: Name = "sq0".parse()?;
+        let sq1: Name = "sq1".parse()?;
+        let sq2: Name = "sq2".parse()?;
+
+        let contigs: Contigs = [
+            (sq0.clone(), Map::<Contig>::new()),
+            (sq1.clone(), Map::<Contig>::new()),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(build(&contigs), Ok(vec![&sq0, &sq1]));
+
+        let contigs: Contigs = [
+            (sq0.clone(), contig_with_idx(2)?),
+            (sq1.clone(), contig_with_idx(0)?),
+            (sq2.clone(), contig_with_idx(1)?),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(build(&contigs), Ok(vec![&sq1, &sq2, &sq0]));
+
+        let contigs: Contigs = [
+            (sq0.clone(), Map::<Contig>::new()),
+            (sq1.clone(), contig_with_idx(0)?),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(build(&contigs), Ok(vec![&sq1, &sq0]));
+
+        let contigs: Contigs = [
+            (sq0.clone(), contig_with_idx(0)?),
+            (sq1.clone(), contig_with_idx(0)?),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(build(&contigs), Err(ContigDictionaryError::DuplicateIdx(0)));
+
+        let contigs: Contigs = [
+            (sq0.clone(), contig_with_idx(0)?),
+            (sq1.clone(), contig_with_idx(2)?),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(build(&contigs), Err(ContigDictionaryError::MissingIdx(1)));
+
+        Ok(())
+    }
+}
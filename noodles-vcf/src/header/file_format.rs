@@ -1,5 +1,9 @@
 //! VCF header file format.
 
+mod version;
+
+pub use self::version::Version;
+
 use std::{error, fmt, num, str::FromStr};
 
 /// A VCF header file format.
@@ -55,6 +59,22 @@ impl FileFormat {
     pub fn minor(&self) -> u32 {
         self.minor
     }
+
+    /// Returns the known VCF version of the file format.
+    ///
+    /// This returns `None` if the file format is not a known VCF version.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::header::{file_format::Version, FileFormat};
+    ///
+    /// assert_eq!(FileFormat::new(4, 4).version(), Some(Version::V4_4));
+    /// assert!(FileFormat::new(5, 0).version().is_none());
+    /// ```
+    pub fn version(&self) -> Option<Version> {
+        Version::try_from(*self).ok()
+    }
 }
 
 impl Default for FileFormat {
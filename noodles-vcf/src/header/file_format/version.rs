@@ -0,0 +1,64 @@
+use super::FileFormat;
+
+/// A known VCF version.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Version {
+    /// VCF 4.0.
+    V4_0,
+    /// VCF 4.1.
+    V4_1,
+    /// VCF 4.2.
+    V4_2,
+    /// VCF 4.3.
+    V4_3,
+    /// VCF 4.4.
+    V4_4,
+}
+
+impl TryFrom<FileFormat> for Version {
+    type Error = FileFormat;
+
+    fn try_from(file_format: FileFormat) -> Result<Self, Self::Error> {
+        match (file_format.major(), file_format.minor()) {
+            (4, 0) => Ok(Self::V4_0),
+            (4, 1) => Ok(Self::V4_1),
+            (4, 2) => Ok(Self::V4_2),
+            (4, 3) => Ok(Self::V4_3),
+            (4, 4) => Ok(Self::V4_4),
+            _ => Err(file_format),
+        }
+    }
+}
+
+impl From<Version> for FileFormat {
+    fn from(version: Version) -> Self {
+        match version {
+            Version::V4_0 => Self::new(4, 0),
+            Version::V4_1 => Self::new(4, 1),
+            Version::V4_2 => Self::new(4, 2),
+            Version::V4_3 => Self::new(4, 3),
+            Version::V4_4 => Self::new(4, 4),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_from_file_format_for_version() {
+        assert_eq!(Version::try_from(FileFormat::new(4, 3)), Ok(Version::V4_3));
+        assert_eq!(Version::try_from(FileFormat::new(4, 4)), Ok(Version::V4_4));
+        assert_eq!(
+            Version::try_from(FileFormat::new(5, 0)),
+            Err(FileFormat::new(5, 0))
+        );
+    }
+
+    #[test]
+    fn test_from_version_for_file_format() {
+        assert_eq!(FileFormat::from(Version::V4_2), FileFormat::new(4, 2));
+        assert_eq!(FileFormat::from(Version::V4_4), FileFormat::new(4, 4));
+    }
+}
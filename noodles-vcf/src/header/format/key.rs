@@ -367,23 +367,29 @@ pub(crate) fn definition(
     }
 }
 
+// Returns the VCF 4.3 definition of a standard key or, for keys added in VCF 4.4, the VCF 4.4
+// definition.
+fn standard_definition(key: Standard) -> Option<(Number, Type, &'static str)> {
+    v4_3::definition(key).or_else(|| v4_4::definition(key))
+}
+
 pub(crate) fn number(key: &Key) -> Option<Number> {
     match key {
-        Key::Standard(k) => v4_3::definition(*k).map(|(number, _, _)| number),
+        Key::Standard(k) => standard_definition(*k).map(|(number, _, _)| number),
         Key::Other(_) => None,
     }
 }
 
 pub(crate) fn ty(key: &Key) -> Option<Type> {
     match key {
-        Key::Standard(k) => v4_3::definition(*k).map(|(_, ty, _)| ty),
+        Key::Standard(k) => standard_definition(*k).map(|(_, ty, _)| ty),
         Key::Other(_) => None,
     }
 }
 
 pub(crate) fn description(key: &Key) -> Option<&str> {
     match key {
-        Key::Standard(k) => v4_3::definition(*k).map(|(_, _, description)| description),
+        Key::Standard(k) => standard_definition(*k).map(|(_, _, description)| description),
         Key::Other(_) => None,
     }
 }
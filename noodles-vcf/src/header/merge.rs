@@ -0,0 +1,543 @@
+//! VCF header merging.
+
+use std::{error, fmt, hash::Hash};
+
+use indexmap::IndexMap;
+
+use super::{
+    format, info,
+    record::value::{
+        map::{self, contig},
+        Map,
+    },
+    Header,
+};
+
+/// The result of merging VCF headers with namespaces.
+///
+/// This is created by calling [`merge_with_namespaces`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Merged {
+    header: Header,
+    renames: Vec<Renames>,
+}
+
+impl Merged {
+    /// Returns the merged header.
+    pub fn header(&self) -> &Header {
+        &self.header
+    }
+
+    /// Returns the key renames of each input header.
+    ///
+    /// The list is in the same order as the input headers.
+    pub fn renames(&self) -> &[Renames] {
+        &self.renames
+    }
+
+    /// Unwraps and returns the merged header.
+    pub fn into_header(self) -> Header {
+        self.header
+    }
+}
+
+/// Key renames of an input header.
+///
+/// Records associated with the input header must have their info and genotype field keys renamed
+/// accordingly before they can be written with the merged header.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Renames {
+    infos: IndexMap<info::Key, info::Key>,
+    formats: IndexMap<format::Key, format::Key>,
+}
+
+impl Renames {
+    /// Returns the info key renames.
+    pub fn infos(&self) -> &IndexMap<info::Key, info::Key> {
+        &self.infos
+    }
+
+    /// Returns the format key renames.
+    pub fn formats(&self) -> &IndexMap<format::Key, format::Key> {
+        &self.formats
+    }
+
+    /// Returns whether there are no renames.
+    pub fn is_empty(&self) -> bool {
+        self.infos.is_empty() && self.formats.is_empty()
+    }
+}
+
+/// An error returned when VCF headers fail to merge.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum MergeError {
+    /// An info header record has an incompatible number or type.
+    InfoConflict(info::Key),
+    /// A format header record has an incompatible number or type.
+    FormatConflict(format::Key),
+    /// A contig header record has an incompatible length.
+    ContigConflict(contig::Name),
+    /// A namespaced info key is invalid.
+    InvalidInfoKey(info::key::ParseError),
+    /// A namespaced format key is invalid.
+    InvalidFormatKey(format::key::ParseError),
+}
+
+impl error::Error for MergeError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::InvalidInfoKey(e) => Some(e),
+            Self::InvalidFormatKey(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for MergeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InfoConflict(key) => write!(f, "conflicting info header record: {key}"),
+            Self::FormatConflict(key) => write!(f, "conflicting format header record: {key}"),
+            Self::ContigConflict(name) => write!(f, "conflicting contig header record: {name}"),
+            Self::InvalidInfoKey(_) => f.write_str("invalid info key"),
+            Self::InvalidFormatKey(_) => f.write_str("invalid format key"),
+        }
+    }
+}
+
+/// Merges VCF headers.
+///
+/// Contig, info, format, filter, and alternative allele header records are combined in order of
+/// first appearance, as are sample names. The first definition of a record is kept.
+///
+/// Info and format header records with the same key must have the same number and type, and contig
+/// header records with the same name must not have different lengths. The merged file format is
+/// the latest of the input file formats.
+///
+/// # Errors
+///
+/// An error is returned if header records conflict.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_vcf::{
+///     self as vcf,
+///     header::{info::key, record::value::{map::Info, Map}},
+/// };
+///
+/// let header_a = vcf::Header::builder()
+///     .add_info(key::TOTAL_DEPTH, Map::<Info>::from(&key::TOTAL_DEPTH))
+///     .add_sample_name("sample0")
+///     .build();
+///
+/// let header_b = vcf::Header::builder()
+///     .add_info(key::ALLELE_FREQUENCIES, Map::<Info>::from(&key::ALLELE_FREQUENCIES))
+///     .add_sample_name("sample0")
+///     .add_sample_name("sample1")
+///     .build();
+///
+/// let header = vcf::header::merge([&header_a, &header_b])?;
+///
+/// assert_eq!(header.infos().len(), 2);
+/// assert_eq!(header.sample_names().len(), 2);
+/// # Ok::<_, vcf::header::merge::MergeError>(())
+/// ```
+pub fn merge<'h, I>(headers: I) -> Result<Header, MergeError>
+where
+    I: IntoIterator<Item = &'h Header>,
+{
+    merge_inner(headers.into_iter().map(|header| (None, header))).map(Merged::into_header)
+}
+
+/// Merges VCF headers, renaming conflicting keys using the given namespaces.
+///
+/// This is the same as [`merge`], except that when an info or format header record conflicts
+/// with an earlier definition, its key is renamed to `<namespace>_<key>` using the namespace of
+/// its header. The renames of each input header are returned with the merged header.
+///
+/// # Errors
+///
+/// An error is returned if contig header records conflict, a namespaced key is invalid, or a
+/// namespaced key also conflicts.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_vcf::{
+///     self as vcf,
+///     header::{
+///         info::key,
+///         record::value::{map::{info::Type, Info}, Map},
+///         Number,
+///     },
+/// };
+///
+/// let header_a = vcf::Header::builder()
+///     .add_info(key::TOTAL_DEPTH, Map::<Info>::from(&key::TOTAL_DEPTH))
+///     .build();
+///
+/// let header_b = vcf::Header::builder()
+///     .add_info(
+///         key::TOTAL_DEPTH,
+///         Map::<Info>::new(Number::Count(1), Type::Float, "Mean depth"),
+///     )
+///     .build();
+///
+/// let merged = vcf::header::merge_with_namespaces([("a", &header_a), ("b", &header_b)])?;
+///
+/// let renamed_key = "b_DP".parse()?;
+/// assert!(merged.header().infos().contains_key(&key::TOTAL_DEPTH));
+/// assert!(merged.header().infos().contains_key(&renamed_key));
+///
+/// assert!(merged.renames()[0].is_empty());
+/// assert_eq!(merged.renames()[1].infos().get(&key::TOTAL_DEPTH), Some(&renamed_key));
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn merge_with_namespaces<'h, I>(headers: I) -> Result<Merged, MergeError>
+where
+    I: IntoIterator<Item = (&'h str, &'h Header)>,
+{
+    merge_inner(
+        headers
+            .into_iter()
+            .map(|(namespace, header)| (Some(namespace), header)),
+    )
+}
+
+fn merge_inner<'h, I>(headers: I) -> Result<Merged, MergeError>
+where
+    I: Iterator<Item = (Option<&'h str>, &'h Header)>,
+{
+    let mut merged_header: Option<Header> = None;
+    let mut renames = Vec::new();
+
+    for (namespace, header) in headers {
+        let dst = match merged_header.as_mut() {
+            Some(dst) => dst,
+            None => {
+                merged_header = Some(header.clone());
+                renames.push(Renames::default());
+                continue;
+            }
+        };
+
+        let mut header_renames = Renames::default();
+
+        if header.file_format() > dst.file_format() {
+            *dst.file_format_mut() = header.file_format();
+        }
+
+        for (key, info) in header.infos() {
+            if let Some((old_key, new_key)) =
+                merge_definition(dst.infos_mut(), key, info, namespace)
+                    .map_err(|e| e.into_info_error(key))?
+            {
+                header_renames.infos.insert(old_key, new_key);
+            }
+        }
+
+        for (key, format) in header.formats() {
+            if let Some((old_key, new_key)) =
+                merge_definition(dst.formats_mut(), key, format, namespace)
+                    .map_err(|e| e.into_format_error(key))?
+            {
+                header_renames.formats.insert(old_key, new_key);
+            }
+        }
+
+        for (name, contig) in header.contigs() {
+            match dst.contigs_mut().get_mut(name) {
+                Some(dst_contig) => match (dst_contig.length(), contig.length()) {
+                    (Some(a), Some(b)) if a != b => {
+                        return Err(MergeError::ContigConflict(name.clone()))
+                    }
+                    (None, Some(b)) => *dst_contig.length_mut() = Some(b),
+                    _ => {}
+                },
+                None => {
+                    dst.contigs_mut().insert(name.clone(), contig.clone());
+                }
+            }
+        }
+
+        merge_first(dst.filters_mut(), header.filters());
+        merge_first(dst.alternative_alleles_mut(), header.alternative_alleles());
+        merge_first(dst.meta_mut(), header.meta());
+        merge_first(dst.samples_mut(), header.samples());
+        merge_first(dst.pedigrees_mut(), header.pedigrees());
+
+        if dst.assembly().is_none() {
+            *dst.assembly_mut() = header.assembly().map(String::from);
+        }
+
+        if dst.pedigree_db().is_none() {
+            *dst.pedigree_db_mut() = header.pedigree_db().map(String::from);
+        }
+
+        for sample_name in header.sample_names() {
+            if !dst.sample_names().contains(sample_name) {
+                dst.sample_names_mut().insert(sample_name.clone());
+            }
+        }
+
+        for (key, values) in header.other_records() {
+            let dst_values = dst.other_records_mut().entry(key.clone()).or_default();
+
+            for value in values {
+                if !dst_values.contains(value) {
+                    dst_values.push(value.clone());
+                }
+            }
+        }
+
+        renames.push(header_renames);
+    }
+
+    Ok(Merged {
+        header: merged_header.unwrap_or_default(),
+        renames,
+    })
+}
+
+enum DefinitionError<E> {
+    Conflict,
+    InvalidKey(E),
+}
+
+impl DefinitionError<info::key::ParseError> {
+    fn into_info_error(self, key: &info::Key) -> MergeError {
+        match self {
+            Self::Conflict => MergeError::InfoConflict(key.clone()),
+            Self::InvalidKey(e) => MergeError::InvalidInfoKey(e),
+        }
+    }
+}
+
+impl DefinitionError<format::key::ParseError> {
+    fn into_format_error(self, key: &format::Key) -> MergeError {
+        match self {
+            Self::Conflict => MergeError::FormatConflict(key.clone()),
+            Self::InvalidKey(e) => MergeError::InvalidFormatKey(e),
+        }
+    }
+}
+
+trait Definition {
+    fn is_compatible(&self, other: &Self) -> bool;
+}
+
+impl Definition for Map<map::Info> {
+    fn is_compatible(&self, other: &Self) -> bool {
+        self.number() == other.number() && self.ty() == other.ty()
+    }
+}
+
+impl Definition for Map<map::Format> {
+    fn is_compatible(&self, other: &Self) -> bool {
+        self.number() == other.number() && self.ty() == other.ty()
+    }
+}
+
+// Adds a definition to the destination map, returning the key rename, if any.
+#[allow(clippy::type_complexity)]
+fn merge_definition<K, V>(
+    dst: &mut IndexMap<K, V>,
+    key: &K,
+    value: &V,
+    namespace: Option<&str>,
+) -> Result<Option<(K, K)>, DefinitionError<<K as std::str::FromStr>::Err>>
+where
+    K: Clone + Eq + Hash + fmt::Display + std::str::FromStr,
+    V: Clone + Definition,
+{
+    match dst.get(key) {
+        None => {
+            dst.insert(key.clone(), value.clone());
+            Ok(None)
+        }
+        Some(dst_value) if dst_value.is_compatible(value) => Ok(None),
+        Some(_) => {
+            let namespace = namespace.ok_or(DefinitionError::Conflict)?;
+
+            let new_key: K = format!("{namespace}_{key}")
+                .parse()
+                .map_err(DefinitionError::InvalidKey)?;
+
+            match dst.get(&new_key) {
+                Some(dst_value) if !dst_value.is_compatible(value) => {
+                    Err(DefinitionError::Conflict)
+                }
+                Some(_) => Ok(Some((key.clone(), new_key))),
+                None => {
+                    dst.insert(new_key.clone(), value.clone());
+                    Ok(Some((key.clone(), new_key)))
+                }
+            }
+        }
+    }
+}
+
+fn merge_first<K, V>(dst: &mut IndexMap<K, V>, src: &IndexMap<K, V>)
+where
+    K: Clone + Eq + Hash,
+    V: Clone,
+{
+    for (key, value) in src {
+        if !dst.contains_key(key) {
+            dst.insert(key.clone(), value.clone());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::header::{
+        record::value::map::{
+            format::Type as FormatType, info::Type, Contig, Filter, Format, Info,
+        },
+        FileFormat, Number,
+    };
+
+    #[test]
+    fn test_merge() -> Result<(), Box<dyn std::error::Error>> {
+        let header_a = Header::builder()
+            .set_file_format(FileFormat::new(4, 2))
+            .add_contig("sq0".parse()?, Map::<Contig>::new())
+            .add_info(
+                info::key::TOTAL_DEPTH,
+                Map::<Info>::from(&info::key::TOTAL_DEPTH),
+            )
+            .add_sample_name("sample0")
+            .build();
+
+        let mut sq0 = Map::<Contig>::new();
+        *sq0.length_mut() = Some(8);
+
+        let header_b = Header::builder()
+            .add_contig("sq0".parse()?, sq0)
+            .add_contig("sq1".parse()?, Map::<Contig>::new())
+            .add_info(
+                info::key::TOTAL_DEPTH,
+                Map::<Info>::new(Number::Count(1), Type::Integer, "Depth"),
+            )
+            .add_filter("q10", Map::<Filter>::new("Quality below 10"))
+            .add_format(
+                format::key::GENOTYPE,
+                Map::<Format>::from(&format::key::GENOTYPE),
+            )
+            .add_sample_name("sample1")
+            .add_sample_name("sample0")
+            .build();
+
+        let actual = merge([&header_a, &header_b])?;
+
+        assert_eq!(actual.file_format(), FileFormat::default());
+
+        assert_eq!(actual.contigs().len(), 2);
+        assert_eq!(
+            actual
+                .contigs()
+                .get("sq0")
+                .and_then(|contig| contig.length()),
+            Some(8)
+        );
+
+        assert_eq!(actual.infos().len(), 1);
+        assert_eq!(
+            actual
+                .infos()
+                .get(&info::key::TOTAL_DEPTH)
+                .map(|info| info.description()),
+            Some(Map::<Info>::from(&info::key::TOTAL_DEPTH).description())
+        );
+
+        assert!(actual.filters().contains_key("q10"));
+        assert!(actual.formats().contains_key(&format::key::GENOTYPE));
+
+        let sample_names: Vec<_> = actual.sample_names().iter().map(String::as_str).collect();
+        assert_eq!(sample_names, ["sample0", "sample1"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_merge_with_conflicts() -> Result<(), Box<dyn std::error::Error>> {
+        let header_a = Header::builder()
+            .add_info(
+                info::key::TOTAL_DEPTH,
+                Map::<Info>::from(&info::key::TOTAL_DEPTH),
+            )
+            .build();
+
+        let header_b = Header::builder()
+            .add_info(
+                info::key::TOTAL_DEPTH,
+                Map::<Info>::new(Number::Count(1), Type::Float, "Depth"),
+            )
+            .build();
+
+        assert_eq!(
+            merge([&header_a, &header_b]),
+            Err(MergeError::InfoConflict(info::key::TOTAL_DEPTH))
+        );
+
+        let mut sq0_a = Map::<Contig>::new();
+        *sq0_a.length_mut() = Some(8);
+        let header_a = Header::builder().add_contig("sq0".parse()?, sq0_a).build();
+
+        let mut sq0_b = Map::<Contig>::new();
+        *sq0_b.length_mut() = Some(13);
+        let header_b = Header::builder().add_contig("sq0".parse()?, sq0_b).build();
+
+        assert_eq!(
+            merge([&header_a, &header_b]),
+            Err(MergeError::ContigConflict("sq0".parse()?))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_merge_with_namespaces() -> Result<(), Box<dyn std::error::Error>> {
+        let header_a = Header::builder()
+            .add_format(
+                format::key::CONDITIONAL_GENOTYPE_QUALITY,
+                Map::<Format>::from(&format::key::CONDITIONAL_GENOTYPE_QUALITY),
+            )
+            .build();
+
+        let header_b = Header::builder()
+            .add_format(
+                format::key::CONDITIONAL_GENOTYPE_QUALITY,
+                Map::<Format>::new(Number::Count(1), FormatType::Float, "Quality"),
+            )
+            .build();
+
+        let merged = merge_with_namespaces([("a", &header_a), ("b", &header_b)])?;
+
+        let renamed_key: format::Key = "b_GQ".parse()?;
+
+        assert_eq!(merged.header().formats().len(), 2);
+        assert!(merged.header().formats().contains_key(&renamed_key));
+
+        assert_eq!(merged.renames().len(), 2);
+        assert!(merged.renames()[0].is_empty());
+        assert_eq!(
+            merged.renames()[1]
+                .formats()
+                .get(&format::key::CONDITIONAL_GENOTYPE_QUALITY),
+            Some(&renamed_key)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_merge_with_no_headers() -> Result<(), MergeError> {
+        let headers: [&Header; 0] = [];
+        assert_eq!(merge(headers)?, Header::default());
+        Ok(())
+    }
+}
@@ -157,7 +157,9 @@ fn parse_record(
         Record::Assembly(assembly) => builder.set_assembly(assembly),
         Record::Contig(id, contig) => builder.add_contig(id, contig),
         Record::Meta(id, meta) => builder.add_meta(id, meta),
+        Record::Pedigree(id, pedigree) => builder.add_pedigree(id, pedigree),
         Record::PedigreeDb(pedigree_db) => builder.set_pedigree_db(pedigree_db),
+        Record::Sample(id, sample) => builder.add_sample(id, sample),
         Record::Other(key, value) => builder.insert(key, value),
     };
 
@@ -237,8 +239,14 @@ mod tests {
         assert_eq!(header.assembly(), Some("file:///assemblies.fasta"));
         assert_eq!(header.contigs().len(), 3);
         assert_eq!(header.meta().len(), 1);
+        assert_eq!(header.samples().len(), 1);
+        assert_eq!(header.pedigrees().len(), 1);
         assert_eq!(header.sample_names().len(), 1);
 
+        let pedigree = &header.pedigrees()["cid"];
+        assert_eq!(pedigree.father(), Some("fid"));
+        assert_eq!(pedigree.mother(), Some("mid"));
+
         assert_eq!(
             header.get("fileDate"),
             Some(&[record::value::Other::from("20200506")][..])
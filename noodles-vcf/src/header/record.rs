@@ -9,7 +9,7 @@ pub use self::key::Key;
 use std::{error, fmt, str::FromStr};
 
 use self::value::{
-    map::{self, AlternativeAllele, Contig, Filter, Format, Info, Meta, Other},
+    map::{self, AlternativeAllele, Contig, Filter, Format, Info, Meta, Other, Pedigree, Sample},
     Map,
 };
 use super::{file_format, FileFormat};
@@ -38,8 +38,12 @@ pub enum Record {
     Info(crate::header::info::Key, Map<Info>),
     /// A `META` record.
     Meta(String, Map<Meta>),
+    /// A `PEDIGREE` record.
+    Pedigree(String, Map<Pedigree>),
     /// A `pedigreeDB` record.
     PedigreeDb(String),
+    /// A `SAMPLE` record.
+    Sample(String, Map<Sample>),
     /// A nonstadard record.
     Other(key::Other, value::Other),
 }
@@ -63,6 +67,10 @@ pub enum ParseError {
     InvalidContig(map::TryFromFieldsError),
     /// A META record is invalid.
     InvalidMeta(map::TryFromFieldsError),
+    /// A PEDIGREE record is invalid.
+    InvalidPedigree(map::TryFromFieldsError),
+    /// A SAMPLE record is invalid.
+    InvalidSample(map::TryFromFieldsError),
 }
 
 impl error::Error for ParseError {
@@ -75,7 +83,9 @@ impl error::Error for ParseError {
             | Self::InvalidFormat(e)
             | Self::InvalidAlternativeAllele(e)
             | Self::InvalidContig(e)
-            | Self::InvalidMeta(e) => Some(e),
+            | Self::InvalidMeta(e)
+            | Self::InvalidPedigree(e)
+            | Self::InvalidSample(e) => Some(e),
         }
     }
 }
@@ -91,6 +101,8 @@ impl fmt::Display for ParseError {
             Self::InvalidAlternativeAllele(_) => write!(f, "invalid {}", key::ALTERNATIVE_ALLELE),
             Self::InvalidContig(_) => write!(f, "invalid {}", key::CONTIG),
             Self::InvalidMeta(_) => write!(f, "invalid {}", key::META),
+            Self::InvalidPedigree(_) => write!(f, "invalid {}", key::PEDIGREE),
+            Self::InvalidSample(_) => write!(f, "invalid {}", key::SAMPLE),
         }
     }
 }
@@ -198,6 +210,28 @@ impl TryFrom<(FileFormat, &str)> for Record {
                 }
                 _ => Err(ParseError::Invalid),
             },
+            key::PEDIGREE => match value {
+                Value::Struct(mut fields) => {
+                    let id = remove_field(&mut fields, "ID").ok_or(ParseError::Invalid)?;
+
+                    let pedigree =
+                        Map::<Pedigree>::try_from(fields).map_err(ParseError::InvalidPedigree)?;
+
+                    Ok(Self::Pedigree(id, pedigree))
+                }
+                _ => Err(ParseError::Invalid),
+            },
+            key::SAMPLE => match value {
+                Value::Struct(mut fields) => {
+                    let id = remove_field(&mut fields, "ID").ok_or(ParseError::Invalid)?;
+
+                    let sample =
+                        Map::<Sample>::try_from(fields).map_err(ParseError::InvalidSample)?;
+
+                    Ok(Self::Sample(id, sample))
+                }
+                _ => Err(ParseError::Invalid),
+            },
             key::PEDIGREE_DB => match value {
                 Value::String(s) => Ok(Self::PedigreeDb(s)),
                 _ => Err(ParseError::Invalid),
@@ -283,6 +317,18 @@ mod tests {
             r#"##INFO=<ID=NS,Number=1,Type=Integer,Description="Number of samples with data">"#;
         assert!(matches!(line.parse(), Ok(Record::Info(..))));
 
+        let line = "##PEDIGREE=<ID=child,Father=father,Mother=mother>";
+        assert!(matches!(
+            line.parse(),
+            Ok(Record::Pedigree(id, pedigree)) if id == "child" && pedigree.father() == Some("father")
+        ));
+
+        let line = r#"##SAMPLE=<ID=sample0,Assay=WholeGenome,Description="Tumor">"#;
+        assert!(matches!(
+            line.parse(),
+            Ok(Record::Sample(id, sample)) if id == "sample0" && sample.description() == Some("Tumor")
+        ));
+
         assert!("".parse::<Record>().is_err());
 
         Ok(())
@@ -26,9 +26,15 @@ pub const CONTIG: Key = Key::Standard(Standard::Contig);
 /// VCF header record meta key.
 pub const META: Key = Key::Standard(Standard::Meta);
 
+/// VCF header record pedigree key.
+pub const PEDIGREE: Key = Key::Standard(Standard::Pedigree);
+
 /// VCF header record pedigree database key.
 pub const PEDIGREE_DB: Key = Key::Standard(Standard::PedigreeDb);
 
+/// VCF header record sample key.
+pub const SAMPLE: Key = Key::Standard(Standard::Sample);
+
 /// A standard VCF record key.
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub enum Standard {
@@ -48,8 +54,12 @@ pub enum Standard {
     Contig,
     /// Meta (`META`).
     Meta,
+    /// Pedigree (`PEDIGREE`).
+    Pedigree,
     /// Pedigree database URI (`pedigreeDB`).
     PedigreeDb,
+    /// Sample (`SAMPLE`).
+    Sample,
 }
 
 impl Standard {
@@ -63,7 +73,9 @@ impl Standard {
             "assembly" => Some(Self::Assembly),
             "contig" => Some(Self::Contig),
             "META" => Some(Self::Meta),
+            "PEDIGREE" => Some(Self::Pedigree),
             "pedigreeDB" => Some(Self::PedigreeDb),
+            "SAMPLE" => Some(Self::Sample),
             _ => None,
         }
     }
@@ -80,7 +92,9 @@ impl AsRef<str> for Standard {
             Self::Assembly => "assembly",
             Self::Contig => "contig",
             Self::Meta => "META",
+            Self::Pedigree => "PEDIGREE",
             Self::PedigreeDb => "pedigreeDB",
+            Self::Sample => "SAMPLE",
         }
     }
 }
@@ -187,7 +201,9 @@ mod tests {
         assert_eq!(ASSEMBLY.to_string(), "assembly");
         assert_eq!(CONTIG.to_string(), "contig");
         assert_eq!(META.to_string(), "META");
+        assert_eq!(PEDIGREE.to_string(), "PEDIGREE");
         assert_eq!(PEDIGREE_DB.to_string(), "pedigreeDB");
+        assert_eq!(SAMPLE.to_string(), "SAMPLE");
         assert_eq!(
             Key::Other(Other(String::from("fileDate"))).to_string(),
             "fileDate"
@@ -204,7 +220,9 @@ mod tests {
         assert_eq!(Key::from("assembly"), ASSEMBLY);
         assert_eq!(Key::from("contig"), CONTIG);
         assert_eq!(Key::from("META"), META);
+        assert_eq!(Key::from("PEDIGREE"), PEDIGREE);
         assert_eq!(Key::from("pedigreeDB"), PEDIGREE_DB);
+        assert_eq!(Key::from("SAMPLE"), SAMPLE);
         assert_eq!(
             Key::from("fileDate"),
             Key::Other(Other(String::from("fileDate")))
@@ -8,11 +8,13 @@ pub mod format;
 pub mod info;
 mod meta;
 mod other;
+mod pedigree;
+mod sample;
 mod tag;
 
 pub use self::{
     alternative_allele::AlternativeAllele, builder::Builder, contig::Contig, filter::Filter,
-    format::Format, info::Info, meta::Meta, other::Other,
+    format::Format, info::Info, meta::Meta, other::Other, pedigree::Pedigree, sample::Sample,
 };
 
 use std::{
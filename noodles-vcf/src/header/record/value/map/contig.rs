@@ -17,6 +17,8 @@ type Tag = super::tag::Tag<StandardTag>;
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
 pub struct Contig {
     length: Option<usize>,
+    md5: Option<String>,
+    url: Option<String>,
     idx: Option<usize>,
 }
 
@@ -78,6 +80,76 @@ impl Map<Contig> {
     pub fn length_mut(&mut self) -> &mut Option<usize> {
         &mut self.inner.length
     }
+
+    /// Returns the MD5 hexdigest of the sequence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::header::record::value::{map::Contig, Map};
+    ///
+    /// let map = Map::<Contig>::builder()
+    ///     .set_md5("d7eba311421bbc9d3ada44709dd61534")
+    ///     .build()?;
+    ///
+    /// assert_eq!(map.md5(), Some("d7eba311421bbc9d3ada44709dd61534"));
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn md5(&self) -> Option<&str> {
+        self.inner.md5.as_deref()
+    }
+
+    /// Returns a mutable reference to the MD5 hexdigest of the sequence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::header::record::value::{map::Contig, Map};
+    ///
+    /// let mut map = Map::<Contig>::new();
+    /// assert!(map.md5().is_none());
+    ///
+    /// *map.md5_mut() = Some(String::from("d7eba311421bbc9d3ada44709dd61534"));
+    /// assert_eq!(map.md5(), Some("d7eba311421bbc9d3ada44709dd61534"));
+    /// ```
+    pub fn md5_mut(&mut self) -> &mut Option<String> {
+        &mut self.inner.md5
+    }
+
+    /// Returns the URL of the sequence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::header::record::value::{map::Contig, Map};
+    ///
+    /// let map = Map::<Contig>::builder()
+    ///     .set_url("https://example.com/reference.fa")
+    ///     .build()?;
+    ///
+    /// assert_eq!(map.url(), Some("https://example.com/reference.fa"));
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn url(&self) -> Option<&str> {
+        self.inner.url.as_deref()
+    }
+
+    /// Returns a mutable reference to the URL of the sequence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::header::record::value::{map::Contig, Map};
+    ///
+    /// let mut map = Map::<Contig>::new();
+    /// assert!(map.url().is_none());
+    ///
+    /// *map.url_mut() = Some(String::from("https://example.com/reference.fa"));
+    /// assert_eq!(map.url(), Some("https://example.com/reference.fa"));
+    /// ```
+    pub fn url_mut(&mut self) -> &mut Option<String> {
+        &mut self.inner.url
+    }
 }
 
 impl fmt::Display for Map<Contig> {
@@ -86,6 +158,14 @@ impl fmt::Display for Map<Contig> {
             write!(f, ",length={length}")?;
         }
 
+        if let Some(md5) = self.md5() {
+            write!(f, ",md5={md5}")?;
+        }
+
+        if let Some(url) = self.url() {
+            write!(f, ",URL={url}")?;
+        }
+
         super::fmt_display_other_fields(f, self.other_fields())?;
 
         if let Some(idx) = self.idx() {
@@ -103,19 +183,28 @@ impl TryFrom<Fields> for Map<Contig> {
         let mut other_fields = super::init_other_fields();
 
         let mut length = None;
+        let mut md5 = None;
+        let mut url = None;
         let mut idx = None;
 
         for (key, value) in fields {
             match Tag::from(key) {
                 Tag::Standard(StandardTag::Id) => return Err(TryFromFieldsError::DuplicateTag),
                 Tag::Standard(StandardTag::Length) => parse_length(&value, &mut length)?,
+                Tag::Standard(StandardTag::Md5) => parse_string(value, &mut md5)?,
+                Tag::Standard(StandardTag::Url) => parse_string(value, &mut url)?,
                 Tag::Standard(StandardTag::Idx) => super::parse_idx(&value, &mut idx)?,
                 Tag::Other(t) => super::insert_other_field(&mut other_fields, t, value)?,
             }
         }
 
         Ok(Self {
-            inner: Contig { length, idx },
+            inner: Contig {
+                length,
+                md5,
+                url,
+                idx,
+            },
             other_fields,
         })
     }
@@ -133,6 +222,14 @@ fn parse_length(s: &str, value: &mut Option<usize>) -> Result<(), TryFromFieldsE
     }
 }
 
+fn parse_string(s: String, value: &mut Option<String>) -> Result<(), TryFromFieldsError> {
+    if value.replace(s).is_none() {
+        Ok(())
+    } else {
+        Err(TryFromFieldsError::DuplicateTag)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -147,7 +244,18 @@ mod tests {
             ),
         ])?;
 
-        let expected = r#",length=8,md5="d7eba311421bbc9d3ada44709dd61534""#;
+        let expected = r#",length=8,md5=d7eba311421bbc9d3ada44709dd61534"#;
+        assert_eq!(map.to_string(), expected);
+
+        let map = Map::<Contig>::try_from(vec![
+            (
+                String::from("URL"),
+                String::from("https://example.com/reference.fa"),
+            ),
+            (String::from("species"), String::from("Homo sapiens")),
+        ])?;
+
+        let expected = r#",URL=https://example.com/reference.fa,species="Homo sapiens""#;
         assert_eq!(map.to_string(), expected);
 
         Ok(())
@@ -4,6 +4,8 @@ use crate::header::record::value::map::{self, builder::BuildError};
 #[derive(Default)]
 pub struct Builder {
     length: Option<usize>,
+    md5: Option<String>,
+    url: Option<String>,
     idx: Option<usize>,
 }
 
@@ -11,6 +13,8 @@ impl map::builder::Inner<Contig> for Builder {
     fn build(self) -> Result<Contig, BuildError> {
         Ok(Contig {
             length: self.length,
+            md5: self.md5,
+            url: self.url,
             idx: self.idx,
         })
     }
@@ -29,4 +33,22 @@ impl map::Builder<Contig> {
         self.inner.length = Some(length);
         self
     }
+
+    /// Sets the MD5 hexdigest.
+    pub fn set_md5<I>(mut self, md5: I) -> Self
+    where
+        I: Into<String>,
+    {
+        self.inner.md5 = Some(md5.into());
+        self
+    }
+
+    /// Sets the URL.
+    pub fn set_url<I>(mut self, url: I) -> Self
+    where
+        I: Into<String>,
+    {
+        self.inner.url = Some(url.into());
+        self
+    }
 }
@@ -3,11 +3,15 @@ use std::str::FromStr;
 use crate::header::record::value::map::tag::{self, ID, IDX};
 
 const LENGTH: &str = "length";
+const MD5: &str = "md5";
+const URL: &str = "URL";
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum Standard {
     Id,
     Length,
+    Md5,
+    Url,
     Idx,
 }
 
@@ -18,6 +22,8 @@ impl AsRef<str> for Standard {
         match self {
             Self::Id => ID,
             Self::Length => LENGTH,
+            Self::Md5 => MD5,
+            Self::Url => URL,
             Self::Idx => IDX,
         }
     }
@@ -30,6 +36,8 @@ impl FromStr for Standard {
         match s {
             ID => Ok(Self::Id),
             LENGTH => Ok(Self::Length),
+            MD5 => Ok(Self::Md5),
+            URL => Ok(Self::Url),
             IDX => Ok(Self::Idx),
             _ => Err(()),
         }
@@ -44,6 +52,8 @@ mod tests {
     fn test_as_ref_str_for_standard() {
         assert_eq!(Standard::Id.as_ref(), "ID");
         assert_eq!(Standard::Length.as_ref(), "length");
+        assert_eq!(Standard::Md5.as_ref(), "md5");
+        assert_eq!(Standard::Url.as_ref(), "URL");
         assert_eq!(Standard::Idx.as_ref(), "IDX");
     }
 }
@@ -0,0 +1,229 @@
+mod builder;
+mod tag;
+
+use std::fmt;
+
+use super::{Fields, Inner, Map, TryFromFieldsError};
+
+type StandardTag = tag::Standard;
+type Tag = super::tag::Tag<StandardTag>;
+
+/// An inner VCF header pedigree map value.
+///
+/// A pedigree record describes the relationships of a sample, e.g., its parents in a trio
+/// (`Father` and `Mother`) or the sample it is derived from (`Original`).
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Pedigree {
+    father: Option<String>,
+    mother: Option<String>,
+    original: Option<String>,
+}
+
+impl Inner for Pedigree {
+    type StandardTag = StandardTag;
+    type Builder = builder::Builder;
+}
+
+impl Map<Pedigree> {
+    /// Creates a VCF header pedigree map value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::header::record::value::{map::Pedigree, Map};
+    /// let map = Map::<Pedigree>::new();
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the father sample ID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::header::record::value::{map::Pedigree, Map};
+    /// let map = Map::<Pedigree>::builder().set_father("sample1").build()?;
+    /// assert_eq!(map.father(), Some("sample1"));
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn father(&self) -> Option<&str> {
+        self.inner.father.as_deref()
+    }
+
+    /// Returns a mutable reference to the father sample ID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::header::record::value::{map::Pedigree, Map};
+    /// let mut map = Map::<Pedigree>::new();
+    /// *map.father_mut() = Some(String::from("sample1"));
+    /// assert_eq!(map.father(), Some("sample1"));
+    /// ```
+    pub fn father_mut(&mut self) -> &mut Option<String> {
+        &mut self.inner.father
+    }
+
+    /// Returns the mother sample ID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::header::record::value::{map::Pedigree, Map};
+    /// let map = Map::<Pedigree>::builder().set_mother("sample2").build()?;
+    /// assert_eq!(map.mother(), Some("sample2"));
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn mother(&self) -> Option<&str> {
+        self.inner.mother.as_deref()
+    }
+
+    /// Returns a mutable reference to the mother sample ID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::header::record::value::{map::Pedigree, Map};
+    /// let mut map = Map::<Pedigree>::new();
+    /// *map.mother_mut() = Some(String::from("sample2"));
+    /// assert_eq!(map.mother(), Some("sample2"));
+    /// ```
+    pub fn mother_mut(&mut self) -> &mut Option<String> {
+        &mut self.inner.mother
+    }
+
+    /// Returns the original sample ID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::header::record::value::{map::Pedigree, Map};
+    /// let map = Map::<Pedigree>::builder().set_original("germline").build()?;
+    /// assert_eq!(map.original(), Some("germline"));
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn original(&self) -> Option<&str> {
+        self.inner.original.as_deref()
+    }
+
+    /// Returns a mutable reference to the original sample ID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::header::record::value::{map::Pedigree, Map};
+    /// let mut map = Map::<Pedigree>::new();
+    /// *map.original_mut() = Some(String::from("germline"));
+    /// assert_eq!(map.original(), Some("germline"));
+    /// ```
+    pub fn original_mut(&mut self) -> &mut Option<String> {
+        &mut self.inner.original
+    }
+}
+
+impl fmt::Display for Map<Pedigree> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(father) = self.father() {
+            write!(f, ",{}={}", StandardTag::Father.as_ref(), father)?;
+        }
+
+        if let Some(mother) = self.mother() {
+            write!(f, ",{}={}", StandardTag::Mother.as_ref(), mother)?;
+        }
+
+        if let Some(original) = self.original() {
+            write!(f, ",{}={}", StandardTag::Original.as_ref(), original)?;
+        }
+
+        super::fmt_display_other_fields(f, self.other_fields())?;
+
+        Ok(())
+    }
+}
+
+impl TryFrom<Fields> for Map<Pedigree> {
+    type Error = TryFromFieldsError;
+
+    fn try_from(fields: Fields) -> Result<Self, Self::Error> {
+        let mut other_fields = super::init_other_fields();
+
+        let mut father = None;
+        let mut mother = None;
+        let mut original = None;
+
+        for (key, value) in fields {
+            match Tag::from(key) {
+                Tag::Standard(StandardTag::Id) => return Err(TryFromFieldsError::DuplicateTag),
+                Tag::Standard(StandardTag::Father) => parse_sample_id(value, &mut father)?,
+                Tag::Standard(StandardTag::Mother) => parse_sample_id(value, &mut mother)?,
+                Tag::Standard(StandardTag::Original) => parse_sample_id(value, &mut original)?,
+                Tag::Other(t) => super::insert_other_field(&mut other_fields, t, value)?,
+            }
+        }
+
+        Ok(Self {
+            inner: Pedigree {
+                father,
+                mother,
+                original,
+            },
+            other_fields,
+        })
+    }
+}
+
+fn parse_sample_id(s: String, id: &mut Option<String>) -> Result<(), TryFromFieldsError> {
+    if id.replace(s).is_none() {
+        Ok(())
+    } else {
+        Err(TryFromFieldsError::DuplicateTag)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fmt() -> Result<(), Box<dyn std::error::Error>> {
+        let map = Map::<Pedigree>::builder()
+            .set_father("sample1")
+            .set_mother("sample2")
+            .build()?;
+
+        assert_eq!(map.to_string(), ",Father=sample1,Mother=sample2");
+
+        let map = Map::<Pedigree>::builder()
+            .set_original("germline")
+            .build()?;
+        assert_eq!(map.to_string(), ",Original=germline");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_try_from_fields_for_map_pedigree() -> Result<(), Box<dyn std::error::Error>> {
+        let actual = Map::<Pedigree>::try_from(vec![
+            (String::from("Father"), String::from("sample1")),
+            (String::from("Mother"), String::from("sample2")),
+        ])?;
+
+        let expected = Map::<Pedigree>::builder()
+            .set_father("sample1")
+            .set_mother("sample2")
+            .build()?;
+
+        assert_eq!(actual, expected);
+
+        assert_eq!(
+            Map::<Pedigree>::try_from(vec![
+                (String::from("Father"), String::from("sample1")),
+                (String::from("Father"), String::from("sample2")),
+            ]),
+            Err(TryFromFieldsError::DuplicateTag)
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,48 @@
+use super::Pedigree;
+use crate::header::record::value::map::{self, builder::BuildError};
+
+#[derive(Default)]
+pub struct Builder {
+    father: Option<String>,
+    mother: Option<String>,
+    original: Option<String>,
+}
+
+impl map::builder::Inner<Pedigree> for Builder {
+    fn build(self) -> Result<Pedigree, BuildError> {
+        Ok(Pedigree {
+            father: self.father,
+            mother: self.mother,
+            original: self.original,
+        })
+    }
+}
+
+impl map::Builder<Pedigree> {
+    /// Sets the father sample ID.
+    pub fn set_father<I>(mut self, father: I) -> Self
+    where
+        I: Into<String>,
+    {
+        self.inner.father = Some(father.into());
+        self
+    }
+
+    /// Sets the mother sample ID.
+    pub fn set_mother<I>(mut self, mother: I) -> Self
+    where
+        I: Into<String>,
+    {
+        self.inner.mother = Some(mother.into());
+        self
+    }
+
+    /// Sets the original sample ID.
+    pub fn set_original<I>(mut self, original: I) -> Self
+    where
+        I: Into<String>,
+    {
+        self.inner.original = Some(original.into());
+        self
+    }
+}
@@ -0,0 +1,55 @@
+use std::str::FromStr;
+
+use crate::header::record::value::map::tag::{self, ID};
+
+const FATHER: &str = "Father";
+const MOTHER: &str = "Mother";
+const ORIGINAL: &str = "Original";
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Standard {
+    Id,
+    Father,
+    Mother,
+    Original,
+}
+
+impl tag::Standard for Standard {}
+
+impl AsRef<str> for Standard {
+    fn as_ref(&self) -> &str {
+        match self {
+            Self::Id => ID,
+            Self::Father => FATHER,
+            Self::Mother => MOTHER,
+            Self::Original => ORIGINAL,
+        }
+    }
+}
+
+impl FromStr for Standard {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            ID => Ok(Self::Id),
+            FATHER => Ok(Self::Father),
+            MOTHER => Ok(Self::Mother),
+            ORIGINAL => Ok(Self::Original),
+            _ => Err(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_as_ref_str_for_standard() {
+        assert_eq!(Standard::Id.as_ref(), "ID");
+        assert_eq!(Standard::Father.as_ref(), "Father");
+        assert_eq!(Standard::Mother.as_ref(), "Mother");
+        assert_eq!(Standard::Original.as_ref(), "Original");
+    }
+}
@@ -0,0 +1,140 @@
+mod builder;
+
+use std::fmt;
+
+use super::{tag, Fields, Inner, Map, TryFromFieldsError};
+
+type StandardTag = tag::Described;
+type Tag = tag::Tag<StandardTag>;
+
+/// An inner VCF header sample map value.
+///
+/// Sample metadata fields other than the description, e.g., `Assay` or `Disease`, are stored as
+/// other fields.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Sample {
+    description: Option<String>,
+}
+
+impl Inner for Sample {
+    type StandardTag = StandardTag;
+    type Builder = builder::Builder;
+}
+
+impl Map<Sample> {
+    /// Creates a VCF header sample map value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::header::record::value::{map::Sample, Map};
+    /// let map = Map::<Sample>::new();
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the description.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::header::record::value::{map::Sample, Map};
+    /// let map = Map::<Sample>::builder().set_description("Tumor").build()?;
+    /// assert_eq!(map.description(), Some("Tumor"));
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn description(&self) -> Option<&str> {
+        self.inner.description.as_deref()
+    }
+
+    /// Returns a mutable reference to the description.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::header::record::value::{map::Sample, Map};
+    /// let mut map = Map::<Sample>::new();
+    /// *map.description_mut() = Some(String::from("Tumor"));
+    /// assert_eq!(map.description(), Some("Tumor"));
+    /// ```
+    pub fn description_mut(&mut self) -> &mut Option<String> {
+        &mut self.inner.description
+    }
+}
+
+impl fmt::Display for Map<Sample> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(description) = self.description() {
+            super::fmt_display_description_field(f, description)?;
+        }
+
+        super::fmt_display_other_fields(f, self.other_fields())?;
+
+        Ok(())
+    }
+}
+
+impl TryFrom<Fields> for Map<Sample> {
+    type Error = TryFromFieldsError;
+
+    fn try_from(fields: Fields) -> Result<Self, Self::Error> {
+        let mut other_fields = super::init_other_fields();
+
+        let mut description = None;
+
+        for (key, value) in fields {
+            match Tag::from(key) {
+                Tag::Standard(StandardTag::Id) => return Err(TryFromFieldsError::DuplicateTag),
+                Tag::Standard(StandardTag::Description) => {
+                    super::parse_description(value, &mut description)?
+                }
+                Tag::Other(t) => super::insert_other_field(&mut other_fields, t, value)?,
+            }
+        }
+
+        Ok(Self {
+            inner: Sample { description },
+            other_fields,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fmt() -> Result<(), Box<dyn std::error::Error>> {
+        let map = Map::<Sample>::new();
+        assert!(map.to_string().is_empty());
+
+        let map = Map::<Sample>::try_from(vec![
+            (String::from("Description"), String::from("Tumor")),
+            (String::from("Assay"), String::from("WholeGenome")),
+        ])?;
+
+        assert_eq!(
+            map.to_string(),
+            r#",Description="Tumor",Assay="WholeGenome""#
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_try_from_fields_for_map_sample() -> Result<(), Box<dyn std::error::Error>> {
+        let actual = Map::<Sample>::try_from(vec![
+            (String::from("Assay"), String::from("WholeGenome")),
+            (String::from("Description"), String::from("Tumor")),
+        ])?;
+
+        assert_eq!(actual.description(), Some("Tumor"));
+        assert_eq!(
+            actual.other_fields().get("Assay").map(|s| s.as_str()),
+            Some("WholeGenome")
+        );
+
+        Ok(())
+    }
+}
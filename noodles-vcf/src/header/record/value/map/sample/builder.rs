@@ -0,0 +1,26 @@
+use super::Sample;
+use crate::header::record::value::map::{self, builder::BuildError};
+
+#[derive(Default)]
+pub struct Builder {
+    description: Option<String>,
+}
+
+impl map::builder::Inner<Sample> for Builder {
+    fn build(self) -> Result<Sample, BuildError> {
+        Ok(Sample {
+            description: self.description,
+        })
+    }
+}
+
+impl map::Builder<Sample> {
+    /// Sets the description.
+    pub fn set_description<D>(mut self, description: D) -> Self
+    where
+        D: Into<String>,
+    {
+        self.inner.description = Some(description.into());
+        self
+    }
+}
@@ -0,0 +1,293 @@
+//! VCF header reference sequence verification.
+
+use std::{error, fmt, io};
+
+use md5::{Digest, Md5};
+use noodles_fasta as fasta;
+
+use super::Header;
+
+/// An error returned when VCF header contigs do not match the reference sequences.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum VerifyContigsError {
+    /// A contig is not in the reference sequences.
+    MissingReferenceSequence(String),
+    /// The length of a contig does not match the length of its reference sequence.
+    LengthMismatch {
+        /// The contig name.
+        name: String,
+        /// The contig length in the header.
+        actual: usize,
+        /// The reference sequence length.
+        expected: usize,
+    },
+    /// The MD5 checksum of a contig does not match the checksum of its reference sequence.
+    Md5ChecksumMismatch {
+        /// The contig name.
+        name: String,
+        /// The contig MD5 checksum in the header.
+        actual: String,
+        /// The reference sequence MD5 checksum.
+        expected: String,
+    },
+}
+
+impl error::Error for VerifyContigsError {}
+
+impl fmt::Display for VerifyContigsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingReferenceSequence(name) => write!(f, "missing reference sequence: {name}"),
+            Self::LengthMismatch {
+                name,
+                actual,
+                expected,
+            } => write!(
+                f,
+                "length mismatch for {name}: expected {expected}, got {actual}"
+            ),
+            Self::Md5ChecksumMismatch {
+                name,
+                actual,
+                expected,
+            } => write!(
+                f,
+                "MD5 checksum mismatch for {name}: expected {expected}, got {actual}"
+            ),
+        }
+    }
+}
+
+/// Verifies the header contigs against a FASTA index.
+///
+/// Each contig must be in the index, and if the contig has a length, it must match the length of
+/// the reference sequence. Reference sequences that are not in the header are ignored.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_fasta::fai;
+/// use noodles_vcf::{
+///     self as vcf,
+///     header::{
+///         self,
+///         record::value::{map::Contig, Map},
+///     },
+/// };
+///
+/// let header = vcf::Header::builder()
+///     .add_contig("sq0".parse()?, Map::<Contig>::builder().set_length(8).build()?)
+///     .build();
+///
+/// let index = vec![fai::Record::new(String::from("sq0"), 8, 5, 8, 9)];
+/// header::verify_contigs(&header, &index)?;
+///
+/// let index = vec![fai::Record::new(String::from("sq0"), 13, 5, 13, 14)];
+/// assert!(header::verify_contigs(&header, &index).is_err());
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn verify_contigs(
+    header: &Header,
+    index: &fasta::fai::Index,
+) -> Result<(), VerifyContigsError> {
+    for (name, contig) in header.contigs() {
+        let name = name.as_ref();
+
+        let record = index
+            .iter()
+            .find(|record| record.name() == name)
+            .ok_or_else(|| VerifyContigsError::MissingReferenceSequence(name.into()))?;
+
+        if let Some(actual) = contig.length() {
+            // An index record length that does not fit in a usize cannot match.
+            let expected = usize::try_from(record.len()).unwrap_or(usize::MAX);
+
+            if actual != expected {
+                return Err(VerifyContigsError::LengthMismatch {
+                    name: name.into(),
+                    actual,
+                    expected,
+                });
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Verifies the MD5 checksums of the header contigs against reference sequences.
+///
+/// The checksum of a reference sequence is calculated as described in _Sequence Alignment/Map
+/// Format Specification_ § 1.3.2 "Reference MD5 calculation". Only contigs with an MD5 checksum
+/// are verified, and reference sequences that are not in the header are ignored.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_fasta as fasta;
+/// use noodles_vcf::{
+///     self as vcf,
+///     header::{
+///         self,
+///         record::value::{map::Contig, Map},
+///     },
+/// };
+///
+/// let header = vcf::Header::builder()
+///     .add_contig(
+///         "sq0".parse()?,
+///         Map::<Contig>::builder()
+///             .set_md5("f1f8f4bf413b16ad135722aa4591043e")
+///             .build()?,
+///     )
+///     .build();
+///
+/// let data = b">sq0\nacgt\n";
+/// let mut reader = fasta::Reader::new(&data[..]);
+///
+/// header::verify_contig_checksums(&header, reader.records())?;
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+///
+/// # Errors
+///
+/// An error of kind [`io::ErrorKind::InvalidData`] is returned if a checksum does not match.
+pub fn verify_contig_checksums<I>(header: &Header, records: I) -> io::Result<()>
+where
+    I: IntoIterator<Item = io::Result<fasta::Record>>,
+{
+    for result in records {
+        let record = result?;
+
+        let actual = match header
+            .contigs()
+            .get(record.name())
+            .and_then(|contig| contig.md5())
+        {
+            Some(md5) => md5,
+            None => continue,
+        };
+
+        let expected = hex_digest(record.sequence().as_ref());
+
+        if !actual.eq_ignore_ascii_case(&expected) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                VerifyContigsError::Md5ChecksumMismatch {
+                    name: record.name().into(),
+                    actual: actual.into(),
+                    expected,
+                },
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+// _Sequence Alignment/Map Format Specification_ (2021-06-03) § 1.3.2 "Reference MD5 calculation"
+fn hex_digest(sequence: &[u8]) -> String {
+    const CHUNK_SIZE: usize = 8192;
+
+    let mut hasher = Md5::new();
+    let mut buf = Vec::with_capacity(CHUNK_SIZE);
+
+    for chunk in sequence.chunks(CHUNK_SIZE) {
+        buf.clear();
+        buf.extend(
+            chunk
+                .iter()
+                .filter(|b| b.is_ascii_graphic())
+                .map(|b| b.to_ascii_uppercase()),
+        );
+
+        hasher.update(&buf);
+    }
+
+    format!("{:x}", hasher.finalize())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::header::record::value::{map::Contig, Map};
+
+    #[test]
+    fn test_verify_contigs() -> Result<(), Box<dyn std::error::Error>> {
+        use fasta::fai;
+
+        let header = Header::builder()
+            .add_contig(
+                "sq0".parse()?,
+                Map::<Contig>::builder().set_length(8).build()?,
+            )
+            .add_contig("sq1".parse()?, Map::<Contig>::new())
+            .build();
+
+        let index = vec![
+            fai::Record::new(String::from("sq0"), 8, 5, 8, 9),
+            fai::Record::new(String::from("sq1"), 13, 19, 13, 14),
+            fai::Record::new(String::from("sq2"), 21, 38, 21, 22),
+        ];
+
+        assert_eq!(verify_contigs(&header, &index), Ok(()));
+
+        let index = vec![fai::Record::new(String::from("sq0"), 8, 5, 8, 9)];
+
+        assert_eq!(
+            verify_contigs(&header, &index),
+            Err(VerifyContigsError::MissingReferenceSequence(String::from(
+                "sq1"
+            )))
+        );
+
+        let index = vec![
+            fai::Record::new(String::from("sq0"), 13, 5, 13, 14),
+            fai::Record::new(String::from("sq1"), 13, 24, 13, 14),
+        ];
+
+        assert_eq!(
+            verify_contigs(&header, &index),
+            Err(VerifyContigsError::LengthMismatch {
+                name: String::from("sq0"),
+                actual: 8,
+                expected: 13,
+            })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_verify_contig_checksums() -> Result<(), Box<dyn std::error::Error>> {
+        let header = Header::builder()
+            .add_contig(
+                "sq0".parse()?,
+                Map::<Contig>::builder()
+                    .set_md5("F1F8F4BF413B16AD135722AA4591043E")
+                    .build()?,
+            )
+            .add_contig("sq1".parse()?, Map::<Contig>::new())
+            .build();
+
+        let data = b">sq0\nAC\ngt\n>sq1\nNNNN\n";
+        let mut reader = fasta::Reader::new(&data[..]);
+        verify_contig_checksums(&header, reader.records())?;
+
+        let data = b">sq0\nACGA\n";
+        let mut reader = fasta::Reader::new(&data[..]);
+
+        assert!(matches!(
+            verify_contig_checksums(&header, reader.records()),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_hex_digest() {
+        assert_eq!(hex_digest(b"ACGT"), "f1f8f4bf413b16ad135722aa4591043e");
+        assert_eq!(hex_digest(b"ac gt\n"), "f1f8f4bf413b16ad135722aa4591043e");
+    }
+}
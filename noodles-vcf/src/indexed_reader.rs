@@ -0,0 +1,267 @@
+//! Indexed VCF reader.
+
+mod builder;
+
+pub use self::builder::Builder;
+
+use std::io::{self, Read, Seek};
+
+use noodles_bgzf as bgzf;
+use noodles_core::Region;
+use noodles_csi::{self as csi, BinningIndex};
+use noodles_tabix as tabix;
+
+use super::{
+    reader::{Query, Records},
+    Header, Reader,
+};
+
+/// An index associated with a VCF.
+#[derive(Debug)]
+pub enum Index {
+    /// A tabix index (`.tbi`).
+    Tabix(tabix::Index),
+    /// A coordinate-sorted index (`.csi`).
+    Csi(csi::Index),
+}
+
+impl From<tabix::Index> for Index {
+    fn from(index: tabix::Index) -> Self {
+        Self::Tabix(index)
+    }
+}
+
+impl From<csi::Index> for Index {
+    fn from(index: csi::Index) -> Self {
+        Self::Csi(index)
+    }
+}
+
+/// An indexed VCF reader.
+///
+/// This combines a bgzip-compressed VCF reader with its associated tabix or CSI index to allow
+/// querying records by region.
+///
+/// # Examples
+///
+/// ```no_run
+/// use noodles_vcf as vcf;
+///
+/// let mut reader = vcf::indexed_reader::Builder::default().build_from_path("sample.vcf.gz")?;
+/// let header = reader.read_header()?.parse()?;
+///
+/// let region = "sq0:8-13".parse()?;
+/// let query = reader.query(&header, &region)?;
+///
+/// for result in query {
+///     let record = result?;
+///     // ...
+/// }
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub struct IndexedReader<R> {
+    inner: Reader<bgzf::Reader<R>>,
+    index: Index,
+}
+
+impl<R> IndexedReader<R>
+where
+    R: Read,
+{
+    /// Creates an indexed VCF reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_tabix as tabix;
+    /// use noodles_vcf as vcf;
+    ///
+    /// let data = [];
+    /// let index = tabix::Index::default();
+    /// let reader = vcf::IndexedReader::new(&data[..], index);
+    /// ```
+    pub fn new<I>(inner: R, index: I) -> Self
+    where
+        I: Into<Index>,
+    {
+        Self {
+            inner: Reader::new(bgzf::Reader::new(inner)),
+            index: index.into(),
+        }
+    }
+
+    /// Returns a reference to the underlying reader.
+    pub fn get_ref(&self) -> &bgzf::Reader<R> {
+        self.inner.get_ref()
+    }
+
+    /// Returns a mutable reference to the underlying reader.
+    pub fn get_mut(&mut self) -> &mut bgzf::Reader<R> {
+        self.inner.get_mut()
+    }
+
+    /// Returns the underlying reader.
+    pub fn into_inner(self) -> bgzf::Reader<R> {
+        self.inner.into_inner()
+    }
+
+    /// Returns the associated index.
+    pub fn index(&self) -> &Index {
+        &self.index
+    }
+
+    /// Reads the raw VCF header.
+    pub fn read_header(&mut self) -> io::Result<String> {
+        self.inner.read_header()
+    }
+
+    /// Reads a single raw VCF record.
+    pub fn read_record(&mut self, buf: &mut String) -> io::Result<usize> {
+        self.inner.read_record(buf)
+    }
+
+    /// Returns an iterator over records starting from the current stream position.
+    pub fn records<'r, 'h>(&'r mut self, header: &'h Header) -> Records<'r, 'h, bgzf::Reader<R>> {
+        self.inner.records(header)
+    }
+}
+
+impl<R> IndexedReader<R>
+where
+    R: Read + Seek,
+{
+    /// Returns an iterator over records that intersect the given region.
+    ///
+    /// For a CSI index, reference sequence names are resolved using the names stored in the
+    /// index auxiliary data, if present; otherwise, the order of the contigs in the VCF header is
+    /// used.
+    pub fn query<'r, 'h>(
+        &'r mut self,
+        header: &'h Header,
+        region: &Region,
+    ) -> io::Result<Query<'r, 'h, R>> {
+        match &self.index {
+            Index::Tabix(index) => self.inner.query(header, index, region),
+            Index::Csi(index) => {
+                let reference_sequence_id = resolve_csi_region(header, index, region)?;
+                let chunks = index.query(reference_sequence_id, region.interval())?;
+
+                Ok(Query::new(
+                    &mut self.inner,
+                    chunks,
+                    region.name().into(),
+                    region.interval(),
+                    header,
+                ))
+            }
+        }
+    }
+}
+
+fn resolve_csi_region(header: &Header, index: &csi::Index, region: &Region) -> io::Result<usize> {
+    let reference_sequence_id = if index.aux().is_empty() {
+        header.contigs().get_index_of(region.name())
+    } else {
+        let reference_sequence_names = parse_aux_reference_sequence_names(index.aux())?;
+        reference_sequence_names.get_index_of(region.name())
+    };
+
+    reference_sequence_id.ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("region reference sequence does not exist in reference sequences: {region:?}"),
+        )
+    })
+}
+
+// The CSI auxiliary data of a VCF index is a tabix header.
+fn parse_aux_reference_sequence_names(
+    aux: &[u8],
+) -> io::Result<tabix::index::header::ReferenceSequenceNames> {
+    tabix::index::Header::try_from(aux).map(|header| header.reference_sequence_names().clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use noodles_core::Position;
+    use noodles_csi::index::reference_sequence::bin::Chunk;
+
+    use super::*;
+    use crate::{Record, Writer};
+
+    #[test]
+    fn test_query() -> Result<(), Box<dyn std::error::Error>> {
+        let header: Header = "\
+##fileformat=VCFv4.3
+##contig=<ID=sq0>
+##contig=<ID=sq1>
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+"
+        .parse()?;
+
+        let mut writer = Writer::new(bgzf::Writer::new(Vec::new()));
+        writer.write_header(&header)?;
+
+        let mut indexer = tabix::Index::indexer();
+
+        for (name, position) in [("sq0", 8), ("sq0", 13), ("sq1", 5)] {
+            let record = Record::builder()
+                .set_chromosome(name.parse()?)
+                .set_position(crate::record::Position::from(position))
+                .set_reference_bases("A".parse()?)
+                .build()?;
+
+            let start_position = writer.get_ref().virtual_position();
+            writer.write_record(&record)?;
+            let end_position = writer.get_ref().virtual_position();
+
+            let start = Position::try_from(position)?;
+            indexer.add_record(name, start, start, Chunk::new(start_position, end_position))?;
+        }
+
+        let data = writer.into_inner().finish()?;
+        let index = indexer.build();
+
+        let mut reader = IndexedReader::new(Cursor::new(data), index);
+        reader.read_header()?;
+
+        let region = "sq0:10-20".parse()?;
+        let positions: Vec<_> = reader
+            .query(&header, &region)?
+            .map(|result| result.map(|record| usize::from(record.position())))
+            .collect::<io::Result<_>>()?;
+
+        assert_eq!(positions, [13]);
+
+        let region = "sq2".parse()?;
+        assert!(reader.query(&header, &region).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_aux_reference_sequence_names() -> io::Result<()> {
+        let mut aux = Vec::new();
+
+        for n in [2, 1, 2, 0, i32::from(b'#'), 0] {
+            aux.extend(i32::to_le_bytes(n));
+        }
+
+        let names = b"sq0\x00sq1\x00";
+        aux.extend(i32::to_le_bytes(names.len() as i32));
+        aux.extend(names);
+
+        let actual = parse_aux_reference_sequence_names(&aux)?;
+        let expected: tabix::index::header::ReferenceSequenceNames =
+            [String::from("sq0"), String::from("sq1")]
+                .into_iter()
+                .collect();
+        assert_eq!(actual, expected);
+
+        assert!(parse_aux_reference_sequence_names(&[]).is_err());
+
+        Ok(())
+    }
+}
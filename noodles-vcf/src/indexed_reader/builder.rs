@@ -0,0 +1,104 @@
+use std::{
+    ffi::{OsStr, OsString},
+    fs::File,
+    io,
+    path::{Path, PathBuf},
+};
+
+use noodles_csi as csi;
+use noodles_tabix as tabix;
+
+use super::{Index, IndexedReader};
+
+/// An indexed VCF reader builder.
+#[derive(Default)]
+pub struct Builder {
+    index: Option<Index>,
+}
+
+impl Builder {
+    /// Sets an index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_tabix as tabix;
+    /// use noodles_vcf::indexed_reader::Builder;
+    ///
+    /// let index = tabix::Index::default();
+    /// let builder = Builder::default().set_index(index);
+    /// ```
+    pub fn set_index<I>(mut self, index: I) -> Self
+    where
+        I: Into<Index>,
+    {
+        self.index = Some(index.into());
+        self
+    }
+
+    /// Builds an indexed VCF reader from a path.
+    ///
+    /// If no index is set, a tabix index (`<src>.tbi`) is read. If it does not exist, a CSI
+    /// index (`<src>.csi`) is read instead.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use noodles_vcf::indexed_reader::Builder;
+    /// let reader = Builder::default().build_from_path("sample.vcf.gz")?;
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn build_from_path<P>(self, src: P) -> io::Result<IndexedReader<File>>
+    where
+        P: AsRef<Path>,
+    {
+        let src = src.as_ref();
+
+        let index = match self.index {
+            Some(index) => index,
+            None => read_associated_index(src)?,
+        };
+
+        let file = File::open(src)?;
+
+        Ok(IndexedReader::new(file, index))
+    }
+}
+
+fn read_associated_index<P>(src: P) -> io::Result<Index>
+where
+    P: AsRef<Path>,
+{
+    let src = src.as_ref();
+
+    match tabix::read(push_ext(src.into(), "tbi")) {
+        Ok(index) => Ok(Index::Tabix(index)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {
+            csi::read(push_ext(src.into(), "csi")).map(Index::Csi)
+        }
+        Err(e) => Err(e),
+    }
+}
+
+fn push_ext<S>(path: PathBuf, ext: S) -> PathBuf
+where
+    S: AsRef<OsStr>,
+{
+    let mut s = OsString::from(path);
+    s.push(".");
+    s.push(ext);
+    PathBuf::from(s)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_push_ext() {
+        assert_eq!(
+            push_ext(PathBuf::from("sample.vcf.gz"), "tbi"),
+            PathBuf::from("sample.vcf.gz.tbi")
+        );
+    }
+}
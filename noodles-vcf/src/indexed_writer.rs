@@ -0,0 +1,202 @@
+//! Indexed VCF writer.
+
+mod builder;
+
+pub use self::builder::Builder;
+
+use std::{
+    fs::File,
+    io::{self, Write},
+    mem,
+    path::PathBuf,
+};
+
+use noodles_bgzf as bgzf;
+use noodles_core::Position;
+use noodles_csi::index::reference_sequence::bin::Chunk;
+use noodles_tabix as tabix;
+
+use super::{Header, Record, Writer};
+
+/// An indexed VCF writer.
+///
+/// This writes a bgzip-compressed VCF and simultaneously builds its tabix index.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_vcf::{self as vcf, record::Position};
+///
+/// let mut writer = vcf::IndexedWriter::new(Vec::new());
+///
+/// let header = vcf::Header::builder()
+///     .add_contig("sq0".parse()?, Default::default())
+///     .build();
+///
+/// writer.write_header(&header)?;
+///
+/// let record = vcf::Record::builder()
+///     .set_chromosome("sq0".parse()?)
+///     .set_position(Position::from(8))
+///     .set_reference_bases("A".parse()?)
+///     .build()?;
+///
+/// writer.write_record(&record)?;
+///
+/// let index = writer.finish()?;
+/// assert_eq!(index.header().reference_sequence_names().len(), 1);
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub struct IndexedWriter<W>
+where
+    W: Write,
+{
+    inner: Writer<bgzf::Writer<W>>,
+    indexer: tabix::index::Indexer,
+    index_dst: Option<PathBuf>,
+}
+
+impl<W> IndexedWriter<W>
+where
+    W: Write,
+{
+    /// Creates an indexed VCF writer.
+    ///
+    /// The output is compressed using BGZF.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf as vcf;
+    /// let writer = vcf::IndexedWriter::new(Vec::new());
+    /// ```
+    pub fn new(inner: W) -> Self {
+        Self::with_index_dst(inner, None)
+    }
+
+    pub(crate) fn with_index_dst(inner: W, index_dst: Option<PathBuf>) -> Self {
+        let mut indexer = tabix::Index::indexer();
+        indexer.set_header(tabix::index::header::Builder::vcf().build());
+
+        Self {
+            inner: Writer::new(bgzf::Writer::new(inner)),
+            indexer,
+            index_dst,
+        }
+    }
+
+    /// Returns a reference to the underlying BGZF writer.
+    pub fn get_ref(&self) -> &bgzf::Writer<W> {
+        self.inner.get_ref()
+    }
+
+    /// Writes a VCF header.
+    pub fn write_header(&mut self, header: &Header) -> io::Result<()> {
+        self.inner.write_header(header)
+    }
+
+    /// Writes a VCF record and adds it to the index.
+    ///
+    /// Records must be written in coordinate-sorted order.
+    pub fn write_record(&mut self, record: &Record) -> io::Result<()> {
+        let start_position = self.inner.get_ref().virtual_position();
+        self.inner.write_record(record)?;
+        let end_position = self.inner.get_ref().virtual_position();
+
+        let (start, end) = resolve_interval(record)?;
+        let chunk = Chunk::new(start_position, end_position);
+
+        self.indexer
+            .add_record(record.chromosome().to_string().as_str(), start, end, chunk)
+    }
+
+    /// Finishes the output stream and builds the index.
+    ///
+    /// This writes the final BGZF blocks, including the EOF block. If the writer was built from
+    /// a path, the index is also written to its destination (`<dst>.tbi`, by default).
+    ///
+    /// No records should be written after the writer is finished.
+    pub fn finish(&mut self) -> io::Result<tabix::Index> {
+        self.inner.get_mut().try_finish()?;
+
+        let index = mem::take(&mut self.indexer).build();
+
+        if let Some(dst) = self.index_dst.take() {
+            let mut writer = File::create(dst).map(tabix::Writer::new)?;
+            writer.write_index(&index)?;
+        }
+
+        Ok(index)
+    }
+}
+
+fn resolve_interval(record: &Record) -> io::Result<(Position, Position)> {
+    let start = Position::try_from(usize::from(record.position()))
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+    let end = record
+        .end()
+        .map(usize::from)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))
+        .and_then(|n| {
+            Position::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))
+        })?;
+
+    Ok((start, end))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+    use crate::{record::Position as RecordPosition, IndexedReader};
+
+    #[test]
+    fn test_finish() -> Result<(), Box<dyn std::error::Error>> {
+        let header: Header = "\
+##fileformat=VCFv4.3
+##contig=<ID=sq0>
+##contig=<ID=sq1>
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+"
+        .parse()?;
+
+        let mut writer = IndexedWriter::new(Vec::new());
+        writer.write_header(&header)?;
+
+        for (name, position) in [("sq0", 8), ("sq0", 13), ("sq1", 5)] {
+            let record = Record::builder()
+                .set_chromosome(name.parse()?)
+                .set_position(RecordPosition::from(position))
+                .set_reference_bases("ACGT".parse()?)
+                .build()?;
+
+            writer.write_record(&record)?;
+        }
+
+        let index = writer.finish()?;
+        let data = writer.get_ref().get_ref().clone();
+
+        let reference_sequence_names: Vec<_> = index
+            .header()
+            .reference_sequence_names()
+            .iter()
+            .cloned()
+            .collect();
+        assert_eq!(reference_sequence_names, ["sq0", "sq1"]);
+
+        let mut reader = IndexedReader::new(Cursor::new(data), index);
+        reader.read_header()?;
+
+        let region = "sq0:10-20".parse()?;
+        let positions: Vec<_> = reader
+            .query(&header, &region)?
+            .map(|result| result.map(|record| usize::from(record.position())))
+            .collect::<io::Result<_>>()?;
+
+        assert_eq!(positions, [8, 13]);
+
+        Ok(())
+    }
+}
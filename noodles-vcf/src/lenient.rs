@@ -0,0 +1,605 @@
+//! Tolerant VCF parsing.
+//!
+//! Many real-world VCFs do not strictly follow the specification. Lenient parsing accepts common
+//! violations and reports each one as a [`Warning`] to a callback instead of failing:
+//!
+//!   * header records with unquoted descriptions or whitespace between fields are repaired, and
+//!     header records that cannot be repaired are skipped;
+//!   * whitespace in INFO fields is accepted regardless of the file format;
+//!   * an invalid quality score, e.g., a negative value, is treated as missing;
+//!   * a chromosome that is not in the header contigs is renamed when adding or removing a `chr`
+//!     prefix matches a contig; and
+//!   * an INFO key that is not defined in the header is treated as a `String` (or `Flag`, when it
+//!     has no value).
+
+mod records;
+
+pub use self::records::Records;
+
+use std::fmt;
+
+use crate::{
+    header::{
+        self, info::Key, parser::ParseError, record::value::map::info::Type, FileFormat, Number,
+    },
+    record::{self, info, QualityScore, FIELD_DELIMITER, MISSING_FIELD},
+    Header, Record,
+};
+
+/// A warning for a spec violation accepted by lenient parsing.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Warning {
+    /// A header record is invalid and was repaired.
+    ///
+    /// This includes the original line.
+    RepairedHeaderRecord(String),
+    /// A header record is invalid and was skipped.
+    ///
+    /// This includes the original line.
+    SkippedHeaderRecord(String),
+    /// The INFO field has whitespace, which is only allowed as of VCF 4.4.
+    InfoWhitespace,
+    /// The quality score is invalid and was set to missing.
+    ///
+    /// This includes the raw quality score.
+    InvalidQualityScore(String),
+    /// The chromosome is not in the header contigs and was renamed to a matching contig.
+    RenamedChromosome {
+        /// The original chromosome name.
+        from: String,
+        /// The contig name.
+        to: String,
+    },
+    /// An INFO key is not defined in the header.
+    ///
+    /// This is only reported for the first occurrence of the key.
+    UndefinedInfoKey(Key),
+}
+
+impl fmt::Display for Warning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::RepairedHeaderRecord(line) => write!(f, "repaired header record: {line}"),
+            Self::SkippedHeaderRecord(line) => write!(f, "skipped header record: {line}"),
+            Self::InfoWhitespace => f.write_str("whitespace in INFO field"),
+            Self::InvalidQualityScore(s) => write!(f, "invalid quality score: {s}"),
+            Self::RenamedChromosome { from, to } => {
+                write!(f, "renamed chromosome: {from} => {to}")
+            }
+            Self::UndefinedInfoKey(key) => write!(f, "undefined INFO key: {key}"),
+        }
+    }
+}
+
+/// Parses a raw VCF header, repairing or skipping invalid header records.
+///
+/// Header records are repaired by quoting an unquoted description and by removing whitespace
+/// between fields. Errors that are not from individual header records, e.g., a missing file
+/// format, are not tolerated.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_vcf::lenient::{self, Warning};
+///
+/// let s = "##fileformat=VCFv4.3
+/// ###INFO=<ID=NS, Number=1, Type=Integer, Description=Number of samples with data>
+/// #CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+/// ";
+///
+/// let mut warnings = Vec::new();
+/// let header = lenient::parse_header(s, |warning| warnings.push(warning))?;
+///
+/// assert_eq!(header.infos().len(), 1);
+/// assert!(matches!(warnings[..], [Warning::RepairedHeaderRecord(_)]));
+/// # Ok::<_, noodles_vcf::header::ParseError>(())
+/// ```
+pub fn parse_header<F>(s: &str, mut on_warning: F) -> Result<Header, ParseError>
+where
+    F: FnMut(Warning),
+{
+    let mut lines = s.lines();
+
+    let file_format = match lines.next().map(|line| line.parse()) {
+        Some(Ok(header::Record::FileFormat(file_format))) => file_format,
+        // Defer to the strict parser for the error.
+        _ => return header::Parser::default().parse(s),
+    };
+
+    let mut dst = String::with_capacity(s.len());
+    dst.push_str(s.lines().next().unwrap_or_default());
+    dst.push('\n');
+
+    for line in &mut lines {
+        if !line.starts_with("##") {
+            dst.push_str(line);
+            dst.push('\n');
+            break;
+        }
+
+        if header::Record::try_from((file_format, line)).is_ok() {
+            dst.push_str(line);
+        } else {
+            let repaired_line = match repair_header_record(line) {
+                Some(repaired_line)
+                    if header::Record::try_from((file_format, repaired_line.as_str())).is_ok() =>
+                {
+                    repaired_line
+                }
+                _ => {
+                    on_warning(Warning::SkippedHeaderRecord(line.into()));
+                    continue;
+                }
+            };
+
+            on_warning(Warning::RepairedHeaderRecord(line.into()));
+            dst.push_str(&repaired_line);
+        }
+
+        dst.push('\n');
+    }
+
+    for line in lines {
+        dst.push_str(line);
+        dst.push('\n');
+    }
+
+    header::Parser::default().parse(&dst)
+}
+
+// Repairs a structured header record, i.e., `##key=<...>`.
+fn repair_header_record(line: &str) -> Option<String> {
+    const DESCRIPTION: &str = "Description";
+
+    let (prefix, rest) = line.split_once("=<")?;
+    let fields = rest.strip_suffix('>')?;
+
+    let mut dst = String::with_capacity(line.len() + 2);
+    dst.push_str(prefix);
+    dst.push_str("=<");
+
+    let mut in_quotes = false;
+    let mut is_escaped = false;
+    let mut i = 0;
+
+    while let Some(c) = fields[i..].chars().next() {
+        if in_quotes {
+            dst.push(c);
+
+            if is_escaped {
+                is_escaped = false;
+            } else if c == '\\' {
+                is_escaped = true;
+            } else if c == '"' {
+                in_quotes = false;
+            }
+
+            i += c.len_utf8();
+            continue;
+        }
+
+        let is_field_start = i == 0 || dst.ends_with(',');
+
+        if is_field_start {
+            if let Some((tag, len)) = parse_tag(&fields[i..]) {
+                dst.push_str(tag);
+                dst.push('=');
+                i += len;
+
+                let value_start = i + (fields[i..].len() - fields[i..].trim_start().len());
+
+                if tag == DESCRIPTION && !fields[value_start..].starts_with('"') {
+                    let end = find_field_end(fields, value_start);
+                    let value = fields[value_start..end].trim_end();
+
+                    dst.push('"');
+                    dst.push_str(&value.replace('\\', "\\\\").replace('"', "\\\""));
+                    dst.push('"');
+
+                    i = end;
+                } else {
+                    i = value_start;
+                }
+
+                continue;
+            }
+        }
+
+        match c {
+            '"' => {
+                in_quotes = true;
+                dst.push(c);
+            }
+            ',' => {
+                let len = dst.trim_end().len();
+                dst.truncate(len);
+                dst.push(c);
+
+                i += c.len_utf8();
+                i += fields[i..].len() - fields[i..].trim_start().len();
+                continue;
+            }
+            _ => dst.push(c),
+        }
+
+        i += c.len_utf8();
+    }
+
+    let len = dst.trim_end().len();
+    dst.truncate(len);
+    dst.push('>');
+
+    Some(dst)
+}
+
+// Parses a field tag, allowing whitespace around the separator (`=`).
+//
+// This returns the tag and the number of bytes read, including the separator.
+fn parse_tag(s: &str) -> Option<(&str, usize)> {
+    let trimmed = s.trim_start();
+    let offset = s.len() - trimmed.len();
+
+    let len = trimmed
+        .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+        .unwrap_or(trimmed.len());
+
+    if len == 0 {
+        return None;
+    }
+
+    let (tag, rest) = trimmed.split_at(len);
+    let rest_trimmed = rest.trim_start();
+
+    if rest_trimmed.starts_with('=') {
+        let consumed = offset + len + (rest.len() - rest_trimmed.len()) + 1;
+        Some((tag, consumed))
+    } else {
+        None
+    }
+}
+
+// Finds the end of an unquoted value, i.e., the start of the next field or the end of the input.
+fn find_field_end(s: &str, start: usize) -> usize {
+    s[start..]
+        .match_indices(',')
+        .map(|(i, _)| start + i)
+        .find(|&i| parse_tag(&s[i + 1..]).is_some())
+        .unwrap_or(s.len())
+}
+
+// Parses a record, reporting and working around spec violations.
+//
+// `undefined_infos` holds definitions of INFO keys that are not in the header. It is updated
+// when a new undefined key is found. `buf` is used to hold the repaired record fields.
+fn parse_record<F>(
+    s: &str,
+    buf: &mut String,
+    header: &Header,
+    undefined_infos: &mut header::Infos,
+    on_warning: &mut F,
+) -> Result<Record, record::ParseError>
+where
+    F: FnMut(Warning),
+{
+    const MAX_FIELDS: usize = 9;
+    const CHROM: usize = 0;
+    const QUAL: usize = 5;
+    const INFO: usize = 7;
+
+    let mut fields: Vec<&str> = s.splitn(MAX_FIELDS, FIELD_DELIMITER).collect();
+
+    if fields.len() <= INFO {
+        // Defer to the strict parser for the error.
+        return Record::try_from_str(s, header);
+    }
+
+    let mut chromosome = None;
+
+    if !header.contigs().is_empty() && !header.contigs().contains_key(fields[CHROM]) {
+        chromosome = rename_chromosome(header, fields[CHROM]);
+
+        if let Some(name) = &chromosome {
+            on_warning(Warning::RenamedChromosome {
+                from: fields[CHROM].into(),
+                to: name.clone(),
+            });
+        }
+    }
+
+    if let Some(name) = &chromosome {
+        fields[CHROM] = name;
+    }
+
+    if fields[QUAL] != MISSING_FIELD && fields[QUAL].parse::<QualityScore>().is_err() {
+        on_warning(Warning::InvalidQualityScore(fields[QUAL].into()));
+        fields[QUAL] = MISSING_FIELD;
+    }
+
+    let raw_info = fields[INFO];
+    fields[INFO] = MISSING_FIELD;
+
+    buf.clear();
+
+    for (i, field) in fields.iter().enumerate() {
+        if i > 0 {
+            buf.push(FIELD_DELIMITER);
+        }
+
+        buf.push_str(field);
+    }
+
+    let mut record = Record::try_from_str(buf, header)?;
+
+    *record.info_mut() = parse_info(raw_info, header, undefined_infos, on_warning)
+        .map_err(record::ParseError::InvalidInfo)?;
+
+    Ok(record)
+}
+
+fn rename_chromosome(header: &Header, name: &str) -> Option<String> {
+    const PREFIX: &str = "chr";
+
+    let candidate = match name.strip_prefix(PREFIX) {
+        Some(suffix) => suffix.into(),
+        None => format!("{PREFIX}{name}"),
+    };
+
+    if header.contigs().contains_key(candidate.as_str()) {
+        Some(candidate)
+    } else {
+        None
+    }
+}
+
+fn parse_info<F>(
+    s: &str,
+    header: &Header,
+    undefined_infos: &mut header::Infos,
+    on_warning: &mut F,
+) -> Result<record::Info, info::ParseError>
+where
+    F: FnMut(Warning),
+{
+    const DELIMITER: char = ';';
+    const SEPARATOR: char = '=';
+
+    match s {
+        "" => return Err(info::ParseError::Empty),
+        MISSING_FIELD => return Ok(record::Info::default()),
+        _ => {}
+    }
+
+    if header.file_format() < FileFormat::new(4, 4) && s.contains(' ') {
+        on_warning(Warning::InfoWhitespace);
+    }
+
+    let mut info = record::Info::default();
+
+    for raw_field in s.split(DELIMITER) {
+        let (raw_key, raw_value) = match raw_field.split_once(SEPARATOR) {
+            Some((k, v)) => (k, Some(v)),
+            None => (raw_field, None),
+        };
+
+        let key: Key = raw_key
+            .parse()
+            .map_err(|e| info::ParseError::InvalidField(info::field::ParseError::InvalidKey(e)))?;
+
+        let (key, value) = if header.infos().contains_key(&key) {
+            info::field::parse(raw_field, header.infos(), header.file_format())
+                .map_err(info::ParseError::InvalidField)?
+        } else {
+            if !undefined_infos.contains_key(&key) {
+                on_warning(Warning::UndefinedInfoKey(key.clone()));
+            }
+
+            // The type of an undefined key is inferred per occurrence: a flag when it has no
+            // value and a string otherwise. Its definition is widened to a string if any
+            // occurrence has a value.
+            if raw_value.is_some() {
+                let map = header::record::value::Map::<header::record::value::map::Info>::new(
+                    Number::Unknown,
+                    Type::String,
+                    "",
+                );
+
+                match undefined_infos.get_mut(&key) {
+                    Some(definition) if definition.ty() == Type::String => {}
+                    Some(definition) => *definition = map,
+                    None => {
+                        undefined_infos.insert(key.clone(), map);
+                    }
+                }
+
+                info::field::parse(raw_field, undefined_infos, header.file_format())
+                    .map_err(info::ParseError::InvalidField)?
+            } else {
+                undefined_infos.entry(key.clone()).or_insert_with(|| {
+                    header::record::value::Map::<header::record::value::map::Info>::new(
+                        Number::Count(0),
+                        Type::Flag,
+                        "",
+                    )
+                });
+
+                (key, Some(info::field::Value::Flag))
+            }
+        };
+
+        if info.insert(key.clone(), value).is_some() {
+            return Err(info::ParseError::Invalid(
+                info::TryFromFieldsError::DuplicateKey(key),
+            ));
+        }
+    }
+
+    Ok(info)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_header() -> Result<(), Box<dyn std::error::Error>> {
+        let s = r#"##fileformat=VCFv4.3
+##INFO=<ID=NS,Number=1,Type=Integer,Description="Number of samples with data">
+##INFO=<ID=DP, Number=1, Type=Integer, Description="Combined depth across samples">
+##FILTER=<ID=q10,Description=Quality below 10, see "QUAL">
+##FORMAT=<ID=GT,Number=1>
+#CHROM	POS	ID	REF	ALT	QUAL	FILTER	INFO
+"#;
+
+        let mut warnings = Vec::new();
+        let header = parse_header(s, |warning| warnings.push(warning))?;
+
+        assert_eq!(header.infos().len(), 2);
+        assert_eq!(
+            header
+                .filters()
+                .get("q10")
+                .map(|filter| filter.description()),
+            Some(r#"Quality below 10, see "QUAL""#)
+        );
+        assert!(header.formats().is_empty());
+
+        assert_eq!(
+            warnings,
+            [
+                Warning::RepairedHeaderRecord(String::from(
+                    r#"##INFO=<ID=DP, Number=1, Type=Integer, Description="Combined depth across samples">"#
+                )),
+                Warning::RepairedHeaderRecord(String::from(
+                    r#"##FILTER=<ID=q10,Description=Quality below 10, see "QUAL">"#
+                )),
+                Warning::SkippedHeaderRecord(String::from("##FORMAT=<ID=GT,Number=1>")),
+            ]
+        );
+
+        assert!(matches!(
+            parse_header("", |_| {}),
+            Err(ParseError::MissingFileFormat)
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_repair_header_record() {
+        assert_eq!(
+            repair_header_record(
+                "##INFO=<ID=DP , Number = 1,Type=Integer,Description=Total depth, all samples,Source=x>"
+            ),
+            Some(String::from(
+                r#"##INFO=<ID=DP,Number=1,Type=Integer,Description="Total depth, all samples",Source=x>"#
+            ))
+        );
+
+        assert_eq!(
+            repair_header_record(r#"##FILTER=<ID=q10,Description="Quality, below 10" >"#),
+            Some(String::from(
+                r#"##FILTER=<ID=q10,Description="Quality, below 10">"#
+            ))
+        );
+
+        assert!(repair_header_record("##fileDate=20230203").is_none());
+    }
+
+    #[test]
+    fn test_parse_record() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::header::record::value::{map::Contig, Map};
+
+        let header = Header::builder()
+            .add_contig("chr1".parse()?, Map::<Contig>::new())
+            .build();
+
+        let mut buf = String::new();
+        let mut undefined_infos = header::Infos::default();
+        let mut warnings = Vec::new();
+
+        let record = parse_record(
+            "1\t8\t.\tA\t.\t-3\tPASS\tFOO=a b;BAR",
+            &mut buf,
+            &header,
+            &mut undefined_infos,
+            &mut |warning| warnings.push(warning),
+        )?;
+
+        assert_eq!(record.chromosome().to_string(), "chr1");
+        assert!(record.quality_score().is_none());
+        assert_eq!(record.info().to_string(), "FOO=a b;BAR",);
+
+        let foo: Key = "FOO".parse()?;
+        let bar: Key = "BAR".parse()?;
+
+        assert_eq!(
+            warnings,
+            [
+                Warning::RenamedChromosome {
+                    from: String::from("1"),
+                    to: String::from("chr1"),
+                },
+                Warning::InvalidQualityScore(String::from("-3")),
+                Warning::InfoWhitespace,
+                Warning::UndefinedInfoKey(foo),
+                Warning::UndefinedInfoKey(bar),
+            ]
+        );
+
+        warnings.clear();
+
+        parse_record(
+            "chr1\t13\t.\tA\t.\t5\tPASS\tFOO=c",
+            &mut buf,
+            &header,
+            &mut undefined_infos,
+            &mut |warning| warnings.push(warning),
+        )?;
+
+        assert!(warnings.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_record_with_undefined_flag_then_valued_info_key(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let header = Header::default();
+
+        let mut buf = String::new();
+        let mut undefined_infos = header::Infos::default();
+        let mut warnings = Vec::new();
+
+        let record = parse_record(
+            "sq0\t8\t.\tA\t.\t.\tPASS\tFOO",
+            &mut buf,
+            &header,
+            &mut undefined_infos,
+            &mut |warning| warnings.push(warning),
+        )?;
+        assert_eq!(record.info().to_string(), "FOO");
+
+        let record = parse_record(
+            "sq0\t13\t.\tA\t.\t.\tPASS\tFOO=bar",
+            &mut buf,
+            &header,
+            &mut undefined_infos,
+            &mut |warning| warnings.push(warning),
+        )?;
+        assert_eq!(record.info().to_string(), "FOO=bar");
+
+        let record = parse_record(
+            "sq0\t21\t.\tA\t.\t.\tPASS\tFOO",
+            &mut buf,
+            &header,
+            &mut undefined_infos,
+            &mut |warning| warnings.push(warning),
+        )?;
+        assert_eq!(record.info().to_string(), "FOO");
+
+        let foo: Key = "FOO".parse()?;
+        assert_eq!(warnings, [Warning::UndefinedInfoKey(foo)]);
+
+        Ok(())
+    }
+}
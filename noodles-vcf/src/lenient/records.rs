@@ -0,0 +1,60 @@
+use std::io::{self, BufRead};
+
+use super::Warning;
+use crate::{header, Header, Reader, Record};
+
+/// An iterator over records of a VCF reader that are leniently parsed.
+///
+/// This is created by calling [`Reader::lenient_records`].
+pub struct Records<'r, 'h, R, F> {
+    inner: &'r mut Reader<R>,
+    header: &'h Header,
+    undefined_infos: header::Infos,
+    on_warning: F,
+    line_buf: String,
+    record_buf: String,
+}
+
+impl<'r, 'h, R, F> Records<'r, 'h, R, F>
+where
+    R: BufRead,
+    F: FnMut(Warning),
+{
+    pub(crate) fn new(inner: &'r mut Reader<R>, header: &'h Header, on_warning: F) -> Self {
+        Self {
+            inner,
+            header,
+            undefined_infos: header::Infos::default(),
+            on_warning,
+            line_buf: String::new(),
+            record_buf: String::new(),
+        }
+    }
+}
+
+impl<'r, 'h, R, F> Iterator for Records<'r, 'h, R, F>
+where
+    R: BufRead,
+    F: FnMut(Warning),
+{
+    type Item = io::Result<Record>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.line_buf.clear();
+
+        match self.inner.read_record(&mut self.line_buf) {
+            Ok(0) => None,
+            Ok(_) => Some(
+                super::parse_record(
+                    &self.line_buf,
+                    &mut self.record_buf,
+                    self.header,
+                    &mut self.undefined_infos,
+                    &mut self.on_warning,
+                )
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
+            ),
+            Err(e) => Some(Err(e)),
+        }
+    }
+}
@@ -23,12 +23,29 @@
 #[cfg(feature = "async")]
 mod r#async;
 
+pub mod annotate;
+#[cfg(test)]
+mod fixtures;
+pub mod gvcf;
 pub mod header;
+pub mod indexed_reader;
+pub mod indexed_writer;
+pub mod lenient;
+pub mod likelihoods;
+pub mod merge;
 pub mod reader;
 pub mod record;
-mod writer;
+pub mod sample_subset;
+mod slice;
+pub mod sorter;
+pub mod stats;
+pub mod variant;
+pub mod writer;
 
-pub use self::{header::Header, reader::Reader, record::Record, writer::Writer};
+pub use self::{
+    header::Header, indexed_reader::IndexedReader, indexed_writer::IndexedWriter, reader::Reader,
+    record::Record, slice::slice, writer::Writer,
+};
 
 #[cfg(feature = "async")]
 pub use self::r#async::{Reader as AsyncReader, Writer as AsyncWriter};
@@ -0,0 +1,391 @@
+//! VCF genotype likelihood conversions.
+//!
+//! Genotype likelihoods are represented in one of three ways:
+//!
+//!   * `PL`: phred-scaled genotype likelihoods, rounded to integers;
+//!   * `GL`: log10-scaled genotype likelihoods; and
+//!   * `GP`: genotype posterior probabilities.
+//!
+//! Values of these fields have one value per possible genotype (`Number=G`) and are ordered as
+//! described in _The Variant Call Format Specification_ (VCFv4.3) § 1.6.2 "Genotype fields". See
+//! [`genotype_index`] and [`genotype_alleles`] for converting between a position in a list of
+//! values and the alleles of its genotype.
+
+use crate::record::multiallelic;
+
+/// Converts a phred-scaled genotype likelihood (`PL`) to a log10-scaled genotype likelihood
+/// (`GL`).
+///
+/// # Examples
+///
+/// ```
+/// use noodles_vcf::likelihoods;
+/// assert_eq!(likelihoods::phred_to_log10(0), 0.0);
+/// assert_eq!(likelihoods::phred_to_log10(30), -3.0);
+/// ```
+pub fn phred_to_log10(pl: i32) -> f32 {
+    pl as f32 / -10.0
+}
+
+/// Converts a log10-scaled genotype likelihood (`GL`) to a phred-scaled genotype likelihood
+/// (`PL`).
+///
+/// The result is rounded to the nearest integer.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_vcf::likelihoods;
+/// assert_eq!(likelihoods::log10_to_phred(0.0), 0);
+/// assert_eq!(likelihoods::log10_to_phred(-2.96), 30);
+/// ```
+pub fn log10_to_phred(gl: f32) -> i32 {
+    (-10.0 * gl).round() as i32
+}
+
+/// Converts a list of phred-scaled genotype likelihoods (`PL`) to log10-scaled genotype
+/// likelihoods (`GL`).
+///
+/// Missing values remain missing.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_vcf::likelihoods;
+///
+/// assert_eq!(
+///     likelihoods::pls_to_gls(&[Some(0), Some(30), None]),
+///     [Some(0.0), Some(-3.0), None]
+/// );
+/// ```
+pub fn pls_to_gls(pls: &[Option<i32>]) -> Vec<Option<f32>> {
+    pls.iter().map(|pl| pl.map(phred_to_log10)).collect()
+}
+
+/// Converts a list of log10-scaled genotype likelihoods (`GL`) to phred-scaled genotype
+/// likelihoods (`PL`).
+///
+/// The results are normalized so that the most likely genotype has a `PL` of 0. Missing values
+/// remain missing.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_vcf::likelihoods;
+///
+/// assert_eq!(
+///     likelihoods::gls_to_pls(&[Some(-1.0), Some(-0.5), None]),
+///     [Some(5), Some(0), None]
+/// );
+/// ```
+pub fn gls_to_pls(gls: &[Option<f32>]) -> Vec<Option<i32>> {
+    let max = gls
+        .iter()
+        .flatten()
+        .copied()
+        .fold(f32::NEG_INFINITY, f32::max);
+
+    gls.iter()
+        .map(|gl| gl.map(|n| log10_to_phred(n - max)))
+        .collect()
+}
+
+/// Converts a list of log10-scaled genotype likelihoods (`GL`) to genotype posterior
+/// probabilities (`GP`).
+///
+/// This assumes a flat prior, i.e., the posterior probabilities are the likelihoods normalized to
+/// sum to 1. Missing values remain missing and are excluded from the normalization.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_vcf::likelihoods;
+///
+/// let gps = likelihoods::gls_to_gps(&[Some(0.0), Some(-1.0), None]);
+///
+/// assert_eq!(gps.len(), 3);
+/// assert!((gps[0].unwrap() - 10.0 / 11.0).abs() < 1e-6);
+/// assert!((gps[1].unwrap() - 1.0 / 11.0).abs() < 1e-6);
+/// assert!(gps[2].is_none());
+/// ```
+pub fn gls_to_gps(gls: &[Option<f32>]) -> Vec<Option<f32>> {
+    // Likelihoods are scaled by the maximum to avoid underflow.
+    let max = gls
+        .iter()
+        .flatten()
+        .copied()
+        .fold(f32::NEG_INFINITY, f32::max);
+    let sum: f32 = gls.iter().flatten().map(|gl| 10f32.powf(gl - max)).sum();
+
+    gls.iter()
+        .map(|gl| gl.map(|n| 10f32.powf(n - max) / sum))
+        .collect()
+}
+
+/// Converts a list of phred-scaled genotype likelihoods (`PL`) to genotype posterior probabilities
+/// (`GP`).
+///
+/// See [`gls_to_gps`].
+///
+/// # Examples
+///
+/// ```
+/// use noodles_vcf::likelihoods;
+///
+/// let gps = likelihoods::pls_to_gps(&[Some(10), Some(0), Some(10)]);
+///
+/// assert!((gps[0].unwrap() - 1.0 / 12.0).abs() < 1e-6);
+/// assert!((gps[1].unwrap() - 10.0 / 12.0).abs() < 1e-6);
+/// assert!((gps[2].unwrap() - 1.0 / 12.0).abs() < 1e-6);
+/// ```
+pub fn pls_to_gps(pls: &[Option<i32>]) -> Vec<Option<f32>> {
+    gls_to_gps(&pls_to_gls(pls))
+}
+
+/// Converts a list of genotype posterior probabilities (`GP`) to log10-scaled values.
+///
+/// Missing values remain missing. A probability of 0 is converted to negative infinity.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_vcf::likelihoods;
+///
+/// assert_eq!(
+///     likelihoods::gps_to_gls(&[Some(1.0), Some(0.1), None]),
+///     [Some(0.0), Some(-1.0), None]
+/// );
+/// ```
+pub fn gps_to_gls(gps: &[Option<f32>]) -> Vec<Option<f32>> {
+    gps.iter().map(|gp| gp.map(f32::log10)).collect()
+}
+
+/// Returns the index of the most likely genotype from a list of phred-scaled genotype likelihoods
+/// (`PL`).
+///
+/// This is the index of the smallest value. Ties are broken by the lowest index. Missing values
+/// are ignored. If all values are missing, this returns `None`.
+///
+/// The alleles of the genotype can be found using [`genotype_alleles`].
+///
+/// # Examples
+///
+/// ```
+/// use noodles_vcf::likelihoods;
+///
+/// let pls = [Some(40), Some(0), Some(25)];
+/// let i = likelihoods::most_likely_genotype(&pls);
+/// assert_eq!(i, Some(1));
+/// assert_eq!(likelihoods::genotype_alleles(1, 2), [0, 1]);
+///
+/// assert!(likelihoods::most_likely_genotype(&[None, None, None]).is_none());
+/// ```
+pub fn most_likely_genotype(pls: &[Option<i32>]) -> Option<usize> {
+    pls.iter()
+        .enumerate()
+        .filter_map(|(i, pl)| pl.map(|n| (i, n)))
+        .min_by_key(|&(_, n)| n)
+        .map(|(i, _)| i)
+}
+
+/// Computes the genotype quality (`GQ`) from a list of phred-scaled genotype likelihoods (`PL`).
+///
+/// The genotype quality is the difference between the second smallest and smallest values. It is
+/// not capped. Missing values are ignored. If fewer than two values are present, this returns
+/// `None`.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_vcf::likelihoods;
+/// assert_eq!(likelihoods::genotype_quality(&[Some(40), Some(5), Some(25)]), Some(20));
+/// assert_eq!(likelihoods::genotype_quality(&[Some(0), None, None]), None);
+/// ```
+pub fn genotype_quality(pls: &[Option<i32>]) -> Option<i32> {
+    let mut first = None;
+    let mut second = None;
+
+    for &n in pls.iter().flatten() {
+        match first {
+            Some(m) if n >= m => {
+                if second.map(|s| n < s).unwrap_or(true) {
+                    second = Some(n);
+                }
+            }
+            _ => {
+                second = first;
+                first = Some(n);
+            }
+        }
+    }
+
+    first.zip(second).map(|(m, s)| s - m)
+}
+
+/// Returns the index of a genotype in the ordering of `Number=G` field values.
+///
+/// The order of the given alleles does not matter.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_vcf::likelihoods;
+///
+/// assert_eq!(likelihoods::genotype_index(&[0, 0]), 0);
+/// assert_eq!(likelihoods::genotype_index(&[0, 1]), 1);
+/// assert_eq!(likelihoods::genotype_index(&[1, 1]), 2);
+/// assert_eq!(likelihoods::genotype_index(&[2, 0]), 3);
+/// ```
+pub fn genotype_index(alleles: &[usize]) -> usize {
+    let mut alleles = alleles.to_vec();
+    alleles.sort_unstable();
+    multiallelic::genotype_index(&alleles)
+}
+
+/// Returns the alleles of the genotype at the given index in the ordering of `Number=G` field
+/// values.
+///
+/// This is the inverse of [`genotype_index`]. The alleles are sorted in ascending order.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_vcf::likelihoods;
+///
+/// assert_eq!(likelihoods::genotype_alleles(0, 2), [0, 0]);
+/// assert_eq!(likelihoods::genotype_alleles(3, 2), [0, 2]);
+/// assert_eq!(likelihoods::genotype_alleles(4, 3), [0, 0, 2]);
+/// ```
+pub fn genotype_alleles(index: usize, ploidy: usize) -> Vec<usize> {
+    let mut alleles = vec![0; ploidy];
+    let mut index = index;
+
+    // The genotype index is the sum of binomial(a_m + m - 1, m) for each sorted allele a_m
+    // (1-based m). The allele with the highest rank is the largest a such that its term does not
+    // exceed the remaining index.
+    for m in (1..=ploidy).rev() {
+        let mut a = 0;
+
+        while multiallelic::binomial(a + m, m) <= index {
+            a += 1;
+        }
+
+        index -= multiallelic::binomial(a + m - 1, m);
+        alleles[m - 1] = a;
+    }
+
+    alleles
+}
+
+/// Reorders a list of `Number=G` field values for re-indexed alleles.
+///
+/// `allele_indices` maps each allele index in the new order to its index in the old order, e.g.,
+/// `[0, 2, 1]` swaps the first and second alternate alleles. This can also subset alleles, e.g.,
+/// `[0, 2]` keeps only the reference and second alternate alleles.
+///
+/// This returns `None` if a genotype of the new order is not in the given values.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_vcf::likelihoods;
+///
+/// // 0/0, 0/1, 1/1, 0/2, 1/2, 2/2
+/// let pls = [Some(0), Some(10), Some(20), Some(30), Some(40), Some(50)];
+///
+/// assert_eq!(
+///     likelihoods::reorder(&pls, 2, &[0, 2, 1]),
+///     Some(vec![Some(0), Some(30), Some(50), Some(10), Some(40), Some(20)])
+/// );
+///
+/// assert_eq!(
+///     likelihoods::reorder(&pls, 2, &[0, 2]),
+///     Some(vec![Some(0), Some(30), Some(50)])
+/// );
+///
+/// assert!(likelihoods::reorder(&pls, 2, &[0, 3]).is_none());
+/// ```
+pub fn reorder<T>(values: &[T], ploidy: usize, allele_indices: &[usize]) -> Option<Vec<T>>
+where
+    T: Clone,
+{
+    let allele_count = allele_indices.len();
+
+    if allele_count == 0 {
+        return Some(Vec::new());
+    }
+
+    let len = multiallelic::binomial(allele_count + ploidy - 1, ploidy);
+
+    (0..len)
+        .map(|i| {
+            let alleles: Vec<_> = genotype_alleles(i, ploidy)
+                .into_iter()
+                .map(|a| allele_indices[a])
+                .collect();
+
+            values.get(genotype_index(&alleles)).cloned()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gls_to_pls() {
+        assert_eq!(
+            gls_to_pls(&[Some(-0.1), Some(-2.3), Some(-5.0)]),
+            [Some(0), Some(22), Some(49)]
+        );
+        assert_eq!(gls_to_pls(&[None, None]), [None, None]);
+        assert!(gls_to_pls(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_gls_to_gps() {
+        let gps = gls_to_gps(&[Some(-1000.0), Some(-1001.0)]);
+        assert!((gps[0].unwrap() - 10.0 / 11.0).abs() < 1e-6);
+        assert!((gps[1].unwrap() - 1.0 / 11.0).abs() < 1e-6);
+
+        assert_eq!(gls_to_gps(&[None]), [None]);
+    }
+
+    #[test]
+    fn test_genotype_quality() {
+        assert_eq!(genotype_quality(&[Some(0), Some(0), Some(30)]), Some(0));
+        assert_eq!(genotype_quality(&[Some(30), Some(10), Some(0)]), Some(10));
+        assert_eq!(genotype_quality(&[Some(20), None, Some(50)]), Some(30));
+        assert_eq!(genotype_quality(&[]), None);
+    }
+
+    #[test]
+    fn test_genotype_alleles() {
+        for ploidy in 1..=4 {
+            for i in 0..multiallelic::binomial(4 + ploidy - 1, ploidy) {
+                let alleles = genotype_alleles(i, ploidy);
+                assert!(alleles.windows(2).all(|w| w[0] <= w[1]));
+                assert_eq!(genotype_index(&alleles), i);
+            }
+        }
+
+        assert_eq!(genotype_alleles(0, 1), [0]);
+        assert_eq!(genotype_alleles(2, 1), [2]);
+        assert_eq!(genotype_alleles(5, 2), [2, 2]);
+        assert!(genotype_alleles(0, 0).is_empty());
+    }
+
+    #[test]
+    fn test_reorder() {
+        // 0/0/0, 0/0/1, 0/1/1, 1/1/1
+        let values = [0, 1, 2, 3];
+        assert_eq!(reorder(&values, 3, &[1, 0]), Some(vec![3, 2, 1, 0]));
+        assert_eq!(reorder(&values, 3, &[0]), Some(vec![0]));
+
+        let values = [0, 1, 2];
+        assert_eq!(reorder(&values, 1, &[2, 0, 1]), Some(vec![2, 0, 1]));
+
+        assert_eq!(reorder(&values, 2, &[]), Some(Vec::new()));
+    }
+}
@@ -0,0 +1,434 @@
+//! VCF record merging.
+//!
+//! This merges several sorted record streams, e.g., call sets of different samples, into a single
+//! sorted stream of records with the union of the samples.
+
+mod header;
+
+pub use self::header::{merge_headers, MergeHeadersError};
+
+use std::io;
+
+use crate::{
+    header::format::key as format_key,
+    record::{
+        self,
+        genotypes::{Genotype, Keys},
+        Genotypes,
+    },
+    Header, Record,
+};
+
+/// The mode of handling co-located records from different sources.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Mode {
+    /// Do not combine records.
+    ///
+    /// Each input record is a separate output record.
+    #[default]
+    None,
+    /// Combine records with the same chromosome, position, reference bases, and alternate bases.
+    ///
+    /// At most one record per source is combined into an output record. Records with different
+    /// alleles are not combined.
+    Combine,
+}
+
+struct Source<I> {
+    records: I,
+    head: Option<Record>,
+}
+
+/// An iterator over merged records.
+///
+/// This is created by calling [`merge`].
+pub struct Merge<I> {
+    header: Header,
+    mode: Mode,
+    sources: Vec<Source<I>>,
+    // The (source index, source sample index) of each merged sample.
+    sample_origins: Vec<(usize, usize)>,
+    is_initialized: bool,
+}
+
+impl<I> Merge<I>
+where
+    I: Iterator<Item = io::Result<Record>>,
+{
+    /// Returns the merged header.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, merge};
+    ///
+    /// let header = vcf::Header::builder().add_sample_name("sample0").build();
+    /// let sources = vec![(header, std::iter::empty())];
+    ///
+    /// let merge = merge::merge(sources, merge::Mode::None)?;
+    /// assert_eq!(merge.header().sample_names().len(), 1);
+    /// # Ok::<_, merge::MergeHeadersError>(())
+    /// ```
+    pub fn header(&self) -> &Header {
+        &self.header
+    }
+
+    fn initialize(&mut self) -> io::Result<()> {
+        for source in &mut self.sources {
+            source.head = source.records.next().transpose()?;
+        }
+
+        self.is_initialized = true;
+
+        Ok(())
+    }
+
+    fn take(&mut self, i: usize) -> io::Result<Record> {
+        let source = &mut self.sources[i];
+
+        // The caller guarantees the source has a head.
+        let record = source.head.take().expect("missing source head");
+        source.head = source.records.next().transpose()?;
+
+        if let Some(next_record) = &source.head {
+            if record::compare(&self.header, next_record, &record).is_lt() {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("unsorted records in source {i}"),
+                ));
+            }
+        }
+
+        Ok(record)
+    }
+
+    fn next_source_index(&self) -> Option<usize> {
+        let mut min: Option<(usize, &Record)> = None;
+
+        for (i, source) in self.sources.iter().enumerate() {
+            if let Some(record) = &source.head {
+                match min {
+                    Some((_, min_record))
+                        if record::compare(&self.header, record, min_record).is_ge() => {}
+                    _ => min = Some((i, record)),
+                }
+            }
+        }
+
+        min.map(|(i, _)| i)
+    }
+
+    fn next_parts(&mut self) -> io::Result<Option<Vec<(usize, Record)>>> {
+        if !self.is_initialized {
+            self.initialize()?;
+        }
+
+        let i = match self.next_source_index() {
+            Some(i) => i,
+            None => return Ok(None),
+        };
+
+        let record = self.take(i)?;
+        let mut parts = vec![(i, record)];
+
+        if self.mode == Mode::Combine {
+            for j in 0..self.sources.len() {
+                if j == i {
+                    continue;
+                }
+
+                let is_combinable = self.sources[j]
+                    .head
+                    .as_ref()
+                    .map(|head| is_colocated(&parts[0].1, head))
+                    .unwrap_or(false);
+
+                if is_combinable {
+                    let record = self.take(j)?;
+                    parts.push((j, record));
+                }
+            }
+
+            parts.sort_by_key(|(j, _)| *j);
+        }
+
+        Ok(Some(parts))
+    }
+}
+
+impl<I> Iterator for Merge<I>
+where
+    I: Iterator<Item = io::Result<Record>>,
+{
+    type Item = io::Result<Record>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.next_parts() {
+            Ok(Some(parts)) => Some(Ok(combine(&self.sample_origins, parts))),
+            Ok(None) => None,
+            Err(e) => Some(Err(e)),
+        }
+    }
+}
+
+/// Merges sorted VCF record streams.
+///
+/// Each source is a header and an iterator over records sorted by [`record::compare`]. The
+/// output records are sorted using the merged header (see [`merge_headers`]) and have the union
+/// of the samples of all sources. Samples without data for a record have missing values.
+///
+/// Using [`Mode::Combine`], co-located records from different sources are combined into a
+/// single record. The combined record takes the position, alleles, quality score, and filters
+/// from the record of the first source; IDs are unioned; and INFO fields are taken from the
+/// first source that has them.
+///
+/// BCF records can be merged by first converting them to VCF records.
+///
+/// # Errors
+///
+/// An error is returned if the headers fail to merge, e.g., due to a sample being in multiple
+/// sources. The iterator returns an error if the records of a source are not sorted.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_vcf::{self as vcf, merge};
+///
+/// let header0 = vcf::Header::builder().add_sample_name("sample0").build();
+/// let records0 = vec![
+///     vcf::Record::try_from_str("sq0\t1\t.\tA\tC\t.\tPASS\t.\tGT\t0/1", &header0),
+///     vcf::Record::try_from_str("sq0\t5\t.\tA\tC\t.\tPASS\t.\tGT\t1/1", &header0),
+/// ];
+///
+/// let header1 = vcf::Header::builder().add_sample_name("sample1").build();
+/// let records1 = vec![vcf::Record::try_from_str(
+///     "sq0\t1\t.\tA\tC\t.\tPASS\t.\tGT\t0/0",
+///     &header1,
+/// )];
+///
+/// let sources = [(header0, records0), (header1, records1)]
+///     .into_iter()
+///     .map(|(header, records)| {
+///         let records = records.into_iter().map(|result| {
+///             result.map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))
+///         });
+///
+///         (header, records)
+///     })
+///     .collect();
+///
+/// let records: Vec<_> = merge::merge(sources, merge::Mode::Combine)?
+///     .map(|result| result.map(|record| record.to_string()))
+///     .collect::<std::io::Result<_>>()?;
+///
+/// assert_eq!(records, [
+///     "sq0\t1\t.\tA\tC\t.\tPASS\t.\tGT\t0/1\t0/0",
+///     "sq0\t5\t.\tA\tC\t.\tPASS\t.\tGT\t1/1\t.",
+/// ]);
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn merge<I>(
+    sources: Vec<(Header, I)>,
+    mode: Mode,
+) -> Result<Merge<I::IntoIter>, MergeHeadersError>
+where
+    I: IntoIterator<Item = io::Result<Record>>,
+{
+    let headers: Vec<_> = sources.iter().map(|(header, _)| header.clone()).collect();
+    let header = merge_headers(&headers)?;
+
+    let sample_origins = headers
+        .iter()
+        .enumerate()
+        .flat_map(|(i, header)| (0..header.sample_names().len()).map(move |j| (i, j)))
+        .collect();
+
+    let sources = sources
+        .into_iter()
+        .map(|(_, records)| Source {
+            records: records.into_iter(),
+            head: None,
+        })
+        .collect();
+
+    Ok(Merge {
+        header,
+        mode,
+        sources,
+        sample_origins,
+        is_initialized: false,
+    })
+}
+
+fn is_colocated(a: &Record, b: &Record) -> bool {
+    a.chromosome() == b.chromosome()
+        && a.position() == b.position()
+        && a.reference_bases() == b.reference_bases()
+        && a.alternate_bases() == b.alternate_bases()
+}
+
+fn combine(sample_origins: &[(usize, usize)], parts: Vec<(usize, Record)>) -> Record {
+    let mut parts = parts.into_iter();
+
+    // A merge never yields an empty list of parts.
+    let (i, mut record) = parts.next().expect("missing record");
+    let mut sources = vec![(i, record.genotypes().clone())];
+
+    for (j, part) in parts {
+        record.ids_mut().extend(part.ids().iter().cloned());
+
+        for (key, value) in part.info().as_ref() {
+            if record.info().get(key).is_none() {
+                record.info_mut().insert(key.clone(), value.clone());
+            }
+        }
+
+        sources.push((j, part.genotypes().clone()));
+    }
+
+    *record.genotypes_mut() = combine_genotypes(sample_origins, &sources);
+
+    record
+}
+
+fn combine_genotypes(
+    sample_origins: &[(usize, usize)],
+    sources: &[(usize, Genotypes)],
+) -> Genotypes {
+    if sample_origins.is_empty() {
+        return Genotypes::default();
+    }
+
+    let mut keys = Keys::default();
+
+    // The genotype key (`GT`) must be first if present. It is also used as a placeholder when
+    // no source has genotypes.
+    let has_genotype_key = sources
+        .iter()
+        .all(|(_, genotypes)| genotypes.keys().is_empty())
+        || sources
+            .iter()
+            .any(|(_, genotypes)| genotypes.keys().contains(&format_key::GENOTYPE));
+
+    if has_genotype_key {
+        keys.insert(format_key::GENOTYPE);
+    }
+
+    for (_, genotypes) in sources {
+        keys.extend(genotypes.keys().iter().cloned());
+    }
+
+    let samples = sample_origins
+        .iter()
+        .map(|&(i, j)| {
+            let genotype = sources
+                .iter()
+                .find(|(k, _)| *k == i)
+                .and_then(|(_, genotypes)| genotypes.get(j));
+
+            keys.iter()
+                .map(|key| {
+                    let value = genotype.and_then(|g| g.get(key)).cloned().flatten();
+                    (key.clone(), value)
+                })
+                .collect::<Genotype>()
+        })
+        .collect();
+
+    Genotypes::new(keys, samples)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        fixtures::parse_records,
+        header::record::value::{map::Contig, Map},
+    };
+
+    fn build_header(sample_name: &str) -> Result<Header, Box<dyn std::error::Error>> {
+        Ok(Header::builder()
+            .add_contig("sq0".parse()?, Map::<Contig>::new())
+            .add_contig("sq1".parse()?, Map::<Contig>::new())
+            .add_sample_name(sample_name)
+            .build())
+    }
+
+    #[test]
+    fn test_merge() -> Result<(), Box<dyn std::error::Error>> {
+        let header0 = build_header("sample0")?;
+        let records0 = [
+            "sq0\t1\tr0\tA\tC\t.\tPASS\tDP=2\tGT:GQ\t0/1:13",
+            "sq1\t1\t.\tA\tC\t.\tPASS\t.\tGT\t1/1",
+        ];
+
+        let header1 = build_header("sample1")?;
+        let records1 = [
+            "sq0\t1\tr1\tA\tC\t.\tq10\tDP=5;AC=1\tGT:DP\t0/0:8",
+            "sq0\t1\t.\tA\tG\t.\tPASS\t.\tGT\t0/1",
+            "sq0\t3\t.\tA\tC\t.\tPASS\t.\tGQ\t21",
+        ];
+
+        let sources = vec![
+            (header0.clone(), parse_records(&header0, &records0)),
+            (header1.clone(), parse_records(&header1, &records1)),
+        ];
+
+        let actual: Vec<_> = merge(sources, Mode::None)?
+            .map(|result| result.map(|record| record.to_string()))
+            .collect::<io::Result<_>>()?;
+
+        assert_eq!(
+            actual,
+            [
+                "sq0\t1\tr0\tA\tC\t.\tPASS\tDP=2\tGT:GQ\t0/1:13\t.:.",
+                "sq0\t1\tr1\tA\tC\t.\tq10\tDP=5;AC=1\tGT:DP\t.:.\t0/0:8",
+                "sq0\t1\t.\tA\tG\t.\tPASS\t.\tGT\t.\t0/1",
+                "sq0\t3\t.\tA\tC\t.\tPASS\t.\tGQ\t.\t21",
+                "sq1\t1\t.\tA\tC\t.\tPASS\t.\tGT\t1/1\t.",
+            ]
+        );
+
+        let sources = vec![
+            (header0.clone(), parse_records(&header0, &records0)),
+            (header1.clone(), parse_records(&header1, &records1)),
+        ];
+
+        let actual: Vec<_> = merge(sources, Mode::Combine)?
+            .map(|result| result.map(|record| record.to_string()))
+            .collect::<io::Result<_>>()?;
+
+        assert_eq!(
+            actual,
+            [
+                "sq0\t1\tr0;r1\tA\tC\t.\tPASS\tDP=2;AC=1\tGT:GQ:DP\t0/1:13:.\t0/0:.:8",
+                "sq0\t1\t.\tA\tG\t.\tPASS\t.\tGT\t.\t0/1",
+                "sq0\t3\t.\tA\tC\t.\tPASS\t.\tGQ\t.\t21",
+                "sq1\t1\t.\tA\tC\t.\tPASS\t.\tGT\t1/1\t.",
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_merge_with_unsorted_records() -> Result<(), Box<dyn std::error::Error>> {
+        let header = build_header("sample0")?;
+        let records = parse_records(
+            &header,
+            &[
+                "sq1\t1\t.\tA\tC\t.\tPASS\t.\tGT\t0/1",
+                "sq0\t1\t.\tA\tC\t.\tPASS\t.\tGT\t0/1",
+            ],
+        );
+
+        let result: io::Result<Vec<_>> = merge(vec![(header, records)], Mode::None)?.collect();
+
+        assert!(matches!(
+            result,
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+}
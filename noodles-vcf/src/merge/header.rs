@@ -0,0 +1,150 @@
+use std::{collections::HashSet, error, fmt};
+
+use crate::{
+    header::{self, merge::MergeError},
+    Header,
+};
+
+/// An error returned when VCF headers fail to merge.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum MergeHeadersError {
+    /// The list of headers is empty.
+    Empty,
+    /// A sample name is duplicated.
+    DuplicateSampleName(String),
+    /// Header records conflict.
+    Conflict(MergeError),
+}
+
+impl error::Error for MergeHeadersError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::Conflict(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for MergeHeadersError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("empty input"),
+            Self::DuplicateSampleName(name) => write!(f, "duplicate sample name: {name}"),
+            Self::Conflict(_) => f.write_str("conflicting header records"),
+        }
+    }
+}
+
+/// Merges VCF headers.
+///
+/// Header records are merged using [`header::merge`], which rejects conflicting info, format, and
+/// contig definitions. Unlike [`header::merge`], sample names are concatenated and must be unique
+/// across all headers, as each header describes a distinct set of samples.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_vcf::{
+///     self as vcf,
+///     header::record::value::{map::Contig, Map},
+///     merge,
+/// };
+///
+/// let header0 = vcf::Header::builder()
+///     .add_contig("sq0".parse()?, Map::<Contig>::new())
+///     .add_sample_name("sample0")
+///     .build();
+///
+/// let header1 = vcf::Header::builder()
+///     .add_contig("sq1".parse()?, Map::<Contig>::new())
+///     .add_sample_name("sample1")
+///     .build();
+///
+/// let header = merge::merge_headers(&[header0, header1])?;
+///
+/// assert_eq!(header.contigs().len(), 2);
+/// assert_eq!(header.sample_names().len(), 2);
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn merge_headers(headers: &[Header]) -> Result<Header, MergeHeadersError> {
+    if headers.is_empty() {
+        return Err(MergeHeadersError::Empty);
+    }
+
+    let mut sample_names = HashSet::new();
+
+    for sample_name in headers.iter().flat_map(|header| header.sample_names()) {
+        if !sample_names.insert(sample_name) {
+            return Err(MergeHeadersError::DuplicateSampleName(sample_name.clone()));
+        }
+    }
+
+    header::merge(headers).map_err(MergeHeadersError::Conflict)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::header::{
+        info::key,
+        record::value::{
+            map::{Contig, Info},
+            Map,
+        },
+        FileFormat,
+    };
+
+    #[test]
+    fn test_merge_headers() -> Result<(), Box<dyn std::error::Error>> {
+        assert_eq!(merge_headers(&[]), Err(MergeHeadersError::Empty));
+
+        let header0 = Header::builder()
+            .set_file_format(FileFormat::new(4, 2))
+            .add_info(key::TOTAL_DEPTH, Map::<Info>::from(&key::TOTAL_DEPTH))
+            .add_contig("sq0".parse()?, Map::<Contig>::new())
+            .add_sample_name("sample0")
+            .build();
+
+        let header1 = Header::builder()
+            .add_info(key::ALLELE_COUNT, Map::<Info>::from(&key::ALLELE_COUNT))
+            .add_info(key::TOTAL_DEPTH, Map::<Info>::from(&key::TOTAL_DEPTH))
+            .add_contig("sq1".parse()?, Map::<Contig>::new())
+            .add_contig("sq0".parse()?, Map::<Contig>::new())
+            .add_sample_name("sample1")
+            .build();
+
+        let header = merge_headers(&[header0.clone(), header1])?;
+
+        assert_eq!(header.file_format(), FileFormat::default());
+        assert!(header
+            .infos()
+            .keys()
+            .eq([&key::TOTAL_DEPTH, &key::ALLELE_COUNT]));
+        assert!(header
+            .contigs()
+            .keys()
+            .map(|name| name.as_ref())
+            .eq(["sq0", "sq1"]));
+        assert!(header.sample_names().iter().eq(["sample0", "sample1"]));
+
+        assert_eq!(
+            merge_headers(&[header0.clone(), header0.clone()]),
+            Err(MergeHeadersError::DuplicateSampleName(String::from(
+                "sample0"
+            )))
+        );
+
+        let mut info = Map::<Info>::from(&key::TOTAL_DEPTH);
+        *info.type_mut() = crate::header::record::value::map::info::Type::String;
+        let header2 = Header::builder().add_info(key::TOTAL_DEPTH, info).build();
+
+        assert_eq!(
+            merge_headers(&[header0, header2]),
+            Err(MergeHeadersError::Conflict(MergeError::InfoConflict(
+                key::TOTAL_DEPTH
+            )))
+        );
+
+        Ok(())
+    }
+}
@@ -1,11 +1,15 @@
 //! VCF reader and iterators.
 
+mod parallel_records;
 pub(crate) mod query;
 mod records;
 
-pub use self::{query::Query, records::Records};
+pub use self::{parallel_records::ParallelRecords, query::Query, records::Records};
 
-use std::io::{self, BufRead, Read, Seek};
+use std::{
+    io::{self, BufRead, Read, Seek},
+    num::NonZeroUsize,
+};
 
 use memchr::memchr;
 use noodles_bgzf as bgzf;
@@ -13,7 +17,7 @@ use noodles_core::Region;
 use noodles_csi::BinningIndex;
 use noodles_tabix as tabix;
 
-use super::Header;
+use super::{lenient, Header};
 
 /// A VCF reader.
 ///
@@ -207,6 +211,84 @@ where
     pub fn records<'r, 'h>(&'r mut self, header: &'h Header) -> Records<'r, 'h, R> {
         Records::new(self, header)
     }
+
+    /// Returns an iterator over records that are leniently parsed.
+    ///
+    /// Unlike [`Self::records`], common spec violations are accepted and reported to the given
+    /// callback. See [`crate::lenient`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, lenient::Warning};
+    ///
+    /// let data = b"##fileformat=VCFv4.3
+    /// #CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+    /// sq0\t1\t.\tA\t.\t-1\tPASS\t.
+    /// ";
+    ///
+    /// let mut reader = vcf::Reader::new(&data[..]);
+    /// let header = reader.read_header()?.parse()?;
+    ///
+    /// let mut warnings = Vec::new();
+    ///
+    /// for result in reader.lenient_records(&header, |warning| warnings.push(warning)) {
+    ///     let record = result?;
+    ///     assert!(record.quality_score().is_none());
+    /// }
+    ///
+    /// assert_eq!(warnings, [Warning::InvalidQualityScore(String::from("-1"))]);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn lenient_records<'r, 'h, F>(
+        &'r mut self,
+        header: &'h Header,
+        on_warning: F,
+    ) -> lenient::Records<'r, 'h, R, F>
+    where
+        F: FnMut(lenient::Warning),
+    {
+        lenient::Records::new(self, header, on_warning)
+    }
+
+    /// Returns an iterator over records that are parsed in parallel.
+    ///
+    /// Lines are read on the calling thread and parsed on `worker_count` worker threads. Records
+    /// are returned in input order. This is useful when parsing, rather than reading, limits
+    /// throughput, e.g., for files with many samples.
+    ///
+    /// The stream is expected to be directly after the header or at the start of another record.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use noodles_vcf as vcf;
+    ///
+    /// let data = b"##fileformat=VCFv4.3
+    /// #CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+    /// sq0\t1\t.\tA\t.\t.\tPASS\t.
+    /// sq0\t2\t.\tA\t.\t.\tPASS\t.
+    /// ";
+    ///
+    /// let mut reader = vcf::Reader::new(&data[..]);
+    /// let header = reader.read_header()?.parse()?;
+    ///
+    /// let worker_count = NonZeroUsize::try_from(2)?;
+    /// let mut records = reader.parallel_records(&header, worker_count);
+    ///
+    /// assert!(records.next().is_some());
+    /// assert!(records.next().is_some());
+    /// assert!(records.next().is_none());
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn parallel_records(
+        &mut self,
+        header: &Header,
+        worker_count: NonZeroUsize,
+    ) -> ParallelRecords<'_, R> {
+        ParallelRecords::new(self, header, worker_count)
+    }
 }
 
 impl<R> Reader<bgzf::Reader<R>>
@@ -0,0 +1,290 @@
+use std::{
+    collections::VecDeque,
+    io::{self, BufRead},
+    num::NonZeroUsize,
+    sync::Arc,
+    thread::{self, JoinHandle},
+    vec,
+};
+
+use crossbeam_channel::{Receiver, Sender};
+
+use super::Reader;
+use crate::{Header, Record};
+
+// The number of lines sent to a parser at a time.
+const BATCH_SIZE: usize = 1024;
+
+type Batch = Vec<io::Result<Record>>;
+type BatchTx = Sender<Batch>;
+type BatchRx = Receiver<Batch>;
+type ParserTx = Sender<(Vec<String>, BatchTx)>;
+type ParserRx = Receiver<(Vec<String>, BatchTx)>;
+
+/// An iterator over records of a VCF reader that are parsed in parallel.
+///
+/// Lines are read on the calling thread and parsed on a pool of worker threads. Records are
+/// returned in input order.
+///
+/// This is created by calling [`Reader::parallel_records`].
+pub struct ParallelRecords<'r, R> {
+    inner: &'r mut Reader<R>,
+    parser_tx: Option<ParserTx>,
+    parser_handles: Vec<JoinHandle<()>>,
+    queue: VecDeque<BatchRx>,
+    queue_capacity: usize,
+    batch: vec::IntoIter<io::Result<Record>>,
+    is_eof: bool,
+}
+
+impl<'r, R> ParallelRecords<'r, R>
+where
+    R: BufRead,
+{
+    pub(crate) fn new(
+        inner: &'r mut Reader<R>,
+        header: &Header,
+        worker_count: NonZeroUsize,
+    ) -> Self {
+        let worker_count = worker_count.get();
+
+        let (parser_tx, parser_rx) = crossbeam_channel::bounded(worker_count);
+        let parser_handles = spawn_parsers(worker_count, Arc::new(header.clone()), parser_rx);
+
+        // Keep each worker busy while the next batch is read.
+        let queue_capacity = 2 * worker_count;
+
+        Self {
+            inner,
+            parser_tx: Some(parser_tx),
+            parser_handles,
+            queue: VecDeque::with_capacity(queue_capacity),
+            queue_capacity,
+            batch: Vec::new().into_iter(),
+            is_eof: false,
+        }
+    }
+
+    fn fill_queue(&mut self) -> io::Result<()> {
+        while self.queue.len() < self.queue_capacity && !self.is_eof {
+            let mut lines = Vec::with_capacity(BATCH_SIZE);
+            let result = read_lines(self.inner, &mut lines);
+
+            if result.is_err() || lines.len() < BATCH_SIZE {
+                self.is_eof = true;
+            }
+
+            if !lines.is_empty() {
+                let (batch_tx, batch_rx) = crossbeam_channel::bounded(1);
+
+                let parser_tx = self.parser_tx.as_ref().ok_or_else(disconnected)?;
+                parser_tx
+                    .send((lines, batch_tx))
+                    .map_err(|_| disconnected())?;
+
+                self.queue.push_back(batch_rx);
+            }
+
+            if let Err(e) = result {
+                // The error is queued after the lines read before it to keep the records in order.
+                let (batch_tx, batch_rx) = crossbeam_channel::bounded(1);
+                batch_tx.send(vec![Err(e)]).map_err(|_| disconnected())?;
+                self.queue.push_back(batch_rx);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn stop(&mut self) {
+        self.is_eof = true;
+        self.queue.clear();
+        self.batch = Vec::new().into_iter();
+    }
+}
+
+impl<'r, R> Iterator for ParallelRecords<'r, R>
+where
+    R: BufRead,
+{
+    type Item = io::Result<Record>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(result) = self.batch.next() {
+                return Some(result);
+            }
+
+            if let Err(e) = self.fill_queue() {
+                self.stop();
+                return Some(Err(e));
+            }
+
+            let batch_rx = self.queue.pop_front()?;
+
+            match batch_rx.recv() {
+                Ok(batch) => self.batch = batch.into_iter(),
+                Err(_) => {
+                    self.stop();
+                    return Some(Err(disconnected()));
+                }
+            }
+        }
+    }
+}
+
+impl<'r, R> Drop for ParallelRecords<'r, R> {
+    fn drop(&mut self) {
+        self.parser_tx.take();
+        self.queue.clear();
+
+        for handle in self.parser_handles.drain(..) {
+            let _ = handle.join();
+        }
+    }
+}
+
+// A parser worker stops early only if it panics.
+fn disconnected() -> io::Error {
+    io::Error::new(io::ErrorKind::Other, "record parser disconnected")
+}
+
+// On error, `lines` holds the lines read before the error.
+fn read_lines<R>(reader: &mut Reader<R>, lines: &mut Vec<String>) -> io::Result<()>
+where
+    R: BufRead,
+{
+    while lines.len() < BATCH_SIZE {
+        let mut line = String::new();
+
+        if reader.read_record(&mut line)? == 0 {
+            break;
+        }
+
+        lines.push(line);
+    }
+
+    Ok(())
+}
+
+fn spawn_parsers(
+    worker_count: usize,
+    header: Arc<Header>,
+    parser_rx: ParserRx,
+) -> Vec<JoinHandle<()>> {
+    let mut handles = Vec::with_capacity(worker_count);
+
+    for _ in 0..worker_count {
+        let header = header.clone();
+        let parser_rx = parser_rx.clone();
+
+        handles.push(thread::spawn(move || {
+            while let Ok((lines, batch_tx)) = parser_rx.recv() {
+                let batch = lines
+                    .iter()
+                    .map(|line| {
+                        Record::try_from_str(line, &header)
+                            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+                    })
+                    .collect();
+
+                if batch_tx.send(batch).is_err() {
+                    continue;
+                }
+            }
+        }));
+    }
+
+    handles
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next() -> Result<(), Box<dyn std::error::Error>> {
+        let mut data =
+            String::from("##fileformat=VCFv4.3\n#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\n");
+
+        let n = 3 * BATCH_SIZE + 5;
+
+        for i in 1..=n {
+            data.push_str(&format!("sq0\t{i}\t.\tA\t.\t.\tPASS\t.\n"));
+        }
+
+        let mut reader = Reader::new(data.as_bytes());
+        let header = reader.read_header()?.parse()?;
+
+        let worker_count = NonZeroUsize::try_from(3)?;
+        let positions: Vec<_> = reader
+            .parallel_records(&header, worker_count)
+            .map(|result| result.map(|record| usize::from(record.position())))
+            .collect::<io::Result<_>>()?;
+
+        assert!(positions.into_iter().eq(1..=n));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_next_with_invalid_record() -> Result<(), Box<dyn std::error::Error>> {
+        let data = b"##fileformat=VCFv4.3
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+sq0\t1\t.\tA\t.\t.\tPASS\t.
+sq0\t2\t.\tA
+sq0\t3\t.\tA\t.\t.\tPASS\t.
+";
+
+        let mut reader = Reader::new(&data[..]);
+        let header = reader.read_header()?.parse()?;
+
+        let worker_count = NonZeroUsize::try_from(2)?;
+        let mut records = reader.parallel_records(&header, worker_count);
+
+        assert!(matches!(records.next(), Some(Ok(_))));
+        assert!(matches!(
+            records.next(),
+            Some(Err(e)) if e.kind() == io::ErrorKind::InvalidData
+        ));
+        assert!(matches!(records.next(), Some(Ok(_))));
+        assert!(records.next().is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_next_with_io_error() -> Result<(), Box<dyn std::error::Error>> {
+        use std::io::{BufReader, Read};
+
+        struct FailingReader;
+
+        impl Read for FailingReader {
+            fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+                Err(io::Error::from(io::ErrorKind::BrokenPipe))
+            }
+        }
+
+        let data = b"##fileformat=VCFv4.3
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+sq0\t1\t.\tA\t.\t.\tPASS\t.
+sq0\t2\t.\tA\t.\t.\tPASS\t.
+";
+
+        let mut reader = Reader::new(BufReader::new(data.chain(FailingReader)));
+        let header = reader.read_header()?.parse()?;
+
+        let worker_count = NonZeroUsize::try_from(2)?;
+        let mut records = reader.parallel_records(&header, worker_count);
+
+        assert!(matches!(records.next(), Some(Ok(record)) if usize::from(record.position()) == 1));
+        assert!(matches!(records.next(), Some(Ok(record)) if usize::from(record.position()) == 2));
+        assert!(matches!(
+            records.next(),
+            Some(Err(e)) if e.kind() == io::ErrorKind::BrokenPipe
+        ));
+        assert!(records.next().is_none());
+
+        Ok(())
+    }
+}
@@ -39,7 +39,7 @@ impl<'r, 'h, R> Query<'r, 'h, R>
 where
     R: Read + Seek,
 {
-    pub(super) fn new(
+    pub(crate) fn new(
         reader: &'r mut Reader<bgzf::Reader<R>>,
         chunks: Vec<Chunk>,
         reference_sequence_name: String,
@@ -2,27 +2,34 @@
 
 pub mod alternate_bases;
 pub mod builder;
+pub mod cardinality;
 pub mod chromosome;
 mod field;
 pub mod filters;
 pub mod genotypes;
 pub mod ids;
 pub mod info;
+pub mod multiallelic;
+mod ordering;
 mod parser;
 pub mod position;
 pub mod quality_score;
+pub mod raw;
 pub mod reference_bases;
+#[cfg(feature = "serde")]
+mod ser;
 pub(crate) mod value;
 
 pub use self::{
     alternate_bases::AlternateBases, builder::Builder, chromosome::Chromosome, field::Field,
-    filters::Filters, genotypes::Genotypes, ids::Ids, info::Info, parser::ParseError,
-    position::Position, quality_score::QualityScore, reference_bases::ReferenceBases,
+    filters::Filters, genotypes::Genotypes, ids::Ids, info::Info, ordering::compare,
+    parser::ParseError, position::Position, quality_score::QualityScore,
+    reference_bases::ReferenceBases,
 };
 
 use std::{error, fmt, num, str::FromStr};
 
-use super::Header;
+use super::{header::FileFormat, Header};
 
 pub(crate) const MISSING_FIELD: &str = ".";
 pub(crate) const FIELD_DELIMITER: char = '\t';
@@ -600,6 +607,118 @@ impl Record {
     pub fn genotypes_mut(&mut self) -> &mut Genotypes {
         &mut self.genotypes
     }
+
+    /// Splits a multiallelic record into biallelic records.
+    ///
+    /// One record is returned per alternate allele. INFO and genotype fields defined with
+    /// `Number=A`, `Number=R`, or `Number=G` are sliced to the values of the reference allele and
+    /// the given alternate allele. Genotype (`GT`) allele indices of other alternate alleles are
+    /// set to the reference allele. All other fields are copied.
+    ///
+    /// A record with at most one alternate allele is returned as is.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if a genotype is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf as vcf;
+    ///
+    /// let header = vcf::Header::builder().add_sample_name("sample0").build();
+    ///
+    /// let record = vcf::Record::try_from_str(
+    ///     "sq0\t1\t.\tA\tC,G\t.\tPASS\t.\tGT\t1/2",
+    ///     &header,
+    /// )?;
+    ///
+    /// let records = record.split_multiallelic(&header)?;
+    ///
+    /// assert_eq!(records.len(), 2);
+    /// assert_eq!(records[0].to_string(), "sq0\t1\t.\tA\tC\t.\tPASS\t.\tGT\t1/0");
+    /// assert_eq!(records[1].to_string(), "sq0\t1\t.\tA\tG\t.\tPASS\t.\tGT\t0/1");
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn split_multiallelic(
+        &self,
+        header: &Header,
+    ) -> Result<Vec<Self>, multiallelic::SplitError> {
+        multiallelic::split(self, header)
+    }
+
+    /// Joins biallelic records into a multiallelic record.
+    ///
+    /// This is the inverse of [`Self::split_multiallelic`]. The records must have the same
+    /// chromosome, position, and reference bases, and each must have exactly one alternate
+    /// allele. The alternate alleles are added in order.
+    ///
+    /// INFO and genotype fields defined with `Number=A`, `Number=R`, or `Number=G` are merged into
+    /// per-allele lists, where values that cannot be determined from the input are missing.
+    /// Genotype (`GT`) alternate allele indices are remapped to the joined alternate alleles. IDs
+    /// are merged; all other fields are taken from the first record with the field.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the list of records is empty, the records are incompatible, or a
+    /// genotype is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf as vcf;
+    ///
+    /// let header = vcf::Header::builder().add_sample_name("sample0").build();
+    ///
+    /// let records = [
+    ///     vcf::Record::try_from_str("sq0\t1\t.\tA\tC\t.\tPASS\t.\tGT\t1/0", &header)?,
+    ///     vcf::Record::try_from_str("sq0\t1\t.\tA\tG\t.\tPASS\t.\tGT\t0/1", &header)?,
+    /// ];
+    ///
+    /// let record = vcf::Record::join_multiallelic(&records, &header)?;
+    /// assert_eq!(record.to_string(), "sq0\t1\t.\tA\tC,G\t.\tPASS\t.\tGT\t1/2");
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn join_multiallelic(
+        records: &[Self],
+        header: &Header,
+    ) -> Result<Self, multiallelic::JoinError> {
+        multiallelic::join(records, header)
+    }
+
+    /// Validates the number of values of each INFO and genotype field value.
+    ///
+    /// The expected number of values is derived from the field's header-declared number, the
+    /// number of alternate alleles, and, for genotype fields, the sample ploidy. INFO fields with
+    /// `Number=G` are assumed to be diploid, and genotype fields with `Number=G` are not checked
+    /// for samples without a genotype (`GT`). Fields not declared in the header, flags, missing
+    /// values, and fields with an unknown number (`Number=.`) are not checked.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{
+    ///     self as vcf,
+    ///     header::{info::key, record::value::{map::Info, Map}},
+    /// };
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .add_info(key::ALLELE_COUNT, Map::<Info>::from(&key::ALLELE_COUNT))
+    ///     .build();
+    ///
+    /// let record = vcf::Record::try_from_str("sq0\t1\t.\tA\tC,G\t.\tPASS\tAC=1,2", &header)?;
+    /// assert!(record.validate_cardinality(&header).is_ok());
+    ///
+    /// let record = vcf::Record::try_from_str("sq0\t1\t.\tA\tC,G\t.\tPASS\tAC=1", &header)?;
+    /// assert!(record.validate_cardinality(&header).is_err());
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn validate_cardinality(
+        &self,
+        header: &Header,
+    ) -> Result<(), cardinality::CardinalityError> {
+        cardinality::validate(self, header)
+    }
 }
 
 /// An error returned when the end position is invalid.
@@ -640,6 +759,30 @@ impl fmt::Display for EndError {
     }
 }
 
+/// An error returned when the structural variant lengths are invalid.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SvLengthsError {
+    /// The INFO structural variant lengths (`SVLEN`) field value type is invalid.
+    InvalidInfoSvLengthsFieldValue,
+    /// A length is invalid.
+    ///
+    /// As of VCF 4.4, lengths must be nonnegative.
+    InvalidLength(i32),
+}
+
+impl error::Error for SvLengthsError {}
+
+impl fmt::Display for SvLengthsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidInfoSvLengthsFieldValue => {
+                f.write_str("invalid INFO structural variant lengths (`SVLEN`) field value type")
+            }
+            Self::InvalidLength(n) => write!(f, "invalid length: {n}"),
+        }
+    }
+}
+
 impl Record {
     /// Returns or calculates the end position on the reference sequence.
     ///
@@ -702,49 +845,70 @@ impl Record {
 
         Ok(Position::from(end))
     }
-}
 
-impl fmt::Display for Record {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "{chrom}\t{pos}",
-            chrom = self.chromosome(),
-            pos = self.position()
-        )?;
-
-        if self.ids().is_empty() {
-            write!(f, "\t{MISSING_FIELD}")?;
-        } else {
-            write!(f, "\t{}", self.ids())?;
-        }
-
-        write!(
-            f,
-            "\t{ref}\t{alt}",
-            r#ref = self.reference_bases(),
-            alt = self.alternate_bases()
-        )?;
-
-        if let Some(quality_score) = self.quality_score() {
-            write!(f, "\t{quality_score}")?;
-        } else {
-            write!(f, "\t{MISSING_FIELD}")?;
-        }
+    /// Returns the structural variant lengths (`SVLEN`).
+    ///
+    /// As of VCF 4.4, `SVLEN` is the length of a structural variant, which is always nonnegative.
+    /// Prior to VCF 4.4, it is the difference in length between the reference and alternate
+    /// alleles, e.g., negative for deletions. Lengths are returned using the VCF 4.4 semantics,
+    /// i.e., the absolute values of pre-VCF 4.4 lengths.
+    ///
+    /// This returns `None` if the record does not have an `SVLEN` INFO field value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, header::FileFormat, record::Position};
+    ///
+    /// let record = vcf::Record::builder()
+    ///     .set_chromosome("sq0".parse()?)
+    ///     .set_position(Position::from(1))
+    ///     .set_reference_bases("A".parse()?)
+    ///     .set_alternate_bases("<DEL>".parse()?)
+    ///     .set_info("SVLEN=-8".parse()?)
+    ///     .build()?;
+    ///
+    /// assert_eq!(
+    ///     record.sv_lengths(FileFormat::new(4, 3)),
+    ///     Some(Ok(vec![Some(8)]))
+    /// );
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn sv_lengths(
+        &self,
+        file_format: FileFormat,
+    ) -> Option<Result<Vec<Option<usize>>, SvLengthsError>> {
+        use self::info::field::Value;
+        use super::header::info::key;
 
-        if let Some(filters) = self.filters() {
-            write!(f, "\t{filters}")?;
-        } else {
-            write!(f, "\t{MISSING_FIELD}")?;
+        fn convert(file_format: FileFormat, n: i32) -> Result<usize, SvLengthsError> {
+            if file_format < FileFormat::new(4, 4) {
+                Ok(n.unsigned_abs() as usize)
+            } else {
+                usize::try_from(n).map_err(|_| SvLengthsError::InvalidLength(n))
+            }
         }
 
-        write!(f, "\t{}", self.info())?;
+        self.info()
+            .get(&key::SV_LENGTHS)
+            .flatten()
+            .map(|value| match value {
+                Value::Integer(n) => convert(file_format, *n).map(|len| vec![Some(len)]),
+                Value::IntegerArray(values) => values
+                    .iter()
+                    .map(|n| n.map(|n| convert(file_format, n)).transpose())
+                    .collect(),
+                _ => Err(SvLengthsError::InvalidInfoSvLengthsFieldValue),
+            })
+    }
+}
 
-        if !self.genotypes().is_empty() {
-            write!(f, "\t{}", self.genotypes())?;
-        }
+impl fmt::Display for Record {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use crate::writer::{record::write_record, FloatFormat};
 
-        Ok(())
+        // This matches the file format used by `FromStr`.
+        write_record(f, self, &FloatFormat::default(), FileFormat::default())
     }
 }
 
@@ -800,6 +964,70 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_sv_lengths() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::header::info::key;
+
+        fn build_record(info: Info) -> Result<Record, Box<dyn std::error::Error>> {
+            Record::builder()
+                .set_chromosome("sq0".parse()?)
+                .set_position(Position::from(1))
+                .set_reference_bases("A".parse()?)
+                .set_info(info)
+                .build()
+                .map_err(|e| e.into())
+        }
+
+        let v4_3 = FileFormat::new(4, 3);
+        let v4_4 = FileFormat::new(4, 4);
+
+        let record = build_record(Info::default())?;
+        assert!(record.sv_lengths(v4_4).is_none());
+
+        let record = build_record(
+            [(
+                key::SV_LENGTHS,
+                Some(info::field::Value::IntegerArray(vec![
+                    Some(-8),
+                    None,
+                    Some(5),
+                ])),
+            )]
+            .into_iter()
+            .collect(),
+        )?;
+
+        assert_eq!(
+            record.sv_lengths(v4_3),
+            Some(Ok(vec![Some(8), None, Some(5)]))
+        );
+        assert_eq!(
+            record.sv_lengths(v4_4),
+            Some(Err(SvLengthsError::InvalidLength(-8)))
+        );
+
+        let record = build_record(
+            [(key::SV_LENGTHS, Some(info::field::Value::Integer(8)))]
+                .into_iter()
+                .collect(),
+        )?;
+
+        assert_eq!(record.sv_lengths(v4_4), Some(Ok(vec![Some(8)])));
+
+        let record = build_record(
+            [(key::SV_LENGTHS, Some(info::field::Value::Flag))]
+                .into_iter()
+                .collect(),
+        )?;
+
+        assert_eq!(
+            record.sv_lengths(v4_4),
+            Some(Err(SvLengthsError::InvalidInfoSvLengthsFieldValue))
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn test_fmt() -> Result<(), Box<dyn std::error::Error>> {
         let record = Record::builder()
@@ -810,6 +1038,10 @@ mod tests {
 
         assert_eq!(record.to_string(), "sq0\t1\t.\tA\t.\t.\t.\t.");
 
+        let s = "sq0\t1\t.\tA\t.\t.\tPASS\tNOTE=n%3Bd%2Cl";
+        let record: Record = s.parse()?;
+        assert_eq!(record.to_string(), s);
+
         Ok(())
     }
 
@@ -0,0 +1,453 @@
+//! VCF record alternate bases allele breakend.
+
+use std::{
+    error,
+    fmt::{self, Write},
+    str::FromStr,
+};
+
+use crate::record::{chromosome, position, Chromosome, Position};
+
+const MISSING: char = '.';
+const MATE_SEPARATOR: char = ':';
+
+/// The side of a sequence on which a breakend join occurs.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Orientation {
+    /// The join is on the left side of the sequence, i.e., the sequence continues to the right.
+    Left,
+    /// The join is on the right side of the sequence, i.e., the sequence continues to the left.
+    Right,
+}
+
+/// A VCF record alternate bases allele breakend mate.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Mate {
+    chromosome: Chromosome,
+    position: Position,
+    orientation: Orientation,
+}
+
+impl Mate {
+    /// Creates a breakend mate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::{
+    ///     alternate_bases::allele::breakend::{Mate, Orientation},
+    ///     Position,
+    /// };
+    ///
+    /// let mate = Mate::new("sq1".parse()?, Position::from(13), Orientation::Left);
+    /// # Ok::<_, noodles_vcf::record::chromosome::ParseError>(())
+    /// ```
+    pub fn new(chromosome: Chromosome, position: Position, orientation: Orientation) -> Self {
+        Self {
+            chromosome,
+            position,
+            orientation,
+        }
+    }
+
+    /// Returns the chromosome of the mate.
+    pub fn chromosome(&self) -> &Chromosome {
+        &self.chromosome
+    }
+
+    /// Returns the position of the mate.
+    pub fn position(&self) -> Position {
+        self.position
+    }
+
+    /// Returns the side of the mate position on which the join occurs.
+    ///
+    /// This is [`Orientation::Left`] when the joined piece extends to the right of the mate
+    /// position (`[p[`) and [`Orientation::Right`] when it extends to the left (`]p]`).
+    pub fn orientation(&self) -> Orientation {
+        self.orientation
+    }
+}
+
+/// A VCF record alternate bases allele breakend.
+///
+/// A breakend is described by a sequence (`t`), the side of the sequence on which the join
+/// occurs, and, unless it is a single breakend, its mate position (`p`). The four forms of a
+/// mated breakend (§ 5.4 "Specifying complex rearrangements with breakends") are
+///
+/// | form    | orientation | mate orientation |
+/// |---------|-------------|------------------|
+/// | `t[p[`  | right       | left             |
+/// | `t]p]`  | right       | right            |
+/// | `]p]t`  | left        | right            |
+/// | `[p[t`  | left        | left             |
+///
+/// Single breakends are written as `t.` (right) and `.t` (left).
+///
+/// # Examples
+///
+/// ```
+/// use noodles_vcf::record::alternate_bases::allele::{
+///     breakend::Orientation,
+///     Breakend,
+/// };
+///
+/// let breakend: Breakend = "G]sq0:8]".parse()?;
+///
+/// assert_eq!(breakend.inserted_sequence(), "G");
+/// assert_eq!(breakend.orientation(), Orientation::Right);
+/// assert_eq!(breakend.mate_chromosome().map(|c| c.to_string()), Some(String::from("sq0")));
+/// assert_eq!(breakend.mate_position().map(usize::from), Some(8));
+/// assert_eq!(breakend.mate().map(|mate| mate.orientation()), Some(Orientation::Right));
+///
+/// assert_eq!(breakend.to_string(), "G]sq0:8]");
+/// # Ok::<_, noodles_vcf::record::alternate_bases::allele::breakend::ParseError>(())
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Breakend {
+    inserted_sequence: String,
+    orientation: Orientation,
+    mate: Option<Mate>,
+}
+
+impl Breakend {
+    /// Creates a breakend.
+    ///
+    /// The mate is `None` for single breakends.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::{
+    ///     alternate_bases::allele::{
+    ///         breakend::{Mate, Orientation},
+    ///         Breakend,
+    ///     },
+    ///     Position,
+    /// };
+    ///
+    /// let mate = Mate::new("sq1".parse()?, Position::from(13), Orientation::Left);
+    /// let breakend = Breakend::new(String::from("C"), Orientation::Right, Some(mate));
+    /// assert_eq!(breakend.to_string(), "C[sq1:13[");
+    ///
+    /// let breakend = Breakend::new(String::from("CT"), Orientation::Right, None);
+    /// assert_eq!(breakend.to_string(), "CT.");
+    /// # Ok::<_, noodles_vcf::record::chromosome::ParseError>(())
+    /// ```
+    pub fn new(inserted_sequence: String, orientation: Orientation, mate: Option<Mate>) -> Self {
+        Self {
+            inserted_sequence,
+            orientation,
+            mate,
+        }
+    }
+
+    /// Returns the sequence (`t`).
+    ///
+    /// This is the reference base at the record position followed by any inserted bases.
+    pub fn inserted_sequence(&self) -> &str {
+        &self.inserted_sequence
+    }
+
+    /// Returns the side of the sequence on which the join occurs.
+    pub fn orientation(&self) -> Orientation {
+        self.orientation
+    }
+
+    /// Returns the mate, if the breakend is not a single breakend.
+    pub fn mate(&self) -> Option<&Mate> {
+        self.mate.as_ref()
+    }
+
+    /// Returns the chromosome of the mate.
+    pub fn mate_chromosome(&self) -> Option<&Chromosome> {
+        self.mate().map(|mate| mate.chromosome())
+    }
+
+    /// Returns the position of the mate.
+    pub fn mate_position(&self) -> Option<Position> {
+        self.mate().map(|mate| mate.position())
+    }
+
+    /// Returns whether the breakend is a single breakend, i.e., it has no mate.
+    pub fn is_single(&self) -> bool {
+        self.mate.is_none()
+    }
+}
+
+impl fmt::Display for Breakend {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sequence = &self.inserted_sequence;
+
+        match (&self.mate, self.orientation) {
+            (None, Orientation::Right) => write!(f, "{sequence}{MISSING}"),
+            (None, Orientation::Left) => write!(f, "{MISSING}{sequence}"),
+            (Some(mate), orientation) => {
+                let bracket = match mate.orientation {
+                    Orientation::Left => '[',
+                    Orientation::Right => ']',
+                };
+
+                if orientation == Orientation::Right {
+                    f.write_str(sequence)?;
+                }
+
+                f.write_char(bracket)?;
+                write!(f, "{}{MATE_SEPARATOR}{}", mate.chromosome, mate.position)?;
+                f.write_char(bracket)?;
+
+                if orientation == Orientation::Left {
+                    f.write_str(sequence)?;
+                }
+
+                Ok(())
+            }
+        }
+    }
+}
+
+/// An error returned when a raw VCF record alternate bases allele breakend fails to parse.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The input is empty.
+    Empty,
+    /// The input is invalid.
+    Invalid,
+    /// The sequence is invalid.
+    InvalidSequence,
+    /// The mate chromosome is invalid.
+    InvalidMateChromosome(chromosome::ParseError),
+    /// The mate position is invalid.
+    InvalidMatePosition(position::ParseError),
+}
+
+impl error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::InvalidMateChromosome(e) => Some(e),
+            Self::InvalidMatePosition(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("empty input"),
+            Self::Invalid => f.write_str("invalid input"),
+            Self::InvalidSequence => f.write_str("invalid sequence"),
+            Self::InvalidMateChromosome(_) => f.write_str("invalid mate chromosome"),
+            Self::InvalidMatePosition(_) => f.write_str("invalid mate position"),
+        }
+    }
+}
+
+impl FromStr for Breakend {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(ParseError::Empty);
+        }
+
+        match s.find(is_bracket) {
+            Some(0) => {
+                let bracket = s.as_bytes()[0];
+                let t = &s[1..];
+                let i = t.find(char::from(bracket)).ok_or(ParseError::Invalid)?;
+                let (p, sequence) = (&t[..i], &t[i + 1..]);
+
+                let mate = parse_mate(p, bracket)?;
+                let sequence = parse_sequence(sequence)?;
+
+                Ok(Self::new(sequence, Orientation::Left, Some(mate)))
+            }
+            Some(i) => {
+                let (sequence, t) = s.split_at(i);
+                let bracket = t.as_bytes()[0];
+
+                let p = t[1..]
+                    .strip_suffix(char::from(bracket))
+                    .ok_or(ParseError::Invalid)?;
+
+                let sequence = parse_sequence(sequence)?;
+                let mate = parse_mate(p, bracket)?;
+
+                Ok(Self::new(sequence, Orientation::Right, Some(mate)))
+            }
+            None => {
+                if let Some(sequence) = s.strip_suffix(MISSING) {
+                    parse_sequence(sequence).map(|t| Self::new(t, Orientation::Right, None))
+                } else if let Some(sequence) = s.strip_prefix(MISSING) {
+                    parse_sequence(sequence).map(|t| Self::new(t, Orientation::Left, None))
+                } else {
+                    Err(ParseError::Invalid)
+                }
+            }
+        }
+    }
+}
+
+fn is_bracket(c: char) -> bool {
+    matches!(c, '[' | ']')
+}
+
+fn parse_sequence(s: &str) -> Result<String, ParseError> {
+    if !s.is_empty() && s.chars().all(|c| c.is_ascii_alphabetic()) {
+        Ok(s.into())
+    } else {
+        Err(ParseError::InvalidSequence)
+    }
+}
+
+fn parse_mate(s: &str, bracket: u8) -> Result<Mate, ParseError> {
+    if s.contains(is_bracket) {
+        return Err(ParseError::Invalid);
+    }
+
+    let (raw_chromosome, raw_position) =
+        s.rsplit_once(MATE_SEPARATOR).ok_or(ParseError::Invalid)?;
+
+    let chromosome = raw_chromosome
+        .parse()
+        .map_err(ParseError::InvalidMateChromosome)?;
+
+    let position = raw_position
+        .parse()
+        .map_err(ParseError::InvalidMatePosition)?;
+
+    let orientation = if bracket == b'[' {
+        Orientation::Left
+    } else {
+        Orientation::Right
+    };
+
+    Ok(Mate::new(chromosome, position, orientation))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mate(chromosome: &str, position: usize, orientation: Orientation) -> Mate {
+        Mate::new(
+            Chromosome::Name(chromosome.into()),
+            Position::from(position),
+            orientation,
+        )
+    }
+
+    #[test]
+    fn test_fmt() {
+        let breakend = Breakend::new(
+            String::from("G"),
+            Orientation::Right,
+            Some(mate("sq0", 8, Orientation::Left)),
+        );
+        assert_eq!(breakend.to_string(), "G[sq0:8[");
+
+        let breakend = Breakend::new(
+            String::from("G"),
+            Orientation::Right,
+            Some(mate("sq0", 8, Orientation::Right)),
+        );
+        assert_eq!(breakend.to_string(), "G]sq0:8]");
+
+        let breakend = Breakend::new(
+            String::from("A"),
+            Orientation::Left,
+            Some(mate("sq0", 5, Orientation::Right)),
+        );
+        assert_eq!(breakend.to_string(), "]sq0:5]A");
+
+        let breakend = Breakend::new(
+            String::from("A"),
+            Orientation::Left,
+            Some(mate("sq0", 5, Orientation::Left)),
+        );
+        assert_eq!(breakend.to_string(), "[sq0:5[A");
+
+        let breakend = Breakend::new(String::from("CT"), Orientation::Right, None);
+        assert_eq!(breakend.to_string(), "CT.");
+
+        let breakend = Breakend::new(String::from("GC"), Orientation::Left, None);
+        assert_eq!(breakend.to_string(), ".GC");
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(
+            "G[sq0:8[".parse(),
+            Ok(Breakend::new(
+                String::from("G"),
+                Orientation::Right,
+                Some(mate("sq0", 8, Orientation::Left)),
+            ))
+        );
+
+        assert_eq!(
+            "GAC]sq0:8]".parse(),
+            Ok(Breakend::new(
+                String::from("GAC"),
+                Orientation::Right,
+                Some(mate("sq0", 8, Orientation::Right)),
+            ))
+        );
+
+        assert_eq!(
+            "]sq0:5]A".parse(),
+            Ok(Breakend::new(
+                String::from("A"),
+                Orientation::Left,
+                Some(mate("sq0", 5, Orientation::Right)),
+            ))
+        );
+
+        assert_eq!(
+            "[HLA-A*01:01:01:01:13[A".parse(),
+            Ok(Breakend::new(
+                String::from("A"),
+                Orientation::Left,
+                Some(mate("HLA-A*01:01:01:01", 13, Orientation::Left)),
+            ))
+        );
+
+        assert_eq!(
+            "C[<ctg1>:1[".parse(),
+            Ok(Breakend::new(
+                String::from("C"),
+                Orientation::Right,
+                Some(Mate::new(
+                    Chromosome::Symbol(String::from("ctg1")),
+                    Position::from(1),
+                    Orientation::Left,
+                )),
+            ))
+        );
+
+        assert_eq!(
+            "CT.".parse(),
+            Ok(Breakend::new(String::from("CT"), Orientation::Right, None))
+        );
+
+        assert_eq!(
+            ".GC".parse(),
+            Ok(Breakend::new(String::from("GC"), Orientation::Left, None))
+        );
+
+        assert_eq!("".parse::<Breakend>(), Err(ParseError::Empty));
+        assert_eq!("G".parse::<Breakend>(), Err(ParseError::Invalid));
+        assert_eq!("G[sq0:8]".parse::<Breakend>(), Err(ParseError::Invalid));
+        assert_eq!("G[sq0[".parse::<Breakend>(), Err(ParseError::Invalid));
+        assert_eq!(
+            "[sq0:8[".parse::<Breakend>(),
+            Err(ParseError::InvalidSequence)
+        );
+        assert_eq!(".".parse::<Breakend>(), Err(ParseError::InvalidSequence));
+        assert!(matches!(
+            "G[sq0:x[".parse::<Breakend>(),
+            Err(ParseError::InvalidMatePosition(_))
+        ));
+    }
+}
@@ -17,6 +17,55 @@ pub enum Symbol {
     Unspecified,
 }
 
+impl Symbol {
+    /// Returns the structural variant type, if the symbol is a structural variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::alternate_bases::allele::{
+    ///     symbol::structural_variant::Type,
+    ///     Symbol,
+    /// };
+    ///
+    /// let symbol: Symbol = "DUP:TANDEM".parse()?;
+    /// assert_eq!(symbol.ty(), Some(Type::Duplication));
+    ///
+    /// let symbol: Symbol = "CN:0".parse()?;
+    /// assert!(symbol.ty().is_none());
+    /// # Ok::<_, noodles_vcf::record::alternate_bases::allele::symbol::ParseError>(())
+    /// ```
+    pub fn ty(&self) -> Option<structural_variant::Type> {
+        match self {
+            Self::StructuralVariant(sv) => Some(sv.ty()),
+            _ => None,
+        }
+    }
+
+    /// Returns the structural variant subtypes.
+    ///
+    /// This is empty if the symbol is not a structural variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::alternate_bases::allele::Symbol;
+    ///
+    /// let symbol: Symbol = "INS:ME:ALU".parse()?;
+    /// assert_eq!(symbol.subtypes(), [String::from("ME"), String::from("ALU")]);
+    ///
+    /// let symbol: Symbol = "CN:0".parse()?;
+    /// assert!(symbol.subtypes().is_empty());
+    /// # Ok::<_, noodles_vcf::record::alternate_bases::allele::symbol::ParseError>(())
+    /// ```
+    pub fn subtypes(&self) -> &[String] {
+        match self {
+            Self::StructuralVariant(sv) => sv.subtypes(),
+            _ => &[],
+        }
+    }
+}
+
 impl fmt::Display for Symbol {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -69,6 +69,36 @@ impl StructuralVariant {
     pub fn subtypes(&self) -> &[String] {
         &self.subtypes
     }
+
+    /// Returns whether the structural variant is of the given type and subtype hierarchy.
+    ///
+    /// The given subtypes are matched as a prefix of the structural variant subtypes, i.e., a
+    /// structural variant matches its type and any of its ancestors in the subtype hierarchy.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::alternate_bases::allele::symbol::{
+    ///     structural_variant::Type,
+    ///     StructuralVariant,
+    /// };
+    ///
+    /// let structural_variant: StructuralVariant = "INS:ME:ALU".parse()?;
+    ///
+    /// assert!(structural_variant.matches(Type::Insertion, &[]));
+    /// assert!(structural_variant.matches(Type::Insertion, &["ME"]));
+    /// assert!(structural_variant.matches(Type::Insertion, &["ME", "ALU"]));
+    ///
+    /// assert!(!structural_variant.matches(Type::Deletion, &[]));
+    /// assert!(!structural_variant.matches(Type::Insertion, &["ME", "L1"]));
+    /// assert!(!structural_variant.matches(Type::Insertion, &["ME", "ALU", "Y"]));
+    /// # Ok::<_, noodles_vcf::record::alternate_bases::allele::symbol::structural_variant::ParseError>(())
+    /// ```
+    pub fn matches(&self, ty: Type, subtypes: &[&str]) -> bool {
+        self.ty == ty
+            && self.subtypes.len() >= subtypes.len()
+            && self.subtypes.iter().zip(subtypes).all(|(a, b)| a == b)
+    }
 }
 
 impl fmt::Display for StructuralVariant {
@@ -99,6 +129,8 @@ pub enum ParseError {
     MissingType,
     /// The type is invalid.
     InvalidType(ty::ParseError),
+    /// A subtype is invalid.
+    InvalidSubtype,
 }
 
 impl error::Error for ParseError {
@@ -116,6 +148,7 @@ impl fmt::Display for ParseError {
             Self::Empty => f.write_str("empty input"),
             Self::MissingType => f.write_str("missing type"),
             Self::InvalidType(_) => f.write_str("invalid type"),
+            Self::InvalidSubtype => f.write_str("invalid subtype"),
         }
     }
 }
@@ -135,7 +168,15 @@ impl FromStr for StructuralVariant {
             .ok_or(ParseError::MissingType)
             .and_then(|s| s.parse().map_err(ParseError::InvalidType))?;
 
-        let subtypes = components.map(|s| s.into()).collect();
+        let subtypes = components
+            .map(|s| {
+                if s.is_empty() {
+                    Err(ParseError::InvalidSubtype)
+                } else {
+                    Ok(s.into())
+                }
+            })
+            .collect::<Result<_, _>>()?;
 
         Ok(Self::new(ty, subtypes))
     }
@@ -160,6 +201,19 @@ mod tests {
         assert_eq!(symbol.to_string(), "DEL:ME:ALU");
     }
 
+    #[test]
+    fn test_matches() {
+        let symbol = StructuralVariant::new(Type::Duplication, vec![String::from("TANDEM")]);
+        assert!(symbol.matches(Type::Duplication, &[]));
+        assert!(symbol.matches(Type::Duplication, &["TANDEM"]));
+        assert!(!symbol.matches(Type::Duplication, &["ME"]));
+        assert!(!symbol.matches(Type::Deletion, &["TANDEM"]));
+
+        let symbol = StructuralVariant::from(Type::CopyNumberVariation);
+        assert!(symbol.matches(Type::CopyNumberVariation, &[]));
+        assert!(!symbol.matches(Type::CopyNumberVariation, &["TR"]));
+    }
+
     #[test]
     fn test_from_type_for_symbol() {
         assert_eq!(
@@ -192,6 +246,14 @@ mod tests {
         );
 
         assert_eq!("".parse::<StructuralVariant>(), Err(ParseError::Empty));
+        assert_eq!(
+            "DEL:".parse::<StructuralVariant>(),
+            Err(ParseError::InvalidSubtype)
+        );
+        assert_eq!(
+            "DEL::ALU".parse::<StructuralVariant>(),
+            Err(ParseError::InvalidSubtype)
+        );
         assert!(matches!(
             "NDL".parse::<StructuralVariant>(),
             Err(ParseError::InvalidType(_))
@@ -0,0 +1,277 @@
+//! VCF record field value cardinality validation.
+
+use std::{error, fmt};
+
+use super::{genotypes::genotype::field::Value as GenotypeFieldValue, multiallelic, Record};
+use crate::{
+    header::{format, info, Number},
+    Header,
+};
+
+// § 1.6.2 Genotype fields (2022-08-05): INFO fields with `Number=G` are assumed to be diploid.
+pub(super) const INFO_PLOIDY: usize = 2;
+
+/// An error returned when a VCF record field value has an invalid number of values.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum CardinalityError {
+    /// An INFO field value has an invalid number of values.
+    InvalidInfoFieldValueCount {
+        /// The field key.
+        key: info::Key,
+        /// The header-declared number.
+        number: Number,
+        /// The expected number of values.
+        expected: usize,
+        /// The actual number of values.
+        actual: usize,
+    },
+    /// A genotype field value has an invalid number of values.
+    InvalidGenotypeFieldValueCount {
+        /// The sample index.
+        sample_index: usize,
+        /// The field key.
+        key: format::Key,
+        /// The header-declared number.
+        number: Number,
+        /// The expected number of values.
+        expected: usize,
+        /// The actual number of values.
+        actual: usize,
+    },
+}
+
+impl error::Error for CardinalityError {}
+
+impl fmt::Display for CardinalityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidInfoFieldValueCount {
+                key,
+                number,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "invalid INFO field value count: {key} (Number={number}): expected {expected}, got {actual}"
+            ),
+            Self::InvalidGenotypeFieldValueCount {
+                sample_index,
+                key,
+                number,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "invalid genotype field value count: sample {sample_index}: {key} (Number={number}): expected {expected}, got {actual}"
+            ),
+        }
+    }
+}
+
+pub(super) fn validate(record: &Record, header: &Header) -> Result<(), CardinalityError> {
+    let alternate_allele_count = record.alternate_bases().len();
+
+    for (key, value) in record.info().keys().zip(record.info().values()) {
+        let (info, value) = match (header.infos().get(key), value) {
+            (Some(info), Some(value)) => (info, value),
+            _ => continue,
+        };
+
+        let number = info.number();
+
+        if let Some(expected) = expected_count(number, alternate_allele_count, INFO_PLOIDY) {
+            let actual = value.len();
+
+            if actual != expected {
+                return Err(CardinalityError::InvalidInfoFieldValueCount {
+                    key: key.clone(),
+                    number,
+                    expected,
+                    actual,
+                });
+            }
+        }
+    }
+
+    for (sample_index, genotype) in record.genotypes().iter().enumerate() {
+        let ploidy = genotype
+            .genotype()
+            .and_then(|result| result.ok())
+            .map(|gt| gt.ploidy());
+
+        for (key, value) in genotype.iter() {
+            if *key == format::key::GENOTYPE {
+                continue;
+            }
+
+            let (format, value) = match (header.formats().get(key), value) {
+                (Some(format), Some(value)) => (format, value),
+                _ => continue,
+            };
+
+            let number = format.number();
+
+            // The expected number of `Number=G` values is unknown without a ploidy.
+            let expected = match (number, ploidy) {
+                (Number::G, None) => None,
+                (_, ploidy) => expected_count(number, alternate_allele_count, ploidy.unwrap_or(0)),
+            };
+
+            if let Some(expected) = expected {
+                let actual = genotype_field_value_len(value);
+
+                if actual != expected {
+                    return Err(CardinalityError::InvalidGenotypeFieldValueCount {
+                        sample_index,
+                        key: key.clone(),
+                        number,
+                        expected,
+                        actual,
+                    });
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+pub(super) fn expected_count(
+    number: Number,
+    alternate_allele_count: usize,
+    ploidy: usize,
+) -> Option<usize> {
+    match number {
+        // Flags have no values.
+        Number::Count(0) => None,
+        Number::Count(n) => Some(n),
+        Number::A => Some(alternate_allele_count),
+        Number::R => Some(alternate_allele_count + 1),
+        Number::G => {
+            let allele_count = alternate_allele_count + 1;
+            Some(multiallelic::binomial(allele_count + ploidy - 1, ploidy))
+        }
+        Number::Unknown => None,
+    }
+}
+
+fn genotype_field_value_len(value: &GenotypeFieldValue) -> usize {
+    match value {
+        GenotypeFieldValue::Integer(_)
+        | GenotypeFieldValue::Float(_)
+        | GenotypeFieldValue::Character(_)
+        | GenotypeFieldValue::String(_) => 1,
+        GenotypeFieldValue::IntegerArray(values) => values.len(),
+        GenotypeFieldValue::FloatArray(values) => values.len(),
+        GenotypeFieldValue::CharacterArray(values) => values.len(),
+        GenotypeFieldValue::StringArray(values) => values.len(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::header::record::value::{
+        map::{Format, Info},
+        Map,
+    };
+
+    fn build_header() -> Header {
+        use crate::header::{format::key as format_key, info::key as info_key};
+
+        Header::builder()
+            .add_info(
+                info_key::TOTAL_DEPTH,
+                Map::<Info>::from(&info_key::TOTAL_DEPTH),
+            )
+            .add_info(
+                info_key::ALLELE_COUNT,
+                Map::<Info>::from(&info_key::ALLELE_COUNT),
+            )
+            .add_info(
+                info_key::TOTAL_READ_DEPTHS,
+                Map::<Info>::from(&info_key::TOTAL_READ_DEPTHS),
+            )
+            .add_format(
+                format_key::GENOTYPE,
+                Map::<Format>::from(&format_key::GENOTYPE),
+            )
+            .add_format(
+                format_key::ROUNDED_GENOTYPE_LIKELIHOODS,
+                Map::<Format>::from(&format_key::ROUNDED_GENOTYPE_LIKELIHOODS),
+            )
+            .add_sample_name("sample0")
+            .add_sample_name("sample1")
+            .build()
+    }
+
+    #[test]
+    fn test_validate() -> Result<(), Box<dyn std::error::Error>> {
+        let header = build_header();
+
+        let record = Record::try_from_str(
+            "sq0\t1\t.\tA\tC,G\t.\tPASS\tDP=8;AC=3,1;AD=5,2,1\tGT:PL\t0/1:0,1,2,3,4,5\t1:0,1,2",
+            &header,
+        )?;
+        assert_eq!(validate(&record, &header), Ok(()));
+
+        let record = Record::try_from_str(
+            "sq0\t1\t.\tA\tC,G\t.\tPASS\tAC=3\tGT:PL\t0/1:0,1,2,3,4,5\t1:0,1,2",
+            &header,
+        )?;
+        assert_eq!(
+            validate(&record, &header),
+            Err(CardinalityError::InvalidInfoFieldValueCount {
+                key: info::key::ALLELE_COUNT,
+                number: Number::A,
+                expected: 2,
+                actual: 1,
+            })
+        );
+
+        let record = Record::try_from_str(
+            "sq0\t1\t.\tA\tC,G\t.\tPASS\tAD=5,2\tGT:PL\t0/1:0,1,2,3,4,5\t1:0,1,2",
+            &header,
+        )?;
+        assert_eq!(
+            validate(&record, &header),
+            Err(CardinalityError::InvalidInfoFieldValueCount {
+                key: info::key::TOTAL_READ_DEPTHS,
+                number: Number::R,
+                expected: 3,
+                actual: 2,
+            })
+        );
+
+        let record = Record::try_from_str(
+            "sq0\t1\t.\tA\tC,G\t.\tPASS\t.\tGT:PL\t0/1:0,1,2,3,4,5\t1:0,1,2,3,4,5",
+            &header,
+        )?;
+        assert_eq!(
+            validate(&record, &header),
+            Err(CardinalityError::InvalidGenotypeFieldValueCount {
+                sample_index: 1,
+                key: format::key::ROUNDED_GENOTYPE_LIKELIHOODS,
+                number: Number::G,
+                expected: 3,
+                actual: 6,
+            })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate_with_missing_values() -> Result<(), Box<dyn std::error::Error>> {
+        let header = build_header();
+
+        let record = Record::try_from_str(
+            "sq0\t1\t.\tA\tC,G\t.\tPASS\tAC=.\tGT:PL\t.:0,1,2\t./.:.",
+            &header,
+        )?;
+
+        assert_eq!(validate(&record, &header), Ok(()));
+
+        Ok(())
+    }
+}
@@ -39,6 +39,23 @@ impl fmt::Display for TryFromIteratorError {
     }
 }
 
+/// An error returned when a filter ID fails to be added to filters.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum InsertError {
+    /// The filter ID is invalid.
+    InvalidFilter(String),
+}
+
+impl error::Error for InsertError {}
+
+impl fmt::Display for InsertError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidFilter(s) => write!(f, "invalid filter: {s}"),
+        }
+    }
+}
+
 impl Filters {
     /// Performs a conversion from a string iterator to a set of filters.
     ///
@@ -83,6 +100,153 @@ impl Filters {
             Ok(Self::Fail(filters))
         }
     }
+
+    /// Returns whether the filters are a pass (`PASS`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::Filters;
+    /// assert!(Filters::Pass.is_pass());
+    /// assert!(!Filters::try_from_iter(["q10"])?.is_pass());
+    /// # Ok::<(), noodles_vcf::record::filters::TryFromIteratorError>(())
+    /// ```
+    pub fn is_pass(&self) -> bool {
+        matches!(self, Self::Pass)
+    }
+
+    /// Returns whether the filters contain the given filter ID.
+    ///
+    /// A pass only contains `PASS`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::Filters;
+    ///
+    /// assert!(Filters::Pass.contains("PASS"));
+    ///
+    /// let filters = Filters::try_from_iter(["q10", "s50"])?;
+    /// assert!(filters.contains("q10"));
+    /// assert!(!filters.contains("PASS"));
+    /// # Ok::<(), noodles_vcf::record::filters::TryFromIteratorError>(())
+    /// ```
+    pub fn contains(&self, id: &str) -> bool {
+        match self {
+            Self::Pass => id == PASS_STATUS,
+            Self::Fail(ids) => ids.contains(id),
+        }
+    }
+
+    /// Returns an iterator over the filter IDs.
+    ///
+    /// A pass yields `PASS`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::Filters;
+    ///
+    /// assert!(Filters::Pass.iter().eq(["PASS"]));
+    ///
+    /// let filters = Filters::try_from_iter(["q10", "s50"])?;
+    /// assert!(filters.iter().eq(["q10", "s50"]));
+    /// # Ok::<(), noodles_vcf::record::filters::TryFromIteratorError>(())
+    /// ```
+    pub fn iter(&self) -> Box<dyn Iterator<Item = &str> + '_> {
+        match self {
+            Self::Pass => Box::new(std::iter::once(PASS_STATUS)),
+            Self::Fail(ids) => Box::new(ids.iter().map(|id| id.as_str())),
+        }
+    }
+
+    /// Adds a filter ID.
+    ///
+    /// Adding a failing filter to a pass replaces `PASS`, e.g., when applying a soft filter.
+    /// Adding `PASS` replaces all failing filters. This returns whether the filters changed.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the filter ID is invalid, e.g., empty, `0`, or containing
+    /// whitespace.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::Filters;
+    ///
+    /// let mut filters = Filters::Pass;
+    ///
+    /// assert!(filters.insert("q10")?);
+    /// assert!(filters.insert("s50")?);
+    /// assert!(!filters.insert("q10")?);
+    /// assert_eq!(filters, Filters::try_from_iter(["q10", "s50"])?);
+    ///
+    /// assert!(filters.insert("q 10").is_err());
+    ///
+    /// assert!(filters.insert("PASS")?);
+    /// assert_eq!(filters, Filters::Pass);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn insert<I>(&mut self, id: I) -> Result<bool, InsertError>
+    where
+        I: Into<String>,
+    {
+        let id = id.into();
+
+        if !is_valid_filter(&id) {
+            return Err(InsertError::InvalidFilter(id));
+        }
+
+        if id == PASS_STATUS {
+            let is_changed = !self.is_pass();
+            *self = Self::Pass;
+            return Ok(is_changed);
+        }
+
+        match self {
+            Self::Pass => {
+                *self = Self::Fail([id].into_iter().collect());
+                Ok(true)
+            }
+            Self::Fail(ids) => Ok(ids.insert(id)),
+        }
+    }
+
+    /// Removes a failing filter ID.
+    ///
+    /// Removing the last failing filter results in a pass. `PASS` cannot be removed. This returns
+    /// whether the filter ID was removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::Filters;
+    ///
+    /// let mut filters = Filters::try_from_iter(["q10", "s50"])?;
+    ///
+    /// assert!(filters.remove("q10"));
+    /// assert!(!filters.remove("q10"));
+    /// assert_eq!(filters, Filters::try_from_iter(["s50"])?);
+    ///
+    /// assert!(filters.remove("s50"));
+    /// assert_eq!(filters, Filters::Pass);
+    /// # Ok::<(), noodles_vcf::record::filters::TryFromIteratorError>(())
+    /// ```
+    pub fn remove(&mut self, id: &str) -> bool {
+        match self {
+            Self::Pass => false,
+            Self::Fail(ids) => {
+                let is_removed = ids.shift_remove(id);
+
+                if ids.is_empty() {
+                    *self = Self::Pass;
+                }
+
+                is_removed
+            }
+        }
+    }
 }
 
 impl fmt::Display for Filters {
@@ -185,6 +349,51 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_insert() -> Result<(), Box<dyn std::error::Error>> {
+        let mut filters = Filters::Pass;
+        assert!(!filters.insert("PASS")?);
+        assert_eq!(filters, Filters::Pass);
+
+        assert!(filters.insert("q10")?);
+        assert_eq!(filters, Filters::try_from_iter(["q10"])?);
+
+        assert!(filters.insert("s50")?);
+        assert!(!filters.insert("s50")?);
+        assert_eq!(filters, Filters::try_from_iter(["q10", "s50"])?);
+
+        assert_eq!(
+            filters.insert(""),
+            Err(InsertError::InvalidFilter(String::new()))
+        );
+        assert_eq!(
+            filters.insert("q 10"),
+            Err(InsertError::InvalidFilter(String::from("q 10")))
+        );
+        assert_eq!(filters, Filters::try_from_iter(["q10", "s50"])?);
+
+        assert!(filters.insert("PASS")?);
+        assert_eq!(filters, Filters::Pass);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_remove() -> Result<(), TryFromIteratorError> {
+        let mut filters = Filters::Pass;
+        assert!(!filters.remove("PASS"));
+        assert_eq!(filters, Filters::Pass);
+
+        let mut filters = Filters::try_from_iter(["q10", "s50"])?;
+        assert!(!filters.remove("PASS"));
+        assert!(filters.remove("q10"));
+        assert_eq!(filters, Filters::try_from_iter(["s50"])?);
+        assert!(filters.remove("s50"));
+        assert_eq!(filters, Filters::Pass);
+
+        Ok(())
+    }
+
     #[test]
     fn test_fmt() -> Result<(), TryFromIteratorError> {
         assert_eq!(Filters::Pass.to_string(), "PASS");
@@ -14,7 +14,7 @@ use std::{
 
 use self::genotype::field;
 use super::FIELD_DELIMITER;
-use crate::Header;
+use crate::{header::SampleNames, Header};
 
 /// VCF record genotypes.
 #[derive(Clone, Debug, Default, PartialEq)]
@@ -122,6 +122,98 @@ impl Genotypes {
         &mut self.keys
     }
 
+    /// Returns the genotype of the sample with the given name.
+    ///
+    /// The sample index is resolved using the header sample names (`Header::sample_names`), which
+    /// is an indexed set. This makes the lookup constant time without building a separate map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{
+    ///     self as vcf,
+    ///     header::format::key,
+    ///     record::{
+    ///         genotypes::{genotype::field::Value, Keys},
+    ///         Genotypes,
+    ///     },
+    /// };
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .add_sample_name("sample0")
+    ///     .add_sample_name("sample1")
+    ///     .build();
+    ///
+    /// let genotypes = Genotypes::new(
+    ///     Keys::try_from(vec![key::CONDITIONAL_GENOTYPE_QUALITY])?,
+    ///     vec![
+    ///         [(key::CONDITIONAL_GENOTYPE_QUALITY, Some(Value::Integer(13)))]
+    ///             .into_iter()
+    ///             .collect(),
+    ///         [(key::CONDITIONAL_GENOTYPE_QUALITY, Some(Value::Integer(8)))]
+    ///             .into_iter()
+    ///             .collect(),
+    ///     ],
+    /// );
+    ///
+    /// let genotype = genotypes.get_by_name(header.sample_names(), "sample1");
+    /// assert_eq!(
+    ///     genotype.and_then(|g| g.get(&key::CONDITIONAL_GENOTYPE_QUALITY)),
+    ///     Some(&Some(Value::Integer(8)))
+    /// );
+    ///
+    /// assert!(genotypes.get_by_name(header.sample_names(), "sample2").is_none());
+    /// # Ok::<_, noodles_vcf::record::genotypes::keys::TryFromKeyVectorError>(())
+    /// ```
+    pub fn get_by_name(&self, sample_names: &SampleNames, name: &str) -> Option<&Genotype> {
+        sample_names
+            .get_index_of(name)
+            .and_then(|i| self.genotypes.get(i))
+    }
+
+    /// Returns a mutable reference to the genotype of the sample with the given name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{
+    ///     self as vcf,
+    ///     header::format::key,
+    ///     record::{
+    ///         genotypes::{genotype::field::Value, Keys},
+    ///         Genotypes,
+    ///     },
+    /// };
+    ///
+    /// let header = vcf::Header::builder().add_sample_name("sample0").build();
+    ///
+    /// let mut genotypes = Genotypes::new(
+    ///     Keys::try_from(vec![key::CONDITIONAL_GENOTYPE_QUALITY])?,
+    ///     vec![[(key::CONDITIONAL_GENOTYPE_QUALITY, Some(Value::Integer(13)))]
+    ///         .into_iter()
+    ///         .collect()],
+    /// );
+    ///
+    /// if let Some(genotype) = genotypes.get_by_name_mut(header.sample_names(), "sample0") {
+    ///     genotype.insert(key::CONDITIONAL_GENOTYPE_QUALITY, Some(Value::Integer(8)));
+    /// }
+    ///
+    /// assert_eq!(
+    ///     genotypes[0].get(&key::CONDITIONAL_GENOTYPE_QUALITY),
+    ///     Some(&Some(Value::Integer(8)))
+    /// );
+    /// # Ok::<_, noodles_vcf::record::genotypes::keys::TryFromKeyVectorError>(())
+    /// ```
+    pub fn get_by_name_mut(
+        &mut self,
+        sample_names: &SampleNames,
+        name: &str,
+    ) -> Option<&mut Genotype> {
+        sample_names
+            .get_index_of(name)
+            .and_then(|i| self.genotypes.get_mut(i))
+    }
+
     /// Returns the VCF record genotype value.
     pub fn genotypes(
         &self,
@@ -213,7 +305,7 @@ fn parse(s: &str, header: &Header) -> Result<Genotypes, ParseError> {
 
     let genotypes = t
         .split(FIELD_DELIMITER)
-        .map(|t| Genotype::parse(t, header.formats(), &keys))
+        .map(|t| genotype::parse(t, header.formats(), &keys, header.file_format()))
         .collect::<Result<_, _>>()
         .map_err(ParseError::InvalidGenotype)?;
 
@@ -261,6 +353,42 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_get_by_name() -> Result<(), Box<dyn std::error::Error>> {
+        let header = crate::Header::builder()
+            .add_sample_name("sample0")
+            .add_sample_name("sample1")
+            .build();
+
+        let keys: Keys = "GQ".parse()?;
+        let genotypes = Genotypes::new(
+            keys.clone(),
+            vec![
+                Genotype::parse("13", header.formats(), &keys)?,
+                Genotype::parse("8", header.formats(), &keys)?,
+            ],
+        );
+
+        assert_eq!(
+            genotypes.get_by_name(header.sample_names(), "sample0"),
+            Some(&genotypes[0])
+        );
+        assert_eq!(
+            genotypes.get_by_name(header.sample_names(), "sample1"),
+            Some(&genotypes[1])
+        );
+        assert!(genotypes
+            .get_by_name(header.sample_names(), "sample2")
+            .is_none());
+
+        let genotypes = Genotypes::new(keys, Vec::new());
+        assert!(genotypes
+            .get_by_name(header.sample_names(), "sample0")
+            .is_none());
+
+        Ok(())
+    }
+
     #[test]
     fn test_fmt() -> Result<(), super::keys::TryFromKeyVectorError> {
         use self::genotype::field::Value;
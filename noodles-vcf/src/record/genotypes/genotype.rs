@@ -14,7 +14,7 @@ use crate::{
     header::{
         format::{key, Key},
         record::value::{map::Format, Map},
-        Formats,
+        FileFormat, Formats,
     },
     record::MISSING_FIELD,
 };
@@ -122,33 +122,7 @@ impl Genotype {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     pub fn parse(s: &str, formats: &Formats, keys: &Keys) -> Result<Self, ParseError> {
-        if s.is_empty() {
-            return Err(ParseError::Empty);
-        } else if s == MISSING_FIELD {
-            return Ok(Self::default());
-        }
-
-        let mut fields = Vec::with_capacity(keys.len());
-        let mut raw_values = s.split(DELIMITER);
-
-        for (key, raw_value) in keys.iter().zip(&mut raw_values) {
-            let field = if let Some(format) = formats.get(key) {
-                let value = parse_value(format, raw_value).map_err(ParseError::InvalidField)?;
-                (key.clone(), value)
-            } else {
-                let format = Map::<Format>::from(key);
-                let value = parse_value(&format, raw_value).map_err(ParseError::InvalidField)?;
-                (key.clone(), value)
-            };
-
-            fields.push(field);
-        }
-
-        if raw_values.next().is_some() {
-            Err(ParseError::UnexpectedValue)
-        } else {
-            Self::try_from(fields).map_err(ParseError::Invalid)
-        }
+        parse(s, formats, keys, FileFormat::default())
     }
 
     /// Returns the VCF record genotypes genotype value.
@@ -285,11 +259,52 @@ impl TryFrom<Vec<(Key, Option<field::Value>)>> for Genotype {
     }
 }
 
-fn parse_value(format: &Map<Format>, s: &str) -> Result<Option<field::Value>, field::ParseError> {
+pub(super) fn parse(
+    s: &str,
+    formats: &Formats,
+    keys: &Keys,
+    file_format: FileFormat,
+) -> Result<Genotype, ParseError> {
+    if s.is_empty() {
+        return Err(ParseError::Empty);
+    } else if s == MISSING_FIELD {
+        return Ok(Genotype::default());
+    }
+
+    let mut fields = Vec::with_capacity(keys.len());
+    let mut raw_values = s.split(DELIMITER);
+
+    for (key, raw_value) in keys.iter().zip(&mut raw_values) {
+        let field = if let Some(format) = formats.get(key) {
+            let value =
+                parse_value(format, raw_value, file_format).map_err(ParseError::InvalidField)?;
+            (key.clone(), value)
+        } else {
+            let format = Map::<Format>::from(key);
+            let value =
+                parse_value(&format, raw_value, file_format).map_err(ParseError::InvalidField)?;
+            (key.clone(), value)
+        };
+
+        fields.push(field);
+    }
+
+    if raw_values.next().is_some() {
+        Err(ParseError::UnexpectedValue)
+    } else {
+        Genotype::try_from(fields).map_err(ParseError::Invalid)
+    }
+}
+
+fn parse_value(
+    format: &Map<Format>,
+    s: &str,
+    file_format: FileFormat,
+) -> Result<Option<field::Value>, field::ParseError> {
     if s == "." {
         Ok(None)
     } else {
-        field::Value::from_str_format(s, format)
+        field::Value::try_from((file_format, format.number(), format.ty(), s))
             .map(Some)
             .map_err(field::ParseError::InvalidValue)
     }
@@ -12,9 +12,9 @@ use crate::{
             map::{format::Type, Format},
             Map,
         },
-        Number,
+        FileFormat, Number,
     },
-    record::value::{self, percent_decode},
+    record::value::{self, decode_string},
 };
 
 const DELIMITER: char = ',';
@@ -167,7 +167,7 @@ impl Value {
     /// assert_eq!(Value::from_str_format("13", &format), Ok(Value::Integer(13)));
     /// ```
     pub fn from_str_format(s: &str, format: &Map<Format>) -> Result<Self, ParseError> {
-        parse(format.number(), format.ty(), s)
+        parse(FileFormat::default(), format.number(), format.ty(), s)
     }
 }
 
@@ -175,11 +175,21 @@ impl TryFrom<(Number, Type, &str)> for Value {
     type Error = ParseError;
 
     fn try_from((number, ty, s): (Number, Type, &str)) -> Result<Self, Self::Error> {
-        parse(number, ty, s)
+        Self::try_from((FileFormat::default(), number, ty, s))
     }
 }
 
-fn parse(number: Number, ty: Type, s: &str) -> Result<Value, ParseError> {
+impl TryFrom<(FileFormat, Number, Type, &str)> for Value {
+    type Error = ParseError;
+
+    fn try_from(
+        (file_format, number, ty, s): (FileFormat, Number, Type, &str),
+    ) -> Result<Self, Self::Error> {
+        parse(file_format, number, ty, s)
+    }
+}
+
+fn parse(file_format: FileFormat, number: Number, ty: Type, s: &str) -> Result<Value, ParseError> {
     match ty {
         Type::Integer => match number {
             Number::Count(0) => Err(ParseError::InvalidNumberForType(number, ty)),
@@ -193,13 +203,13 @@ fn parse(number: Number, ty: Type, s: &str) -> Result<Value, ParseError> {
         },
         Type::Character => match number {
             Number::Count(0) => Err(ParseError::InvalidNumberForType(number, ty)),
-            Number::Count(1) => parse_char(s),
-            _ => parse_char_array(s),
+            Number::Count(1) => parse_char(file_format, s),
+            _ => parse_char_array(file_format, s),
         },
         Type::String => match number {
             Number::Count(0) => Err(ParseError::InvalidNumberForType(number, ty)),
-            Number::Count(1) => parse_string(s),
-            _ => parse_string_array(s),
+            Number::Count(1) => parse_string(file_format, s),
+            _ => parse_string_array(file_format, s),
         },
     }
 }
@@ -244,8 +254,9 @@ fn parse_f32_array(s: &str) -> Result<Value, ParseError> {
         .map(Value::FloatArray)
 }
 
-fn parse_raw_char(s: &str) -> Result<char, ParseError> {
-    let mut chars = s.chars();
+fn parse_raw_char(file_format: FileFormat, s: &str) -> Result<char, ParseError> {
+    let t = decode_string(file_format, s).map_err(|_| ParseError::InvalidCharacter)?;
+    let mut chars = t.chars();
 
     if let Some(c) = chars.next() {
         if chars.next().is_none() {
@@ -256,36 +267,36 @@ fn parse_raw_char(s: &str) -> Result<char, ParseError> {
     Err(ParseError::InvalidCharacter)
 }
 
-fn parse_char(s: &str) -> Result<Value, ParseError> {
-    parse_raw_char(s).map(Value::Character)
+fn parse_char(file_format: FileFormat, s: &str) -> Result<Value, ParseError> {
+    parse_raw_char(file_format, s).map(Value::Character)
 }
 
-fn parse_char_array(s: &str) -> Result<Value, ParseError> {
+fn parse_char_array(file_format: FileFormat, s: &str) -> Result<Value, ParseError> {
     s.split(DELIMITER)
         .map(|t| {
             if t == MISSING_VALUE {
                 Ok(None)
             } else {
-                parse_raw_char(t).map(Some)
+                parse_raw_char(file_format, t).map(Some)
             }
         })
         .collect::<Result<_, _>>()
         .map(Value::CharacterArray)
 }
 
-fn parse_string(s: &str) -> Result<Value, ParseError> {
-    percent_decode(s)
+fn parse_string(file_format: FileFormat, s: &str) -> Result<Value, ParseError> {
+    decode_string(file_format, s)
         .map(|t| Value::String(t.into()))
         .map_err(ParseError::InvalidString)
 }
 
-fn parse_string_array(s: &str) -> Result<Value, ParseError> {
+fn parse_string_array(file_format: FileFormat, s: &str) -> Result<Value, ParseError> {
     s.split(DELIMITER)
         .map(|t| {
             if t == MISSING_VALUE {
                 Ok(None)
             } else {
-                percent_decode(t)
+                decode_string(file_format, t)
                     .map(|u| Some(u.into()))
                     .map_err(ParseError::InvalidString)
             }
@@ -355,7 +366,7 @@ mod tests {
     #[test]
     fn test_parse_with_integer() {
         assert_eq!(
-            parse(Number::Count(0), Type::Integer, "8"),
+            parse(FileFormat::default(), Number::Count(0), Type::Integer, "8"),
             Err(ParseError::InvalidNumberForType(
                 Number::Count(0),
                 Type::Integer
@@ -363,17 +374,27 @@ mod tests {
         );
 
         assert_eq!(
-            parse(Number::Count(1), Type::Integer, "8"),
+            parse(FileFormat::default(), Number::Count(1), Type::Integer, "8"),
             Ok(Value::Integer(8))
         );
 
         assert_eq!(
-            parse(Number::Count(2), Type::Integer, "8,13"),
+            parse(
+                FileFormat::default(),
+                Number::Count(2),
+                Type::Integer,
+                "8,13"
+            ),
             Ok(Value::IntegerArray(vec![Some(8), Some(13)]))
         );
 
         assert_eq!(
-            parse(Number::Count(2), Type::Integer, "8,."),
+            parse(
+                FileFormat::default(),
+                Number::Count(2),
+                Type::Integer,
+                "8,."
+            ),
             Ok(Value::IntegerArray(vec![Some(8), None]))
         );
     }
@@ -381,7 +402,12 @@ mod tests {
     #[test]
     fn test_parse_with_float() {
         assert_eq!(
-            parse(Number::Count(0), Type::Float, "0.333"),
+            parse(
+                FileFormat::default(),
+                Number::Count(0),
+                Type::Float,
+                "0.333"
+            ),
             Err(ParseError::InvalidNumberForType(
                 Number::Count(0),
                 Type::Float
@@ -389,17 +415,32 @@ mod tests {
         );
 
         assert_eq!(
-            parse(Number::Count(1), Type::Float, "0.333"),
+            parse(
+                FileFormat::default(),
+                Number::Count(1),
+                Type::Float,
+                "0.333"
+            ),
             Ok(Value::Float(0.333))
         );
 
         assert_eq!(
-            parse(Number::Count(2), Type::Float, "0.333,0.667"),
+            parse(
+                FileFormat::default(),
+                Number::Count(2),
+                Type::Float,
+                "0.333,0.667"
+            ),
             Ok(Value::FloatArray(vec![Some(0.333), Some(0.667)]))
         );
 
         assert_eq!(
-            parse(Number::Count(2), Type::Float, "0.333,."),
+            parse(
+                FileFormat::default(),
+                Number::Count(2),
+                Type::Float,
+                "0.333,."
+            ),
             Ok(Value::FloatArray(vec![Some(0.333), None]))
         );
     }
@@ -407,7 +448,12 @@ mod tests {
     #[test]
     fn test_parse_with_character() {
         assert_eq!(
-            parse(Number::Count(0), Type::Character, "n"),
+            parse(
+                FileFormat::default(),
+                Number::Count(0),
+                Type::Character,
+                "n"
+            ),
             Err(ParseError::InvalidNumberForType(
                 Number::Count(0),
                 Type::Character
@@ -415,12 +461,22 @@ mod tests {
         );
 
         assert_eq!(
-            parse(Number::Count(1), Type::Character, "n"),
+            parse(
+                FileFormat::default(),
+                Number::Count(1),
+                Type::Character,
+                "n"
+            ),
             Ok(Value::Character('n'))
         );
 
         assert_eq!(
-            parse(Number::Count(2), Type::Character, "n,d,l,s"),
+            parse(
+                FileFormat::default(),
+                Number::Count(2),
+                Type::Character,
+                "n,d,l,s"
+            ),
             Ok(Value::CharacterArray(vec![
                 Some('n'),
                 Some('d'),
@@ -430,7 +486,12 @@ mod tests {
         );
 
         assert_eq!(
-            parse(Number::Count(2), Type::Character, "n,d,l,."),
+            parse(
+                FileFormat::default(),
+                Number::Count(2),
+                Type::Character,
+                "n,d,l,."
+            ),
             Ok(Value::CharacterArray(vec![
                 Some('n'),
                 Some('d'),
@@ -443,7 +504,12 @@ mod tests {
     #[test]
     fn test_parse_with_string() {
         assert_eq!(
-            parse(Number::Count(0), Type::String, "noodles"),
+            parse(
+                FileFormat::default(),
+                Number::Count(0),
+                Type::String,
+                "noodles"
+            ),
             Err(ParseError::InvalidNumberForType(
                 Number::Count(0),
                 Type::String
@@ -451,34 +517,84 @@ mod tests {
         );
 
         assert_eq!(
-            parse(Number::Count(1), Type::String, "noodles"),
+            parse(
+                FileFormat::default(),
+                Number::Count(1),
+                Type::String,
+                "noodles"
+            ),
             Ok(Value::String(String::from("noodles")))
         );
         assert_eq!(
-            parse(Number::Count(1), Type::String, "8%25"),
+            parse(
+                FileFormat::default(),
+                Number::Count(1),
+                Type::String,
+                "8%25"
+            ),
             Ok(Value::String(String::from("8%")))
         );
 
         assert_eq!(
-            parse(Number::Count(2), Type::String, "noodles,vcf"),
+            parse(
+                FileFormat::default(),
+                Number::Count(2),
+                Type::String,
+                "noodles,vcf"
+            ),
             Ok(Value::StringArray(vec![
                 Some(String::from("noodles")),
                 Some(String::from("vcf"))
             ]))
         );
         assert_eq!(
-            parse(Number::Count(2), Type::String, "8%25,13%25"),
+            parse(
+                FileFormat::default(),
+                Number::Count(2),
+                Type::String,
+                "8%25,13%25"
+            ),
             Ok(Value::StringArray(vec![
                 Some(String::from("8%")),
                 Some(String::from("13%")),
             ]))
         );
         assert_eq!(
-            parse(Number::Count(2), Type::String, "noodles,."),
+            parse(
+                FileFormat::default(),
+                Number::Count(2),
+                Type::String,
+                "noodles,."
+            ),
             Ok(Value::StringArray(vec![
                 Some(String::from("noodles")),
                 None,
             ]))
         );
     }
+
+    #[test]
+    fn test_try_from_file_format_number_type_str_for_value() {
+        let file_format = FileFormat::new(4, 3);
+
+        assert_eq!(
+            Value::try_from((file_format, Number::Count(1), Type::String, "n%3Bd")),
+            Ok(Value::String(String::from("n;d")))
+        );
+        assert_eq!(
+            Value::try_from((file_format, Number::Count(1), Type::Character, "%3B")),
+            Ok(Value::Character(';'))
+        );
+
+        let file_format = FileFormat::new(4, 2);
+
+        assert_eq!(
+            Value::try_from((file_format, Number::Count(1), Type::String, "n%3Bd")),
+            Ok(Value::String(String::from("n%3Bd")))
+        );
+        assert_eq!(
+            Value::try_from((file_format, Number::Count(1), Type::Character, "%3B")),
+            Err(ParseError::InvalidCharacter)
+        );
+    }
 }
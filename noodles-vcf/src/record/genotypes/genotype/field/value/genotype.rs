@@ -15,6 +15,189 @@ use std::{
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Genotype(Vec<Allele>);
 
+impl Genotype {
+    /// Returns the ploidy, i.e., the number of alleles.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::genotypes::genotype::field::value::Genotype;
+    ///
+    /// let genotype: Genotype = "0".parse()?;
+    /// assert_eq!(genotype.ploidy(), 1);
+    ///
+    /// let genotype: Genotype = "0/1/2".parse()?;
+    /// assert_eq!(genotype.ploidy(), 3);
+    /// # Ok::<_, noodles_vcf::record::genotypes::genotype::field::value::genotype::ParseError>(())
+    /// ```
+    pub fn ploidy(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns whether all alleles are phased.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::genotypes::genotype::field::value::Genotype;
+    ///
+    /// let genotype: Genotype = "0|1".parse()?;
+    /// assert!(genotype.is_phased());
+    ///
+    /// let genotype: Genotype = "0/1".parse()?;
+    /// assert!(!genotype.is_phased());
+    /// # Ok::<_, noodles_vcf::record::genotypes::genotype::field::value::genotype::ParseError>(())
+    /// ```
+    pub fn is_phased(&self) -> bool {
+        self.0
+            .iter()
+            .all(|allele| allele.phasing() == allele::Phasing::Phased)
+    }
+
+    /// Returns whether all alleles are missing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::genotypes::genotype::field::value::Genotype;
+    ///
+    /// let genotype: Genotype = "./.".parse()?;
+    /// assert!(genotype.is_missing());
+    ///
+    /// let genotype: Genotype = "./1".parse()?;
+    /// assert!(!genotype.is_missing());
+    /// # Ok::<_, noodles_vcf::record::genotypes::genotype::field::value::genotype::ParseError>(())
+    /// ```
+    pub fn is_missing(&self) -> bool {
+        self.0.iter().all(|allele| allele.position().is_none())
+    }
+
+    /// Returns whether some, but not all, alleles are missing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::genotypes::genotype::field::value::Genotype;
+    ///
+    /// let genotype: Genotype = "./1".parse()?;
+    /// assert!(genotype.is_partially_missing());
+    ///
+    /// let genotype: Genotype = "./.".parse()?;
+    /// assert!(!genotype.is_partially_missing());
+    /// # Ok::<_, noodles_vcf::record::genotypes::genotype::field::value::genotype::ParseError>(())
+    /// ```
+    pub fn is_partially_missing(&self) -> bool {
+        self.has_missing_allele() && !self.is_missing()
+    }
+
+    /// Returns whether the genotype is homozygous for the reference allele.
+    ///
+    /// All alleles must be called.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::genotypes::genotype::field::value::Genotype;
+    ///
+    /// let genotype: Genotype = "0/0".parse()?;
+    /// assert!(genotype.is_hom_ref());
+    ///
+    /// let genotype: Genotype = "0/.".parse()?;
+    /// assert!(!genotype.is_hom_ref());
+    /// # Ok::<_, noodles_vcf::record::genotypes::genotype::field::value::genotype::ParseError>(())
+    /// ```
+    pub fn is_hom_ref(&self) -> bool {
+        self.0.iter().all(|allele| allele.position() == Some(0))
+    }
+
+    /// Returns whether the genotype is homozygous for an alternate allele.
+    ///
+    /// All alleles must be called.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::genotypes::genotype::field::value::Genotype;
+    ///
+    /// let genotype: Genotype = "1/1".parse()?;
+    /// assert!(genotype.is_hom_alt());
+    ///
+    /// let genotype: Genotype = "1/2".parse()?;
+    /// assert!(!genotype.is_hom_alt());
+    /// # Ok::<_, noodles_vcf::record::genotypes::genotype::field::value::genotype::ParseError>(())
+    /// ```
+    pub fn is_hom_alt(&self) -> bool {
+        match self.0.first().and_then(|allele| allele.position()) {
+            Some(0) | None => false,
+            Some(position) => self
+                .0
+                .iter()
+                .all(|allele| allele.position() == Some(position)),
+        }
+    }
+
+    /// Returns whether the genotype is heterozygous, i.e., has at least two distinct alleles.
+    ///
+    /// All alleles must be called. A haploid genotype is never heterozygous.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::genotypes::genotype::field::value::Genotype;
+    ///
+    /// let genotype: Genotype = "0/1".parse()?;
+    /// assert!(genotype.is_het());
+    ///
+    /// let genotype: Genotype = "1/2".parse()?;
+    /// assert!(genotype.is_het());
+    ///
+    /// let genotype: Genotype = "./1".parse()?;
+    /// assert!(!genotype.is_het());
+    /// # Ok::<_, noodles_vcf::record::genotypes::genotype::field::value::genotype::ParseError>(())
+    /// ```
+    pub fn is_het(&self) -> bool {
+        if self.has_missing_allele() {
+            return false;
+        }
+
+        let mut positions = self.0.iter().map(|allele| allele.position());
+        let first = positions.next();
+        positions.any(|position| position != first.flatten())
+    }
+
+    /// Returns the number of times each allele is called.
+    ///
+    /// The count of allele `i` (where 0 is the reference allele) is at index `i`. The list is
+    /// extended to include the largest called allele; missing alleles are not counted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::genotypes::genotype::field::value::Genotype;
+    ///
+    /// let genotype: Genotype = "0/2/2/.".parse()?;
+    /// assert_eq!(genotype.allele_counts(), [1, 0, 2]);
+    /// # Ok::<_, noodles_vcf::record::genotypes::genotype::field::value::genotype::ParseError>(())
+    /// ```
+    pub fn allele_counts(&self) -> Vec<usize> {
+        let mut counts = Vec::new();
+
+        for position in self.0.iter().filter_map(|allele| allele.position()) {
+            if position >= counts.len() {
+                counts.resize(position + 1, 0);
+            }
+
+            counts[position] += 1;
+        }
+
+        counts
+    }
+
+    fn has_missing_allele(&self) -> bool {
+        self.0.iter().any(|allele| allele.position().is_none())
+    }
+}
+
 impl Deref for Genotype {
     type Target = [Allele];
 
@@ -29,6 +212,23 @@ impl DerefMut for Genotype {
     }
 }
 
+impl fmt::Display for Genotype {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, allele) in self.0.iter().enumerate() {
+            if i == 0 {
+                match allele.position() {
+                    Some(position) => write!(f, "{position}")?,
+                    None => f.write_str(".")?,
+                }
+            } else {
+                write!(f, "{allele}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
 impl FromStr for Genotype {
     type Err = ParseError;
 
@@ -75,6 +275,77 @@ impl fmt::Display for TryFromAllelesError {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_fmt() -> Result<(), ParseError> {
+        for s in ["0", "0/1", "0|1", "./.", "./1", "1|2/3"] {
+            let genotype: Genotype = s.parse()?;
+            assert_eq!(genotype.to_string(), s);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_zygosity() -> Result<(), ParseError> {
+        fn zygosity(s: &str) -> Result<(bool, bool, bool), ParseError> {
+            let genotype: Genotype = s.parse()?;
+            Ok((
+                genotype.is_hom_ref(),
+                genotype.is_het(),
+                genotype.is_hom_alt(),
+            ))
+        }
+
+        assert_eq!(zygosity("0")?, (true, false, false));
+        assert_eq!(zygosity("1")?, (false, false, true));
+        assert_eq!(zygosity(".")?, (false, false, false));
+        assert_eq!(zygosity("0/0")?, (true, false, false));
+        assert_eq!(zygosity("0|1")?, (false, true, false));
+        assert_eq!(zygosity("2/2")?, (false, false, true));
+        assert_eq!(zygosity("1/2")?, (false, true, false));
+        assert_eq!(zygosity("./0")?, (false, false, false));
+        assert_eq!(zygosity("./1")?, (false, false, false));
+        assert_eq!(zygosity("0/0/1")?, (false, true, false));
+        assert_eq!(zygosity("1/1/1/1")?, (false, false, true));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_missing() -> Result<(), ParseError> {
+        let genotype: Genotype = ".".parse()?;
+        assert!(genotype.is_missing());
+        assert!(!genotype.is_partially_missing());
+
+        let genotype: Genotype = "./.".parse()?;
+        assert!(genotype.is_missing());
+        assert!(!genotype.is_partially_missing());
+
+        let genotype: Genotype = "0/.".parse()?;
+        assert!(!genotype.is_missing());
+        assert!(genotype.is_partially_missing());
+
+        let genotype: Genotype = "0/1".parse()?;
+        assert!(!genotype.is_missing());
+        assert!(!genotype.is_partially_missing());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_allele_counts() -> Result<(), ParseError> {
+        let genotype: Genotype = "./.".parse()?;
+        assert!(genotype.allele_counts().is_empty());
+
+        let genotype: Genotype = "1|1".parse()?;
+        assert_eq!(genotype.allele_counts(), [0, 2]);
+
+        let genotype: Genotype = "0/3/.".parse()?;
+        assert_eq!(genotype.allele_counts(), [1, 0, 0, 1]);
+
+        Ok(())
+    }
+
     #[test]
     fn test_from_str() {
         use allele::Phasing;
@@ -83,6 +83,18 @@ impl Allele {
     }
 }
 
+impl fmt::Display for Allele {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.phasing)?;
+
+        if let Some(position) = self.position {
+            write!(f, "{position}")
+        } else {
+            f.write_str(MISSING_POSITION)
+        }
+    }
+}
+
 /// An error returned when a raw VCF record genotype value allele fails to parse.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum ParseError {
@@ -141,6 +153,13 @@ pub(super) fn parse_position(s: &str) -> Result<Option<usize>, ParseError> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_fmt() {
+        assert_eq!(Allele::new(None, Phasing::Unphased).to_string(), "/.");
+        assert_eq!(Allele::new(Some(0), Phasing::Unphased).to_string(), "/0");
+        assert_eq!(Allele::new(Some(13), Phasing::Phased).to_string(), "|13");
+    }
+
     #[test]
     fn test_from_str() {
         assert_eq!("/.".parse(), Ok(Allele::new(None, Phasing::Unphased)));
@@ -6,8 +6,11 @@ use std::{error, fmt, str::FromStr};
 
 use indexmap::IndexMap;
 
-use super::MISSING_FIELD;
-use crate::header::{self, info::Key};
+use super::{
+    cardinality::{self, INFO_PLOIDY},
+    MISSING_FIELD,
+};
+use crate::header::{self, info::Key, record::value::map::info::Type, FileFormat, Number};
 
 const DELIMITER: char = ';';
 
@@ -18,7 +21,7 @@ pub struct Info(IndexMap<Key, Option<field::Value>>);
 impl Info {
     /// Parses raw VCF record info.
     pub fn try_from_str(s: &str, infos: &header::Infos) -> Result<Self, ParseError> {
-        parse(s, infos)
+        parse(s, infos, FileFormat::default())
     }
 
     /// Returns the number of info fields.
@@ -92,6 +95,150 @@ impl Info {
         self.0.get(key).map(|value| value.as_ref())
     }
 
+    /// Returns the field value with the given key as the given type.
+    ///
+    /// The value is checked against its definition in the given header INFO records: the defined
+    /// type must be the requested type, and for fields with a known number of values, e.g.,
+    /// `Number=A`, `Number=R`, or `Number=G`, the value must have the expected number of values
+    /// for the given number of alternate alleles.
+    ///
+    /// This returns `Ok(None)` if the field or its value is missing. A single-element array, e.g.,
+    /// a `Number=A` value of a biallelic record, is converted to its only element.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the field is not defined in the header, if the value is not of the
+    /// requested type, if it has an invalid number of values for its definition, or if it holds
+    /// more than one value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{
+    ///     self as vcf,
+    ///     header::info::key,
+    ///     record::{info::field::Value, Info},
+    /// };
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .add_info(key::TOTAL_DEPTH, vcf::header::record::value::Map::from(&key::TOTAL_DEPTH))
+    ///     .add_info(
+    ///         key::ALLELE_FREQUENCIES,
+    ///         vcf::header::record::value::Map::from(&key::ALLELE_FREQUENCIES),
+    ///     )
+    ///     .build();
+    ///
+    /// let dp = (key::TOTAL_DEPTH, Some(Value::Integer(13)));
+    /// let af = (key::ALLELE_FREQUENCIES, Some(Value::FloatArray(vec![Some(0.5)])));
+    /// let info: Info = [dp, af].into_iter().collect();
+    ///
+    /// let infos = header.infos();
+    /// assert_eq!(info.get_as::<i32>(infos, 1, &key::TOTAL_DEPTH), Ok(Some(13)));
+    /// assert_eq!(info.get_as::<f32>(infos, 1, &key::ALLELE_FREQUENCIES), Ok(Some(0.5)));
+    /// assert!(info.get_as::<f32>(infos, 1, &key::TOTAL_DEPTH).is_err());
+    /// assert!(info.get_as::<f32>(infos, 2, &key::ALLELE_FREQUENCIES).is_err());
+    /// ```
+    pub fn get_as<T>(
+        &self,
+        infos: &header::Infos,
+        alternate_allele_count: usize,
+        key: &Key,
+    ) -> Result<Option<T>, GetError>
+    where
+        T: field::value::FromValue,
+    {
+        let value = match self.get(key).flatten() {
+            Some(value) => value,
+            None => return Ok(None),
+        };
+
+        check_value::<T>(infos, alternate_allele_count, key, value)?;
+
+        if let Some(v) = T::from_value(value) {
+            return Ok(Some(v));
+        }
+
+        match T::from_array_value(value) {
+            Some(mut values) if values.len() == 1 => Ok(values.pop().flatten()),
+            _ => Err(GetError::InvalidNumber(key.clone(), value.len())),
+        }
+    }
+
+    /// Returns the field value with the given key as an array of the given type.
+    ///
+    /// The value is checked against its definition in the given header INFO records, as in
+    /// [`Self::get_as`].
+    ///
+    /// This returns `Ok(None)` if the field or its value is missing. A scalar value is converted
+    /// to a single-element array.
+    ///
+    /// For fields with `Number=A` or `Number=R`, the values are ordered by alternate allele or
+    /// allele, respectively.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the field is not defined in the header, if the value is not of the
+    /// requested type, or if it has an invalid number of values for its definition.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{
+    ///     self as vcf,
+    ///     header::info::key,
+    ///     record::{info::field::Value, Info},
+    /// };
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .add_info(key::TOTAL_DEPTH, vcf::header::record::value::Map::from(&key::TOTAL_DEPTH))
+    ///     .add_info(
+    ///         key::ALLELE_FREQUENCIES,
+    ///         vcf::header::record::value::Map::from(&key::ALLELE_FREQUENCIES),
+    ///     )
+    ///     .build();
+    ///
+    /// let dp = (key::TOTAL_DEPTH, Some(Value::Integer(13)));
+    /// let af = (
+    ///     key::ALLELE_FREQUENCIES,
+    ///     Some(Value::FloatArray(vec![Some(0.333), Some(0.667)])),
+    /// );
+    /// let info: Info = [dp, af].into_iter().collect();
+    ///
+    /// let infos = header.infos();
+    ///
+    /// assert_eq!(
+    ///     info.get_array_as::<f32>(infos, 2, &key::ALLELE_FREQUENCIES),
+    ///     Ok(Some(vec![Some(0.333), Some(0.667)]))
+    /// );
+    /// assert_eq!(
+    ///     info.get_array_as::<i32>(infos, 2, &key::TOTAL_DEPTH),
+    ///     Ok(Some(vec![Some(13)]))
+    /// );
+    /// assert!(info.get_array_as::<i32>(infos, 2, &key::ALLELE_FREQUENCIES).is_err());
+    /// assert!(info.get_array_as::<f32>(infos, 1, &key::ALLELE_FREQUENCIES).is_err());
+    /// ```
+    pub fn get_array_as<T>(
+        &self,
+        infos: &header::Infos,
+        alternate_allele_count: usize,
+        key: &Key,
+    ) -> Result<Option<Vec<Option<T>>>, GetError>
+    where
+        T: field::value::FromValue,
+    {
+        let value = match self.get(key).flatten() {
+            Some(value) => value,
+            None => return Ok(None),
+        };
+
+        check_value::<T>(infos, alternate_allele_count, key, value)?;
+
+        T::from_array_value(value)
+            .or_else(|| T::from_value(value).map(|v| vec![Some(v)]))
+            .map(Some)
+            .ok_or_else(|| GetError::InvalidNumber(key.clone(), value.len()))
+    }
+
     /// Returns a mutable reference to the field value with the given key.
     ///
     /// # Examples
@@ -316,6 +463,112 @@ impl fmt::Display for ParseError {
     }
 }
 
+/// An error returned when a VCF record info field value fails to convert to a requested type.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum GetError {
+    /// The value type does not match the requested type.
+    TypeMismatch {
+        /// The field key.
+        key: Key,
+        /// The requested type.
+        expected: Type,
+        /// The actual type.
+        actual: Type,
+    },
+    /// The number of values is invalid for the requested conversion.
+    InvalidNumber(Key, usize),
+    /// The field is not defined in the header.
+    MissingDefinition(Key),
+    /// The number of values does not match the header-declared number.
+    CardinalityMismatch {
+        /// The field key.
+        key: Key,
+        /// The header-declared number.
+        number: Number,
+        /// The expected number of values.
+        expected: usize,
+        /// The actual number of values.
+        actual: usize,
+    },
+}
+
+impl error::Error for GetError {}
+
+impl fmt::Display for GetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TypeMismatch {
+                key,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "type mismatch for {key}: expected {expected}, got {actual}"
+            ),
+            Self::InvalidNumber(key, n) => {
+                write!(f, "invalid number of values for {key}: got {n}")
+            }
+            Self::MissingDefinition(key) => write!(f, "missing header definition for {key}"),
+            Self::CardinalityMismatch {
+                key,
+                number,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "invalid number of values for {key} (Number={number}): expected {expected}, got {actual}"
+            ),
+        }
+    }
+}
+
+fn check_value<T>(
+    infos: &header::Infos,
+    alternate_allele_count: usize,
+    key: &Key,
+    value: &field::Value,
+) -> Result<(), GetError>
+where
+    T: field::value::FromValue,
+{
+    let info = infos
+        .get(key)
+        .ok_or_else(|| GetError::MissingDefinition(key.clone()))?;
+
+    check_type(key, T::TYPE, info.ty())?;
+    check_type(key, info.ty(), value.ty())?;
+
+    let number = info.number();
+
+    if let Some(expected) = cardinality::expected_count(number, alternate_allele_count, INFO_PLOIDY)
+    {
+        let actual = value.len();
+
+        if actual != expected {
+            return Err(GetError::CardinalityMismatch {
+                key: key.clone(),
+                number,
+                expected,
+                actual,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+fn check_type(key: &Key, expected: Type, actual: Type) -> Result<(), GetError> {
+    if actual == expected {
+        Ok(())
+    } else {
+        Err(GetError::TypeMismatch {
+            key: key.clone(),
+            expected,
+            actual,
+        })
+    }
+}
+
 impl Extend<(Key, Option<field::Value>)> for Info {
     fn extend<T: IntoIterator<Item = (Key, Option<field::Value>)>>(&mut self, iter: T) {
         self.0.extend(iter);
@@ -338,7 +591,11 @@ impl FromStr for Info {
     }
 }
 
-fn parse(s: &str, infos: &header::Infos) -> Result<Info, ParseError> {
+pub(super) fn parse(
+    s: &str,
+    infos: &header::Infos,
+    file_format: FileFormat,
+) -> Result<Info, ParseError> {
     match s {
         "" => Err(ParseError::Empty),
         MISSING_FIELD => Ok(Info::default()),
@@ -346,8 +603,8 @@ fn parse(s: &str, infos: &header::Infos) -> Result<Info, ParseError> {
             let mut info = Info::default();
 
             for raw_field in s.split(DELIMITER) {
-                let (key, value) =
-                    field::parse(raw_field, infos).map_err(ParseError::InvalidField)?;
+                let (key, value) = field::parse(raw_field, infos, file_format)
+                    .map_err(ParseError::InvalidField)?;
 
                 if info.insert(key.clone(), value).is_some() {
                     return Err(ParseError::Invalid(TryFromFieldsError::DuplicateKey(key)));
@@ -419,6 +676,124 @@ mod tests {
         assert_eq!(info, expected);
     }
 
+    fn build_infos() -> header::Infos {
+        use crate::header::record::value::Map;
+
+        [
+            key::TOTAL_DEPTH,
+            key::ALLELE_COUNT,
+            key::ALLELE_FREQUENCIES,
+            key::SAMPLES_WITH_DATA_COUNT,
+            key::IS_SOMATIC_MUTATION,
+        ]
+        .into_iter()
+        .map(|key| {
+            let info = Map::from(&key);
+            (key, info)
+        })
+        .collect()
+    }
+
+    #[test]
+    fn test_get_as() {
+        let infos = build_infos();
+
+        let info: Info = [
+            (key::TOTAL_DEPTH, Some(field::Value::Integer(13))),
+            (
+                key::ALLELE_COUNT,
+                Some(field::Value::IntegerArray(vec![None])),
+            ),
+            (
+                key::ALLELE_FREQUENCIES,
+                Some(field::Value::FloatArray(vec![Some(0.333), Some(0.667)])),
+            ),
+            (key::SAMPLES_WITH_DATA_COUNT, None),
+            (key::IS_IN_DB_SNP, Some(field::Value::Flag)),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(
+            info.get_as::<i32>(&infos, 1, &key::TOTAL_DEPTH),
+            Ok(Some(13))
+        );
+        assert_eq!(info.get_as::<i32>(&infos, 1, &key::ALLELE_COUNT), Ok(None));
+        assert_eq!(
+            info.get_as::<i32>(&infos, 1, &key::SAMPLES_WITH_DATA_COUNT),
+            Ok(None)
+        );
+        assert_eq!(info.get_as::<i32>(&infos, 1, &key::END_POSITION), Ok(None));
+
+        assert_eq!(
+            info.get_as::<f32>(&infos, 1, &key::TOTAL_DEPTH),
+            Err(GetError::TypeMismatch {
+                key: key::TOTAL_DEPTH,
+                expected: Type::Float,
+                actual: Type::Integer,
+            })
+        );
+
+        assert_eq!(
+            info.get_as::<f32>(&infos, 2, &key::ALLELE_FREQUENCIES),
+            Err(GetError::InvalidNumber(key::ALLELE_FREQUENCIES, 2))
+        );
+
+        assert_eq!(
+            info.get_as::<f32>(&infos, 1, &key::ALLELE_FREQUENCIES),
+            Err(GetError::CardinalityMismatch {
+                key: key::ALLELE_FREQUENCIES,
+                number: Number::A,
+                expected: 1,
+                actual: 2,
+            })
+        );
+
+        assert_eq!(
+            info.get_as::<i32>(&infos, 1, &key::IS_IN_DB_SNP),
+            Err(GetError::MissingDefinition(key::IS_IN_DB_SNP))
+        );
+    }
+
+    #[test]
+    fn test_get_array_as() {
+        let infos = build_infos();
+
+        let info: Info = [
+            (key::TOTAL_DEPTH, Some(field::Value::Integer(13))),
+            (
+                key::ALLELE_FREQUENCIES,
+                Some(field::Value::FloatArray(vec![Some(0.333), None])),
+            ),
+            (key::IS_SOMATIC_MUTATION, Some(field::Value::Flag)),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(
+            info.get_array_as::<i32>(&infos, 2, &key::TOTAL_DEPTH),
+            Ok(Some(vec![Some(13)]))
+        );
+        assert_eq!(
+            info.get_array_as::<f32>(&infos, 2, &key::ALLELE_FREQUENCIES),
+            Ok(Some(vec![Some(0.333), None]))
+        );
+        assert_eq!(
+            info.get_array_as::<f32>(&infos, 2, &key::ALLELE_COUNT),
+            Ok(None)
+        );
+
+        assert!(matches!(
+            info.get_array_as::<String>(&infos, 2, &key::IS_SOMATIC_MUTATION),
+            Err(GetError::TypeMismatch { .. })
+        ));
+
+        assert!(matches!(
+            info.get_array_as::<f32>(&infos, 3, &key::ALLELE_FREQUENCIES),
+            Err(GetError::CardinalityMismatch { .. })
+        ));
+    }
+
     #[test]
     fn test_from_str() -> Result<(), ParseError> {
         let actual: Info = ".".parse()?;
@@ -13,7 +13,7 @@ use crate::header::{
         map::{info::Type, Info},
         Map,
     },
-    Infos,
+    FileFormat, Infos,
 };
 
 const MISSING_VALUE: &str = ".";
@@ -53,7 +53,11 @@ impl fmt::Display for ParseError {
     }
 }
 
-pub(super) fn parse(s: &str, infos: &Infos) -> Result<(Key, Option<Value>), ParseError> {
+pub(crate) fn parse(
+    s: &str,
+    infos: &Infos,
+    file_format: FileFormat,
+) -> Result<(Key, Option<Value>), ParseError> {
     const MAX_COMPONENTS: usize = 2;
 
     let mut components = s.splitn(MAX_COMPONENTS, SEPARATOR);
@@ -64,10 +68,10 @@ pub(super) fn parse(s: &str, infos: &Infos) -> Result<(Key, Option<Value>), Pars
         .and_then(|t| t.parse().map_err(ParseError::InvalidKey))?;
 
     let value = if let Some(info) = infos.get(&key) {
-        parse_value(&mut components, &key, info)?
+        parse_value(&mut components, &key, info, file_format)?
     } else {
         let info = Map::<Info>::from(&key);
-        parse_value(&mut components, &key, &info)?
+        parse_value(&mut components, &key, &info, file_format)?
     };
 
     Ok((key, value))
@@ -77,6 +81,7 @@ fn parse_value<'a, I>(
     iter: &mut I,
     key: &Key,
     info: &Map<Info>,
+    file_format: FileFormat,
 ) -> Result<Option<Value>, ParseError>
 where
     I: Iterator<Item = &'a str>,
@@ -87,7 +92,7 @@ where
         if t == MISSING_VALUE {
             Ok(None)
         } else {
-            Value::from_str_info(t, info)
+            Value::try_from((file_format, info.number(), info.ty(), t))
                 .map(Some)
                 .map_err(ParseError::InvalidValue)
         }
@@ -96,7 +101,7 @@ where
             if t == MISSING_VALUE {
                 Ok(None)
             } else {
-                Value::from_str_info(t, info)
+                Value::try_from((file_format, info.number(), info.ty(), t))
                     .map(Some)
                     .map_err(ParseError::InvalidValue)
             }
@@ -107,7 +112,7 @@ where
         if t == MISSING_VALUE {
             Ok(None)
         } else {
-            Value::from_str_info(t, info)
+            Value::try_from((file_format, info.number(), info.ty(), t))
                 .map(Some)
                 .map_err(ParseError::InvalidValue)
         }
@@ -139,25 +144,28 @@ mod tests {
             )
             .build();
 
-        assert_eq!(parse("AC=.", header.infos()), Ok((key::ALLELE_COUNT, None)));
+        assert_eq!(
+            parse("AC=.", header.infos(), header.file_format()),
+            Ok((key::ALLELE_COUNT, None))
+        );
 
         assert_eq!(
-            parse("NS=2", header.infos()),
+            parse("NS=2", header.infos(), header.file_format()),
             Ok((key::SAMPLES_WITH_DATA_COUNT, Some(Value::Integer(2))))
         );
 
         assert_eq!(
-            parse("BQ=1.333", header.infos()),
+            parse("BQ=1.333", header.infos(), header.file_format()),
             Ok((key::BASE_QUALITY, Some(Value::Float(1.333))))
         );
 
         assert_eq!(
-            parse("SOMATIC", header.infos()),
+            parse("SOMATIC", header.infos(), header.file_format()),
             Ok((key::IS_SOMATIC_MUTATION, Some(Value::Flag)))
         );
 
         assert_eq!(
-            parse("EVENT=INV0", header.infos()),
+            parse("EVENT=INV0", header.infos(), header.file_format()),
             Ok((
                 key::BREAKEND_EVENT_ID,
                 Some(Value::String(String::from("INV0")))
@@ -166,12 +174,15 @@ mod tests {
 
         let key = "NDLS".parse()?;
         assert_eq!(
-            parse("NDLS=VCF", header.infos()),
+            parse("NDLS=VCF", header.infos(), header.file_format()),
             Ok((key, Some(Value::String(String::from("VCF")))))
         );
 
         let key = "FLG".parse()?;
-        assert_eq!(parse("FLG", header.infos()), Ok((key, Some(Value::Flag))));
+        assert_eq!(
+            parse("FLG", header.infos(), header.file_format()),
+            Ok((key, Some(Value::Flag)))
+        );
 
         Ok(())
     }
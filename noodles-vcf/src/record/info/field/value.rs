@@ -1,5 +1,9 @@
 //! VCF record info field value.
 
+mod from_value;
+
+pub use self::from_value::FromValue;
+
 use std::{error, fmt, num, str};
 
 use super::MISSING_VALUE;
@@ -9,9 +13,9 @@ use crate::{
             map::{info::Type, Info},
             Map,
         },
-        Number,
+        FileFormat, Number,
     },
-    record::value::{self, percent_decode},
+    record::value::{self, decode_string},
 };
 
 const DELIMITER: char = ',';
@@ -169,7 +173,53 @@ impl Value {
     /// assert_eq!(Value::from_str_info("1", &info), Ok(Value::Integer(1)));
     /// ```
     pub fn from_str_info(s: &str, info: &Map<Info>) -> Result<Self, ParseError> {
-        parse(info.number(), info.ty(), s)
+        parse(FileFormat::default(), info.number(), info.ty(), s)
+    }
+
+    /// Returns the type of the value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{
+    ///     header::record::value::map::info::Type, record::info::field::Value,
+    /// };
+    ///
+    /// assert_eq!(Value::Integer(8).ty(), Type::Integer);
+    /// assert_eq!(Value::FloatArray(vec![Some(0.5)]).ty(), Type::Float);
+    /// ```
+    pub fn ty(&self) -> Type {
+        match self {
+            Self::Integer(_) | Self::IntegerArray(_) => Type::Integer,
+            Self::Float(_) | Self::FloatArray(_) => Type::Float,
+            Self::Flag => Type::Flag,
+            Self::Character(_) | Self::CharacterArray(_) => Type::Character,
+            Self::String(_) | Self::StringArray(_) => Type::String,
+        }
+    }
+
+    /// Returns the number of values.
+    ///
+    /// Scalars have a length of 1, and flags, 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::info::field::Value;
+    /// assert_eq!(Value::Integer(8).len(), 1);
+    /// assert_eq!(Value::IntegerArray(vec![Some(8), None]).len(), 2);
+    /// assert_eq!(Value::Flag.len(), 0);
+    /// ```
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> usize {
+        match self {
+            Self::Flag => 0,
+            Self::Integer(_) | Self::Float(_) | Self::Character(_) | Self::String(_) => 1,
+            Self::IntegerArray(values) => values.len(),
+            Self::FloatArray(values) => values.len(),
+            Self::CharacterArray(values) => values.len(),
+            Self::StringArray(values) => values.len(),
+        }
     }
 }
 
@@ -177,11 +227,21 @@ impl TryFrom<(Number, Type, &str)> for Value {
     type Error = ParseError;
 
     fn try_from((number, ty, s): (Number, Type, &str)) -> Result<Self, Self::Error> {
-        parse(number, ty, s)
+        Self::try_from((FileFormat::default(), number, ty, s))
+    }
+}
+
+impl TryFrom<(FileFormat, Number, Type, &str)> for Value {
+    type Error = ParseError;
+
+    fn try_from(
+        (file_format, number, ty, s): (FileFormat, Number, Type, &str),
+    ) -> Result<Self, Self::Error> {
+        parse(file_format, number, ty, s)
     }
 }
 
-fn parse(number: Number, ty: Type, s: &str) -> Result<Value, ParseError> {
+fn parse(file_format: FileFormat, number: Number, ty: Type, s: &str) -> Result<Value, ParseError> {
     match ty {
         Type::Integer => match number {
             Number::Count(0) => Err(ParseError::InvalidNumberForType(number, ty)),
@@ -199,13 +259,13 @@ fn parse(number: Number, ty: Type, s: &str) -> Result<Value, ParseError> {
         },
         Type::Character => match number {
             Number::Count(0) => Err(ParseError::InvalidNumberForType(number, ty)),
-            Number::Count(1) => parse_char(s),
-            _ => parse_char_array(s),
+            Number::Count(1) => parse_char(file_format, s),
+            _ => parse_char_array(file_format, s),
         },
         Type::String => match number {
             Number::Count(0) => Err(ParseError::InvalidNumberForType(number, ty)),
-            Number::Count(1) => parse_string(s),
-            _ => parse_string_array(s),
+            Number::Count(1) => parse_string(file_format, s),
+            _ => parse_string_array(file_format, s),
         },
     }
 }
@@ -252,8 +312,9 @@ fn parse_flag(s: &str) -> Result<Value, ParseError> {
     }
 }
 
-fn parse_raw_char(s: &str) -> Result<char, ParseError> {
-    let mut chars = s.chars();
+fn parse_raw_char(file_format: FileFormat, s: &str) -> Result<char, ParseError> {
+    let t = decode_string(file_format, s).map_err(|_| ParseError::InvalidCharacter)?;
+    let mut chars = t.chars();
 
     if let Some(c) = chars.next() {
         if chars.next().is_none() {
@@ -264,31 +325,31 @@ fn parse_raw_char(s: &str) -> Result<char, ParseError> {
     Err(ParseError::InvalidCharacter)
 }
 
-fn parse_char(s: &str) -> Result<Value, ParseError> {
-    parse_raw_char(s).map(Value::Character)
+fn parse_char(file_format: FileFormat, s: &str) -> Result<Value, ParseError> {
+    parse_raw_char(file_format, s).map(Value::Character)
 }
 
-fn parse_char_array(s: &str) -> Result<Value, ParseError> {
+fn parse_char_array(file_format: FileFormat, s: &str) -> Result<Value, ParseError> {
     s.split(DELIMITER)
         .map(|t| match t {
             MISSING_VALUE => Ok(None),
-            _ => parse_raw_char(t).map(Some),
+            _ => parse_raw_char(file_format, t).map(Some),
         })
         .collect::<Result<_, _>>()
         .map(Value::CharacterArray)
 }
 
-fn parse_string(s: &str) -> Result<Value, ParseError> {
-    percent_decode(s)
+fn parse_string(file_format: FileFormat, s: &str) -> Result<Value, ParseError> {
+    decode_string(file_format, s)
         .map(|t| Value::String(t.into()))
         .map_err(ParseError::InvalidString)
 }
 
-fn parse_string_array(s: &str) -> Result<Value, ParseError> {
+fn parse_string_array(file_format: FileFormat, s: &str) -> Result<Value, ParseError> {
     s.split(DELIMITER)
         .map(|t| match t {
             MISSING_VALUE => Ok(None),
-            _ => percent_decode(t)
+            _ => decode_string(file_format, t)
                 .map(|u| Some(u.into()))
                 .map_err(ParseError::InvalidString),
         })
@@ -359,7 +420,7 @@ mod tests {
     #[test]
     fn test_parse_with_integer() {
         assert_eq!(
-            parse(Number::Count(0), Type::Integer, "8"),
+            parse(FileFormat::default(), Number::Count(0), Type::Integer, "8"),
             Err(ParseError::InvalidNumberForType(
                 Number::Count(0),
                 Type::Integer
@@ -367,16 +428,26 @@ mod tests {
         );
 
         assert_eq!(
-            parse(Number::Count(1), Type::Integer, "8"),
+            parse(FileFormat::default(), Number::Count(1), Type::Integer, "8"),
             Ok(Value::Integer(8))
         );
 
         assert_eq!(
-            parse(Number::Count(2), Type::Integer, "8,13"),
+            parse(
+                FileFormat::default(),
+                Number::Count(2),
+                Type::Integer,
+                "8,13"
+            ),
             Ok(Value::IntegerArray(vec![Some(8), Some(13)])),
         );
         assert_eq!(
-            parse(Number::Count(2), Type::Integer, "8,."),
+            parse(
+                FileFormat::default(),
+                Number::Count(2),
+                Type::Integer,
+                "8,."
+            ),
             Ok(Value::IntegerArray(vec![Some(8), None])),
         );
     }
@@ -384,7 +455,12 @@ mod tests {
     #[test]
     fn test_parse_with_float() {
         assert_eq!(
-            parse(Number::Count(0), Type::Float, "0.333"),
+            parse(
+                FileFormat::default(),
+                Number::Count(0),
+                Type::Float,
+                "0.333"
+            ),
             Err(ParseError::InvalidNumberForType(
                 Number::Count(0),
                 Type::Float
@@ -392,31 +468,49 @@ mod tests {
         );
 
         assert_eq!(
-            parse(Number::Count(1), Type::Float, "0.333"),
+            parse(
+                FileFormat::default(),
+                Number::Count(1),
+                Type::Float,
+                "0.333"
+            ),
             Ok(Value::Float(0.333))
         );
 
         assert_eq!(
-            parse(Number::Count(2), Type::Float, "0.333,0.667"),
+            parse(
+                FileFormat::default(),
+                Number::Count(2),
+                Type::Float,
+                "0.333,0.667"
+            ),
             Ok(Value::FloatArray(vec![Some(0.333), Some(0.667)]))
         );
         assert_eq!(
-            parse(Number::Count(2), Type::Float, "0.333,."),
+            parse(
+                FileFormat::default(),
+                Number::Count(2),
+                Type::Float,
+                "0.333,."
+            ),
             Ok(Value::FloatArray(vec![Some(0.333), None]))
         );
     }
 
     #[test]
     fn test_parse_with_flag() {
-        assert_eq!(parse(Number::Count(0), Type::Flag, ""), Ok(Value::Flag));
+        assert_eq!(
+            parse(FileFormat::default(), Number::Count(0), Type::Flag, ""),
+            Ok(Value::Flag)
+        );
 
         assert_eq!(
-            parse(Number::Count(0), Type::Flag, "true"),
+            parse(FileFormat::default(), Number::Count(0), Type::Flag, "true"),
             Err(ParseError::InvalidFlag)
         );
 
         assert_eq!(
-            parse(Number::Count(1), Type::Flag, ""),
+            parse(FileFormat::default(), Number::Count(1), Type::Flag, ""),
             Err(ParseError::InvalidNumberForType(
                 Number::Count(1),
                 Type::Flag
@@ -427,7 +521,12 @@ mod tests {
     #[test]
     fn test_parse_with_character() {
         assert_eq!(
-            parse(Number::Count(0), Type::Character, "n"),
+            parse(
+                FileFormat::default(),
+                Number::Count(0),
+                Type::Character,
+                "n"
+            ),
             Err(ParseError::InvalidNumberForType(
                 Number::Count(0),
                 Type::Character
@@ -435,12 +534,22 @@ mod tests {
         );
 
         assert_eq!(
-            parse(Number::Count(1), Type::Character, "n"),
+            parse(
+                FileFormat::default(),
+                Number::Count(1),
+                Type::Character,
+                "n"
+            ),
             Ok(Value::Character('n'))
         );
 
         assert_eq!(
-            parse(Number::Count(2), Type::Character, "n,d,l,s"),
+            parse(
+                FileFormat::default(),
+                Number::Count(2),
+                Type::Character,
+                "n,d,l,s"
+            ),
             Ok(Value::CharacterArray(vec![
                 Some('n'),
                 Some('d'),
@@ -449,7 +558,12 @@ mod tests {
             ]))
         );
         assert_eq!(
-            parse(Number::Count(2), Type::Character, "n,d,l,."),
+            parse(
+                FileFormat::default(),
+                Number::Count(2),
+                Type::Character,
+                "n,d,l,."
+            ),
             Ok(Value::CharacterArray(vec![
                 Some('n'),
                 Some('d'),
@@ -462,7 +576,12 @@ mod tests {
     #[test]
     fn test_parse_with_string() {
         assert_eq!(
-            parse(Number::Count(0), Type::String, "noodles"),
+            parse(
+                FileFormat::default(),
+                Number::Count(0),
+                Type::String,
+                "noodles"
+            ),
             Err(ParseError::InvalidNumberForType(
                 Number::Count(0),
                 Type::String
@@ -470,34 +589,84 @@ mod tests {
         );
 
         assert_eq!(
-            parse(Number::Count(1), Type::String, "noodles"),
+            parse(
+                FileFormat::default(),
+                Number::Count(1),
+                Type::String,
+                "noodles"
+            ),
             Ok(Value::String(String::from("noodles")))
         );
         assert_eq!(
-            parse(Number::Count(1), Type::String, "8%25"),
+            parse(
+                FileFormat::default(),
+                Number::Count(1),
+                Type::String,
+                "8%25"
+            ),
             Ok(Value::String(String::from("8%")))
         );
 
         assert_eq!(
-            parse(Number::Count(2), Type::String, "noodles,vcf"),
+            parse(
+                FileFormat::default(),
+                Number::Count(2),
+                Type::String,
+                "noodles,vcf"
+            ),
             Ok(Value::StringArray(vec![
                 Some(String::from("noodles")),
                 Some(String::from("vcf"))
             ]))
         );
         assert_eq!(
-            parse(Number::Count(2), Type::String, "noodles,."),
+            parse(
+                FileFormat::default(),
+                Number::Count(2),
+                Type::String,
+                "noodles,."
+            ),
             Ok(Value::StringArray(vec![
                 Some(String::from("noodles")),
                 None
             ]))
         );
         assert_eq!(
-            parse(Number::Count(2), Type::String, "8%25,13%25"),
+            parse(
+                FileFormat::default(),
+                Number::Count(2),
+                Type::String,
+                "8%25,13%25"
+            ),
             Ok(Value::StringArray(vec![
                 Some(String::from("8%")),
                 Some(String::from("13%"))
             ]))
         );
     }
+
+    #[test]
+    fn test_try_from_file_format_number_type_str_for_value() {
+        let file_format = FileFormat::new(4, 3);
+
+        assert_eq!(
+            Value::try_from((file_format, Number::Count(1), Type::String, "n%3Bd")),
+            Ok(Value::String(String::from("n;d")))
+        );
+        assert_eq!(
+            Value::try_from((file_format, Number::Count(1), Type::Character, "%3B")),
+            Ok(Value::Character(';'))
+        );
+
+        let file_format = FileFormat::new(4, 2);
+
+        assert_eq!(
+            Value::try_from((file_format, Number::Count(1), Type::String, "n%3Bd")),
+            Ok(Value::String(String::from("n%3Bd")))
+        );
+        assert_eq!(
+            Value::try_from((file_format, Number::Count(1), Type::Character, "%3B")),
+            Err(ParseError::InvalidCharacter)
+        );
+    }
 }
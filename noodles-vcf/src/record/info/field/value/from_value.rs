@@ -0,0 +1,119 @@
+use super::Value;
+use crate::header::record::value::map::info::Type;
+
+/// A type that can be extracted from a VCF record info field value.
+///
+/// This is used by [`crate::record::Info::get_as`] and [`crate::record::Info::get_array_as`].
+pub trait FromValue: Sized {
+    /// The info field value type that corresponds to this type.
+    const TYPE: Type;
+
+    /// Converts a scalar value.
+    ///
+    /// This returns `None` if the value is not a scalar of the corresponding type.
+    fn from_value(value: &Value) -> Option<Self>;
+
+    /// Converts an array value.
+    ///
+    /// This returns `None` if the value is not an array of the corresponding type.
+    fn from_array_value(value: &Value) -> Option<Vec<Option<Self>>>;
+}
+
+impl FromValue for i32 {
+    const TYPE: Type = Type::Integer;
+
+    fn from_value(value: &Value) -> Option<Self> {
+        match value {
+            Value::Integer(n) => Some(*n),
+            _ => None,
+        }
+    }
+
+    fn from_array_value(value: &Value) -> Option<Vec<Option<Self>>> {
+        match value {
+            Value::IntegerArray(values) => Some(values.clone()),
+            _ => None,
+        }
+    }
+}
+
+impl FromValue for f32 {
+    const TYPE: Type = Type::Float;
+
+    fn from_value(value: &Value) -> Option<Self> {
+        match value {
+            Value::Float(n) => Some(*n),
+            _ => None,
+        }
+    }
+
+    fn from_array_value(value: &Value) -> Option<Vec<Option<Self>>> {
+        match value {
+            Value::FloatArray(values) => Some(values.clone()),
+            _ => None,
+        }
+    }
+}
+
+impl FromValue for char {
+    const TYPE: Type = Type::Character;
+
+    fn from_value(value: &Value) -> Option<Self> {
+        match value {
+            Value::Character(c) => Some(*c),
+            _ => None,
+        }
+    }
+
+    fn from_array_value(value: &Value) -> Option<Vec<Option<Self>>> {
+        match value {
+            Value::CharacterArray(values) => Some(values.clone()),
+            _ => None,
+        }
+    }
+}
+
+impl FromValue for String {
+    const TYPE: Type = Type::String;
+
+    fn from_value(value: &Value) -> Option<Self> {
+        match value {
+            Value::String(s) => Some(s.clone()),
+            _ => None,
+        }
+    }
+
+    fn from_array_value(value: &Value) -> Option<Vec<Option<Self>>> {
+        match value {
+            Value::StringArray(values) => Some(values.clone()),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_value() {
+        assert_eq!(i32::from_value(&Value::Integer(8)), Some(8));
+        assert!(i32::from_value(&Value::Float(8.0)).is_none());
+        assert_eq!(f32::from_value(&Value::Float(0.5)), Some(0.5));
+        assert_eq!(char::from_value(&Value::Character('n')), Some('n'));
+        assert_eq!(
+            String::from_value(&Value::String(String::from("ndls"))),
+            Some(String::from("ndls"))
+        );
+    }
+
+    #[test]
+    fn test_from_array_value() {
+        assert_eq!(
+            i32::from_array_value(&Value::IntegerArray(vec![Some(8), None])),
+            Some(vec![Some(8), None])
+        );
+        assert!(i32::from_array_value(&Value::Integer(8)).is_none());
+        assert!(f32::from_array_value(&Value::IntegerArray(vec![Some(8)])).is_none());
+    }
+}
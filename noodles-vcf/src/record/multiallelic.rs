@@ -0,0 +1,674 @@
+//! VCF record multiallelic splitting and joining.
+
+use std::{error, fmt};
+
+use super::{
+    genotypes::{
+        genotype::{self, field::value::genotype::Allele},
+        Genotype,
+    },
+    info, AlternateBases, Info, Record,
+};
+use crate::{
+    header::{format::key as format_key, info::key as info_key, Number},
+    Header,
+};
+
+/// An error returned when a VCF record fails to split.
+#[derive(Clone, Debug, PartialEq)]
+pub enum SplitError {
+    /// A genotype is invalid.
+    InvalidGenotype(genotype::GenotypeError),
+}
+
+impl error::Error for SplitError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::InvalidGenotype(e) => Some(e),
+        }
+    }
+}
+
+impl fmt::Display for SplitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidGenotype(_) => f.write_str("invalid genotype"),
+        }
+    }
+}
+
+/// An error returned when VCF records fail to join.
+#[derive(Clone, Debug, PartialEq)]
+pub enum JoinError {
+    /// The list of records is empty.
+    Empty,
+    /// A record does not have exactly one alternate allele.
+    InvalidAlternateBases,
+    /// A record has a different chromosome, position, or reference bases than the first record.
+    IncompatibleRecord,
+    /// A record has a different number of samples than the first record.
+    SampleCountMismatch,
+    /// A genotype is invalid.
+    InvalidGenotype(genotype::GenotypeError),
+}
+
+impl error::Error for JoinError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::InvalidGenotype(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for JoinError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("empty input"),
+            Self::InvalidAlternateBases => f.write_str("invalid alternate bases"),
+            Self::IncompatibleRecord => f.write_str("incompatible record"),
+            Self::SampleCountMismatch => f.write_str("sample count mismatch"),
+            Self::InvalidGenotype(_) => f.write_str("invalid genotype"),
+        }
+    }
+}
+
+pub(super) fn split(record: &Record, header: &Header) -> Result<Vec<Record>, SplitError> {
+    let alternate_allele_count = record.alternate_bases().len();
+
+    if alternate_allele_count <= 1 {
+        return Ok(vec![record.clone()]);
+    }
+
+    (1..=alternate_allele_count)
+        .map(|k| split_allele(record, header, k))
+        .collect()
+}
+
+fn split_allele(record: &Record, header: &Header, k: usize) -> Result<Record, SplitError> {
+    let allele_count = record.alternate_bases().len() + 1;
+
+    let mut split_record = record.clone();
+
+    *split_record.alternate_bases_mut() =
+        AlternateBases::from(vec![record.alternate_bases()[k - 1].clone()]);
+
+    *split_record.info_mut() = record
+        .info()
+        .keys()
+        .zip(record.info().values())
+        .map(|(key, value)| {
+            let number = header.infos().get(key).map(|info| info.number());
+
+            let value = value.map(|v| {
+                let ploidy = infer_ploidy(allele_count, v.array_len());
+
+                match split_indices(number, k, ploidy) {
+                    Some(indices) => v.select(&indices),
+                    None => v.clone(),
+                }
+            });
+
+            (key.clone(), value)
+        })
+        .collect();
+
+    for genotype in split_record.genotypes_mut().iter_mut() {
+        let ploidy = match genotype.genotype() {
+            Some(result) => Some(result.map_err(SplitError::InvalidGenotype)?.len()),
+            None => None,
+        };
+
+        for (key, value) in genotype.iter_mut() {
+            let v = match value {
+                Some(v) => v,
+                None => continue,
+            };
+
+            if *key == format_key::GENOTYPE {
+                if let genotype::field::Value::String(s) = v {
+                    *s = split_genotype(s, k)?;
+                }
+
+                continue;
+            }
+
+            let number = header.formats().get(key).map(|format| format.number());
+            let ploidy = ploidy.or_else(|| infer_ploidy(allele_count, v.array_len()));
+
+            if let Some(indices) = split_indices(number, k, ploidy) {
+                *v = v.select(&indices);
+            }
+        }
+    }
+
+    Ok(split_record)
+}
+
+fn split_indices(number: Option<Number>, k: usize, ploidy: Option<usize>) -> Option<Vec<usize>> {
+    match number? {
+        Number::A => Some(vec![k - 1]),
+        Number::R => Some(vec![0, k]),
+        Number::G => {
+            let ploidy = ploidy?;
+
+            let indices = (0..=ploidy)
+                .map(|m| {
+                    let mut alleles = vec![0; ploidy - m];
+                    alleles.resize(ploidy, k);
+                    genotype_index(&alleles)
+                })
+                .collect();
+
+            Some(indices)
+        }
+        _ => None,
+    }
+}
+
+fn split_genotype(s: &str, k: usize) -> Result<String, SplitError> {
+    let mut genotype: genotype::field::value::Genotype = s
+        .parse()
+        .map_err(|e| SplitError::InvalidGenotype(genotype::GenotypeError::InvalidValue(e)))?;
+
+    for allele in genotype.iter_mut() {
+        if let Some(position) = allele.position_mut() {
+            // Other alternate alleles are set to the reference allele.
+            *position = usize::from(*position == k);
+        }
+    }
+
+    Ok(genotype.to_string())
+}
+
+pub(super) fn join(records: &[Record], header: &Header) -> Result<Record, JoinError> {
+    let first = records.first().ok_or(JoinError::Empty)?;
+
+    for record in records {
+        if record.alternate_bases().len() != 1 {
+            return Err(JoinError::InvalidAlternateBases);
+        } else if record.chromosome() != first.chromosome()
+            || record.position() != first.position()
+            || record.reference_bases() != first.reference_bases()
+        {
+            return Err(JoinError::IncompatibleRecord);
+        } else if record.genotypes().len() != first.genotypes().len() {
+            return Err(JoinError::SampleCountMismatch);
+        }
+    }
+
+    let mut joined_record = first.clone();
+
+    *joined_record.alternate_bases_mut() = AlternateBases::from(
+        records
+            .iter()
+            .flat_map(|record| record.alternate_bases().iter().cloned())
+            .collect::<Vec<_>>(),
+    );
+
+    for record in &records[1..] {
+        for id in record.ids().iter() {
+            joined_record.ids_mut().insert(id.clone());
+        }
+    }
+
+    *joined_record.info_mut() = join_info(records, header);
+
+    for record in &records[1..] {
+        for key in record.format().iter() {
+            joined_record.genotypes_mut().keys_mut().insert(key.clone());
+        }
+    }
+
+    let keys = joined_record.format().clone();
+
+    for (i, genotype) in joined_record.genotypes_mut().iter_mut().enumerate() {
+        let sample_genotypes: Vec<_> = records.iter().map(|r| &r.genotypes()[i]).collect();
+        *genotype = join_genotype(&sample_genotypes, keys.iter(), header)?;
+    }
+
+    Ok(joined_record)
+}
+
+fn join_info(records: &[Record], header: &Header) -> Info {
+    let mut keys: Vec<&info_key::Key> = Vec::new();
+
+    for record in records {
+        for key in record.info().keys() {
+            if !keys.contains(&key) {
+                keys.push(key);
+            }
+        }
+    }
+
+    keys.into_iter()
+        .map(|key| {
+            let values: Vec<_> = records
+                .iter()
+                .map(|record| record.info().get(key).flatten())
+                .collect();
+
+            let number = header.infos().get(key).map(|info| info.number());
+            let value = join_values(&values, number, None);
+
+            (key.clone(), value)
+        })
+        .collect()
+}
+
+fn join_genotype<'a, I>(
+    genotypes: &[&Genotype],
+    keys: I,
+    header: &Header,
+) -> Result<Genotype, JoinError>
+where
+    I: Iterator<Item = &'a format_key::Key>,
+{
+    let mut joined_genotype = Genotype::default();
+    let mut ploidy = None;
+
+    for key in keys {
+        let value = if *key == format_key::GENOTYPE {
+            let gt = join_genotype_value(genotypes)?;
+            ploidy = gt.as_ref().map(|gt| gt.len());
+            gt.map(|gt| genotype::field::Value::String(gt.to_string()))
+        } else {
+            let values: Vec<_> = genotypes
+                .iter()
+                .map(|genotype| genotype.get(key).and_then(|value| value.as_ref()))
+                .collect();
+
+            let number = header.formats().get(key).map(|format| format.number());
+            join_values(&values, number, ploidy)
+        };
+
+        joined_genotype.insert(key.clone(), value);
+    }
+
+    Ok(joined_genotype)
+}
+
+fn join_genotype_value(
+    genotypes: &[&Genotype],
+) -> Result<Option<genotype::field::value::Genotype>, JoinError> {
+    let mut joined_genotype: Option<genotype::field::value::Genotype> = None;
+
+    for (i, genotype) in genotypes.iter().enumerate() {
+        let gt = match genotype.genotype() {
+            Some(result) => result.map_err(JoinError::InvalidGenotype)?,
+            None => continue,
+        };
+
+        let alternate_allele = i + 1;
+
+        let dst = match joined_genotype.as_mut() {
+            Some(dst) => dst,
+            None => {
+                let mut gt = gt;
+
+                for allele in gt.iter_mut() {
+                    if let Some(position) = allele.position_mut() {
+                        if *position > 0 {
+                            *position = alternate_allele;
+                        }
+                    }
+                }
+
+                joined_genotype = Some(gt);
+                continue;
+            }
+        };
+
+        for (j, allele) in gt.iter().enumerate() {
+            if !matches!(allele.position(), Some(n) if n > 0) {
+                continue;
+            }
+
+            // Fill the same allele slot if it is the reference allele. Otherwise, fill the first
+            // reference allele slot.
+            let slot = if is_reference_allele(dst.get(j)) {
+                Some(j)
+            } else {
+                dst.iter().position(|a| is_reference_allele(Some(a)))
+            };
+
+            if let Some(slot) = slot {
+                *dst[slot].position_mut() = Some(alternate_allele);
+            }
+        }
+    }
+
+    Ok(joined_genotype)
+}
+
+fn is_reference_allele(allele: Option<&Allele>) -> bool {
+    matches!(allele.and_then(|a| a.position()), Some(0))
+}
+
+fn join_values<V>(values: &[Option<&V>], number: Option<Number>, ploidy: Option<usize>) -> Option<V>
+where
+    V: ArrayValue,
+{
+    let first = values.iter().flatten().next()?;
+    let alternate_allele_count = values.len();
+
+    let sources: Vec<Option<(&V, usize)>> = match number {
+        Some(Number::A) => values.iter().map(|value| value.map(|v| (v, 0))).collect(),
+        Some(Number::R) => {
+            let reference_source = values.iter().flatten().next().map(|v| (*v, 0));
+
+            [reference_source]
+                .into_iter()
+                .chain(values.iter().map(|value| value.map(|v| (v, 1))))
+                .collect()
+        }
+        Some(Number::G) => {
+            let ploidy = ploidy.or_else(|| first.array_len().and_then(|n| n.checked_sub(1)))?;
+            let len = binomial(alternate_allele_count + ploidy, ploidy);
+
+            let mut sources = vec![None; len];
+
+            for (i, value) in values.iter().enumerate() {
+                let v = match value {
+                    Some(v) => v,
+                    None => continue,
+                };
+
+                for m in 0..=ploidy {
+                    let mut alleles = vec![0; ploidy - m];
+                    alleles.resize(ploidy, i + 1);
+
+                    if let Some(source) = sources.get_mut(genotype_index(&alleles)) {
+                        if source.is_none() {
+                            *source = Some((*v, m));
+                        }
+                    }
+                }
+            }
+
+            sources
+        }
+        _ => return Some((*first).clone()),
+    };
+
+    V::gather(&sources)
+}
+
+// Returns the index of a genotype in the canonical ordering of genotypes (§ 1.6.2 "Genotype
+// fields").
+//
+// The alleles must be sorted in ascending order.
+pub(crate) fn genotype_index(alleles: &[usize]) -> usize {
+    alleles
+        .iter()
+        .enumerate()
+        .map(|(i, &a)| binomial(a + i, i + 1))
+        .sum()
+}
+
+pub(crate) fn binomial(n: usize, k: usize) -> usize {
+    if k > n {
+        return 0;
+    }
+
+    let k = k.min(n - k);
+    (0..k).fold(1, |acc, i| acc * (n - i) / (i + 1))
+}
+
+// Infers the ploidy from the number of values of a `Number=G` field.
+fn infer_ploidy(allele_count: usize, len: Option<usize>) -> Option<usize> {
+    let len = len?;
+
+    (1..)
+        .map(|ploidy| (ploidy, binomial(allele_count + ploidy - 1, ploidy)))
+        .take_while(|(_, n)| *n <= len)
+        .find(|(_, n)| *n == len)
+        .map(|(ploidy, _)| ploidy)
+}
+
+pub(crate) trait ArrayValue: Clone + Sized {
+    fn array_len(&self) -> Option<usize>;
+    fn select(&self, indices: &[usize]) -> Self;
+    fn gather(sources: &[Option<(&Self, usize)>]) -> Option<Self>;
+}
+
+macro_rules! impl_array_value {
+    ($ty:ty) => {
+        impl ArrayValue for $ty {
+            fn array_len(&self) -> Option<usize> {
+                match self {
+                    Self::IntegerArray(values) => Some(values.len()),
+                    Self::FloatArray(values) => Some(values.len()),
+                    Self::CharacterArray(values) => Some(values.len()),
+                    Self::StringArray(values) => Some(values.len()),
+                    _ => None,
+                }
+            }
+
+            fn select(&self, indices: &[usize]) -> Self {
+                match self {
+                    Self::IntegerArray(values) => {
+                        Self::IntegerArray(indices.iter().map(|&i| get(values, i)).collect())
+                    }
+                    Self::FloatArray(values) => {
+                        Self::FloatArray(indices.iter().map(|&i| get(values, i)).collect())
+                    }
+                    Self::CharacterArray(values) => {
+                        Self::CharacterArray(indices.iter().map(|&i| get(values, i)).collect())
+                    }
+                    Self::StringArray(values) => {
+                        Self::StringArray(indices.iter().map(|&i| get(values, i)).collect())
+                    }
+                    _ => self.clone(),
+                }
+            }
+
+            fn gather(sources: &[Option<(&Self, usize)>]) -> Option<Self> {
+                let (template, _) = sources.iter().flatten().next()?;
+
+                let value = match template {
+                    Self::Integer(_) | Self::IntegerArray(_) => Self::IntegerArray(
+                        sources
+                            .iter()
+                            .map(|source| match source {
+                                Some((Self::Integer(n), 0)) => Some(*n),
+                                Some((Self::IntegerArray(values), i)) => get(values, *i),
+                                _ => None,
+                            })
+                            .collect(),
+                    ),
+                    Self::Float(_) | Self::FloatArray(_) => Self::FloatArray(
+                        sources
+                            .iter()
+                            .map(|source| match source {
+                                Some((Self::Float(n), 0)) => Some(*n),
+                                Some((Self::FloatArray(values), i)) => get(values, *i),
+                                _ => None,
+                            })
+                            .collect(),
+                    ),
+                    Self::Character(_) | Self::CharacterArray(_) => Self::CharacterArray(
+                        sources
+                            .iter()
+                            .map(|source| match source {
+                                Some((Self::Character(c), 0)) => Some(*c),
+                                Some((Self::CharacterArray(values), i)) => get(values, *i),
+                                _ => None,
+                            })
+                            .collect(),
+                    ),
+                    Self::String(_) | Self::StringArray(_) => Self::StringArray(
+                        sources
+                            .iter()
+                            .map(|source| match source {
+                                Some((Self::String(s), 0)) => Some(s.clone()),
+                                Some((Self::StringArray(values), i)) => get(values, *i),
+                                _ => None,
+                            })
+                            .collect(),
+                    ),
+                    #[allow(unreachable_patterns)]
+                    _ => (*template).clone(),
+                };
+
+                Some(value)
+            }
+        }
+    };
+}
+
+impl_array_value!(info::field::Value);
+impl_array_value!(genotype::field::Value);
+
+fn get<T: Clone>(values: &[Option<T>], i: usize) -> Option<T> {
+    values.get(i).cloned().flatten()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_header() -> Header {
+        use crate::header::record::value::{
+            map::{Format, Info},
+            Map,
+        };
+
+        Header::builder()
+            .add_info(
+                info_key::TOTAL_DEPTH,
+                Map::<Info>::from(&info_key::TOTAL_DEPTH),
+            )
+            .add_info(
+                info_key::ALLELE_COUNT,
+                Map::<Info>::from(&info_key::ALLELE_COUNT),
+            )
+            .add_info(
+                info_key::TOTAL_READ_DEPTHS,
+                Map::<Info>::from(&info_key::TOTAL_READ_DEPTHS),
+            )
+            .add_format(
+                format_key::GENOTYPE,
+                Map::<Format>::from(&format_key::GENOTYPE),
+            )
+            .add_format(
+                format_key::READ_DEPTHS,
+                Map::<Format>::from(&format_key::READ_DEPTHS),
+            )
+            .add_format(
+                format_key::ROUNDED_GENOTYPE_LIKELIHOODS,
+                Map::<Format>::from(&format_key::ROUNDED_GENOTYPE_LIKELIHOODS),
+            )
+            .add_sample_name("sample0")
+            .add_sample_name("sample1")
+            .build()
+    }
+
+    #[test]
+    fn test_genotype_index() {
+        assert_eq!(genotype_index(&[0]), 0);
+        assert_eq!(genotype_index(&[2]), 2);
+
+        assert_eq!(genotype_index(&[0, 0]), 0);
+        assert_eq!(genotype_index(&[0, 1]), 1);
+        assert_eq!(genotype_index(&[1, 1]), 2);
+        assert_eq!(genotype_index(&[0, 2]), 3);
+        assert_eq!(genotype_index(&[1, 2]), 4);
+        assert_eq!(genotype_index(&[2, 2]), 5);
+
+        assert_eq!(genotype_index(&[0, 0, 0]), 0);
+        assert_eq!(genotype_index(&[0, 0, 1]), 1);
+        assert_eq!(genotype_index(&[0, 1, 1]), 2);
+        assert_eq!(genotype_index(&[1, 1, 1]), 3);
+        assert_eq!(genotype_index(&[0, 0, 2]), 4);
+    }
+
+    #[test]
+    fn test_infer_ploidy() {
+        assert_eq!(infer_ploidy(3, Some(3)), Some(1));
+        assert_eq!(infer_ploidy(3, Some(6)), Some(2));
+        assert_eq!(infer_ploidy(3, Some(10)), Some(3));
+        assert_eq!(infer_ploidy(3, Some(4)), None);
+        assert_eq!(infer_ploidy(3, None), None);
+    }
+
+    #[test]
+    fn test_split() -> Result<(), Box<dyn std::error::Error>> {
+        let header = build_header();
+
+        let record = Record::try_from_str(
+            "sq0\t1\trs1\tA\tC,G\t13\tPASS\tDP=8;AC=3,1;AD=5,2,1\tGT:AD:PL\t0/1:3,2,0:10,0,20,30,40,50\t1|2:2,0,1:60,50,40,30,20,10",
+            &header,
+        )?;
+
+        let records = split(&record, &header)?;
+
+        let actual: Vec<_> = records.iter().map(|r| r.to_string()).collect();
+
+        let expected = [
+            "sq0\t1\trs1\tA\tC\t13\tPASS\tDP=8;AC=3;AD=5,2\tGT:AD:PL\t0/1:3,2:10,0,20\t1|0:2,0:60,50,40",
+            "sq0\t1\trs1\tA\tG\t13\tPASS\tDP=8;AC=1;AD=5,1\tGT:AD:PL\t0/0:3,0:10,30,50\t0|1:2,1:60,30,10",
+        ];
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_split_with_biallelic_record() -> Result<(), Box<dyn std::error::Error>> {
+        let header = build_header();
+        let record = Record::try_from_str("sq0\t1\t.\tA\tC\t.\tPASS\t.", &header)?;
+        assert_eq!(split(&record, &header)?, [record]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_join() -> Result<(), Box<dyn std::error::Error>> {
+        let header = build_header();
+
+        let records = [
+            Record::try_from_str(
+                "sq0\t1\trs1\tA\tC\t13\tPASS\tDP=8;AC=3;AD=5,2\tGT:AD:PL\t0/1:3,2:10,0,20\t1|0:2,0:60,50,40",
+                &header,
+            )?,
+            Record::try_from_str(
+                "sq0\t1\trs2\tA\tG\t13\tPASS\tDP=8;AC=1;AD=5,1\tGT:AD:PL\t0/0:3,0:10,30,50\t0|1:2,1:60,30,10",
+                &header,
+            )?,
+        ];
+
+        let actual = join(&records, &header)?;
+
+        assert_eq!(
+            actual.to_string(),
+            "sq0\t1\trs1;rs2\tA\tC,G\t13\tPASS\tDP=8;AC=3,1;AD=5,2,1\tGT:AD:PL\t0/1:3,2,0:10,0,20,30,.,50\t1|2:2,0,1:60,50,40,30,.,10"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_join_with_invalid_records() -> Result<(), Box<dyn std::error::Error>> {
+        let header = build_header();
+
+        assert_eq!(join(&[], &header), Err(JoinError::Empty));
+
+        let records = [Record::try_from_str(
+            "sq0\t1\t.\tA\tC,G\t.\tPASS\t.",
+            &header,
+        )?];
+        assert_eq!(
+            join(&records, &header),
+            Err(JoinError::InvalidAlternateBases)
+        );
+
+        let records = [
+            Record::try_from_str("sq0\t1\t.\tA\tC\t.\tPASS\t.", &header)?,
+            Record::try_from_str("sq0\t2\t.\tA\tG\t.\tPASS\t.", &header)?,
+        ];
+        assert_eq!(join(&records, &header), Err(JoinError::IncompatibleRecord));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,107 @@
+use std::cmp::Ordering;
+
+use super::{Chromosome, Record};
+use crate::Header;
+
+/// Compares two records by their genomic position.
+///
+/// Records are ordered by the index of their chromosome in the header contigs and then by
+/// position. Records with a chromosome not in the header contigs are ordered after all other
+/// records, by chromosome name.
+///
+/// # Examples
+///
+/// ```
+/// use std::cmp::Ordering;
+///
+/// use noodles_vcf::{
+///     self as vcf,
+///     header::record::value::{map::Contig, Map},
+///     record,
+/// };
+///
+/// let header = vcf::Header::builder()
+///     .add_contig("sq1".parse()?, Map::<Contig>::new())
+///     .add_contig("sq0".parse()?, Map::<Contig>::new())
+///     .build();
+///
+/// let a = vcf::Record::try_from_str("sq1\t8\t.\tA\t.\t.\tPASS\t.", &header)?;
+/// let b = vcf::Record::try_from_str("sq0\t5\t.\tA\t.\t.\tPASS\t.", &header)?;
+/// assert_eq!(record::compare(&header, &a, &b), Ordering::Less);
+///
+/// let mut records = vec![b, a];
+/// records.sort_by(|a, b| record::compare(&header, a, b));
+/// assert_eq!(records[0].chromosome().to_string(), "sq1");
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn compare(header: &Header, a: &Record, b: &Record) -> Ordering {
+    compare_chromosomes(header, a.chromosome(), b.chromosome())
+        .then_with(|| a.position().cmp(&b.position()))
+}
+
+fn compare_chromosomes(header: &Header, a: &Chromosome, b: &Chromosome) -> Ordering {
+    let a_name = chromosome_name(a);
+    let b_name = chromosome_name(b);
+
+    if a_name == b_name {
+        return Ordering::Equal;
+    }
+
+    let contigs = header.contigs();
+
+    match (contigs.get_index_of(a_name), contigs.get_index_of(b_name)) {
+        (Some(i), Some(j)) => i.cmp(&j),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => a_name.cmp(b_name),
+    }
+}
+
+fn chromosome_name(chromosome: &Chromosome) -> &str {
+    match chromosome {
+        Chromosome::Name(name) | Chromosome::Symbol(name) => name,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::header::record::value::{map::Contig, Map};
+
+    #[test]
+    fn test_compare() -> Result<(), Box<dyn std::error::Error>> {
+        let header = Header::builder()
+            .add_contig("sq1".parse()?, Map::<Contig>::new())
+            .add_contig("sq0".parse()?, Map::<Contig>::new())
+            .build();
+
+        let t = |a: &str, b: &str| -> Result<Ordering, Box<dyn std::error::Error>> {
+            let a = Record::try_from_str(a, &header)?;
+            let b = Record::try_from_str(b, &header)?;
+            Ok(compare(&header, &a, &b))
+        };
+
+        assert_eq!(
+            t("sq1\t8\t.\tA\t.\t.\tPASS\t.", "sq0\t5\t.\tA\t.\t.\tPASS\t.")?,
+            Ordering::Less
+        );
+        assert_eq!(
+            t("sq0\t8\t.\tA\t.\t.\tPASS\t.", "sq0\t5\t.\tA\t.\t.\tPASS\t.")?,
+            Ordering::Greater
+        );
+        assert_eq!(
+            t("sq0\t5\t.\tA\t.\t.\tPASS\t.", "sq0\t5\t.\tC\t.\t.\tPASS\t.")?,
+            Ordering::Equal
+        );
+        assert_eq!(
+            t("sq2\t1\t.\tA\t.\t.\tPASS\t.", "sq0\t5\t.\tA\t.\t.\tPASS\t.")?,
+            Ordering::Greater
+        );
+        assert_eq!(
+            t("sq2\t1\t.\tA\t.\t.\tPASS\t.", "sq3\t1\t.\tA\t.\t.\tPASS\t.")?,
+            Ordering::Less
+        );
+
+        Ok(())
+    }
+}
@@ -2,7 +2,7 @@ use std::{error, fmt};
 
 use super::{
     alternate_bases, chromosome, filters, genotypes, ids, info, position, quality_score,
-    reference_bases, Field, Filters, Genotypes, Ids, Info, QualityScore, Record, FIELD_DELIMITER,
+    reference_bases, Field, Filters, Genotypes, Ids, QualityScore, Record, FIELD_DELIMITER,
     MISSING_FIELD,
 };
 use crate::Header;
@@ -88,8 +88,9 @@ pub fn parse(s: &str, header: &Header) -> Result<Record, ParseError> {
     let qual = parse_quality_score(&mut fields)?;
     let filter = parse_filters(&mut fields)?;
 
-    let info = parse_string(&mut fields, Field::Info)
-        .and_then(|s| Info::try_from_str(s, header.infos()).map_err(ParseError::InvalidInfo))?;
+    let info = parse_string(&mut fields, Field::Info).and_then(|s| {
+        info::parse(s, header.infos(), header.file_format()).map_err(ParseError::InvalidInfo)
+    })?;
 
     let genotypes = if let Some(s) = fields.next() {
         Genotypes::parse(s, header).map_err(ParseError::InvalidGenotypes)?
@@ -207,4 +208,21 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_parse_with_info_whitespace() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::header::FileFormat;
+
+        let s = "sq0\t1\t.\tA\t.\t.\tPASS\tNOTE=noodles vcf";
+
+        // Space characters are only allowed in INFO values as of VCF 4.4, but they are not
+        // rejected when reading.
+        for file_format in [FileFormat::new(4, 3), FileFormat::new(4, 4)] {
+            let header = Header::builder().set_file_format(file_format).build();
+            let record = parse(s, &header)?;
+            assert_eq!(record.info().len(), 1);
+        }
+
+        Ok(())
+    }
 }
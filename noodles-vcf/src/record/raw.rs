@@ -0,0 +1,232 @@
+//! Raw VCF record.
+//!
+//! A raw record borrows its fields from a line of a VCF, e.g., as read by
+//! [`crate::Reader::read_record`]. Fields are not parsed or validated, and INFO and genotype values
+//! are looked up without allocating. This is useful when scanning many records for a few fields.
+//!
+//! Raw values can be parsed using, e.g., [`crate::record::info::field::Value`]'s
+//! `TryFrom<(FileFormat, Number, Type, &str)>` implementation.
+
+mod genotypes;
+mod info;
+
+pub use self::{genotypes::Genotypes, info::Info};
+
+use std::{error, fmt};
+
+use super::{Field, FIELD_DELIMITER, MISSING_FIELD};
+
+const MAX_FIELDS: usize = 9;
+
+/// A raw VCF record.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Record<'a> {
+    fields: [&'a str; 8],
+    genotypes: &'a str,
+}
+
+impl<'a> Record<'a> {
+    /// Returns the chromosome (`CHROM`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::raw;
+    /// let record = raw::Record::try_from("sq0\t1\t.\tA\t.\t.\tPASS\t.")?;
+    /// assert_eq!(record.chromosome(), "sq0");
+    /// # Ok::<_, raw::ParseError>(())
+    /// ```
+    pub fn chromosome(&self) -> &'a str {
+        self.fields[0]
+    }
+
+    /// Returns the position (`POS`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::raw;
+    /// let record = raw::Record::try_from("sq0\t1\t.\tA\t.\t.\tPASS\t.")?;
+    /// assert_eq!(record.position(), "1");
+    /// # Ok::<_, raw::ParseError>(())
+    /// ```
+    pub fn position(&self) -> &'a str {
+        self.fields[1]
+    }
+
+    /// Returns the IDs (`ID`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::raw;
+    /// let record = raw::Record::try_from("sq0\t1\tnd0\tA\t.\t.\tPASS\t.")?;
+    /// assert_eq!(record.ids(), "nd0");
+    /// # Ok::<_, raw::ParseError>(())
+    /// ```
+    pub fn ids(&self) -> &'a str {
+        self.fields[2]
+    }
+
+    /// Returns the reference bases (`REF`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::raw;
+    /// let record = raw::Record::try_from("sq0\t1\t.\tA\t.\t.\tPASS\t.")?;
+    /// assert_eq!(record.reference_bases(), "A");
+    /// # Ok::<_, raw::ParseError>(())
+    /// ```
+    pub fn reference_bases(&self) -> &'a str {
+        self.fields[3]
+    }
+
+    /// Returns the alternate bases (`ALT`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::raw;
+    /// let record = raw::Record::try_from("sq0\t1\t.\tA\tC,G\t.\tPASS\t.")?;
+    /// assert_eq!(record.alternate_bases(), "C,G");
+    /// # Ok::<_, raw::ParseError>(())
+    /// ```
+    pub fn alternate_bases(&self) -> &'a str {
+        self.fields[4]
+    }
+
+    /// Returns the quality score (`QUAL`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::raw;
+    /// let record = raw::Record::try_from("sq0\t1\t.\tA\t.\t13\tPASS\t.")?;
+    /// assert_eq!(record.quality_score(), "13");
+    /// # Ok::<_, raw::ParseError>(())
+    /// ```
+    pub fn quality_score(&self) -> &'a str {
+        self.fields[5]
+    }
+
+    /// Returns the filters (`FILTER`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::raw;
+    /// let record = raw::Record::try_from("sq0\t1\t.\tA\t.\t.\tPASS\t.")?;
+    /// assert_eq!(record.filters(), "PASS");
+    /// # Ok::<_, raw::ParseError>(())
+    /// ```
+    pub fn filters(&self) -> &'a str {
+        self.fields[6]
+    }
+
+    /// Returns the information fields (`INFO`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::raw;
+    /// let record = raw::Record::try_from("sq0\t1\t.\tA\t.\t.\tPASS\tNS=2;DP=13")?;
+    /// assert_eq!(record.info().get("DP"), Some(Some("13")));
+    /// # Ok::<_, raw::ParseError>(())
+    /// ```
+    pub fn info(&self) -> Info<'a> {
+        Info::new(self.fields[7])
+    }
+
+    /// Returns the genotypes (`FORMAT` and sample fields).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::raw;
+    /// let record = raw::Record::try_from("sq0\t1\t.\tA\t.\t.\tPASS\t.\tGT:GQ\t0|0:13")?;
+    /// assert_eq!(record.genotypes().get(0, "GQ"), Some(Some("13")));
+    /// # Ok::<_, raw::ParseError>(())
+    /// ```
+    pub fn genotypes(&self) -> Genotypes<'a> {
+        Genotypes::new(self.genotypes)
+    }
+}
+
+/// An error returned when a raw VCF record fails to parse.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// A field is missing.
+    MissingField(Field),
+}
+
+impl error::Error for ParseError {}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingField(field) => write!(f, "missing field: {field}"),
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a str> for Record<'a> {
+    type Error = ParseError;
+
+    fn try_from(s: &'a str) -> Result<Self, Self::Error> {
+        const FIELDS: [Field; 8] = [
+            Field::Chromosome,
+            Field::Position,
+            Field::Ids,
+            Field::ReferenceBases,
+            Field::AlternateBases,
+            Field::QualityScore,
+            Field::Filters,
+            Field::Info,
+        ];
+
+        let mut raw_fields = s.splitn(MAX_FIELDS, FIELD_DELIMITER);
+        let mut fields = [MISSING_FIELD; 8];
+
+        for (field, raw_field) in FIELDS.into_iter().zip(&mut fields) {
+            *raw_field = raw_fields.next().ok_or(ParseError::MissingField(field))?;
+        }
+
+        let genotypes = raw_fields.next().unwrap_or_default();
+
+        Ok(Self { fields, genotypes })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_from_str_for_record() {
+        let record = Record::try_from("sq0\t8\tnd0\tA\tC\t13\tPASS\tDP=5\tGT\t0/1\t1/1");
+
+        assert_eq!(
+            record.map(|r| (
+                r.chromosome(),
+                r.position(),
+                r.ids(),
+                r.reference_bases(),
+                r.alternate_bases(),
+                r.quality_score(),
+                r.filters(),
+            )),
+            Ok(("sq0", "8", "nd0", "A", "C", "13", "PASS"))
+        );
+
+        assert_eq!(
+            Record::try_from("sq0\t8\t.\tA\tC\t13\tPASS"),
+            Err(ParseError::MissingField(Field::Info))
+        );
+
+        assert_eq!(
+            Record::try_from(""),
+            Err(ParseError::MissingField(Field::Position))
+        );
+    }
+}
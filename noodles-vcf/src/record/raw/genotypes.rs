@@ -0,0 +1,178 @@
+use crate::record::{FIELD_DELIMITER, MISSING_FIELD};
+
+const DELIMITER: char = ':';
+
+/// Raw VCF record genotypes.
+///
+/// This is the genotype format (`FORMAT`) and sample fields of a record. Keys and values borrow
+/// from the raw fields. A missing value (`.`) and a dropped trailing value are both `None`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Genotypes<'a> {
+    format: &'a str,
+    samples: &'a str,
+}
+
+impl<'a> Genotypes<'a> {
+    /// Creates raw VCF record genotypes.
+    ///
+    /// The input is the tab-delimited genotype format (`FORMAT`) and sample fields.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::raw;
+    /// let genotypes = raw::Genotypes::new("GT:GQ\t0|0:13\t0/1:8");
+    /// ```
+    pub fn new(s: &'a str) -> Self {
+        let (format, samples) = s.split_once(FIELD_DELIMITER).unwrap_or((s, ""));
+        Self { format, samples }
+    }
+
+    /// Returns whether there are any genotype keys.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::raw;
+    /// assert!(raw::Genotypes::new("").is_empty());
+    /// assert!(!raw::Genotypes::new("GT\t0|0").is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.format.is_empty()
+    }
+
+    /// Returns an iterator over the genotype keys.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::raw;
+    /// let genotypes = raw::Genotypes::new("GT:GQ\t0|0:13");
+    /// assert!(genotypes.keys().eq(["GT", "GQ"]));
+    /// ```
+    pub fn keys(&self) -> impl Iterator<Item = &'a str> {
+        split(self.format, DELIMITER)
+    }
+
+    /// Returns the number of samples.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::raw;
+    /// assert_eq!(raw::Genotypes::new("").sample_count(), 0);
+    /// assert_eq!(raw::Genotypes::new("GT:GQ\t0|0:13\t0/1:8").sample_count(), 2);
+    /// ```
+    pub fn sample_count(&self) -> usize {
+        self.samples().count()
+    }
+
+    /// Returns the value of the field with the given key of the sample at the given index.
+    ///
+    /// This returns `None` if the key or sample does not exist.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::raw;
+    ///
+    /// let genotypes = raw::Genotypes::new("GT:GQ:DP\t0|0:13\t0/1:.:8\t.");
+    ///
+    /// assert_eq!(genotypes.get(0, "GQ"), Some(Some("13")));
+    /// assert_eq!(genotypes.get(0, "DP"), Some(None));
+    /// assert_eq!(genotypes.get(1, "GQ"), Some(None));
+    /// assert_eq!(genotypes.get(2, "GT"), Some(None));
+    /// assert!(genotypes.get(0, "PS").is_none());
+    /// assert!(genotypes.get(3, "GT").is_none());
+    /// ```
+    pub fn get(&self, sample_index: usize, key: &str) -> Option<Option<&'a str>> {
+        let i = self.keys().position(|k| k == key)?;
+        self.get_index(sample_index, i)
+    }
+
+    /// Returns the value of the field at the given key index of the sample at the given index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::raw;
+    /// let genotypes = raw::Genotypes::new("GT:GQ\t0|0:13\t0/1:8");
+    /// assert_eq!(genotypes.get_index(1, 1), Some(Some("8")));
+    /// ```
+    pub fn get_index(&self, sample_index: usize, key_index: usize) -> Option<Option<&'a str>> {
+        if key_index >= self.keys().count() {
+            return None;
+        }
+
+        self.samples()
+            .nth(sample_index)
+            .map(|sample| get_value(sample, key_index))
+    }
+
+    /// Returns an iterator over the values of the field with the given key of all samples.
+    ///
+    /// This returns `None` if the key does not exist.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::raw;
+    ///
+    /// let genotypes = raw::Genotypes::new("GT:GQ\t0|0:13\t0/1\t.");
+    /// let values = genotypes.values("GQ").map(|values| values.collect::<Vec<_>>());
+    ///
+    /// assert_eq!(values, Some(vec![Some("13"), None, None]));
+    /// ```
+    pub fn values(&self, key: &str) -> Option<impl Iterator<Item = Option<&'a str>>> {
+        let i = self.keys().position(|k| k == key)?;
+        Some(self.samples().map(move |sample| get_value(sample, i)))
+    }
+
+    fn samples(&self) -> impl Iterator<Item = &'a str> {
+        split(self.samples, FIELD_DELIMITER)
+    }
+}
+
+fn split(s: &str, delimiter: char) -> impl Iterator<Item = &str> {
+    let mut iter = s.split(delimiter);
+
+    // An empty string has no values.
+    if s.is_empty() {
+        iter.next();
+    }
+
+    iter
+}
+
+fn get_value(sample: &str, i: usize) -> Option<&str> {
+    match sample.split(DELIMITER).nth(i) {
+        None | Some(MISSING_FIELD) => None,
+        Some(value) => Some(value),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        let genotypes = Genotypes::new("GT");
+        assert!(genotypes.keys().eq(["GT"]));
+        assert_eq!(genotypes.sample_count(), 0);
+        assert!(genotypes.get(0, "GT").is_none());
+
+        let genotypes = Genotypes::new("");
+        assert_eq!(genotypes.keys().count(), 0);
+        assert!(genotypes.values("GT").is_none());
+    }
+
+    #[test]
+    fn test_get_index() {
+        let genotypes = Genotypes::new("GT:AD\t0/1:5,8");
+        assert_eq!(genotypes.get_index(0, 0), Some(Some("0/1")));
+        assert_eq!(genotypes.get_index(0, 1), Some(Some("5,8")));
+        assert!(genotypes.get_index(0, 2).is_none());
+        assert!(genotypes.get_index(1, 0).is_none());
+    }
+}
@@ -0,0 +1,132 @@
+use crate::record::MISSING_FIELD;
+
+const DELIMITER: char = ';';
+const SEPARATOR: char = '=';
+
+/// Raw VCF record information fields (`INFO`).
+///
+/// Keys and values borrow from the raw field. Values are returned as stored: a field without a
+/// value, e.g., a flag, has a value of `None`, and a missing value is `Some(".")`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Info<'a>(&'a str);
+
+impl<'a> Info<'a> {
+    /// Creates raw VCF record information fields.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::raw;
+    /// let info = raw::Info::new("NS=2;DP=13");
+    /// ```
+    pub fn new(s: &'a str) -> Self {
+        Self(s)
+    }
+
+    /// Returns whether there are any info fields.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::raw;
+    /// assert!(raw::Info::new(".").is_empty());
+    /// assert!(!raw::Info::new("NS=2;DP=13").is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty() || self.0 == MISSING_FIELD
+    }
+
+    /// Returns the number of info fields.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::raw;
+    /// assert_eq!(raw::Info::new(".").len(), 0);
+    /// assert_eq!(raw::Info::new("NS=2;DP=13").len(), 2);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.iter().count()
+    }
+
+    /// Returns the value of the field with the given key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::raw;
+    ///
+    /// let info = raw::Info::new("NS=2;DB;AF=.");
+    ///
+    /// assert_eq!(info.get("NS"), Some(Some("2")));
+    /// assert_eq!(info.get("DB"), Some(None));
+    /// assert_eq!(info.get("AF"), Some(Some(".")));
+    /// assert!(info.get("DP").is_none());
+    /// ```
+    pub fn get(&self, key: &str) -> Option<Option<&'a str>> {
+        self.iter().find(|(k, _)| *k == key).map(|(_, v)| v)
+    }
+
+    /// Returns the key and value of the field at the given index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::raw;
+    ///
+    /// let info = raw::Info::new("NS=2;DP=13");
+    ///
+    /// assert_eq!(info.get_index(1), Some(("DP", Some("13"))));
+    /// assert!(info.get_index(2).is_none());
+    /// ```
+    pub fn get_index(&self, i: usize) -> Option<(&'a str, Option<&'a str>)> {
+        self.iter().nth(i)
+    }
+
+    /// Returns an iterator over the keys and values of the fields.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::record::raw;
+    ///
+    /// let info = raw::Info::new("NS=2;DB;AF=.");
+    /// let mut iter = info.iter();
+    ///
+    /// assert_eq!(iter.next(), Some(("NS", Some("2"))));
+    /// assert_eq!(iter.next(), Some(("DB", None)));
+    /// assert_eq!(iter.next(), Some(("AF", Some("."))));
+    /// assert!(iter.next().is_none());
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = (&'a str, Option<&'a str>)> {
+        let s = if self.is_empty() { "" } else { self.0 };
+
+        s.split(DELIMITER)
+            .filter(|field| !field.is_empty())
+            .map(|field| match field.split_once(SEPARATOR) {
+                Some((key, value)) => (key, Some(value)),
+                None => (field, None),
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_iter() {
+        assert_eq!(Info::new("").iter().count(), 0);
+        assert_eq!(Info::new(".").iter().count(), 0);
+
+        let info = Info::new("NS=2;DB;AF=0.333,0.667;H2=.");
+        let actual: Vec<_> = info.iter().collect();
+        let expected = [
+            ("NS", Some("2")),
+            ("DB", None),
+            ("AF", Some("0.333,0.667")),
+            ("H2", Some(".")),
+        ];
+        assert_eq!(actual, expected);
+    }
+}
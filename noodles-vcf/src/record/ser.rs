@@ -0,0 +1,268 @@
+//! Serde serialization of VCF records.
+//!
+//! A record is serialized as a map of its fields. INFO fields are serialized as a map of keys to
+//! typed values, and genotypes are serialized as a list of sample maps of keys to typed values.
+//! Missing values are serialized as unit values (e.g., `null` in JSON), and flags are serialized
+//! as `true`.
+
+use serde::ser::{Serialize, SerializeMap, SerializeSeq, SerializeStruct, Serializer};
+
+use super::{
+    genotypes::{genotype, Genotype},
+    info, AlternateBases, Chromosome, Filters, Genotypes, Ids, Info, Position, QualityScore,
+    Record, ReferenceBases,
+};
+
+impl Serialize for Record {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Record", 9)?;
+        state.serialize_field("chromosome", self.chromosome())?;
+        state.serialize_field("position", &self.position())?;
+        state.serialize_field("ids", self.ids())?;
+        state.serialize_field("reference_bases", self.reference_bases())?;
+        state.serialize_field("alternate_bases", self.alternate_bases())?;
+        state.serialize_field("quality_score", &self.quality_score())?;
+        state.serialize_field("filters", &self.filters())?;
+        state.serialize_field("info", self.info())?;
+        state.serialize_field("genotypes", self.genotypes())?;
+        state.end()
+    }
+}
+
+impl Serialize for Chromosome {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl Serialize for Position {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_u64(usize::from(*self) as u64)
+    }
+}
+
+impl Serialize for Ids {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_seq(self.iter().map(|id| &**id))
+    }
+}
+
+impl Serialize for ReferenceBases {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl Serialize for AlternateBases {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+
+        for allele in self.iter() {
+            seq.serialize_element(&allele.to_string())?;
+        }
+
+        seq.end()
+    }
+}
+
+impl Serialize for QualityScore {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_f32(f32::from(*self))
+    }
+}
+
+impl Serialize for Filters {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Self::Pass => serializer.collect_seq(["PASS"]),
+            Self::Fail(ids) => serializer.collect_seq(ids),
+        }
+    }
+}
+
+impl Serialize for Info {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(self.len()))?;
+
+        for (key, value) in self.keys().zip(self.values()) {
+            map.serialize_entry(key.as_ref(), &value)?;
+        }
+
+        map.end()
+    }
+}
+
+impl Serialize for info::field::Value {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Self::Integer(n) => serializer.serialize_i32(*n),
+            Self::Float(n) => serializer.serialize_f32(*n),
+            Self::Flag => serializer.serialize_bool(true),
+            Self::Character(c) => serializer.serialize_char(*c),
+            Self::String(s) => serializer.serialize_str(s),
+            Self::IntegerArray(values) => values.serialize(serializer),
+            Self::FloatArray(values) => values.serialize(serializer),
+            Self::CharacterArray(values) => values.serialize(serializer),
+            Self::StringArray(values) => values.serialize(serializer),
+        }
+    }
+}
+
+impl Serialize for Genotypes {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+impl Serialize for Genotype {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(self.len()))?;
+
+        for (key, value) in self.iter() {
+            map.serialize_entry(key.as_ref(), value)?;
+        }
+
+        map.end()
+    }
+}
+
+impl Serialize for genotype::field::Value {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Self::Integer(n) => serializer.serialize_i32(*n),
+            Self::Float(n) => serializer.serialize_f32(*n),
+            Self::Character(c) => serializer.serialize_char(*c),
+            Self::String(s) => serializer.serialize_str(s),
+            Self::IntegerArray(values) => values.serialize(serializer),
+            Self::FloatArray(values) => values.serialize(serializer),
+            Self::CharacterArray(values) => values.serialize(serializer),
+            Self::StringArray(values) => values.serialize(serializer),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        header::{
+            format::key as format_key,
+            info::key as info_key,
+            record::value::{
+                map::{Format, Info as InfoMap},
+                Map,
+            },
+        },
+        Header,
+    };
+
+    #[test]
+    fn test_serialize() -> Result<(), Box<dyn std::error::Error>> {
+        let header = Header::builder()
+            .add_info(
+                info_key::TOTAL_DEPTH,
+                Map::<InfoMap>::from(&info_key::TOTAL_DEPTH),
+            )
+            .add_info(
+                info_key::ALLELE_FREQUENCIES,
+                Map::<InfoMap>::from(&info_key::ALLELE_FREQUENCIES),
+            )
+            .add_info(
+                info_key::IS_IN_DB_SNP,
+                Map::<InfoMap>::from(&info_key::IS_IN_DB_SNP),
+            )
+            .add_format(
+                format_key::GENOTYPE,
+                Map::<Format>::from(&format_key::GENOTYPE),
+            )
+            .add_format(
+                format_key::CONDITIONAL_GENOTYPE_QUALITY,
+                Map::<Format>::from(&format_key::CONDITIONAL_GENOTYPE_QUALITY),
+            )
+            .add_sample_name("sample0")
+            .add_sample_name("sample1")
+            .build();
+
+        let record = Record::try_from_str(
+            "sq0\t8\trs1\tA\tC,<DEL>\t13.5\tq10\tDP=5;AF=0.5,.;DB\tGT:GQ\t0/1:21\t./.:.",
+            &header,
+        )?;
+
+        let actual = serde_json::to_value(&record)?;
+
+        let expected = serde_json::json!({
+            "chromosome": "sq0",
+            "position": 8,
+            "ids": ["rs1"],
+            "reference_bases": "A",
+            "alternate_bases": ["C", "<DEL>"],
+            "quality_score": 13.5,
+            "filters": ["q10"],
+            "info": { "DP": 5, "AF": [0.5, null], "DB": true },
+            "genotypes": [
+                { "GT": "0/1", "GQ": 21 },
+                { "GT": "./.", "GQ": null },
+            ],
+        });
+
+        assert_eq!(actual, expected);
+
+        let record = Record::try_from_str("sq0\t8\t.\tA\t.\t.\tPASS\t.", &Header::default())?;
+        let actual = serde_json::to_value(&record)?;
+
+        let expected = serde_json::json!({
+            "chromosome": "sq0",
+            "position": 8,
+            "ids": [],
+            "reference_bases": "A",
+            "alternate_bases": [],
+            "quality_score": null,
+            "filters": ["PASS"],
+            "info": {},
+            "genotypes": [],
+        });
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+}
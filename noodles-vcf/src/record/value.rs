@@ -2,6 +2,8 @@ use std::{borrow::Cow, num, str};
 
 use percent_encoding::percent_decode_str;
 
+use crate::header::FileFormat;
+
 /// Parses a single-precision floating-point.
 pub(crate) fn parse_f32(s: &str) -> Result<f32, num::ParseFloatError> {
     s.parse()
@@ -11,6 +13,66 @@ pub(crate) fn percent_decode(s: &str) -> Result<Cow<'_, str>, str::Utf8Error> {
     percent_decode_str(s).decode_utf8()
 }
 
+/// Decodes a raw string value.
+///
+/// Percent-encoding was introduced in VCF 4.3. Values of records with an earlier file format are
+/// not decoded.
+pub(crate) fn decode_string(
+    file_format: FileFormat,
+    s: &str,
+) -> Result<Cow<'_, str>, str::Utf8Error> {
+    if file_format < FileFormat::new(4, 3) {
+        Ok(Cow::from(s))
+    } else {
+        percent_decode(s)
+    }
+}
+
+/// Encodes a string value.
+///
+/// Percent-encoding was introduced in VCF 4.3. Values of records with an earlier file format are
+/// not encoded.
+pub(crate) fn encode_string(file_format: FileFormat, s: &str) -> Cow<'_, str> {
+    if file_format < FileFormat::new(4, 3) {
+        Cow::from(s)
+    } else {
+        percent_encode(s)
+    }
+}
+
+/// Percent-encodes a string value.
+///
+/// § 1.2 Character encoding, non-printable characters and characters with special meaning
+/// (2022-08-05): ":" (`%3A`), ";" (`%3B`), "=" (`%3D`), "%" (`%25`), "," (`%2C`), CR (`%0D`), LF
+/// (`%0A`), and TAB (`%09`) must be percent-encoded.
+pub(crate) fn percent_encode(s: &str) -> Cow<'_, str> {
+    const HEX_DIGITS: &[u8; 16] = b"0123456789ABCDEF";
+
+    if !s.contains(is_reserved_char) {
+        return Cow::from(s);
+    }
+
+    let mut t = String::with_capacity(s.len() + 8);
+
+    for c in s.chars() {
+        if is_reserved_char(c) {
+            // All reserved characters are ASCII.
+            let b = c as u8;
+            t.push('%');
+            t.push(char::from(HEX_DIGITS[usize::from(b >> 4)]));
+            t.push(char::from(HEX_DIGITS[usize::from(b & 0x0f)]));
+        } else {
+            t.push(c);
+        }
+    }
+
+    Cow::from(t)
+}
+
+fn is_reserved_char(c: char) -> bool {
+    matches!(c, ':' | ';' | '=' | '%' | ',' | '\r' | '\n' | '\t')
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -40,4 +102,34 @@ mod tests {
         assert_eq!(percent_decode("noodles%3Dvcf")?, "noodles=vcf");
         Ok(())
     }
+
+    #[test]
+    fn test_decode_string() -> Result<(), str::Utf8Error> {
+        assert_eq!(
+            decode_string(FileFormat::new(4, 3), "ndls%3Bvcf")?,
+            "ndls;vcf"
+        );
+        assert_eq!(
+            decode_string(FileFormat::new(4, 2), "ndls%3Bvcf")?,
+            "ndls%3Bvcf"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_percent_encode() {
+        assert_eq!(percent_encode("noodles"), "noodles");
+        assert_eq!(percent_encode("noodles=vcf"), "noodles%3Dvcf");
+        assert_eq!(percent_encode(":;=%,\r\n\t"), "%3A%3B%3D%25%2C%0D%0A%09");
+        assert_eq!(percent_encode("🍜;"), "🍜%3B");
+    }
+
+    #[test]
+    fn test_encode_string() {
+        assert_eq!(
+            encode_string(FileFormat::new(4, 3), "ndls;vcf"),
+            "ndls%3Bvcf"
+        );
+        assert_eq!(encode_string(FileFormat::new(4, 2), "ndls;vcf"), "ndls;vcf");
+    }
 }
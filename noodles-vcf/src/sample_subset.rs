@@ -0,0 +1,367 @@
+//! VCF sample subsetting.
+
+mod builder;
+
+pub use self::builder::{BuildError, Builder};
+
+use std::io;
+
+use crate::{
+    header::{
+        info::key,
+        record::value::{map::Info, Map},
+        SampleNames,
+    },
+    record::Genotypes,
+    stats, Header, Record,
+};
+
+/// A VCF sample subset.
+///
+/// A sample subset projects headers and records onto a subset and/or reordering of the header
+/// samples. This is typically used to write a VCF with only the selected samples, i.e., the
+/// projected header and records are passed to a [`crate::Writer`].
+///
+/// # Examples
+///
+/// ```
+/// use noodles_vcf::{self as vcf, sample_subset};
+///
+/// let header = vcf::Header::builder()
+///     .add_sample_name("sample0")
+///     .add_sample_name("sample1")
+///     .add_sample_name("sample2")
+///     .build();
+///
+/// let subset = sample_subset::Builder::default()
+///     .add_sample_name("sample2")
+///     .add_sample_name("sample0")
+///     .build(&header)?;
+///
+/// let subset_header = subset.header(&header);
+///
+/// let mut record = vcf::Record::try_from_str(
+///     "sq0\t1\t.\tA\tG\t.\tPASS\t.\tGT\t0|0\t0|1\t1|1",
+///     &header,
+/// )?;
+/// subset.apply(&mut record)?;
+///
+/// let mut writer = vcf::Writer::new(Vec::new());
+/// writer.write_header(&subset_header)?;
+/// writer.write_record(&record)?;
+///
+/// let expected = b"##fileformat=VCFv4.3
+/// #CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\tFORMAT\tsample2\tsample0
+/// sq0\t1\t.\tA\tG\t.\tPASS\t.\tGT\t1|1\t0|0
+/// ";
+///
+/// assert_eq!(&writer.get_ref()[..], &expected[..]);
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SampleSubset {
+    sample_names: SampleNames,
+    indices: Vec<usize>,
+    recompute_allele_counts: bool,
+}
+
+impl SampleSubset {
+    /// Returns the names of the selected samples.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, sample_subset};
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .add_sample_name("sample0")
+    ///     .add_sample_name("sample1")
+    ///     .build();
+    ///
+    /// let subset = sample_subset::Builder::default()
+    ///     .add_sample_name("sample1")
+    ///     .build(&header)?;
+    ///
+    /// assert_eq!(subset.sample_names().len(), 1);
+    /// assert!(subset.sample_names().contains("sample1"));
+    /// # Ok::<_, sample_subset::BuildError>(())
+    /// ```
+    pub fn sample_names(&self) -> &SampleNames {
+        &self.sample_names
+    }
+
+    /// Projects a VCF header onto the sample subset.
+    ///
+    /// The sample names are replaced by the selected samples. If allele counts are to be
+    /// recomputed, the reserved `AC`, `AN`, and `AF` info header records are added when missing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, sample_subset};
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .add_sample_name("sample0")
+    ///     .add_sample_name("sample1")
+    ///     .build();
+    ///
+    /// let subset = sample_subset::Builder::default()
+    ///     .add_sample_name("sample1")
+    ///     .build(&header)?;
+    ///
+    /// let subset_header = subset.header(&header);
+    /// assert_eq!(subset_header.sample_names(), subset.sample_names());
+    /// # Ok::<_, sample_subset::BuildError>(())
+    /// ```
+    pub fn header(&self, header: &Header) -> Header {
+        let mut header = header.clone();
+
+        *header.sample_names_mut() = self.sample_names.clone();
+
+        if self.recompute_allele_counts {
+            for key in [
+                key::ALLELE_COUNT,
+                key::TOTAL_ALLELE_COUNT,
+                key::ALLELE_FREQUENCIES,
+            ] {
+                header
+                    .infos_mut()
+                    .entry(key.clone())
+                    .or_insert_with(|| Map::<Info>::from(&key));
+            }
+        }
+
+        header
+    }
+
+    /// Projects a VCF record onto the sample subset.
+    ///
+    /// The genotypes are reordered and trimmed to the selected samples. If no samples are
+    /// selected, the genotypes, including the format keys, are cleared.
+    ///
+    /// If allele counts are to be recomputed, the `AC`, `AN`, and `AF` info fields are set using
+    /// the genotypes (`GT`) of the selected samples.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the record is missing a selected sample or a genotype is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, sample_subset};
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .add_sample_name("sample0")
+    ///     .add_sample_name("sample1")
+    ///     .build();
+    ///
+    /// let subset = sample_subset::Builder::default()
+    ///     .add_sample_name("sample1")
+    ///     .build(&header)?;
+    ///
+    /// let mut record = vcf::Record::try_from_str(
+    ///     "sq0\t1\t.\tA\tG\t.\tPASS\t.\tGT\t0|0\t0|1",
+    ///     &header,
+    /// )?;
+    /// subset.apply(&mut record)?;
+    ///
+    /// assert_eq!(record.genotypes().len(), 1);
+    /// assert_eq!(record.to_string(), "sq0\t1\t.\tA\tG\t.\tPASS\t.\tGT\t0|1");
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn apply(&self, record: &mut Record) -> io::Result<()> {
+        let genotypes = record.genotypes_mut();
+
+        if self.indices.is_empty() {
+            *genotypes = Genotypes::default();
+        } else {
+            let mut src: Vec<_> = genotypes.drain(..).map(Some).collect();
+            let mut dst = Vec::with_capacity(self.indices.len());
+
+            for &i in &self.indices {
+                let genotype = src
+                    .get_mut(i)
+                    .and_then(|genotype| genotype.take())
+                    .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "missing sample"))?;
+
+                dst.push(genotype);
+            }
+
+            **genotypes = dst;
+        }
+
+        if self.recompute_allele_counts {
+            update_allele_counts(record)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn update_allele_counts(record: &mut Record) -> io::Result<()> {
+    let allele_stats =
+        stats::compute(record).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+    allele_stats
+        .update_info(record.info_mut())
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::record::info::field::Value;
+
+    fn build_header() -> Header {
+        Header::builder()
+            .add_sample_name("sample0")
+            .add_sample_name("sample1")
+            .add_sample_name("sample2")
+            .build()
+    }
+
+    #[test]
+    fn test_header() -> Result<(), BuildError> {
+        let header = build_header();
+
+        let subset = Builder::default()
+            .add_sample_name("sample2")
+            .add_sample_name("sample0")
+            .build(&header)?;
+
+        let actual = subset.header(&header);
+
+        let expected: SampleNames = [String::from("sample2"), String::from("sample0")]
+            .into_iter()
+            .collect();
+
+        assert_eq!(actual.sample_names(), &expected);
+        assert!(actual.infos().is_empty());
+
+        let subset = Builder::default()
+            .add_sample_name("sample1")
+            .set_recompute_allele_counts(true)
+            .build(&header)?;
+
+        let actual = subset.header(&header);
+
+        assert!(actual.infos().contains_key(&key::ALLELE_COUNT));
+        assert!(actual.infos().contains_key(&key::TOTAL_ALLELE_COUNT));
+        assert!(actual.infos().contains_key(&key::ALLELE_FREQUENCIES));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_apply() -> Result<(), Box<dyn std::error::Error>> {
+        let header = build_header();
+
+        let subset = Builder::default()
+            .add_sample_name("sample2")
+            .add_sample_name("sample0")
+            .build(&header)?;
+
+        let mut record = Record::try_from_str(
+            "sq0\t1\t.\tA\tG\t.\tPASS\t.\tGT:GQ\t0|0:7\t0|1:8\t1|1:13",
+            &header,
+        )?;
+        subset.apply(&mut record)?;
+
+        assert_eq!(
+            record.to_string(),
+            "sq0\t1\t.\tA\tG\t.\tPASS\t.\tGT:GQ\t1|1:13\t0|0:7"
+        );
+
+        let subset = Builder::default().build(&header)?;
+
+        let mut record =
+            Record::try_from_str("sq0\t1\t.\tA\tG\t.\tPASS\t.\tGT\t0|0\t0|1\t1|1", &header)?;
+        subset.apply(&mut record)?;
+
+        assert_eq!(record.to_string(), "sq0\t1\t.\tA\tG\t.\tPASS\t.");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_apply_with_missing_sample() -> Result<(), Box<dyn std::error::Error>> {
+        let header = build_header();
+
+        let subset = Builder::default()
+            .add_sample_name("sample2")
+            .build(&header)?;
+
+        let mut record = Record::try_from_str("sq0\t1\t.\tA\tG\t.\tPASS\t.\tGT\t0|0", &header)?;
+
+        assert!(matches!(
+            subset.apply(&mut record),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_apply_with_recompute_allele_counts() -> Result<(), Box<dyn std::error::Error>> {
+        let header = build_header();
+
+        let subset = Builder::default()
+            .add_sample_name("sample1")
+            .add_sample_name("sample2")
+            .set_recompute_allele_counts(true)
+            .build(&header)?;
+
+        let mut record = Record::try_from_str(
+            "sq0\t1\t.\tA\tG,T\t.\tPASS\tAC=4,0;AN=6\tGT\t1|1\t0|1\t./2",
+            &header,
+        )?;
+        subset.apply(&mut record)?;
+
+        let info = record.info();
+
+        assert_eq!(
+            info.get(&key::ALLELE_COUNT),
+            Some(Some(&Value::IntegerArray(vec![Some(1), Some(1)])))
+        );
+        assert_eq!(
+            info.get(&key::TOTAL_ALLELE_COUNT),
+            Some(Some(&Value::Integer(3)))
+        );
+
+        let allele_frequencies = 1.0 / 3.0;
+        assert_eq!(
+            info.get(&key::ALLELE_FREQUENCIES),
+            Some(Some(&Value::FloatArray(vec![
+                Some(allele_frequencies),
+                Some(allele_frequencies)
+            ])))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_apply_with_recompute_allele_counts_and_no_alternate_bases(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let header = build_header();
+
+        let subset = Builder::default()
+            .add_sample_name("sample0")
+            .set_recompute_allele_counts(true)
+            .build(&header)?;
+
+        let mut record = Record::try_from_str(
+            "sq0\t1\t.\tA\t.\t.\tPASS\tAC=1;AN=6;AF=0.5\tGT\t0|0\t0|0\t0|0",
+            &header,
+        )?;
+        subset.apply(&mut record)?;
+
+        assert_eq!(
+            record.to_string(),
+            "sq0\t1\t.\tA\t.\t.\tPASS\tAN=2\tGT\t0|0"
+        );
+
+        Ok(())
+    }
+}
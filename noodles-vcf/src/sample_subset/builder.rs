@@ -0,0 +1,150 @@
+use std::{error, fmt};
+
+use super::SampleSubset;
+use crate::{header::SampleNames, Header};
+
+/// A VCF sample subset builder.
+#[derive(Debug, Default)]
+pub struct Builder {
+    sample_names: Vec<String>,
+    recompute_allele_counts: bool,
+}
+
+impl Builder {
+    /// Adds a sample to select.
+    ///
+    /// Samples are output in the order they are added.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::sample_subset;
+    /// let builder = sample_subset::Builder::default().add_sample_name("sample0");
+    /// ```
+    pub fn add_sample_name<N>(mut self, sample_name: N) -> Self
+    where
+        N: Into<String>,
+    {
+        self.sample_names.push(sample_name.into());
+        self
+    }
+
+    /// Sets whether to recompute the allele counts (`AC`, `AN`, and `AF`) from the selected
+    /// samples.
+    ///
+    /// By default, allele counts are not recomputed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::sample_subset;
+    /// let builder = sample_subset::Builder::default().set_recompute_allele_counts(true);
+    /// ```
+    pub fn set_recompute_allele_counts(mut self, recompute_allele_counts: bool) -> Self {
+        self.recompute_allele_counts = recompute_allele_counts;
+        self
+    }
+
+    /// Builds a sample subset using the sample names in the given header.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if a sample name is not in the header or is selected more than once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, sample_subset};
+    ///
+    /// let header = vcf::Header::builder().add_sample_name("sample0").build();
+    ///
+    /// let subset = sample_subset::Builder::default()
+    ///     .add_sample_name("sample0")
+    ///     .build(&header)?;
+    ///
+    /// assert!(sample_subset::Builder::default()
+    ///     .add_sample_name("sample1")
+    ///     .build(&header)
+    ///     .is_err());
+    /// # Ok::<_, sample_subset::BuildError>(())
+    /// ```
+    pub fn build(self, header: &Header) -> Result<SampleSubset, BuildError> {
+        let mut sample_names = SampleNames::with_capacity(self.sample_names.len());
+        let mut indices = Vec::with_capacity(self.sample_names.len());
+
+        for sample_name in self.sample_names {
+            let i = header
+                .sample_names()
+                .get_index_of(&sample_name)
+                .ok_or_else(|| BuildError::MissingSampleName(sample_name.clone()))?;
+
+            if sample_names.contains(&sample_name) {
+                return Err(BuildError::DuplicateSampleName(sample_name));
+            }
+
+            sample_names.insert(sample_name);
+            indices.push(i);
+        }
+
+        Ok(SampleSubset {
+            sample_names,
+            indices,
+            recompute_allele_counts: self.recompute_allele_counts,
+        })
+    }
+}
+
+/// An error returned when a VCF sample subset fails to build.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum BuildError {
+    /// A sample name is not in the header.
+    MissingSampleName(String),
+    /// A sample name is duplicated.
+    DuplicateSampleName(String),
+}
+
+impl error::Error for BuildError {}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingSampleName(name) => write!(f, "missing sample name: {name}"),
+            Self::DuplicateSampleName(name) => write!(f, "duplicate sample name: {name}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build() {
+        let header = Header::builder()
+            .add_sample_name("sample0")
+            .add_sample_name("sample1")
+            .build();
+
+        assert_eq!(
+            Builder::default().add_sample_name("sample1").build(&header),
+            Ok(SampleSubset {
+                sample_names: [String::from("sample1")].into_iter().collect(),
+                indices: vec![1],
+                recompute_allele_counts: false,
+            })
+        );
+
+        assert_eq!(
+            Builder::default().add_sample_name("sample2").build(&header),
+            Err(BuildError::MissingSampleName(String::from("sample2")))
+        );
+
+        assert_eq!(
+            Builder::default()
+                .add_sample_name("sample0")
+                .add_sample_name("sample0")
+                .build(&header),
+            Err(BuildError::DuplicateSampleName(String::from("sample0")))
+        );
+    }
+}
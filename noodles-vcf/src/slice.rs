@@ -0,0 +1,261 @@
+use std::io::{self, Read, Seek, Write};
+
+use noodles_bgzf as bgzf;
+use noodles_core::{Position, Region};
+use noodles_tabix as tabix;
+
+use super::{reader::resolve_region, Reader, Writer};
+
+/// Extracts records in the given regions from an indexed VCF reader and writes them as a VCF.
+///
+/// The reader is expected to be at the start of the stream. Its header is written to the writer
+/// using [`Writer::write_header`], so records are written using the file format of the input,
+/// followed by the records that intersect any of the regions.
+///
+/// Overlapping regions are merged, and regions are sorted by the order of reference sequences in
+/// the index and by start position. Each record is written at most once, even if it intersects
+/// more than one region.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::{fs::File, io};
+/// use noodles_bgzf as bgzf;
+/// use noodles_tabix as tabix;
+/// use noodles_vcf as vcf;
+///
+/// let mut reader = File::open("sample.vcf.gz")
+///     .map(bgzf::Reader::new)
+///     .map(vcf::Reader::new)?;
+///
+/// let index = tabix::read("sample.vcf.gz.tbi")?;
+/// let regions = ["sq0:8-13".parse()?, "sq1".parse()?];
+///
+/// let mut writer = vcf::Writer::new(io::stdout().lock());
+///
+/// vcf::slice(&mut reader, &index, &regions, &mut writer)?;
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+///
+/// # Errors
+///
+/// An error of kind [`io::ErrorKind::InvalidInput`] is returned if a region reference sequence is
+/// not in the index.
+pub fn slice<R, W>(
+    reader: &mut Reader<bgzf::Reader<R>>,
+    index: &tabix::Index,
+    regions: &[Region],
+    writer: &mut Writer<W>,
+) -> io::Result<()>
+where
+    R: Read + Seek,
+    W: Write,
+{
+    let regions = merge_regions(index, regions)?;
+
+    let header = reader
+        .read_header()?
+        .parse()
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+    writer.write_header(&header)?;
+
+    let mut prev: Option<(usize, Option<Position>)> = None;
+
+    for (reference_sequence_id, region) in regions {
+        // A record that starts before and intersects this region also intersects the previous
+        // region on the same reference sequence if it starts at or before its end.
+        let prev_end = match prev {
+            Some((id, end)) if id == reference_sequence_id => end,
+            _ => None,
+        };
+
+        for result in reader.query(&header, index, &region)? {
+            let record = result?;
+
+            if let Some(end) = prev_end {
+                if usize::from(record.position()) <= usize::from(end) {
+                    continue;
+                }
+            }
+
+            writer.write_record(&record)?;
+        }
+
+        prev = Some((reference_sequence_id, region.interval().end()));
+    }
+
+    Ok(())
+}
+
+// Sorts and merges overlapping regions.
+fn merge_regions(index: &tabix::Index, regions: &[Region]) -> io::Result<Vec<(usize, Region)>> {
+    let mut intervals = Vec::with_capacity(regions.len());
+
+    for region in regions {
+        let (reference_sequence_id, _) = resolve_region(index, region)?;
+        let interval = region.interval();
+        let start = interval.start().unwrap_or(Position::MIN);
+        intervals.push((reference_sequence_id, start, interval.end()));
+    }
+
+    intervals.sort_by_key(|&(id, start, _)| (id, start));
+
+    let mut merged_intervals: Vec<(usize, Position, Option<Position>)> = Vec::new();
+
+    for (id, start, end) in intervals {
+        if let Some((last_id, _, last_end)) = merged_intervals.last_mut() {
+            if *last_id == id && last_end.map(|e| start <= e).unwrap_or(true) {
+                *last_end = last_end.zip(end).map(|(a, b)| a.max(b));
+                continue;
+            }
+        }
+
+        merged_intervals.push((id, start, end));
+    }
+
+    let reference_sequence_names = index.header().reference_sequence_names();
+
+    Ok(merged_intervals
+        .into_iter()
+        .map(|(id, start, end)| {
+            // The reference sequence ID was resolved from the index.
+            let name = reference_sequence_names
+                .get_index(id)
+                .expect("invalid reference sequence ID");
+
+            let region = match end {
+                Some(end) => Region::new(name.as_str(), start..=end),
+                None => Region::new(name.as_str(), start..),
+            };
+
+            (id, region)
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+    use crate::{
+        header::record::value::{map::Contig, Map},
+        Header, IndexedWriter, Record,
+    };
+
+    fn write_indexed(header: &Header, records: &[&str]) -> io::Result<(tabix::Index, Vec<u8>)> {
+        let mut indexed_writer = IndexedWriter::new(Vec::new());
+        indexed_writer.write_header(header)?;
+
+        for s in records {
+            let record = Record::try_from_str(s, header)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+            indexed_writer.write_record(&record)?;
+        }
+
+        let index = indexed_writer.finish()?;
+        let data = indexed_writer.get_ref().get_ref().clone();
+
+        Ok((index, data))
+    }
+
+    #[test]
+    fn test_slice() -> Result<(), Box<dyn std::error::Error>> {
+        let header = Header::builder()
+            .add_contig("sq0".parse()?, Map::<Contig>::new())
+            .add_contig("sq1".parse()?, Map::<Contig>::new())
+            .build();
+
+        let (index, data) = write_indexed(
+            &header,
+            &[
+                "sq0\t1\t.\tA\t.\t.\tPASS\t.",
+                "sq0\t5\t.\tACGTACGT\tA\t.\tPASS\t.",
+                "sq0\t8\t.\tA\t.\t.\tPASS\t.",
+                "sq0\t13\t.\tA\t.\t.\tPASS\t.",
+                "sq1\t2\t.\tA\t.\t.\tPASS\t.",
+            ],
+        )?;
+
+        let regions = [
+            "sq1".parse()?,
+            "sq0:8-9".parse()?,
+            "sq0:3-6".parse()?,
+            "sq0:6-7".parse()?,
+        ];
+
+        let mut reader = Reader::new(bgzf::Reader::new(Cursor::new(data)));
+        let mut writer = Writer::new(Vec::new());
+        slice(&mut reader, &index, &regions, &mut writer)?;
+
+        let expected = format!(
+            "{header}sq0\t5\t.\tACGTACGT\tA\t.\tPASS\t.
+sq0\t8\t.\tA\t.\t.\tPASS\t.
+sq1\t2\t.\tA\t.\t.\tPASS\t.
+"
+        );
+
+        assert_eq!(String::from_utf8(writer.into_inner())?, expected);
+
+        let regions = ["sq2".parse()?];
+        let mut reader = Reader::new(bgzf::Reader::new(Cursor::new(Vec::new())));
+        let mut writer = Writer::new(Vec::new());
+
+        assert!(matches!(
+            slice(&mut reader, &index, &regions, &mut writer),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_slice_with_vcf_4_2_input() -> Result<(), Box<dyn std::error::Error>> {
+        // Percent-encoding was introduced in VCF 4.3, so `%` is written literally.
+        let header: Header = r#"##fileformat=VCFv4.2
+##INFO=<ID=NOTE,Number=1,Type=String,Description="Note">
+##contig=<ID=sq0>
+#CHROM	POS	ID	REF	ALT	QUAL	FILTER	INFO
+"#
+        .parse()?;
+
+        let (index, data) = write_indexed(&header, &["sq0\t8\t.\tA\t.\t.\tPASS\tNOTE=100%"])?;
+
+        let regions = ["sq0".parse()?];
+        let mut reader = Reader::new(bgzf::Reader::new(Cursor::new(data)));
+        let mut writer = Writer::new(Vec::new());
+        slice(&mut reader, &index, &regions, &mut writer)?;
+
+        let expected = format!("{header}sq0\t8\t.\tA\t.\t.\tPASS\tNOTE=100%\n");
+        assert_eq!(String::from_utf8(writer.into_inner())?, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_slice_with_vcf_4_4_input() -> Result<(), Box<dyn std::error::Error>> {
+        // Spaces in INFO field values are allowed as of VCF 4.4.
+        let header: Header = r#"##fileformat=VCFv4.4
+##INFO=<ID=NOTE,Number=1,Type=String,Description="Note">
+##contig=<ID=sq0>
+#CHROM	POS	ID	REF	ALT	QUAL	FILTER	INFO
+"#
+        .parse()?;
+
+        let (index, data) =
+            write_indexed(&header, &["sq0\t8\t.\tA\t.\t.\tPASS\tNOTE=noodles vcf"])?;
+
+        let regions = ["sq0".parse()?];
+        let mut reader = Reader::new(bgzf::Reader::new(Cursor::new(data)));
+        let mut writer = crate::writer::Builder::default()
+            .set_validate_info_whitespace(true)
+            .build_with_writer(Vec::new());
+        slice(&mut reader, &index, &regions, &mut writer)?;
+
+        let expected = format!("{header}sq0\t8\t.\tA\t.\t.\tPASS\tNOTE=noodles vcf\n");
+        assert_eq!(String::from_utf8(writer.into_inner())?, expected);
+
+        Ok(())
+    }
+}
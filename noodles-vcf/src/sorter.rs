@@ -0,0 +1,275 @@
+//! VCF record sorter.
+
+mod builder;
+mod records;
+
+pub use self::{builder::Builder, records::Records};
+
+use std::{
+    fs::{self, File},
+    io::{self, BufWriter, Write},
+    path::PathBuf,
+    process,
+    sync::atomic::{self, AtomicUsize},
+};
+
+use noodles_bgzf as bgzf;
+
+use crate::{
+    record,
+    writer::{record::write_record, FloatFormat},
+    Header, Record,
+};
+
+static SORTER_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// A VCF record sorter.
+///
+/// A sorter sorts records by their genomic position (see [`record::compare`]). Records are
+/// buffered in memory, and when the buffer is full, it is sorted and spilled to a temporary
+/// (bgzip-compressed) file. Finishing the sorter merges the spilled chunks and any remaining
+/// buffered records.
+///
+/// The sort is stable, i.e., records at the same position are returned in their input order.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_vcf::{
+///     self as vcf,
+///     header::record::value::{map::Contig, Map},
+///     sorter,
+/// };
+///
+/// let header = vcf::Header::builder()
+///     .add_contig("sq0".parse()?, Map::<Contig>::new())
+///     .add_contig("sq1".parse()?, Map::<Contig>::new())
+///     .build();
+///
+/// let mut sorter = sorter::Builder::default()
+///     .set_max_record_count(2)
+///     .build(header.clone());
+///
+/// for s in [
+///     "sq1\t1\t.\tA\t.\t.\tPASS\t.",
+///     "sq0\t8\t.\tA\t.\t.\tPASS\t.",
+///     "sq0\t5\t.\tA\t.\t.\tPASS\t.",
+/// ] {
+///     sorter.push(vcf::Record::try_from_str(s, &header)?)?;
+/// }
+///
+/// let positions: Vec<_> = sorter
+///     .finish()?
+///     .map(|result| result.map(|record| record.to_string()))
+///     .collect::<std::io::Result<_>>()?;
+///
+/// assert_eq!(positions, [
+///     "sq0\t5\t.\tA\t.\t.\tPASS\t.",
+///     "sq0\t8\t.\tA\t.\t.\tPASS\t.",
+///     "sq1\t1\t.\tA\t.\t.\tPASS\t.",
+/// ]);
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug)]
+pub struct Sorter {
+    header: Header,
+    max_record_count: usize,
+    temp_dir: PathBuf,
+    id: usize,
+    buf: Vec<Record>,
+    chunk_paths: Vec<PathBuf>,
+}
+
+impl Sorter {
+    /// Creates a VCF record sorter with the default options.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, sorter::Sorter};
+    /// let sorter = Sorter::new(vcf::Header::default());
+    /// ```
+    pub fn new(header: Header) -> Self {
+        Builder::default().build(header)
+    }
+
+    /// Adds a record to the sorter.
+    ///
+    /// If the in-memory buffer is full, it is sorted and spilled to a temporary file.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, sorter::Sorter};
+    ///
+    /// let header = vcf::Header::default();
+    /// let mut sorter = Sorter::new(header.clone());
+    ///
+    /// let record = vcf::Record::try_from_str("sq0\t1\t.\tA\t.\t.\tPASS\t.", &header)?;
+    /// sorter.push(record)?;
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn push(&mut self, record: Record) -> io::Result<()> {
+        self.buf.push(record);
+
+        if self.buf.len() >= self.max_record_count {
+            self.spill()?;
+        }
+
+        Ok(())
+    }
+
+    /// Finishes sorting and returns an iterator over the sorted records.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, sorter::Sorter};
+    /// let sorter = Sorter::new(vcf::Header::default());
+    /// let mut records = sorter.finish()?;
+    /// assert!(records.next().is_none());
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn finish(mut self) -> io::Result<Records> {
+        sort(&self.header, &mut self.buf);
+
+        let header = self.header.clone();
+        let buf = std::mem::take(&mut self.buf);
+        let chunk_paths = std::mem::take(&mut self.chunk_paths);
+
+        Records::new(header, buf, chunk_paths)
+    }
+
+    fn spill(&mut self) -> io::Result<()> {
+        sort(&self.header, &mut self.buf);
+
+        let file_name = format!(
+            "noodles-vcf-sorter-{}-{}-{}.vcf.gz",
+            process::id(),
+            self.id,
+            self.chunk_paths.len()
+        );
+
+        let path = self.temp_dir.join(file_name);
+        let file = File::create(&path)?;
+        self.chunk_paths.push(path);
+
+        let mut writer = bgzf::Writer::new(BufWriter::new(file));
+
+        // Spilled records are read back using the header, so values are percent-encoded as
+        // required by its file format.
+        let float_format = FloatFormat::default();
+        let file_format = self.header.file_format();
+        let mut line = String::new();
+
+        for record in self.buf.drain(..) {
+            line.clear();
+            write_record(&mut line, &record, &float_format, file_format)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+            writeln!(writer, "{line}")?;
+        }
+
+        writer.finish()?.flush()
+    }
+}
+
+impl Drop for Sorter {
+    fn drop(&mut self) {
+        for path in &self.chunk_paths {
+            let _ = fs::remove_file(path);
+        }
+    }
+}
+
+fn next_id() -> usize {
+    SORTER_ID.fetch_add(1, atomic::Ordering::Relaxed)
+}
+
+fn sort(header: &Header, records: &mut [Record]) {
+    // `sort_by` is stable.
+    records.sort_by(|a, b| record::compare(header, a, b));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::header::record::value::{map::Contig, Map};
+
+    #[test]
+    fn test_finish() -> Result<(), Box<dyn std::error::Error>> {
+        let header = Header::builder()
+            .add_contig("sq0".parse()?, Map::<Contig>::new())
+            .add_contig("sq1".parse()?, Map::<Contig>::new())
+            .build();
+
+        let records = [
+            "sq1\t3\t.\tA\t.\t.\tPASS\t.",
+            "sq0\t8\tr0\tA\t.\t.\tPASS\t.",
+            "sq1\t1\t.\tA\t.\t.\tPASS\t.",
+            "sq0\t8\tr1\tA\t.\t.\tPASS\t.",
+            "sq0\t2\t.\tA\t.\t.\tPASS\t.",
+            "sq2\t1\t.\tA\t.\t.\tPASS\t.",
+            "sq0\t8\tr2\tA\t.\t.\tPASS\t.",
+        ];
+
+        let expected = [
+            "sq0\t2\t.\tA\t.\t.\tPASS\t.",
+            "sq0\t8\tr0\tA\t.\t.\tPASS\t.",
+            "sq0\t8\tr1\tA\t.\t.\tPASS\t.",
+            "sq0\t8\tr2\tA\t.\t.\tPASS\t.",
+            "sq1\t1\t.\tA\t.\t.\tPASS\t.",
+            "sq1\t3\t.\tA\t.\t.\tPASS\t.",
+            "sq2\t1\t.\tA\t.\t.\tPASS\t.",
+        ];
+
+        for max_record_count in [1, 2, 3, 100] {
+            let mut sorter = Builder::default()
+                .set_max_record_count(max_record_count)
+                .build(header.clone());
+
+            for s in records {
+                sorter.push(Record::try_from_str(s, &header)?)?;
+            }
+
+            let chunk_paths = sorter.chunk_paths.clone();
+
+            let actual: Vec<_> = sorter
+                .finish()?
+                .map(|result| result.map(|record| record.to_string()))
+                .collect::<io::Result<_>>()?;
+
+            assert_eq!(actual, expected);
+            assert!(chunk_paths.iter().all(|path| !path.exists()));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_finish_with_percent_encoded_info_value() -> Result<(), Box<dyn std::error::Error>> {
+        let header: Header = r#"##fileformat=VCFv4.3
+##INFO=<ID=X,Number=1,Type=String,Description="X">
+##contig=<ID=sq0>
+#CHROM	POS	ID	REF	ALT	QUAL	FILTER	INFO
+"#
+        .parse()?;
+
+        let records = [
+            Record::try_from_str("sq0\t8\t.\tA\t.\t.\tPASS\tX=a%3Bb", &header)?,
+            Record::try_from_str("sq0\t5\t.\tA\t.\t.\tPASS\tX=c%3Dd", &header)?,
+        ];
+
+        let mut sorter = Builder::default()
+            .set_max_record_count(1)
+            .build(header.clone());
+
+        for record in records.iter().cloned() {
+            sorter.push(record)?;
+        }
+
+        let actual: Vec<_> = sorter.finish()?.collect::<io::Result<_>>()?;
+        assert_eq!(actual, [records[1].clone(), records[0].clone()]);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,81 @@
+use std::{env, path::PathBuf};
+
+use super::{next_id, Sorter};
+use crate::Header;
+
+const DEFAULT_MAX_RECORD_COUNT: usize = 1 << 16;
+
+/// A VCF record sorter builder.
+#[derive(Debug)]
+pub struct Builder {
+    max_record_count: usize,
+    temp_dir: PathBuf,
+}
+
+impl Builder {
+    /// Sets the maximum number of records to buffer in memory.
+    ///
+    /// When the buffer is full, it is sorted and spilled to a temporary file. A count of 0 is
+    /// treated as 1.
+    ///
+    /// By default, this is set to 65536.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::sorter;
+    /// let builder = sorter::Builder::default().set_max_record_count(1024);
+    /// ```
+    pub fn set_max_record_count(mut self, max_record_count: usize) -> Self {
+        self.max_record_count = max_record_count.max(1);
+        self
+    }
+
+    /// Sets the directory to write temporary files to.
+    ///
+    /// By default, this is the system temporary directory ([`std::env::temp_dir`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::sorter;
+    /// let builder = sorter::Builder::default().set_temp_dir("tmp");
+    /// ```
+    pub fn set_temp_dir<P>(mut self, temp_dir: P) -> Self
+    where
+        P: Into<PathBuf>,
+    {
+        self.temp_dir = temp_dir.into();
+        self
+    }
+
+    /// Builds a VCF record sorter.
+    ///
+    /// The header is used to order records by contig and to read spilled records.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, sorter};
+    /// let sorter = sorter::Builder::default().build(vcf::Header::default());
+    /// ```
+    pub fn build(self, header: Header) -> Sorter {
+        Sorter {
+            header,
+            max_record_count: self.max_record_count,
+            temp_dir: self.temp_dir,
+            id: next_id(),
+            buf: Vec::new(),
+            chunk_paths: Vec::new(),
+        }
+    }
+}
+
+impl Default for Builder {
+    fn default() -> Self {
+        Self {
+            max_record_count: DEFAULT_MAX_RECORD_COUNT,
+            temp_dir: env::temp_dir(),
+        }
+    }
+}
@@ -0,0 +1,121 @@
+use std::{
+    fs::{self, File},
+    io::{self, BufRead, BufReader},
+    path::PathBuf,
+    vec,
+};
+
+use noodles_bgzf as bgzf;
+
+use crate::{record, Header, Record};
+
+struct Chunk {
+    reader: bgzf::Reader<BufReader<File>>,
+    line_buf: String,
+}
+
+impl Chunk {
+    fn read_record(&mut self, header: &Header) -> io::Result<Option<Record>> {
+        self.line_buf.clear();
+
+        if self.reader.read_line(&mut self.line_buf)? == 0 {
+            return Ok(None);
+        }
+
+        let line = self.line_buf.trim_end_matches('\n');
+
+        Record::try_from_str(line, header)
+            .map(Some)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+}
+
+/// An iterator over sorted records.
+///
+/// This is created by calling [`super::Sorter::finish`]. Temporary files are removed when the
+/// iterator is dropped.
+pub struct Records {
+    header: Header,
+    chunks: Vec<Chunk>,
+    chunk_paths: Vec<PathBuf>,
+    buf: vec::IntoIter<Record>,
+    // The next record of each chunk followed by the next buffered record.
+    heads: Vec<Option<Record>>,
+}
+
+impl Records {
+    pub(super) fn new(
+        header: Header,
+        buf: Vec<Record>,
+        chunk_paths: Vec<PathBuf>,
+    ) -> io::Result<Self> {
+        let mut records = Self {
+            header,
+            chunks: Vec::with_capacity(chunk_paths.len()),
+            chunk_paths,
+            buf: buf.into_iter(),
+            heads: Vec::new(),
+        };
+
+        for path in &records.chunk_paths {
+            let reader = File::open(path).map(BufReader::new)?;
+
+            records.chunks.push(Chunk {
+                reader: bgzf::Reader::new(reader),
+                line_buf: String::new(),
+            });
+        }
+
+        for chunk in &mut records.chunks {
+            let record = chunk.read_record(&records.header)?;
+            records.heads.push(record);
+        }
+
+        let record = records.buf.next();
+        records.heads.push(record);
+
+        Ok(records)
+    }
+
+    fn advance(&mut self, i: usize) -> io::Result<Option<Record>> {
+        if let Some(chunk) = self.chunks.get_mut(i) {
+            chunk.read_record(&self.header)
+        } else {
+            Ok(self.buf.next())
+        }
+    }
+}
+
+impl Iterator for Records {
+    type Item = io::Result<Record>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut min: Option<(usize, &Record)> = None;
+
+        // Chunks are in input order, so choosing the first minimum keeps the sort stable.
+        for (i, head) in self.heads.iter().enumerate() {
+            if let Some(record) = head {
+                match min {
+                    Some((_, min_record))
+                        if record::compare(&self.header, record, min_record).is_ge() => {}
+                    _ => min = Some((i, record)),
+                }
+            }
+        }
+
+        let (i, _) = min?;
+
+        match self.advance(i) {
+            Ok(record) => std::mem::replace(&mut self.heads[i], record).map(Ok),
+            Err(e) => Some(Err(e)),
+        }
+    }
+}
+
+impl Drop for Records {
+    fn drop(&mut self) {
+        for path in &self.chunk_paths {
+            let _ = fs::remove_file(path);
+        }
+    }
+}
@@ -0,0 +1,568 @@
+//! VCF record allele statistics.
+
+use std::{error, fmt, num};
+
+use crate::{
+    header::info::key,
+    record::{genotypes::genotype::GenotypeError, info::field::Value, Info},
+    Record,
+};
+
+/// An error returned when allele statistics fail to compute.
+#[derive(Clone, Debug, PartialEq)]
+pub enum StatsError {
+    /// A genotype (`GT`) field value is invalid.
+    InvalidGenotype(GenotypeError),
+    /// An allele index is not a reference or alternate allele of the record.
+    InvalidAlleleIndex(usize),
+}
+
+impl error::Error for StatsError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::InvalidGenotype(e) => Some(e),
+            Self::InvalidAlleleIndex(_) => None,
+        }
+    }
+}
+
+impl fmt::Display for StatsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidGenotype(_) => f.write_str("invalid genotype"),
+            Self::InvalidAlleleIndex(i) => write!(f, "invalid allele index: {i}"),
+        }
+    }
+}
+
+/// Allele statistics of a VCF record.
+///
+/// This is created by calling [`compute`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AlleleStats {
+    allele_counts: Vec<usize>,
+    sample_count: usize,
+    called_sample_count: usize,
+    hom_ref_count: usize,
+    het_count: usize,
+    hom_alt_count: usize,
+    hardy_weinberg_p_value: Option<f64>,
+}
+
+impl AlleleStats {
+    /// Returns the number of called alleles for each allele.
+    ///
+    /// The count of allele `i` (where 0 is the reference allele) is at index `i`. Alleles of
+    /// partially missing genotypes are counted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, stats};
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .add_sample_name("sample0")
+    ///     .add_sample_name("sample1")
+    ///     .build();
+    ///
+    /// let record = vcf::Record::try_from_str("sq0\t1\t.\tA\tC\t.\tPASS\t.\tGT\t0/1\t1/1", &header)?;
+    /// let allele_stats = stats::compute(&record)?;
+    /// assert_eq!(allele_stats.allele_counts(), [1, 3]);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn allele_counts(&self) -> &[usize] {
+        &self.allele_counts
+    }
+
+    /// Returns the total number of called alleles (`AN`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, stats};
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .add_sample_name("sample0")
+    ///     .add_sample_name("sample1")
+    ///     .build();
+    ///
+    /// let record = vcf::Record::try_from_str("sq0\t1\t.\tA\tC\t.\tPASS\t.\tGT\t0/1\t1/.", &header)?;
+    /// let allele_stats = stats::compute(&record)?;
+    /// assert_eq!(allele_stats.allele_number(), 3);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn allele_number(&self) -> usize {
+        self.allele_counts.iter().sum()
+    }
+
+    /// Returns the frequency of each alternate allele (`AF`).
+    ///
+    /// This returns `None` if no alleles are called.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, stats};
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .add_sample_name("sample0")
+    ///     .add_sample_name("sample1")
+    ///     .build();
+    ///
+    /// let record = vcf::Record::try_from_str("sq0\t1\t.\tA\tC,G\t.\tPASS\t.\tGT\t0/1\t1/1", &header)?;
+    /// let allele_stats = stats::compute(&record)?;
+    /// assert_eq!(allele_stats.allele_frequencies(), Some(vec![0.75, 0.0]));
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn allele_frequencies(&self) -> Option<Vec<f64>> {
+        let allele_number = self.allele_number();
+
+        if allele_number == 0 {
+            return None;
+        }
+
+        let frequencies = self
+            .allele_counts
+            .iter()
+            .skip(1)
+            .map(|&count| count as f64 / allele_number as f64)
+            .collect();
+
+        Some(frequencies)
+    }
+
+    /// Returns the minor allele frequency (MAF).
+    ///
+    /// This is the frequency of the second most common allele, which, for biallelic records, is
+    /// the frequency of the less common allele. This returns `None` if no alleles are called.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, stats};
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .add_sample_name("sample0")
+    ///     .add_sample_name("sample1")
+    ///     .build();
+    ///
+    /// let record = vcf::Record::try_from_str("sq0\t1\t.\tA\tC\t.\tPASS\t.\tGT\t0/1\t1/1", &header)?;
+    /// let allele_stats = stats::compute(&record)?;
+    /// assert_eq!(allele_stats.minor_allele_frequency(), Some(0.25));
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn minor_allele_frequency(&self) -> Option<f64> {
+        let allele_number = self.allele_number();
+
+        if allele_number == 0 {
+            return None;
+        }
+
+        let mut counts = self.allele_counts.clone();
+        counts.sort_unstable_by(|a, b| b.cmp(a));
+
+        let count = counts.get(1).copied().unwrap_or(0);
+
+        Some(count as f64 / allele_number as f64)
+    }
+
+    /// Returns the number of samples.
+    pub fn sample_count(&self) -> usize {
+        self.sample_count
+    }
+
+    /// Returns the number of samples with a fully called genotype.
+    pub fn called_sample_count(&self) -> usize {
+        self.called_sample_count
+    }
+
+    /// Returns the fraction of samples with a fully called genotype.
+    ///
+    /// This returns `None` if there are no samples.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, stats};
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .add_sample_name("sample0")
+    ///     .add_sample_name("sample1")
+    ///     .build();
+    ///
+    /// let record = vcf::Record::try_from_str("sq0\t1\t.\tA\tC\t.\tPASS\t.\tGT\t0/1\t1/.", &header)?;
+    /// let allele_stats = stats::compute(&record)?;
+    /// assert_eq!(allele_stats.call_rate(), Some(0.5));
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn call_rate(&self) -> Option<f64> {
+        if self.sample_count == 0 {
+            None
+        } else {
+            Some(self.called_sample_count as f64 / self.sample_count as f64)
+        }
+    }
+
+    /// Returns the number of samples with a homozygous reference genotype.
+    pub fn hom_ref_count(&self) -> usize {
+        self.hom_ref_count
+    }
+
+    /// Returns the number of samples with a heterozygous genotype.
+    pub fn het_count(&self) -> usize {
+        self.het_count
+    }
+
+    /// Returns the number of samples with a homozygous alternate genotype.
+    pub fn hom_alt_count(&self) -> usize {
+        self.hom_alt_count
+    }
+
+    /// Returns the Hardy-Weinberg equilibrium exact test p-value.
+    ///
+    /// This is computed using the exact test of Wigginton et al. (2005) over fully called diploid
+    /// genotypes. This returns `None` if the record is not biallelic or has no called diploid
+    /// genotypes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, stats};
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .add_sample_name("sample0")
+    ///     .add_sample_name("sample1")
+    ///     .build();
+    ///
+    /// let record = vcf::Record::try_from_str("sq0\t1\t.\tA\tC\t.\tPASS\t.\tGT\t0/1\t0/1", &header)?;
+    /// let allele_stats = stats::compute(&record)?;
+    /// assert_eq!(allele_stats.hardy_weinberg_p_value(), Some(1.0));
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn hardy_weinberg_p_value(&self) -> Option<f64> {
+        self.hardy_weinberg_p_value
+    }
+
+    /// Writes the allele count (`AC`), total allele count (`AN`), and allele frequencies (`AF`)
+    /// to INFO fields.
+    ///
+    /// Existing values are replaced. If no alleles are called, the allele frequencies are
+    /// missing. If the record has no alternate alleles, only `AN` is written, and `AC` and `AF`
+    /// are removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, stats};
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .add_sample_name("sample0")
+    ///     .add_sample_name("sample1")
+    ///     .build();
+    ///
+    /// let mut record = vcf::Record::try_from_str("sq0\t1\t.\tA\tC\t.\tPASS\t.\tGT\t0/1\t1/1", &header)?;
+    ///
+    /// let allele_stats = stats::compute(&record)?;
+    /// allele_stats.update_info(record.info_mut())?;
+    ///
+    /// assert_eq!(record.info().to_string(), "AC=3;AN=4;AF=0.75");
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn update_info(&self, info: &mut Info) -> Result<(), num::TryFromIntError> {
+        let allele_number = i32::try_from(self.allele_number())?;
+
+        // `AC` and `AF` have one value per alternate allele, so they cannot be written without
+        // any.
+        if self.allele_counts.len() <= 1 {
+            info.as_mut().shift_remove(&key::ALLELE_COUNT);
+            info.insert(key::TOTAL_ALLELE_COUNT, Some(Value::Integer(allele_number)));
+            info.as_mut().shift_remove(&key::ALLELE_FREQUENCIES);
+            return Ok(());
+        }
+
+        let allele_counts = self
+            .allele_counts
+            .iter()
+            .skip(1)
+            .map(|&count| i32::try_from(count).map(Some))
+            .collect::<Result<_, _>>()?;
+
+        let allele_frequencies = match self.allele_frequencies() {
+            Some(frequencies) => frequencies.into_iter().map(|f| Some(f as f32)).collect(),
+            None => vec![None; self.allele_counts.len() - 1],
+        };
+
+        info.insert(key::ALLELE_COUNT, Some(Value::IntegerArray(allele_counts)));
+        info.insert(key::TOTAL_ALLELE_COUNT, Some(Value::Integer(allele_number)));
+        info.insert(
+            key::ALLELE_FREQUENCIES,
+            Some(Value::FloatArray(allele_frequencies)),
+        );
+
+        Ok(())
+    }
+}
+
+/// Computes allele statistics from the genotypes (`GT`) of a record.
+///
+/// Samples without a genotype are counted as missing.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_vcf::{self as vcf, stats};
+///
+/// let header = vcf::Header::builder()
+///     .add_sample_name("sample0")
+///     .add_sample_name("sample1")
+///     .add_sample_name("sample2")
+///     .build();
+///
+/// let record = vcf::Record::try_from_str(
+///     "sq0\t1\t.\tA\tC\t.\tPASS\t.\tGT\t0/0\t0/1\t./.",
+///     &header,
+/// )?;
+///
+/// let allele_stats = stats::compute(&record)?;
+///
+/// assert_eq!(allele_stats.allele_counts(), [3, 1]);
+/// assert_eq!(allele_stats.called_sample_count(), 2);
+/// assert_eq!(allele_stats.hom_ref_count(), 1);
+/// assert_eq!(allele_stats.het_count(), 1);
+/// assert_eq!(allele_stats.hom_alt_count(), 0);
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn compute(record: &Record) -> Result<AlleleStats, StatsError> {
+    let allele_count = record.alternate_bases().len() + 1;
+
+    let mut stats = AlleleStats {
+        allele_counts: vec![0; allele_count],
+        sample_count: record.genotypes().len(),
+        ..Default::default()
+    };
+
+    // Diploid genotype counts: (hom ref, het, hom alt).
+    let mut diploid_counts = (0, 0, 0);
+
+    for sample in record.genotypes().iter() {
+        let genotype = match sample.genotype() {
+            Some(result) => result.map_err(StatsError::InvalidGenotype)?,
+            None => continue,
+        };
+
+        for (i, count) in genotype.allele_counts().into_iter().enumerate() {
+            if count > 0 {
+                let dst = stats
+                    .allele_counts
+                    .get_mut(i)
+                    .ok_or(StatsError::InvalidAlleleIndex(i))?;
+
+                *dst += count;
+            }
+        }
+
+        if genotype.is_missing() || genotype.is_partially_missing() {
+            continue;
+        }
+
+        stats.called_sample_count += 1;
+
+        let is_diploid = genotype.ploidy() == 2;
+
+        if genotype.is_hom_ref() {
+            stats.hom_ref_count += 1;
+
+            if is_diploid {
+                diploid_counts.0 += 1;
+            }
+        } else if genotype.is_het() {
+            stats.het_count += 1;
+
+            if is_diploid {
+                diploid_counts.1 += 1;
+            }
+        } else if genotype.is_hom_alt() {
+            stats.hom_alt_count += 1;
+
+            if is_diploid {
+                diploid_counts.2 += 1;
+            }
+        }
+    }
+
+    let (hom_ref_count, het_count, hom_alt_count) = diploid_counts;
+
+    if allele_count == 2 && hom_ref_count + het_count + hom_alt_count > 0 {
+        stats.hardy_weinberg_p_value = Some(hardy_weinberg_exact_test(
+            het_count,
+            hom_ref_count,
+            hom_alt_count,
+        ));
+    }
+
+    Ok(stats)
+}
+
+// Wigginton JE, Cutler DJ, Abecasis GR. A note on exact tests of Hardy-Weinberg equilibrium. Am J
+// Hum Genet. 2005;76(5):887-893. doi:10.1086/429864
+fn hardy_weinberg_exact_test(obs_hets: usize, obs_hom1: usize, obs_hom2: usize) -> f64 {
+    let obs_homc = obs_hom1.max(obs_hom2);
+    let obs_homr = obs_hom1.min(obs_hom2);
+
+    let rare_copies = 2 * obs_homr + obs_hets;
+    let genotypes = obs_hets + obs_homc + obs_homr;
+
+    if genotypes == 0 {
+        return 1.0;
+    }
+
+    let mut het_probs = vec![0.0; rare_copies + 1];
+
+    // Start at the midpoint, with the same parity as the number of rare alleles.
+    let mut mid = rare_copies * (2 * genotypes - rare_copies) / (2 * genotypes);
+
+    if (rare_copies % 2) != (mid % 2) {
+        mid += 1;
+    }
+
+    het_probs[mid] = 1.0;
+    let mut sum = 1.0;
+
+    let mut curr_hets = mid;
+    let mut curr_homr = (rare_copies - mid) / 2;
+    let mut curr_homc = genotypes - curr_hets - curr_homr;
+
+    while curr_hets > 1 {
+        let p = het_probs[curr_hets] * (curr_hets * (curr_hets - 1)) as f64
+            / (4 * (curr_homr + 1) * (curr_homc + 1)) as f64;
+
+        het_probs[curr_hets - 2] = p;
+        sum += p;
+
+        curr_hets -= 2;
+        curr_homr += 1;
+        curr_homc += 1;
+    }
+
+    let mut curr_hets = mid;
+    let mut curr_homr = (rare_copies - mid) / 2;
+    let mut curr_homc = genotypes - curr_hets - curr_homr;
+
+    while curr_hets + 2 <= rare_copies {
+        let p = het_probs[curr_hets] * (4 * curr_homr * curr_homc) as f64
+            / ((curr_hets + 2) * (curr_hets + 1)) as f64;
+
+        het_probs[curr_hets + 2] = p;
+        sum += p;
+
+        curr_hets += 2;
+        curr_homr -= 1;
+        curr_homc -= 1;
+    }
+
+    let obs_prob = het_probs[obs_hets] / sum;
+
+    let p_value: f64 = het_probs
+        .iter()
+        .map(|p| p / sum)
+        .filter(|&p| p <= obs_prob)
+        .sum();
+
+    p_value.min(1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Header;
+
+    fn build_header(sample_count: usize) -> Header {
+        (0..sample_count)
+            .fold(Header::builder(), |builder, i| {
+                builder.add_sample_name(format!("sample{i}"))
+            })
+            .build()
+    }
+
+    #[test]
+    fn test_compute() -> Result<(), Box<dyn std::error::Error>> {
+        let header = build_header(5);
+
+        let record = Record::try_from_str(
+            "sq0\t1\t.\tA\tC,G\t.\tPASS\t.\tGT\t0/0\t0|2\t2/2\t1/.\t1",
+            &header,
+        )?;
+
+        let allele_stats = compute(&record)?;
+
+        assert_eq!(allele_stats.allele_counts(), [3, 2, 3]);
+        assert_eq!(allele_stats.allele_number(), 8);
+        assert_eq!(allele_stats.allele_frequencies(), Some(vec![0.25, 0.375]));
+        assert_eq!(allele_stats.minor_allele_frequency(), Some(0.375));
+        assert_eq!(allele_stats.sample_count(), 5);
+        assert_eq!(allele_stats.called_sample_count(), 4);
+        assert_eq!(allele_stats.call_rate(), Some(0.8));
+        assert_eq!(allele_stats.hom_ref_count(), 1);
+        assert_eq!(allele_stats.het_count(), 1);
+        assert_eq!(allele_stats.hom_alt_count(), 2);
+        assert!(allele_stats.hardy_weinberg_p_value().is_none());
+
+        let record =
+            Record::try_from_str("sq0\t1\t.\tA\tC\t.\tPASS\t.\tGT\t0/2\t.\t.\t.\t.", &header)?;
+
+        assert_eq!(compute(&record), Err(StatsError::InvalidAlleleIndex(2)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_compute_with_no_samples() -> Result<(), Box<dyn std::error::Error>> {
+        let header = build_header(0);
+        let record = Record::try_from_str("sq0\t1\t.\tA\tC\t.\tPASS\t.", &header)?;
+
+        let allele_stats = compute(&record)?;
+
+        assert_eq!(allele_stats.allele_counts(), [0, 0]);
+        assert!(allele_stats.allele_frequencies().is_none());
+        assert!(allele_stats.minor_allele_frequency().is_none());
+        assert!(allele_stats.call_rate().is_none());
+        assert!(allele_stats.hardy_weinberg_p_value().is_none());
+
+        let mut info = Info::default();
+        allele_stats.update_info(&mut info)?;
+        assert_eq!(info.to_string(), "AC=0;AN=0;AF=.");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_update_info_with_no_alternate_bases() -> Result<(), Box<dyn std::error::Error>> {
+        let header = build_header(2);
+        let mut record = Record::try_from_str(
+            "sq0\t1\t.\tA\t.\t.\tPASS\tAC=1;AN=2;AF=0.5;DP=8\tGT\t0/0\t0/.",
+            &header,
+        )?;
+
+        let allele_stats = compute(&record)?;
+        allele_stats.update_info(record.info_mut())?;
+        assert_eq!(record.info().to_string(), "AN=3;DP=8");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_hardy_weinberg_exact_test() {
+        fn assert_approx_eq(actual: f64, expected: f64) {
+            assert!((actual - expected).abs() < 1e-9, "{actual} != {expected}");
+        }
+
+        assert_approx_eq(hardy_weinberg_exact_test(0, 0, 0), 1.0);
+        assert_approx_eq(hardy_weinberg_exact_test(2, 0, 0), 1.0);
+        assert_approx_eq(hardy_weinberg_exact_test(0, 1, 1), 1.0 / 3.0);
+        assert_approx_eq(hardy_weinberg_exact_test(0, 0, 5), 1.0);
+
+        // 100 samples: P(hets = 0 | 10 rare alleles) is small.
+        assert!(hardy_weinberg_exact_test(0, 95, 5) < 1e-6);
+        assert_approx_eq(hardy_weinberg_exact_test(10, 90, 0), 1.0);
+    }
+}
@@ -0,0 +1,9 @@
+//! VCF variant operations.
+
+pub mod key;
+pub mod normalize;
+
+pub use self::{
+    key::{dedup, diff, VariantKey},
+    normalize::normalize,
+};
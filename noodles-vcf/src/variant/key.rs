@@ -0,0 +1,420 @@
+//! VCF variant key.
+
+use std::{
+    collections::{BTreeSet, HashSet},
+    fmt,
+};
+
+use crate::{
+    record::{alternate_bases::Allele, Position},
+    Record,
+};
+
+/// A VCF variant key.
+///
+/// A variant key identifies a single alternate allele at a locus: the chromosome, position,
+/// reference bases, and alternate allele. Keys compare equal when they represent the same variant
+/// in its minimal representation, i.e., bases shared by both alleles are trimmed, and bases are
+/// uppercase. Symbolic alleles, breakends, and overlapping deletions are not trimmed.
+///
+/// This does not left-align variants. Use [`super::normalize`] on records first to also compare
+/// variants that are represented at different positions.
+///
+/// Keys are ordered by chromosome name (lexicographically), position, reference bases, and
+/// alternate allele.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct VariantKey {
+    chromosome: String,
+    position: usize,
+    reference_bases: String,
+    alternate_allele: String,
+}
+
+impl VariantKey {
+    /// Creates a variant key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{record::Position, variant::VariantKey};
+    ///
+    /// let key = VariantKey::new("sq0", Position::from(8), "ACGT", "AGT");
+    ///
+    /// assert_eq!(key.position(), Position::from(8));
+    /// assert_eq!(key.reference_bases(), "AC");
+    /// assert_eq!(key.alternate_allele(), "A");
+    /// ```
+    pub fn new<C, R, A>(
+        chromosome: C,
+        position: Position,
+        reference_bases: R,
+        alternate_allele: A,
+    ) -> Self
+    where
+        C: Into<String>,
+        R: Into<String>,
+        A: Into<String>,
+    {
+        let mut reference_bases = reference_bases.into();
+        let mut alternate_allele = alternate_allele.into();
+        let mut position = usize::from(position);
+
+        reference_bases.make_ascii_uppercase();
+
+        if is_bases(&alternate_allele) {
+            alternate_allele.make_ascii_uppercase();
+            position = trim(&mut reference_bases, &mut alternate_allele, position);
+        }
+
+        Self {
+            chromosome: chromosome.into(),
+            position,
+            reference_bases,
+            alternate_allele,
+        }
+    }
+
+    /// Returns the variant keys of a record.
+    ///
+    /// There is one key per alternate allele. A record with no alternate alleles has no keys.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, record::Position, variant::VariantKey};
+    ///
+    /// let record = vcf::Record::builder()
+    ///     .set_chromosome("sq0".parse()?)
+    ///     .set_position(Position::from(8))
+    ///     .set_reference_bases("A".parse()?)
+    ///     .set_alternate_bases("C,G".parse()?)
+    ///     .build()?;
+    ///
+    /// let keys = VariantKey::from_record(&record);
+    ///
+    /// assert_eq!(keys, [
+    ///     VariantKey::new("sq0", Position::from(8), "A", "C"),
+    ///     VariantKey::new("sq0", Position::from(8), "A", "G"),
+    /// ]);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn from_record(record: &Record) -> Vec<Self> {
+        let chromosome = record.chromosome().to_string();
+        let reference_bases = record.reference_bases().to_string();
+
+        record
+            .alternate_bases()
+            .iter()
+            .map(|allele| {
+                Self::new(
+                    chromosome.as_str(),
+                    record.position(),
+                    reference_bases.as_str(),
+                    allele.to_string(),
+                )
+            })
+            .collect()
+    }
+
+    /// Returns the chromosome.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{record::Position, variant::VariantKey};
+    /// let key = VariantKey::new("sq0", Position::from(8), "A", "C");
+    /// assert_eq!(key.chromosome(), "sq0");
+    /// ```
+    pub fn chromosome(&self) -> &str {
+        &self.chromosome
+    }
+
+    /// Returns the position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{record::Position, variant::VariantKey};
+    /// let key = VariantKey::new("sq0", Position::from(8), "AC", "GC");
+    /// assert_eq!(key.position(), Position::from(8));
+    /// ```
+    pub fn position(&self) -> Position {
+        Position::from(self.position)
+    }
+
+    /// Returns the reference bases.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{record::Position, variant::VariantKey};
+    /// let key = VariantKey::new("sq0", Position::from(8), "ac", "gc");
+    /// assert_eq!(key.reference_bases(), "A");
+    /// ```
+    pub fn reference_bases(&self) -> &str {
+        &self.reference_bases
+    }
+
+    /// Returns the alternate allele.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{record::Position, variant::VariantKey};
+    /// let key = VariantKey::new("sq0", Position::from(8), "ac", "gc");
+    /// assert_eq!(key.alternate_allele(), "G");
+    /// ```
+    pub fn alternate_allele(&self) -> &str {
+        &self.alternate_allele
+    }
+}
+
+impl fmt::Display for VariantKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}:{}:{}:{}",
+            self.chromosome, self.position, self.reference_bases, self.alternate_allele
+        )
+    }
+}
+
+// Returns whether the allele is a list of bases, i.e., not symbolic, a breakend, or an overlapping
+// deletion.
+fn is_bases(s: &str) -> bool {
+    s.parse::<Allele>()
+        .map(|allele| matches!(allele, Allele::Bases(_)))
+        .unwrap_or(false)
+}
+
+// Trims bases shared by both alleles, keeping at least one base in each. Returns the new position.
+fn trim(reference_bases: &mut String, alternate_allele: &mut String, mut position: usize) -> usize {
+    while reference_bases.len() > 1
+        && alternate_allele.len() > 1
+        && reference_bases.as_bytes().last() == alternate_allele.as_bytes().last()
+    {
+        reference_bases.pop();
+        alternate_allele.pop();
+    }
+
+    let prefix_len = reference_bases
+        .bytes()
+        .zip(alternate_allele.bytes())
+        .take(
+            reference_bases
+                .len()
+                .min(alternate_allele.len())
+                .saturating_sub(1),
+        )
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    if prefix_len > 0 {
+        reference_bases.drain(..prefix_len);
+        alternate_allele.drain(..prefix_len);
+        position += prefix_len;
+    }
+
+    position
+}
+
+/// Removes records with duplicate variants, keeping the first occurrence.
+///
+/// A record is a duplicate if all of its variant keys are keys of earlier records. Records with no
+/// alternate alleles are compared by their chromosome, position, and reference bases.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_vcf::{self as vcf, record::Position, variant};
+///
+/// let builder = || {
+///     vcf::Record::builder()
+///         .set_chromosome("sq0".parse().unwrap())
+///         .set_position(Position::from(8))
+/// };
+///
+/// let mut records = vec![
+///     builder().set_reference_bases("A".parse()?).set_alternate_bases("C".parse()?).build()?,
+///     builder().set_reference_bases("AT".parse()?).set_alternate_bases("CT".parse()?).build()?,
+///     builder().set_reference_bases("A".parse()?).set_alternate_bases("G".parse()?).build()?,
+/// ];
+///
+/// variant::dedup(&mut records);
+///
+/// assert_eq!(records.len(), 2);
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn dedup(records: &mut Vec<Record>) {
+    let mut seen = HashSet::new();
+
+    records.retain(|record| {
+        let keys = record_keys(record);
+        let is_duplicate = keys.iter().all(|key| seen.contains(key));
+        seen.extend(keys);
+        !is_duplicate
+    });
+}
+
+// Returns the variant keys of a record, or if the record has no alternate alleles, a key with a
+// missing (`.`) alternate allele.
+fn record_keys(record: &Record) -> Vec<VariantKey> {
+    const MISSING_ALLELE: &str = ".";
+
+    let keys = VariantKey::from_record(record);
+
+    if keys.is_empty() {
+        vec![VariantKey::new(
+            record.chromosome().to_string(),
+            record.position(),
+            record.reference_bases().to_string(),
+            MISSING_ALLELE,
+        )]
+    } else {
+        keys
+    }
+}
+
+/// The difference of variants of two sets of records.
+///
+/// This is created by calling [`diff`].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Diff {
+    /// Variants only in the first set of records.
+    pub only_in_a: BTreeSet<VariantKey>,
+    /// Variants only in the second set of records.
+    pub only_in_b: BTreeSet<VariantKey>,
+    /// Variants in both sets of records.
+    pub shared: BTreeSet<VariantKey>,
+}
+
+/// Compares the variants of two sets of records.
+///
+/// Variants are compared by their keys (see [`VariantKey`]). Records with no alternate alleles
+/// are ignored.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_vcf::{self as vcf, record::Position, variant::{self, VariantKey}};
+///
+/// let builder = |alt: &str| {
+///     vcf::Record::builder()
+///         .set_chromosome("sq0".parse().unwrap())
+///         .set_position(Position::from(8))
+///         .set_reference_bases("A".parse().unwrap())
+///         .set_alternate_bases(alt.parse().unwrap())
+///         .build()
+/// };
+///
+/// let a = [builder("C,G")?];
+/// let b = [builder("G")?, builder("T")?];
+///
+/// let diff = variant::diff(&a, &b);
+///
+/// assert!(diff.only_in_a.contains(&VariantKey::new("sq0", Position::from(8), "A", "C")));
+/// assert!(diff.only_in_b.contains(&VariantKey::new("sq0", Position::from(8), "A", "T")));
+/// assert!(diff.shared.contains(&VariantKey::new("sq0", Position::from(8), "A", "G")));
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn diff(a: &[Record], b: &[Record]) -> Diff {
+    let a: BTreeSet<_> = a.iter().flat_map(VariantKey::from_record).collect();
+    let b: BTreeSet<_> = b.iter().flat_map(VariantKey::from_record).collect();
+
+    Diff {
+        only_in_a: a.difference(&b).cloned().collect(),
+        only_in_b: b.difference(&a).cloned().collect(),
+        shared: a.intersection(&b).cloned().collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        let key = VariantKey::new("sq0", Position::from(8), "ACGT", "ACGT");
+        assert_eq!(
+            (
+                key.position(),
+                key.reference_bases(),
+                key.alternate_allele()
+            ),
+            (Position::from(8), "A", "A")
+        );
+
+        let key = VariantKey::new("sq0", Position::from(8), "CAT", "CGT");
+        assert_eq!(
+            (
+                key.position(),
+                key.reference_bases(),
+                key.alternate_allele()
+            ),
+            (Position::from(9), "A", "G")
+        );
+
+        let key = VariantKey::new("sq0", Position::from(8), "CA", "CAAA");
+        assert_eq!(
+            (
+                key.position(),
+                key.reference_bases(),
+                key.alternate_allele()
+            ),
+            (Position::from(8), "C", "CAA")
+        );
+
+        let key = VariantKey::new("sq0", Position::from(8), "ac", "<del>");
+        assert_eq!(
+            (
+                key.position(),
+                key.reference_bases(),
+                key.alternate_allele()
+            ),
+            (Position::from(8), "AC", "<del>")
+        );
+
+        assert_eq!(
+            VariantKey::new("sq0", Position::from(8), "ACGT", "AGT"),
+            VariantKey::new("sq0", Position::from(8), "AC", "A")
+        );
+    }
+
+    #[test]
+    fn test_fmt() {
+        let key = VariantKey::new("sq0", Position::from(8), "A", "C");
+        assert_eq!(key.to_string(), "sq0:8:A:C");
+    }
+
+    #[test]
+    fn test_dedup() -> Result<(), Box<dyn std::error::Error>> {
+        let record = |pos: usize, r#ref: &str, alt: &str| {
+            Record::builder()
+                .set_chromosome("sq0".parse().unwrap())
+                .set_position(Position::from(pos))
+                .set_reference_bases(r#ref.parse().unwrap())
+                .set_alternate_bases(alt.parse().unwrap())
+                .build()
+        };
+
+        let mut records = vec![
+            record(8, "A", "C,G")?,
+            record(8, "A", "G")?,
+            record(8, "A", "G,T")?,
+            record(13, "A", ".")?,
+            record(13, "A", ".")?,
+        ];
+
+        dedup(&mut records);
+
+        let actual: Vec<_> = records.iter().map(|r| r.to_string()).collect();
+        let expected = [
+            "sq0\t8\t.\tA\tC,G\t.\t.\t.",
+            "sq0\t8\t.\tA\tG,T\t.\t.\t.",
+            "sq0\t13\t.\tA\t.\t.\t.\t.",
+        ];
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,310 @@
+//! VCF variant normalization.
+
+use std::{error, fmt};
+
+use crate::{
+    record::{alternate_bases::Allele, reference_bases::Base, Position, ReferenceBases},
+    Record,
+};
+
+/// An error returned when a VCF variant fails to normalize.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum NormalizeError {
+    /// The record position is invalid for the reference sequence.
+    InvalidPosition,
+    /// A reference sequence base is invalid.
+    InvalidReferenceBase,
+    /// The reference bases do not match the reference sequence.
+    ReferenceMismatch,
+}
+
+impl error::Error for NormalizeError {}
+
+impl fmt::Display for NormalizeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidPosition => f.write_str("invalid position"),
+            Self::InvalidReferenceBase => f.write_str("invalid reference base"),
+            Self::ReferenceMismatch => f.write_str("reference mismatch"),
+        }
+    }
+}
+
+/// Normalizes a VCF variant.
+///
+/// This trims bases shared by all alleles and left-aligns the variant, i.e., moves it to the
+/// leftmost position at which it represents the same haplotype (Tan et al., 2015). The record
+/// position, reference bases, and alternate bases are updated in place.
+///
+/// The reference sequence is the complete sequence of the record chromosome, e.g., as read from
+/// a FASTA repository. The first base is at position 1.
+///
+/// Records with alternate alleles that are not a list of bases (symbolic alleles, breakends, and
+/// overlapping deletions) and records with an alternate allele equal to the reference allele are
+/// left unchanged.
+///
+/// # Errors
+///
+/// An error is returned if the reference bases do not match the reference sequence.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_vcf::{self as vcf, record::Position, variant};
+///
+/// // 1234567
+/// // GGCACAT
+/// let reference_sequence = b"GGCACAT";
+///
+/// let mut record = vcf::Record::builder()
+///     .set_chromosome("sq0".parse()?)
+///     .set_position(Position::from(4))
+///     .set_reference_bases("ACA".parse()?)
+///     .set_alternate_bases("A".parse()?)
+///     .build()?;
+///
+/// variant::normalize(&mut record, reference_sequence)?;
+///
+/// assert_eq!(record.position(), Position::from(2));
+/// assert_eq!(record.reference_bases().to_string(), "GCA");
+/// assert_eq!(record.alternate_bases().to_string(), "G");
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn normalize(record: &mut Record, reference_sequence: &[u8]) -> Result<(), NormalizeError> {
+    let position = usize::from(record.position());
+
+    let reference_bases = get_bases(reference_sequence, position, record.reference_bases().len())?;
+
+    if reference_bases != **record.reference_bases() {
+        return Err(NormalizeError::ReferenceMismatch);
+    }
+
+    let mut alleles = vec![reference_bases];
+
+    for allele in record.alternate_bases().iter() {
+        match allele {
+            Allele::Bases(bases) if *bases != alleles[0] => alleles.push(bases.clone()),
+            _ => return Ok(()),
+        }
+    }
+
+    if alleles.len() < 2 {
+        return Ok(());
+    }
+
+    let position = normalize_alleles(&mut alleles, reference_sequence, position)?;
+
+    let mut alleles = alleles.into_iter();
+
+    // Normalization never empties an allele.
+    if let Some(Ok(reference_bases)) = alleles.next().map(ReferenceBases::try_from) {
+        *record.reference_bases_mut() = reference_bases;
+    }
+
+    for (dst, src) in record.alternate_bases_mut().iter_mut().zip(alleles) {
+        *dst = Allele::Bases(src);
+    }
+
+    *record.position_mut() = Position::from(position);
+
+    Ok(())
+}
+
+// Returns the new position.
+fn normalize_alleles(
+    alleles: &mut [Vec<Base>],
+    reference_sequence: &[u8],
+    mut position: usize,
+) -> Result<usize, NormalizeError> {
+    loop {
+        let mut is_changed = false;
+
+        if shares_last_base(alleles) {
+            if alleles.iter().any(|allele| allele.len() == 1) {
+                if position <= 1 {
+                    break;
+                }
+
+                position -= 1;
+                let base = get_base(reference_sequence, position)?;
+
+                for allele in alleles.iter_mut() {
+                    allele.insert(0, base);
+                }
+            }
+
+            for allele in alleles.iter_mut() {
+                allele.pop();
+            }
+
+            is_changed = true;
+        }
+
+        if !is_changed {
+            break;
+        }
+    }
+
+    while alleles.iter().all(|allele| allele.len() >= 2) && shares_first_base(alleles) {
+        for allele in alleles.iter_mut() {
+            allele.remove(0);
+        }
+
+        position += 1;
+    }
+
+    Ok(position)
+}
+
+fn shares_last_base(alleles: &[Vec<Base>]) -> bool {
+    let mut last_bases = alleles.iter().map(|allele| allele.last());
+
+    match last_bases.next() {
+        Some(Some(base)) => last_bases.all(|b| b == Some(base)),
+        _ => false,
+    }
+}
+
+fn shares_first_base(alleles: &[Vec<Base>]) -> bool {
+    let mut first_bases = alleles.iter().map(|allele| allele.first());
+
+    match first_bases.next() {
+        Some(Some(base)) => first_bases.all(|b| b == Some(base)),
+        _ => false,
+    }
+}
+
+fn get_base(reference_sequence: &[u8], position: usize) -> Result<Base, NormalizeError> {
+    let i = position
+        .checked_sub(1)
+        .ok_or(NormalizeError::InvalidPosition)?;
+
+    let b = reference_sequence
+        .get(i)
+        .ok_or(NormalizeError::InvalidPosition)?;
+
+    Base::try_from(char::from(b.to_ascii_uppercase()))
+        .map_err(|_| NormalizeError::InvalidReferenceBase)
+}
+
+fn get_bases(
+    reference_sequence: &[u8],
+    position: usize,
+    len: usize,
+) -> Result<Vec<Base>, NormalizeError> {
+    (position..position + len)
+        .map(|p| get_base(reference_sequence, p))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_record(
+        position: usize,
+        reference_bases: &str,
+        alternate_bases: &str,
+    ) -> Result<Record, Box<dyn std::error::Error>> {
+        Record::builder()
+            .set_chromosome("sq0".parse()?)
+            .set_position(Position::from(position))
+            .set_reference_bases(reference_bases.parse()?)
+            .set_alternate_bases(alternate_bases.parse()?)
+            .build()
+            .map_err(|e| e.into())
+    }
+
+    fn assert_normalized(
+        reference_sequence: &[u8],
+        (position, reference_bases, alternate_bases): (usize, &str, &str),
+        (expected_position, expected_reference_bases, expected_alternate_bases): (
+            usize,
+            &str,
+            &str,
+        ),
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let mut record = build_record(position, reference_bases, alternate_bases)?;
+        normalize(&mut record, reference_sequence)?;
+
+        assert_eq!(
+            (
+                usize::from(record.position()),
+                record.reference_bases().to_string(),
+                record.alternate_bases().to_string()
+            ),
+            (
+                expected_position,
+                String::from(expected_reference_bases),
+                String::from(expected_alternate_bases)
+            )
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_normalize() -> Result<(), Box<dyn std::error::Error>> {
+        //          1111
+        // 1234567890123
+        // GGGCACACACAGT
+        let reference_sequence = b"GGGCACACACAGT";
+
+        // already normalized SNV
+        assert_normalized(reference_sequence, (4, "C", "T"), (4, "C", "T"))?;
+
+        // right-trimmed MNV
+        assert_normalized(reference_sequence, (4, "CA", "TA"), (4, "C", "T"))?;
+
+        // left-trimmed MNV
+        assert_normalized(reference_sequence, (4, "CA", "CG"), (5, "A", "G"))?;
+
+        // left-aligned deletion of a repeat unit
+        assert_normalized(reference_sequence, (9, "ACA", "A"), (3, "GCA", "G"))?;
+
+        // left-aligned insertion of a repeat unit
+        assert_normalized(reference_sequence, (11, "A", "ACA"), (3, "G", "GCA"))?;
+
+        // multiallelic
+        assert_normalized(
+            reference_sequence,
+            (9, "ACAG", "AG,ACACAG"),
+            (3, "GCA", "G,GCACA"),
+        )?;
+
+        // deletion at the start of the reference sequence
+        assert_normalized(reference_sequence, (1, "GG", "G"), (1, "GG", "G"))?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_normalize_with_unnormalizable_alleles() -> Result<(), Box<dyn std::error::Error>> {
+        let reference_sequence = b"GGGCACACACAGT";
+
+        assert_normalized(reference_sequence, (4, "CA", "<DEL>"), (4, "CA", "<DEL>"))?;
+        assert_normalized(reference_sequence, (4, "CA", "CA"), (4, "CA", "CA"))?;
+        assert_normalized(reference_sequence, (4, "CA", "C,*"), (4, "CA", "C,*"))?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_normalize_with_reference_mismatch() -> Result<(), Box<dyn std::error::Error>> {
+        let reference_sequence = b"GGGCACACACAGT";
+
+        let mut record = build_record(4, "TA", "T")?;
+        assert_eq!(
+            normalize(&mut record, reference_sequence),
+            Err(NormalizeError::ReferenceMismatch)
+        );
+
+        let mut record = build_record(13, "TA", "T")?;
+        assert_eq!(
+            normalize(&mut record, reference_sequence),
+            Err(NormalizeError::InvalidPosition)
+        );
+
+        Ok(())
+    }
+}
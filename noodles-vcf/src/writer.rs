@@ -1,6 +1,18 @@
+//! VCF writer.
+
+mod builder;
+mod float_format;
+pub(crate) mod record;
+
+pub use self::{builder::Builder, float_format::FloatFormat};
+
 use std::io::{self, Write};
 
-use super::{Header, Record};
+use super::{
+    header::FileFormat,
+    record::{info, Filters},
+    Header, Record,
+};
 
 /// A VCF writer.
 ///
@@ -42,6 +54,12 @@ use super::{Header, Record};
 #[derive(Debug)]
 pub struct Writer<W> {
     inner: W,
+    validate_cardinality: bool,
+    validate_filters: bool,
+    validate_info_whitespace: bool,
+    float_format: FloatFormat,
+    file_format: FileFormat,
+    header: Option<Header>,
 }
 
 impl<W> Writer<W>
@@ -57,7 +75,7 @@ where
     /// let writer = vcf::Writer::new(Vec::new());
     /// ```
     pub fn new(inner: W) -> Self {
-        Self { inner }
+        Builder::default().build_with_writer(inner)
     }
 
     /// Returns a reference to the underlying writer.
@@ -114,6 +132,12 @@ where
     /// # Ok::<(), io::Error>(())
     /// ```
     pub fn write_header(&mut self, header: &Header) -> io::Result<()> {
+        self.file_format = header.file_format();
+
+        if self.validate_cardinality || self.validate_filters {
+            self.header = Some(header.clone());
+        }
+
         write!(self.inner, "{header}")
     }
 
@@ -134,11 +158,94 @@ where
     /// writer.write_record(&record)?;
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
+    ///
+    /// # Errors
+    ///
+    /// If cardinality validation is enabled (see [`Builder::set_validate_cardinality`]), an error
+    /// of kind [`io::ErrorKind::InvalidInput`] is returned when a record field value has an
+    /// invalid number of values for the header written by [`Self::write_header`].
+    ///
+    /// If filter validation is enabled (see [`Builder::set_validate_filters`]), an error of kind
+    /// [`io::ErrorKind::InvalidInput`] is returned when a record has a failing filter that is not
+    /// defined in the header written by [`Self::write_header`].
+    ///
+    /// If INFO whitespace validation is enabled (see [`Builder::set_validate_info_whitespace`]),
+    /// an error of kind [`io::ErrorKind::InvalidInput`] is returned when an INFO field value
+    /// contains a space character and the file format of the header written by
+    /// [`Self::write_header`] is < VCF 4.4.
     pub fn write_record(&mut self, record: &Record) -> io::Result<()> {
-        writeln!(self.inner, "{record}")
+        if self.validate_info_whitespace {
+            validate_info_whitespace(self.file_format, record)?;
+        }
+
+        if let Some(header) = &self.header {
+            if self.validate_cardinality {
+                record
+                    .validate_cardinality(header)
+                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+            }
+
+            if self.validate_filters {
+                validate_filters(header, record)?;
+            }
+        }
+
+        let formatted_record = record::FormattedRecord {
+            record,
+            float_format: &self.float_format,
+            file_format: self.file_format,
+        };
+
+        writeln!(self.inner, "{formatted_record}")
     }
 }
 
+// § 1.6.1 Fixed fields (VCFv4.3 2022-08-05): "(String, no whitespace, semicolons, or
+// equals-signs permitted...)". Space characters are allowed as of VCF 4.4.
+fn validate_info_whitespace(file_format: FileFormat, record: &Record) -> io::Result<()> {
+    const SPACE: char = ' ';
+
+    if file_format >= FileFormat::new(4, 4) {
+        return Ok(());
+    }
+
+    for (key, value) in record.info().keys().zip(record.info().values()) {
+        let has_space = match value {
+            Some(info::field::Value::Character(c)) => *c == SPACE,
+            Some(info::field::Value::String(s)) => s.contains(SPACE),
+            Some(info::field::Value::CharacterArray(values)) => values.contains(&Some(SPACE)),
+            Some(info::field::Value::StringArray(values)) => {
+                values.iter().flatten().any(|value| value.contains(SPACE))
+            }
+            _ => false,
+        };
+
+        if has_space {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("INFO field value contains a space: {key} (file format {file_format})"),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+fn validate_filters(header: &Header, record: &Record) -> io::Result<()> {
+    if let Some(Filters::Fail(ids)) = record.filters() {
+        for id in ids {
+            if !header.filters().contains_key(id) {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("undefined filter: {id}"),
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -216,4 +323,117 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_write_record_with_cardinality_validation() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::header::{
+            info::key,
+            record::value::{map::Info, Map},
+        };
+
+        let header = Header::builder()
+            .add_info(key::ALLELE_COUNT, Map::<Info>::from(&key::ALLELE_COUNT))
+            .build();
+
+        let record = Record::try_from_str("sq0\t1\t.\tA\tC,G\t.\tPASS\tAC=1", &header)?;
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_header(&header)?;
+        writer.write_record(&record)?;
+
+        let mut writer = Builder::default()
+            .set_validate_cardinality(true)
+            .build_with_writer(Vec::new());
+        writer.write_header(&header)?;
+
+        assert!(matches!(
+            writer.write_record(&record),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        let record = Record::try_from_str("sq0\t1\t.\tA\tC,G\t.\tPASS\tAC=1,2", &header)?;
+        writer.write_record(&record)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_record_with_filter_validation() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::header::record::value::{map::Filter, Map};
+
+        let header = Header::builder()
+            .add_filter("q10", Map::<Filter>::new("Quality below 10"))
+            .build();
+
+        let mut writer = Builder::default()
+            .set_validate_filters(true)
+            .build_with_writer(Vec::new());
+        writer.write_header(&header)?;
+
+        let record = Record::try_from_str("sq0\t1\t.\tA\t.\t.\tPASS\t.", &header)?;
+        writer.write_record(&record)?;
+
+        let record = Record::try_from_str("sq0\t1\t.\tA\t.\t.\tq10\t.", &header)?;
+        writer.write_record(&record)?;
+
+        let record = Record::try_from_str("sq0\t1\t.\tA\t.\t.\tq10;s50\t.", &header)?;
+        assert!(matches!(
+            writer.write_record(&record),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_record_with_info_whitespace() -> Result<(), Box<dyn std::error::Error>> {
+        let s = "sq0\t1\t.\tA\t.\t.\tPASS\tNOTE=noodles vcf";
+
+        let header = Header::builder()
+            .set_file_format(FileFormat::new(4, 3))
+            .build();
+        let record = Record::try_from_str(s, &header)?;
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_header(&header)?;
+        writer.write_record(&record)?;
+
+        let mut writer = Builder::default()
+            .set_validate_info_whitespace(true)
+            .build_with_writer(Vec::new());
+        writer.write_header(&header)?;
+
+        assert!(matches!(
+            writer.write_record(&record),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        let header = Header::builder()
+            .set_file_format(FileFormat::new(4, 4))
+            .build();
+        let record = Record::try_from_str(s, &header)?;
+
+        let mut writer = Builder::default()
+            .set_validate_info_whitespace(true)
+            .build_with_writer(Vec::new());
+        writer.write_header(&header)?;
+        writer.write_record(&record)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_record_with_float_format() -> Result<(), Box<dyn std::error::Error>> {
+        let record =
+            Record::try_from_str("sq0\t1\t.\tA\t.\t13.333333\tPASS\t.", &Header::default())?;
+
+        let mut writer = Builder::default()
+            .set_float_format(FloatFormat::default().set_precision(Some(3)))
+            .build_with_writer(Vec::new());
+        writer.write_record(&record)?;
+
+        assert_eq!(writer.get_ref(), b"sq0\t1\t.\tA\t.\t13.3\tPASS\t.\n");
+
+        Ok(())
+    }
 }
@@ -0,0 +1,115 @@
+use std::io::Write;
+
+use super::{FloatFormat, Writer};
+use crate::header::FileFormat;
+
+/// A VCF writer builder.
+#[derive(Debug, Default)]
+pub struct Builder {
+    validate_cardinality: bool,
+    validate_filters: bool,
+    validate_info_whitespace: bool,
+    float_format: FloatFormat,
+}
+
+impl Builder {
+    /// Sets whether to validate the cardinality of record field values.
+    ///
+    /// When enabled, [`Writer::write_record`] checks that each INFO and genotype field value has
+    /// the number of values declared in the header written by [`Writer::write_header`], given the
+    /// record's alternate alleles and sample ploidy. See [`crate::Record::validate_cardinality`].
+    ///
+    /// By default, this is disabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf as vcf;
+    /// let builder = vcf::writer::Builder::default().set_validate_cardinality(true);
+    /// ```
+    pub fn set_validate_cardinality(mut self, validate_cardinality: bool) -> Self {
+        self.validate_cardinality = validate_cardinality;
+        self
+    }
+
+    /// Sets whether to validate record filters.
+    ///
+    /// When enabled, [`Writer::write_record`] checks that each failing filter of a record is
+    /// defined in the `FILTER` records of the header written by [`Writer::write_header`]. `PASS`
+    /// is always valid.
+    ///
+    /// By default, this is disabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf as vcf;
+    /// let builder = vcf::writer::Builder::default().set_validate_filters(true);
+    /// ```
+    pub fn set_validate_filters(mut self, validate_filters: bool) -> Self {
+        self.validate_filters = validate_filters;
+        self
+    }
+
+    /// Sets whether to validate whitespace in INFO field values.
+    ///
+    /// When enabled, [`Writer::write_record`] checks that no INFO field value contains a space
+    /// character if the file format of the header written by [`Writer::write_header`] is < VCF
+    /// 4.4. Spaces are allowed as of VCF 4.4.
+    ///
+    /// By default, this is disabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf as vcf;
+    /// let builder = vcf::writer::Builder::default().set_validate_info_whitespace(true);
+    /// ```
+    pub fn set_validate_info_whitespace(mut self, validate_info_whitespace: bool) -> Self {
+        self.validate_info_whitespace = validate_info_whitespace;
+        self
+    }
+
+    /// Sets the float format.
+    ///
+    /// This controls how `QUAL` and floating-point INFO and genotype field values are written.
+    ///
+    /// By default, this is [`FloatFormat::default`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, writer::FloatFormat};
+    ///
+    /// let float_format = FloatFormat::default().set_precision(Some(6));
+    /// let builder = vcf::writer::Builder::default().set_float_format(float_format);
+    /// ```
+    pub fn set_float_format(mut self, float_format: FloatFormat) -> Self {
+        self.float_format = float_format;
+        self
+    }
+
+    /// Builds a VCF writer from a writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_vcf as vcf;
+    /// let writer = vcf::writer::Builder::default().build_with_writer(io::sink());
+    /// ```
+    pub fn build_with_writer<W>(self, writer: W) -> Writer<W>
+    where
+        W: Write,
+    {
+        Writer {
+            inner: writer,
+            validate_cardinality: self.validate_cardinality,
+            validate_filters: self.validate_filters,
+            validate_info_whitespace: self.validate_info_whitespace,
+            float_format: self.float_format,
+            file_format: FileFormat::default(),
+            header: None,
+        }
+    }
+}
@@ -0,0 +1,210 @@
+use std::fmt::{self, Write};
+
+/// A VCF writer float format.
+///
+/// This controls how `QUAL` and floating-point INFO and genotype field values are written.
+///
+/// By default, floats are written with the shortest representation that round-trips and never
+/// in scientific notation, and NaN is written as `NaN`.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_vcf::writer::FloatFormat;
+///
+/// // Similar to the C `%g` conversion, e.g., as used by bcftools.
+/// let float_format = FloatFormat::default()
+///     .set_precision(Some(6))
+///     .set_scientific_notation_thresholds(Some((-4, 6)))
+///     .set_nan_as_missing(true);
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct FloatFormat {
+    precision: Option<usize>,
+    scientific_notation_thresholds: Option<(i32, i32)>,
+    nan_as_missing: bool,
+}
+
+impl FloatFormat {
+    /// Sets the maximum number of significant digits.
+    ///
+    /// Digits in the integer part are never truncated, and trailing zeros in the fractional part
+    /// are removed. When unset, floats are written with the fewest digits that round-trip. A
+    /// precision of 0 is treated as 1.
+    ///
+    /// By default, this is unset.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::writer::FloatFormat;
+    /// let float_format = FloatFormat::default().set_precision(Some(6));
+    /// ```
+    pub fn set_precision(mut self, precision: Option<usize>) -> Self {
+        self.precision = precision.map(|p| p.max(1));
+        self
+    }
+
+    /// Sets the decimal exponent bounds outside of which floats are written in scientific
+    /// notation.
+    ///
+    /// Given `(lower, upper)`, a float with a decimal exponent `e` is written in scientific
+    /// notation when `e < lower` or `e >= upper`. The exponent is written with a sign and at least
+    /// two digits, e.g., `1.5e-05`. When unset, scientific notation is never used.
+    ///
+    /// By default, this is unset.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::writer::FloatFormat;
+    /// let float_format = FloatFormat::default().set_scientific_notation_thresholds(Some((-4, 6)));
+    /// ```
+    pub fn set_scientific_notation_thresholds(mut self, thresholds: Option<(i32, i32)>) -> Self {
+        self.scientific_notation_thresholds = thresholds;
+        self
+    }
+
+    /// Sets whether to write NaN as a missing value (`.`).
+    ///
+    /// By default, this is disabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::writer::FloatFormat;
+    /// let float_format = FloatFormat::default().set_nan_as_missing(true);
+    /// ```
+    pub fn set_nan_as_missing(mut self, nan_as_missing: bool) -> Self {
+        self.nan_as_missing = nan_as_missing;
+        self
+    }
+
+    pub(crate) fn write<W>(&self, writer: &mut W, n: f32) -> fmt::Result
+    where
+        W: Write,
+    {
+        if n.is_nan() {
+            if self.nan_as_missing {
+                return writer.write_str(".");
+            } else {
+                return write!(writer, "{n}");
+            }
+        } else if n.is_infinite() {
+            return write!(writer, "{n}");
+        }
+
+        let (mantissa, exponent) = self.scientific_parts(n);
+
+        let is_scientific = self
+            .scientific_notation_thresholds
+            .map(|(lower, upper)| exponent < lower || exponent >= upper)
+            .unwrap_or(false);
+
+        if is_scientific {
+            let sign = if exponent < 0 { '-' } else { '+' };
+            write!(
+                writer,
+                "{}e{}{:02}",
+                trim_fraction(&mantissa),
+                sign,
+                exponent.unsigned_abs()
+            )
+        } else if let Some(precision) = self.precision {
+            let scale = usize::try_from(precision as i64 - 1 - i64::from(exponent)).unwrap_or(0);
+            let s = format!("{n:.scale$}");
+            writer.write_str(trim_fraction(&s))
+        } else {
+            write!(writer, "{n}")
+        }
+    }
+
+    // Returns the (rounded) decimal mantissa and exponent of a finite float.
+    fn scientific_parts(&self, n: f32) -> (String, i32) {
+        let s = match self.precision {
+            Some(precision) => format!("{:.*e}", precision - 1, n),
+            None => format!("{n:e}"),
+        };
+
+        // The formatted exponent of a finite float is always valid.
+        let (mantissa, exponent) = s.split_once('e').expect("missing exponent");
+        let exponent = exponent.parse().expect("invalid exponent");
+
+        (mantissa.into(), exponent)
+    }
+}
+
+fn trim_fraction(s: &str) -> &str {
+    if s.contains('.') {
+        s.trim_end_matches('0').trim_end_matches('.')
+    } else {
+        s
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn format(float_format: FloatFormat, n: f32) -> Result<String, fmt::Error> {
+        let mut s = String::new();
+        float_format.write(&mut s, n)?;
+        Ok(s)
+    }
+
+    #[test]
+    fn test_write_with_default() -> fmt::Result {
+        let float_format = FloatFormat::default();
+
+        assert_eq!(format(float_format, 0.0)?, "0");
+        assert_eq!(format(float_format, 13.5)?, "13.5");
+        assert_eq!(format(float_format, 0.333)?, "0.333");
+        assert_eq!(format(float_format, 1e-7)?, "0.0000001");
+        assert_eq!(format(float_format, f32::NAN)?, "NaN");
+        assert_eq!(format(float_format, f32::INFINITY)?, "inf");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_with_precision() -> fmt::Result {
+        let float_format = FloatFormat::default().set_precision(Some(3));
+
+        assert_eq!(format(float_format, 0.0)?, "0");
+        assert_eq!(format(float_format, 13.5)?, "13.5");
+        assert_eq!(format(float_format, 1.0 / 3.0)?, "0.333");
+        assert_eq!(format(float_format, 99.99)?, "100");
+        assert_eq!(format(float_format, 12345.0)?, "12345");
+        assert_eq!(format(float_format, -2.0 / 3.0)?, "-0.667");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_with_scientific_notation_thresholds() -> fmt::Result {
+        let float_format = FloatFormat::default()
+            .set_precision(Some(6))
+            .set_scientific_notation_thresholds(Some((-4, 6)));
+
+        assert_eq!(format(float_format, 0.0)?, "0");
+        assert_eq!(format(float_format, 0.0001)?, "0.0001");
+        assert_eq!(format(float_format, 0.000015)?, "1.5e-05");
+        assert_eq!(format(float_format, 123456.0)?, "123456");
+        assert_eq!(format(float_format, 1234567.0)?, "1.23457e+06");
+        assert_eq!(format(float_format, 999999.5)?, "1e+06");
+
+        let float_format = FloatFormat::default().set_scientific_notation_thresholds(Some((-2, 3)));
+        assert_eq!(format(float_format, 0.00125)?, "1.25e-03");
+        assert_eq!(format(float_format, 1500.0)?, "1.5e+03");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_with_nan_as_missing() -> fmt::Result {
+        let float_format = FloatFormat::default().set_nan_as_missing(true);
+        assert_eq!(format(float_format, f32::NAN)?, ".");
+        assert_eq!(format(float_format, 1.0)?, "1");
+        Ok(())
+    }
+}
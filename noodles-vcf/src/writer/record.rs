@@ -0,0 +1,356 @@
+use std::fmt::{self, Write};
+
+use super::FloatFormat;
+use crate::{
+    header::FileFormat,
+    record::{
+        genotypes::{genotype, Genotype},
+        info,
+        value::encode_string,
+        Genotypes, Info, Record, FIELD_DELIMITER, MISSING_FIELD,
+    },
+};
+
+const INFO_DELIMITER: &str = ";";
+const GENOTYPE_DELIMITER: &str = ":";
+const VALUE_DELIMITER: &str = ",";
+const MISSING_VALUE: &str = ".";
+
+// A record displayed with a writer's float format and file format.
+pub(super) struct FormattedRecord<'a> {
+    pub(super) record: &'a Record,
+    pub(super) float_format: &'a FloatFormat,
+    pub(super) file_format: FileFormat,
+}
+
+impl fmt::Display for FormattedRecord<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_record(f, self.record, self.float_format, self.file_format)
+    }
+}
+
+// Writes a record with floats formatted by the given float format and string and character values
+// percent-encoded, if supported by the given file format. All other values are written as their
+// display representations.
+//
+// This is shared by the `Record` display implementation and the sync and async writers.
+pub(crate) fn write_record<W>(
+    writer: &mut W,
+    record: &Record,
+    float_format: &FloatFormat,
+    file_format: FileFormat,
+) -> fmt::Result
+where
+    W: Write,
+{
+    write!(
+        writer,
+        "{chrom}{FIELD_DELIMITER}{pos}",
+        chrom = record.chromosome(),
+        pos = record.position()
+    )?;
+
+    if record.ids().is_empty() {
+        write!(writer, "{FIELD_DELIMITER}{MISSING_FIELD}")?;
+    } else {
+        write!(writer, "{FIELD_DELIMITER}{}", record.ids())?;
+    }
+
+    write!(
+        writer,
+        "{FIELD_DELIMITER}{ref}{FIELD_DELIMITER}{alt}{FIELD_DELIMITER}",
+        r#ref = record.reference_bases(),
+        alt = record.alternate_bases()
+    )?;
+
+    if let Some(quality_score) = record.quality_score() {
+        float_format.write(writer, f32::from(quality_score))?;
+    } else {
+        writer.write_str(MISSING_FIELD)?;
+    }
+
+    if let Some(filters) = record.filters() {
+        write!(writer, "{FIELD_DELIMITER}{filters}")?;
+    } else {
+        write!(writer, "{FIELD_DELIMITER}{MISSING_FIELD}")?;
+    }
+
+    write!(writer, "{FIELD_DELIMITER}")?;
+    write_info(writer, record.info(), float_format, file_format)?;
+
+    if !record.genotypes().is_empty() {
+        write!(writer, "{FIELD_DELIMITER}")?;
+        write_genotypes(writer, record.genotypes(), float_format, file_format)?;
+    }
+
+    Ok(())
+}
+
+fn write_info<W>(
+    writer: &mut W,
+    info: &Info,
+    float_format: &FloatFormat,
+    file_format: FileFormat,
+) -> fmt::Result
+where
+    W: Write,
+{
+    if info.is_empty() {
+        return writer.write_str(MISSING_FIELD);
+    }
+
+    for (i, (key, value)) in info.keys().zip(info.values()).enumerate() {
+        if i > 0 {
+            writer.write_str(INFO_DELIMITER)?;
+        }
+
+        write!(writer, "{key}")?;
+
+        match value {
+            None => write!(writer, "={MISSING_VALUE}")?,
+            Some(info::field::Value::Flag) => {}
+            Some(info::field::Value::Float(n)) => {
+                writer.write_char('=')?;
+                float_format.write(writer, *n)?;
+            }
+            Some(info::field::Value::FloatArray(values)) => {
+                writer.write_char('=')?;
+                write_float_array(writer, values, float_format)?;
+            }
+            Some(info::field::Value::Character(c)) => {
+                writer.write_char('=')?;
+                write_character(writer, file_format, *c)?;
+            }
+            Some(info::field::Value::String(s)) => {
+                writer.write_char('=')?;
+                write_string(writer, file_format, s)?;
+            }
+            Some(info::field::Value::CharacterArray(values)) => {
+                writer.write_char('=')?;
+                write_array(writer, values, |w, c| write_character(w, file_format, *c))?;
+            }
+            Some(info::field::Value::StringArray(values)) => {
+                writer.write_char('=')?;
+                write_array(writer, values, |w, s| write_string(w, file_format, s))?;
+            }
+            Some(v) => write!(writer, "={v}")?,
+        }
+    }
+
+    Ok(())
+}
+
+fn write_genotypes<W>(
+    writer: &mut W,
+    genotypes: &Genotypes,
+    float_format: &FloatFormat,
+    file_format: FileFormat,
+) -> fmt::Result
+where
+    W: Write,
+{
+    write!(writer, "{}", genotypes.keys())?;
+
+    for genotype in genotypes.iter() {
+        write!(writer, "{FIELD_DELIMITER}")?;
+        write_genotype(writer, genotype, float_format, file_format)?;
+    }
+
+    Ok(())
+}
+
+fn write_genotype<W>(
+    writer: &mut W,
+    genotype: &Genotype,
+    float_format: &FloatFormat,
+    file_format: FileFormat,
+) -> fmt::Result
+where
+    W: Write,
+{
+    if genotype.is_empty() {
+        return writer.write_str(MISSING_FIELD);
+    }
+
+    for (i, value) in genotype.values().enumerate() {
+        if i > 0 {
+            writer.write_str(GENOTYPE_DELIMITER)?;
+        }
+
+        match value {
+            None => writer.write_str(MISSING_VALUE)?,
+            Some(genotype::field::Value::Float(n)) => float_format.write(writer, *n)?,
+            Some(genotype::field::Value::FloatArray(values)) => {
+                write_float_array(writer, values, float_format)?
+            }
+            Some(genotype::field::Value::Character(c)) => write_character(writer, file_format, *c)?,
+            Some(genotype::field::Value::String(s)) => write_string(writer, file_format, s)?,
+            Some(genotype::field::Value::CharacterArray(values)) => {
+                write_array(writer, values, |w, c| write_character(w, file_format, *c))?
+            }
+            Some(genotype::field::Value::StringArray(values)) => {
+                write_array(writer, values, |w, s| write_string(w, file_format, s))?
+            }
+            Some(v) => write!(writer, "{v}")?,
+        }
+    }
+
+    Ok(())
+}
+
+fn write_float_array<W>(
+    writer: &mut W,
+    values: &[Option<f32>],
+    float_format: &FloatFormat,
+) -> fmt::Result
+where
+    W: Write,
+{
+    write_array(writer, values, |w, n| float_format.write(w, *n))
+}
+
+fn write_array<W, T, F>(writer: &mut W, values: &[Option<T>], mut f: F) -> fmt::Result
+where
+    W: Write,
+    F: FnMut(&mut W, &T) -> fmt::Result,
+{
+    for (i, value) in values.iter().enumerate() {
+        if i > 0 {
+            writer.write_str(VALUE_DELIMITER)?;
+        }
+
+        if let Some(v) = value {
+            f(writer, v)?;
+        } else {
+            writer.write_str(MISSING_VALUE)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn write_character<W>(writer: &mut W, file_format: FileFormat, c: char) -> fmt::Result
+where
+    W: Write,
+{
+    let mut buf = [0; 4];
+    write_string(writer, file_format, c.encode_utf8(&mut buf))
+}
+
+fn write_string<W>(writer: &mut W, file_format: FileFormat, s: &str) -> fmt::Result
+where
+    W: Write,
+{
+    writer.write_str(&encode_string(file_format, s))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Header;
+
+    #[test]
+    fn test_write_record() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::header::{
+            format::key as format_key,
+            info::key as info_key,
+            record::value::{
+                map::{Format, Info as InfoMap},
+                Map,
+            },
+        };
+
+        let header = Header::builder()
+            .add_info(
+                info_key::ALLELE_FREQUENCIES,
+                Map::<InfoMap>::from(&info_key::ALLELE_FREQUENCIES),
+            )
+            .add_info(
+                info_key::IS_IN_DB_SNP,
+                Map::<InfoMap>::from(&info_key::IS_IN_DB_SNP),
+            )
+            .add_format(
+                format_key::GENOTYPE,
+                Map::<Format>::from(&format_key::GENOTYPE),
+            )
+            .add_format(
+                format_key::GENOTYPE_POSTERIOR_PROBABILITIES,
+                Map::<Format>::from(&format_key::GENOTYPE_POSTERIOR_PROBABILITIES),
+            )
+            .add_sample_name("sample0")
+            .add_sample_name("sample1")
+            .build();
+
+        let record = Record::try_from_str(
+            "sq0\t1\t.\tA\tC,G\t13.333333\tPASS\tAF=0.000015,.;DB\tGT:GP\t0/1:0.1,0.8,0.1,0,0,0\t./.:.",
+            &header,
+        )?;
+
+        let mut buf = String::new();
+        write_record(
+            &mut buf,
+            &record,
+            &FloatFormat::default(),
+            header.file_format(),
+        )?;
+        assert_eq!(buf, record.to_string());
+
+        let float_format = FloatFormat::default()
+            .set_precision(Some(3))
+            .set_scientific_notation_thresholds(Some((-4, 3)));
+
+        buf.clear();
+        write_record(&mut buf, &record, &float_format, header.file_format())?;
+
+        let expected =
+            "sq0\t1\t.\tA\tC,G\t13.3\tPASS\tAF=1.5e-05,.;DB\tGT:GP\t0/1:0.1,0.8,0.1,0,0,0\t./.:.";
+        assert_eq!(buf, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_record_with_reserved_characters() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::header::{
+            format::key as format_key,
+            record::value::{map::Format, Map},
+        };
+
+        let header = Header::builder()
+            .add_format(
+                format_key::GENOTYPE,
+                Map::<Format>::from(&format_key::GENOTYPE),
+            )
+            .add_format(format_key::FILTER, Map::<Format>::from(&format_key::FILTER))
+            .add_sample_name("sample0")
+            .build();
+
+        let record = Record::try_from_str(
+            "sq0\t1\t.\tA\t.\t.\tPASS\tNOTE=n%3Bd%2Cl\tGT:FT\t0|0:q%3A10",
+            &header,
+        )?;
+
+        let mut buf = String::new();
+        write_record(
+            &mut buf,
+            &record,
+            &FloatFormat::default(),
+            FileFormat::new(4, 3),
+        )?;
+        let expected = "sq0\t1\t.\tA\t.\t.\tPASS\tNOTE=n%3Bd%2Cl\tGT:FT\t0|0:q%3A10";
+        assert_eq!(buf, expected);
+
+        // Percent-encoding was introduced in VCF 4.3.
+        let mut buf = String::new();
+        write_record(
+            &mut buf,
+            &record,
+            &FloatFormat::default(),
+            FileFormat::new(4, 2),
+        )?;
+        let expected = "sq0\t1\t.\tA\t.\t.\tPASS\tNOTE=n;d,l\tGT:FT\t0|0:q:10";
+        assert_eq!(buf, expected);
+
+        Ok(())
+    }
+}